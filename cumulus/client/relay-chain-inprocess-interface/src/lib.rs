@@ -297,6 +297,7 @@ fn build_polkadot_full_node(
 
 			overseer_gen: polkadot_service::RealOverseerGen,
 			overseer_message_channel_capacity_override: None,
+			availability_store_max_size: None,
 			malus_finality_delay: None,
 			hwbench,
 		},
@@ -380,6 +380,20 @@ impl RuntimeApiSubsystemClient for BlockChainRpcClient {
 		Ok(self.rpc_client.parachain_host_unapplied_slashes(at).await?)
 	}
 
+	async fn unapplied_backer_slashes(
+		&self,
+		at: Hash,
+	) -> Result<
+		Vec<(
+			polkadot_primitives::SessionIndex,
+			polkadot_primitives::CandidateHash,
+			slashing::PendingSlashes,
+		)>,
+		ApiError,
+	> {
+		Ok(self.rpc_client.parachain_host_unapplied_backer_slashes(at).await?)
+	}
+
 	async fn key_ownership_proof(
 		&self,
 		at: Hash,
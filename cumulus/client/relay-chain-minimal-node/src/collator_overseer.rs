@@ -165,6 +165,7 @@ fn build_overseer(
 		.activation_external_listeners(Default::default())
 		.span_per_active_leaf(Default::default())
 		.active_leaves(Default::default())
+		.subsystems_health(Default::default())
 		.supports_parachains(runtime_client)
 		.metrics(Metrics::register(registry)?)
 		.spawner(spawner);
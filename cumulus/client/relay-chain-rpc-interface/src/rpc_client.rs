@@ -420,6 +420,16 @@ impl RelayChainRpcClient {
 			.await
 	}
 
+	/// Returns a list of validators that backed a candidate which was later disputed and
+	/// concluded invalid in a past session, and that are still pending slashing.
+	pub async fn parachain_host_unapplied_backer_slashes(
+		&self,
+		at: RelayHash,
+	) -> Result<Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>, RelayChainError> {
+		self.call_remote_runtime_function("ParachainHost_unapplied_backer_slashes", at, None::<()>)
+			.await
+	}
+
 	/// Returns a merkle proof of a validator session key in a past session.
 	///
 	/// This is a staging method! Do not use on production runtimes!
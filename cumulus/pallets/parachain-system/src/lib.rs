@@ -44,7 +44,7 @@ use frame_support::{
 	weights::Weight,
 };
 use frame_system::{ensure_none, ensure_root, pallet_prelude::HeaderFor};
-use polkadot_parachain_primitives::primitives::RelayChainBlockNumber;
+use polkadot_parachain_primitives::primitives::{RelayChainBlockNumber, UpwardMessageFragment};
 use polkadot_runtime_parachains::FeeTracker;
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -889,6 +889,11 @@ pub mod pallet {
 	pub(super) type PendingUpwardMessages<T: Config> =
 		StorageValue<_, Vec<UpwardMessage>, ValueQuery>;
 
+	/// The id of the next upward message which is too big to be sent in one piece and is sent in
+	/// fragments instead.
+	#[pallet::storage]
+	pub(super) type NextUpwardMessageFragmentId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Initialization value for the delivery fee factor for UMP.
 	#[pallet::type_value]
 	pub fn UpwardInitialDeliveryFeeFactor() -> FixedU128 {
@@ -1527,6 +1532,7 @@ impl<T: Config> Pallet<T> {
 				allowed_ancestry_len: 0,
 				max_candidate_depth: 0,
 			},
+			max_upward_reassembled_message_size: 4 * 1024,
 		};
 		<HostConfiguration<T>>::put(host_config);
 	}
@@ -1543,7 +1549,9 @@ impl<T: Config> frame_system::SetCode<T> for ParachainSetCode<T> {
 impl<T: Config> Pallet<T> {
 	/// Puts a message in the `PendingUpwardMessages` storage item.
 	/// The message will be later sent in `on_finalize`.
-	/// Checks host configuration to see if message is too big.
+	/// Checks host configuration to see if message is too big, in which case it is split into
+	/// fragments that the relay chain reassembles, as long as it doesn't exceed
+	/// `max_upward_reassembled_message_size` either.
 	/// Increases the delivery fee factor if the queue is sufficiently (see
 	/// [`ump_constants::THRESHOLD_FACTOR`]) congested.
 	pub fn send_upward_message(message: UpwardMessage) -> Result<(u32, XcmHash), MessageSendError> {
@@ -1562,13 +1570,24 @@ impl<T: Config> Pallet<T> {
 		// However, changing this setting is expected to be rare.
 		if let Some(cfg) = Self::host_configuration() {
 			if message_len > cfg.max_upward_message_size as usize {
-				return Err(MessageSendError::TooBig)
+				if message_len > cfg.max_upward_reassembled_message_size as usize {
+					return Err(MessageSendError::TooBig)
+				}
+				let message_id = NextUpwardMessageFragmentId::<T>::get();
+				let fragments =
+					UpwardMessageFragment::split(message_id, &message, cfg.max_upward_message_size)
+						.ok_or(MessageSendError::TooBig)?;
+				NextUpwardMessageFragmentId::<T>::put(message_id.wrapping_add(1));
+				for fragment in fragments {
+					<PendingUpwardMessages<T>>::append(fragment);
+				}
+			} else {
+				<PendingUpwardMessages<T>>::append(message.clone());
 			}
 			let threshold =
 				cfg.max_upward_queue_size.saturating_div(ump_constants::THRESHOLD_FACTOR);
 			// We check the threshold against total size and not number of messages since messages
 			// could be big or small.
-			<PendingUpwardMessages<T>>::append(message.clone());
 			let pending_messages = PendingUpwardMessages::<T>::get();
 			let total_size: usize = pending_messages.iter().map(UpwardMessage::len).sum();
 			if total_size > threshold as usize {
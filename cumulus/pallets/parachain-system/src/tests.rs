@@ -599,6 +599,74 @@ fn send_upward_message_num_per_candidate() {
 		);
 }
 
+#[test]
+fn send_upward_message_too_big_in_fragments() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, sproof| {
+			sproof.host_config.max_upward_message_size = 32;
+			sproof.host_config.max_upward_reassembled_message_size = 64;
+			sproof.host_config.max_upward_message_num_per_candidate = 8;
+			sproof.relay_dispatch_queue_remaining_capacity = None;
+		})
+		.add_with_post_test(
+			1,
+			|| {
+				ParachainSystem::send_upward_message(vec![1u8; 50]).unwrap();
+				ParachainSystem::send_upward_message(b"small".to_vec()).unwrap();
+				ParachainSystem::send_upward_message(vec![2u8; 40]).unwrap();
+				// The relay chain doesn't reassemble a message larger than that.
+				assert_eq!(
+					ParachainSystem::send_upward_message(vec![0u8; 65]),
+					Err(MessageSendError::TooBig),
+				);
+			},
+			|| {
+				let mut expected = UpwardMessageFragment::split(0, &[1u8; 50], 32).unwrap();
+				expected.push(b"small".to_vec());
+				expected.extend(UpwardMessageFragment::split(1, &[2u8; 40], 32).unwrap());
+				assert!(expected.iter().all(|message| message.len() <= 32));
+				assert_eq!(UpwardMessages::<Test>::get(), expected);
+				assert_eq!(NextUpwardMessageFragmentId::<Test>::get(), 2);
+			},
+		);
+}
+
+/// With the sizes of Rococo, whose message queue enqueues messages of less than 32 KiB, messages
+/// of up to 50 KiB can't be sent in fragments.
+#[test]
+fn send_upward_message_too_big_to_reassemble() {
+	let max_size = 32 * 1024 - 256;
+	BlockTests::new()
+		.with_relay_sproof_builder(move |_, _, sproof| {
+			sproof.host_config.max_upward_message_size = 8 * 1024;
+			sproof.host_config.max_upward_reassembled_message_size = max_size;
+			sproof.host_config.max_upward_message_num_per_candidate = 8;
+			sproof.host_config.max_upward_queue_size = 1024 * 1024;
+			sproof.relay_dispatch_queue_remaining_capacity = None;
+		})
+		.add_with_post_test(
+			1,
+			move || {
+				assert_eq!(
+					ParachainSystem::send_upward_message(vec![0u8; 50 * 1024]),
+					Err(MessageSendError::TooBig),
+				);
+				assert_eq!(
+					ParachainSystem::send_upward_message(vec![0u8; max_size as usize + 1]),
+					Err(MessageSendError::TooBig),
+				);
+				ParachainSystem::send_upward_message(vec![1u8; max_size as usize]).unwrap();
+			},
+			move || {
+				let expected =
+					UpwardMessageFragment::split(0, &vec![1u8; max_size as usize], 8 * 1024)
+						.unwrap();
+				assert_eq!(UpwardMessages::<Test>::get(), expected);
+				assert_eq!(NextUpwardMessageFragmentId::<Test>::get(), 1);
+			},
+		);
+}
+
 #[test]
 fn send_upward_message_relay_bottleneck() {
 	BlockTests::new()
@@ -1286,6 +1286,75 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_runtime_api::XcmDryRunApi<Block, RuntimeCall, RuntimeEvent> for Runtime {
+		fn dry_run_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+		) -> Result<
+			xcm_dry_run_runtime_api::ExtrinsicDryRunEffects<RuntimeEvent>,
+			xcm_dry_run_runtime_api::Error,
+		> {
+			System::reset_events();
+			let (result, forwarded_xcms) =
+				xcm_builder::record_sent_messages(|| Executive::apply_extrinsic(extrinsic));
+			let execution_result = result.map_err(|error| {
+				log::error!(
+					target: "xcm::XcmDryRunApi::dry_run_extrinsic",
+					"Applying extrinsic failed with error {:?}",
+					error,
+				);
+				xcm_dry_run_runtime_api::Error::InvalidExtrinsic
+			})?;
+			let emitted_events =
+				System::read_events_no_consensus().map(|record| record.event).collect();
+			Ok(xcm_dry_run_runtime_api::ExtrinsicDryRunEffects {
+				execution_result,
+				emitted_events,
+				forwarded_xcms: xcm_dry_run_runtime_api::versioned_forwarded_xcms(forwarded_xcms),
+			})
+		}
+
+		fn dry_run_xcm(
+			origin_location: xcm::VersionedMultiLocation,
+			xcm: xcm::VersionedXcm<RuntimeCall>,
+		) -> Result<
+			xcm_dry_run_runtime_api::XcmDryRunEffects<RuntimeEvent>,
+			xcm_dry_run_runtime_api::Error,
+		> {
+			let origin_location: MultiLocation = origin_location.try_into().map_err(|()| {
+				log::error!(
+					target: "xcm::XcmDryRunApi::dry_run_xcm",
+					"Location version conversion failed",
+				);
+				xcm_dry_run_runtime_api::Error::VersionedConversionFailed
+			})?;
+			let xcm: Xcm<RuntimeCall> = xcm.try_into().map_err(|()| {
+				log::error!(
+					target: "xcm::XcmDryRunApi::dry_run_xcm",
+					"Xcm version conversion failed",
+				);
+				xcm_dry_run_runtime_api::Error::VersionedConversionFailed
+			})?;
+			let mut hash = xcm.using_encoded(sp_core::hashing::blake2_256);
+			System::reset_events();
+			let (execution_result, forwarded_xcms) = xcm_builder::record_sent_messages(|| {
+				xcm_executor::XcmExecutor::<xcm_config::XcmConfig>::prepare_and_execute(
+					origin_location,
+					xcm,
+					&mut hash,
+					Weight::MAX,
+					Weight::zero(),
+				)
+			});
+			let emitted_events =
+				System::read_events_no_consensus().map(|record| record.event).collect();
+			Ok(xcm_dry_run_runtime_api::XcmDryRunEffects {
+				execution_result,
+				emitted_events,
+				forwarded_xcms: xcm_dry_run_runtime_api::versioned_forwarded_xcms(forwarded_xcms),
+			})
+		}
+	}
+
 	impl assets_common::runtime_api::FungiblesApi<
 		Block,
 		AccountId,
@@ -52,7 +52,7 @@ use xcm_builder::{
 	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
 	SignedToAccountId32, SovereignSignedViaLocation, StartsWith, StartsWithExplicitGlobalConsensus,
 	TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin,
-	WithUniqueTopic, XcmFeeManagerFromComponents, XcmFeeToAccount,
+	WithMessageRecording, WithUniqueTopic, XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 use xcm_executor::{traits::WithOriginFilter, XcmExecutor};
 
@@ -628,13 +628,15 @@ type LocalXcmRouter = (
 );
 
 /// The means for routing XCM messages which are not for local execution into the right message
-/// queues.
-pub type XcmRouter = WithUniqueTopic<(
-	LocalXcmRouter,
-	// Router which wraps and sends xcm to BridgeHub to be delivered to the Rococo
-	// GlobalConsensus
-	ToRococoXcmRouter,
-)>;
+/// queues. Sent messages can be recorded so that they show up in dry-runs.
+pub type XcmRouter = WithUniqueTopic<
+	WithMessageRecording<(
+		LocalXcmRouter,
+		// Router which wraps and sends xcm to BridgeHub to be delivered to the Rococo
+		// GlobalConsensus
+		ToRococoXcmRouter,
+	)>,
+>;
 
 impl pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
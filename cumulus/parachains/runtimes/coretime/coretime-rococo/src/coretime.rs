@@ -26,7 +26,7 @@ use frame_support::{
 	},
 };
 use pallet_broker::{CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, RCBlockNumberOf};
-use parachains_common::{AccountId, Balance, BlockNumber};
+use parachains_common::{AccountId, Balance};
 use xcm::latest::prelude::*;
 
 pub struct CreditToCollatorPot;
@@ -70,7 +70,6 @@ parameter_types! {
 
 parameter_types! {
 	pub storage CoreCount: Option<CoreIndex> = None;
-	pub storage CoretimeRevenue: Option<(BlockNumber, Balance)> = None;
 }
 
 /// Type that implements the `CoretimeInterface` for the allocation of Coretime. Meant to operate
@@ -206,14 +205,16 @@ impl CoretimeInterface for CoretimeAllocator {
 	}
 
 	fn check_notify_revenue_info() -> Option<(RCBlockNumberOf<Self>, Self::Balance)> {
-		let revenue = CoretimeRevenue::get();
-		CoretimeRevenue::set(&None);
-		revenue
+		// Revenue is pushed by the Relay-chain through `Broker::notify_revenue`.
+		None
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn ensure_notify_revenue_info(when: RCBlockNumberOf<Self>, revenue: Self::Balance) {
-		CoretimeRevenue::set(&Some((when, revenue)));
+		pallet_broker::RevenueInbox::<Runtime>::put(pallet_broker::OnDemandRevenueRecord {
+			until: when,
+			amount: revenue,
+		});
 	}
 }
 
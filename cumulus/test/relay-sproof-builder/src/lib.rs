@@ -67,6 +67,7 @@ impl Default for RelayStateSproofBuilder {
 					allowed_ancestry_len: 0,
 					max_candidate_depth: 0,
 				},
+				max_upward_reassembled_message_size: 256,
 			},
 			dmq_mqc_head: None,
 			upgrade_go_ahead: None,
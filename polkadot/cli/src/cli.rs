@@ -61,6 +61,85 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Export the evidence of a dispute into a self-contained bundle.
+	ExportDispute(ExportDisputeCmd),
+
+	/// Re-run the candidate of an exported dispute bundle through a local PVF host.
+	ReplayDispute(ReplayDisputeCmd),
+}
+
+/// The `export-dispute` command.
+///
+/// Collects the candidate receipt, all recorded votes, the PoV, the validation code and the
+/// executor parameters of a dispute and writes them SCALE encoded to a file.
+#[derive(Debug, Parser)]
+pub struct ExportDisputeCmd {
+	/// The session the dispute was raised in.
+	#[arg(long)]
+	pub session: u32,
+
+	/// The hash of the disputed candidate.
+	#[arg(long, value_name = "HASH")]
+	pub candidate_hash: service::Hash,
+
+	/// The file to write the bundle to.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: sc_cli::PruningParams,
+}
+
+impl sc_cli::CliConfiguration for ExportDisputeCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+
+	fn pruning_params(&self) -> Option<&sc_cli::PruningParams> {
+		Some(&self.pruning_params)
+	}
+}
+
+/// The `replay-dispute` command.
+///
+/// Reads a bundle written by `export-dispute` and reports the verdict of a local validation next
+/// to the votes recorded in the bundle.
+#[derive(Debug, Parser)]
+pub struct ReplayDisputeCmd {
+	/// The bundle file written by `export-dispute`.
+	#[arg(value_name = "PATH")]
+	pub input: PathBuf,
+
+	/// Path to the directory where auxiliary worker binaries reside.
+	///
+	/// If not specified, the main binary's directory is searched first, then
+	/// `/usr/lib/polkadot` is searched.
+	#[arg(long, value_name = "PATH")]
+	pub workers_path: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+}
+
+impl sc_cli::CliConfiguration for ReplayDisputeCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
 }
 
 #[allow(missing_docs)]
@@ -126,6 +205,14 @@ pub struct RunCmd {
 	#[arg(long)]
 	pub overseer_channel_capacity_override: Option<usize>,
 
+	/// Maximum size of the availability store in MiB.
+	///
+	/// Once exceeded, the full available data of the oldest candidates is dropped first, then
+	/// their erasure chunks which aren't our own. If not specified, the availability store is only
+	/// pruned by time.
+	#[arg(long, value_name = "MiB")]
+	pub availability_store_max_size: Option<u64>,
+
 	/// Path to the directory where auxiliary worker binaries reside.
 	///
 	/// If not specified, the main binary's directory is searched first, then
@@ -18,6 +18,8 @@ use crate::cli::{Cli, Subcommand, NODE_VERSION};
 use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, SUBSTRATE_REFERENCE_HARDWARE};
 use futures::future::TryFutureExt;
 use log::info;
+use parity_scale_codec::Encode;
+use polkadot_node_primitives::ValidationResult;
 use sc_cli::SubstrateCli;
 use service::{
 	self,
@@ -250,6 +252,10 @@ where
 				overseer_message_channel_capacity_override: cli
 					.run
 					.overseer_channel_capacity_override,
+				availability_store_max_size: cli
+					.run
+					.availability_store_max_size
+					.map(|max_size| max_size.saturating_mul(1024 * 1024)),
 				malus_finality_delay: maybe_malus_finality_delay,
 				hwbench,
 			},
@@ -484,6 +490,89 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<service::Block>(&config))?)
 		},
+		Some(Subcommand::ExportDispute(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			let chain_spec = &runner.config().chain_spec;
+
+			set_default_ss58_version(chain_spec);
+
+			runner.sync_run(|mut config| {
+				let (client, _, _, _) = service::new_chain_ops(&mut config, None)?;
+				let bundle = service::dispute_bundle::export_dispute_bundle(
+					&client,
+					&config.database,
+					cmd.session,
+					cmd.candidate_hash,
+				)?;
+
+				if !bundle.is_replayable() {
+					log::warn!(
+						"Dispute bundle is incomplete and can't be replayed: available data: {}, \
+						validation code: {}, executor params: {}",
+						bundle.available_data.is_some(),
+						bundle.validation_code.is_some(),
+						bundle.executor_params.is_some(),
+					);
+				}
+				std::fs::write(&cmd.output, bundle.encode()).map_err(sc_cli::Error::from)?;
+				info!(
+					"Exported dispute bundle with {} valid and {} invalid votes to {:?}",
+					bundle.valid.len(),
+					bundle.invalid.len(),
+					cmd.output,
+				);
+
+				Ok(())
+			})
+		},
+		Some(Subcommand::ReplayDispute(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			let node_version = if cli.run.disable_worker_version_check {
+				None
+			} else {
+				Some(NODE_VERSION.to_string())
+			};
+
+			runner.sync_run(|config| {
+				let encoded = std::fs::read(&cmd.input).map_err(sc_cli::Error::from)?;
+				let bundle = service::dispute_bundle::DisputeBundle::decode_versioned(&encoded)
+					.map_err(|err| Error::Other(err.to_string()))?;
+				let candidate_hash = bundle.candidate_hash;
+				let (valid_votes, invalid_votes) = (bundle.valid.len(), bundle.invalid.len());
+				let conclusion = bundle.conclusion();
+
+				let replay = service::dispute_bundle::replay_dispute_bundle(
+					bundle,
+					config.base_path.path().join("pvf-replay-artifacts"),
+					cmd.workers_path.clone(),
+					node_version,
+				);
+				let result = config.tokio_handle.block_on(replay)?;
+
+				let valid = match result {
+					ValidationResult::Valid(..) => true,
+					ValidationResult::Invalid(ref reason) => {
+						info!("Candidate {:?} is invalid: {:?}", candidate_hash, reason);
+						false
+					},
+				};
+				info!(
+					"Replayed candidate {:?}: local verdict {}, recorded votes: {} valid, {} invalid",
+					candidate_hash,
+					if valid { "valid" } else { "invalid" },
+					valid_votes,
+					invalid_votes,
+				);
+				if conclusion.map_or(false, |conclusion| conclusion != valid) {
+					log::warn!(
+						"Local verdict for candidate {:?} differs from the dispute conclusion",
+						candidate_hash,
+					);
+				}
+
+				Ok(())
+			})
+		},
 	}?;
 
 	#[cfg(feature = "pyroscope")]
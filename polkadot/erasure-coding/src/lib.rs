@@ -96,6 +96,15 @@ pub const fn recovery_threshold(n_validators: usize) -> Result<usize, Error> {
 	Ok(needed + 1)
 }
 
+/// Obtain the number of systematic chunks needed to recover the data.
+///
+/// The encoding is systematic: the first chunks, in chunk index order, hold the encoded data
+/// itself. Their count is the highest power of two not exceeding the [`recovery_threshold`].
+pub fn systematic_recovery_threshold(n_validators: usize) -> Result<usize, Error> {
+	let threshold = recovery_threshold(n_validators)?;
+	Ok(1 << threshold.ilog2())
+}
+
 fn code_params(n_validators: usize) -> Result<CodeParams, Error> {
 	// we need to be able to reconstruct from 1/3 - eps
 
@@ -204,6 +213,52 @@ where
 	Decode::decode(&mut &payload_bytes[..]).or_else(|_e| Err(Error::BadPayload))
 }
 
+/// Reconstruct the v1 available data from the systematic chunks.
+///
+/// See [`reconstruct_from_systematic`].
+pub fn reconstruct_from_systematic_v1(
+	n_validators: usize,
+	chunks: Vec<Vec<u8>>,
+) -> Result<AvailableData, Error> {
+	reconstruct_from_systematic(n_validators, chunks)
+}
+
+/// Reconstruct decodable data from the systematic chunks, without any Reed-Solomon decoding.
+///
+/// `chunks` must hold the first [`systematic_recovery_threshold`] chunks, ordered by their
+/// index. Any further chunks are ignored.
+///
+/// Works only up to 65536 validators, and `n_validators` must be non-zero.
+pub fn reconstruct_from_systematic<T: Decode>(
+	n_validators: usize,
+	chunks: Vec<Vec<u8>>,
+) -> Result<T, Error> {
+	let k = systematic_recovery_threshold(n_validators)?;
+	if chunks.len() < k {
+		return Err(Error::NotEnoughChunks)
+	}
+	let chunks = &chunks[..k];
+
+	let shard_len = chunks[0].len();
+	if shard_len % 2 != 0 {
+		return Err(Error::UnevenLength)
+	}
+	if shard_len == 0 || chunks.iter().any(|chunk| chunk.len() != shard_len) {
+		return Err(Error::NonUniformChunks)
+	}
+
+	// The payload is split into pieces of two bytes, which are distributed round-robin over the
+	// systematic chunks.
+	let mut payload_bytes = Vec::with_capacity(shard_len * k);
+	for offset in (0..shard_len).step_by(2) {
+		for chunk in chunks {
+			payload_bytes.extend_from_slice(&chunk[offset..offset + 2]);
+		}
+	}
+
+	Decode::decode(&mut &payload_bytes[..]).map_err(|_| Error::BadPayload)
+}
+
 /// An iterator that yields merkle branches and chunk data for all chunks to
 /// be sent to other validators.
 pub struct Branches<'a, I> {
@@ -379,6 +434,35 @@ mod tests {
 		assert_eq!(reconstructed, available_data);
 	}
 
+	#[test]
+	fn systematic_round_trip_works() {
+		let pov = PoV { block_data: BlockData((0..255).collect()) };
+		let available_data = AvailableData { pov: pov.into(), validation_data: Default::default() };
+
+		for n_validators in [2, 4, 7, 10, 100, 1000] {
+			let chunks = obtain_chunks(n_validators, &available_data).unwrap();
+			let k = systematic_recovery_threshold(n_validators).unwrap();
+			assert!(k <= recovery_threshold(n_validators).unwrap());
+
+			let reconstructed: AvailableData =
+				reconstruct_from_systematic(n_validators, chunks[..k].to_vec()).unwrap();
+			assert_eq!(reconstructed, available_data);
+
+			assert_eq!(
+				reconstruct_from_systematic_v1(n_validators, chunks[..k - 1].to_vec()),
+				Err(Error::NotEnoughChunks),
+			);
+		}
+	}
+
+	#[test]
+	fn systematic_recovery_threshold_is_power_of_two() {
+		assert_eq!(systematic_recovery_threshold(10), Ok(4));
+		assert_eq!(systematic_recovery_threshold(7), Ok(2));
+		assert_eq!(systematic_recovery_threshold(100), Ok(32));
+		assert_eq!(systematic_recovery_threshold(1), Err(Error::NotEnoughValidators));
+	}
+
 	#[test]
 	fn reconstruct_does_not_panic_on_low_validator_count() {
 		let reconstructed = reconstruct_v1(1, [].iter().cloned());
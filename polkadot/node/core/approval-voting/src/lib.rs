@@ -98,6 +98,16 @@ mod ops;
 mod persisted_entries;
 mod time;
 
+/// The parts of approval voting needed by the subsystem benchmarks to compute assignments and
+/// to drive the subsystem with their own clock.
+#[cfg(feature = "subsystem-benchmarks")]
+pub mod bench_support {
+	pub use crate::{
+		criteria::{compute_assignments, Config as AssignmentConfig, OurAssignment},
+		time::{slot_number_to_tick, Clock, SystemClock, Tick},
+	};
+}
+
 use crate::{
 	approval_checking::{Check, TranchesToApproveResult},
 	approval_db::common::{Config as DatabaseConfig, DbBackend},
@@ -159,6 +169,7 @@ pub struct ApprovalVotingSubsystem {
 	db: Arc<dyn Database>,
 	mode: Mode,
 	metrics: Metrics,
+	clock: Arc<dyn Clock + Send + Sync>,
 }
 
 #[derive(Clone)]
@@ -452,9 +463,26 @@ impl ApprovalVotingSubsystem {
 			db_config: DatabaseConfig { col_approval_data: config.col_approval_data },
 			mode: Mode::Syncing(sync_oracle),
 			metrics,
+			clock: Arc::new(SystemClock),
 		}
 	}
 
+	/// Create a new approval voting subsystem with the given keystore, config, database and
+	/// clock.
+	///
+	/// Used by the benchmarks to replay blocks whose slots are in the past.
+	#[cfg(feature = "subsystem-benchmarks")]
+	pub fn with_config_and_clock(
+		config: Config,
+		db: Arc<dyn Database>,
+		keystore: Arc<LocalKeystore>,
+		sync_oracle: Box<dyn SyncOracle + Send>,
+		metrics: Metrics,
+		clock: Arc<dyn Clock + Send + Sync>,
+	) -> Self {
+		Self { clock, ..Self::with_config(config, db, keystore, sync_oracle, metrics) }
+	}
+
 	/// Revert to the block corresponding to the specified `hash`.
 	/// The operation is not allowed for blocks older than the last finalized one.
 	pub fn revert_to(&self, hash: Hash) -> Result<(), SubsystemError> {
@@ -493,15 +521,11 @@ fn db_sanity_check(db: Arc<dyn Database>, config: DatabaseConfig) -> SubsystemRe
 impl<Context: Send> ApprovalVotingSubsystem {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
 		let backend = DbBackend::new(self.db.clone(), self.db_config);
-		let future = run::<DbBackend, Context>(
-			ctx,
-			self,
-			Box::new(SystemClock),
-			Box::new(RealAssignmentCriteria),
-			backend,
-		)
-		.map_err(|e| SubsystemError::with_origin("approval-voting", e))
-		.boxed();
+		let clock = Box::new(self.clock.clone());
+		let future =
+			run::<DbBackend, Context>(ctx, self, clock, Box::new(RealAssignmentCriteria), backend)
+				.map_err(|e| SubsystemError::with_origin("approval-voting", e))
+				.boxed();
 
 		SpawnedSubsystem { name: "approval-voting-subsystem", future }
 	}
@@ -1245,6 +1269,17 @@ async fn handle_actions<Context>(
 				)?;
 
 				ctx.send_messages(messages.into_iter()).await;
+
+				// Resume the approval work we were doing before going down. The resulting
+				// actions need to be handled before the remaining ones, like for `IssueApproval`.
+				let next_actions: Vec<Action> =
+					pending_work_for_activation(ctx, overlayed_db, state, session_info_provider)
+						.await?
+						.into_iter()
+						.chain(actions_iter)
+						.collect();
+
+				actions_iter = next_actions.into_iter();
 			},
 			Action::Conclude => {
 				conclude = true;
@@ -1484,6 +1519,118 @@ fn distribution_messages_for_activation(
 	Ok(messages)
 }
 
+// Rebuilds the approval work which was pending when the node went down.
+//
+// Our assignments are persisted together with their `triggered` flag and our approvals as part of
+// the approval entries, so the database already records which checks we started: a triggered
+// assignment without an approval for a candidate that is neither approved nor waiting for our
+// signature is a check that never finished. These checks are launched again, without
+// distributing the assignment a second time, as `distribution_messages_for_activation` already
+// does that. Assignments which were not triggered yet get their first wakeup scheduled again,
+// exactly like on block import.
+#[overseer::contextbounds(ApprovalVoting, prefix = self::overseer)]
+async fn pending_work_for_activation<Context>(
+	ctx: &mut Context,
+	db: &OverlayedBackend<'_, impl Backend>,
+	state: &State,
+	session_info_provider: &mut RuntimeInfo,
+) -> SubsystemResult<Vec<Action>> {
+	let mut actions = Vec::new();
+
+	for block_hash in db.load_all_blocks()? {
+		let block_entry = match db.load_block_entry(&block_hash)? {
+			Some(b) => b,
+			None => continue,
+		};
+
+		let executor_params = match get_extended_session_info(
+			session_info_provider,
+			ctx.sender(),
+			block_hash,
+			block_entry.session(),
+		)
+		.await
+		{
+			Some(session_info) => session_info.executor_params.clone(),
+			None => continue,
+		};
+		let block_tick = slot_number_to_tick(state.slot_duration_millis, block_entry.slot());
+
+		for (_, candidate_hash) in block_entry.candidates() {
+			if block_entry.is_candidate_approved(candidate_hash) ||
+				block_entry.is_candidate_pending_signature(candidate_hash)
+			{
+				continue
+			}
+
+			let candidate_entry = match db.load_candidate_entry(candidate_hash)? {
+				Some(c) => c,
+				None => continue,
+			};
+			let approval_entry = match candidate_entry.approval_entry(&block_hash) {
+				Some(a) => a,
+				None => continue,
+			};
+			let assignment = match approval_entry.local_statements() {
+				(Some(assignment), None) => assignment,
+				_ => continue,
+			};
+
+			if !assignment.triggered() {
+				actions.push(Action::ScheduleWakeup {
+					block_hash,
+					block_number: block_entry.block_number(),
+					candidate_hash: *candidate_hash,
+					tick: assignment.tranche() as Tick + block_tick,
+				});
+				continue
+			}
+
+			let claimed_candidate_indices =
+				get_assignment_core_indices(&assignment.cert().kind, candidate_hash, &block_entry)
+					.and_then(|cores| cores_to_candidate_indices(&cores, &block_entry).ok());
+			let claimed_candidate_indices = match claimed_candidate_indices {
+				Some(indices) => indices,
+				None => {
+					gum::warn!(
+						target: LOG_TARGET,
+						?block_hash,
+						?candidate_hash,
+						"Cannot resume approval check, failed to get claimed candidates",
+					);
+					continue
+				},
+			};
+
+			gum::debug!(
+				target: LOG_TARGET,
+				?block_hash,
+				?candidate_hash,
+				"Resuming unfinished approval check",
+			);
+
+			actions.push(Action::LaunchApproval {
+				claimed_candidate_indices,
+				candidate_hash: *candidate_hash,
+				indirect_cert: IndirectAssignmentCertV2 {
+					block_hash,
+					validator: assignment.validator_index(),
+					cert: assignment.cert().clone(),
+				},
+				assignment_tranche: assignment.tranche(),
+				relay_block_hash: block_hash,
+				session: block_entry.session(),
+				executor_params: executor_params.clone(),
+				candidate: candidate_entry.candidate_receipt().clone(),
+				backing_group: approval_entry.backing_group(),
+				distribute_assignment: false,
+			});
+		}
+	}
+
+	Ok(actions)
+}
+
 // Handle an incoming signal from the overseer. Returns true if execution should conclude.
 #[overseer::contextbounds(ApprovalVoting, prefix = self::overseer)]
 async fn handle_from_overseer<Context>(
@@ -588,6 +588,13 @@ impl BlockEntry {
 		!self.candidates_pending_signature.is_empty()
 	}
 
+	/// Whether we checked the candidate, but didn't sign and advertise our approval yet.
+	pub fn is_candidate_pending_signature(&self, candidate_hash: &CandidateHash) -> bool {
+		self.candidates_pending_signature
+			.values()
+			.any(|signing_context| &signing_context.candidate_hash == candidate_hash)
+	}
+
 	/// Candidate hashes  for candidates pending signatures
 	fn candidate_hashes_pending_signature(&self) -> Vec<CandidateHash> {
 		self.candidates_pending_signature
@@ -476,6 +476,11 @@ impl HarnessConfigBuilder {
 		self
 	}
 
+	pub fn backend(&mut self, backend: TestStore) -> &mut Self {
+		self.backend = Some(backend);
+		self
+	}
+
 	pub fn build(&mut self) -> HarnessConfig {
 		let (sync_oracle, sync_oracle_handle) =
 			self.sync_oracle.take().unwrap_or_else(|| make_sync_oracle(false));
@@ -4040,3 +4045,293 @@ fn test_approval_is_sent_on_max_approval_coalesce_wait() {
 		virtual_overseer
 	});
 }
+
+fn single_assignment_criteria() -> Box<dyn AssignmentCriteria + Send + Sync + 'static> {
+	Box::new(MockAssignmentCriteria(
+		|| {
+			let mut assignments = HashMap::new();
+			let _ = assignments.insert(
+				CoreIndex(0),
+				approval_db::v2::OurAssignment {
+					cert: garbage_assignment_cert(AssignmentCertKind::RelayVRFModulo { sample: 0 })
+						.into(),
+					tranche: 0,
+					validator_index: ValidatorIndex(0),
+					triggered: false,
+				}
+				.into(),
+			);
+			assignments
+		},
+		|_| Ok(0),
+	))
+}
+
+fn restart_session_info() -> SessionInfo {
+	let validators = vec![
+		Sr25519Keyring::Alice,
+		Sr25519Keyring::Bob,
+		Sr25519Keyring::Charlie,
+		Sr25519Keyring::Dave,
+		Sr25519Keyring::Eve,
+	];
+	SessionInfo {
+		validator_groups: IndexedVec::<GroupIndex, Vec<ValidatorIndex>>::from(vec![
+			vec![ValidatorIndex(0), ValidatorIndex(1)],
+			vec![ValidatorIndex(2)],
+			vec![ValidatorIndex(3), ValidatorIndex(4)],
+		]),
+		..session_info(&validators)
+	}
+}
+
+// Imports a block with a single candidate we are assigned to and stops the subsystem afterwards.
+// If `trigger_assignment` is set, the subsystem is stopped while our approval check is in flight,
+// otherwise before our assignment is triggered.
+fn run_until_crash(
+	store: TestStore,
+	block_hash: Hash,
+	candidate_receipt: CandidateReceipt,
+	slot: Slot,
+	trigger_assignment: bool,
+) {
+	let config = HarnessConfigBuilder::default()
+		.assignment_criteria(single_assignment_criteria())
+		.backend(store)
+		.build();
+
+	test_harness(config, |test_harness| async move {
+		let TestHarness { mut virtual_overseer, clock, sync_oracle_handle: _sync_oracle_handle } =
+			test_harness;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::ChainApi(ChainApiMessage::FinalizedBlockNumber(rx)) => {
+				rx.send(Ok(0)).unwrap();
+			}
+		);
+
+		ChainBuilder::new()
+			.add_block(
+				block_hash,
+				ChainBuilder::GENESIS_HASH,
+				1,
+				BlockConfig {
+					slot,
+					candidates: Some(vec![(candidate_receipt, CoreIndex(0), GroupIndex(0))]),
+					session_info: Some(restart_session_info()),
+				},
+			)
+			.build(&mut virtual_overseer)
+			.await;
+
+		if trigger_assignment {
+			futures_timer::Delay::new(Duration::from_millis(100)).await;
+			assert!(clock.inner.lock().current_wakeup_is(slot_to_tick(slot)));
+			clock.inner.lock().wakeup_all(slot_to_tick(slot));
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::ApprovalDistribution(
+					ApprovalDistributionMessage::DistributeAssignment(..)
+				)
+			);
+
+			// The node goes down while the data for our check is being recovered.
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::AvailabilityRecovery(
+					AvailabilityRecoveryMessage::RecoverAvailableData(..)
+				)
+			);
+		}
+
+		virtual_overseer
+	});
+}
+
+async fn restart_until_resumed(virtual_overseer: &mut VirtualOverseer) {
+	assert_matches!(
+		overseer_recv(virtual_overseer).await,
+		AllMessages::ChainApi(ChainApiMessage::FinalizedBlockNumber(rx)) => {
+			rx.send(Ok(0)).unwrap();
+		}
+	);
+
+	// The first message after start-up makes the subsystem notice that syncing is done.
+	overseer_signal(
+		virtual_overseer,
+		OverseerSignal::BlockFinalized(ChainBuilder::GENESIS_HASH, 0),
+	)
+	.await;
+
+	assert_matches!(
+		overseer_recv(virtual_overseer).await,
+		AllMessages::ApprovalDistribution(ApprovalDistributionMessage::NewBlocks(blocks)) => {
+			assert_eq!(blocks.len(), 1);
+		}
+	);
+}
+
+async fn answer_session_info_requests(
+	virtual_overseer: &mut VirtualOverseer,
+	session_info: SessionInfo,
+) {
+	assert_matches!(
+		overseer_recv(virtual_overseer).await,
+		AllMessages::RuntimeApi(
+			RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionInfo(_, si_tx))
+		) => {
+			si_tx.send(Ok(Some(session_info))).unwrap();
+		}
+	);
+	assert_matches!(
+		overseer_recv(virtual_overseer).await,
+		AllMessages::RuntimeApi(
+			RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionExecutorParams(_, si_tx))
+		) => {
+			si_tx.send(Ok(Some(ExecutorParams::default()))).unwrap();
+		}
+	);
+	assert_matches!(
+		overseer_recv(virtual_overseer).await,
+		AllMessages::RuntimeApi(
+			RuntimeApiMessage::Request(_, RuntimeApiRequest::NodeFeatures(_, si_tx))
+		) => {
+			si_tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+		}
+	);
+}
+
+#[test]
+fn approval_check_in_flight_is_resumed_after_restart() {
+	let store = TestStore::default();
+	let block_hash = Hash::repeat_byte(0x01);
+	let mut candidate_receipt = dummy_candidate_receipt(block_hash);
+	candidate_receipt.commitments_hash = CandidateCommitments::default().hash();
+	let candidate_hash = candidate_receipt.hash();
+	let candidate_index: CandidateIndex = 0;
+	let slot = Slot::from(1);
+
+	run_until_crash(store.clone(), block_hash, candidate_receipt, slot, true);
+
+	// The check was started, but never finished.
+	let candidate_entry = store.load_candidate_entry(&candidate_hash).unwrap().unwrap();
+	let (our_assignment, our_approval) =
+		candidate_entry.approval_entry(&block_hash).unwrap().local_statements();
+	assert!(our_assignment.is_some());
+	assert!(our_approval.is_none());
+
+	let config = HarnessConfigBuilder::default().backend(store.clone()).build();
+	test_harness(config, |test_harness| async move {
+		let TestHarness { mut virtual_overseer, sync_oracle_handle: _sync_oracle_handle, .. } =
+			test_harness;
+
+		restart_until_resumed(&mut virtual_overseer).await;
+
+		// Our assignment is distributed again on activation.
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::ApprovalDistribution(ApprovalDistributionMessage::DistributeAssignment(
+				cert,
+				c_indices,
+			)) => {
+				assert_eq!(cert.block_hash, block_hash);
+				assert_eq!(Into::<CandidateBitfield>::into(candidate_index), c_indices);
+			}
+		);
+
+		answer_session_info_requests(&mut virtual_overseer, restart_session_info()).await;
+
+		// The check is launched again, without distributing the assignment a second time.
+		recover_available_data(&mut virtual_overseer).await;
+		fetch_validation_code(&mut virtual_overseer).await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::CandidateValidation(CandidateValidationMessage::ValidateFromExhaustive {
+				exec_kind,
+				response_sender,
+				..
+			}) if exec_kind == PvfExecKind::Approval => {
+				response_sender.send(Ok(ValidationResult::Valid(Default::default(), Default::default())))
+					.unwrap();
+			}
+		);
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::ApprovalVotingParams(_, sender))) => {
+				let _ = sender.send(Ok(ApprovalVotingParams {
+					max_approval_coalesce_count: 1,
+				}));
+			}
+		);
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::ApprovalDistribution(ApprovalDistributionMessage::DistributeApproval(
+				approval,
+			)) => {
+				assert_eq!(approval.block_hash, block_hash);
+			}
+		);
+
+		virtual_overseer
+	});
+
+	let candidate_entry = store.load_candidate_entry(&candidate_hash).unwrap().unwrap();
+	let (_, our_approval) = candidate_entry.approval_entry(&block_hash).unwrap().local_statements();
+	assert!(our_approval.is_some());
+}
+
+#[test]
+fn untriggered_assignment_is_rescheduled_after_restart() {
+	let store = TestStore::default();
+	let block_hash = Hash::repeat_byte(0x01);
+	let candidate_receipt = dummy_candidate_receipt(block_hash);
+	let candidate_hash = candidate_receipt.hash();
+	let slot = Slot::from(1);
+
+	run_until_crash(store.clone(), block_hash, candidate_receipt, slot, false);
+
+	let candidate_entry = store.load_candidate_entry(&candidate_hash).unwrap().unwrap();
+	let our_assignment =
+		candidate_entry.approval_entry(&block_hash).unwrap().our_assignment().unwrap();
+	assert!(!our_assignment.triggered());
+
+	let config = HarnessConfigBuilder::default().backend(store.clone()).build();
+	test_harness(config, |test_harness| async move {
+		let TestHarness { mut virtual_overseer, clock, sync_oracle_handle: _sync_oracle_handle } =
+			test_harness;
+
+		restart_until_resumed(&mut virtual_overseer).await;
+		answer_session_info_requests(&mut virtual_overseer, restart_session_info()).await;
+
+		// The wakeup for our assignment is scheduled again.
+		futures_timer::Delay::new(Duration::from_millis(100)).await;
+		assert!(clock.inner.lock().current_wakeup_is(slot_to_tick(slot)));
+		clock.inner.lock().wakeup_all(slot_to_tick(slot));
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::ApprovalDistribution(ApprovalDistributionMessage::DistributeAssignment(
+				cert,
+				_,
+			)) => {
+				assert_eq!(cert.block_hash, block_hash);
+			}
+		);
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::AvailabilityRecovery(AvailabilityRecoveryMessage::RecoverAvailableData(..))
+		);
+
+		virtual_overseer
+	});
+
+	let candidate_entry = store.load_candidate_entry(&candidate_hash).unwrap().unwrap();
+	let our_assignment =
+		candidate_entry.approval_entry(&block_hash).unwrap().our_assignment().unwrap();
+	assert!(our_assignment.triggered());
+}
@@ -28,6 +28,7 @@ use sp_consensus_slots::Slot;
 use std::{
 	collections::HashSet,
 	pin::Pin,
+	sync::Arc,
 	task::Poll,
 	time::{Duration, SystemTime},
 };
@@ -36,11 +37,11 @@ use polkadot_primitives::{Hash, ValidatorIndex};
 const TICK_DURATION_MILLIS: u64 = 500;
 
 /// A base unit of time, starting from the Unix epoch, split into half-second intervals.
-pub(crate) type Tick = u64;
+pub type Tick = u64;
 
 /// A clock which allows querying of the current tick as well as
 /// waiting for a tick to be reached.
-pub(crate) trait Clock {
+pub trait Clock {
 	/// Yields the current tick.
 	fn tick_now(&self) -> Tick;
 
@@ -61,7 +62,7 @@ impl<C: Clock + ?Sized> ClockExt for C {
 }
 
 /// A clock which uses the actual underlying system clock.
-pub(crate) struct SystemClock;
+pub struct SystemClock;
 
 impl Clock for SystemClock {
 	/// Yields the current tick.
@@ -88,12 +89,22 @@ impl Clock for SystemClock {
 	}
 }
 
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+	fn tick_now(&self) -> Tick {
+		(**self).tick_now()
+	}
+
+	fn wait(&self, tick: Tick) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> {
+		(**self).wait(tick)
+	}
+}
+
 fn tick_to_time(tick: Tick) -> SystemTime {
 	SystemTime::UNIX_EPOCH + Duration::from_millis(TICK_DURATION_MILLIS * tick)
 }
 
 /// assumes `slot_duration_millis` evenly divided by tick duration.
-pub(crate) fn slot_number_to_tick(slot_duration_millis: u64, slot: Slot) -> Tick {
+pub fn slot_number_to_tick(slot_duration_millis: u64, slot: Slot) -> Tick {
 	let ticks_per_slot = slot_duration_millis / TICK_DURATION_MILLIS;
 	u64::from(slot) * ticks_per_slot
 }
@@ -66,6 +66,8 @@ const CHUNK_PREFIX: &[u8; 5] = b"chunk";
 const META_PREFIX: &[u8; 4] = b"meta";
 const UNFINALIZED_PREFIX: &[u8; 11] = b"unfinalized";
 const PRUNE_BY_TIME_PREFIX: &[u8; 13] = b"prune_by_time";
+const OWN_CHUNK_PREFIX: &[u8; 9] = b"own_chunk";
+const SIZE_PREFIX: &[u8; 4] = b"size";
 
 // We have some keys we want to map to empty values because existence of the key is enough. We use
 // this because rocksdb doesn't support empty values.
@@ -159,6 +161,45 @@ struct CandidateMeta {
 	chunks_stored: BitVec<u8, BitOrderLsb0>,
 }
 
+// Sizes of the values stored for a candidate, used to keep the store within its size limit.
+#[derive(Debug, Default, Clone, Copy, Encode, Decode)]
+struct CandidateSize {
+	/// The size of the encoded available data.
+	available_data: u32,
+	/// The size of a single encoded erasure chunk.
+	chunk: u32,
+}
+
+/// The size of the values kept in the store, per category.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct StoreSize {
+	available_data: u64,
+	chunks: u64,
+}
+
+impl StoreSize {
+	fn of_candidate(meta: &CandidateMeta, size: &CandidateSize) -> Self {
+		StoreSize {
+			available_data: if meta.data_available { size.available_data as u64 } else { 0 },
+			chunks: meta.chunks_stored.count_ones() as u64 * size.chunk as u64,
+		}
+	}
+
+	fn add(&mut self, other: StoreSize) {
+		self.available_data = self.available_data.saturating_add(other.available_data);
+		self.chunks = self.chunks.saturating_add(other.chunks);
+	}
+
+	fn sub(&mut self, other: StoreSize) {
+		self.available_data = self.available_data.saturating_sub(other.available_data);
+		self.chunks = self.chunks.saturating_sub(other.chunks);
+	}
+
+	fn total(&self) -> u64 {
+		self.available_data.saturating_add(self.chunks)
+	}
+}
+
 fn query_inner<D: Decode>(
 	db: &Arc<dyn Database>,
 	column: u32,
@@ -188,7 +229,11 @@ fn write_available_data(
 	tx.put_vec(config.col_data, &key[..], available_data.encode());
 }
 
-fn load_available_data(
+/// Load the full available data of a candidate straight from the database.
+///
+/// This is meant for offline tooling which has access to the parachains DB but not to a running
+/// availability store subsystem.
+pub fn load_available_data(
 	db: &Arc<dyn Database>,
 	config: &Config,
 	hash: &CandidateHash,
@@ -259,6 +304,96 @@ fn delete_meta(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash) {
 	tx.delete(config.col_meta, &key[..])
 }
 
+fn load_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	hash: &CandidateHash,
+) -> Result<Option<CandidateSize>, Error> {
+	let key = (SIZE_PREFIX, hash).encode();
+
+	query_inner(db, config.col_meta, &key)
+}
+
+fn write_size(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash, size: &CandidateSize) {
+	let key = (SIZE_PREFIX, hash).encode();
+
+	tx.put_vec(config.col_meta, &key, size.encode());
+}
+
+fn delete_size(tx: &mut DBTransaction, config: &Config, hash: &CandidateHash) {
+	let key = (SIZE_PREFIX, hash).encode();
+	tx.delete(config.col_meta, &key[..])
+}
+
+// Measure the sizes of the values stored for a candidate from the values themselves.
+//
+// Candidates stored before sizes were tracked have no size entry, so this is used to fill it in.
+fn measure_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	hash: &CandidateHash,
+	meta: &CandidateMeta,
+) -> Result<CandidateSize, Error> {
+	let raw_len = |key: Vec<u8>| -> Result<u32, Error> {
+		Ok(db.get(config.col_data, &key)?.map_or(0, |raw| raw.len() as u32))
+	};
+
+	let available_data =
+		if meta.data_available { raw_len((AVAILABLE_PREFIX, hash).encode())? } else { 0 };
+	let chunk = match meta.chunks_stored.first_one() {
+		Some(i) => raw_len((CHUNK_PREFIX, hash, ValidatorIndex(i as _)).encode())?,
+		None => 0,
+	};
+
+	Ok(CandidateSize { available_data, chunk })
+}
+
+// Load the sizes of the values stored for a candidate, measuring them if there is no size entry.
+fn load_or_measure_size(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	hash: &CandidateHash,
+	meta: &CandidateMeta,
+) -> Result<CandidateSize, Error> {
+	match load_size(db, config, hash)? {
+		Some(size) => Ok(size),
+		None => measure_size(db, config, hash, meta),
+	}
+}
+
+// Chunks stored one by one are the ones availability distribution fetched for ourselves. We keep
+// track of them, so they are never dropped when compacting the store.
+fn write_own_chunk(
+	tx: &mut DBTransaction,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+	chunk_index: ValidatorIndex,
+) {
+	let key = (OWN_CHUNK_PREFIX, candidate_hash, chunk_index).encode();
+	tx.put(config.col_meta, &key, TOMBSTONE_VALUE);
+}
+
+fn load_own_chunks(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+) -> Result<HashSet<ValidatorIndex>, Error> {
+	let prefix = (OWN_CHUNK_PREFIX, candidate_hash).encode();
+
+	let mut own_chunks = HashSet::new();
+	for r in db.iter_with_prefix(config.col_meta, &prefix) {
+		let (k, _v) = r?;
+		own_chunks.insert(ValidatorIndex::decode(&mut &k[prefix.len()..])?);
+	}
+
+	Ok(own_chunks)
+}
+
+fn delete_own_chunks(tx: &mut DBTransaction, config: &Config, candidate_hash: &CandidateHash) {
+	let prefix = (OWN_CHUNK_PREFIX, candidate_hash).encode();
+	tx.delete_prefix(config.col_meta, &prefix);
+}
+
 fn delete_unfinalized_height(tx: &mut DBTransaction, config: &Config, block_number: BlockNumber) {
 	let prefix = (UNFINALIZED_PREFIX, BEBlockNumber(block_number)).encode();
 	tx.delete_prefix(config.col_meta, &prefix);
@@ -439,6 +574,12 @@ pub struct Config {
 	pub col_data: u32,
 	/// The column family for availability store meta information.
 	pub col_meta: u32,
+	/// The maximum size in bytes of the available data and chunks kept in the store.
+	///
+	/// Once exceeded, the store is compacted in the background: the full available data of the
+	/// oldest candidates is dropped first, then their chunks which aren't our own. Our own chunks
+	/// are only removed by time based pruning. `None` disables the size limit.
+	pub max_size: Option<u64>,
 }
 
 trait Clock: Send + Sync {
@@ -669,7 +810,8 @@ async fn start_prune_all<Context>(
 			let _timer = metrics.time_pruning();
 
 			gum::debug!(target: LOG_TARGET, "Prunning started");
-			let result = prune_all(&db, &config, time_now);
+			let result = prune_all(&db, &config, time_now)
+				.and_then(|()| prune_by_size(&db, &config, &metrics));
 
 			if let Err(err) = pruning_result_tx.send(result).await {
 				// This usually means that the node is closing down, log it just in case
@@ -1245,10 +1387,14 @@ fn store_chunk(
 	match meta.chunks_stored.get(chunk.index.0 as usize).map(|b| *b) {
 		Some(true) => return Ok(true), // already stored.
 		Some(false) => {
+			let mut size = load_or_measure_size(db, config, &candidate_hash, &meta)?;
 			meta.chunks_stored.set(chunk.index.0 as usize, true);
+			size.chunk = chunk.encoded_size() as u32;
 
 			write_chunk(&mut tx, config, &candidate_hash, chunk.index, &chunk);
+			write_own_chunk(&mut tx, config, &candidate_hash, chunk.index);
 			write_meta(&mut tx, config, &candidate_hash, &meta);
+			write_size(&mut tx, config, &candidate_hash, &size);
 		},
 		None => return Ok(false), // out of bounds.
 	}
@@ -1320,7 +1466,9 @@ fn store_available_data(
 		},
 	);
 
+	let mut size = CandidateSize { available_data: available_data.encoded_size() as u32, chunk: 0 };
 	for chunk in erasure_chunks {
+		size.chunk = chunk.encoded_size() as u32;
 		write_chunk(&mut tx, &subsystem.config, &candidate_hash, chunk.index, &chunk);
 	}
 
@@ -1328,6 +1476,7 @@ fn store_available_data(
 	meta.chunks_stored = bitvec::bitvec![u8, BitOrderLsb0; 1; n_validators];
 
 	write_meta(&mut tx, &subsystem.config, &candidate_hash, &meta);
+	write_size(&mut tx, &subsystem.config, &candidate_hash, &size);
 	write_available_data(&mut tx, &subsystem.config, &candidate_hash, &available_data);
 
 	subsystem.db.write(tx)?;
@@ -1355,6 +1504,8 @@ fn prune_all(db: &Arc<dyn Database>, config: &Config, now: Duration) -> Result<(
 		};
 
 		delete_meta(&mut tx, config, &candidate_hash);
+		delete_size(&mut tx, config, &candidate_hash);
+		delete_own_chunks(&mut tx, config, &candidate_hash);
 
 		// Clean up all attached data of the candidate.
 		if let Some(meta) = load_meta(db, config, &candidate_hash)? {
@@ -1389,3 +1540,130 @@ fn prune_all(db: &Arc<dyn Database>, config: &Config, now: Duration) -> Result<(
 	db.write(tx)?;
 	Ok(())
 }
+
+// Measure the size of the store and compact it if it exceeds `Config::max_size`.
+fn prune_by_size(db: &Arc<dyn Database>, config: &Config, metrics: &Metrics) -> Result<(), Error> {
+	let mut store_size = StoreSize::default();
+	// Candidates to compact, with whether they are finalized and the time they were first
+	// observed, or finalized at.
+	let mut candidates = Vec::new();
+	// Sizes of candidates stored before sizes were tracked.
+	let mut tx = DBTransaction::new();
+
+	for r in db.iter_with_prefix(config.col_meta, &META_PREFIX[..]) {
+		let (k, v) = r?;
+		let candidate_hash = CandidateHash::decode(&mut &k[META_PREFIX.len()..])?;
+		let meta = CandidateMeta::decode(&mut &v[..])?;
+		let size = match load_size(db, config, &candidate_hash)? {
+			Some(size) => size,
+			None => {
+				let size = measure_size(db, config, &candidate_hash, &meta)?;
+				write_size(&mut tx, config, &candidate_hash, &size);
+				size
+			},
+		};
+
+		store_size.add(StoreSize::of_candidate(&meta, &size));
+
+		if config.max_size.is_some() {
+			let (finalized, at) = match meta.state {
+				State::Unavailable(at) | State::Unfinalized(at, _) => (false, at),
+				State::Finalized(at) => (true, at),
+			};
+			candidates.push((!finalized, at, candidate_hash));
+		}
+	}
+
+	db.write(tx)?;
+
+	if let Some(max_size) = config.max_size.filter(|max_size| store_size.total() > *max_size) {
+		// Finalized candidates are compacted first, the oldest ones first.
+		candidates.sort();
+		let candidates: Vec<_> =
+			candidates.into_iter().map(|(_, _, candidate_hash)| candidate_hash).collect();
+
+		let compacted = compact(db, config, &candidates, store_size.total() - max_size)?;
+		metrics.on_compacted(compacted.available_data, compacted.chunks);
+		store_size.sub(compacted);
+
+		if store_size.total() > max_size {
+			gum::warn!(
+				target: LOG_TARGET,
+				?store_size,
+				max_size,
+				"Availability store exceeds its maximum size, only our own chunks are left",
+			);
+		}
+	}
+
+	metrics.on_store_size(store_size.available_data, store_size.chunks);
+
+	Ok(())
+}
+
+// Drop stored values of the given candidates, in order, until at least `excess` bytes have been
+// freed.
+//
+// The full available data is dropped first, as it can be recovered from the chunks. Then the
+// chunks which aren't our own are dropped, but only for candidates we know our own chunk of.
+fn compact(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidates: &[CandidateHash],
+	excess: u64,
+) -> Result<StoreSize, Error> {
+	let mut compacted = StoreSize::default();
+
+	let mut tx = DBTransaction::new();
+	for candidate_hash in candidates {
+		if compacted.total() >= excess {
+			break
+		}
+
+		let mut meta = match load_meta(db, config, candidate_hash)? {
+			Some(meta) if meta.data_available => meta,
+			_ => continue,
+		};
+		let size = load_or_measure_size(db, config, candidate_hash, &meta)?;
+
+		delete_available_data(&mut tx, config, candidate_hash);
+		meta.data_available = false;
+		write_meta(&mut tx, config, candidate_hash, &meta);
+
+		compacted.available_data += size.available_data as u64;
+	}
+	// The metas are loaded again below, so the transaction needs to be written first.
+	db.write(tx)?;
+
+	let mut tx = DBTransaction::new();
+	for candidate_hash in candidates {
+		if compacted.total() >= excess {
+			break
+		}
+
+		let own_chunks = load_own_chunks(db, config, candidate_hash)?;
+		if own_chunks.is_empty() {
+			continue
+		}
+
+		let mut meta = match load_meta(db, config, candidate_hash)? {
+			Some(meta) => meta,
+			None => continue,
+		};
+		let size = load_or_measure_size(db, config, candidate_hash, &meta)?;
+
+		for (i, mut stored) in meta.chunks_stored.iter_mut().enumerate() {
+			let chunk_index = ValidatorIndex(i as _);
+			if *stored && !own_chunks.contains(&chunk_index) {
+				delete_chunk(&mut tx, config, candidate_hash, chunk_index);
+				*stored = false;
+
+				compacted.chunks += size.chunk as u64;
+			}
+		}
+		write_meta(&mut tx, config, candidate_hash, &meta);
+	}
+	db.write(tx)?;
+
+	Ok(compacted)
+}
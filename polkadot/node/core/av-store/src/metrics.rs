@@ -26,6 +26,8 @@ pub(crate) struct MetricsInner {
 	store_available_data: prometheus::Histogram,
 	store_chunk: prometheus::Histogram,
 	get_chunk: prometheus::Histogram,
+	store_size: prometheus::GaugeVec<prometheus::U64>,
+	compacted_bytes: prometheus::CounterVec<prometheus::U64>,
 }
 
 /// Availability metrics.
@@ -85,6 +87,25 @@ impl Metrics {
 	pub(crate) fn time_get_chunk(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.get_chunk.start_timer())
 	}
+
+	/// Set the size of the available data and chunks kept in the store.
+	pub(crate) fn on_store_size(&self, available_data: u64, chunks: u64) {
+		if let Some(metrics) = &self.0 {
+			metrics.store_size.with_label_values(&["available_data"]).set(available_data);
+			metrics.store_size.with_label_values(&["chunks"]).set(chunks);
+		}
+	}
+
+	/// Note available data and chunks dropped to keep the store within its size limit.
+	pub(crate) fn on_compacted(&self, available_data: u64, chunks: u64) {
+		if let Some(metrics) = &self.0 {
+			metrics
+				.compacted_bytes
+				.with_label_values(&["available_data"])
+				.inc_by(available_data);
+			metrics.compacted_bytes.with_label_values(&["chunks"]).inc_by(chunks);
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -152,6 +173,26 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			store_size: prometheus::register(
+				prometheus::GaugeVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_av_store_size_bytes",
+						"Size of the available data and chunks kept in the availability store.",
+					),
+					&["category"],
+				)?,
+				registry,
+			)?,
+			compacted_bytes: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_av_store_compacted_bytes_total",
+						"Bytes of available data and chunks dropped to keep the availability store within its size limit.",
+					),
+					&["category"],
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
@@ -42,7 +42,8 @@ mod columns {
 	pub const NUM_COLUMNS: u32 = 2;
 }
 
-const TEST_CONFIG: Config = Config { col_data: columns::DATA, col_meta: columns::META };
+const TEST_CONFIG: Config =
+	Config { col_data: columns::DATA, col_meta: columns::META, max_size: None };
 
 type VirtualOverseer = test_helpers::TestSubsystemContextHandle<AvailabilityStoreMessage>;
 
@@ -70,6 +71,7 @@ impl Clock for TestClock {
 #[derive(Clone)]
 struct TestState {
 	persisted_validation_data: PersistedValidationData,
+	config: Config,
 	pruning_config: PruningConfig,
 	clock: TestClock,
 }
@@ -99,7 +101,7 @@ impl Default for TestState {
 
 		let clock = TestClock { inner: Arc::new(Mutex::new(Duration::from_secs(0))) };
 
-		Self { persisted_validation_data, pruning_config, clock }
+		Self { persisted_validation_data, config: TEST_CONFIG, pruning_config, clock }
 	}
 }
 
@@ -131,7 +133,7 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 
 	let subsystem = AvailabilityStoreSubsystem::with_pruning_config_and_clock(
 		store,
-		TEST_CONFIG,
+		state.config,
 		state.pruning_config.clone(),
 		Box::new(state.clock),
 		Box::new(NoSyncOracle),
@@ -690,6 +692,181 @@ fn stored_but_not_included_data_is_pruned() {
 	});
 }
 
+#[test]
+fn store_is_compacted_when_exceeding_max_size() {
+	let store = test_store();
+	let test_state =
+		TestState { config: Config { max_size: Some(1), ..TEST_CONFIG }, ..Default::default() };
+
+	test_harness(test_state.clone(), store.clone(), |mut virtual_overseer| async move {
+		let n_validators = 10;
+		let own_chunk_index = ValidatorIndex(3);
+
+		let pov = PoV { block_data: BlockData(vec![4, 5, 6]) };
+		let available_data = AvailableData {
+			pov: Arc::new(pov),
+			validation_data: test_state.persisted_validation_data.clone(),
+		};
+		let chunks = erasure::obtain_chunks_v1(n_validators as _, &available_data).unwrap();
+		let branches = erasure::branches(chunks.as_ref());
+		let expected_erasure_root = branches.root();
+		let own_chunk = branches
+			.map(|(proof, chunk)| ErasureChunk {
+				chunk: chunk.to_vec(),
+				proof,
+				index: own_chunk_index,
+			})
+			.nth(own_chunk_index.0 as usize)
+			.unwrap();
+
+		// One candidate we know our own chunk of, and one we don't.
+		let with_own_chunk = CandidateHash(Hash::repeat_byte(1));
+		let without_own_chunk = CandidateHash(Hash::repeat_byte(2));
+
+		with_tx(&store, |tx| {
+			super::write_meta(
+				tx,
+				&TEST_CONFIG,
+				&with_own_chunk,
+				&CandidateMeta {
+					data_available: false,
+					chunks_stored: bitvec::bitvec![u8, BitOrderLsb0; 0; n_validators as _],
+					state: State::Unavailable(BETimestamp(0)),
+				},
+			);
+		});
+
+		let (tx, rx) = oneshot::channel();
+		let chunk_msg = AvailabilityStoreMessage::StoreChunk {
+			candidate_hash: with_own_chunk,
+			chunk: own_chunk.clone(),
+			tx,
+		};
+		overseer_send(&mut virtual_overseer, chunk_msg).await;
+		assert_eq!(rx.await.unwrap(), Ok(()));
+
+		for candidate_hash in [with_own_chunk, without_own_chunk] {
+			let (tx, rx) = oneshot::channel();
+			let block_msg = AvailabilityStoreMessage::StoreAvailableData {
+				candidate_hash,
+				n_validators,
+				available_data: available_data.clone(),
+				tx,
+				expected_erasure_root,
+			};
+			overseer_send(&mut virtual_overseer, block_msg).await;
+			rx.await.unwrap().unwrap();
+		}
+
+		test_state.wait_for_pruning().await;
+
+		// The full data is dropped first.
+		assert!(query_available_data(&mut virtual_overseer, with_own_chunk).await.is_none());
+		assert!(query_available_data(&mut virtual_overseer, without_own_chunk).await.is_none());
+
+		// Only our own chunk is kept.
+		assert_eq!(
+			query_chunk(&mut virtual_overseer, with_own_chunk, own_chunk_index).await,
+			Some(own_chunk),
+		);
+		for i in (0..n_validators).filter(|i| *i != own_chunk_index.0) {
+			assert!(query_chunk(&mut virtual_overseer, with_own_chunk, ValidatorIndex(i))
+				.await
+				.is_none());
+		}
+
+		// Chunks of candidates we don't know our own chunk of are kept.
+		assert!(has_all_chunks(&mut virtual_overseer, without_own_chunk, n_validators, true).await);
+		virtual_overseer
+	});
+}
+
+#[test]
+fn size_of_candidates_without_a_size_entry_is_measured() {
+	let store = test_store();
+
+	let available_data = AvailableData {
+		pov: Arc::new(PoV { block_data: BlockData(vec![4, 5, 6]) }),
+		validation_data: TestState::default().persisted_validation_data,
+	};
+	let data_size = available_data.encoded_size() as u64;
+
+	// Candidates stored before sizes were tracked have no size entry.
+	let older = CandidateHash(Hash::repeat_byte(1));
+	let newer = CandidateHash(Hash::repeat_byte(2));
+	with_tx(&store, |tx| {
+		for (at, candidate_hash) in [(0, older), (1, newer)] {
+			super::write_available_data(tx, &TEST_CONFIG, &candidate_hash, &available_data);
+			super::write_meta(
+				tx,
+				&TEST_CONFIG,
+				&candidate_hash,
+				&CandidateMeta {
+					data_available: true,
+					chunks_stored: bitvec::bitvec![u8, BitOrderLsb0; 0; 10],
+					state: State::Unavailable(BETimestamp(at)),
+				},
+			);
+		}
+	});
+
+	// Only one of the candidates needs to be compacted to fit.
+	let config = Config { max_size: Some(2 * data_size - 1), ..TEST_CONFIG };
+	prune_by_size(&store, &config, &Metrics::default()).unwrap();
+
+	assert!(super::load_available_data(&store, &config, &older).unwrap().is_none());
+	assert_eq!(super::load_available_data(&store, &config, &newer).unwrap(), Some(available_data));
+
+	// The measured size is written for later use.
+	let size = super::load_size(&store, &config, &newer).unwrap().unwrap();
+	assert_eq!(size.available_data as u64, data_size);
+	assert_eq!(size.chunk, 0);
+}
+
+#[test]
+fn finalized_candidates_are_compacted_first() {
+	let store = test_store();
+
+	let available_data = AvailableData {
+		pov: Arc::new(PoV { block_data: BlockData(vec![4, 5, 6]) }),
+		validation_data: TestState::default().persisted_validation_data,
+	};
+	let data_size = available_data.encoded_size() as u64;
+	let size = CandidateSize { available_data: data_size as u32, chunk: 0 };
+
+	let unfinalized = CandidateHash(Hash::repeat_byte(1));
+	let finalized = CandidateHash(Hash::repeat_byte(2));
+	with_tx(&store, |tx| {
+		for (state, candidate_hash) in [
+			(State::Unavailable(BETimestamp(0)), unfinalized),
+			(State::Finalized(BETimestamp(10)), finalized),
+		] {
+			super::write_available_data(tx, &TEST_CONFIG, &candidate_hash, &available_data);
+			super::write_size(tx, &TEST_CONFIG, &candidate_hash, &size);
+			super::write_meta(
+				tx,
+				&TEST_CONFIG,
+				&candidate_hash,
+				&CandidateMeta {
+					data_available: true,
+					chunks_stored: bitvec::bitvec![u8, BitOrderLsb0; 0; 10],
+					state,
+				},
+			);
+		}
+	});
+
+	let config = Config { max_size: Some(data_size), ..TEST_CONFIG };
+	prune_by_size(&store, &config, &Metrics::default()).unwrap();
+
+	// The finalized candidate is compacted even though it is the newer one.
+	assert!(super::load_available_data(&store, &config, &finalized).unwrap().is_none());
+	assert_eq!(
+		super::load_available_data(&store, &config, &unfinalized).unwrap(),
+		Some(available_data),
+	);
+}
+
 #[test]
 fn stored_data_kept_until_finalized() {
 	let store = test_store();
@@ -99,11 +99,13 @@ use polkadot_node_subsystem_util::{
 	executor_params_at_relay_parent, request_from_runtime, request_session_index_for_child,
 	request_validator_groups, request_validators,
 	runtime::{
-		self, prospective_parachains_mode, request_min_backing_votes, ProspectiveParachainsMode,
+		self, prospective_parachains_mode, request_min_backing_votes, request_node_features,
+		ProspectiveParachainsMode,
 	},
 	Validator,
 };
 use polkadot_primitives::{
+	vstaging::{node_features::FeatureIndex, NodeFeatures},
 	BackedCandidate, CandidateCommitments, CandidateHash, CandidateReceipt,
 	CommittedCandidateReceipt, CoreIndex, CoreState, ExecutorParams, Hash, Id as ParaId,
 	PersistedValidationData, PvfExecKind, SigningContext, ValidationCode, ValidatorId,
@@ -224,6 +226,9 @@ struct PerRelayParentState {
 	fallbacks: HashMap<CandidateHash, AttestingData>,
 	/// The minimum backing votes threshold.
 	minimum_backing_votes: u32,
+	/// Whether the core index is injected into the backed candidates, i.e. whether the
+	/// `ElasticScalingMVP` node feature is enabled.
+	inject_core_index: bool,
 }
 
 struct PerCandidateState {
@@ -383,6 +388,8 @@ struct AttestingData {
 struct TableContext {
 	validator: Option<Validator>,
 	groups: HashMap<ParaId, Vec<ValidatorIndex>>,
+	/// The core the group of each para in `groups` is assigned to.
+	core_indices: HashMap<ParaId, CoreIndex>,
 	validators: Vec<ValidatorId>,
 	disabled_validators: Vec<ValidatorIndex>,
 }
@@ -448,6 +455,7 @@ fn table_attested_to_backed(
 		ValidatorSignature,
 	>,
 	table_context: &TableContext,
+	inject_core_index: bool,
 ) -> Option<BackedCandidate> {
 	let TableAttestedCandidate { candidate, validity_votes, group_id: para_id } = attested;
 
@@ -479,14 +487,18 @@ fn table_attested_to_backed(
 	}
 	vote_positions.sort_by_key(|(_orig, pos_in_group)| *pos_in_group);
 
-	Some(BackedCandidate {
+	let core_index =
+		if inject_core_index { Some(*table_context.core_indices.get(&para_id)?) } else { None };
+
+	Some(BackedCandidate::new(
 		candidate,
-		validity_votes: vote_positions
+		vote_positions
 			.into_iter()
 			.map(|(pos_in_votes, _pos_in_group)| validity_votes[pos_in_votes].clone())
 			.collect(),
 		validator_indices,
-	})
+		core_index,
+	))
 }
 
 async fn store_available_data(
@@ -1025,6 +1037,13 @@ async fn construct_per_relay_parent_state<Context>(
 	let cores = try_runtime_api!(cores);
 	let minimum_backing_votes =
 		try_runtime_api!(request_min_backing_votes(parent, session_index, ctx.sender()).await);
+	let node_features =
+		try_runtime_api!(request_node_features(parent, session_index, ctx.sender()).await)
+			.unwrap_or(NodeFeatures::EMPTY);
+	let inject_core_index = node_features
+		.get(FeatureIndex::ElasticScalingMVP as usize)
+		.map(|b| *b)
+		.unwrap_or(false);
 
 	// TODO: https://github.com/paritytech/polkadot-sdk/issues/1940
 	// Once runtime ver `DISABLED_VALIDATORS_RUNTIME_REQUIREMENT` is released remove this call to
@@ -1056,6 +1075,7 @@ async fn construct_per_relay_parent_state<Context>(
 	};
 
 	let mut groups = HashMap::new();
+	let mut core_indices = HashMap::new();
 	let n_cores = cores.len();
 	let mut assignment = None;
 
@@ -1076,14 +1096,21 @@ async fn construct_per_relay_parent_state<Context>(
 		let core_index = CoreIndex(idx as _);
 		let group_index = group_rotation_info.group_for_core(core_index, n_cores);
 		if let Some(g) = validator_groups.get(group_index.0 as usize) {
-			if validator.as_ref().map_or(false, |v| g.contains(&v.index())) {
+			let is_our_group = validator.as_ref().map_or(false, |v| g.contains(&v.index()));
+			if is_our_group {
 				assignment = Some(core_para_id);
 			}
-			groups.insert(core_para_id, g.clone());
+			// A para may be assigned to multiple cores. Our own group takes precedence, as
+			// these are the candidates we are going to back.
+			if is_our_group || !groups.contains_key(&core_para_id) {
+				groups.insert(core_para_id, g.clone());
+				core_indices.insert(core_para_id, core_index);
+			}
 		}
 	}
 
-	let table_context = TableContext { validator, groups, validators, disabled_validators };
+	let table_context =
+		TableContext { validator, groups, core_indices, validators, disabled_validators };
 	let table_config = TableConfig {
 		allow_multiple_seconded: match mode {
 			ProspectiveParachainsMode::Enabled { .. } => true,
@@ -1102,6 +1129,7 @@ async fn construct_per_relay_parent_state<Context>(
 		awaiting_validation: HashSet::new(),
 		fallbacks: HashMap::new(),
 		minimum_backing_votes,
+		inject_core_index,
 	}))
 }
 
@@ -1615,7 +1643,11 @@ async fn post_import_statement_actions<Context>(
 
 		// `HashSet::insert` returns true if the thing wasn't in there already.
 		if rp_state.backed.insert(candidate_hash) {
-			if let Some(backed) = table_attested_to_backed(attested, &rp_state.table_context) {
+			if let Some(backed) = table_attested_to_backed(
+				attested,
+				&rp_state.table_context,
+				rp_state.inject_core_index,
+			) {
 				let para_id = backed.candidate.descriptor.para_id;
 				gum::debug!(
 					target: LOG_TARGET,
@@ -2087,7 +2119,13 @@ fn handle_get_backed_candidates_message(
 					&rp_state.table_context,
 					rp_state.minimum_backing_votes,
 				)
-				.and_then(|attested| table_attested_to_backed(attested, &rp_state.table_context))
+				.and_then(|attested| {
+					table_attested_to_backed(
+						attested,
+						&rp_state.table_context,
+						rp_state.inject_core_index,
+					)
+				})
 		})
 		.collect();
 
@@ -296,6 +296,17 @@ async fn test_startup(virtual_overseer: &mut VirtualOverseer, test_state: &TestS
 		}
 	);
 
+	// Check if subsystem job issues a request for the node features.
+	assert_matches!(
+		virtual_overseer.recv().await,
+		AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+			parent,
+			RuntimeApiRequest::NodeFeatures(session_index, tx),
+		)) if parent == test_state.relay_parent && session_index == test_state.signing_context.session_index => {
+			tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+		}
+	);
+
 	// Check that subsystem job issues a request for the runtime version.
 	assert_matches!(
 		virtual_overseer.recv().await,
@@ -1444,6 +1455,7 @@ fn candidate_backing_reorders_votes() {
 		validator: None,
 		disabled_validators: Vec::new(),
 		groups: validator_groups,
+		core_indices: [(para_id, CoreIndex(2))].into_iter().collect(),
 		validators: validator_public.clone(),
 	};
 
@@ -1469,7 +1481,7 @@ fn candidate_backing_reorders_votes() {
 		group_id: para_id,
 	};
 
-	let backed = table_attested_to_backed(attested, &table_context).unwrap();
+	let backed = table_attested_to_backed(attested.clone(), &table_context, false).unwrap();
 
 	let expected_bitvec = {
 		let mut validator_indices = BitVec::<u8, bitvec::order::Lsb0>::with_capacity(6);
@@ -1488,6 +1500,14 @@ fn candidate_backing_reorders_votes() {
 
 	assert_eq!(backed.validator_indices, expected_bitvec);
 	assert_eq!(backed.validity_votes, expected_attestations);
+
+	// With elastic scaling, the core of the group is committed in the validator indices.
+	let backed = table_attested_to_backed(attested, &table_context, true).unwrap();
+	assert_eq!(
+		backed.validator_indices_and_core_index(true),
+		(expected_bitvec.as_bitslice(), Some(CoreIndex(2)))
+	);
+	assert_eq!(backed.validity_votes, expected_attestations);
 }
 
 // Test whether we retry on failed PoV fetching.
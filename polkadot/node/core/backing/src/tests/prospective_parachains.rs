@@ -196,6 +196,17 @@ async fn activate_leaf(
 			}
 		);
 
+		// Check if subsystem job issues a request for the node features.
+		assert_matches!(
+			virtual_overseer.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+				parent,
+				RuntimeApiRequest::NodeFeatures(session_index, tx),
+			)) if parent == hash && session_index == test_state.signing_context.session_index => {
+				tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+			}
+		);
+
 		// Check that subsystem job issues a request for the runtime version.
 		assert_matches!(
 			virtual_overseer.recv().await,
@@ -35,8 +35,12 @@ use polkadot_node_subsystem::{
 	overseer, ActivatedLeaf, FromOrchestra, OverseerSignal, PerLeafSpan, SpawnedSubsystem,
 	SubsystemError, SubsystemResult, SubsystemSender,
 };
-use polkadot_node_subsystem_util::{self as util, Validator};
-use polkadot_primitives::{AvailabilityBitfield, CoreState, Hash, ValidatorIndex};
+use polkadot_node_subsystem_util::{
+	self as util, availability_chunks::availability_chunk_index, Validator,
+};
+use polkadot_primitives::{
+	vstaging::NodeFeatures, AvailabilityBitfield, CoreState, Hash, SessionIndex, ValidatorIndex,
+};
 use sp_keystore::{Error as KeystoreError, KeystorePtr};
 use std::{collections::HashMap, iter::FromIterator, time::Duration};
 use wasm_timer::{Delay, Instant};
@@ -71,21 +75,33 @@ pub enum Error {
 	#[error(transparent)]
 	Runtime(#[from] RuntimeApiError),
 
+	#[error(transparent)]
+	RuntimeInfo(#[from] util::runtime::Error),
+
 	#[error("Keystore failed: {0:?}")]
 	Keystore(KeystoreError),
 }
 
 /// If there is a candidate pending availability, query the Availability Store
-/// for whether we have the availability chunk for our validator index.
+/// for whether we have the availability chunk our validator index is responsible for.
 async fn get_core_availability(
 	core: &CoreState,
 	validator_idx: ValidatorIndex,
+	n_validators: usize,
+	node_features: Option<&NodeFeatures>,
 	sender: &Mutex<&mut impl SubsystemSender<overseer::BitfieldSigningOutgoingMessages>>,
 	span: &jaeger::Span,
 ) -> Result<bool, Error> {
 	if let CoreState::Occupied(core) = core {
 		let _span = span.child("query-chunk-availability");
 
+		let chunk_index = availability_chunk_index(
+			node_features,
+			n_validators,
+			&core.candidate_hash,
+			validator_idx,
+		);
+
 		let (tx, rx) = oneshot::channel();
 		sender
 			.lock()
@@ -93,7 +109,7 @@ async fn get_core_availability(
 			.send_message(
 				AvailabilityStoreMessage::QueryChunkAvailability(
 					core.candidate_hash,
-					validator_idx,
+					chunk_index,
 					tx,
 				)
 				.into(),
@@ -136,6 +152,8 @@ async fn get_availability_cores(
 }
 
 /// - get the list of core states from the runtime
+/// - get the number of validators and the node features, which determine the chunk we are
+///   responsible for
 /// - for each core, concurrently determine chunk availability (see `get_core_availability`)
 /// - return the bitfield if there were no errors at any point in this process (otherwise, it's
 ///   prone to false negatives)
@@ -143,7 +161,8 @@ async fn construct_availability_bitfield(
 	relay_parent: Hash,
 	span: &jaeger::Span,
 	validator_idx: ValidatorIndex,
-	sender: &mut impl SubsystemSender<overseer::BitfieldSigningOutgoingMessages>,
+	session_index: SessionIndex,
+	sender: &mut impl overseer::BitfieldSigningSenderTrait,
 ) -> Result<AvailabilityBitfield, Error> {
 	// get the set of availability cores from the runtime
 	let availability_cores = {
@@ -151,6 +170,10 @@ async fn construct_availability_bitfield(
 		get_availability_cores(relay_parent, sender).await?
 	};
 
+	let n_validators = util::request_validators(relay_parent, sender).await.await??.len();
+	let node_features =
+		util::runtime::request_node_features(relay_parent, session_index, sender).await?;
+
 	// Wrap the sender in a Mutex to share it between the futures.
 	//
 	// We use a `Mutex` here to not `clone` the sender inside the future, because
@@ -160,11 +183,16 @@ async fn construct_availability_bitfield(
 
 	// Handle all cores concurrently
 	// `try_join_all` returns all results in the same order as the input futures.
-	let results = future::try_join_all(
-		availability_cores
-			.iter()
-			.map(|core| get_core_availability(core, validator_idx, &sender, span)),
-	)
+	let results = future::try_join_all(availability_cores.iter().map(|core| {
+		get_core_availability(
+			core,
+			validator_idx,
+			n_validators,
+			node_features.as_ref(),
+			&sender,
+			span,
+		)
+	}))
 	.await?;
 
 	let core_bits = FromIterator::from_iter(results.into_iter());
@@ -283,6 +311,7 @@ where
 		leaf.hash,
 		&span_availability,
 		validator.index(),
+		validator.signing_context().session_index,
 		&mut sender,
 	)
 	.await
@@ -292,6 +321,10 @@ where
 			gum::warn!(target: LOG_TARGET, err = ?runtime_err, "Encountered a runtime API error");
 			return Ok(())
 		},
+		Err(Error::RuntimeInfo(runtime_err)) => {
+			gum::warn!(target: LOG_TARGET, err = ?runtime_err, "Encountered a runtime API error");
+			return Ok(())
+		},
 		Err(err) => return Err(err),
 		Ok(bitfield) => bitfield,
 	};
@@ -17,9 +17,18 @@
 use super::*;
 use futures::{executor::block_on, pin_mut, StreamExt};
 use polkadot_node_subsystem::messages::AllMessages;
-use polkadot_primitives::{CandidateHash, OccupiedCore};
+use polkadot_node_subsystem_util::availability_chunks::availability_chunk_holder;
+use polkadot_primitives::{vstaging::node_features::FeatureIndex, CandidateHash, OccupiedCore};
+use sp_keyring::Sr25519Keyring;
 use test_helpers::dummy_candidate_descriptor;
 
+fn validators() -> Vec<polkadot_primitives::ValidatorId> {
+	[Sr25519Keyring::Alice, Sr25519Keyring::Bob, Sr25519Keyring::Charlie, Sr25519Keyring::Dave]
+		.iter()
+		.map(|k| k.public().into())
+		.collect()
+}
+
 fn occupied_core(para_id: u32, candidate_hash: CandidateHash) -> CoreState {
 	CoreState::Occupied(OccupiedCore {
 		group_responsible: para_id.into(),
@@ -44,6 +53,7 @@ fn construct_availability_bitfield_works() {
 			relay_parent,
 			&jaeger::Span::Disabled,
 			validator_index,
+			1,
 			&mut sender,
 		)
 		.fuse();
@@ -61,6 +71,17 @@ fn construct_availability_bitfield_works() {
 						assert_eq!(relay_parent, rp);
 						tx.send(Ok(vec![CoreState::Free, occupied_core(1, hash_a), occupied_core(2, hash_b)])).unwrap();
 					}
+					AllMessages::RuntimeApi(
+						RuntimeApiMessage::Request(_, RuntimeApiRequest::Validators(tx)),
+					) => {
+						tx.send(Ok(validators())).unwrap();
+					}
+					AllMessages::RuntimeApi(
+						RuntimeApiMessage::Request(_, RuntimeApiRequest::NodeFeatures(session, tx)),
+					) => {
+						assert_eq!(session, 1);
+						tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+					}
 					AllMessages::AvailabilityStore(
 						AvailabilityStoreMessage::QueryChunkAvailability(c_hash, vidx, tx),
 					) => {
@@ -83,3 +104,75 @@ fn construct_availability_bitfield_works() {
 		}
 	});
 }
+
+#[test]
+fn construct_availability_bitfield_queries_mapped_chunk() {
+	block_on(async move {
+		let relay_parent = Hash::default();
+		let validator_index = ValidatorIndex(1u32);
+		let n_validators = validators().len();
+
+		let mut node_features = NodeFeatures::EMPTY;
+		node_features.resize(FeatureIndex::AvailabilityChunkMapping as usize + 1, false);
+		node_features.set(FeatureIndex::AvailabilityChunkMapping as usize, true);
+
+		// A candidate for which our validator does not hold the chunk with its own index.
+		let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+		let expected_chunk_index = ValidatorIndex(2);
+		assert_eq!(
+			availability_chunk_holder(
+				Some(&node_features),
+				n_validators,
+				&candidate_hash,
+				expected_chunk_index,
+			),
+			validator_index,
+		);
+
+		let (mut sender, mut receiver) = polkadot_node_subsystem_test_helpers::sender_receiver();
+		let future = construct_availability_bitfield(
+			relay_parent,
+			&jaeger::Span::Disabled,
+			validator_index,
+			1,
+			&mut sender,
+		)
+		.fuse();
+		pin_mut!(future);
+
+		loop {
+			futures::select! {
+				m = receiver.next() => match m.unwrap() {
+					AllMessages::RuntimeApi(
+						RuntimeApiMessage::Request(_, RuntimeApiRequest::AvailabilityCores(tx)),
+					) => {
+						tx.send(Ok(vec![occupied_core(1, candidate_hash)])).unwrap();
+					}
+					AllMessages::RuntimeApi(
+						RuntimeApiMessage::Request(_, RuntimeApiRequest::Validators(tx)),
+					) => {
+						tx.send(Ok(validators())).unwrap();
+					}
+					AllMessages::RuntimeApi(
+						RuntimeApiMessage::Request(_, RuntimeApiRequest::NodeFeatures(_, tx)),
+					) => {
+						tx.send(Ok(node_features.clone())).unwrap();
+					}
+					AllMessages::AvailabilityStore(
+						AvailabilityStoreMessage::QueryChunkAvailability(_, chunk_index, tx),
+					) => {
+						tx.send(chunk_index == expected_chunk_index).unwrap();
+					},
+					o => panic!("Unknown message: {:?}", o),
+				},
+				r = future => match r {
+					Ok(r) => {
+						assert!(r.0.get(0).unwrap());
+						break
+					},
+					Err(e) => panic!("Failed: {:?}", e),
+				},
+			}
+		}
+	});
+}
@@ -553,6 +553,81 @@ where
 	validation_result
 }
 
+/// Validate a candidate outside of the overseer, e.g. to reproduce the verdict of a dispute from
+/// an exported dispute bundle.
+///
+/// A dedicated PVF validation host is started from `config` and the candidate is executed the
+/// same way approval checking would execute it. The host is torn down once the result is known.
+pub async fn validate_candidate_offline(
+	config: Config,
+	persisted_validation_data: PersistedValidationData,
+	validation_code: ValidationCode,
+	candidate_receipt: CandidateReceipt,
+	pov: Arc<PoV>,
+	executor_params: ExecutorParams,
+) -> Result<ValidationResult, ValidationFailed> {
+	let Config {
+		artifacts_cache_path,
+		node_version,
+		secure_validator_mode,
+		prep_worker_path,
+		exec_worker_path,
+	} = config;
+	let (validation_host, task) = polkadot_node_core_pvf::start(
+		polkadot_node_core_pvf::Config::new(
+			artifacts_cache_path,
+			node_version,
+			secure_validator_mode,
+			prep_worker_path,
+			exec_worker_path,
+		),
+		Default::default(),
+	)
+	.await
+	.map_err(|e| ValidationFailed(e.to_string()))?;
+
+	validate_candidate_with_host(
+		validation_host,
+		task,
+		persisted_validation_data,
+		validation_code,
+		candidate_receipt,
+		pov,
+		executor_params,
+	)
+	.await
+}
+
+/// Validate a candidate as approval checking would, while driving the task of the validation
+/// host.
+async fn validate_candidate_with_host(
+	validation_backend: impl ValidationBackend + Send,
+	host_task: impl Future<Output = ()>,
+	persisted_validation_data: PersistedValidationData,
+	validation_code: ValidationCode,
+	candidate_receipt: CandidateReceipt,
+	pov: Arc<PoV>,
+	executor_params: ExecutorParams,
+) -> Result<ValidationResult, ValidationFailed> {
+	let validation = validate_candidate_exhaustive(
+		validation_backend,
+		persisted_validation_data,
+		validation_code,
+		candidate_receipt,
+		pov,
+		executor_params,
+		PvfExecKind::Approval,
+		&Default::default(),
+	)
+	.boxed();
+
+	match future::select(validation, Box::pin(host_task)).await {
+		future::Either::Left((result, _)) => result,
+		future::Either::Right(((), _)) =>
+			Err(ValidationFailed("PVF validation host exited unexpectedly".to_string())),
+	}
+}
+
 async fn validate_candidate_exhaustive(
 	mut validation_backend: impl ValidationBackend + Send,
 	persisted_validation_data: PersistedValidationData,
@@ -451,6 +451,88 @@ fn candidate_validation_ok_is_ok() {
 	});
 }
 
+/// A backend whose validations never finish.
+struct PendingValidationBackend;
+
+#[async_trait]
+impl ValidationBackend for PendingValidationBackend {
+	async fn validate_candidate(
+		&mut self,
+		_pvf: PvfPrepData,
+		_timeout: Duration,
+		_encoded_params: Vec<u8>,
+	) -> Result<WasmValidationResult, ValidationError> {
+		future::pending().await
+	}
+
+	async fn precheck_pvf(&mut self, _pvf: PvfPrepData) -> Result<(), PrepareError> {
+		unreachable!()
+	}
+}
+
+#[test]
+fn offline_validation_returns_the_verdict_of_the_host() {
+	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let head_data = HeadData(vec![1, 1, 1]);
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = make_valid_candidate_descriptor(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		validation_data.hash(),
+		pov.hash(),
+		validation_code.hash(),
+		head_data.hash(),
+		dummy_hash(),
+		Sr25519Keyring::Alice,
+	);
+	let validation_result = WasmValidationResult {
+		head_data,
+		new_validation_code: None,
+		upward_messages: Default::default(),
+		horizontal_messages: Default::default(),
+		processed_downward_messages: 0,
+		hrmp_watermark: 0,
+	};
+	let commitments = CandidateCommitments {
+		head_data: validation_result.head_data.clone(),
+		upward_messages: validation_result.upward_messages.clone(),
+		horizontal_messages: validation_result.horizontal_messages.clone(),
+		new_validation_code: validation_result.new_validation_code.clone(),
+		processed_downward_messages: validation_result.processed_downward_messages,
+		hrmp_watermark: validation_result.hrmp_watermark,
+	};
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: commitments.hash() };
+
+	let v = executor::block_on(validate_candidate_with_host(
+		MockValidateCandidateBackend::with_hardcoded_result(Ok(validation_result)),
+		future::pending(),
+		validation_data.clone(),
+		validation_code.clone(),
+		candidate_receipt.clone(),
+		Arc::new(pov.clone()),
+		ExecutorParams::default(),
+	))
+	.unwrap();
+	assert_matches!(v, ValidationResult::Valid(outputs, used_validation_data) => {
+		assert_eq!(outputs.head_data, HeadData(vec![1, 1, 1]));
+		assert_eq!(used_validation_data, validation_data);
+	});
+
+	// The validation fails if the host goes away before answering.
+	let v = executor::block_on(validate_candidate_with_host(
+		PendingValidationBackend,
+		future::ready(()),
+		validation_data,
+		validation_code,
+		candidate_receipt,
+		Arc::new(pov),
+		ExecutorParams::default(),
+	));
+	assert_matches!(v, Err(ValidationFailed(_)));
+}
+
 #[test]
 fn candidate_validation_bad_return_is_invalid() {
 	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
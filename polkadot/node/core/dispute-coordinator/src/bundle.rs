@@ -0,0 +1,248 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Self-contained dispute bundles for offline analysis.
+//!
+//! A [`DisputeBundle`] gathers everything which is needed to re-run a disputed candidate outside
+//! of a running node: the candidate receipt, all recorded votes, the available data (PoV and
+//! persisted validation data), the validation code and the executor parameters of the session.
+//!
+//! The votes are read directly from the dispute coordinator database, the remaining pieces have
+//! to be filled in by the caller from the availability store and the runtime.
+
+use parity_scale_codec::{Decode, Encode};
+
+use polkadot_node_primitives::{AvailableData, DisputeStatus};
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{
+	CandidateHash, CandidateReceipt, ExecutorParams, InvalidDisputeStatementKind, SessionIndex,
+	ValidDisputeStatementKind, ValidationCode, ValidatorIndex, ValidatorSignature,
+};
+
+use crate::{db, error::FatalError, Config};
+
+/// Errors while reading dispute data for a bundle.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Reading from the dispute coordinator database failed.
+	#[error("Reading from the dispute coordinator database failed: {0}")]
+	DbReadFailed(String),
+	/// The bundle was written in a format this node doesn't know.
+	#[error("Unsupported dispute bundle version {0}, expected {BUNDLE_VERSION}")]
+	UnsupportedVersion(u32),
+	/// The bundle couldn't be decoded.
+	#[error("Invalid dispute bundle: {0}")]
+	Decode(#[from] parity_scale_codec::Error),
+}
+
+impl From<FatalError> for Error {
+	fn from(err: FatalError) -> Self {
+		Self::DbReadFailed(err.to_string())
+	}
+}
+
+/// The version of the bundles written by this node.
+///
+/// Must be bumped on any change to the encoding of [`DisputeBundle`].
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Everything needed to reproduce the verdict of a dispute outside of a running node.
+///
+/// The bundle is SCALE encoded when exported, see [`DisputeBundle::decode_versioned`] to read it
+/// back.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct DisputeBundle {
+	/// The version of the bundle format, always encoded first.
+	pub version: u32,
+	/// The session the dispute was raised in.
+	pub session: SessionIndex,
+	/// The hash of the disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// The last known status of the dispute, if the dispute is still considered recent.
+	pub status: Option<DisputeStatus>,
+	/// The receipt of the disputed candidate.
+	pub candidate_receipt: CandidateReceipt,
+	/// Votes of validators which consider the candidate valid.
+	pub valid: Vec<(ValidDisputeStatementKind, ValidatorIndex, ValidatorSignature)>,
+	/// Votes of validators which consider the candidate invalid.
+	pub invalid: Vec<(InvalidDisputeStatementKind, ValidatorIndex, ValidatorSignature)>,
+	/// The PoV and persisted validation data, if available locally.
+	pub available_data: Option<AvailableData>,
+	/// The validation code the candidate was validated against, if still known to the runtime.
+	pub validation_code: Option<ValidationCode>,
+	/// The executor parameters of the session, if still known to the runtime.
+	pub executor_params: Option<ExecutorParams>,
+}
+
+impl DisputeBundle {
+	/// Load the votes recorded for a candidate from the dispute coordinator database.
+	///
+	/// Returns `None` if no votes for the candidate are known in the given session. The available
+	/// data, validation code and executor parameters are left empty.
+	pub fn load(
+		db: &dyn Database,
+		config: &Config,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+	) -> Result<Option<Self>, Error> {
+		let column_config = config.column_config();
+		let Some(votes) =
+			db::v1::load_candidate_votes(db, &column_config, session, &candidate_hash)?
+		else {
+			return Ok(None)
+		};
+		let status = db::v1::load_recent_disputes(db, &column_config)?
+			.and_then(|recent| recent.get(&(session, candidate_hash)).copied());
+
+		Ok(Some(DisputeBundle {
+			version: BUNDLE_VERSION,
+			session,
+			candidate_hash,
+			status,
+			candidate_receipt: votes.candidate_receipt,
+			valid: votes.valid,
+			invalid: votes.invalid,
+			available_data: None,
+			validation_code: None,
+			executor_params: None,
+		}))
+	}
+
+	/// Decode an exported bundle, checking that its format is the one of this node.
+	pub fn decode_versioned(encoded: &[u8]) -> Result<Self, Error> {
+		let version = u32::decode(&mut &encoded[..])?;
+		if version != BUNDLE_VERSION {
+			return Err(Error::UnsupportedVersion(version))
+		}
+		Ok(Self::decode(&mut &encoded[..])?)
+	}
+
+	/// Whether the bundle contains everything needed to re-run the candidate.
+	pub fn is_replayable(&self) -> bool {
+		self.available_data.is_some() &&
+			self.validation_code.is_some() &&
+			self.executor_params.is_some()
+	}
+
+	/// The recorded conclusion of the dispute: `Some(true)` if it concluded for the candidate,
+	/// `Some(false)` if it concluded against it and `None` if it did not conclude (yet).
+	pub fn conclusion(&self) -> Option<bool> {
+		match self.status {
+			Some(DisputeStatus::ConcludedFor(_)) => Some(true),
+			Some(DisputeStatus::ConcludedAgainst(_)) => Some(false),
+			Some(DisputeStatus::Active) | Some(DisputeStatus::Confirmed) | None => None,
+		}
+	}
+}
+
+/// List all disputes the dispute coordinator database still considers recent.
+pub fn recent_disputes(
+	db: &dyn Database,
+	config: &Config,
+) -> Result<Vec<(SessionIndex, CandidateHash, DisputeStatus)>, Error> {
+	Ok(db::v1::load_recent_disputes(db, &config.column_config())?
+		.unwrap_or_default()
+		.into_iter()
+		.map(|((session, candidate_hash), status)| (session, candidate_hash, status))
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		backend::{Backend, OverlayedBackend},
+		db::v1::{CandidateVotes, DbBackend},
+		metrics::Metrics,
+	};
+	use ::test_helpers::{dummy_candidate_receipt, dummy_hash};
+	use polkadot_primitives::Hash;
+	use std::sync::Arc;
+
+	#[test]
+	fn bundle_contains_votes_and_status() {
+		let db = kvdb_memorydb::create(1);
+		let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[0]);
+		let store: Arc<dyn Database> = Arc::new(db);
+		let config = Config { col_dispute_data: 0 };
+		let mut backend = DbBackend::new(store.clone(), config.column_config(), Metrics::default());
+
+		let session = 1;
+		let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+		let receipt = dummy_candidate_receipt(dummy_hash());
+
+		let mut overlay_db = OverlayedBackend::new(&backend);
+		overlay_db.write_candidate_votes(
+			session,
+			candidate_hash,
+			CandidateVotes {
+				candidate_receipt: receipt.clone(),
+				valid: Vec::new(),
+				invalid: Vec::new(),
+			},
+		);
+		overlay_db.write_recent_disputes(
+			vec![((session, candidate_hash), DisputeStatus::ConcludedAgainst(0))]
+				.into_iter()
+				.collect(),
+		);
+		let write_ops = overlay_db.into_write_ops();
+		backend.write(write_ops).unwrap();
+
+		let bundle =
+			DisputeBundle::load(&*store, &config, session, candidate_hash).unwrap().unwrap();
+		assert_eq!(bundle.version, BUNDLE_VERSION);
+		assert_eq!(bundle.candidate_receipt, receipt);
+		assert_eq!(bundle.conclusion(), Some(false));
+		assert!(!bundle.is_replayable());
+
+		assert!(DisputeBundle::load(&*store, &config, session + 1, candidate_hash)
+			.unwrap()
+			.is_none());
+		assert_eq!(
+			recent_disputes(&*store, &config).unwrap(),
+			vec![(session, candidate_hash, DisputeStatus::ConcludedAgainst(0))],
+		);
+	}
+
+	#[test]
+	fn bundle_version_is_checked() {
+		let bundle = DisputeBundle {
+			version: BUNDLE_VERSION,
+			session: 1,
+			candidate_hash: CandidateHash(Hash::repeat_byte(1)),
+			status: Some(DisputeStatus::ConcludedFor(0)),
+			candidate_receipt: dummy_candidate_receipt(dummy_hash()),
+			valid: Vec::new(),
+			invalid: Vec::new(),
+			available_data: None,
+			validation_code: Some(ValidationCode(vec![1, 2, 3])),
+			executor_params: Some(ExecutorParams::default()),
+		};
+
+		let decoded = DisputeBundle::decode_versioned(&bundle.encode()).unwrap();
+		assert_eq!(decoded.candidate_receipt, bundle.candidate_receipt);
+		assert_eq!(decoded.conclusion(), Some(true));
+		assert_eq!(decoded.validation_code, bundle.validation_code);
+
+		let future = DisputeBundle { version: BUNDLE_VERSION + 1, ..bundle };
+		assert!(matches!(
+			DisputeBundle::decode_versioned(&future.encode()),
+			Err(Error::UnsupportedVersion(version)) if version == BUNDLE_VERSION + 1
+		));
+		assert!(matches!(DisputeBundle::decode_versioned(&[1, 0]), Err(Error::Decode(_))));
+	}
+}
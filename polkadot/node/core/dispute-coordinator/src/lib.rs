@@ -103,6 +103,9 @@ pub(crate) mod participation;
 /// Pure processing of vote imports.
 pub(crate) mod import;
 
+/// Export of dispute evidence for offline analysis.
+pub mod bundle;
+
 /// Metrics types.
 mod metrics;
 
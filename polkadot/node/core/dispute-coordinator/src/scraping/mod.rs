@@ -25,7 +25,8 @@ use polkadot_node_subsystem::{
 	RuntimeApiError, SubsystemSender,
 };
 use polkadot_node_subsystem_util::runtime::{
-	self, get_candidate_events, get_on_chain_votes, get_unapplied_slashes,
+	self, get_candidate_events, get_on_chain_votes, get_unapplied_backer_slashes,
+	get_unapplied_slashes,
 };
 use polkadot_primitives::{
 	slashing::PendingSlashes, BlockNumber, CandidateEvent, CandidateHash, CandidateReceipt, Hash,
@@ -300,6 +301,28 @@ impl ChainScraper {
 			},
 		}
 
+		// backers of candidates concluded invalid are tracked separately
+		match get_unapplied_backer_slashes(sender, activated.hash).await {
+			Ok(unapplied_slashes) => {
+				scraped_updates.unapplied_slashes.extend(unapplied_slashes);
+			},
+			Err(runtime::Error::RuntimeRequest(RuntimeApiError::NotSupported { .. })) => {
+				gum::debug!(
+					target: LOG_TARGET,
+					block_hash = ?activated.hash,
+					"Fetching unapplied backer slashes not yet supported.",
+				);
+			},
+			Err(error) => {
+				gum::warn!(
+					target: LOG_TARGET,
+					block_hash = ?activated.hash,
+					?error,
+					"Error fetching unapplied backer slashes.",
+				);
+			},
+		}
+
 		self.last_observed_blocks.insert(activated.hash, ());
 
 		Ok(scraped_updates)
@@ -253,6 +253,15 @@ async fn assert_unapplied_slashes_request(virtual_overseer: &mut VirtualOverseer
 			tx.send(Ok(Vec::new())).unwrap();
 		}
 	);
+	assert_matches!(
+		overseer_recv(virtual_overseer).await,
+		AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+			_hash,
+			RuntimeApiRequest::UnappliedBackerSlashes(tx),
+		)) => {
+			tx.send(Ok(Vec::new())).unwrap();
+		}
+	);
 }
 
 async fn assert_finalized_block_number_request(
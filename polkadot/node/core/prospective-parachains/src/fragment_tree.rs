@@ -753,26 +753,32 @@ impl FragmentTree {
 		depths.iter_ones().collect()
 	}
 
-	/// Select a candidate after the given `required_path` which passes
-	/// the predicate.
+	/// Select `count` candidates after the given `required_path` which pass
+	/// the predicate, forming a chain: every selected candidate is a child of
+	/// the one selected before it.
 	///
-	/// If there are multiple possibilities, this will select the first one.
+	/// If there are multiple possibilities at a step, this will select the first one.
 	///
-	/// This returns `None` if there is no candidate meeting those criteria.
+	/// This returns fewer than `count` candidates, possibly none, if the chain of candidates
+	/// meeting those criteria is shorter.
 	///
 	/// The intention of the `required_path` is to allow queries on the basis of
 	/// one or more candidates which were previously pending availability becoming
-	/// available and opening up more room on the core.
-	pub(crate) fn select_child(
+	/// available and opening up more room on the cores.
+	pub(crate) fn select_children(
 		&self,
 		required_path: &[CandidateHash],
+		count: u32,
 		pred: impl Fn(&CandidateHash) -> bool,
-	) -> Option<CandidateHash> {
+	) -> Vec<CandidateHash> {
 		let base_node = {
 			// traverse the required path.
 			let mut node = NodePointer::Root;
 			for required_step in required_path {
-				node = self.node_candidate_child(node, &required_step)?;
+				match self.node_candidate_child(node, &required_step) {
+					Some(next_node) => node = next_node,
+					None => return Vec::new(),
+				}
 			}
 
 			node
@@ -783,23 +789,34 @@ impl FragmentTree {
 		//
 		// For plausibly unique parachains, this shouldn't matter much.
 		// figure out alternative selection criteria?
-		match base_node {
-			NodePointer::Root => self
-				.nodes
-				.iter()
-				.take_while(|n| n.parent == NodePointer::Root)
-				.filter(|n| self.scope.get_pending_availability(&n.candidate_hash).is_none())
-				.filter(|n| pred(&n.candidate_hash))
-				.map(|n| n.candidate_hash)
-				.next(),
-			NodePointer::Storage(ptr) => self.nodes[ptr]
-				.children
-				.iter()
-				.filter(|n| self.scope.get_pending_availability(&n.1).is_none())
-				.filter(|n| pred(&n.1))
-				.map(|n| n.1)
-				.next(),
+		let mut selected = Vec::with_capacity(count as usize);
+		let mut node = base_node;
+		while selected.len() < count as usize {
+			let child = match node {
+				NodePointer::Root => self
+					.nodes
+					.iter()
+					.enumerate()
+					.take_while(|(_, n)| n.parent == NodePointer::Root)
+					.filter(|(_, n)| {
+						self.scope.get_pending_availability(&n.candidate_hash).is_none()
+					})
+					.find(|(_, n)| pred(&n.candidate_hash))
+					.map(|(ptr, n)| (NodePointer::Storage(ptr), n.candidate_hash)),
+				NodePointer::Storage(ptr) => self.nodes[ptr]
+					.children
+					.iter()
+					.filter(|n| self.scope.get_pending_availability(&n.1).is_none())
+					.find(|n| pred(&n.1))
+					.copied(),
+			};
+
+			let Some((child_node, child_hash)) = child else { break };
+			selected.push(child_hash);
+			node = child_node;
 		}
+
+		selected
 	}
 
 	fn populate_from_bases(&mut self, storage: &CandidateStorage, initial_bases: Vec<NodePointer>) {
@@ -146,12 +146,20 @@ async fn run_iteration<Context>(
 					handle_candidate_seconded(view, para, candidate_hash),
 				ProspectiveParachainsMessage::CandidateBacked(para, candidate_hash) =>
 					handle_candidate_backed(&mut *ctx, view, para, candidate_hash).await?,
-				ProspectiveParachainsMessage::GetBackableCandidate(
+				ProspectiveParachainsMessage::GetBackableCandidates(
 					relay_parent,
 					para,
+					count,
 					required_path,
 					tx,
-				) => answer_get_backable_candidate(&view, relay_parent, para, required_path, tx),
+				) => answer_get_backable_candidates(
+					&view,
+					relay_parent,
+					para,
+					count,
+					required_path,
+					tx,
+				),
 				ProspectiveParachainsMessage::GetHypotheticalFrontier(request, tx) =>
 					answer_hypothetical_frontier_request(&view, request, tx),
 				ProspectiveParachainsMessage::GetTreeMembership(para, candidate, tx) =>
@@ -552,12 +560,13 @@ async fn handle_candidate_backed<Context>(
 	Ok(())
 }
 
-fn answer_get_backable_candidate(
+fn answer_get_backable_candidates(
 	view: &View,
 	relay_parent: Hash,
 	para: ParaId,
+	count: u32,
 	required_path: Vec<CandidateHash>,
-	tx: oneshot::Sender<Option<(CandidateHash, Hash)>>,
+	tx: oneshot::Sender<Vec<(CandidateHash, Hash)>>,
 ) {
 	let data = match view.active_leaves.get(&relay_parent) {
 		None => {
@@ -568,7 +577,7 @@ fn answer_get_backable_candidate(
 				"Requested backable candidate for inactive relay-parent."
 			);
 
-			let _ = tx.send(None);
+			let _ = tx.send(Vec::new());
 			return
 		},
 		Some(d) => d,
@@ -583,7 +592,7 @@ fn answer_get_backable_candidate(
 				"Requested backable candidate for inactive para."
 			);
 
-			let _ = tx.send(None);
+			let _ = tx.send(Vec::new());
 			return
 		},
 		Some(tree) => tree,
@@ -598,30 +607,42 @@ fn answer_get_backable_candidate(
 				"No candidate storage for active para",
 			);
 
-			let _ = tx.send(None);
+			let _ = tx.send(Vec::new());
 			return
 		},
 		Some(s) => s,
 	};
 
-	let Some(child_hash) =
-		tree.select_child(&required_path, |candidate| storage.is_backed(candidate))
-	else {
-		let _ = tx.send(None);
-		return
-	};
-	let Some(candidate_relay_parent) = storage.relay_parent_by_candidate_hash(&child_hash) else {
-		gum::error!(
+	// The candidates form a chain, so stop at the first one we can't provide.
+	let backable_children = tree
+		.select_children(&required_path, count, |candidate| storage.is_backed(candidate))
+		.into_iter()
+		.map_while(|child_hash| {
+			let Some(candidate_relay_parent) = storage.relay_parent_by_candidate_hash(&child_hash)
+			else {
+				gum::error!(
+					target: LOG_TARGET,
+					?child_hash,
+					para_id = ?para,
+					"Candidate is present in fragment tree but not in candidate's storage!",
+				);
+				return None
+			};
+			Some((child_hash, candidate_relay_parent))
+		})
+		.collect::<Vec<_>>();
+
+	if backable_children.is_empty() {
+		gum::trace!(
 			target: LOG_TARGET,
-			?child_hash,
+			?required_path,
 			para_id = ?para,
-			"Candidate is present in fragment tree but not in candidate's storage!",
+			%relay_parent,
+			"Could not find any backable candidate",
 		);
-		let _ = tx.send(None);
-		return
-	};
+	}
 
-	let _ = tx.send(Some((child_hash, candidate_relay_parent)));
+	let _ = tx.send(backable_children);
 }
 
 fn answer_hypothetical_frontier_request(
@@ -634,6 +634,8 @@ async fn select_candidate_hashes_from_tracked(
 /// Requests backable candidates from Prospective Parachains subsystem
 /// based on core states.
 ///
+/// A para with multiple cores becoming free gets a chain of candidates, one for each core.
+///
 /// Should be called when prospective parachains are enabled.
 async fn request_backable_candidates(
 	availability_cores: &[CoreState],
@@ -643,14 +645,18 @@ async fn request_backable_candidates(
 ) -> Result<Vec<(CandidateHash, Hash)>, Error> {
 	let block_number = get_block_number_under_construction(relay_parent, sender).await?;
 
-	let mut selected_candidates = Vec::with_capacity(availability_cores.len());
+	// The number of cores each para can back candidates on.
+	let mut scheduled_cores_per_para: BTreeMap<ParaId, u32> = BTreeMap::new();
+	// The candidates of each para which are going to be included, which the backable candidates
+	// have to build upon.
+	let mut ancestors: HashMap<ParaId, Vec<CandidateHash>> = HashMap::new();
 
 	for (core_idx, core) in availability_cores.iter().enumerate() {
-		let (para_id, required_path) = match core {
+		let para_id = match core {
 			CoreState::Scheduled(scheduled_core) => {
 				// The core is free, pick the first eligible candidate from
 				// the fragment tree.
-				(scheduled_core.para_id, Vec::new())
+				scheduled_core.para_id
 			},
 			CoreState::Occupied(occupied_core) => {
 				if bitfields_indicate_availability(core_idx, bitfields, &occupied_core.availability)
@@ -662,7 +668,11 @@ async fn request_backable_candidates(
 						// TODO: doesn't work for on-demand parachains. We lean hard on the
 						// assumption that cores are fixed to specific parachains within a session.
 						// https://github.com/paritytech/polkadot/issues/5492
-						(scheduled_core.para_id, vec![occupied_core.candidate_hash])
+						ancestors
+							.entry(scheduled_core.para_id)
+							.or_default()
+							.push(occupied_core.candidate_hash);
+						scheduled_core.para_id
 					} else {
 						continue
 					}
@@ -672,7 +682,7 @@ async fn request_backable_candidates(
 					}
 					if let Some(ref scheduled_core) = occupied_core.next_up_on_time_out {
 						// Candidate's availability timed out, practically same as scheduled.
-						(scheduled_core.para_id, Vec::new())
+						scheduled_core.para_id
 					} else {
 						continue
 					}
@@ -681,19 +691,28 @@ async fn request_backable_candidates(
 			CoreState::Free => continue,
 		};
 
-		let response = get_backable_candidate(relay_parent, para_id, required_path, sender).await?;
+		*scheduled_cores_per_para.entry(para_id).or_default() += 1;
+	}
 
-		match response {
-			Some((hash, relay_parent)) => selected_candidates.push((hash, relay_parent)),
-			None => {
-				gum::debug!(
-					target: LOG_TARGET,
-					leaf_hash = ?relay_parent,
-					core = core_idx,
-					"No backable candidate returned by prospective parachains",
-				);
-			},
+	let mut selected_candidates = Vec::with_capacity(availability_cores.len());
+
+	for (para_id, core_count) in scheduled_cores_per_para {
+		let para_ancestors = ancestors.remove(&para_id).unwrap_or_default();
+
+		let response =
+			get_backable_candidates(relay_parent, para_id, para_ancestors, core_count, sender)
+				.await?;
+
+		if response.is_empty() {
+			gum::debug!(
+				target: LOG_TARGET,
+				leaf_hash = ?relay_parent,
+				?para_id,
+				"No backable candidate returned by prospective parachains",
+			);
 		}
+
+		selected_candidates.extend(response);
 	}
 
 	Ok(selected_candidates)
@@ -737,8 +756,9 @@ async fn select_candidates(
 	gum::trace!(target: LOG_TARGET, leaf_hash=?relay_parent,
 				"Got {} backed candidates", candidates.len());
 
-	// `selected_candidates` is generated in ascending order by core index, and
-	// `GetBackedCandidates` _should_ preserve that property, but let's just make sure.
+	// `selected_candidates` is generated in ascending order by core index, or by para with the
+	// candidates of each para in the order of their chain, and `GetBackedCandidates` _should_
+	// preserve that order, but let's just make sure.
 	//
 	// We can't easily map from `BackedCandidate` to `core_idx`, but we know that every selected
 	// candidate maps to either 0 or 1 backed candidate, and the hashes correspond. Therefore, by
@@ -796,19 +816,21 @@ async fn get_block_number_under_construction(
 	}
 }
 
-/// Requests backable candidate from Prospective Parachains based on
+/// Requests backable candidates from Prospective Parachains based on
 /// the given path in the fragment tree.
-async fn get_backable_candidate(
+async fn get_backable_candidates(
 	relay_parent: Hash,
 	para_id: ParaId,
 	required_path: Vec<CandidateHash>,
+	count: u32,
 	sender: &mut impl overseer::ProvisionerSenderTrait,
-) -> Result<Option<(CandidateHash, Hash)>, Error> {
+) -> Result<Vec<(CandidateHash, Hash)>, Error> {
 	let (tx, rx) = oneshot::channel();
 	sender
-		.send_message(ProspectiveParachainsMessage::GetBackableCandidate(
+		.send_message(ProspectiveParachainsMessage::GetBackableCandidates(
 			relay_parent,
 			para_id,
+			count,
 			required_path,
 			tx,
 		))
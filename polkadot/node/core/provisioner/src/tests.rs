@@ -341,9 +341,15 @@ mod select_candidates {
 		use ChainApiMessage::BlockNumber;
 		use RuntimeApiMessage::Request;
 
-		let mut candidates_iter = expected
+		let candidates = expected
 			.iter()
-			.map(|candidate| (candidate.hash(), candidate.descriptor().relay_parent));
+			.map(|candidate| {
+				(
+					candidate.descriptor().para_id,
+					(candidate.hash(), candidate.descriptor().relay_parent),
+				)
+			})
+			.collect::<Vec<_>>();
 
 		let mut backed_iter = expected.clone().into_iter();
 
@@ -373,10 +379,17 @@ mod select_candidates {
 					let _ = sender.send(response);
 				},
 				AllMessages::ProspectiveParachains(
-					ProspectiveParachainsMessage::GetBackableCandidate(.., tx),
+					ProspectiveParachainsMessage::GetBackableCandidates(_, para_id, count, _, tx),
 				) => match prospective_parachains_mode {
 					ProspectiveParachainsMode::Enabled { .. } => {
-						let _ = tx.send(candidates_iter.next());
+						let _ = tx.send(
+							candidates
+								.iter()
+								.filter(|(candidate_para, _)| *candidate_para == para_id)
+								.map(|(_, candidate)| *candidate)
+								.take(count as usize)
+								.collect(),
+						);
 					},
 					ProspectiveParachainsMode::Disabled =>
 						panic!("unexpected prospective parachains request"),
@@ -700,4 +713,68 @@ mod select_candidates {
 			},
 		)
 	}
+
+	#[test]
+	fn request_chains_for_paras_with_multiple_cores() {
+		use CoreState::Scheduled;
+
+		// Para 1 has two cores, para 2 has one.
+		let mock_cores = vec![
+			Scheduled(scheduled_core(1)),
+			Scheduled(scheduled_core(2)),
+			Scheduled(scheduled_core(1)),
+		];
+
+		let descriptor_template = dummy_candidate_descriptor(dummy_hash());
+		let candidate_template = CandidateReceipt {
+			descriptor: descriptor_template,
+			commitments_hash: CandidateCommitments::default().hash(),
+		};
+
+		// The candidates of para 1 form a chain.
+		let expected_candidates: Vec<_> = [(1, 1), (1, 2), (2, 3)]
+			.into_iter()
+			.map(|(para_id, pov_byte)| {
+				let mut candidate = candidate_template.clone();
+				candidate.descriptor.para_id = para_id.into();
+				candidate.descriptor.pov_hash = Hash::repeat_byte(pov_byte);
+				candidate
+			})
+			.collect();
+		let prospective_parachains_mode =
+			ProspectiveParachainsMode::Enabled { max_candidate_depth: 1, allowed_ancestry_len: 0 };
+
+		let expected_backed: Vec<_> = expected_candidates
+			.iter()
+			.map(|c| BackedCandidate {
+				candidate: CommittedCandidateReceipt {
+					descriptor: c.descriptor.clone(),
+					commitments: Default::default(),
+				},
+				validity_votes: Vec::new(),
+				validator_indices: default_bitvec(MOCK_GROUP_SIZE),
+			})
+			.collect();
+
+		test_harness(
+			|r| mock_overseer(r, expected_backed, prospective_parachains_mode),
+			|mut tx: TestSubsystemSender| async move {
+				let result = select_candidates(
+					&mock_cores,
+					&[],
+					&[],
+					prospective_parachains_mode,
+					Default::default(),
+					&mut tx,
+				)
+				.await
+				.unwrap();
+
+				assert_eq!(result.len(), expected_candidates.len());
+				result.into_iter().zip(expected_candidates.iter()).for_each(|(c, c2)| {
+					assert!(c.candidate.corresponds_to(c2), "Failed to find candidate: {:?}", c)
+				});
+			},
+		)
+	}
 }
@@ -0,0 +1,167 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! On-disk format of the compiled artifacts.
+//!
+//! An artifact file starts with [`ARTIFACT_MAGIC`], followed by a SCALE-encoded
+//! [`ArtifactHeader`], followed by the compiled code. The header allows the host to keep the
+//! artifacts across restarts: an artifact is only reused if it was compiled by the same node
+//! version and if the header and the compiled code match the checksum.
+
+use parity_scale_codec::{Decode, Encode};
+use polkadot_parachain_primitives::primitives::ValidationCodeHash;
+use polkadot_primitives::ExecutorParams;
+use std::io::{self, Write};
+
+/// Magic bytes every artifact file starts with. The last byte is the version of the format.
+pub const ARTIFACT_MAGIC: [u8; 8] = *b"pvfart\x00\x02";
+
+/// Header of an artifact file, describing the compiled code that follows it.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ArtifactHeader {
+	/// Version of the node that compiled the artifact, if known.
+	pub node_version: Option<String>,
+	/// Hash of the PVF code the artifact was compiled from.
+	pub code_hash: ValidationCodeHash,
+	/// Executor parameters the artifact was compiled with.
+	pub executor_params: ExecutorParams,
+	/// BLAKE3 checksum of the other fields of the header, SCALE-encoded, followed by the compiled
+	/// code.
+	pub checksum: [u8; 32],
+}
+
+/// Error when reading an artifact file.
+#[derive(thiserror::Error, Debug)]
+pub enum ArtifactError {
+	#[error("artifact doesn't start with the expected magic bytes")]
+	InvalidMagic,
+	#[error("cannot decode artifact header: {0}")]
+	InvalidHeader(#[from] parity_scale_codec::Error),
+	#[error("artifact header or compiled code doesn't match the artifact checksum")]
+	ChecksumMismatch,
+}
+
+impl ArtifactHeader {
+	/// Creates the header of the given compiled code.
+	pub fn new(
+		node_version: Option<&str>,
+		code_hash: ValidationCodeHash,
+		executor_params: ExecutorParams,
+		code: &[u8],
+	) -> Self {
+		let mut header = Self {
+			node_version: node_version.map(String::from),
+			code_hash,
+			executor_params,
+			checksum: [0; 32],
+		};
+		header.checksum = header.compute_checksum(code);
+		header
+	}
+
+	/// Computes the checksum of the header, excluding the checksum itself, and the given compiled
+	/// code.
+	fn compute_checksum(&self, code: &[u8]) -> [u8; 32] {
+		let mut hasher = blake3::Hasher::new();
+		hasher.update(&(&self.node_version, &self.code_hash, &self.executor_params).encode());
+		hasher.update(code);
+		*hasher.finalize().as_bytes()
+	}
+
+	/// Writes an artifact file made of this header and the given compiled code.
+	pub fn write_artifact(&self, mut w: impl Write, code: &[u8]) -> io::Result<()> {
+		w.write_all(&ARTIFACT_MAGIC)?;
+		w.write_all(&self.encode())?;
+		w.write_all(code)?;
+		w.flush()
+	}
+}
+
+/// Splits the content of an artifact file into its header and the compiled code.
+///
+/// The checksum isn't verified, see [`verify_artifact`].
+pub fn decode_artifact(bytes: &[u8]) -> Result<(ArtifactHeader, &[u8]), ArtifactError> {
+	let mut input = bytes.strip_prefix(&ARTIFACT_MAGIC[..]).ok_or(ArtifactError::InvalidMagic)?;
+	let header = ArtifactHeader::decode(&mut input)?;
+	Ok((header, input))
+}
+
+/// Same as [`decode_artifact`], but also verifies that the header and the compiled code match the
+/// checksum.
+pub fn verify_artifact(bytes: &[u8]) -> Result<(ArtifactHeader, &[u8]), ArtifactError> {
+	let (header, code) = decode_artifact(bytes)?;
+	if header.compute_checksum(code) != header.checksum {
+		return Err(ArtifactError::ChecksumMismatch)
+	}
+	Ok((header, code))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use assert_matches::assert_matches;
+
+	#[test]
+	fn artifact_roundtrip() {
+		let code = b"compiled code".to_vec();
+		let header = ArtifactHeader::new(
+			Some("1.0.0"),
+			ValidationCodeHash::from(sp_core::H256::repeat_byte(1)),
+			ExecutorParams::default(),
+			&code,
+		);
+
+		let mut file = Vec::new();
+		header.write_artifact(&mut file, &code).unwrap();
+
+		let (decoded, decoded_code) = verify_artifact(&file).unwrap();
+		assert_eq!(decoded, header);
+		assert_eq!(decoded_code, &code[..]);
+
+		// Corrupt the compiled code.
+		let last = file.len() - 1;
+		file[last] ^= 1;
+		assert!(decode_artifact(&file).is_ok());
+		assert_matches!(verify_artifact(&file), Err(ArtifactError::ChecksumMismatch));
+
+		assert_matches!(verify_artifact(&code), Err(ArtifactError::InvalidMagic));
+	}
+
+	#[test]
+	fn artifact_header_is_covered_by_checksum() {
+		let code = b"compiled code".to_vec();
+		let header = ArtifactHeader::new(
+			Some("1.0.0"),
+			ValidationCodeHash::from(sp_core::H256::repeat_byte(1)),
+			ExecutorParams::default(),
+			&code,
+		);
+
+		// Tamper with the header, keeping the checksum.
+		let tampered = ArtifactHeader { node_version: Some("1.0.1".into()), ..header.clone() };
+		let mut file = Vec::new();
+		tampered.write_artifact(&mut file, &code).unwrap();
+		assert_matches!(verify_artifact(&file), Err(ArtifactError::ChecksumMismatch));
+
+		let tampered = ArtifactHeader {
+			code_hash: ValidationCodeHash::from(sp_core::H256::repeat_byte(2)),
+			..header
+		};
+		let mut file = Vec::new();
+		tampered.write_artifact(&mut file, &code).unwrap();
+		assert_matches!(verify_artifact(&file), Err(ArtifactError::ChecksumMismatch));
+	}
+}
@@ -92,6 +92,10 @@ pub const DEFAULT_CONFIG: Config = Config {
 		wasm_simd: false,
 		wasm_bulk_memory: false,
 		wasm_multi_value: false,
+
+		// Execution is only limited by timeouts by default. Deterministic metering has to be
+		// enabled explicitly with the `PvfExecFuelLimit` executor parameter.
+		fuel_limit: None,
 	},
 };
 
@@ -164,6 +168,7 @@ pub fn params_to_wasmtime_semantics(par: &ExecutorParams) -> Semantics {
 			ExecutorParam::StackLogicalMax(slm) => stack_limit.logical_max = *slm,
 			ExecutorParam::StackNativeMax(snm) => stack_limit.native_stack_max = *snm,
 			ExecutorParam::WasmExtBulkMemory => sem.wasm_bulk_memory = true,
+			ExecutorParam::PvfExecFuelLimit(limit) => sem.fuel_limit = Some(*limit),
 			ExecutorParam::PrecheckingMaxMemory(_) |
 			ExecutorParam::PvfPrepTimeout(_, _) |
 			ExecutorParam::PvfExecTimeout(_, _) => (), /* Not used here */
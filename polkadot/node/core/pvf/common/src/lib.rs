@@ -16,6 +16,7 @@
 
 //! Contains functionality related to PVFs that is shared by the PVF host and the PVF workers.
 
+pub mod artifact;
 pub mod error;
 pub mod execute;
 pub mod executor_interface;
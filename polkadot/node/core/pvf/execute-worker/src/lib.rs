@@ -34,6 +34,7 @@ use nix::{
 use os_pipe::{self, PipeReader, PipeWriter};
 use parity_scale_codec::{Decode, Encode};
 use polkadot_node_core_pvf_common::{
+	artifact::decode_artifact,
 	error::InternalValidationError,
 	execute::{Handshake, JobError, JobResponse, JobResult, WorkerResponse},
 	framed_recv_blocking, framed_send_blocking,
@@ -172,6 +173,22 @@ pub fn worker_entrypoint(
 					},
 				};
 
+				// Skip the artifact header, the host already verified it when loading the artifact.
+				let code_offset = match decode_artifact(&compiled_artifact_blob) {
+					Ok((_, code)) => compiled_artifact_blob.len() - code.len(),
+					Err(err) => {
+						let response = WorkerResponse::InternalError(
+							InternalValidationError::CouldNotOpenFile(format!(
+								"invalid artifact {}: {}",
+								artifact_path.display(),
+								err
+							)),
+						);
+						send_response(&mut stream, response)?;
+						continue
+					},
+				};
+
 				let (pipe_reader, pipe_writer) = os_pipe::pipe()?;
 
 				let usage_before = match nix::sys::resource::getrusage(UsageWho::RUSAGE_CHILDREN) {
@@ -199,6 +216,7 @@ pub fn worker_entrypoint(
 						handle_child_process(
 							pipe_writer,
 							compiled_artifact_blob,
+							code_offset,
 							executor_params,
 							params,
 							execution_timeout,
@@ -261,6 +279,8 @@ fn validate_using_artifact(
 ///
 /// - `compiled_artifact_blob`: The artifact bytes from compiled by the prepare worker`.
 ///
+/// - `code_offset`: Offset of the compiled code in `compiled_artifact_blob`, after the header.
+///
 /// - `executor_params`: Deterministically serialized execution environment semantics.
 ///
 /// - `params`: Validation parameters.
@@ -273,6 +293,7 @@ fn validate_using_artifact(
 fn handle_child_process(
 	mut pipe_write: PipeWriter,
 	compiled_artifact_blob: Vec<u8>,
+	code_offset: usize,
 	executor_params: ExecutorParams,
 	params: Vec<u8>,
 	execution_timeout: Duration,
@@ -311,7 +332,13 @@ fn handle_child_process(
 	let executor_params_2 = executor_params.clone();
 	let execute_thread = thread::spawn_worker_thread_with_stack_size(
 		"execute thread",
-		move || validate_using_artifact(&compiled_artifact_blob, &executor_params_2, &params),
+		move || {
+			validate_using_artifact(
+				&compiled_artifact_blob[code_offset..],
+				&executor_params_2,
+				&params,
+			)
+		},
 		Arc::clone(&condvar),
 		WaitOutcome::Finished,
 		EXECUTE_THREAD_STACK_SIZE,
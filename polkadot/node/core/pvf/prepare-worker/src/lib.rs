@@ -40,6 +40,7 @@ use nix::{
 use os_pipe::{self, PipeReader, PipeWriter};
 use parity_scale_codec::{Decode, Encode};
 use polkadot_node_core_pvf_common::{
+	artifact::ArtifactHeader,
 	error::{PrepareError, PrepareWorkerResult},
 	executor_interface::create_runtime_from_artifact_bytes,
 	framed_recv_blocking, framed_send_blocking,
@@ -52,7 +53,7 @@ use polkadot_node_core_pvf_common::{
 	},
 	worker_dir, ProcessTime,
 };
-use polkadot_primitives::ExecutorParams;
+use polkadot_primitives::{ExecutorParams, ValidationCodeHash};
 use std::{
 	fs,
 	io::{self, Read},
@@ -215,6 +216,7 @@ pub fn worker_entrypoint(
 				let preparation_timeout = pvf.prep_timeout();
 				let prepare_job_kind = pvf.prep_kind();
 				let executor_params = pvf.executor_params();
+				let code_hash = pvf.code_hash();
 
 				let (pipe_reader, pipe_writer) = os_pipe::pipe()?;
 
@@ -245,7 +247,7 @@ pub fn worker_entrypoint(
 							pipe_writer,
 							preparation_timeout,
 							prepare_job_kind,
-							executor_params,
+							executor_params.clone(),
 						)
 					},
 					Ok(ForkResult::Parent { child }) => {
@@ -258,6 +260,7 @@ pub fn worker_entrypoint(
 							worker_pid,
 							child,
 							temp_artifact_dest.clone(),
+							ArtifactDescription { node_version, code_hash, executor_params },
 							usage_before,
 							preparation_timeout,
 						)
@@ -487,6 +490,13 @@ fn handle_child_process(
 	send_child_response(&mut pipe_write, result);
 }
 
+/// Description of the artifact being prepared, recorded in the header of the artifact file.
+struct ArtifactDescription<'a> {
+	node_version: Option<&'a str>,
+	code_hash: ValidationCodeHash,
+	executor_params: Arc<ExecutorParams>,
+}
+
 /// Waits for child process to finish and handle child response from pipe.
 ///
 /// # Arguments
@@ -497,6 +507,8 @@ fn handle_child_process(
 ///
 /// - `temp_artifact_dest`: The destination `PathBuf` to write the temporary artifact file.
 ///
+/// - `artifact_description`: Description of the artifact, written in the header of the file.
+///
 /// - `worker_pid`: The PID of the child process.
 ///
 /// - `usage_before`: Resource usage statistics before executing the child process.
@@ -516,6 +528,7 @@ fn handle_parent_process(
 	worker_pid: u32,
 	job_pid: Pid,
 	temp_artifact_dest: PathBuf,
+	artifact_description: ArtifactDescription,
 	usage_before: Usage,
 	timeout: Duration,
 ) -> Result<PrepareWorkerSuccess, PrepareError> {
@@ -586,8 +599,19 @@ fn handle_parent_process(
 						"worker: writing artifact to {}",
 						temp_artifact_dest.display(),
 					);
-					// Write to the temp file created by the host.
-					if let Err(err) = fs::write(&temp_artifact_dest, &artifact) {
+					// Write to the temp file created by the host, prefixed with the header allowing
+					// the host to reuse the artifact after a restart.
+					let ArtifactDescription { node_version, code_hash, executor_params } =
+						artifact_description;
+					let header = ArtifactHeader::new(
+						node_version,
+						code_hash,
+						(*executor_params).clone(),
+						artifact.as_ref(),
+					);
+					if let Err(err) = fs::File::create(&temp_artifact_dest).and_then(|file| {
+						header.write_artifact(io::BufWriter::new(file), artifact.as_ref())
+					}) {
 						return Err(PrepareError::IoErr(err.to_string()))
 					};
 
@@ -18,7 +18,9 @@
 //!
 //! # Lifecycle of an artifact
 //!
-//! 1. During node start-up, we prune all the cached artifacts, if any.
+//! 1. During node start-up, we load the cached artifacts that were compiled by the same node
+//!    version and whose checksum is still valid. They are inserted as [`ArtifactState::Prepared`].
+//!    All the other cached artifacts, if any, are pruned.
 //!
 //! 2. In order to be executed, a PVF should be prepared first. This means that artifacts should
 //!    have an [`ArtifactState::Prepared`] entry for that artifact in the table. If not, the
@@ -54,9 +56,11 @@
 //!    older by a predefined parameter. This process is run very rarely (say, once a day). Once the
 //!    artifact is expired it is removed from disk eagerly atomically.
 
-use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX};
+use crate::{host::PrecheckResultSender, worker_interface::WORKER_DIR_PREFIX, LOG_TARGET};
 use always_assert::always;
-use polkadot_node_core_pvf_common::{error::PrepareError, prepare::PrepareStats, pvf::PvfPrepData};
+use polkadot_node_core_pvf_common::{
+	artifact::verify_artifact, error::PrepareError, prepare::PrepareStats, pvf::PvfPrepData,
+};
 use polkadot_parachain_primitives::primitives::ValidationCodeHash;
 use polkadot_primitives::ExecutorParamsHash;
 use std::{
@@ -175,12 +179,38 @@ impl Artifacts {
 		self.inner.len()
 	}
 
-	/// Create an empty table and the cache directory on-disk if it doesn't exist.
-	pub async fn new(cache_path: &Path) -> Self {
+	/// Create the cache directory on-disk if it doesn't exist and fill the table with the valid
+	/// artifacts found in it.
+	///
+	/// An artifact is valid if its checksum matches and if it was compiled by the same node
+	/// version. Invalid artifacts are removed.
+	pub async fn new(cache_path: &Path, node_version: Option<&str>) -> Self {
 		// Make sure that the cache path directory and all its parents are created.
 		let _ = tokio::fs::create_dir_all(cache_path).await;
 
-		// Delete any leftover artifacts and worker dirs from previous runs. We don't delete the
+		// Reading the artifacts and verifying their checksums is blocking.
+		let cache_path = cache_path.to_owned();
+		let node_version = node_version.map(ToOwned::to_owned);
+		let inner = tokio::task::spawn_blocking(move || {
+			Self::load_cache(&cache_path, node_version.as_deref())
+		})
+		.await
+		.unwrap_or_else(|err| {
+			gum::warn!(target: LOG_TARGET, "failed to load the artifacts cache: {}", err);
+			HashMap::new()
+		});
+
+		Self { inner }
+	}
+
+	/// Read the valid artifacts of the cache directory, removing the other files.
+	fn load_cache(
+		cache_path: &Path,
+		node_version: Option<&str>,
+	) -> HashMap<ArtifactId, ArtifactState> {
+		let mut inner = HashMap::new();
+
+		// Delete any invalid artifacts and worker dirs from previous runs. We don't delete the
 		// entire cache directory in case the user made a mistake and set it to e.g. their home
 		// directory. This is a best-effort to do clean-up, so ignore any errors.
 		for entry in fs::read_dir(cache_path).into_iter().flatten().flatten() {
@@ -188,14 +218,34 @@ impl Artifacts {
 			let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else { continue };
 			if path.is_dir() && file_name.starts_with(WORKER_DIR_PREFIX) {
 				let _ = fs::remove_dir_all(path);
-			} else if path.extension().map_or(false, |ext| ext == ARTIFACT_EXTENSION) ||
-				file_name.starts_with(ARTIFACT_OLD_PREFIX)
-			{
+			} else if file_name.starts_with(ARTIFACT_OLD_PREFIX) {
 				let _ = fs::remove_file(path);
+			} else if path.extension().map_or(false, |ext| ext == ARTIFACT_EXTENSION) {
+				match load_artifact(&path, node_version) {
+					Some(id) if !inner.contains_key(&id) => {
+						gum::debug!(
+							target: LOG_TARGET,
+							?id,
+							path = %path.display(),
+							"reusing cached artifact",
+						);
+						inner.insert(
+							id,
+							ArtifactState::Prepared {
+								path,
+								last_time_needed: SystemTime::now(),
+								prepare_stats: PrepareStats::default(),
+							},
+						);
+					},
+					_ => {
+						let _ = fs::remove_file(path);
+					},
+				}
 			}
 		}
 
-		Self { inner: HashMap::new() }
+		inner
 	}
 
 	/// Returns the state of the given artifact by its ID.
@@ -263,6 +313,51 @@ impl Artifacts {
 	}
 }
 
+/// Reads the artifact at the given path and returns its ID if it can be reused.
+fn load_artifact(path: &Path, node_version: Option<&str>) -> Option<ArtifactId> {
+	let bytes = match fs::read(path) {
+		Ok(bytes) => bytes,
+		Err(err) => {
+			gum::warn!(
+				target: LOG_TARGET,
+				path = %path.display(),
+				"failed to read cached artifact: {}",
+				err,
+			);
+			return None
+		},
+	};
+	let header = match verify_artifact(&bytes) {
+		Ok((header, _)) => header,
+		Err(err) => {
+			gum::debug!(
+				target: LOG_TARGET,
+				path = %path.display(),
+				"discarding invalid cached artifact: {}",
+				err,
+			);
+			return None
+		},
+	};
+	// Without a version on either side, there is no telling whether the artifact was compiled by
+	// the same node.
+	let same_version = match (header.node_version.as_deref(), node_version) {
+		(Some(artifact_node_version), Some(node_version)) => artifact_node_version == node_version,
+		_ => false,
+	};
+	if !same_version {
+		gum::debug!(
+			target: LOG_TARGET,
+			path = %path.display(),
+			artifact_node_version = ?header.node_version,
+			?node_version,
+			"discarding cached artifact compiled by another node version",
+		);
+		return None
+	}
+	Some(ArtifactId::new(header.code_hash, header.executor_params.hash()))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -282,7 +377,7 @@ mod tests {
 		fs::write(cache_path.join("polkadot_..."), "test").unwrap();
 		fs::create_dir(cache_path.join("worker-prepare-test")).unwrap();
 
-		let artifacts = Artifacts::new(cache_path).await;
+		let artifacts = Artifacts::new(cache_path, None).await;
 
 		let entries: Vec<String> = fs::read_dir(&cache_path)
 			.unwrap()
@@ -294,4 +389,87 @@ mod tests {
 		assert!(entries.contains(&String::from("worker-prepare-test")));
 		assert_eq!(artifacts.len(), 0);
 	}
+
+	#[tokio::test]
+	async fn valid_artifacts_kept_on_startup() {
+		use polkadot_node_core_pvf_common::artifact::ArtifactHeader;
+		use polkadot_primitives::ExecutorParams;
+
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path();
+		let code = b"compiled code";
+
+		let write = |name: &str, node_version: Option<&str>, code_hash: u8| {
+			let header = ArtifactHeader::new(
+				node_version,
+				ValidationCodeHash::from(sp_core::H256::repeat_byte(code_hash)),
+				ExecutorParams::default(),
+				code,
+			);
+			let file = fs::File::create(cache_path.join(name)).unwrap();
+			header.write_artifact(file, code).unwrap();
+		};
+
+		// Kept.
+		write("valid.pvf", Some("1.0.0"), 1);
+		// Removed, compiled by another node version.
+		write("other-version.pvf", Some("0.9.0"), 2);
+		// Removed, compiled by a node without a version.
+		write("no-version.pvf", None, 4);
+		// Removed, the compiled code doesn't match the checksum.
+		write("corrupted.pvf", Some("1.0.0"), 3);
+		let mut corrupted = fs::read(cache_path.join("corrupted.pvf")).unwrap();
+		*corrupted.last_mut().unwrap() ^= 1;
+		fs::write(cache_path.join("corrupted.pvf"), corrupted).unwrap();
+		// Removed, no header.
+		fs::write(cache_path.join("no-header.pvf"), code).unwrap();
+
+		let mut artifacts = Artifacts::new(cache_path, Some("1.0.0")).await;
+
+		let entries: Vec<String> = fs::read_dir(&cache_path)
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name().into_string().unwrap())
+			.collect();
+		assert_eq!(entries, vec![String::from("valid.pvf")]);
+		assert_eq!(artifacts.len(), 1);
+
+		let id = ArtifactId::new(
+			ValidationCodeHash::from(sp_core::H256::repeat_byte(1)),
+			ExecutorParams::default().hash(),
+		);
+		assert_matches::assert_matches!(
+			artifacts.artifact_state_mut(&id),
+			Some(ArtifactState::Prepared { path, .. }) if *path == cache_path.join("valid.pvf")
+		);
+	}
+
+	#[tokio::test]
+	async fn artifacts_removed_on_startup_without_node_version() {
+		use polkadot_node_core_pvf_common::artifact::ArtifactHeader;
+		use polkadot_primitives::ExecutorParams;
+
+		let tempdir = tempfile::tempdir().unwrap();
+		let cache_path = tempdir.path();
+		let code = b"compiled code";
+
+		let write = |name: &str, node_version: Option<&str>, code_hash: u8| {
+			let header = ArtifactHeader::new(
+				node_version,
+				ValidationCodeHash::from(sp_core::H256::repeat_byte(code_hash)),
+				ExecutorParams::default(),
+				code,
+			);
+			let file = fs::File::create(cache_path.join(name)).unwrap();
+			header.write_artifact(file, code).unwrap();
+		};
+
+		// Neither is reused by a node without a version, even the one compiled without a version.
+		write("versioned.pvf", Some("1.0.0"), 1);
+		write("no-version.pvf", None, 2);
+
+		let artifacts = Artifacts::new(cache_path, None).await;
+
+		assert_eq!(fs::read_dir(&cache_path).unwrap().count(), 0);
+		assert_eq!(artifacts.len(), 0);
+	}
 }
@@ -180,6 +180,36 @@ async fn execute_job_terminates_on_timeout() {
 	assert!(duration < TEST_EXECUTION_TIMEOUT * JOB_TIMEOUT_WALL_CLOCK_FACTOR);
 }
 
+#[tokio::test]
+async fn execute_job_exhausting_fuel_is_invalid() {
+	let host = TestHost::new().await;
+
+	let start = std::time::Instant::now();
+	let result = host
+		.validate_candidate(
+			halt::wasm_binary_unwrap(),
+			ValidationParams {
+				block_data: BlockData(Vec::new()),
+				parent_head: Default::default(),
+				relay_parent_number: 1,
+				relay_parent_storage_root: Default::default(),
+			},
+			ExecutorParams::from(&[ExecutorParam::PvfExecFuelLimit(1_000_000)][..]),
+		)
+		.await;
+
+	match result {
+		Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err))) => {
+			assert!(err.contains("all fuel consumed by WebAssembly"), "{}", err);
+		},
+		r => panic!("{:?}", r),
+	}
+
+	// The fuel is exhausted long before the timeout.
+	let duration = std::time::Instant::now().duration_since(start);
+	assert!(duration < TEST_EXECUTION_TIMEOUT);
+}
+
 #[cfg(feature = "ci-only-tests")]
 #[tokio::test]
 async fn ensure_parallel_execution() {
@@ -359,7 +389,7 @@ async fn deleting_prepared_artifact_does_not_dispute() {
 }
 
 #[tokio::test]
-async fn cache_cleared_on_startup() {
+async fn artifact_kept_on_startup() {
 	// Don't drop this host, it owns the `TempDir` which gets cleared on drop.
 	let host = TestHost::new().await;
 
@@ -369,12 +399,16 @@ async fn cache_cleared_on_startup() {
 	let cache_dir = host.cache_dir.path().to_owned();
 	assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 2);
 
-	// Start a new host, previous artifact should be cleared.
-	let _host = TestHost::new_with_config(|cfg| {
+	// Start a new host, the worker dir should be cleared but the artifact should be kept.
+	let host = TestHost::new_with_config(|cfg| {
 		cfg.cache_path = cache_dir.clone();
 	})
 	.await;
-	assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 0);
+	assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+	// The artifact is reused, no new one is prepared.
+	let _stats = host.precheck_pvf(halt::wasm_binary_unwrap(), Default::default()).await.unwrap();
+	assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
 }
 
 // This test checks if the adder parachain runtime can be prepared with 10Mb preparation memory
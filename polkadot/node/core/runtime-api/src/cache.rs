@@ -63,6 +63,8 @@ pub(crate) struct RequestResultCache {
 	version: LruMap<Hash, u32>,
 	disputes: LruMap<Hash, Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>>,
 	unapplied_slashes: LruMap<Hash, Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>>,
+	unapplied_backer_slashes:
+		LruMap<Hash, Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>>,
 	key_ownership_proof: LruMap<(Hash, ValidatorId), Option<slashing::OpaqueKeyOwnershipProof>>,
 	minimum_backing_votes: LruMap<SessionIndex, u32>,
 	disabled_validators: LruMap<Hash, Vec<ValidatorIndex>>,
@@ -98,6 +100,7 @@ impl Default for RequestResultCache {
 			version: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			disputes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			unapplied_slashes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
+			unapplied_backer_slashes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			key_ownership_proof: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			minimum_backing_votes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			approval_voting_params: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
@@ -416,6 +419,21 @@ impl RequestResultCache {
 		self.unapplied_slashes.insert(relay_parent, value);
 	}
 
+	pub(crate) fn unapplied_backer_slashes(
+		&mut self,
+		relay_parent: &Hash,
+	) -> Option<&Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>> {
+		self.unapplied_backer_slashes.get(relay_parent).map(|v| &*v)
+	}
+
+	pub(crate) fn cache_unapplied_backer_slashes(
+		&mut self,
+		relay_parent: Hash,
+		value: Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>,
+	) {
+		self.unapplied_backer_slashes.insert(relay_parent, value);
+	}
+
 	pub(crate) fn key_ownership_proof(
 		&mut self,
 		key: (Hash, ValidatorId),
@@ -564,6 +582,7 @@ pub(crate) enum RequestResult {
 	Version(Hash, u32),
 	Disputes(Hash, Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>),
 	UnappliedSlashes(Hash, Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>),
+	UnappliedBackerSlashes(Hash, Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>),
 	KeyOwnershipProof(Hash, ValidatorId, Option<slashing::OpaqueKeyOwnershipProof>),
 	// This is a request with side-effects.
 	SubmitReportDisputeLost(
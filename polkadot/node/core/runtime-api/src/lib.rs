@@ -162,6 +162,9 @@ where
 				self.requests_cache.cache_disputes(relay_parent, disputes),
 			UnappliedSlashes(relay_parent, unapplied_slashes) =>
 				self.requests_cache.cache_unapplied_slashes(relay_parent, unapplied_slashes),
+			UnappliedBackerSlashes(relay_parent, unapplied_slashes) => self
+				.requests_cache
+				.cache_unapplied_backer_slashes(relay_parent, unapplied_slashes),
 			KeyOwnershipProof(relay_parent, validator_id, key_ownership_proof) => self
 				.requests_cache
 				.cache_key_ownership_proof((relay_parent, validator_id), key_ownership_proof),
@@ -293,6 +296,8 @@ where
 				query!(disputes(), sender).map(|sender| Request::Disputes(sender)),
 			Request::UnappliedSlashes(sender) =>
 				query!(unapplied_slashes(), sender).map(|sender| Request::UnappliedSlashes(sender)),
+			Request::UnappliedBackerSlashes(sender) => query!(unapplied_backer_slashes(), sender)
+				.map(|sender| Request::UnappliedBackerSlashes(sender)),
 			Request::KeyOwnershipProof(validator_id, sender) =>
 				query!(key_ownership_proof(validator_id), sender)
 					.map(|sender| Request::KeyOwnershipProof(validator_id, sender)),
@@ -570,6 +575,12 @@ where
 			ver = Request::UNAPPLIED_SLASHES_RUNTIME_REQUIREMENT,
 			sender
 		),
+		Request::UnappliedBackerSlashes(sender) => query!(
+			UnappliedBackerSlashes,
+			unapplied_backer_slashes(),
+			ver = Request::UNAPPLIED_BACKER_SLASHES_RUNTIME_REQUIREMENT,
+			sender
+		),
 		Request::KeyOwnershipProof(validator_id, sender) => query!(
 			KeyOwnershipProof,
 			key_ownership_proof(validator_id),
@@ -40,6 +40,14 @@ enum NemesisVariant {
 	DisputeAncestor(DisputeAncestorOptions),
 	/// Delayed disputing of finalized candidates.
 	DisputeFinalizedCandidates(DisputeFinalizedCandidatesOptions),
+	/// Drop or delay collations instead of passing them on to backing.
+	WithholdCollations(WithholdCollationsOptions),
+	/// Refuse to serve availability chunks to other validators.
+	WithholdAvailabilityChunks(WithholdAvailabilityChunksOptions),
+	/// Send invalid approval assignments along with our own.
+	SpamApprovalAssignments(SpamApprovalAssignmentsOptions),
+	/// Second a conflicting candidate for every seconded candidate.
+	EquivocatingStatements(EquivocatingStatementsOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -98,6 +106,38 @@ impl MalusCli {
 					finality_delay,
 				)?
 			},
+			NemesisVariant::WithholdCollations(opts) => {
+				let WithholdCollationsOptions { percentage, delay_ms, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					WithholdCollations { percentage, delay_ms },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::WithholdAvailabilityChunks(opts) => {
+				let WithholdAvailabilityChunksOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					WithholdAvailabilityChunks { percentage },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::SpamApprovalAssignments(opts) => {
+				let SpamApprovalAssignmentsOptions { spam_factor, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					SpamApprovalAssignments { spam_factor },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::EquivocatingStatements(opts) => {
+				let EquivocatingStatementsOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(cli, EquivocatingStatements { percentage }, finality_delay)?
+			},
 		}
 		Ok(())
 	}
@@ -237,4 +277,111 @@ mod tests {
 			assert!(opts.cli.run.base.bob);
 		});
 	}
+
+	#[test]
+	fn withhold_collations_delay_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-collations",
+			"--percentage",
+			"50",
+			"--delay-ms",
+			"2000",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdCollations(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 50);
+			assert_eq!(opts.delay_ms, Some(2000));
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn withhold_collations_drops_by_default() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-collations",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdCollations(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 100);
+			assert_eq!(opts.delay_ms, None);
+		});
+	}
+
+	#[test]
+	fn withhold_availability_chunks_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-availability-chunks",
+			"--percentage",
+			"30",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdAvailabilityChunks(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 30);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn spam_approval_assignments_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"spam-approval-assignments",
+			"--spam-factor",
+			"5",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::SpamApprovalAssignments(opts),
+			..
+		} => {
+			assert_eq!(opts.spam_factor, 5);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	#[should_panic]
+	fn validate_range_for_spam_factor() {
+		MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"spam-approval-assignments",
+			"--spam-factor",
+			"0",
+			"--bob",
+		]))
+		.unwrap();
+	}
+
+	#[test]
+	fn equivocating_statements_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"equivocating-statements",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::EquivocatingStatements(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 100);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
 }
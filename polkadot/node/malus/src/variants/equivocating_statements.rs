@@ -0,0 +1,222 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that equivocates in backing.
+//!
+//! Whenever the node seconds a candidate, it additionally seconds a conflicting candidate with
+//! the same relay parent, which differs from the original one only in its head data. Both
+//! statements are signed with the validator key of the node and shared with its peers.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, Handle, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_primitives::{SignedFullStatementWithPVD, StatementWithPVD};
+use polkadot_node_subsystem::{messages::StatementDistributionMessage, SpawnGlue};
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use polkadot_node_subsystem_util::{request_session_index_for_child, request_validators};
+use polkadot_primitives::{CandidateHash, SigningContext};
+use sp_core::traits::SpawnNamed;
+use sp_keystore::KeystorePtr;
+
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+};
+
+/// Follows every local `Seconded` statement with a conflicting one.
+#[derive(Clone)]
+struct Equivocate<Spawner> {
+	spawner: Spawner,
+	overseer: Handle,
+	keystore: KeystorePtr,
+	percentage: f64,
+	/// Conflicting candidates whose statements are about to be shared.
+	forged: Arc<Mutex<HashSet<CandidateHash>>>,
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for Equivocate<Spawner>
+where
+	Sender: overseer::StatementDistributionSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = StatementDistributionMessage;
+
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: StatementDistributionMessage::Share(relay_parent, ref statement),
+			} => {
+				let StatementWithPVD::Seconded(candidate, pvd) = statement.payload() else {
+					return Some(msg)
+				};
+				if self.forged.lock().expect("poisoned lock").remove(&candidate.hash()) {
+					return Some(msg)
+				}
+
+				let distribution = Bernoulli::new(self.percentage / 100.0)
+					.expect("Invalid probability! Percentage must be in range [0..=100].");
+				if !distribution.sample(&mut rand::thread_rng()) {
+					return Some(msg)
+				}
+
+				let mut forged_candidate = candidate.clone();
+				forged_candidate.commitments.head_data.0.push(0);
+				forged_candidate.descriptor.para_head =
+					forged_candidate.commitments.head_data.hash();
+				let forged_statement = StatementWithPVD::Seconded(forged_candidate, pvd.clone());
+				let validator_index = statement.validator_index();
+
+				let mut sender = subsystem_sender.clone();
+				let mut overseer = self.overseer.clone();
+				let keystore = self.keystore.clone();
+				let forged = self.forged.clone();
+				self.spawner.spawn(
+					"malus-equivocate",
+					Some("malus"),
+					Box::pin(async move {
+						let session_index =
+							match request_session_index_for_child(relay_parent, &mut sender)
+								.await
+								.await
+							{
+								Ok(Ok(session_index)) => session_index,
+								_ => {
+									gum::error!(target: MALUS, "😈 Failed to fetch session index.");
+									return
+								},
+							};
+						let validator_key =
+							match request_validators(relay_parent, &mut sender).await.await {
+								Ok(Ok(validators)) =>
+									validators.get(validator_index.0 as usize).cloned(),
+								_ => None,
+							};
+						let Some(validator_key) = validator_key else {
+							gum::error!(target: MALUS, "😈 Failed to fetch our validator key.");
+							return
+						};
+
+						let signing_context =
+							SigningContext { session_index, parent_hash: relay_parent };
+						let signed = SignedFullStatementWithPVD::sign(
+							&keystore,
+							forged_statement,
+							&signing_context,
+							validator_index,
+							&validator_key,
+						);
+						let Ok(Some(signed)) = signed else {
+							gum::error!(target: MALUS, "😈 Failed to sign conflicting statement.");
+							return
+						};
+
+						let forged_hash = signed.payload().candidate_hash();
+						gum::info!(
+							target: MALUS,
+							?relay_parent,
+							?forged_hash,
+							"😈 Seconding conflicting candidate.",
+						);
+						forged.lock().expect("poisoned lock").insert(forged_hash);
+						overseer
+							.send_msg(
+								StatementDistributionMessage::Share(relay_parent, signed),
+								MALUS,
+							)
+							.await;
+					}),
+				);
+
+				Some(msg)
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct EquivocatingStatementsOptions {
+	/// Determines the percentage of seconded candidates for which a conflicting candidate is
+	/// seconded as well. Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// EquivocatingStatements implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct EquivocatingStatements {
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for EquivocatingStatements {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node equivocating on {:?} percent of seconded candidates.",
+			&self.percentage,
+		);
+
+		let equivocate = Equivocate {
+			spawner: SpawnGlue(args.spawner.clone()),
+			overseer: Handle::new(connector.handle()),
+			keystore: args.keystore.clone(),
+			percentage: f64::from(self.percentage),
+			forged: Default::default(),
+		};
+
+		prepared_overseer_builder(args)?
+			.replace_statement_distribution(move |sd| InterceptedSubsystem::new(sd, equivocate))
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
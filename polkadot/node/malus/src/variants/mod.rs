@@ -20,14 +20,22 @@ mod back_garbage_candidate;
 mod common;
 mod dispute_finalized_candidates;
 mod dispute_valid_candidates;
+mod equivocating_statements;
+mod spam_approval_assignments;
 mod suggest_garbage_candidate;
 mod support_disabled;
+mod withhold_availability_chunks;
+mod withhold_collations;
 
 pub(crate) use self::{
 	back_garbage_candidate::{BackGarbageCandidateOptions, BackGarbageCandidates},
 	dispute_finalized_candidates::{DisputeFinalizedCandidates, DisputeFinalizedCandidatesOptions},
 	dispute_valid_candidates::{DisputeAncestorOptions, DisputeValidCandidates},
+	equivocating_statements::{EquivocatingStatements, EquivocatingStatementsOptions},
+	spam_approval_assignments::{SpamApprovalAssignments, SpamApprovalAssignmentsOptions},
 	suggest_garbage_candidate::{SuggestGarbageCandidateOptions, SuggestGarbageCandidates},
 	support_disabled::{SupportDisabled, SupportDisabledOptions},
+	withhold_availability_chunks::{WithholdAvailabilityChunks, WithholdAvailabilityChunksOptions},
+	withhold_collations::{WithholdCollations, WithholdCollationsOptions},
 };
 pub(crate) use common::*;
@@ -0,0 +1,190 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that floods its peers with invalid approval assignments.
+//!
+//! Every assignment of the node is distributed as usual, followed by a configurable number of
+//! copies claiming to originate from other validator indices. The certificates of those copies
+//! don't verify against the keys of the claimed validators, so honest peers have to reject them.
+//!
+//! As approval distribution imports local assignments without checks, the node itself considers
+//! the forged assignments known and won't import the genuine ones of the impersonated validators.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, Handle, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_primitives::approval::v2::{CandidateBitfield, IndirectAssignmentCertV2};
+use polkadot_node_subsystem::{messages::ApprovalDistributionMessage, SpawnGlue};
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use polkadot_primitives::ValidatorIndex;
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::sync::{Arc, Mutex};
+
+/// Follows every local assignment with forged copies of it.
+#[derive(Clone)]
+struct SpamAssignments<Spawner> {
+	spawner: Spawner,
+	overseer: Handle,
+	spam_factor: u32,
+	/// Forged assignments which are about to be distributed.
+	///
+	/// The node may send several assignments of a validator for the same block, claiming
+	/// different candidates, so the whole assignment is kept. The same forged assignment can be
+	/// pending more than once.
+	forged: Arc<Mutex<Vec<(IndirectAssignmentCertV2, CandidateBitfield)>>>,
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for SpamAssignments<Spawner>
+where
+	Sender: overseer::ApprovalDistributionSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = ApprovalDistributionMessage;
+
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: ApprovalDistributionMessage::DistributeAssignment(ref cert, ref candidates),
+			} => {
+				{
+					let mut forged = self.forged.lock().expect("poisoned lock");
+					if let Some(index) =
+						forged.iter().position(|(forged_cert, forged_candidates)| {
+							forged_cert == cert && forged_candidates == candidates
+						}) {
+						forged.swap_remove(index);
+						return Some(msg)
+					}
+				}
+
+				gum::debug!(
+					target: MALUS,
+					block_hash = ?cert.block_hash,
+					spam_factor = self.spam_factor,
+					"😈 Spamming forged approval assignments.",
+				);
+
+				let forged_assignments = (1..=self.spam_factor)
+					.map(|offset| IndirectAssignmentCertV2 {
+						block_hash: cert.block_hash,
+						validator: ValidatorIndex(cert.validator.0.wrapping_add(offset)),
+						cert: cert.cert.clone(),
+					})
+					.collect::<Vec<_>>();
+				self.forged.lock().expect("poisoned lock").extend(
+					forged_assignments.iter().map(|forged| (forged.clone(), candidates.clone())),
+				);
+
+				let mut overseer = self.overseer.clone();
+				let candidates = candidates.clone();
+				self.spawner.spawn(
+					"malus-spam-assignments",
+					Some("malus"),
+					Box::pin(async move {
+						for forged in forged_assignments {
+							overseer
+								.send_msg(
+									ApprovalDistributionMessage::DistributeAssignment(
+										forged,
+										candidates.clone(),
+									),
+									MALUS,
+								)
+								.await;
+						}
+					}),
+				);
+
+				Some(msg)
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct SpamApprovalAssignmentsOptions {
+	/// Number of forged assignments sent along with every assignment of the node.
+	/// Must be in the range [1..=100].
+	#[clap(long, ignore_case = true, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=100))]
+	pub spam_factor: u32,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// SpamApprovalAssignments implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct SpamApprovalAssignments {
+	/// Number of forged assignments per genuine one.
+	pub spam_factor: u32,
+}
+
+impl OverseerGen for SpamApprovalAssignments {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node sending {:?} forged assignments per assignment.",
+			&self.spam_factor,
+		);
+
+		let spam_assignments = SpamAssignments {
+			spawner: SpawnGlue(args.spawner.clone()),
+			overseer: Handle::new(connector.handle()),
+			spam_factor: self.spam_factor,
+			forged: Default::default(),
+		};
+
+		prepared_overseer_builder(args)?
+			.replace_approval_distribution(move |ad| {
+				InterceptedSubsystem::new(ad, spam_assignments)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
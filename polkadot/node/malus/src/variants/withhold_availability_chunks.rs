@@ -0,0 +1,147 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that keeps its availability chunks to itself.
+//!
+//! Chunks are still fetched, stored and attested in availability bitfields, but chunk and full
+//! data queries to the availability store are answered as if nothing was stored. As those
+//! queries are used to serve requests of other validators, the data can't be recovered from
+//! this node.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use sp_core::traits::SpawnNamed;
+
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::sync::Arc;
+
+/// Answers chunk and available data queries with `None`.
+#[derive(Clone)]
+struct WithholdChunks {
+	percentage: f64,
+}
+
+impl WithholdChunks {
+	fn withhold(&self) -> bool {
+		Bernoulli::new(self.percentage / 100.0)
+			.expect("Invalid probability! Percentage must be in range [0..=100].")
+			.sample(&mut rand::thread_rng())
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for WithholdChunks
+where
+	Sender: overseer::AvailabilityStoreSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityStoreMessage;
+
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryChunk(candidate_hash, validator_index, tx),
+			} if self.withhold() => {
+				gum::debug!(
+					target: MALUS,
+					?candidate_hash,
+					?validator_index,
+					"😈 Withholding availability chunk.",
+				);
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAvailableData(candidate_hash, tx),
+			} if self.withhold() => {
+				gum::debug!(target: MALUS, ?candidate_hash, "😈 Withholding available data.",);
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdAvailabilityChunksOptions {
+	/// Determines the percentage of chunk and available data queries which are answered as if
+	/// the data was missing. Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// WithholdAvailabilityChunks implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct WithholdAvailabilityChunks {
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for WithholdAvailabilityChunks {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node withholding {:?} percent of availability chunks.",
+			&self.percentage,
+		);
+
+		let withhold_chunks = WithholdChunks { percentage: f64::from(self.percentage) };
+
+		prepared_overseer_builder(args)?
+			.replace_availability_store(move |av_store| {
+				InterceptedSubsystem::new(av_store, withhold_chunks)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -0,0 +1,193 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that drops or delays the collations it fetched from collators,
+//! instead of passing them on to backing right away.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, Handle, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_subsystem::{messages::CandidateBackingMessage, SpawnGlue};
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use polkadot_primitives::CandidateHash;
+use sp_core::traits::SpawnNamed;
+
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+/// Drops or delays incoming `Second` requests of the `collator-protocol` subsystem.
+#[derive(Clone)]
+struct WithholdCollation<Spawner> {
+	spawner: Spawner,
+	overseer: Handle,
+	percentage: f64,
+	delay: Option<Duration>,
+	/// Collations which were delayed and are about to be handed to backing again.
+	released: Arc<Mutex<HashSet<CandidateHash>>>,
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for WithholdCollation<Spawner>
+where
+	Sender: overseer::CandidateBackingSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = CandidateBackingMessage;
+
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: CandidateBackingMessage::Second(relay_parent, candidate, pvd, pov),
+			} => {
+				let candidate_hash = candidate.hash();
+				if self.released.lock().expect("poisoned lock").remove(&candidate_hash) {
+					return Some(FromOrchestra::Communication {
+						msg: CandidateBackingMessage::Second(relay_parent, candidate, pvd, pov),
+					})
+				}
+
+				let distribution = Bernoulli::new(self.percentage / 100.0)
+					.expect("Invalid probability! Percentage must be in range [0..=100].");
+				if !distribution.sample(&mut rand::thread_rng()) {
+					return Some(FromOrchestra::Communication {
+						msg: CandidateBackingMessage::Second(relay_parent, candidate, pvd, pov),
+					})
+				}
+
+				let Some(delay) = self.delay else {
+					gum::info!(
+						target: MALUS,
+						?candidate_hash,
+						?relay_parent,
+						"😈 Dropping collation.",
+					);
+					return None
+				};
+
+				gum::info!(
+					target: MALUS,
+					?candidate_hash,
+					?relay_parent,
+					?delay,
+					"😈 Delaying collation.",
+				);
+
+				let mut overseer = self.overseer.clone();
+				let released = self.released.clone();
+				self.spawner.spawn(
+					"malus-delay-collation",
+					Some("malus"),
+					Box::pin(async move {
+						futures_timer::Delay::new(delay).await;
+						released.lock().expect("poisoned lock").insert(candidate_hash);
+						overseer
+							.send_msg(
+								CandidateBackingMessage::Second(relay_parent, candidate, pvd, pov),
+								MALUS,
+							)
+							.await;
+					}),
+				);
+				None
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdCollationsOptions {
+	/// Determines the percentage of fetched collations which are withheld from backing.
+	/// Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	/// Delay in milliseconds after which withheld collations are passed on to backing. If not
+	/// set, withheld collations are dropped.
+	#[clap(long)]
+	pub delay_ms: Option<u64>,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// WithholdCollations implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct WithholdCollations {
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+	/// The delay after which withheld collations are released, if any.
+	pub delay_ms: Option<u64>,
+}
+
+impl OverseerGen for WithholdCollations {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node withholding {:?} percent of collations (delay: {:?} ms).",
+			&self.percentage,
+			&self.delay_ms,
+		);
+
+		let withhold_collation = WithholdCollation {
+			spawner: SpawnGlue(args.spawner.clone()),
+			overseer: Handle::new(connector.handle()),
+			percentage: f64::from(self.percentage),
+			delay: self.delay_ms.map(Duration::from_millis),
+			released: Default::default(),
+		};
+
+		prepared_overseer_builder(args)?
+			.replace_candidate_backing(move |cb| InterceptedSubsystem::new(cb, withhold_collation))
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -33,6 +33,7 @@ use polkadot_node_subsystem::{
 	messages::{AvailabilityStoreMessage, IfDisconnected, NetworkBridgeTxMessage},
 	overseer,
 };
+use polkadot_node_subsystem_util::availability_chunks::availability_chunk_index;
 use polkadot_primitives::{
 	AuthorityDiscoveryId, BlakeTwo256, CandidateHash, GroupIndex, Hash, HashT, OccupiedCore,
 	SessionIndex,
@@ -167,7 +168,12 @@ impl FetchTaskConfig {
 				.clone(),
 			request: ChunkFetchingRequest {
 				candidate_hash: core.candidate_hash,
-				index: session_info.our_index,
+				index: availability_chunk_index(
+					Some(&session_info.node_features),
+					session_info.n_validators,
+					&core.candidate_hash,
+					session_info.our_index,
+				),
 			},
 			erasure_root: core.candidate_descriptor.erasure_root,
 			relay_parent: core.candidate_descriptor.relay_parent,
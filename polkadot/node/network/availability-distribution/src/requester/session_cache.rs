@@ -21,7 +21,9 @@ use schnellru::{ByLength, LruMap};
 
 use polkadot_node_subsystem::overseer;
 use polkadot_node_subsystem_util::runtime::RuntimeInfo;
-use polkadot_primitives::{AuthorityDiscoveryId, GroupIndex, Hash, SessionIndex, ValidatorIndex};
+use polkadot_primitives::{
+	vstaging::NodeFeatures, AuthorityDiscoveryId, GroupIndex, Hash, SessionIndex, ValidatorIndex,
+};
 
 use crate::{
 	error::{Error, Result},
@@ -62,6 +64,12 @@ pub struct SessionInfo {
 	///
 	/// `None`, if we are not in fact part of any group.
 	pub our_group: Option<GroupIndex>,
+
+	/// Number of validators in the session.
+	pub n_validators: usize,
+
+	/// Node features enabled in the session, determining which chunk we are responsible for.
+	pub node_features: NodeFeatures,
 }
 
 /// Report of bad validators.
@@ -182,6 +190,8 @@ impl SessionCache {
 			.await?;
 
 		let discovery_keys = info.session_info.discovery_keys.clone();
+		let n_validators = info.session_info.validators.len();
+		let node_features = info.node_features.clone();
 		let mut validator_groups = info.session_info.validator_groups.clone();
 
 		if let Some(our_index) = info.validator_info.our_index {
@@ -208,7 +218,14 @@ impl SessionCache {
 				})
 				.collect();
 
-			let info = SessionInfo { validator_groups, our_index, session_index, our_group };
+			let info = SessionInfo {
+				validator_groups,
+				our_index,
+				session_index,
+				our_group,
+				n_validators,
+				node_features,
+			};
 			return Ok(Some(info))
 		}
 		return Ok(None)
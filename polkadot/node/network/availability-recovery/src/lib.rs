@@ -35,11 +35,15 @@ use futures::{
 	task::{Context, Poll},
 };
 use schnellru::{ByLength, LruMap};
-use task::{FetchChunks, FetchChunksParams, FetchFull, FetchFullParams};
+use task::{
+	FetchChunks, FetchChunksParams, FetchFull, FetchFullParams, FetchSystematicChunks,
+	FetchSystematicChunksParams,
+};
 
 use fatality::Nested;
 use polkadot_erasure_coding::{
-	branch_hash, branches, obtain_chunks_v1, recovery_threshold, Error as ErasureEncodingError,
+	branch_hash, branches, obtain_chunks_v1, recovery_threshold, systematic_recovery_threshold,
+	Error as ErasureEncodingError,
 };
 use task::{RecoveryParams, RecoveryStrategy, RecoveryTask};
 
@@ -55,7 +59,11 @@ use polkadot_node_subsystem::{
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem,
 	SubsystemContext, SubsystemError, SubsystemResult,
 };
-use polkadot_node_subsystem_util::request_session_info;
+use polkadot_node_subsystem_util::{
+	availability_chunks::{availability_chunk_holder, availability_chunk_indices},
+	request_session_info,
+	runtime::request_node_features,
+};
 use polkadot_primitives::{
 	BlakeTwo256, BlockNumber, CandidateHash, CandidateReceipt, GroupIndex, Hash, HashT,
 	SessionIndex, SessionInfo, ValidatorIndex,
@@ -90,6 +98,29 @@ pub enum RecoveryStrategyKind {
 	BackersFirstIfSizeLower(usize),
 	/// We always recover using validator chunks.
 	ChunksAlways,
+	/// We try the systematic chunks first, then fallback to regular validator chunks.
+	SystematicChunks,
+	/// We try the backing group first if PoV size is lower than specified, then the systematic
+	/// chunks and finally regular validator chunks.
+	BackersFirstIfSizeLowerThenSystematicChunks(usize),
+}
+
+impl RecoveryStrategyKind {
+	fn small_pov_limit(&self) -> Option<usize> {
+		match self {
+			RecoveryStrategyKind::BackersFirstIfSizeLower(limit) |
+			RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(limit) => Some(*limit),
+			_ => None,
+		}
+	}
+
+	fn uses_systematic_chunks(&self) -> bool {
+		matches!(
+			self,
+			RecoveryStrategyKind::SystematicChunks |
+				RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_)
+		)
+	}
 }
 
 /// The Availability Recovery Subsystem.
@@ -126,6 +157,13 @@ pub enum ErasureTask {
 		HashMap<ValidatorIndex, ErasureChunk>,
 		oneshot::Sender<Result<AvailableData, ErasureEncodingError>>,
 	),
+	/// Reconstructs `AvailableData` from the systematic chunks, ordered by chunk index, given
+	/// `n_validators`.
+	ReconstructFromSystematic(
+		usize,
+		Vec<Vec<u8>>,
+		oneshot::Sender<Result<AvailableData, ErasureEncodingError>>,
+	),
 	/// Re-encode `AvailableData` into erasure chunks in order to verify the provided root hash of
 	/// the Merkle tree.
 	Reencode(usize, Hash, AvailableData, oneshot::Sender<Option<AvailableData>>),
@@ -350,6 +388,7 @@ async fn launch_recovery_task<Context>(
 	state: &mut State,
 	ctx: &mut Context,
 	session_info: SessionInfo,
+	chunk_indices: Vec<ValidatorIndex>,
 	receipt: CandidateReceipt,
 	response_sender: oneshot::Sender<Result<AvailableData, RecoveryError>>,
 	metrics: &Metrics,
@@ -361,6 +400,7 @@ async fn launch_recovery_task<Context>(
 	let params = RecoveryParams {
 		validator_authority_keys: session_info.discovery_keys.clone(),
 		n_validators: session_info.validators.len(),
+		chunk_indices,
 		threshold: recovery_threshold(session_info.validators.len())?,
 		candidate_hash,
 		erasure_root: receipt.descriptor.erasure_root,
@@ -440,6 +480,13 @@ async fn handle_recover<Context>(
 	let _span = span.child("session-info-ctx-received");
 	match session_info {
 		Some(session_info) => {
+			let n_validators = session_info.validators.len();
+			// The node features determine which chunk each validator holds.
+			let node_features =
+				request_node_features(state.live_block.1, session_index, ctx.sender()).await?;
+			let chunk_indices =
+				availability_chunk_indices(node_features.as_ref(), n_validators, &candidate_hash);
+
 			let mut recovery_strategies: VecDeque<
 				Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>,
 			> = VecDeque::with_capacity(2);
@@ -448,15 +495,12 @@ async fn handle_recover<Context>(
 				if let Some(backing_validators) = session_info.validator_groups.get(backing_group) {
 					let mut small_pov_size = true;
 
-					if let RecoveryStrategyKind::BackersFirstIfSizeLower(small_pov_limit) =
-						recovery_strategy_kind
-					{
+					if let Some(small_pov_limit) = recovery_strategy_kind.small_pov_limit() {
 						// Get our own chunk size to get an estimate of the PoV size.
 						let chunk_size: Result<Option<usize>, error::Error> =
 							query_chunk_size(ctx, candidate_hash).await;
 						if let Ok(Some(chunk_size)) = chunk_size {
-							let pov_size_estimate =
-								chunk_size.saturating_mul(session_info.validators.len()) / 3;
+							let pov_size_estimate = chunk_size.saturating_mul(n_validators) / 3;
 							small_pov_size = pov_size_estimate < small_pov_limit;
 
 							gum::trace!(
@@ -476,19 +520,42 @@ async fn handle_recover<Context>(
 
 					match (&recovery_strategy_kind, small_pov_size) {
 						(RecoveryStrategyKind::BackersFirstAlways, _) |
-						(RecoveryStrategyKind::BackersFirstIfSizeLower(_), true) => recovery_strategies.push_back(
-							Box::new(FetchFull::new(FetchFullParams {
+						(RecoveryStrategyKind::BackersFirstIfSizeLower(_), true) |
+						(
+							RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_),
+							true,
+						) => recovery_strategies.push_back(Box::new(FetchFull::new(
+							FetchFullParams {
 								validators: backing_validators.to_vec(),
 								erasure_task_tx: erasure_task_tx.clone(),
-							})),
-						),
+							},
+						))),
 						_ => {},
 					};
 				}
 			}
 
+			if recovery_strategy_kind.uses_systematic_chunks() {
+				let systematic_threshold = systematic_recovery_threshold(n_validators)?;
+				recovery_strategies.push_back(Box::new(FetchSystematicChunks::new(
+					FetchSystematicChunksParams {
+						validators: (0..systematic_threshold)
+							.map(|i| {
+								availability_chunk_holder(
+									node_features.as_ref(),
+									n_validators,
+									&candidate_hash,
+									ValidatorIndex(i as u32),
+								)
+							})
+							.collect(),
+						erasure_task_tx: erasure_task_tx.clone(),
+					},
+				)));
+			}
+
 			recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
-				n_validators: session_info.validators.len(),
+				n_validators,
 				erasure_task_tx,
 			})));
 
@@ -496,6 +563,7 @@ async fn handle_recover<Context>(
 				state,
 				ctx,
 				session_info,
+				chunk_indices,
 				receipt,
 				response_sender,
 				metrics,
@@ -588,6 +656,37 @@ impl AvailabilityRecoverySubsystem {
 		}
 	}
 
+	/// Create a new instance of `AvailabilityRecoverySubsystem` which requests the systematic
+	/// chunks first and falls back to regular chunks.
+	pub fn with_systematic_chunks(
+		req_receiver: IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
+		metrics: Metrics,
+	) -> Self {
+		Self {
+			recovery_strategy_kind: RecoveryStrategyKind::SystematicChunks,
+			bypass_availability_store: false,
+			post_recovery_check: PostRecoveryCheck::Reencode,
+			req_receiver,
+			metrics,
+		}
+	}
+
+	/// Create a new instance of `AvailabilityRecoverySubsystem` which requests systematic chunks,
+	/// falling back to regular chunks, if PoV is above a threshold.
+	pub fn with_systematic_chunks_if_pov_large(
+		req_receiver: IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
+		metrics: Metrics,
+	) -> Self {
+		Self {
+			recovery_strategy_kind:
+				RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(SMALL_POV_LIMIT),
+			bypass_availability_store: false,
+			post_recovery_check: PostRecoveryCheck::Reencode,
+			req_receiver,
+			metrics,
+		}
+	}
+
 	/// Create a new instance of `AvailabilityRecoverySubsystem` which requests chunks if PoV is
 	/// above a threshold.
 	pub fn with_chunks_if_pov_large(
@@ -825,11 +924,19 @@ async fn erasure_task_thread(
 	loop {
 		match ingress.next().await {
 			Some(ErasureTask::Reconstruct(n_validators, chunks, sender)) => {
+				let _timer = metrics.time_regular_reconstruct();
 				let _ = sender.send(polkadot_erasure_coding::reconstruct_v1(
 					n_validators,
 					chunks.values().map(|c| (&c.chunk[..], c.index.0 as usize)),
 				));
 			},
+			Some(ErasureTask::ReconstructFromSystematic(n_validators, chunks, sender)) => {
+				let _timer = metrics.time_systematic_reconstruct();
+				let _ = sender.send(polkadot_erasure_coding::reconstruct_from_systematic_v1(
+					n_validators,
+					chunks,
+				));
+			},
 			Some(ErasureTask::Reencode(n_validators, root, available_data, sender)) => {
 				let metrics = metrics.clone();
 
@@ -16,7 +16,9 @@
 
 use polkadot_node_subsystem_util::metrics::{
 	self,
-	prometheus::{self, Counter, CounterVec, Histogram, Opts, PrometheusError, Registry, U64},
+	prometheus::{
+		self, Counter, CounterVec, Histogram, HistogramVec, Opts, PrometheusError, Registry, U64,
+	},
 };
 
 /// Availability Distribution metrics.
@@ -49,6 +51,13 @@ struct MetricsInner {
 	/// The duration between the pure recovery and verification.
 	time_erasure_recovery: Histogram,
 
+	/// How much time it takes to reconstruct the data from chunks, without verification.
+	///
+	/// Split by the kind of reconstruction:
+	/// - `regular` ... Reed-Solomon decoding of any sufficient set of chunks.
+	/// - `systematic` ... concatenation of the systematic chunks.
+	time_erasure_reconstruct: HistogramVec,
+
 	/// How much time it takes to re-encode the data into erasure chunks in order to verify
 	/// the root hash of the provided Merkle tree. See `reconstructed_data_matches_root`.
 	time_reencode_chunks: Histogram,
@@ -60,6 +69,11 @@ struct MetricsInner {
 	/// Number of full recoveries that have been finished one way or the other.
 	full_recoveries_finished: CounterVec<U64>,
 
+	/// Number of recoveries from systematic chunks that have been finished one way or the other.
+	///
+	/// Failed ones fall back to regular chunk recovery.
+	systematic_recoveries_finished: CounterVec<U64>,
+
 	/// Number of full recoveries that have been started on this subsystem.
 	///
 	/// Note: Those are only recoveries which could not get served locally already - so in other
@@ -125,6 +139,27 @@ impl Metrics {
 		self.0.as_ref().map(|metrics| metrics.time_erasure_recovery.start_timer())
 	}
 
+	/// Get a timer to time the reconstruction of data from regular chunks.
+	pub fn time_regular_reconstruct(
+		&self,
+	) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
+		self.0.as_ref().map(|metrics| {
+			metrics.time_erasure_reconstruct.with_label_values(&["regular"]).start_timer()
+		})
+	}
+
+	/// Get a timer to time the reconstruction of data from systematic chunks.
+	pub fn time_systematic_reconstruct(
+		&self,
+	) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
+		self.0.as_ref().map(|metrics| {
+			metrics
+				.time_erasure_reconstruct
+				.with_label_values(&["systematic"])
+				.start_timer()
+		})
+	}
+
 	/// Get a timer to time chunk encoding.
 	pub fn time_reencode_chunks(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.time_reencode_chunks.start_timer())
@@ -157,6 +192,27 @@ impl Metrics {
 		}
 	}
 
+	/// A recovery from systematic chunks succeeded.
+	pub fn on_systematic_recovery_succeeded(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.systematic_recoveries_finished.with_label_values(&["success"]).inc()
+		}
+	}
+
+	/// A recovery from systematic chunks failed (not all systematic chunks available).
+	pub fn on_systematic_recovery_failed(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.systematic_recoveries_finished.with_label_values(&["failure"]).inc()
+		}
+	}
+
+	/// A recovery from systematic chunks failed (data was recovered, but invalid).
+	pub fn on_systematic_recovery_invalid(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.systematic_recoveries_finished.with_label_values(&["invalid"]).inc()
+		}
+	}
+
 	/// A recover was started.
 	pub fn on_recovery_started(&self) {
 		if let Some(metrics) = &self.0 {
@@ -206,6 +262,16 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			time_erasure_reconstruct: prometheus::register(
+				HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_availability_recovery_time_erasure_reconstruct",
+						"Time spent to reconstruct the data from chunks, without verification",
+					),
+					&["type"],
+				)?,
+				registry,
+			)?,
 			time_reencode_chunks: prometheus::register(
 				prometheus::Histogram::with_opts(prometheus::HistogramOpts::new(
 					"polkadot_parachain_availability_reencode_chunks",
@@ -230,6 +296,16 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			systematic_recoveries_finished: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_availability_recovery_systematic_recoveries_finished",
+						"Total number of recoveries from systematic chunks that finished.",
+					),
+					&["result"],
+				)?,
+				registry,
+			)?,
 			full_recoveries_started: prometheus::register(
 				Counter::new(
 					"polkadot_parachain_availability_recovery_recovieries_started",
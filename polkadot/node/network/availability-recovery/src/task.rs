@@ -82,6 +82,9 @@ pub struct RecoveryParams {
 	/// Number of validators.
 	pub n_validators: usize,
 
+	/// Index of the chunk held by each validator, indexed by the validator index.
+	pub chunk_indices: Vec<ValidatorIndex>,
+
 	/// The number of chunks needed.
 	pub threshold: usize,
 
@@ -104,6 +107,13 @@ pub struct RecoveryParams {
 	pub pov_hash: Hash,
 }
 
+impl RecoveryParams {
+	/// Index of the chunk held by `validator_index`.
+	fn chunk_index(&self, validator_index: ValidatorIndex) -> ValidatorIndex {
+		self.chunk_indices[validator_index.0 as usize]
+	}
+}
+
 /// Intermediate/common data that must be passed between `RecoveryStrategy`s belonging to the
 /// same `RecoveryTask`.
 pub struct State {
@@ -124,6 +134,14 @@ impl State {
 		self.received_chunks.len()
 	}
 
+	/// Number of received chunks among the first `systematic_threshold` chunks.
+	fn systematic_chunk_count(&self, systematic_threshold: usize) -> usize {
+		self.received_chunks
+			.keys()
+			.filter(|i| (i.0 as usize) < systematic_threshold)
+			.count()
+	}
+
 	/// Retrieve the local chunks held in the av-store (either 0 or 1).
 	async fn populate_from_av_store<Sender: overseer::AvailabilityRecoverySenderTrait>(
 		&mut self,
@@ -204,7 +222,7 @@ impl State {
 				// Request data.
 				let raw_request = req_res::v1::ChunkFetchingRequest {
 					candidate_hash: params.candidate_hash,
-					index: validator_index,
+					index: params.chunk_index(validator_index),
 				};
 
 				let (req, res) = OutgoingRequest::new(Recipient::Authority(validator), raw_request);
@@ -451,6 +469,51 @@ where
 	}
 }
 
+/// Check the data recovered from chunks according to the configured `PostRecoveryCheck`.
+///
+/// Returns `None` if the check failed.
+async fn post_recovery_check(
+	erasure_task_tx: &mut futures::channel::mpsc::Sender<ErasureTask>,
+	common_params: &RecoveryParams,
+	data: AvailableData,
+) -> Result<Option<AvailableData>, RecoveryError> {
+	Ok(match common_params.post_recovery_check {
+		PostRecoveryCheck::Reencode => {
+			// Send request to re-encode the chunks and check merkle root.
+			let (reencode_tx, reencode_rx) = oneshot::channel();
+			erasure_task_tx
+				.send(ErasureTask::Reencode(
+					common_params.n_validators,
+					common_params.erasure_root,
+					data,
+					reencode_tx,
+				))
+				.await
+				.map_err(|_| RecoveryError::ChannelClosed)?;
+
+			reencode_rx.await.map_err(|_| RecoveryError::ChannelClosed)?.or_else(|| {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					"Data recovery error - root mismatch",
+				);
+				None
+			})
+		},
+		PostRecoveryCheck::PovHash =>
+			(data.pov.hash() == common_params.pov_hash).then_some(data).or_else(|| {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					pov_hash = ?common_params.pov_hash,
+					"Data recovery error - PoV hash mismatch",
+				);
+				None
+			}),
+	})
+}
+
 /// `RecoveryStrategy` that sequentially tries to fetch the full `AvailableData` from
 /// already-connected validators in the configured validator set.
 pub struct FetchFull {
@@ -663,41 +726,8 @@ impl FetchChunks {
 
 		match available_data_response {
 			Ok(data) => {
-				let maybe_data = match common_params.post_recovery_check {
-					PostRecoveryCheck::Reencode => {
-						// Send request to re-encode the chunks and check merkle root.
-						let (reencode_tx, reencode_rx) = oneshot::channel();
-						self.erasure_task_tx
-							.send(ErasureTask::Reencode(
-								common_params.n_validators,
-								common_params.erasure_root,
-								data,
-								reencode_tx,
-							))
-							.await
-							.map_err(|_| RecoveryError::ChannelClosed)?;
-
-						reencode_rx.await.map_err(|_| RecoveryError::ChannelClosed)?.or_else(|| {
-							gum::trace!(
-								target: LOG_TARGET,
-								candidate_hash = ?common_params.candidate_hash,
-								erasure_root = ?common_params.erasure_root,
-								"Data recovery error - root mismatch",
-							);
-							None
-						})
-					},
-					PostRecoveryCheck::PovHash =>
-						(data.pov.hash() == common_params.pov_hash).then_some(data).or_else(|| {
-							gum::trace!(
-								target: LOG_TARGET,
-								candidate_hash = ?common_params.candidate_hash,
-								pov_hash = ?common_params.pov_hash,
-								"Data recovery error - PoV hash mismatch",
-							);
-							None
-						}),
-				};
+				let maybe_data =
+					post_recovery_check(&mut self.erasure_task_tx, common_params, data).await?;
 
 				if let Some(data) = maybe_data {
 					gum::trace!(
@@ -745,11 +775,13 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 		// First query the store for any chunks we've got.
 		if !common_params.bypass_availability_store {
 			let local_chunk_indices = state.populate_from_av_store(common_params, sender).await;
-			self.validators.retain(|i| !local_chunk_indices.contains(i));
+			self.validators
+				.retain(|i| !local_chunk_indices.contains(&common_params.chunk_index(*i)));
 		}
 
 		// No need to query the validators that have the chunks we already received.
-		self.validators.retain(|i| !state.received_chunks.contains_key(i));
+		self.validators
+			.retain(|i| !state.received_chunks.contains_key(&common_params.chunk_index(*i)));
 
 		loop {
 			// If received_chunks has more than threshold entries, attempt to recover the data.
@@ -827,6 +859,210 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 	}
 }
 
+/// `RecoveryStrategy` that requests the systematic chunks from their holders, in parallel.
+///
+/// The systematic chunks hold the encoded data itself, so it can be recovered by concatenating
+/// them instead of running the expensive Reed-Solomon decoding. As each systematic chunk is held
+/// by a single validator, the strategy gives up as soon as one of the requests fails. Chunks
+/// received up to that point are kept for the strategies that follow.
+pub struct FetchSystematicChunks {
+	/// Number of systematic chunks needed to recover the data.
+	threshold: usize,
+	/// Validators holding the systematic chunks which were not requested yet.
+	validators: VecDeque<ValidatorIndex>,
+	/// Collection of in-flight requests.
+	requesting_chunks: FuturesUndead<Result<Option<ErasureChunk>, (ValidatorIndex, RequestError)>>,
+	/// Channel to the erasure task handler.
+	erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
+}
+
+/// Parameters specific to the `FetchSystematicChunks` strategy.
+pub struct FetchSystematicChunksParams {
+	/// Validators holding the systematic chunks, ordered by chunk index.
+	pub validators: Vec<ValidatorIndex>,
+	/// Channel to the erasure task handler.
+	pub erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
+}
+
+impl FetchSystematicChunks {
+	/// Instantiate a new strategy.
+	pub fn new(params: FetchSystematicChunksParams) -> Self {
+		Self {
+			threshold: params.validators.len(),
+			validators: params.validators.into(),
+			requesting_chunks: FuturesUndead::new(),
+			erasure_task_tx: params.erasure_task_tx,
+		}
+	}
+
+	async fn attempt_systematic_recovery(
+		&mut self,
+		state: &mut State,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		let recovery_duration = common_params.metrics.time_erasure_recovery();
+
+		let chunks = (0..self.threshold)
+			.map(|index| {
+				state
+					.received_chunks
+					.remove(&ValidatorIndex(index as u32))
+					.map(|chunk| chunk.chunk)
+					.expect("all systematic chunks were received; qed")
+			})
+			.collect();
+
+		let (available_data_tx, available_data_rx) = oneshot::channel();
+		self.erasure_task_tx
+			.send(ErasureTask::ReconstructFromSystematic(
+				common_params.n_validators,
+				chunks,
+				available_data_tx,
+			))
+			.await
+			.map_err(|_| RecoveryError::ChannelClosed)?;
+
+		let available_data_response =
+			available_data_rx.await.map_err(|_| RecoveryError::ChannelClosed)?;
+
+		// The chunks were checked against the erasure root, so the data is invalid if it can't be
+		// reconstructed from them.
+		let maybe_data = match available_data_response {
+			Ok(data) => post_recovery_check(&mut self.erasure_task_tx, common_params, data).await?,
+			Err(err) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					?err,
+					"Systematic data recovery error",
+				);
+				None
+			},
+		};
+
+		match maybe_data {
+			Some(data) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					"Data recovery from systematic chunks complete",
+				);
+				common_params.metrics.on_systematic_recovery_succeeded();
+
+				Ok(data)
+			},
+			None => {
+				recovery_duration.map(|rd| rd.stop_and_discard());
+				common_params.metrics.on_systematic_recovery_invalid();
+
+				Err(RecoveryError::Invalid)
+			},
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
+	for FetchSystematicChunks
+{
+	fn display_name(&self) -> &'static str {
+		"Fetch systematic chunks"
+	}
+
+	async fn run(
+		&mut self,
+		state: &mut State,
+		sender: &mut Sender,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		// First query the store for any chunks we've got.
+		if !common_params.bypass_availability_store {
+			let local_chunk_indices = state.populate_from_av_store(common_params, sender).await;
+			self.validators
+				.retain(|i| !local_chunk_indices.contains(&common_params.chunk_index(*i)));
+		}
+
+		// No need to query the validators that have the chunks we already received.
+		self.validators
+			.retain(|i| !state.received_chunks.contains_key(&common_params.chunk_index(*i)));
+
+		// Chunks received by other means than this strategy don't count towards the threshold.
+		let non_systematic_count =
+			state.chunk_count() - state.systematic_chunk_count(self.threshold);
+
+		loop {
+			if state.systematic_chunk_count(self.threshold) >= self.threshold {
+				return self.attempt_systematic_recovery(state, common_params).await
+			}
+
+			if is_unavailable(
+				state.systematic_chunk_count(self.threshold),
+				self.requesting_chunks.total_len(),
+				self.validators.len(),
+				self.threshold,
+			) {
+				gum::debug!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					received = %state.systematic_chunk_count(self.threshold),
+					systematic_threshold = %self.threshold,
+					"Data recovery from systematic chunks is not possible",
+				);
+				common_params.metrics.on_systematic_recovery_failed();
+
+				return Err(RecoveryError::Unavailable)
+			}
+
+			let desired_requests_count = std::cmp::min(N_PARALLEL, self.threshold);
+			state
+				.launch_parallel_chunk_requests(
+					common_params,
+					sender,
+					desired_requests_count,
+					&mut self.validators,
+					&mut self.requesting_chunks,
+				)
+				.await;
+
+			let threshold = self.threshold;
+			let (_, error_count) = state
+				.wait_for_chunks(
+					common_params,
+					&mut self.validators,
+					&mut self.requesting_chunks,
+					|unrequested_validators, reqs, chunk_count, _params, error_count| {
+						let systematic_count = chunk_count - non_systematic_count;
+						error_count > 0 ||
+							systematic_count >= threshold ||
+							is_unavailable(
+								systematic_count,
+								reqs,
+								unrequested_validators,
+								threshold,
+							)
+					},
+				)
+				.await;
+
+			// Every systematic chunk has a single holder, so there is little point in retrying.
+			if error_count > 0 {
+				gum::debug!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					?error_count,
+					"Systematic chunk requests failed",
+				);
+				common_params.metrics.on_systematic_recovery_failed();
+
+				return Err(RecoveryError::Unavailable)
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
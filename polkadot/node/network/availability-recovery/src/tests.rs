@@ -40,6 +40,7 @@ use polkadot_node_subsystem_test_helpers::{
 };
 use polkadot_node_subsystem_util::TimeoutExt;
 use polkadot_primitives::{
+	vstaging::{node_features::FeatureIndex, NodeFeatures},
 	AuthorityDiscoveryId, Hash, HeadData, IndexedVec, PersistedValidationData, ValidatorId,
 };
 use polkadot_primitives_test_helpers::{dummy_candidate_receipt, dummy_hash};
@@ -169,6 +170,7 @@ struct TestState {
 	available_data: AvailableData,
 	chunks: Vec<ErasureChunk>,
 	invalid_chunks: Vec<ErasureChunk>,
+	node_features: NodeFeatures,
 }
 
 impl TestState {
@@ -211,6 +213,28 @@ impl TestState {
 				}))).unwrap();
 			}
 		);
+		assert_matches!(
+			overseer_recv(virtual_overseer).await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+				relay_parent,
+				RuntimeApiRequest::NodeFeatures(session_index, tx)
+			)) => {
+				assert_eq!(relay_parent, self.current);
+				assert_eq!(session_index, self.session_index);
+
+				tx.send(Ok(self.node_features.clone())).unwrap();
+			}
+		);
+	}
+
+	/// Index of the validator holding the chunk `chunk_index`.
+	fn chunk_holder(&self, chunk_index: ValidatorIndex) -> ValidatorIndex {
+		availability_chunk_holder(
+			Some(&self.node_features),
+			self.validators.len(),
+			&self.candidate.hash(),
+			chunk_index,
+		)
 	}
 
 	async fn respond_to_available_data_query(
@@ -301,6 +325,14 @@ impl TestState {
 							Requests::ChunkFetchingV1(req) => {
 								assert_eq!(req.payload.candidate_hash, candidate_hash);
 
+								let holder = self.chunk_holder(req.payload.index);
+								assert_eq!(
+									req.peer,
+									Recipient::Authority(
+										self.validator_authority_id[holder.0 as usize].clone()
+									),
+								);
+
 								let validator_index = req.payload.index.0 as usize;
 								let available_data = match who_has(validator_index) {
 									Has::No => Ok(None),
@@ -461,6 +493,7 @@ impl Default for TestState {
 			available_data,
 			chunks,
 			invalid_chunks,
+			node_features: NodeFeatures::EMPTY,
 		}
 	}
 }
@@ -1578,3 +1611,193 @@ fn invalid_local_chunk_is_ignored() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn availability_is_recovered_from_systematic_chunks() {
+	let test_state = TestState::default();
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let subsystem = AvailabilityRecoverySubsystem::with_systematic_chunks(
+		request_receiver(&req_protocol_names),
+		Metrics::new_dummy(),
+	);
+	let systematic_threshold = systematic_recovery_threshold(test_state.validators.len()).unwrap();
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				None,
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api(&mut virtual_overseer).await;
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+		test_state.respond_to_query_all_request(&mut virtual_overseer, |_| false).await;
+
+		test_state
+			.test_chunk_requests(
+				&req_protocol_names,
+				test_state.candidate.hash(),
+				&mut virtual_overseer,
+				systematic_threshold,
+				|i| {
+					assert!(i < systematic_threshold, "requested non-systematic chunk {}", i);
+					Has::Yes
+				},
+			)
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+		virtual_overseer
+	});
+}
+
+#[test]
+fn systematic_chunks_are_requested_from_their_mapped_holders() {
+	let mut test_state = TestState::default();
+	test_state
+		.node_features
+		.resize(FeatureIndex::AvailabilityChunkMapping as usize + 1, false);
+	test_state
+		.node_features
+		.set(FeatureIndex::AvailabilityChunkMapping as usize, true);
+	// Make sure the systematic chunks are not held by the first validators.
+	while test_state.chunk_holder(ValidatorIndex(0)) == ValidatorIndex(0) {
+		let para_id = u32::from(test_state.candidate.descriptor.para_id);
+		test_state.candidate.descriptor.para_id = (para_id + 1).into();
+	}
+
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let subsystem = AvailabilityRecoverySubsystem::with_systematic_chunks(
+		request_receiver(&req_protocol_names),
+		Metrics::new_dummy(),
+	);
+	let systematic_threshold = systematic_recovery_threshold(test_state.validators.len()).unwrap();
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				None,
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api(&mut virtual_overseer).await;
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+		test_state.respond_to_query_all_request(&mut virtual_overseer, |_| false).await;
+
+		// The recipient of each request is checked against the chunk mapping.
+		test_state
+			.test_chunk_requests(
+				&req_protocol_names,
+				test_state.candidate.hash(),
+				&mut virtual_overseer,
+				systematic_threshold,
+				|i| {
+					assert!(i < systematic_threshold, "requested non-systematic chunk {}", i);
+					Has::Yes
+				},
+			)
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+		virtual_overseer
+	});
+}
+
+#[test]
+fn systematic_recovery_falls_back_to_regular_chunks() {
+	let test_state = TestState::default();
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let subsystem = AvailabilityRecoverySubsystem::with_systematic_chunks(
+		request_receiver(&req_protocol_names),
+		Metrics::new_dummy(),
+	);
+	let systematic_threshold = systematic_recovery_threshold(test_state.validators.len()).unwrap();
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				None,
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api(&mut virtual_overseer).await;
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+		test_state.respond_to_query_all_request(&mut virtual_overseer, |_| false).await;
+
+		let candidate_hash = test_state.candidate.hash();
+
+		// The holder of the last systematic chunk doesn't respond in time.
+		test_state
+			.test_chunk_requests(
+				&req_protocol_names,
+				candidate_hash,
+				&mut virtual_overseer,
+				systematic_threshold,
+				|i| if i == systematic_threshold - 1 { Has::timeout() } else { Has::Yes },
+			)
+			.await;
+
+		// Regular chunk recovery reuses the systematic chunks received so far.
+		test_state.respond_to_query_all_request(&mut virtual_overseer, |_| false).await;
+		test_state
+			.test_chunk_requests(
+				&req_protocol_names,
+				candidate_hash,
+				&mut virtual_overseer,
+				test_state.threshold() - (systematic_threshold - 1),
+				|_| Has::Yes,
+			)
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+		virtual_overseer
+	});
+}
@@ -116,6 +116,12 @@ pub enum FetchError {
 
 	#[error("Peer's protocol doesn't match the advertisement")]
 	ProtocolMismatch,
+
+	#[error("Too many advertisements of the peer are waiting to be fetched")]
+	PeerQueueLimitReached,
+
+	#[error("Too many advertisements for the para are waiting to be fetched")]
+	ParaQueueLimitReached,
 }
 
 /// Utility for eating top level errors and log them.
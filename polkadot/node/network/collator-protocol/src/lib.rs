@@ -21,14 +21,17 @@
 #![deny(unused_crate_dependencies)]
 #![recursion_limit = "256"]
 
-use std::time::{Duration, Instant};
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
 use futures::{
 	stream::{FusedStream, StreamExt},
 	FutureExt, TryFutureExt,
 };
 
-use polkadot_node_subsystem_util::reputation::ReputationAggregator;
+use polkadot_node_subsystem_util::{database::Database, reputation::ReputationAggregator};
 use sp_keystore::KeystorePtr;
 
 use polkadot_node_network_protocol::{
@@ -74,6 +77,10 @@ pub enum ProtocolSide {
 		eviction_policy: CollatorEvictionPolicy,
 		/// Prometheus metrics for validators.
 		metrics: validator_side::Metrics,
+		/// The parachains DB, in which the reputations of collators are persisted.
+		db: Arc<dyn Database>,
+		/// The column of `db` holding the reputations of collators.
+		col_reputation_data: u32,
 	},
 	/// Collators operate on a parachain.
 	Collator {
@@ -112,10 +119,21 @@ impl CollatorProtocolSubsystem {
 impl<Context> CollatorProtocolSubsystem {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
 		let future = match self.protocol_side {
-			ProtocolSide::Validator { keystore, eviction_policy, metrics } =>
-				validator_side::run(ctx, keystore, eviction_policy, metrics)
-					.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
-					.boxed(),
+			ProtocolSide::Validator {
+				keystore,
+				eviction_policy,
+				metrics,
+				db,
+				col_reputation_data,
+			} => validator_side::run(
+				ctx,
+				keystore,
+				eviction_policy,
+				metrics,
+				validator_side::ReputationDb { db, col_data: col_reputation_data },
+			)
+			.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
+			.boxed(),
 			ProtocolSide::Collator {
 				peer_id,
 				collator_pair,
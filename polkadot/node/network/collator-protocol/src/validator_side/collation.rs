@@ -47,6 +47,8 @@ use tokio_util::sync::CancellationToken;
 
 use crate::{error::SecondingError, LOG_TARGET};
 
+use super::collator_reputation::CollatorReputation;
+
 /// Candidate supplied with a para head it's built on top of.
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 pub struct ProspectiveCandidate {
@@ -239,11 +241,13 @@ impl Collations {
 	/// This will reset the status back to `Waiting` using [`CollationStatus::back_to_waiting`].
 	///
 	/// Returns `Some(_)` if there is any collation to fetch, the `status` is not `Seconded` and
-	/// the passed in `finished_one` is the currently `waiting_collation`.
+	/// the passed in `finished_one` is the currently `waiting_collation`. Advertisements of
+	/// collators with a better reputation are preferred, ties are broken by arrival order.
 	pub(super) fn get_next_collation_to_fetch(
 		&mut self,
 		finished_one: &(CollatorId, Option<CandidateHash>),
 		relay_parent_mode: ProspectiveParachainsMode,
+		reputation: &CollatorReputation,
 	) -> Option<(PendingCollation, CollatorId)> {
 		// If finished one does not match waiting_collation, then we already dequeued another fetch
 		// to replace it.
@@ -270,13 +274,26 @@ impl Collations {
 				if !self.is_seconded_limit_reached(relay_parent_mode) {
 					None
 				} else {
-					self.waiting_queue.pop_front()
+					self.pop_best_waiting(reputation)
 				},
 			CollationStatus::WaitingOnValidation | CollationStatus::Fetching =>
 				unreachable!("We have reset the status above!"),
 		}
 	}
 
+	/// Removes the queued advertisement of the collator with the best reputation.
+	fn pop_best_waiting(
+		&mut self,
+		reputation: &CollatorReputation,
+	) -> Option<(PendingCollation, CollatorId)> {
+		// `max_by_key` returns the last of equal elements, iterate in reverse to pick the
+		// earliest advertisement among equally scored collators.
+		let (index, _) = self.waiting_queue.iter().enumerate().rev().max_by_key(
+			|(_, (collation, collator_id))| reputation.score(collation.para_id, collator_id),
+		)?;
+		self.waiting_queue.remove(index)
+	}
+
 	/// Checks the limit of seconded candidates for a given para.
 	pub(super) fn is_seconded_limit_reached(
 		&self,
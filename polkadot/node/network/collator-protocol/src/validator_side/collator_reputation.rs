@@ -0,0 +1,339 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Reputation of collators, tracked per para.
+//!
+//! Unlike the peer reputation reported to the network bridge, which is lost once a peer
+//! disconnects, these scores are tied to the collator id and outlive the connection. They are
+//! used to decide which of the queued advertisements of a para gets fetched next, and which one
+//! gets dropped once the queue is full.
+//!
+//! Scores only move on events observed by this node:
+//! 	1. A fetched collation got seconded by backing.
+//! 	2. Fetching a collation timed out or failed on the network level.
+//! 	3. A fetched collation turned out to be invalid or didn't match its advertisement.
+//!
+//! The scores are persisted in the parachains DB, one entry per para, so they survive restarts of
+//! the node. Changed entries are written back periodically.
+
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
+
+use parity_scale_codec::{Decode, Encode};
+use polkadot_node_subsystem_util::database::{DBTransaction, Database};
+use polkadot_primitives::{CollatorId, Id as ParaId};
+
+use crate::LOG_TARGET;
+
+/// Prefix of the DB keys holding the scores of a para.
+const REPUTATION_PREFIX: &[u8; 10] = b"CR_para_id";
+
+/// Reputation score of a collator. Unknown collators have a score of zero.
+pub type Score = i32;
+
+/// Upper bound of a collator's score.
+const MAX_SCORE: Score = 1_000;
+
+/// Lower bound of a collator's score.
+const MIN_SCORE: Score = -1_000;
+
+/// The number of collators per para we keep scores for.
+///
+/// Once reached, the score which is closest to neutral is forgotten to make room for a new
+/// collator. This keeps both very good and very bad collators around, while the memory used
+/// can't be inflated by declaring with fresh keys.
+pub const MAX_COLLATORS_PER_PARA: usize = 256;
+
+/// An event affecting the reputation of a collator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReputationEvent {
+	/// A collation of the collator was seconded.
+	Seconded,
+	/// Fetching a collation from the collator timed out or failed on the network level.
+	FetchFailed,
+	/// The collator provided an invalid collation, or one not matching its advertisement.
+	Invalid,
+}
+
+impl ReputationEvent {
+	/// The change of the score caused by this event.
+	pub fn score_change(&self) -> Score {
+		match self {
+			Self::Seconded => 10,
+			Self::FetchFailed => -5,
+			Self::Invalid => -50,
+		}
+	}
+
+	/// The label of this event used in metrics.
+	pub fn as_label(&self) -> &'static str {
+		match self {
+			Self::Seconded => "seconded",
+			Self::FetchFailed => "fetch_failed",
+			Self::Invalid => "invalid",
+		}
+	}
+}
+
+/// The database the reputations are persisted in.
+#[derive(Clone)]
+pub struct ReputationDb {
+	/// The parachains database.
+	pub db: Arc<dyn Database>,
+	/// The column holding the reputations.
+	pub col_data: u32,
+}
+
+fn para_key(para_id: ParaId) -> [u8; 14] {
+	let mut key = [0u8; 10 + 4];
+	key[..10].copy_from_slice(REPUTATION_PREFIX);
+	key[10..].copy_from_slice(&u32::from(para_id).to_be_bytes());
+	key
+}
+
+fn decode_para_key(key: &[u8]) -> Option<ParaId> {
+	if key.len() != 10 + 4 || !key.starts_with(REPUTATION_PREFIX) {
+		return None
+	}
+
+	let mut bytes = [0u8; 4];
+	bytes.copy_from_slice(&key[10..]);
+	Some(ParaId::from(u32::from_be_bytes(bytes)))
+}
+
+/// Reputation scores of the collators of all paras we have been assigned to.
+#[derive(Debug, Default)]
+pub struct CollatorReputation {
+	per_para: HashMap<ParaId, HashMap<CollatorId, Score>>,
+	/// Paras whose scores changed since they were last written to the DB.
+	dirty: HashSet<ParaId>,
+}
+
+impl CollatorReputation {
+	/// Load the scores persisted in the DB.
+	///
+	/// Entries which fail to decode are skipped, so that a corrupted entry only costs the scores
+	/// of a single para.
+	pub fn load(db: &ReputationDb) -> Self {
+		let mut per_para = HashMap::new();
+
+		for item in db.db.iter_with_prefix(db.col_data, REPUTATION_PREFIX) {
+			let (key, value) = match item {
+				Ok(item) => item,
+				Err(err) => {
+					gum::warn!(target: LOG_TARGET, ?err, "Failed to load collator reputations");
+					break
+				},
+			};
+
+			let Some(para_id) = decode_para_key(&key) else { continue };
+			match Vec::<(CollatorId, Score)>::decode(&mut &value[..]) {
+				Ok(scores) => {
+					per_para.insert(para_id, scores.into_iter().collect());
+				},
+				Err(err) => {
+					gum::warn!(
+						target: LOG_TARGET,
+						?para_id,
+						?err,
+						"Failed to decode collator reputations",
+					);
+				},
+			}
+		}
+
+		Self { per_para, dirty: HashSet::new() }
+	}
+
+	/// Write the scores which changed since the last write to the DB.
+	pub fn write(&mut self, db: &ReputationDb) {
+		if self.dirty.is_empty() {
+			return
+		}
+
+		let mut tx = DBTransaction::new();
+		for para_id in self.dirty.iter() {
+			match self.per_para.get(para_id) {
+				Some(scores) if !scores.is_empty() => {
+					let scores: Vec<(&CollatorId, &Score)> = scores.iter().collect();
+					tx.put_vec(db.col_data, &para_key(*para_id), scores.encode());
+				},
+				_ => tx.delete(db.col_data, &para_key(*para_id)),
+			}
+		}
+
+		match db.db.write(tx) {
+			Ok(()) => self.dirty.clear(),
+			Err(err) => {
+				gum::warn!(target: LOG_TARGET, ?err, "Failed to write collator reputations");
+			},
+		}
+	}
+
+	/// The current score of a collator of the given para.
+	pub fn score(&self, para_id: ParaId, collator_id: &CollatorId) -> Score {
+		self.per_para
+			.get(&para_id)
+			.and_then(|scores| scores.get(collator_id))
+			.copied()
+			.unwrap_or_default()
+	}
+
+	/// Apply an event to the score of a collator and return the new score.
+	pub fn note_event(
+		&mut self,
+		para_id: ParaId,
+		collator_id: &CollatorId,
+		event: ReputationEvent,
+	) -> Score {
+		self.dirty.insert(para_id);
+		let scores = self.per_para.entry(para_id).or_default();
+
+		if !scores.contains_key(collator_id) && scores.len() >= MAX_COLLATORS_PER_PARA {
+			let most_neutral = scores
+				.iter()
+				.min_by_key(|(_, score)| score.unsigned_abs())
+				.map(|(collator_id, _)| collator_id.clone());
+			if let Some(most_neutral) = most_neutral {
+				scores.remove(&most_neutral);
+			}
+		}
+
+		let score = scores.entry(collator_id.clone()).or_default();
+		*score = score.saturating_add(event.score_change()).clamp(MIN_SCORE, MAX_SCORE);
+		*score
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
+	use polkadot_primitives::CollatorPair;
+	use sp_core::Pair;
+
+	fn reputation_db() -> ReputationDb {
+		let db = kvdb_memorydb::create(1);
+		ReputationDb { db: Arc::new(DbAdapter::new(db, &[0])), col_data: 0 }
+	}
+
+	fn collator() -> CollatorId {
+		CollatorPair::generate().0.public()
+	}
+
+	#[test]
+	fn scores_are_tracked_per_para() {
+		let mut reputation = CollatorReputation::default();
+		let para_a = ParaId::from(1);
+		let para_b = ParaId::from(2);
+		let collator = collator();
+
+		assert_eq!(reputation.score(para_a, &collator), 0);
+
+		reputation.note_event(para_a, &collator, ReputationEvent::Seconded);
+		reputation.note_event(para_a, &collator, ReputationEvent::FetchFailed);
+		reputation.note_event(para_b, &collator, ReputationEvent::Invalid);
+
+		assert_eq!(reputation.score(para_a, &collator), 5);
+		assert_eq!(reputation.score(para_b, &collator), -50);
+	}
+
+	#[test]
+	fn scores_are_bounded() {
+		let mut reputation = CollatorReputation::default();
+		let para_id = ParaId::from(1);
+		let good = collator();
+		let bad = collator();
+
+		for _ in 0..1_000 {
+			reputation.note_event(para_id, &good, ReputationEvent::Seconded);
+			reputation.note_event(para_id, &bad, ReputationEvent::Invalid);
+		}
+
+		assert_eq!(reputation.score(para_id, &good), MAX_SCORE);
+		assert_eq!(reputation.score(para_id, &bad), MIN_SCORE);
+	}
+
+	#[test]
+	fn most_neutral_collator_is_forgotten_first() {
+		let mut reputation = CollatorReputation::default();
+		let para_id = ParaId::from(1);
+		let good = collator();
+		let bad = collator();
+
+		reputation.note_event(para_id, &good, ReputationEvent::Seconded);
+		reputation.note_event(para_id, &bad, ReputationEvent::Invalid);
+
+		let neutral = collator();
+		reputation.note_event(para_id, &neutral, ReputationEvent::FetchFailed);
+
+		for _ in 3..MAX_COLLATORS_PER_PARA {
+			reputation.note_event(para_id, &collator(), ReputationEvent::Seconded);
+		}
+		assert_eq!(reputation.per_para[&para_id].len(), MAX_COLLATORS_PER_PARA);
+
+		reputation.note_event(para_id, &collator(), ReputationEvent::Invalid);
+
+		assert_eq!(reputation.per_para[&para_id].len(), MAX_COLLATORS_PER_PARA);
+		assert_eq!(reputation.score(para_id, &neutral), 0);
+		assert_eq!(reputation.score(para_id, &good), 10);
+		assert_eq!(reputation.score(para_id, &bad), -50);
+	}
+
+	#[test]
+	fn scores_survive_a_restart() {
+		let db = reputation_db();
+		let para_a = ParaId::from(1);
+		let para_b = ParaId::from(2);
+		let collator = collator();
+
+		let mut reputation = CollatorReputation::load(&db);
+		reputation.note_event(para_a, &collator, ReputationEvent::Seconded);
+		reputation.note_event(para_b, &collator, ReputationEvent::Invalid);
+		reputation.write(&db);
+		assert!(reputation.dirty.is_empty());
+
+		let reputation = CollatorReputation::load(&db);
+		assert_eq!(reputation.score(para_a, &collator), 10);
+		assert_eq!(reputation.score(para_b, &collator), -50);
+	}
+
+	#[test]
+	fn only_changed_paras_are_written() {
+		let db = reputation_db();
+		let para_a = ParaId::from(1);
+		let para_b = ParaId::from(2);
+		let collator = collator();
+
+		let mut reputation = CollatorReputation::load(&db);
+		reputation.note_event(para_a, &collator, ReputationEvent::Seconded);
+		reputation.write(&db);
+
+		// Overwrite the entry of `para_a` behind the back of `reputation`.
+		let mut tx = DBTransaction::new();
+		tx.delete(db.col_data, &para_key(para_a));
+		db.db.write(tx).unwrap();
+
+		reputation.note_event(para_b, &collator, ReputationEvent::Seconded);
+		reputation.write(&db);
+
+		let reloaded = CollatorReputation::load(&db);
+		assert_eq!(reloaded.score(para_a, &collator), 0);
+		assert_eq!(reloaded.score(para_b, &collator), 10);
+	}
+}
@@ -16,6 +16,8 @@
 
 use polkadot_node_subsystem_util::metrics::{self, prometheus};
 
+use super::collator_reputation::ReputationEvent;
+
 #[derive(Clone, Default)]
 pub struct Metrics(Option<MetricsInner>);
 
@@ -65,6 +67,20 @@ impl Metrics {
 			.as_ref()
 			.map(|metrics| metrics.request_unblocked_collations.start_timer())
 	}
+
+	/// Note an event affecting the reputation of a collator.
+	pub fn on_collator_reputation_event(&self, event: ReputationEvent) {
+		if let Some(metrics) = &self.0 {
+			metrics.collator_reputation_events.with_label_values(&[event.as_label()]).inc();
+		}
+	}
+
+	/// Note an advertisement dropped because of the limits of queued advertisements.
+	pub fn on_advertisement_dropped(&self, reason: &'static str) {
+		if let Some(metrics) = &self.0 {
+			metrics.advertisements_dropped.with_label_values(&[reason]).inc();
+		}
+	}
 }
 
 #[derive(Clone)]
@@ -75,6 +91,8 @@ struct MetricsInner {
 	collator_peer_count: prometheus::Gauge<prometheus::U64>,
 	collation_request_duration: prometheus::Histogram,
 	request_unblocked_collations: prometheus::Histogram,
+	collator_reputation_events: prometheus::CounterVec<prometheus::U64>,
+	advertisements_dropped: prometheus::CounterVec<prometheus::U64>,
 }
 
 impl metrics::Metrics for Metrics {
@@ -135,6 +153,26 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			collator_reputation_events: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_collator_protocol_validator_reputation_events_total",
+						"Number of events affecting the reputation of collators.",
+					),
+					&["event"],
+				)?,
+				registry,
+			)?,
+			advertisements_dropped: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_collator_protocol_validator_advertisements_dropped_total",
+						"Number of advertisements dropped because too many were waiting to be fetched.",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
 		};
 
 		Ok(Metrics(Some(metrics)))
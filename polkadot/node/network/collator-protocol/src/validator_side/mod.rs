@@ -64,6 +64,7 @@ use crate::error::{Error, FetchError, Result, SecondingError};
 use super::{modify_reputation, tick_stream, LOG_TARGET};
 
 mod collation;
+mod collator_reputation;
 mod metrics;
 
 use collation::{
@@ -71,6 +72,8 @@ use collation::{
 	CollationFetchRequest, CollationStatus, Collations, FetchedCollation, PendingCollation,
 	PendingCollationFetch, ProspectiveCandidate,
 };
+pub use collator_reputation::ReputationDb;
+use collator_reputation::{CollatorReputation, ReputationEvent};
 
 #[cfg(test)]
 mod tests;
@@ -112,6 +115,17 @@ const ACTIVITY_POLL: Duration = Duration::from_secs(1);
 #[cfg(test)]
 const MAX_UNSHARED_DOWNLOAD_TIME: Duration = Duration::from_millis(100);
 
+/// The maximum number of advertisements of a single peer waiting to be fetched, over all relay
+/// parents.
+const MAX_QUEUED_ADVERTISEMENTS_PER_PEER: usize = 8;
+
+/// The maximum number of advertisements for a single para waiting to be fetched, over all relay
+/// parents.
+///
+/// Once reached, an advertisement of a collator with a better reputation replaces the queued
+/// advertisement of the collator with the worst one.
+const MAX_QUEUED_ADVERTISEMENTS_PER_PARA: usize = 32;
+
 #[cfg(test)]
 const ACTIVITY_POLL: Duration = Duration::from_millis(10);
 
@@ -445,6 +459,10 @@ struct State {
 
 	/// Aggregated reputation change
 	reputation: ReputationAggregator,
+
+	/// Reputation of collators per para, which outlives their connections and is persisted in
+	/// the DB.
+	collator_reputation: CollatorReputation,
 }
 
 fn is_relay_parent_in_implicit_view(
@@ -628,6 +646,102 @@ async fn note_good_collation(
 	}
 }
 
+/// Apply an event to the reputation of a collator of the given para.
+fn note_collator_reputation_event(
+	state: &mut State,
+	para_id: ParaId,
+	collator_id: &CollatorId,
+	event: ReputationEvent,
+) {
+	let score = state.collator_reputation.note_event(para_id, collator_id, event);
+	state.metrics.on_collator_reputation_event(event);
+
+	gum::trace!(
+		target: LOG_TARGET,
+		?para_id,
+		?collator_id,
+		?event,
+		score,
+		"Collator reputation changed",
+	);
+}
+
+/// Make room in the waiting queues for an advertisement of a collator, respecting the limits
+/// of queued advertisements per peer and per para.
+///
+/// If the para limit is reached, the queued advertisement of the collator with the worst
+/// reputation is dropped, provided it's worse than the reputation of the advertising collator.
+fn make_room_for_advertisement(
+	state: &mut State,
+	para_id: ParaId,
+	peer_id: &PeerId,
+	collator_id: &CollatorId,
+) -> std::result::Result<(), FetchError> {
+	let queued = || {
+		state
+			.per_relay_parent
+			.values()
+			.flat_map(|per_relay_parent| per_relay_parent.collations.waiting_queue.iter())
+	};
+
+	if queued().filter(|(collation, _)| &collation.peer_id == peer_id).count() >=
+		MAX_QUEUED_ADVERTISEMENTS_PER_PEER
+	{
+		state.metrics.on_advertisement_dropped("peer_limit");
+		return Err(FetchError::PeerQueueLimitReached)
+	}
+
+	if queued().filter(|(collation, _)| collation.para_id == para_id).count() <
+		MAX_QUEUED_ADVERTISEMENTS_PER_PARA
+	{
+		return Ok(())
+	}
+
+	let reputation = &state.collator_reputation;
+	let score = reputation.score(para_id, collator_id);
+	let worst = state
+		.per_relay_parent
+		.iter()
+		.flat_map(|(relay_parent, per_relay_parent)| {
+			per_relay_parent.collations.waiting_queue.iter().enumerate().map(
+				move |(index, (collation, collator_id))| {
+					(*relay_parent, index, collation, collator_id)
+				},
+			)
+		})
+		.filter(|(_, _, collation, _)| collation.para_id == para_id)
+		.min_by_key(|(_, _, collation, collator_id)| {
+			reputation.score(collation.para_id, collator_id)
+		})
+		.filter(|(_, _, collation, collator_id)| {
+			reputation.score(collation.para_id, collator_id) < score
+		})
+		.map(|(relay_parent, index, ..)| (relay_parent, index));
+
+	let Some((relay_parent, index)) = worst else {
+		state.metrics.on_advertisement_dropped("para_limit");
+		return Err(FetchError::ParaQueueLimitReached)
+	};
+
+	if let Some(per_relay_parent) = state.per_relay_parent.get_mut(&relay_parent) {
+		if let Some((dropped, dropped_collator_id)) =
+			per_relay_parent.collations.waiting_queue.remove(index)
+		{
+			gum::debug!(
+				target: LOG_TARGET,
+				?relay_parent,
+				?para_id,
+				peer_id = ?dropped.peer_id,
+				collator_id = ?dropped_collator_id,
+				"Dropping queued advertisement in favour of a collator with better reputation",
+			);
+		}
+	}
+	state.metrics.on_advertisement_dropped("evicted");
+
+	Ok(())
+}
+
 /// Notify a collator that its collation got seconded.
 async fn notify_collation_seconded(
 	sender: &mut impl overseer::CollatorProtocolSenderTrait,
@@ -1214,7 +1328,7 @@ where
 			parent_head_data_hash,
 		});
 
-	let collations = &mut per_relay_parent.collations;
+	let collations = &per_relay_parent.collations;
 	if !collations.is_seconded_limit_reached(relay_parent_mode) {
 		gum::trace!(
 			target: LOG_TARGET,
@@ -1231,6 +1345,8 @@ where
 
 	match collations.status {
 		CollationStatus::Fetching | CollationStatus::WaitingOnValidation => {
+			make_room_for_advertisement(state, para_id, &peer_id, &collator_id)?;
+
 			gum::trace!(
 				target: LOG_TARGET,
 				peer_id = ?peer_id,
@@ -1238,7 +1354,12 @@ where
 				?relay_parent,
 				"Added collation to the pending list"
 			);
-			collations.waiting_queue.push_back((pending_collation, collator_id));
+			if let Some(per_relay_parent) = state.per_relay_parent.get_mut(&relay_parent) {
+				per_relay_parent
+					.collations
+					.waiting_queue
+					.push_back((pending_collation, collator_id));
+			}
 		},
 		CollationStatus::Waiting => {
 			fetch_collation(sender, state, pending_collation, collator_id).await?;
@@ -1512,8 +1633,15 @@ async fn process_msg<Context>(
 			if let Some(CollationEvent { collator_id, pending_collation, .. }) =
 				state.fetched_candidates.remove(&fetched_collation)
 			{
-				let PendingCollation { relay_parent, peer_id, prospective_candidate, .. } =
-					pending_collation;
+				let PendingCollation {
+					relay_parent, para_id, peer_id, prospective_candidate, ..
+				} = pending_collation;
+				note_collator_reputation_event(
+					state,
+					para_id,
+					&collator_id,
+					ReputationEvent::Seconded,
+				);
 				note_good_collation(
 					&mut state.reputation,
 					ctx.sender(),
@@ -1561,6 +1689,7 @@ async fn process_msg<Context>(
 		Invalid(parent, candidate_receipt) => {
 			let fetched_collation = FetchedCollation::from(&candidate_receipt);
 			let candidate_hash = fetched_collation.candidate_hash;
+			let para_id = fetched_collation.para_id;
 			let id = match state.fetched_candidates.entry(fetched_collation) {
 				Entry::Occupied(entry)
 					if entry.get().pending_collation.commitments_hash ==
@@ -1578,6 +1707,7 @@ async fn process_msg<Context>(
 				Entry::Vacant(_) => return,
 			};
 
+			note_collator_reputation_event(state, para_id, &id, ReputationEvent::Invalid);
 			report_collator(&mut state.reputation, ctx.sender(), &state.peer_data, id.clone())
 				.await;
 
@@ -1593,12 +1723,14 @@ pub(crate) async fn run<Context>(
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
 	metrics: Metrics,
+	reputation_db: ReputationDb,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	run_inner(
 		ctx,
 		keystore,
 		eviction_policy,
 		metrics,
+		reputation_db,
 		ReputationAggregator::default(),
 		REPUTATION_CHANGE_INTERVAL,
 	)
@@ -1611,13 +1743,15 @@ async fn run_inner<Context>(
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
 	metrics: Metrics,
+	reputation_db: ReputationDb,
 	reputation: ReputationAggregator,
 	reputation_interval: Duration,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	let new_reputation_delay = || futures_timer::Delay::new(reputation_interval).fuse();
 	let mut reputation_delay = new_reputation_delay();
 
-	let mut state = State { metrics, reputation, ..Default::default() };
+	let collator_reputation = CollatorReputation::load(&reputation_db);
+	let mut state = State { metrics, reputation, collator_reputation, ..Default::default() };
 
 	let next_inactivity_stream = tick_stream(ACTIVITY_POLL);
 	futures::pin_mut!(next_inactivity_stream);
@@ -1629,6 +1763,7 @@ async fn run_inner<Context>(
 		select! {
 			_ = reputation_delay => {
 				state.reputation.send(ctx.sender()).await;
+				state.collator_reputation.write(&reputation_db);
 				reputation_delay = new_reputation_delay();
 			},
 			res = ctx.recv().fuse() => {
@@ -1680,6 +1815,12 @@ async fn run_inner<Context>(
 
 					if err.is_malicious() {
 						// Report malicious peer.
+						note_collator_reputation_event(
+							&mut state,
+							pending_collation.para_id,
+							&collator_id,
+							ReputationEvent::Invalid,
+						);
 						modify_reputation(&mut state.reputation, ctx.sender(), pending_collation.peer_id, COST_REPORT_BAD).await;
 					}
 					let maybe_candidate_hash =
@@ -1712,6 +1853,8 @@ async fn run_inner<Context>(
 		}
 	}
 
+	state.collator_reputation.write(&reputation_db);
+
 	Ok(())
 }
 
@@ -1724,11 +1867,14 @@ async fn dequeue_next_collation_and_fetch<Context>(
 	// The collator we tried to fetch from last, optionally which candidate.
 	previous_fetch: (CollatorId, Option<CandidateHash>),
 ) {
-	while let Some((next, id)) = state.per_relay_parent.get_mut(&relay_parent).and_then(|state| {
-		state
-			.collations
-			.get_next_collation_to_fetch(&previous_fetch, state.prospective_parachains_mode)
-	}) {
+	while let Some((next, id)) =
+		state.per_relay_parent.get_mut(&relay_parent).and_then(|per_relay_parent| {
+			per_relay_parent.collations.get_next_collation_to_fetch(
+				&previous_fetch,
+				per_relay_parent.prospective_parachains_mode,
+				&state.collator_reputation,
+			)
+		}) {
 		gum::debug!(
 			target: LOG_TARGET,
 			?relay_parent,
@@ -1934,6 +2080,12 @@ async fn handle_collation_fetch_response(
 				err = ?err,
 				"Collator provided response that could not be decoded"
 			);
+			note_collator_reputation_event(
+				state,
+				pending_collation.para_id,
+				&collator_id,
+				ReputationEvent::Invalid,
+			);
 			Err(Some((pending_collation.peer_id, COST_CORRUPTED_MESSAGE)))
 		},
 		Err(err) if err.is_timed_out() => {
@@ -1946,6 +2098,15 @@ async fn handle_collation_fetch_response(
 			);
 			// For now we don't want to change reputation on timeout, to mitigate issues like
 			// this: https://github.com/paritytech/polkadot/issues/4617
+			//
+			// The collator reputation is only used to order advertisements of this para, so a
+			// penalty there doesn't lead to disconnects.
+			note_collator_reputation_event(
+				state,
+				pending_collation.para_id,
+				&collator_id,
+				ReputationEvent::FetchFailed,
+			);
 			Err(None)
 		},
 		Err(RequestError::NetworkError(err)) => {
@@ -1963,6 +2124,12 @@ async fn handle_collation_fetch_response(
 			// sensible. In theory this could be exploited, by DoSing this node,
 			// which would result in reduced reputation for proper nodes, but the
 			// same can happen for penalties on timeouts, which we also have.
+			note_collator_reputation_event(
+				state,
+				pending_collation.para_id,
+				&collator_id,
+				ReputationEvent::FetchFailed,
+			);
 			Err(Some((pending_collation.peer_id, COST_NETWORK_ERROR)))
 		},
 		Err(RequestError::Canceled(err)) => {
@@ -1989,6 +2156,12 @@ async fn handle_collation_fetch_response(
 				"Got wrong para ID for requested collation."
 			);
 
+			note_collator_reputation_event(
+				state,
+				pending_collation.para_id,
+				&collator_id,
+				ReputationEvent::Invalid,
+			);
 			Err(Some((pending_collation.peer_id, COST_WRONG_PARA)))
 		},
 		Ok(request_v1::CollationFetchingResponse::Collation(candidate_receipt, pov)) => {
@@ -169,6 +169,13 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 			undeclared: DECLARE_TIMEOUT,
 		},
 		Metrics::default(),
+		ReputationDb {
+			db: Arc::new(polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
+				kvdb_memorydb::create(1),
+				&[0],
+			)),
+			col_data: 0,
+		},
 		reputation,
 		REPUTATION_CHANGE_TEST_INTERVAL,
 	);
@@ -1070,6 +1077,133 @@ fn fetch_next_collation_on_invalid_collation() {
 	});
 }
 
+// Ensure that queued advertisements of collators which provided invalid collations before are
+// fetched last.
+#[test]
+fn queued_advertisements_are_fetched_by_collator_reputation() {
+	let test_state = TestState::default();
+
+	test_harness(ReputationAggregator::new(|_| true), |test_harness| async move {
+		let TestHarness { mut virtual_overseer, .. } = test_harness;
+
+		let second = Hash::random();
+
+		let our_view = our_view![test_state.relay_parent, second];
+
+		overseer_send(
+			&mut virtual_overseer,
+			CollatorProtocolMessage::NetworkBridgeUpdate(NetworkBridgeEvent::OurViewChange(
+				our_view.clone(),
+			)),
+		)
+		.await;
+
+		for hash in our_view.iter() {
+			assert_async_backing_params_request(&mut virtual_overseer, *hash).await;
+			respond_to_core_info_queries(&mut virtual_overseer, &test_state).await;
+		}
+
+		let peer_b = PeerId::random();
+		let peer_c = PeerId::random();
+		let peer_d = PeerId::random();
+
+		for (peer, collator) in [(peer_b, 0), (peer_c, 1), (peer_d, 2)] {
+			connect_and_declare_collator(
+				&mut virtual_overseer,
+				peer,
+				test_state.collators[collator].clone(),
+				test_state.chain_ids[0],
+				CollationVersion::V1,
+			)
+			.await;
+		}
+
+		// Collator `b` provides an invalid collation.
+		advertise_collation(&mut virtual_overseer, peer_b, test_state.relay_parent, None).await;
+
+		let response_channel = assert_fetch_collation_request(
+			&mut virtual_overseer,
+			test_state.relay_parent,
+			test_state.chain_ids[0],
+			None,
+		)
+		.await;
+
+		let pov = PoV { block_data: BlockData(vec![]) };
+		let mut candidate_a =
+			dummy_candidate_receipt_bad_sig(dummy_hash(), Some(Default::default()));
+		candidate_a.descriptor.para_id = test_state.chain_ids[0];
+		candidate_a.descriptor.relay_parent = test_state.relay_parent;
+		candidate_a.descriptor.persisted_validation_data_hash = dummy_pvd().hash();
+		response_channel
+			.send(Ok((
+				request_v1::CollationFetchingResponse::Collation(candidate_a.clone(), pov.clone())
+					.encode(),
+				ProtocolName::from(""),
+			)))
+			.expect("Sending response should succeed");
+
+		let receipt = assert_candidate_backing_second(
+			&mut virtual_overseer,
+			test_state.relay_parent,
+			test_state.chain_ids[0],
+			&pov,
+			CollationVersion::V1,
+		)
+		.await;
+
+		overseer_send(
+			&mut virtual_overseer,
+			CollatorProtocolMessage::Invalid(test_state.relay_parent, receipt),
+		)
+		.await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::ReportPeer(
+				ReportPeerMessage::Single(peer, rep),
+			)) => {
+				assert_eq!(peer, peer_b);
+				assert_eq!(rep.value, COST_REPORT_BAD.cost_or_benefit());
+			}
+		);
+
+		// While fetching from `c`, both `b` and `d` advertise.
+		advertise_collation(&mut virtual_overseer, peer_c, second, None).await;
+		let _response_channel_c = assert_fetch_collation_request(
+			&mut virtual_overseer,
+			second,
+			test_state.chain_ids[0],
+			None,
+		)
+		.await;
+
+		advertise_collation(&mut virtual_overseer, peer_b, second, None).await;
+		advertise_collation(&mut virtual_overseer, peer_d, second, None).await;
+
+		// `d` is preferred over `b`, even though `b` advertised first.
+		Delay::new(MAX_UNSHARED_DOWNLOAD_TIME + Duration::from_millis(50)).await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::SendRequests(
+				reqs,
+				IfDisconnected::ImmediateError,
+			)) => {
+				assert_matches!(
+					&reqs[..],
+					[Requests::CollationFetchingV1(req)] => {
+						assert_eq!(req.peer, Recipient::Peer(peer_d));
+						assert_eq!(req.payload.relay_parent, second);
+					}
+				);
+			}
+		);
+
+		virtual_overseer
+	});
+}
+
 #[test]
 fn inactive_disconnected() {
 	let test_state = TestState::default();
@@ -0,0 +1,174 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Live view of the health of the subsystems.
+//!
+//! The channel meters of all subsystems are read by the metrics metronome anyway. On every tick we
+//! also note whether a subsystem received anything from its channels since the previous tick, which
+//! allows to tell a subsystem which is busy from one which stalled.
+//!
+//! The [`SubsystemsHealth`] is shared between the overseer and its readers, e.g. the RPC, so
+//! reading it doesn't go through the overseer and works even if the overseer itself stalled.
+
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{Duration, SystemTime},
+};
+
+use futures::channel::oneshot;
+use parking_lot::Mutex;
+use polkadot_primitives::{BlockNumber, Hash};
+
+use crate::SubsystemMeterReadouts;
+
+/// A subsystem with queued messages or signals, which didn't receive any of them for this long,
+/// is considered stalled.
+pub const SUBSYSTEM_STALL_THRESHOLD: Duration = Duration::from_secs(10);
+
+/// Health of a single subsystem, as observed by the overseer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubsystemHealth {
+	/// The name of the subsystem.
+	pub name: &'static str,
+	/// The number of messages waiting in the bounded channel of the subsystem.
+	pub bounded_queue_len: usize,
+	/// The number of messages waiting in the unbounded channel of the subsystem.
+	pub unbounded_queue_len: usize,
+	/// The number of signals, e.g. active leaves updates, the subsystem didn't receive yet.
+	pub pending_signals: usize,
+	/// The total number of messages the subsystem received.
+	pub messages_received: usize,
+	/// When the subsystem was last observed receiving a message or signal, if ever.
+	pub last_progress: Option<SystemTime>,
+	/// Whether the subsystem has pending messages or signals, but didn't receive any of them for
+	/// at least [`SUBSYSTEM_STALL_THRESHOLD`].
+	pub stalled: bool,
+}
+
+/// A snapshot of the state of the overseer and its subsystems.
+#[derive(Debug, Clone, Default)]
+pub struct OverseerIntrospection {
+	/// The leaves currently active, as known to the overseer.
+	///
+	/// A subsystem without pending signals knows about all of them.
+	pub active_leaves: Vec<(Hash, BlockNumber)>,
+	/// The health of all running subsystems, ordered by name.
+	pub subsystems: Vec<SubsystemHealth>,
+}
+
+struct TrackedSubsystem {
+	received: usize,
+	progress_observed_at: SystemTime,
+	health: SubsystemHealth,
+}
+
+#[derive(Default)]
+struct Inner {
+	subsystems: HashMap<&'static str, TrackedSubsystem>,
+	active_leaves: Vec<(Hash, BlockNumber)>,
+	update_listeners: Vec<oneshot::Sender<()>>,
+}
+
+/// Tracks the health of the subsystems based on the readouts of their channel meters, along with
+/// the active leaves of the overseer.
+#[derive(Clone, Default)]
+pub struct SubsystemsHealth(Arc<Mutex<Inner>>);
+
+impl SubsystemsHealth {
+	/// Note the current readouts of the channel meters of all subsystems.
+	///
+	/// Listeners waiting for an update are notified afterwards.
+	pub(crate) fn note_readouts(
+		&self,
+		readouts: &[(&'static str, SubsystemMeterReadouts)],
+		now: SystemTime,
+	) {
+		let mut inner = self.0.lock();
+		for (name, readouts) in readouts {
+			note_subsystem_readouts(&mut inner.subsystems, *name, readouts, now);
+		}
+
+		for listener in inner.update_listeners.drain(..) {
+			// it's fine if the listener is no longer interested
+			let _ = listener.send(());
+		}
+	}
+
+	/// Note the leaves currently active.
+	pub(crate) fn note_active_leaves(&self, active_leaves: &HashMap<Hash, BlockNumber>) {
+		self.0.lock().active_leaves =
+			active_leaves.iter().map(|(hash, number)| (*hash, *number)).collect();
+	}
+
+	/// A snapshot of the active leaves and the health of all subsystems, ordered by name.
+	pub fn introspect(&self) -> OverseerIntrospection {
+		let inner = self.0.lock();
+		let mut subsystems: Vec<_> =
+			inner.subsystems.values().map(|tracked| tracked.health.clone()).collect();
+		subsystems.sort_by_key(|health| health.name);
+
+		OverseerIntrospection { active_leaves: inner.active_leaves.clone(), subsystems }
+	}
+
+	/// Returns a receiver which resolves the next time the readouts of the subsystems are noted.
+	pub fn next_update(&self) -> oneshot::Receiver<()> {
+		let (tx, rx) = oneshot::channel();
+		self.0.lock().update_listeners.push(tx);
+		rx
+	}
+}
+
+fn note_subsystem_readouts(
+	subsystems: &mut HashMap<&'static str, TrackedSubsystem>,
+	name: &'static str,
+	readouts: &SubsystemMeterReadouts,
+	now: SystemTime,
+) {
+	let messages_received = readouts.bounded.received + readouts.unbounded.received;
+	let received = messages_received + readouts.signals.received;
+
+	let tracked = subsystems.entry(name).or_insert_with(|| TrackedSubsystem {
+		received: 0,
+		progress_observed_at: now,
+		health: SubsystemHealth {
+			name,
+			bounded_queue_len: 0,
+			unbounded_queue_len: 0,
+			pending_signals: 0,
+			messages_received: 0,
+			last_progress: None,
+			stalled: false,
+		},
+	});
+
+	if tracked.received != received {
+		tracked.received = received;
+		tracked.progress_observed_at = now;
+		tracked.health.last_progress = Some(now);
+	}
+
+	let health = &mut tracked.health;
+	health.bounded_queue_len = readouts.bounded.sent.saturating_sub(readouts.bounded.received);
+	health.unbounded_queue_len =
+		readouts.unbounded.sent.saturating_sub(readouts.unbounded.received);
+	health.pending_signals = readouts.signals.sent.saturating_sub(readouts.signals.received);
+	health.messages_received = messages_received;
+
+	let pending = health.bounded_queue_len + health.unbounded_queue_len + health.pending_signals;
+	let since_progress = now.duration_since(tracked.progress_observed_at).unwrap_or_default();
+	health.stalled = pending > 0 && since_progress >= SUBSYSTEM_STALL_THRESHOLD;
+}
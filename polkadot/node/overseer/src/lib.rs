@@ -66,7 +66,7 @@ use std::{
 	fmt::{self, Debug},
 	pin::Pin,
 	sync::Arc,
-	time::Duration,
+	time::{Duration, SystemTime},
 };
 
 use futures::{channel::oneshot, future::BoxFuture, select, Future, FutureExt, StreamExt};
@@ -94,6 +94,11 @@ pub use polkadot_node_subsystem_types::{
 pub mod metrics;
 pub use self::metrics::Metrics as OverseerMetrics;
 
+pub mod introspection;
+pub use self::introspection::{
+	OverseerIntrospection, SubsystemHealth, SubsystemsHealth, SUBSYSTEM_STALL_THRESHOLD,
+};
+
 /// A dummy subsystem, mostly useful for placeholders and tests.
 pub mod dummy;
 pub use self::dummy::DummySubsystem;
@@ -644,9 +649,14 @@ pub struct Overseer<SupportsParachains> {
 
 	/// Various Prometheus metrics.
 	pub metrics: OverseerMetrics,
+
+	/// The health of the subsystems, shared with whoever wants to observe it.
+	pub subsystems_health: SubsystemsHealth,
 }
 
 /// Spawn the metrics metronome task.
+///
+/// Besides updating the metrics, the metronome keeps track of the health of the subsystems.
 pub fn spawn_metronome_metrics<S, SupportsParachains>(
 	overseer: &mut Overseer<S, SupportsParachains>,
 	metronome_metrics: OverseerMetrics,
@@ -668,6 +678,7 @@ where
 		}
 	}
 	let subsystem_meters = overseer.map_subsystems(ExtractNameAndMeters);
+	let subsystems_health = overseer.subsystems_health.clone();
 
 	#[cfg(any(target_os = "linux", feature = "jemalloc-allocator"))]
 	let collect_memory_stats: Box<dyn Fn(&OverseerMetrics) + Send> =
@@ -704,13 +715,16 @@ where
 		// We combine the amount of messages from subsystems to the overseer
 		// as well as the amount of messages from external sources to the overseer
 		// into one `to_overseer` value.
-		metronome_metrics.channel_metrics_snapshot(
-			subsystem_meters
-				.iter()
-				.cloned()
-				.flatten()
-				.map(|(name, ref meters)| (name, meters.read())),
-		);
+		let readouts: Vec<_> = subsystem_meters
+			.iter()
+			.cloned()
+			.flatten()
+			.map(|(name, ref meters)| (name, meters.read()))
+			.collect();
+
+		subsystems_health.note_readouts(&readouts, SystemTime::now());
+
+		metronome_metrics.channel_metrics_snapshot(readouts);
 
 		futures::future::ready(())
 	});
@@ -814,6 +828,7 @@ where
 			update.deactivated.push(block.parent_hash);
 			self.on_head_deactivated(&block.parent_hash);
 		}
+		self.subsystems_health.note_active_leaves(&self.active_leaves);
 
 		self.clean_up_external_listeners();
 
@@ -840,6 +855,7 @@ where
 		for deactivated in &update.deactivated {
 			self.on_head_deactivated(deactivated)
 		}
+		self.subsystems_health.note_active_leaves(&self.active_leaves);
 
 		self.broadcast_signal(OverseerSignal::BlockFinalized(block.hash, block.number))
 			.await?;
@@ -564,6 +564,97 @@ fn overseer_finalize_works() {
 	});
 }
 
+#[test]
+fn overseer_introspection_works() {
+	let spawner = sp_core::testing::TaskExecutor::new();
+
+	executor::block_on(async move {
+		let first_block_hash = [1; 32].into();
+		let first_block = BlockInfo {
+			hash: first_block_hash,
+			parent_hash: [0; 32].into(),
+			number: 1,
+			unpin_handle: dummy_unpin_handle(dummy_hash()),
+		};
+
+		let (overseer, handle) = dummy_overseer_builder(spawner, MockSupportsParachains, None)
+			.unwrap()
+			.build()
+			.unwrap();
+		let subsystems_health = overseer.subsystems_health.clone();
+		let mut handle = Handle::new(handle);
+
+		let overseer_fut = overseer.run_inner();
+		let test_fut = async move {
+			handle.block_imported(first_block).await;
+
+			let (tx, rx) = oneshot::channel();
+			handle.wait_for_activation(first_block_hash, tx).await;
+			rx.await.unwrap().unwrap();
+
+			// Wait for the metrics metronome to observe all subsystems receiving the signal.
+			let introspection = loop {
+				subsystems_health.next_update().await.unwrap();
+
+				let introspection = subsystems_health.introspect();
+				if introspection.subsystems.iter().all(|health| health.pending_signals == 0) {
+					break introspection
+				}
+			};
+
+			handle.stop().await;
+			introspection
+		};
+
+		let (res, introspection) = futures::join!(overseer_fut, test_fut);
+		assert!(res.is_ok());
+
+		assert_eq!(introspection.active_leaves, vec![(first_block_hash, 1)]);
+		assert!(!introspection.subsystems.is_empty());
+		for health in introspection.subsystems {
+			assert_eq!(health.bounded_queue_len, 0);
+			assert!(health.last_progress.is_some());
+			assert!(!health.stalled);
+		}
+	});
+}
+
+#[test]
+fn subsystem_without_progress_is_stalled() {
+	let subsystems_health = SubsystemsHealth::default();
+	let start = std::time::SystemTime::UNIX_EPOCH;
+
+	let readouts = |sent, received| SubsystemMeterReadouts {
+		bounded: metered::Readout { sent, received, ..Default::default() },
+		unbounded: Default::default(),
+		signals: Default::default(),
+	};
+	let health = |subsystems_health: &SubsystemsHealth| {
+		subsystems_health.introspect().subsystems.pop().unwrap()
+	};
+
+	subsystems_health.note_readouts(&[("subsystem", readouts(3, 1))], start);
+	assert_eq!(health(&subsystems_health).bounded_queue_len, 2);
+	assert!(!health(&subsystems_health).stalled);
+
+	// The subsystem makes progress, so it isn't stalled no matter how long it takes.
+	let later = start + SUBSYSTEM_STALL_THRESHOLD;
+	subsystems_health.note_readouts(&[("subsystem", readouts(3, 2))], later);
+	assert_eq!(health(&subsystems_health).last_progress, Some(later));
+	assert!(!health(&subsystems_health).stalled);
+
+	// No progress for a while, with messages still pending.
+	let much_later = later + SUBSYSTEM_STALL_THRESHOLD;
+	subsystems_health.note_readouts(&[("subsystem", readouts(3, 2))], much_later);
+	assert!(health(&subsystems_health).stalled);
+
+	// No progress, but nothing pending either.
+	subsystems_health.note_readouts(&[("subsystem", readouts(3, 3))], much_later);
+	subsystems_health
+		.note_readouts(&[("subsystem", readouts(3, 3))], much_later + SUBSYSTEM_STALL_THRESHOLD);
+	assert!(!health(&subsystems_health).stalled);
+}
+
 // Tests that finalization of an active leaf doesn't remove it from
 // the leaves set.
 #[test]
@@ -0,0 +1,152 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Export of dispute evidence from a node's databases and offline replay of it.
+
+use super::{
+	open_database, parachains_db, workers, DatabaseSource, Error, FullClient, HeaderBackend,
+	ParachainHost, ProvideRuntimeApi, AVAILABILITY_CONFIG,
+};
+use polkadot_node_core_candidate_validation::{
+	validate_candidate_offline, Config as CandidateValidationConfig,
+};
+use polkadot_node_core_dispute_coordinator::Config as DisputeCoordinatorConfig;
+use polkadot_node_primitives::ValidationResult;
+use polkadot_primitives::{CandidateHash, Hash, SessionIndex};
+use std::path::PathBuf;
+
+pub use polkadot_node_core_dispute_coordinator::bundle::DisputeBundle;
+
+/// Assemble the dispute bundle of a candidate.
+///
+/// Votes are read from the dispute coordinator data and the available data from the availability
+/// store, both in the parachains DB. The validation code and executor parameters are queried from
+/// the runtime at the best block.
+pub fn export_dispute_bundle(
+	client: &FullClient,
+	database: &DatabaseSource,
+	session: SessionIndex,
+	candidate_hash: Hash,
+) -> Result<DisputeBundle, Error> {
+	let candidate_hash = CandidateHash(candidate_hash);
+	let parachains_db = open_database(database)?;
+	let dispute_coordinator_config = DisputeCoordinatorConfig {
+		col_dispute_data: parachains_db::REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+
+	let mut bundle =
+		DisputeBundle::load(&*parachains_db, &dispute_coordinator_config, session, candidate_hash)
+			.map_err(|err| Error::DisputeBundle(err.to_string()))?
+			.ok_or_else(|| {
+				Error::DisputeBundle(format!(
+					"No votes known for candidate {:?} in session {}",
+					candidate_hash, session
+				))
+			})?;
+
+	bundle.available_data = polkadot_node_core_av_store::load_available_data(
+		&parachains_db,
+		&AVAILABILITY_CONFIG,
+		&candidate_hash,
+	)?;
+
+	let best_hash = client.info().best_hash;
+	let runtime_api = client.runtime_api();
+	bundle.validation_code = runtime_api
+		.validation_code_by_hash(
+			best_hash,
+			bundle.candidate_receipt.descriptor.validation_code_hash,
+		)
+		.map_err(sp_blockchain::Error::from)?;
+	bundle.executor_params = runtime_api
+		.session_executor_params(best_hash, session)
+		.map_err(sp_blockchain::Error::from)?;
+
+	Ok(bundle)
+}
+
+/// Re-run the candidate of a dispute bundle through a local PVF validation host.
+///
+/// Compiled artifacts are written to `artifacts_cache_path`. Secure validator mode is not
+/// required, as the replay is meant to be run on analysis machines.
+pub async fn replay_dispute_bundle(
+	bundle: DisputeBundle,
+	artifacts_cache_path: PathBuf,
+	workers_path: Option<PathBuf>,
+	node_version: Option<String>,
+) -> Result<ValidationResult, Error> {
+	let (Some(available_data), Some(validation_code), Some(executor_params)) =
+		(bundle.available_data, bundle.validation_code, bundle.executor_params)
+	else {
+		return Err(Error::DisputeBundle(
+			"The bundle lacks the available data, validation code or executor parameters".into(),
+		))
+	};
+
+	let (prep_worker_path, exec_worker_path) =
+		workers::determine_workers_paths(workers_path, None, node_version.clone())?;
+
+	validate_candidate_offline(
+		CandidateValidationConfig {
+			artifacts_cache_path,
+			node_version,
+			secure_validator_mode: false,
+			prep_worker_path,
+			exec_worker_path,
+		},
+		available_data.validation_data,
+		validation_code,
+		bundle.candidate_receipt,
+		available_data.pov,
+		executor_params,
+	)
+	.await
+	.map_err(|err| Error::DisputeBundle(err.0))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_core_dispute_coordinator::bundle::BUNDLE_VERSION;
+	use polkadot_primitives::{ExecutorParams, ValidationCode};
+	use test_helpers::{dummy_candidate_receipt, dummy_hash};
+
+	#[test]
+	fn incomplete_bundle_is_not_replayed() {
+		let bundle = DisputeBundle {
+			version: BUNDLE_VERSION,
+			session: 1,
+			candidate_hash: CandidateHash(dummy_hash()),
+			status: None,
+			candidate_receipt: dummy_candidate_receipt(dummy_hash()),
+			valid: Vec::new(),
+			invalid: Vec::new(),
+			available_data: None,
+			validation_code: Some(ValidationCode(vec![1, 2, 3])),
+			executor_params: Some(ExecutorParams::default()),
+		};
+		assert!(!bundle.is_replayable());
+
+		let tempdir = tempfile::tempdir().unwrap();
+		let result = futures::executor::block_on(replay_dispute_bundle(
+			bundle,
+			tempdir.path().join("artifacts"),
+			Some(tempdir.path().to_path_buf()),
+			None,
+		));
+		assert!(matches!(result, Err(Error::DisputeBundle(_))));
+	}
+}
@@ -30,6 +30,9 @@ pub mod overseer;
 #[cfg(feature = "full-node")]
 pub mod workers;
 
+#[cfg(feature = "full-node")]
+pub mod dispute_bundle;
+
 #[cfg(feature = "full-node")]
 pub use self::overseer::{OverseerGen, OverseerGenArgs, RealOverseerGen};
 
@@ -63,7 +66,7 @@ use polkadot_node_subsystem_util::database::Database;
 
 #[cfg(feature = "full-node")]
 pub use {
-	polkadot_overseer::{Handle, Overseer, OverseerConnector, OverseerHandle},
+	polkadot_overseer::{Handle, Overseer, OverseerConnector, OverseerHandle, SubsystemsHealth},
 	polkadot_primitives::runtime_api::ParachainHost,
 	relay_chain_selection::SelectRelayChain,
 	sc_client_api::AuxStore,
@@ -234,6 +237,10 @@ pub enum Error {
 	#[error("Expected at least one of polkadot, kusama, westend or rococo runtime feature")]
 	NoRuntime,
 
+	#[cfg(feature = "full-node")]
+	#[error("Dispute bundle: {0}")]
+	DisputeBundle(String),
+
 	#[cfg(feature = "full-node")]
 	#[error("Worker binaries not executable, prepare binary: {prep_worker_path:?}, execute binary: {exec_worker_path:?}")]
 	InvalidWorkerBinaries { prep_worker_path: PathBuf, exec_worker_path: PathBuf },
@@ -466,6 +473,7 @@ fn new_partial<ChainSelection>(
 	config: &mut Configuration,
 	Basics { task_manager, backend, client, keystore_container, telemetry }: Basics,
 	select_chain: ChainSelection,
+	subsystems_health: Option<SubsystemsHealth>,
 ) -> Result<
 	service::PartialComponents<
 		FullClient,
@@ -602,6 +610,9 @@ where
 					beefy_best_block_stream: beefy_rpc_links.from_voter_best_beefy_stream.clone(),
 					subscription_executor,
 				},
+				overseer: polkadot_rpc::OverseerDeps {
+					subsystems_health: subsystems_health.clone(),
+				},
 				backend: backend.clone(),
 			};
 
@@ -641,6 +652,8 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub workers_names: Option<(String, String)>,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
+	/// The maximum size in bytes of the availability store, if any.
+	pub availability_store_max_size: Option<u64>,
 	#[allow(dead_code)]
 	pub malus_finality_delay: Option<u32>,
 	pub hwbench: Option<sc_sysinfo::HwBench>,
@@ -703,6 +716,7 @@ impl IsParachainNode {
 pub const AVAILABILITY_CONFIG: AvailabilityConfig = AvailabilityConfig {
 	col_data: parachains_db::REAL_COLUMNS.col_availability_data,
 	col_meta: parachains_db::REAL_COLUMNS.col_availability_meta,
+	max_size: None,
 };
 
 /// Create a new full node of arbitrary runtime and executor.
@@ -729,6 +743,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		workers_names,
 		overseer_gen,
 		overseer_message_channel_capacity_override,
+		availability_store_max_size,
 		malus_finality_delay: _malus_finality_delay,
 		hwbench,
 	}: NewFullParams<OverseerGenerator>,
@@ -770,6 +785,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 
 	let overseer_connector = OverseerConnector::default();
 	let overseer_handle = Handle::new(overseer_connector.handle());
+	let subsystems_health = SubsystemsHealth::default();
 
 	let chain_spec = config.chain_spec.cloned_box();
 
@@ -801,7 +817,12 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		import_queue,
 		transaction_pool,
 		other: (rpc_extensions_builder, import_setup, rpc_setup, slot_duration, mut telemetry),
-	} = new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+	} = new_partial::<SelectRelayChain<_>>(
+		&mut config,
+		basics,
+		select_chain,
+		auth_or_collator.then(|| subsystems_health.clone()),
+	)?;
 
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
@@ -1085,10 +1106,15 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					spawner,
 					is_parachain_node,
 					approval_voting_config,
-					availability_config: AVAILABILITY_CONFIG,
+					availability_config: AvailabilityConfig {
+						max_size: availability_store_max_size,
+						..AVAILABILITY_CONFIG
+					},
 					candidate_validation_config,
 					chain_selection_config,
 					dispute_coordinator_config,
+					col_collator_reputation_data: parachains_db::REAL_COLUMNS
+						.col_collator_reputation_data,
 					pvf_checker_enabled,
 					overseer_message_channel_capacity_override,
 					req_protocol_names,
@@ -1097,6 +1123,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 						transaction_pool.clone(),
 					),
 					notification_services,
+					subsystems_health,
 				},
 			)
 			.map_err(|e| {
@@ -1323,7 +1350,7 @@ macro_rules! chain_ops {
 		let chain_selection = LongestChain::new(basics.backend.clone());
 
 		let service::PartialComponents { client, backend, import_queue, task_manager, .. } =
-			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection)?;
+			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection, None)?;
 		Ok((client, backend, import_queue, task_manager))
 	}};
 }
@@ -38,7 +38,7 @@ pub use polkadot_overseer::{
 };
 use polkadot_overseer::{
 	metrics::Metrics as OverseerMetrics, InitializedOverseerBuilder, MetricsTrait, Overseer,
-	OverseerConnector, OverseerHandle, SpawnGlue,
+	OverseerConnector, OverseerHandle, SpawnGlue, SubsystemsHealth,
 };
 
 use parking_lot::Mutex;
@@ -131,6 +131,9 @@ where
 	pub chain_selection_config: ChainSelectionConfig,
 	/// Configuration for the dispute coordinator subsystem.
 	pub dispute_coordinator_config: DisputeCoordinatorConfig,
+	/// The column of the parachains DB in which the collator protocol persists the reputation of
+	/// collators.
+	pub col_collator_reputation_data: u32,
 	/// Enable PVF pre-checking
 	pub pvf_checker_enabled: bool,
 	/// Overseer channel capacity override.
@@ -143,6 +146,8 @@ where
 	pub offchain_transaction_pool_factory: OffchainTransactionPoolFactory<Block>,
 	/// Notification services for validation/collation protocols.
 	pub notification_services: HashMap<PeerSet, Box<dyn NotificationService>>,
+	/// The health of the subsystems, shared with the RPC.
+	pub subsystems_health: SubsystemsHealth,
 }
 
 /// Obtain a prepared `OverseerBuilder`, that is initialized
@@ -171,12 +176,14 @@ pub fn prepared_overseer_builder<Spawner, RuntimeClient>(
 		candidate_validation_config,
 		chain_selection_config,
 		dispute_coordinator_config,
+		col_collator_reputation_data,
 		pvf_checker_enabled,
 		overseer_message_channel_capacity_override,
 		req_protocol_names,
 		peerset_protocol_names,
 		offchain_transaction_pool_factory,
 		notification_services,
+		subsystems_health,
 	}: OverseerGenArgs<Spawner, RuntimeClient>,
 ) -> Result<
 	InitializedOverseerBuilder<
@@ -256,7 +263,7 @@ where
 			IncomingRequestReceivers { pov_req_receiver, chunk_req_receiver },
 			Metrics::register(registry)?,
 		))
-		.availability_recovery(AvailabilityRecoverySubsystem::with_chunks_if_pov_large(
+		.availability_recovery(AvailabilityRecoverySubsystem::with_systematic_chunks_if_pov_large(
 			available_data_req_receiver,
 			Metrics::register(registry)?,
 		))
@@ -301,6 +308,8 @@ where
 					keystore: keystore.clone(),
 					eviction_policy: Default::default(),
 					metrics: Metrics::register(registry)?,
+					db: parachains_db.clone(),
+					col_reputation_data: col_collator_reputation_data,
 				},
 			};
 			CollatorProtocolSubsystem::new(side)
@@ -348,6 +357,7 @@ where
 		.activation_external_listeners(Default::default())
 		.span_per_active_leaf(Default::default())
 		.active_leaves(Default::default())
+		.subsystems_health(subsystems_health)
 		.supports_parachains(runtime_api_client)
 		.metrics(metrics)
 		.spawner(spawner);
@@ -0,0 +1,43 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct ApprovalsOptions {
+	#[clap(long, default_value_t = 89)]
+	/// The last tranche for which the emulated peers send assignments.
+	pub last_considered_tranche: u32,
+
+	#[clap(long, default_value_t = 1)]
+	/// The maximum number of candidates an emulated peer approves with a single signature.
+	pub max_coalesce: u32,
+
+	#[clap(long, default_value_t = 0)]
+	/// The number of emulated peers per candidate which send an assignment, but never
+	/// approve it.
+	pub num_no_shows_per_candidate: u32,
+
+	#[clap(long, default_value_t = true, action = clap::ArgAction::Set)]
+	/// Whether the emulated peers send compact v2 assignments covering multiple candidates.
+	pub enable_assignments_v2: bool,
+
+	#[clap(long, default_value_t = true, action = clap::ArgAction::Set)]
+	/// Stop sending messages for a block as soon as our node has approved it.
+	pub stop_when_approved: bool,
+}
@@ -0,0 +1,175 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+//!
+//! Mocks of the subsystems approval-voting depends on for checking our own assignments.
+
+use futures::FutureExt;
+use polkadot_node_primitives::{AvailableData, BlockData, PoV};
+use polkadot_node_subsystem::{
+	messages::{AvailabilityRecoveryMessage, CandidateValidationMessage, ChainSelectionMessage},
+	overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+use polkadot_primitives::{CandidateCommitments, Hash, HeadData, PersistedValidationData};
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+
+use polkadot_node_primitives::ValidationResult;
+
+const LOG_TARGET: &str = "subsystem-bench::approval-mocks";
+
+/// The `PersistedValidationData` of all candidates used by the test.
+pub fn persisted_validation_data() -> PersistedValidationData {
+	PersistedValidationData {
+		parent_head: HeadData(vec![7, 8, 9]),
+		relay_parent_number: Default::default(),
+		max_pov_size: 1024,
+		relay_parent_storage_root: Default::default(),
+	}
+}
+
+/// A chain selection mock recording the time at which each block has been approved.
+pub struct MockChainSelection {
+	approved_blocks: Arc<Mutex<HashMap<Hash, Instant>>>,
+}
+
+impl MockChainSelection {
+	pub fn new(approved_blocks: Arc<Mutex<HashMap<Hash, Instant>>>) -> MockChainSelection {
+		Self { approved_blocks }
+	}
+}
+
+#[overseer::subsystem(ChainSelection, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockChainSelection {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(ChainSelection, prefix = self::overseer)]
+impl MockChainSelection {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					ChainSelectionMessage::Approved(hash) => {
+						gum::debug!(target: LOG_TARGET, ?hash, "Block approved");
+						self.approved_blocks
+							.lock()
+							.expect("never poisoned; qed")
+							.entry(hash)
+							.or_insert_with(Instant::now);
+					},
+					msg => {
+						gum::debug!(target: LOG_TARGET, ?msg, "Ignoring chain selection message");
+					},
+				},
+			}
+		}
+	}
+}
+
+/// An availability recovery mock which instantly recovers the data of any candidate.
+pub struct MockAvailabilityRecovery {}
+
+#[overseer::subsystem(AvailabilityRecovery, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockAvailabilityRecovery {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(AvailabilityRecovery, prefix = self::overseer)]
+impl MockAvailabilityRecovery {
+	async fn run<Context>(self, mut ctx: Context) {
+		let available_data = AvailableData {
+			pov: Arc::new(PoV { block_data: BlockData(Vec::new()) }),
+			validation_data: persisted_validation_data(),
+		};
+
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					AvailabilityRecoveryMessage::RecoverAvailableData(_, _, _, tx) => {
+						let _ = tx.send(Ok(available_data.clone()));
+					},
+				},
+			}
+		}
+	}
+}
+
+/// A candidate validation mock which considers all candidates valid.
+pub struct MockCandidateValidation {}
+
+#[overseer::subsystem(CandidateValidation, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockCandidateValidation {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(CandidateValidation, prefix = self::overseer)]
+impl MockCandidateValidation {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					CandidateValidationMessage::ValidateFromExhaustive {
+						validation_data,
+						response_sender,
+						..
+					} => {
+						let _ = response_sender.send(Ok(ValidationResult::Valid(
+							CandidateCommitments::default(),
+							validation_data,
+						)));
+					},
+					msg => {
+						gum::debug!(target: LOG_TARGET, ?msg, "Ignoring candidate validation message");
+					},
+				},
+			}
+		}
+	}
+}
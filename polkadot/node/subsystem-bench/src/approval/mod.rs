@@ -0,0 +1,773 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+//! Approval voting and approval distribution benchmark.
+//!
+//! Our node runs the real `approval-voting` and `approval-distribution` subsystems, while the
+//! other validators are emulated by sending their assignments and approvals over the emulated
+//! network, at the tranches they would have been sent on a real network.
+use itertools::Itertools;
+use parity_scale_codec::Encode;
+use std::{
+	collections::{BTreeMap, HashMap},
+	pin::Pin,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc, Mutex,
+	},
+	time::{Duration, Instant},
+};
+
+use colored::Colorize;
+use futures::{Future, FutureExt};
+
+use polkadot_approval_distribution::ApprovalDistribution;
+use polkadot_node_core_approval_voting::{
+	bench_support::{
+		compute_assignments, slot_number_to_tick, AssignmentConfig, Clock, SystemClock, Tick,
+	},
+	ApprovalVotingSubsystem, Config as ApprovalVotingConfig,
+};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	jaeger, request_response::ReqProtocolNames, v3 as protocol_v3, OurView, Versioned, View,
+};
+use polkadot_node_primitives::{
+	approval::{
+		v1::{babe_unsafe_vrf_info, VrfTranscript},
+		v2::{CandidateBitfield, IndirectAssignmentCertV2, IndirectSignedApprovalVoteV2},
+	},
+	BabeAllowedSlots, BabeEpoch, BabeEpochConfiguration,
+};
+use polkadot_node_subsystem::{
+	messages::{AllMessages, ApprovalDistributionMessage, NetworkBridgeEvent},
+	Overseer, OverseerConnector, SpawnGlue,
+};
+use polkadot_node_subsystem_test_helpers::mock::dummy_unpin_handle;
+use polkadot_overseer::{BlockInfo, Handle as OverseerHandle};
+use polkadot_primitives::{
+	vstaging::{node_features::FeatureIndex, ApprovalVotingParams, NodeFeatures},
+	ApprovalVoteMultipleCandidates, BlockNumber, CandidateCommitments, CandidateEvent,
+	CandidateHash, CandidateIndex, CoreIndex, GroupIndex, Hash, HeadData, Header, Id as ParaId,
+	SessionIndex, Slot, ValidatorIndex, ValidatorSignature, ASSIGNMENT_KEY_TYPE_ID,
+	PARACHAIN_KEY_TYPE_ID,
+};
+use polkadot_primitives_test_helpers::dummy_candidate_receipt;
+use sc_keystore::LocalKeystore;
+use sc_service::SpawnTaskHandle;
+use sp_consensus_babe::digests::{CompatibleDigestItem, PreDigest, SecondaryVRFPreDigest};
+use sp_core::{crypto::VrfSecret, sr25519::Pair, Pair as PairT};
+use sp_keystore::Keystore;
+use sp_runtime::{Digest, DigestItem};
+
+use crate::{
+	core::{
+		configuration::{TestAuthorities, TestConfiguration},
+		environment::{TestEnvironment, TestEnvironmentDependencies},
+		mock::{
+			chain_api::{ChainApiState, MockChainApi},
+			dummy_builder,
+			network_bridge::{MockNetworkBridgeTx, NetworkAvailabilityState},
+			AlwaysSupportsParachains, MockRuntimeApi,
+		},
+		network::{
+			generate_new_gossip_topology, generate_peer_connected, NetworkAction, NetworkEmulator,
+		},
+	},
+	GENESIS_HASH,
+};
+
+mod cli;
+mod mocks;
+pub use cli::ApprovalsOptions;
+use mocks::{MockAvailabilityRecovery, MockCandidateValidation, MockChainSelection};
+
+const LOG_TARGET: &str = "subsystem-bench::approval";
+
+/// The slot duration of the emulated relay chain.
+const SLOT_DURATION_MILLIS: u64 = 6000;
+/// The number of ticks in a slot.
+const TICKS_PER_SLOT: u64 = SLOT_DURATION_MILLIS / 500;
+/// The tick duration of approval-voting.
+const TICK_DURATION: Duration = Duration::from_millis(500);
+/// The number of ticks an emulated peer needs to check a candidate after sending its assignment.
+const APPROVAL_CHECK_TICKS: u32 = 2;
+/// The session all blocks are part of.
+const SESSION_INDEX: SessionIndex = 0;
+/// The column approval-voting stores its data in.
+const APPROVAL_DATA_COLUMN: u32 = 0;
+/// The number of columns of the approval-voting database.
+const NUM_COLUMNS: u32 = 1;
+
+/// A clock which runs behind the system clock by a configurable number of ticks.
+///
+/// The relay chain blocks are generated before the test starts, so the benchmark moves the
+/// clock of approval-voting back to the slot of the first block when it starts importing them.
+struct PastSystemClock {
+	real_system_clock: SystemClock,
+	delta_ticks: Arc<AtomicU64>,
+}
+
+impl Clock for PastSystemClock {
+	fn tick_now(&self) -> Tick {
+		self.real_system_clock.tick_now() - self.delta_ticks.load(Ordering::SeqCst)
+	}
+
+	fn wait(&self, tick: Tick) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> {
+		self.real_system_clock.wait(tick + self.delta_ticks.load(Ordering::SeqCst))
+	}
+}
+
+/// A message an emulated peer sends to our node.
+struct TestMessage {
+	/// The tranche (ticks since the start of the block's slot) at which it is sent.
+	tranche: u32,
+	/// The emulated validator sending the message.
+	sender: ValidatorIndex,
+	/// The message itself.
+	message: protocol_v3::ApprovalDistributionMessage,
+}
+
+impl TestMessage {
+	fn is_assignment(&self) -> bool {
+		matches!(self.message, protocol_v3::ApprovalDistributionMessage::Assignments(_))
+	}
+}
+
+/// All the data of a generated relay chain block.
+struct BlockTestData {
+	slot: Slot,
+	hash: Hash,
+	header: Header,
+	candidates: Vec<CandidateEvent>,
+	/// The messages of the emulated peers, sorted by tranche.
+	messages: Vec<TestMessage>,
+}
+
+/// The state of the approval voting benchmark.
+pub struct ApprovalTestState {
+	options: ApprovalsOptions,
+	config: TestConfiguration,
+	test_authorities: TestAuthorities,
+	babe_epoch: BabeEpoch,
+	blocks: Vec<BlockTestData>,
+	/// How far behind the system clock the clock of approval-voting is.
+	delta_ticks: Arc<AtomicU64>,
+	/// The time at which each block has been approved by our node.
+	approved_blocks: Arc<Mutex<HashMap<Hash, Instant>>>,
+}
+
+impl ApprovalTestState {
+	pub fn new(config: &TestConfiguration, options: ApprovalsOptions) -> Self {
+		let config = config.clone();
+		let test_authorities = config.generate_authorities();
+		let babe_epoch = BabeEpoch {
+			epoch_index: 1,
+			start_slot: Slot::from(0),
+			duration: 200,
+			authorities: vec![(test_authorities.keyrings[0].clone().public().into(), 1)],
+			randomness: [0u8; 32],
+			config: BabeEpochConfiguration {
+				c: (1, 4),
+				allowed_slots: BabeAllowedSlots::PrimarySlots,
+			},
+		};
+
+		let mut state = Self {
+			options,
+			config,
+			test_authorities,
+			babe_epoch,
+			blocks: Vec::new(),
+			delta_ticks: Arc::new(AtomicU64::new(0)),
+			approved_blocks: Arc::new(Mutex::new(HashMap::new())),
+		};
+		state.generate_blocks();
+		state
+	}
+
+	/// Generates the relay chain blocks and the messages of the emulated peers.
+	fn generate_blocks(&mut self) {
+		gum::info!(target: LOG_TARGET, "{}", "Generating keys.".bright_blue());
+
+		let pairs = self
+			.test_authorities
+			.keyrings
+			.iter()
+			.map(|keyring| keyring.clone().pair())
+			.collect::<Vec<_>>();
+		let keystores = self
+			.test_authorities
+			.key_seeds
+			.iter()
+			.map(|seed| {
+				let keystore = LocalKeystore::in_memory();
+				keystore
+					.sr25519_generate_new(ASSIGNMENT_KEY_TYPE_ID, Some(seed))
+					.expect("Insert key into keystore");
+				keystore
+			})
+			.collect::<Vec<_>>();
+
+		let session_info =
+			MockRuntimeApi::new(self.config.clone(), self.test_authorities.clone()).session_info();
+		let assignment_config = AssignmentConfig::from(&session_info);
+		let n_groups = session_info.validator_groups.len();
+
+		// The first block is in the next slot.
+		let initial_slot = Slot::from(SystemClock.tick_now() / TICKS_PER_SLOT + 1);
+		let mut parent_hash = GENESIS_HASH;
+
+		for block_index in 0..self.config.num_blocks {
+			gum::info!(
+				target: LOG_TARGET,
+				"{}",
+				format!("Generating block {}/{}", block_index + 1, self.config.num_blocks)
+					.bright_blue()
+			);
+			let block = self.generate_block(
+				(block_index + 1) as BlockNumber,
+				parent_hash,
+				initial_slot + block_index as u64,
+				n_groups,
+				&assignment_config,
+				&pairs,
+				&keystores,
+			);
+			parent_hash = block.hash;
+			self.blocks.push(block);
+		}
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn generate_block(
+		&self,
+		number: BlockNumber,
+		parent_hash: Hash,
+		slot: Slot,
+		n_groups: usize,
+		assignment_config: &AssignmentConfig,
+		pairs: &[Pair],
+		keystores: &[LocalKeystore],
+	) -> BlockTestData {
+		// The relay VRF of the block is signed by our node, the only BABE authority.
+		let vrf_signature = pairs[0]
+			.vrf_sign(&VrfTranscript::new(b"subsystem-bench", &[(b"slot", &slot.encode())]).into());
+		let mut digest = Digest::default();
+		digest.push(DigestItem::babe_pre_digest(PreDigest::SecondaryVRF(SecondaryVRFPreDigest {
+			authority_index: 0,
+			slot,
+			vrf_signature,
+		})));
+		let header = Header {
+			digest,
+			extrinsics_root: Default::default(),
+			number,
+			state_root: Default::default(),
+			parent_hash,
+		};
+		let hash = header.hash();
+
+		let relay_vrf_story = babe_unsafe_vrf_info(&header)
+			.expect("header has a BABE pre-digest; qed")
+			.compute_randomness(
+				&self.babe_epoch.authorities,
+				&self.babe_epoch.randomness,
+				self.babe_epoch.epoch_index,
+			)
+			.expect("our node is the BABE authority; qed");
+
+		// One candidate is included on each core. The candidate index is the core index.
+		let candidates = (0..self.config.n_cores)
+			.map(|core_index| {
+				let mut candidate_receipt = dummy_candidate_receipt(hash);
+				candidate_receipt.descriptor.para_id = ParaId::from(core_index as u32);
+				candidate_receipt.commitments_hash = CandidateCommitments::default().hash();
+
+				CandidateEvent::CandidateIncluded(
+					candidate_receipt,
+					HeadData(Vec::new()),
+					CoreIndex(core_index as u32),
+					GroupIndex((core_index % n_groups) as u32),
+				)
+			})
+			.collect::<Vec<_>>();
+		let candidate_hashes = candidates
+			.iter()
+			.map(|event| match event {
+				CandidateEvent::CandidateIncluded(receipt, _, _, _) => receipt.hash(),
+				_ => unreachable!("only included candidates are generated; qed"),
+			})
+			.collect::<Vec<_>>();
+		let leaving_cores = candidates
+			.iter()
+			.zip(candidate_hashes.iter())
+			.map(|(event, candidate_hash)| match event {
+				CandidateEvent::CandidateIncluded(_, _, core_index, group_index) =>
+					(*candidate_hash, *core_index, *group_index),
+				_ => unreachable!("only included candidates are generated; qed"),
+			})
+			.collect::<Vec<_>>();
+
+		// Compute the assignments of all emulated peers. Our node computes its own.
+		let mut assignments = Vec::new();
+		for (validator_index, keystore) in keystores.iter().enumerate().skip(1) {
+			let our_assignments = compute_assignments(
+				keystore,
+				relay_vrf_story.clone(),
+				assignment_config,
+				leaving_cores.clone(),
+				self.options.enable_assignments_v2,
+			);
+
+			// A compact assignment covers multiple cores with the same certificate.
+			let mut unique_assignments: Vec<(_, Vec<CandidateIndex>)> = Vec::new();
+			for (core_index, assignment) in our_assignments {
+				match unique_assignments.iter_mut().find(|(a, _)| a.cert() == assignment.cert()) {
+					Some((_, candidate_indices)) => candidate_indices.push(core_index.0),
+					None => unique_assignments.push((assignment, vec![core_index.0])),
+				}
+			}
+
+			assignments.extend(unique_assignments.into_iter().map(
+				|(assignment, mut candidate_indices)| {
+					candidate_indices.sort();
+					(
+						assignment.tranche(),
+						ValidatorIndex(validator_index as u32),
+						assignment.cert().clone(),
+						candidate_indices,
+					)
+				},
+			));
+		}
+		assignments.sort_by_key(|(tranche, validator_index, _, _)| (*tranche, *validator_index));
+
+		// Only send the assignments needed to approve all candidates, the no-shows included.
+		let needed_assignments =
+			self.config.needed_approvals + self.options.num_no_shows_per_candidate as usize;
+		let mut assignment_count = vec![0usize; candidates.len()];
+		let mut no_show_count = vec![0u32; candidates.len()];
+		let mut approvals_to_send: BTreeMap<ValidatorIndex, Vec<(u32, CandidateIndex)>> =
+			BTreeMap::new();
+		let mut messages = Vec::new();
+
+		for (tranche, validator_index, cert, candidate_indices) in assignments {
+			if tranche > self.options.last_considered_tranche {
+				break
+			}
+
+			if candidate_indices
+				.iter()
+				.all(|index| assignment_count[*index as usize] >= needed_assignments)
+			{
+				continue
+			}
+
+			let bitfield: CandidateBitfield = candidate_indices
+				.clone()
+				.try_into()
+				.expect("assignments always cover at least one candidate; qed");
+			messages.push(TestMessage {
+				tranche,
+				sender: validator_index,
+				message: protocol_v3::ApprovalDistributionMessage::Assignments(vec![(
+					IndirectAssignmentCertV2 { block_hash: hash, validator: validator_index, cert },
+					bitfield,
+				)]),
+			});
+
+			for candidate_index in candidate_indices {
+				assignment_count[candidate_index as usize] += 1;
+
+				// The first assigned validators of a candidate never approve it.
+				if no_show_count[candidate_index as usize] < self.options.num_no_shows_per_candidate
+				{
+					no_show_count[candidate_index as usize] += 1;
+					continue
+				}
+				approvals_to_send
+					.entry(validator_index)
+					.or_default()
+					.push((tranche, candidate_index));
+			}
+		}
+
+		// Sign the approvals, coalescing up to `max_coalesce` candidates per signature.
+		for (validator_index, approvals) in approvals_to_send {
+			for chunk in approvals.chunks(self.options.max_coalesce.max(1) as usize) {
+				let tranche = chunk.iter().map(|(tranche, _)| *tranche).max().unwrap_or_default();
+				let candidate_indices =
+					chunk.iter().map(|(_, index)| *index).sorted().collect::<Vec<_>>();
+				let signed_hashes = candidate_indices
+					.iter()
+					.map(|index| candidate_hashes[*index as usize])
+					.collect::<Vec<CandidateHash>>();
+				let payload =
+					ApprovalVoteMultipleCandidates(&signed_hashes).signing_payload(SESSION_INDEX);
+				let signature: ValidatorSignature =
+					pairs[validator_index.0 as usize].sign(&payload[..]).into();
+
+				messages.push(TestMessage {
+					tranche: tranche + APPROVAL_CHECK_TICKS,
+					sender: validator_index,
+					message: protocol_v3::ApprovalDistributionMessage::Approvals(vec![
+						IndirectSignedApprovalVoteV2 {
+							block_hash: hash,
+							candidate_indices: candidate_indices
+								.try_into()
+								.expect("approvals always cover at least one candidate; qed"),
+							validator: validator_index,
+							signature,
+						},
+					]),
+				});
+			}
+		}
+
+		// Assignments go first if sent in the same tick as an approval.
+		messages.sort_by_key(|message| (message.tranche, !message.is_assignment()));
+
+		BlockTestData { slot, hash, header, candidates, messages }
+	}
+
+	fn candidate_events(&self) -> HashMap<Hash, Vec<CandidateEvent>> {
+		self.blocks.iter().map(|block| (block.hash, block.candidates.clone())).collect()
+	}
+
+	fn block_headers(&self) -> HashMap<Hash, Header> {
+		self.blocks.iter().map(|block| (block.hash, block.header.clone())).collect()
+	}
+
+	fn is_approved(&self, hash: &Hash) -> bool {
+		self.approved_blocks.lock().expect("never poisoned; qed").contains_key(hash)
+	}
+}
+
+fn build_overseer(
+	spawn_task_handle: SpawnTaskHandle,
+	runtime_api: MockRuntimeApi,
+	chain_api: MockChainApi,
+	chain_selection: MockChainSelection,
+	network_bridge: MockNetworkBridgeTx,
+	approval_voting: ApprovalVotingSubsystem,
+	approval_distribution: ApprovalDistribution,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let dummy = dummy_builder!(spawn_task_handle);
+	let builder = dummy
+		.replace_runtime_api(|_| runtime_api)
+		.replace_chain_api(|_| chain_api)
+		.replace_chain_selection(|_| chain_selection)
+		.replace_network_bridge_tx(|_| network_bridge)
+		.replace_availability_recovery(|_| MockAvailabilityRecovery {})
+		.replace_candidate_validation(|_| MockCandidateValidation {})
+		.replace_approval_voting(|_| approval_voting)
+		.replace_approval_distribution(|_| approval_distribution);
+
+	let (overseer, raw_handle) =
+		builder.build_with_connector(overseer_connector).expect("Should not fail");
+
+	(overseer, OverseerHandle::new(raw_handle))
+}
+
+struct NoSyncOracle;
+
+impl sp_consensus::SyncOracle for NoSyncOracle {
+	fn is_major_syncing(&self) -> bool {
+		false
+	}
+
+	fn is_offline(&self) -> bool {
+		false
+	}
+}
+
+/// Takes a test configuration and uses it to create the `TestEnvironment`.
+pub fn prepare_test(config: TestConfiguration, state: &ApprovalTestState) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+
+	let node_features = NodeFeatures::repeat(
+		state.options.enable_assignments_v2,
+		FeatureIndex::EnableAssignmentsV2 as usize + 1,
+	);
+	let runtime_api = MockRuntimeApi::new(config.clone(), state.test_authorities.clone())
+		.with_candidate_events(state.candidate_events())
+		.with_babe_epoch(state.babe_epoch.clone())
+		.with_node_features(node_features)
+		.with_approval_voting_params(ApprovalVotingParams {
+			max_approval_coalesce_count: state.options.max_coalesce,
+		});
+	let chain_api = MockChainApi::new(ChainApiState { block_headers: state.block_headers() });
+	let chain_selection = MockChainSelection::new(state.approved_blocks.clone());
+
+	let network = NetworkEmulator::new(
+		&config,
+		&dependencies,
+		&state.test_authorities,
+		ReqProtocolNames::new(GENESIS_HASH, None),
+	);
+	// Nothing is fetched over request/response by the approval subsystems.
+	let availability_state = NetworkAvailabilityState {
+		candidate_hashes: HashMap::new(),
+		available_data: Vec::new(),
+		chunks: Vec::new(),
+	};
+	let network_bridge =
+		MockNetworkBridgeTx::new(config.clone(), availability_state, network.clone());
+
+	// Our node is the validator at index 0.
+	let keystore = LocalKeystore::in_memory();
+	for key_type in [ASSIGNMENT_KEY_TYPE_ID, PARACHAIN_KEY_TYPE_ID] {
+		keystore
+			.sr25519_generate_new(key_type, Some(&state.test_authorities.key_seeds[0]))
+			.expect("Insert key into keystore");
+	}
+
+	let db = kvdb_memorydb::create(NUM_COLUMNS);
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[]);
+	let clock =
+		PastSystemClock { real_system_clock: SystemClock, delta_ticks: state.delta_ticks.clone() };
+	let approval_voting = ApprovalVotingSubsystem::with_config_and_clock(
+		ApprovalVotingConfig {
+			col_approval_data: APPROVAL_DATA_COLUMN,
+			slot_duration_millis: SLOT_DURATION_MILLIS,
+		},
+		Arc::new(db),
+		Arc::new(keystore),
+		Box::new(NoSyncOracle),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+		Arc::new(clock),
+	);
+	let approval_distribution =
+		ApprovalDistribution::new(Metrics::try_register(&dependencies.registry).unwrap());
+
+	let (overseer, overseer_handle) = build_overseer(
+		dependencies.task_manager.spawn_handle(),
+		runtime_api,
+		chain_api,
+		chain_selection,
+		network_bridge,
+		approval_voting,
+		approval_distribution,
+	);
+
+	TestEnvironment::new(
+		dependencies,
+		config,
+		network,
+		overseer,
+		overseer_handle,
+		vec!["approval-voting", "approval-distribution"],
+	)
+}
+
+// Sends `message` from the emulated peer `sender` to approval-distribution over the emulated
+// network.
+fn send_peer_message(
+	env: &TestEnvironment,
+	test_authorities: &TestAuthorities,
+	sender: ValidatorIndex,
+	message: protocol_v3::ApprovalDistributionMessage,
+) {
+	let peer_id = test_authorities.peer_ids[sender.0 as usize];
+	let authority_id = test_authorities.validator_authority_id[sender.0 as usize].clone();
+	let size = message.encoded_size();
+	let network = env.network().clone();
+	let mut overseer_handle = env.overseer_handle();
+
+	let future = async move {
+		// Account our node receiving the message.
+		network.inc_received(size);
+		overseer_handle
+			.send_msg(
+				AllMessages::ApprovalDistribution(
+					ApprovalDistributionMessage::NetworkBridgeUpdate(
+						NetworkBridgeEvent::PeerMessage(peer_id, Versioned::V3(message)),
+					),
+				),
+				LOG_TARGET,
+			)
+			.await;
+	}
+	.boxed();
+
+	let action = NetworkAction::new(
+		authority_id.clone(),
+		future,
+		size,
+		crate::core::configuration::random_latency(env.config().latency.as_ref()),
+	);
+	env.network().clone().submit_peer_action(authority_id, action);
+}
+
+pub async fn benchmark_approvals(env: &mut TestEnvironment, state: ApprovalTestState) {
+	let config = env.config().clone();
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	// Connect the emulated peers and set up the gossip topology.
+	for event in generate_peer_connected(&state.test_authorities) {
+		env.send_message(AllMessages::ApprovalDistribution(
+			ApprovalDistributionMessage::NetworkBridgeUpdate(event),
+		))
+		.await;
+	}
+	env.send_message(AllMessages::ApprovalDistribution(
+		ApprovalDistributionMessage::NetworkBridgeUpdate(NetworkBridgeEvent::NewGossipTopology(
+			generate_new_gossip_topology(&state.test_authorities, SESSION_INDEX),
+		)),
+	))
+	.await;
+
+	// Move the approval-voting clock back to the slot of the first block.
+	let first_block_tick = slot_number_to_tick(SLOT_DURATION_MILLIS, state.blocks[0].slot);
+	state
+		.delta_ticks
+		.store(SystemClock.tick_now().saturating_sub(first_block_tick), Ordering::SeqCst);
+	let clock =
+		PastSystemClock { real_system_clock: SystemClock, delta_ticks: state.delta_ticks.clone() };
+
+	let start_marker = Instant::now();
+	let mut imported_blocks = 0;
+	let mut next_message = vec![0usize; state.blocks.len()];
+	let mut import_times = HashMap::new();
+	let mut total_assignments = 0usize;
+	let mut total_approvals = 0usize;
+
+	loop {
+		let tick_now = clock.tick_now();
+
+		// Import the blocks whose slot has started.
+		while imported_blocks < state.blocks.len() &&
+			slot_number_to_tick(SLOT_DURATION_MILLIS, state.blocks[imported_blocks].slot) <=
+				tick_now
+		{
+			let block = &state.blocks[imported_blocks];
+			gum::info!(target: LOG_TARGET, "Current block {}/{}", imported_blocks + 1, config.num_blocks);
+			env.metrics().set_current_block(imported_blocks);
+			env.import_block(BlockInfo {
+				hash: block.hash,
+				parent_hash: block.header.parent_hash,
+				number: block.header.number,
+				unpin_handle: dummy_unpin_handle(block.hash),
+			})
+			.await;
+			import_times.insert(block.hash, Instant::now());
+
+			env.send_message(AllMessages::ApprovalDistribution(
+				ApprovalDistributionMessage::NetworkBridgeUpdate(
+					NetworkBridgeEvent::OurViewChange(OurView::new(
+						[(block.hash, Arc::new(jaeger::Span::Disabled))],
+						0,
+					)),
+				),
+			))
+			.await;
+			for peer_id in state.test_authorities.peer_ids.iter().skip(1) {
+				env.send_message(AllMessages::ApprovalDistribution(
+					ApprovalDistributionMessage::NetworkBridgeUpdate(
+						NetworkBridgeEvent::PeerViewChange(*peer_id, View::new([block.hash], 0)),
+					),
+				))
+				.await;
+			}
+			imported_blocks += 1;
+		}
+
+		// Send the messages of the emulated peers which are due.
+		for (block, next_message) in
+			state.blocks.iter().zip(next_message.iter_mut()).take(imported_blocks)
+		{
+			if state.options.stop_when_approved && state.is_approved(&block.hash) {
+				*next_message = block.messages.len();
+				continue
+			}
+
+			let block_tick = slot_number_to_tick(SLOT_DURATION_MILLIS, block.slot);
+			while let Some(message) = block.messages.get(*next_message) {
+				if block_tick + message.tranche as Tick > tick_now {
+					break
+				}
+				if message.is_assignment() {
+					total_assignments += 1;
+				} else {
+					total_approvals += 1;
+				}
+				send_peer_message(
+					env,
+					&state.test_authorities,
+					message.sender,
+					message.message.clone(),
+				);
+				*next_message += 1;
+			}
+		}
+
+		let all_sent = imported_blocks == state.blocks.len() &&
+			state
+				.blocks
+				.iter()
+				.zip(next_message.iter())
+				.all(|(block, next_message)| *next_message == block.messages.len());
+		let all_approved = state.blocks.iter().all(|block| state.is_approved(&block.hash));
+
+		if all_approved || (all_sent && !state.options.stop_when_approved) {
+			break
+		}
+
+		if all_sent {
+			// Wait for the no-show timeouts and our own approvals, but not forever.
+			let last_tick = slot_number_to_tick(
+				SLOT_DURATION_MILLIS,
+				state.blocks.last().expect("at least one block; qed").slot,
+			) + config.n_delay_tranches as Tick +
+				config.no_show_slots as Tick * TICKS_PER_SLOT * 2;
+			if tick_now > last_tick {
+				gum::warn!(target: LOG_TARGET, "{}", "Not all blocks have been approved".bright_red());
+				break
+			}
+		}
+
+		tokio::time::sleep(TICK_DURATION).await;
+	}
+
+	let duration: u128 = start_marker.elapsed().as_millis();
+	gum::info!("All blocks processed in {}", format!("{:?}ms", duration).cyan());
+	gum::info!(
+		"Sent {} assignments and {} approvals",
+		format!("{}", total_assignments).cyan(),
+		format!("{}", total_approvals).cyan()
+	);
+
+	let approved_blocks = state.approved_blocks.lock().expect("never poisoned; qed").clone();
+	for (index, block) in state.blocks.iter().enumerate() {
+		match (approved_blocks.get(&block.hash), import_times.get(&block.hash)) {
+			(Some(approved_at), Some(imported_at)) => {
+				let approval_time = approved_at.saturating_duration_since(*imported_at);
+				env.metrics().set_block_time(approval_time.as_millis() as u64);
+				gum::info!(
+					"Block {} approved in {}",
+					index + 1,
+					format!("{:?}ms", approval_time.as_millis()).cyan()
+				);
+			},
+			_ => gum::info!("Block {} {}", index + 1, "not approved".bright_red()),
+		}
+	}
+
+	gum::info!("{}", &env);
+	env.stop().await;
+}
@@ -147,7 +147,17 @@ fn prepare_test_inner(
 		subsystem,
 	);
 
-	(TestEnvironment::new(dependencies, config, network, overseer, overseer_handle), req_cfg)
+	(
+		TestEnvironment::new(
+			dependencies,
+			config,
+			network,
+			overseer,
+			overseer_handle,
+			vec!["availability-recovery"],
+		),
+		req_cfg,
+	)
 }
 
 #[derive(Clone)]
@@ -13,7 +13,7 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
-use super::availability::DataAvailabilityReadOptions;
+use super::{approval::ApprovalsOptions, availability::DataAvailabilityReadOptions};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
@@ -30,6 +30,10 @@ pub struct TestSequenceOptions {
 pub enum TestObjective {
 	/// Benchmark availability recovery strategies.
 	DataAvailabilityRead(DataAvailabilityReadOptions),
+	/// Benchmark approval-voting and approval-distribution.
+	ApprovalVoting(ApprovalsOptions),
+	/// Benchmark statement-distribution.
+	StatementDistribution,
 	/// Run a test sequence specified in a file
 	TestSequence(TestSequenceOptions),
 }
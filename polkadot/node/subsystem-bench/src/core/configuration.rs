@@ -20,7 +20,8 @@ use keyring::Keyring;
 use std::{path::Path, time::Duration};
 
 pub use crate::cli::TestObjective;
-use polkadot_primitives::{AuthorityDiscoveryId, ValidatorId};
+use polkadot_node_network_protocol::PeerId;
+use polkadot_primitives::{AssignmentId, AuthorityDiscoveryId, ValidatorId};
 use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
 use serde::{Deserialize, Serialize};
 
@@ -58,6 +59,36 @@ fn default_connectivity() -> usize {
 	100
 }
 
+// Default number of approvals needed to approve a candidate.
+fn default_needed_approvals() -> usize {
+	30
+}
+
+// Default number of delay tranches.
+fn default_n_delay_tranches() -> usize {
+	89
+}
+
+// Default number of slots after which an assigned validator is considered a no-show.
+fn default_no_show_slots() -> usize {
+	3
+}
+
+// Default number of samples of `relay_vrf_modulo`.
+fn default_relay_vrf_modulo_samples() -> usize {
+	6
+}
+
+// Default number of validators in a backing group.
+fn default_max_validators_per_core() -> usize {
+	5
+}
+
+// Default number of backing votes required to back a candidate.
+fn default_minimum_backing_votes() -> u32 {
+	2
+}
+
 /// The test input parameters
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TestConfiguration {
@@ -94,6 +125,27 @@ pub struct TestConfiguration {
 	pub connectivity: usize,
 	/// Number of blocks to run the test for
 	pub num_blocks: usize,
+	/// Number of approvals needed to approve a candidate.
+	#[serde(default = "default_needed_approvals")]
+	pub needed_approvals: usize,
+	/// The number of delay tranches in total.
+	#[serde(default = "default_n_delay_tranches")]
+	pub n_delay_tranches: usize,
+	/// The number of slots after which an assigned validator is considered a no-show.
+	#[serde(default = "default_no_show_slots")]
+	pub no_show_slots: usize,
+	/// The number of samples of `relay_vrf_modulo`.
+	#[serde(default = "default_relay_vrf_modulo_samples")]
+	pub relay_vrf_modulo_samples: usize,
+	/// The width of the zeroth delay tranche.
+	#[serde(default)]
+	pub zeroth_delay_tranche_width: usize,
+	/// The number of validators in a backing group.
+	#[serde(default = "default_max_validators_per_core")]
+	pub max_validators_per_core: usize,
+	/// The number of backing votes required to back a candidate.
+	#[serde(default = "default_minimum_backing_votes")]
+	pub minimum_backing_votes: u32,
 }
 
 fn generate_pov_sizes(count: usize, min_kib: usize, max_kib: usize) -> Vec<usize> {
@@ -132,6 +184,9 @@ pub struct TestAuthorities {
 	pub keyrings: Vec<Keyring>,
 	pub validator_public: Vec<ValidatorId>,
 	pub validator_authority_id: Vec<AuthorityDiscoveryId>,
+	pub validator_assignment_id: Vec<AssignmentId>,
+	pub key_seeds: Vec<String>,
+	pub peer_ids: Vec<PeerId>,
 }
 
 impl TestConfiguration {
@@ -164,7 +219,23 @@ impl TestConfiguration {
 			.map(|keyring| keyring.clone().public().into())
 			.collect::<Vec<_>>();
 
-		TestAuthorities { keyrings, validator_public, validator_authority_id }
+		let validator_assignment_id: Vec<AssignmentId> = keyrings
+			.iter()
+			.map(|keyring| keyring.clone().public().into())
+			.collect::<Vec<_>>();
+
+		let key_seeds = keyrings.iter().map(|keyring| keyring.seed()).collect::<Vec<_>>();
+
+		let peer_ids = (0..self.n_validators).map(|_| PeerId::random()).collect::<Vec<_>>();
+
+		TestAuthorities {
+			keyrings,
+			validator_public,
+			validator_authority_id,
+			validator_assignment_id,
+			key_seeds,
+			peer_ids,
+		}
 	}
 
 	/// An unconstrained standard configuration matching Polkadot/Kusama
@@ -190,6 +261,13 @@ impl TestConfiguration {
 			min_pov_size,
 			max_pov_size,
 			connectivity: 100,
+			needed_approvals: default_needed_approvals(),
+			n_delay_tranches: default_n_delay_tranches(),
+			no_show_slots: default_no_show_slots(),
+			relay_vrf_modulo_samples: default_relay_vrf_modulo_samples(),
+			zeroth_delay_tranche_width: 0,
+			max_validators_per_core: default_max_validators_per_core(),
+			minimum_backing_votes: default_minimum_backing_votes(),
 		}
 	}
 
@@ -217,6 +295,13 @@ impl TestConfiguration {
 			min_pov_size,
 			max_pov_size,
 			connectivity: 95,
+			needed_approvals: default_needed_approvals(),
+			n_delay_tranches: default_n_delay_tranches(),
+			no_show_slots: default_no_show_slots(),
+			relay_vrf_modulo_samples: default_relay_vrf_modulo_samples(),
+			zeroth_delay_tranche_width: 0,
+			max_validators_per_core: default_max_validators_per_core(),
+			minimum_backing_votes: default_minimum_backing_votes(),
 		}
 	}
 
@@ -244,6 +329,13 @@ impl TestConfiguration {
 			min_pov_size,
 			max_pov_size,
 			connectivity: 67,
+			needed_approvals: default_needed_approvals(),
+			n_delay_tranches: default_n_delay_tranches(),
+			no_show_slots: default_no_show_slots(),
+			relay_vrf_modulo_samples: default_relay_vrf_modulo_samples(),
+			zeroth_delay_tranche_width: 0,
+			max_validators_per_core: default_max_validators_per_core(),
+			minimum_backing_votes: default_minimum_backing_votes(),
 		}
 	}
 }
@@ -192,6 +192,8 @@ pub struct TestEnvironment {
 	network: NetworkEmulator,
 	/// Configuration/env metrics
 	metrics: TestEnvironmentMetrics,
+	/// The task groups of the subsystems under test, used to report their resource usage.
+	subsystems_under_test: Vec<&'static str>,
 }
 
 impl TestEnvironment {
@@ -202,6 +204,7 @@ impl TestEnvironment {
 		network: NetworkEmulator,
 		overseer: Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>,
 		overseer_handle: OverseerHandle,
+		subsystems_under_test: Vec<&'static str>,
 	) -> Self {
 		let metrics = TestEnvironmentMetrics::new(&dependencies.registry)
 			.expect("Metrics need to be registered");
@@ -230,6 +233,7 @@ impl TestEnvironment {
 			config,
 			network,
 			metrics,
+			subsystems_under_test,
 		}
 	}
 
@@ -253,6 +257,10 @@ impl TestEnvironment {
 		self.runtime_handle.clone()
 	}
 
+	pub fn overseer_handle(&self) -> OverseerHandle {
+		self.overseer_handle.clone()
+	}
+
 	// Send a message to the subsystem under test environment.
 	pub async fn send_message(&mut self, msg: AllMessages) {
 		self.overseer_handle
@@ -306,15 +314,22 @@ impl Display for TestEnvironment {
 		)?;
 
 		let test_metrics = super::display::parse_metrics(self.registry());
-		let subsystem_cpu_metrics =
-			test_metrics.subset_with_label_value("task_group", "availability-recovery");
-		let total_cpu = subsystem_cpu_metrics.sum_by("substrate_tasks_polling_duration_sum");
-		writeln!(f, "Total subsystem CPU usage {}", format!("{:.2}s", total_cpu).bright_purple())?;
-		writeln!(
-			f,
-			"CPU usage per block {}",
-			format!("{:.2}s", total_cpu / self.config().num_blocks as f64).bright_purple()
-		)?;
+		for subsystem in self.subsystems_under_test.iter() {
+			let subsystem_cpu_metrics =
+				test_metrics.subset_with_label_value("task_group", subsystem);
+			let total_cpu = subsystem_cpu_metrics.sum_by("substrate_tasks_polling_duration_sum");
+			writeln!(
+				f,
+				"{} CPU usage {}",
+				subsystem.to_string().bright_green(),
+				format!("{:.2}s", total_cpu).bright_purple()
+			)?;
+			writeln!(
+				f,
+				"CPU usage per block {}",
+				format!("{:.2}s", total_cpu / self.config().num_blocks as f64).bright_purple()
+			)?;
+		}
 
 		let test_env_cpu_metrics =
 			test_metrics.subset_with_label_value("task_group", "test-environment");
@@ -29,8 +29,12 @@ impl Keyring {
 		Self { name }
 	}
 
+	pub fn seed(&self) -> String {
+		format!("//{}", self.name)
+	}
+
 	pub fn pair(self) -> Pair {
-		Pair::from_string(&format!("//{}", self.name), None).expect("input is always good; qed")
+		Pair::from_string(&self.seed(), None).expect("input is always good; qed")
 	}
 
 	pub fn public(self) -> Public {
@@ -0,0 +1,120 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+//!
+//! A generic chain api subsystem mockup suitable to be used in benchmarks.
+
+use polkadot_primitives::{BlockNumber, Hash, Header};
+
+use polkadot_node_subsystem::{
+	messages::ChainApiMessage, overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+
+use futures::FutureExt;
+use std::collections::HashMap;
+
+const LOG_TARGET: &str = "subsystem-bench::chain-api-mock";
+
+/// State used to respond to `BlockHeader` requests.
+pub struct ChainApiState {
+	pub block_headers: HashMap<Hash, Header>,
+}
+
+pub struct MockChainApi {
+	state: ChainApiState,
+}
+
+impl MockChainApi {
+	pub fn new(state: ChainApiState) -> MockChainApi {
+		Self { state }
+	}
+
+	// Walk back the chain from `hash` and collect at most `k` ancestor hashes.
+	fn ancestors(&self, hash: Hash, k: usize) -> Vec<Hash> {
+		let mut ancestors = Vec::with_capacity(k);
+		let mut current = self.state.block_headers.get(&hash);
+
+		while let Some(header) = current {
+			if ancestors.len() == k {
+				break
+			}
+			ancestors.push(header.parent_hash);
+			current = self.state.block_headers.get(&header.parent_hash);
+		}
+
+		ancestors
+	}
+}
+
+#[overseer::subsystem(ChainApi, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockChainApi {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(ChainApi, prefix = self::overseer)]
+impl MockChainApi {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => {
+					gum::debug!(target: LOG_TARGET, msg=?msg, "recv message");
+
+					match msg {
+						ChainApiMessage::BlockHeader(hash, response_channel) => {
+							let _ = response_channel.send(Ok(self
+								.state
+								.block_headers
+								.get(&hash)
+								.cloned()));
+						},
+						ChainApiMessage::BlockNumber(hash, response_channel) => {
+							let _ = response_channel.send(Ok(self
+								.state
+								.block_headers
+								.get(&hash)
+								.map(|header| header.number)));
+						},
+						ChainApiMessage::FinalizedBlockNumber(response_channel) => {
+							// Nothing is ever finalized during a test.
+							let _ = response_channel.send(Ok(0 as BlockNumber));
+						},
+						ChainApiMessage::FinalizedBlockHash(_number, response_channel) => {
+							let _ = response_channel.send(Ok(None));
+						},
+						ChainApiMessage::Ancestors { hash, k, response_channel } => {
+							let _ = response_channel.send(Ok(self.ancestors(hash, k)));
+						},
+						msg => {
+							// Dropping the message drops its response channel, so the requester
+							// sees the request as canceled.
+							gum::warn!(target: LOG_TARGET, ?msg, "Unexpected chain-api message");
+						},
+					}
+				},
+			}
+		}
+	}
+}
@@ -18,6 +18,7 @@ use polkadot_node_subsystem::HeadSupportsParachains;
 use polkadot_node_subsystem_types::Hash;
 
 pub mod av_store;
+pub mod chain_api;
 pub mod dummy;
 pub mod network_bridge;
 pub mod runtime_api;
@@ -67,6 +68,7 @@ macro_rules! dummy_builder {
 			.activation_external_listeners(Default::default())
 			.span_per_active_leaf(Default::default())
 			.active_leaves(Default::default())
+			.subsystems_health(Default::default())
 			.metrics(Default::default())
 			.supports_parachains(AlwaysSupportsParachains {})
 			.spawner(SpawnGlue($spawn_task_handle))
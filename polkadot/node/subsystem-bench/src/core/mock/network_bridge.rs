@@ -32,13 +32,18 @@ use polkadot_node_subsystem::{
 	messages::NetworkBridgeTxMessage, overseer, SpawnedSubsystem, SubsystemError,
 };
 
-use polkadot_node_network_protocol::request_response::{
-	self as req_res,
-	v1::{AvailableDataFetchingRequest, ChunkFetchingRequest, ChunkResponse},
-	IsRequest, Requests,
+use polkadot_node_network_protocol::{
+	request_response::{
+		self as req_res,
+		v1::{AvailableDataFetchingRequest, ChunkFetchingRequest, ChunkResponse},
+		IsRequest, Requests,
+	},
+	PeerId, Versioned, VersionedValidationProtocol,
 };
 use polkadot_primitives::AuthorityDiscoveryId;
 
+use tokio::sync::mpsc::UnboundedSender;
+
 use crate::core::{
 	configuration::{random_error, random_latency, TestConfiguration},
 	network::{NetworkAction, NetworkEmulator, RateLimit},
@@ -55,6 +60,16 @@ pub struct NetworkAvailabilityState {
 
 const LOG_TARGET: &str = "subsystem-bench::network-bridge-tx-mock";
 
+/// A message sent by our node to the emulated network which is not answered by the mock
+/// itself, but forwarded to the test so it can emulate the behaviour of the remote peers.
+#[derive(Debug)]
+pub enum OutgoingMessage {
+	/// A validation protocol message sent to the given peers.
+	Validation(Vec<PeerId>, VersionedValidationProtocol),
+	/// A request the mock doesn't know how to answer.
+	Request(Requests),
+}
+
 /// A mock of the network bridge tx subsystem.
 pub struct MockNetworkBridgeTx {
 	/// The test configurationg
@@ -63,6 +78,8 @@ pub struct MockNetworkBridgeTx {
 	availabilty: NetworkAvailabilityState,
 	/// A network emulator instance
 	network: NetworkEmulator,
+	/// Where to forward the messages the mock doesn't answer.
+	outgoing_tx: Option<UnboundedSender<OutgoingMessage>>,
 }
 
 impl MockNetworkBridgeTx {
@@ -71,7 +88,32 @@ impl MockNetworkBridgeTx {
 		availabilty: NetworkAvailabilityState,
 		network: NetworkEmulator,
 	) -> MockNetworkBridgeTx {
-		Self { config, availabilty, network }
+		Self { config, availabilty, network, outgoing_tx: None }
+	}
+
+	/// Forward the validation protocol messages and the requests the mock doesn't answer
+	/// to `outgoing_tx`.
+	pub fn with_outgoing_messages(mut self, outgoing_tx: UnboundedSender<OutgoingMessage>) -> Self {
+		self.outgoing_tx = Some(outgoing_tx);
+		self
+	}
+
+	// Accounts for a validation protocol message sent by our node to `peers` and forwards it.
+	fn send_validation_message(&self, peers: Vec<PeerId>, message: VersionedValidationProtocol) {
+		let size = validation_message_size(&message);
+
+		for peer in &peers {
+			let Some(peer_stats) = self.network.peer_stats_by_peer_id(peer) else {
+				gum::debug!(target: LOG_TARGET, ?peer, "Message sent to an unknown peer");
+				continue
+			};
+			self.network.inc_sent(size);
+			peer_stats.inc_received(size);
+		}
+
+		if let Some(outgoing_tx) = self.outgoing_tx.as_ref() {
+			let _ = outgoing_tx.send(OutgoingMessage::Validation(peers, message));
+		}
 	}
 
 	fn not_connected_response(
@@ -250,7 +292,39 @@ impl MockNetworkBridgeTx {
 					random_latency(self.config.latency.as_ref()),
 				)
 			},
-			_ => panic!("received an unexpected request"),
+			request => {
+				let outgoing_tx =
+					self.outgoing_tx.as_ref().expect("received an unexpected request").clone();
+				let peer = match &request {
+					Requests::AttestedCandidateV2(outgoing_request) => match &outgoing_request.peer
+					{
+						req_res::Recipient::Peer(peer_id) => self
+							.network
+							.authority_id_by_peer_id(peer_id)
+							.expect("all test peers are known; qed"),
+						req_res::Recipient::Authority(authority_discovery_id) =>
+							authority_discovery_id.clone(),
+					},
+					_ => panic!("received an unexpected request"),
+				};
+
+				// Account for remote received request bytes.
+				self.network.peer_stats_by_id(&peer).inc_received(request_size(&request));
+
+				// The test answers the request on behalf of the remote peer.
+				let future = async move {
+					let _ = outgoing_tx.send(OutgoingMessage::Request(request));
+				}
+				.boxed();
+
+				NetworkAction::new(
+					peer,
+					future,
+					0,
+					// Generate a random latency based on configuration.
+					random_latency(self.config.latency.as_ref()),
+				)
+			},
 		}
 	}
 }
@@ -302,6 +376,20 @@ impl MockNetworkBridgeTx {
 						return
 					},
 				orchestra::FromOrchestra::Communication { msg } => match msg {
+					NetworkBridgeTxMessage::SendValidationMessage(peers, message) => {
+						self.send_validation_message(peers, message);
+					},
+					NetworkBridgeTxMessage::SendValidationMessages(messages) => {
+						for (peers, message) in messages {
+							self.send_validation_message(peers, message);
+						}
+					},
+					NetworkBridgeTxMessage::ReportPeer(_) |
+					NetworkBridgeTxMessage::DisconnectPeer(_, _) |
+					NetworkBridgeTxMessage::ConnectToValidators { .. } |
+					NetworkBridgeTxMessage::ConnectToResolvedValidators { .. } => {
+						gum::trace!(target: LOG_TARGET, "Ignoring peer set management message");
+					},
 					NetworkBridgeTxMessage::SendRequests(requests, _if_disconnected) => {
 						for request in requests {
 							gum::debug!(target: LOG_TARGET, request = ?request, "Processing request");
@@ -328,6 +416,16 @@ fn request_size(request: &Requests) -> usize {
 		Requests::ChunkFetchingV1(outgoing_request) => outgoing_request.payload.encoded_size(),
 		Requests::AvailableDataFetchingV1(outgoing_request) =>
 			outgoing_request.payload.encoded_size(),
+		Requests::AttestedCandidateV2(outgoing_request) => outgoing_request.payload.encoded_size(),
 		_ => unimplemented!("received an unexpected request"),
 	}
 }
+
+// A helper to determine the size of a validation protocol message.
+fn validation_message_size(message: &VersionedValidationProtocol) -> usize {
+	match message {
+		Versioned::V1(message) => message.encoded_size(),
+		Versioned::V2(message) => message.encoded_size(),
+		Versioned::V3(message) => message.encoded_size(),
+	}
+}
@@ -16,8 +16,14 @@
 //!
 //! A generic runtime api subsystem mockup suitable to be used in benchmarks.
 
-use polkadot_primitives::{GroupIndex, IndexedVec, SessionInfo, ValidatorIndex};
+use polkadot_primitives::{
+	vstaging::{ApprovalVotingParams, NodeFeatures},
+	AsyncBackingParams, CandidateEvent, CoreState, ExecutorParams, GroupIndex, GroupRotationInfo,
+	Hash, Id as ParaId, IndexedVec, ScheduledCore, SessionIndex, SessionInfo, ValidationCode,
+	ValidatorIndex,
+};
 
+use polkadot_node_primitives::BabeEpoch;
 use polkadot_node_subsystem::{
 	messages::{RuntimeApiMessage, RuntimeApiRequest},
 	overseer, SpawnedSubsystem, SubsystemError,
@@ -26,11 +32,25 @@ use polkadot_node_subsystem_types::OverseerSignal;
 
 use crate::core::configuration::{TestAuthorities, TestConfiguration};
 use futures::FutureExt;
+use std::collections::HashMap;
 
 const LOG_TARGET: &str = "subsystem-bench::runtime-api-mock";
 
+// Groups never rotate during a test.
+const GROUP_ROTATION_FREQUENCY: u32 = 1_000_000;
+
 pub struct RuntimeApiState {
 	authorities: TestAuthorities,
+	// The session index returned for every block.
+	session_index: SessionIndex,
+	// The candidate events of each relay chain block.
+	candidate_events: HashMap<Hash, Vec<CandidateEvent>>,
+	// The BABE epoch returned for every block.
+	babe_epoch: Option<BabeEpoch>,
+	// The node features enabled by the runtime.
+	node_features: NodeFeatures,
+	// Approval voting parameters.
+	approval_voting_params: ApprovalVotingParams,
 }
 
 pub struct MockRuntimeApi {
@@ -40,32 +60,90 @@ pub struct MockRuntimeApi {
 
 impl MockRuntimeApi {
 	pub fn new(config: TestConfiguration, authorities: TestAuthorities) -> MockRuntimeApi {
-		Self { state: RuntimeApiState { authorities }, config }
+		Self {
+			state: RuntimeApiState {
+				authorities,
+				session_index: 0,
+				candidate_events: HashMap::new(),
+				babe_epoch: None,
+				node_features: NodeFeatures::EMPTY,
+				approval_voting_params: ApprovalVotingParams { max_approval_coalesce_count: 1 },
+			},
+			config,
+		}
+	}
+
+	/// Answer `CandidateEvents` requests with the given events per block.
+	pub fn with_candidate_events(
+		mut self,
+		candidate_events: HashMap<Hash, Vec<CandidateEvent>>,
+	) -> Self {
+		self.state.candidate_events = candidate_events;
+		self
+	}
+
+	/// Answer `CurrentBabeEpoch` requests with the given epoch.
+	pub fn with_babe_epoch(mut self, babe_epoch: BabeEpoch) -> Self {
+		self.state.babe_epoch = Some(babe_epoch);
+		self
+	}
+
+	/// Answer `NodeFeatures` requests with the given features.
+	pub fn with_node_features(mut self, node_features: NodeFeatures) -> Self {
+		self.state.node_features = node_features;
+		self
 	}
 
-	fn session_info(&self) -> SessionInfo {
+	/// Answer `ApprovalVotingParams` requests with the given parameters.
+	pub fn with_approval_voting_params(
+		mut self,
+		approval_voting_params: ApprovalVotingParams,
+	) -> Self {
+		self.state.approval_voting_params = approval_voting_params;
+		self
+	}
+
+	fn validator_groups(&self) -> Vec<Vec<ValidatorIndex>> {
 		let all_validators = (0..self.config.n_validators)
 			.map(|i| ValidatorIndex(i as _))
 			.collect::<Vec<_>>();
 
-		let validator_groups = all_validators.chunks(5).map(Vec::from).collect::<Vec<_>>();
+		all_validators
+			.chunks(self.config.max_validators_per_core)
+			.map(Vec::from)
+			.collect::<Vec<_>>()
+	}
 
+	pub fn session_info(&self) -> SessionInfo {
 		SessionInfo {
 			validators: self.state.authorities.validator_public.clone().into(),
 			discovery_keys: self.state.authorities.validator_authority_id.clone(),
-			validator_groups: IndexedVec::<GroupIndex, Vec<ValidatorIndex>>::from(validator_groups),
-			assignment_keys: vec![],
+			validator_groups: IndexedVec::<GroupIndex, Vec<ValidatorIndex>>::from(
+				self.validator_groups(),
+			),
+			assignment_keys: self.state.authorities.validator_assignment_id.clone(),
 			n_cores: self.config.n_cores as u32,
-			zeroth_delay_tranche_width: 0,
-			relay_vrf_modulo_samples: 0,
-			n_delay_tranches: 0,
-			no_show_slots: 0,
-			needed_approvals: 0,
+			zeroth_delay_tranche_width: self.config.zeroth_delay_tranche_width as u32,
+			relay_vrf_modulo_samples: self.config.relay_vrf_modulo_samples as u32,
+			n_delay_tranches: self.config.n_delay_tranches as u32,
+			no_show_slots: self.config.no_show_slots as u32,
+			needed_approvals: self.config.needed_approvals as u32,
 			active_validator_indices: vec![],
 			dispute_period: 6,
 			random_seed: [0u8; 32],
 		}
 	}
+
+	fn availability_cores(&self) -> Vec<CoreState> {
+		(0..self.config.n_cores)
+			.map(|index| {
+				CoreState::Scheduled(ScheduledCore {
+					para_id: ParaId::from(index as u32),
+					collator: None,
+				})
+			})
+			.collect()
+	}
 }
 
 #[overseer::subsystem(RuntimeApi, error=SubsystemError, prefix=self::overseer)]
@@ -98,6 +176,105 @@ impl MockRuntimeApi {
 						) => {
 							let _ = sender.send(Ok(Some(self.session_info())));
 						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::Version(sender),
+						) => {
+							let _ = sender
+								.send(Ok(RuntimeApiRequest::APPROVAL_VOTING_PARAMS_REQUIREMENT));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::SessionIndexForChild(sender),
+						) => {
+							let _ = sender.send(Ok(self.state.session_index));
+						},
+						RuntimeApiMessage::Request(
+							request,
+							RuntimeApiRequest::CandidateEvents(sender),
+						) => {
+							let events = self
+								.state
+								.candidate_events
+								.get(&request)
+								.cloned()
+								.unwrap_or_default();
+							let _ = sender.send(Ok(events));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::CurrentBabeEpoch(sender),
+						) => {
+							let epoch =
+								self.state.babe_epoch.clone().expect(
+									"BABE epoch must be set for tests importing blocks; qed",
+								);
+							let _ = sender.send(Ok(epoch));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::SessionExecutorParams(_session_index, sender),
+						) => {
+							let _ = sender.send(Ok(Some(ExecutorParams::default())));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::NodeFeatures(_session_index, sender),
+						) => {
+							let _ = sender.send(Ok(self.state.node_features.clone()));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::ApprovalVotingParams(_session_index, sender),
+						) => {
+							let _ = sender.send(Ok(self.state.approval_voting_params));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::ValidationCodeByHash(_hash, sender),
+						) => {
+							let _ = sender.send(Ok(Some(ValidationCode(Vec::new()))));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::AsyncBackingParams(sender),
+						) => {
+							let _ = sender.send(Ok(AsyncBackingParams {
+								max_candidate_depth: 3,
+								allowed_ancestry_len: 2,
+							}));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::MinimumBackingVotes(_session_index, sender),
+						) => {
+							let _ = sender.send(Ok(self.config.minimum_backing_votes));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::AvailabilityCores(sender),
+						) => {
+							let _ = sender.send(Ok(self.availability_cores()));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::ValidatorGroups(sender),
+						) => {
+							let _ = sender.send(Ok((
+								self.validator_groups(),
+								GroupRotationInfo {
+									session_start_block: 0,
+									group_rotation_frequency: GROUP_ROTATION_FREQUENCY,
+									now: 0,
+								},
+							)));
+						},
+						RuntimeApiMessage::Request(
+							_request,
+							RuntimeApiRequest::DisabledValidators(sender),
+						) => {
+							let _ = sender.send(Ok(Vec::new()));
+						},
 						// Long term TODO: implement more as needed.
 						_ => {
 							unimplemented!("Unexpected runtime-api message")
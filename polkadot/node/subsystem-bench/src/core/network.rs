@@ -19,13 +19,21 @@ use super::{
 	*,
 };
 use colored::Colorize;
-use polkadot_node_network_protocol::request_response::ReqProtocolNames;
-use polkadot_primitives::AuthorityDiscoveryId;
+use polkadot_node_network_protocol::{
+	grid_topology::{SessionGridTopology, TopologyPeerInfo},
+	peer_set::ValidationVersion,
+	request_response::ReqProtocolNames,
+	ObservedRole, PeerId,
+};
+use polkadot_node_subsystem::messages::{
+	network_bridge_event::NewGossipTopology, NetworkBridgeEvent,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, SessionIndex, ValidatorIndex};
 use prometheus_endpoint::U64;
 use rand::{seq::SliceRandom, thread_rng};
 use sc_service::SpawnTaskHandle;
 use std::{
-	collections::HashMap,
+	collections::{HashMap, HashSet},
 	sync::{
 		atomic::{AtomicU64, Ordering},
 		Arc,
@@ -312,6 +320,8 @@ pub struct NetworkEmulator {
 	stats: Vec<Arc<PeerEmulatorStats>>,
 	/// Each emulated peer is a validator.
 	validator_authority_ids: HashMap<AuthorityDiscoveryId, usize>,
+	/// Maps the `PeerId` of each emulated validator to its `AuthorityDiscoveryId`.
+	validator_peer_ids: HashMap<PeerId, AuthorityDiscoveryId>,
 	/// Request protocol names
 	req_protocol_names: ReqProtocolNames,
 }
@@ -359,10 +369,18 @@ impl NetworkEmulator {
 
 		gum::info!(target: LOG_TARGET, "{}",format!("Network created, connected validator count {}", connected_count).bright_black());
 
+		let validator_peer_ids = authorities
+			.peer_ids
+			.iter()
+			.cloned()
+			.zip(authorities.validator_authority_id.iter().cloned())
+			.collect();
+
 		Self {
 			peers,
 			stats,
 			validator_authority_ids: validator_authority_id_mapping,
+			validator_peer_ids,
 			req_protocol_names,
 		}
 	}
@@ -413,6 +431,18 @@ impl NetworkEmulator {
 		self.stats[peer_index].clone()
 	}
 
+	// Returns the `AuthorityDiscoveryId` of the validator with the given `PeerId`.
+	pub fn authority_id_by_peer_id(&self, peer: &PeerId) -> Option<AuthorityDiscoveryId> {
+		self.validator_peer_ids.get(peer).cloned()
+	}
+
+	// Returns the sent/received stats for the validator with the given `PeerId`.
+	pub fn peer_stats_by_peer_id(&self, peer: &PeerId) -> Option<Arc<PeerEmulatorStats>> {
+		self.validator_peer_ids
+			.get(peer)
+			.map(|authority_id| self.stats[self.peer_index(authority_id)].clone())
+	}
+
 	// Returns the sent/received stats for all peers.
 	pub fn stats(&self) -> Vec<PeerStats> {
 		let r = self
@@ -444,6 +474,50 @@ impl NetworkEmulator {
 	}
 }
 
+/// Generates a `PeerConnected` event for each emulated validator except our node (index 0).
+pub fn generate_peer_connected<M>(authorities: &TestAuthorities) -> Vec<NetworkBridgeEvent<M>> {
+	authorities
+		.peer_ids
+		.iter()
+		.zip(authorities.validator_authority_id.iter())
+		.skip(1)
+		.map(|(peer_id, authority_id)| {
+			NetworkBridgeEvent::PeerConnected(
+				*peer_id,
+				ObservedRole::Authority,
+				ValidationVersion::V3.into(),
+				Some(HashSet::from([authority_id.clone()])),
+			)
+		})
+		.collect()
+}
+
+/// Generates the gossip topology of a session where the validators are not shuffled and our
+/// node is the validator at index 0.
+pub fn generate_new_gossip_topology(
+	authorities: &TestAuthorities,
+	session: SessionIndex,
+) -> NewGossipTopology {
+	let canonical_shuffling = authorities
+		.peer_ids
+		.iter()
+		.zip(authorities.validator_authority_id.iter())
+		.enumerate()
+		.map(|(index, (peer_id, authority_id))| TopologyPeerInfo {
+			peer_ids: vec![*peer_id],
+			validator_index: ValidatorIndex(index as u32),
+			discovery_id: authority_id.clone(),
+		})
+		.collect::<Vec<_>>();
+	let shuffled_indices = (0..canonical_shuffling.len()).collect::<Vec<_>>();
+
+	NewGossipTopology {
+		session,
+		topology: SessionGridTopology::new(shuffled_indices, canonical_shuffling),
+		local_index: Some(ValidatorIndex(0)),
+	}
+}
+
 use polkadot_node_subsystem_util::metrics::prometheus::{
 	self, CounterVec, Opts, PrometheusError, Registry,
 };
@@ -0,0 +1,171 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+//!
+//! Mocks of the subsystems statement-distribution depends on.
+
+use futures::FutureExt;
+use polkadot_node_subsystem::{
+	messages::{
+		CandidateBackingMessage, ProspectiveParachainsMessage, StatementDistributionMessage,
+	},
+	overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+use polkadot_primitives::{BlockNumber, CandidateHash, Hash, Id as ParaId, ValidatorIndex};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+
+const LOG_TARGET: &str = "subsystem-bench::statement-mocks";
+
+/// A prospective parachains mock which considers every candidate a member of the fragment
+/// tree of its relay parent.
+pub struct MockProspectiveParachains {
+	/// The number of each relay chain block.
+	block_numbers: HashMap<Hash, BlockNumber>,
+	/// The number of parachains.
+	n_paras: usize,
+}
+
+impl MockProspectiveParachains {
+	pub fn new(block_numbers: HashMap<Hash, BlockNumber>, n_paras: usize) -> Self {
+		Self { block_numbers, n_paras }
+	}
+}
+
+#[overseer::subsystem(ProspectiveParachains, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockProspectiveParachains {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
+impl MockProspectiveParachains {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					ProspectiveParachainsMessage::GetMinimumRelayParents(hash, tx) => {
+						// Candidates can only be built on top of the leaf itself.
+						let number = self.block_numbers.get(&hash).copied().unwrap_or_default();
+						let _ = tx.send(
+							(0..self.n_paras)
+								.map(|para| (ParaId::from(para as u32), number))
+								.collect(),
+						);
+					},
+					ProspectiveParachainsMessage::GetHypotheticalFrontier(request, tx) => {
+						let _ = tx.send(
+							request
+								.candidates
+								.into_iter()
+								.map(|candidate| {
+									let membership = vec![(candidate.relay_parent(), vec![0])];
+									(candidate, membership)
+								})
+								.collect(),
+						);
+					},
+					msg => {
+						gum::debug!(target: LOG_TARGET, ?msg, "Ignoring prospective parachains message");
+					},
+				},
+			}
+		}
+	}
+}
+
+/// A candidate backing mock which notifies statement-distribution once a candidate received
+/// enough statements.
+pub struct MockCandidateBacking {
+	/// The number of statements each candidate needs from the emulated peers to be backed.
+	required_statements: HashMap<CandidateHash, usize>,
+	/// The time at which each candidate has been backed.
+	backed_candidates: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+}
+
+impl MockCandidateBacking {
+	pub fn new(
+		required_statements: HashMap<CandidateHash, usize>,
+		backed_candidates: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+	) -> Self {
+		Self { required_statements, backed_candidates }
+	}
+}
+
+#[overseer::subsystem(CandidateBacking, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockCandidateBacking {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(CandidateBacking, prefix = self::overseer)]
+impl MockCandidateBacking {
+	async fn run<Context>(self, mut ctx: Context) {
+		let mut statements: HashMap<CandidateHash, HashSet<ValidatorIndex>> = HashMap::new();
+
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					CandidateBackingMessage::Statement(_relay_parent, statement) => {
+						let candidate_hash = statement.payload().candidate_hash();
+						let Some(required) = self.required_statements.get(&candidate_hash) else {
+							gum::debug!(target: LOG_TARGET, ?candidate_hash, "Statement for an unknown candidate");
+							continue
+						};
+
+						let validators = statements.entry(candidate_hash).or_default();
+						if !validators.insert(statement.validator_index()) ||
+							validators.len() != *required
+						{
+							continue
+						}
+
+						self.backed_candidates
+							.lock()
+							.expect("never poisoned; qed")
+							.insert(candidate_hash, Instant::now());
+						ctx.send_message(StatementDistributionMessage::Backed(candidate_hash))
+							.await;
+					},
+					msg => {
+						gum::debug!(target: LOG_TARGET, ?msg, "Ignoring candidate backing message");
+					},
+				},
+			}
+		}
+	}
+}
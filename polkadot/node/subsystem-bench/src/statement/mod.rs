@@ -0,0 +1,763 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+//! Statement distribution benchmark.
+//!
+//! Our node runs the real `statement-distribution` subsystem. On every block it seconds the
+//! candidate of its own backing group, which the other group members validate, and receives
+//! manifests of the candidates of all other groups from its grid neighbours, which it then
+//! fetches. The emulated peers react to the messages and requests of our node the way the
+//! real network would.
+use parity_scale_codec::Encode;
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+use colored::Colorize;
+use futures::FutureExt;
+use rand::SeedableRng;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	grid_topology::{GridNeighbors, SessionGridTopology},
+	jaeger,
+	request_response::{
+		v2::{AttestedCandidateRequest, AttestedCandidateResponse},
+		IncomingRequest, Recipient, ReqProtocolNames, Requests,
+	},
+	v3::{self as protocol_v3, BackedCandidateManifest, StatementFilter},
+	OurView, PeerId, Versioned, View,
+};
+use polkadot_node_primitives::{SignedFullStatementWithPVD, StatementWithPVD};
+use polkadot_node_subsystem::{
+	messages::{AllMessages, NetworkBridgeEvent, StatementDistributionMessage},
+	Overseer, OverseerConnector, SpawnGlue,
+};
+use polkadot_node_subsystem_test_helpers::mock::dummy_unpin_handle;
+use polkadot_overseer::{BlockInfo, Handle as OverseerHandle};
+use polkadot_primitives::{
+	BlockNumber, CandidateHash, CommittedCandidateReceipt, CompactStatement, GroupIndex, Hash,
+	HeadData, Header, Id as ParaId, PersistedValidationData, SessionIndex, SigningContext,
+	UncheckedSigned, UncheckedSignedStatement, ValidatorIndex, PARACHAIN_KEY_TYPE_ID,
+};
+use polkadot_primitives_test_helpers::{dummy_validation_code, make_candidate};
+use polkadot_statement_distribution::StatementDistributionSubsystem;
+use sc_keystore::LocalKeystore;
+use sc_service::SpawnTaskHandle;
+use sp_core::{sr25519::Pair, Pair as PairT};
+use sp_keystore::Keystore;
+
+use crate::{
+	core::{
+		configuration::{random_latency, TestAuthorities, TestConfiguration},
+		environment::{TestEnvironment, TestEnvironmentDependencies},
+		mock::{
+			chain_api::{ChainApiState, MockChainApi},
+			dummy_builder,
+			network_bridge::{MockNetworkBridgeTx, NetworkAvailabilityState, OutgoingMessage},
+			AlwaysSupportsParachains, MockRuntimeApi,
+		},
+		network::{
+			generate_new_gossip_topology, generate_peer_connected, NetworkAction, NetworkEmulator,
+		},
+	},
+	GENESIS_HASH,
+};
+
+mod mocks;
+use mocks::{MockCandidateBacking, MockProspectiveParachains};
+
+const LOG_TARGET: &str = "subsystem-bench::statement";
+
+/// The session all blocks are part of.
+const SESSION_INDEX: SessionIndex = 0;
+/// How long to wait for all candidates of a block to be backed.
+const BLOCK_TIMEOUT: Duration = Duration::from_secs(6);
+/// How often to check whether all candidates of a block have been backed.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A candidate backed by one of the groups at a relay chain block.
+struct CandidateTestData {
+	hash: CandidateHash,
+	receipt: CommittedCandidateReceipt,
+	persisted_validation_data: PersistedValidationData,
+	group_index: GroupIndex,
+	/// The statements of the backing group, the `Seconded` one first.
+	statements: Vec<UncheckedSignedStatement>,
+}
+
+/// All the data of a generated relay chain block.
+struct BlockTestData {
+	hash: Hash,
+	header: Header,
+	/// The candidate seconded by our node.
+	own_candidate: Option<SignedFullStatementWithPVD>,
+	/// The manifests our grid neighbours send us for the candidates of the other groups.
+	manifests: Vec<(ValidatorIndex, BackedCandidateManifest)>,
+	/// All the candidates backed at this block.
+	candidates: Vec<CandidateHash>,
+}
+
+/// The state of the statement distribution benchmark.
+pub struct StatementTestState {
+	config: TestConfiguration,
+	test_authorities: TestAuthorities,
+	pairs: Arc<Vec<Pair>>,
+	groups: Vec<Vec<ValidatorIndex>>,
+	blocks: Vec<BlockTestData>,
+	/// The responses the emulated peers send to `AttestedCandidateV2` requests.
+	responses: Arc<HashMap<CandidateHash, AttestedCandidateResponse>>,
+	/// The number of statements each candidate needs from the emulated peers to be backed.
+	required_statements: HashMap<CandidateHash, usize>,
+	/// The time at which each candidate has been backed.
+	backed_candidates: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+}
+
+impl StatementTestState {
+	pub fn new(config: &TestConfiguration) -> Self {
+		let config = config.clone();
+		let test_authorities = config.generate_authorities();
+		let pairs = test_authorities
+			.keyrings
+			.iter()
+			.map(|keyring| keyring.clone().pair())
+			.collect::<Vec<_>>();
+		let groups = MockRuntimeApi::new(config.clone(), test_authorities.clone())
+			.session_info()
+			.validator_groups
+			.into_iter()
+			.collect::<Vec<_>>();
+
+		let mut state = Self {
+			config,
+			test_authorities,
+			pairs: Arc::new(pairs),
+			groups,
+			blocks: Vec::new(),
+			responses: Arc::new(HashMap::new()),
+			required_statements: HashMap::new(),
+			backed_candidates: Arc::new(Mutex::new(HashMap::new())),
+		};
+		state.generate_blocks();
+		state
+	}
+
+	/// Generates the relay chain blocks and the candidates backed at each of them.
+	fn generate_blocks(&mut self) {
+		let topology = generate_new_gossip_topology(&self.test_authorities, SESSION_INDEX).topology;
+		let our_neighbors = topology
+			.compute_grid_neighbors_for(ValidatorIndex(0))
+			.expect("our node is part of the topology; qed");
+
+		// Paras are only scheduled on cores which have a backing group.
+		let n_paras = self.config.n_cores.min(self.groups.len());
+		let mut responses = HashMap::new();
+		let mut parent_hash = GENESIS_HASH;
+
+		for block_index in 0..self.config.num_blocks {
+			gum::info!(
+				target: LOG_TARGET,
+				"{}",
+				format!("Generating block {}/{}", block_index + 1, self.config.num_blocks)
+					.bright_blue()
+			);
+			let header = Header {
+				digest: Default::default(),
+				extrinsics_root: Default::default(),
+				number: (block_index + 1) as BlockNumber,
+				state_root: Default::default(),
+				parent_hash,
+			};
+			let hash = header.hash();
+			let signing_context =
+				SigningContext { session_index: SESSION_INDEX, parent_hash: hash };
+
+			let mut block = BlockTestData {
+				hash,
+				header,
+				own_candidate: None,
+				manifests: Vec::new(),
+				candidates: Vec::new(),
+			};
+
+			for para in 0..n_paras {
+				let candidate = self.generate_candidate(
+					hash,
+					block.header.number,
+					ParaId::from(para as u32),
+					GroupIndex(para as u32),
+					&signing_context,
+				);
+				let group = &self.groups[para];
+				block.candidates.push(candidate.hash);
+
+				if group.contains(&ValidatorIndex(0)) {
+					// Our node seconds the candidate, the other group members validate it.
+					let signature = candidate.statements[0].unchecked_signature().clone();
+					block.own_candidate = Some(
+						SignedFullStatementWithPVD::new(
+							StatementWithPVD::Seconded(
+								candidate.receipt.clone(),
+								candidate.persisted_validation_data.clone(),
+							),
+							ValidatorIndex(0),
+							signature,
+							&signing_context,
+							&self.test_authorities.validator_public[0],
+						)
+						.expect("statement is signed by our node; qed"),
+					);
+					self.required_statements.insert(
+						candidate.hash,
+						self.config.minimum_backing_votes.saturating_sub(1).max(1) as usize,
+					);
+				} else {
+					let Some(sender) = manifest_sender(&topology, &our_neighbors, group) else {
+						gum::debug!(target: LOG_TARGET, ?para, "No grid neighbour knows about the candidate");
+						continue
+					};
+					block.manifests.push((
+						sender,
+						BackedCandidateManifest {
+							relay_parent: hash,
+							candidate_hash: candidate.hash,
+							group_index: candidate.group_index,
+							para_id: candidate.receipt.descriptor.para_id,
+							parent_head_data_hash: candidate
+								.persisted_validation_data
+								.parent_head
+								.hash(),
+							statement_knowledge: statement_knowledge(group.len()),
+						},
+					));
+					self.required_statements.insert(
+						candidate.hash,
+						(self.config.minimum_backing_votes as usize).min(group.len()),
+					);
+				}
+
+				responses.insert(
+					candidate.hash,
+					AttestedCandidateResponse {
+						candidate_receipt: candidate.receipt,
+						persisted_validation_data: candidate.persisted_validation_data,
+						statements: candidate.statements,
+					},
+				);
+			}
+
+			parent_hash = hash;
+			self.blocks.push(block);
+		}
+
+		self.responses = Arc::new(responses);
+	}
+
+	fn generate_candidate(
+		&self,
+		relay_parent: Hash,
+		relay_parent_number: BlockNumber,
+		para_id: ParaId,
+		group_index: GroupIndex,
+		signing_context: &SigningContext,
+	) -> CandidateTestData {
+		let (receipt, persisted_validation_data) = make_candidate(
+			relay_parent,
+			relay_parent_number,
+			para_id,
+			HeadData(vec![7, 8, 9]),
+			HeadData(vec![para_id.encode(), relay_parent_number.encode()].concat()),
+			dummy_validation_code().hash(),
+		);
+		let hash = receipt.hash();
+
+		let statements = self.groups[group_index.0 as usize]
+			.iter()
+			.enumerate()
+			.map(|(index_in_group, validator_index)| {
+				let statement = if index_in_group == 0 {
+					CompactStatement::Seconded(hash)
+				} else {
+					CompactStatement::Valid(hash)
+				};
+				sign_statement(&self.pairs, statement, *validator_index, signing_context)
+			})
+			.collect();
+
+		CandidateTestData { hash, receipt, persisted_validation_data, group_index, statements }
+	}
+
+	fn block_headers(&self) -> HashMap<Hash, Header> {
+		self.blocks.iter().map(|block| (block.hash, block.header.clone())).collect()
+	}
+
+	fn block_numbers(&self) -> HashMap<Hash, BlockNumber> {
+		self.blocks.iter().map(|block| (block.hash, block.header.number)).collect()
+	}
+
+	fn is_backed(&self, candidate_hash: &CandidateHash) -> bool {
+		self.backed_candidates
+			.lock()
+			.expect("never poisoned; qed")
+			.contains_key(candidate_hash)
+	}
+}
+
+fn sign_statement(
+	pairs: &[Pair],
+	statement: CompactStatement,
+	validator_index: ValidatorIndex,
+	signing_context: &SigningContext,
+) -> UncheckedSignedStatement {
+	let payload = statement.signing_payload(signing_context);
+	let signature = pairs[validator_index.0 as usize].sign(&payload[..]).into();
+	UncheckedSigned::new(statement, validator_index, signature)
+}
+
+// The knowledge of a group which has fully backed its candidate: the first member seconded it,
+// all others validated it.
+fn statement_knowledge(group_size: usize) -> StatementFilter {
+	let mut filter = StatementFilter::blank(group_size);
+	filter.seconded_in_group.set(0, true);
+	for index in 1..group_size {
+		filter.validated_in_group.set(index, true);
+	}
+	filter
+}
+
+// Picks the validator our node expects to receive the manifests of `group` from, following the
+// grid topology rules of statement-distribution.
+fn manifest_sender(
+	topology: &SessionGridTopology,
+	our_neighbors: &GridNeighbors,
+	group: &[ValidatorIndex],
+) -> Option<ValidatorIndex> {
+	let is_our_neighbor = |validator: &ValidatorIndex| {
+		our_neighbors.validator_indices_x.contains(validator) ||
+			our_neighbors.validator_indices_y.contains(validator)
+	};
+
+	// Group members sharing a row or a column with us send us their manifests directly.
+	if let Some(sender) = group.iter().find(|validator| is_our_neighbor(validator)) {
+		return Some(*sender)
+	}
+
+	// Otherwise the validator sharing a row with a group member and a column with us forwards
+	// them, or the one sharing a column with a group member and a row with us.
+	group.iter().find_map(|group_val| {
+		let their_neighbors = topology.compute_grid_neighbors_for(*group_val)?;
+		their_neighbors
+			.validator_indices_x
+			.iter()
+			.find(|validator| our_neighbors.validator_indices_y.contains(validator))
+			.or_else(|| {
+				their_neighbors
+					.validator_indices_y
+					.iter()
+					.find(|validator| our_neighbors.validator_indices_x.contains(validator))
+			})
+			.copied()
+	})
+}
+
+fn build_overseer(
+	spawn_task_handle: SpawnTaskHandle,
+	runtime_api: MockRuntimeApi,
+	chain_api: MockChainApi,
+	prospective_parachains: MockProspectiveParachains,
+	candidate_backing: MockCandidateBacking,
+	network_bridge: MockNetworkBridgeTx,
+	statement_distribution: StatementDistributionSubsystem<rand::rngs::StdRng>,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let dummy = dummy_builder!(spawn_task_handle);
+	let builder = dummy
+		.replace_runtime_api(|_| runtime_api)
+		.replace_chain_api(|_| chain_api)
+		.replace_prospective_parachains(|_| prospective_parachains)
+		.replace_candidate_backing(|_| candidate_backing)
+		.replace_network_bridge_tx(|_| network_bridge)
+		.replace_statement_distribution(|_| statement_distribution);
+
+	let (overseer, raw_handle) =
+		builder.build_with_connector(overseer_connector).expect("Should not fail");
+
+	(overseer, OverseerHandle::new(raw_handle))
+}
+
+/// Takes a test configuration and uses it to create the `TestEnvironment`.
+///
+/// Also returns the messages and requests our node sends to the emulated peers.
+pub fn prepare_test(
+	config: TestConfiguration,
+	state: &StatementTestState,
+) -> (TestEnvironment, UnboundedReceiver<OutgoingMessage>) {
+	let dependencies = TestEnvironmentDependencies::default();
+	let req_protocol_names = ReqProtocolNames::new(GENESIS_HASH, None);
+
+	let runtime_api = MockRuntimeApi::new(config.clone(), state.test_authorities.clone());
+	let chain_api = MockChainApi::new(ChainApiState { block_headers: state.block_headers() });
+	let prospective_parachains =
+		MockProspectiveParachains::new(state.block_numbers(), config.n_cores);
+	let candidate_backing = MockCandidateBacking::new(
+		state.required_statements.clone(),
+		state.backed_candidates.clone(),
+	);
+
+	let network = NetworkEmulator::new(
+		&config,
+		&dependencies,
+		&state.test_authorities,
+		req_protocol_names.clone(),
+	);
+	// Nothing is fetched over the availability protocols.
+	let availability_state = NetworkAvailabilityState {
+		candidate_hashes: HashMap::new(),
+		available_data: Vec::new(),
+		chunks: Vec::new(),
+	};
+	let (outgoing_tx, outgoing_rx) = tokio::sync::mpsc::unbounded_channel();
+	let network_bridge =
+		MockNetworkBridgeTx::new(config.clone(), availability_state, network.clone())
+			.with_outgoing_messages(outgoing_tx);
+
+	// Our node is the validator at index 0.
+	let keystore = LocalKeystore::in_memory();
+	keystore
+		.sr25519_generate_new(PARACHAIN_KEY_TYPE_ID, Some(&state.test_authorities.key_seeds[0]))
+		.expect("Insert key into keystore");
+
+	let (statement_req_receiver, _) = IncomingRequest::get_config_receiver(&req_protocol_names);
+	let (candidate_req_receiver, _) = IncomingRequest::get_config_receiver(&req_protocol_names);
+	let statement_distribution = StatementDistributionSubsystem::new(
+		Arc::new(keystore),
+		statement_req_receiver,
+		candidate_req_receiver,
+		Metrics::try_register(&dependencies.registry).unwrap(),
+		rand::rngs::StdRng::from_entropy(),
+	);
+
+	let (overseer, overseer_handle) = build_overseer(
+		dependencies.task_manager.spawn_handle(),
+		runtime_api,
+		chain_api,
+		prospective_parachains,
+		candidate_backing,
+		network_bridge,
+		statement_distribution,
+	);
+
+	(
+		TestEnvironment::new(
+			dependencies,
+			config,
+			network,
+			overseer,
+			overseer_handle,
+			vec!["statement-distribution"],
+		),
+		outgoing_rx,
+	)
+}
+
+/// Sends `message` from the emulated peer `sender` to statement-distribution over the emulated
+/// network.
+fn send_peer_message(
+	network: &NetworkEmulator,
+	mut overseer_handle: OverseerHandle,
+	config: &TestConfiguration,
+	test_authorities: &TestAuthorities,
+	sender: ValidatorIndex,
+	message: protocol_v3::StatementDistributionMessage,
+) {
+	let peer_id = test_authorities.peer_ids[sender.0 as usize];
+	let authority_id = test_authorities.validator_authority_id[sender.0 as usize].clone();
+	let size = message.encoded_size();
+	let network_clone = network.clone();
+
+	let future = async move {
+		// Account our node receiving the message.
+		network_clone.inc_received(size);
+		overseer_handle
+			.send_msg(
+				AllMessages::StatementDistribution(
+					StatementDistributionMessage::NetworkBridgeUpdate(
+						NetworkBridgeEvent::PeerMessage(peer_id, Versioned::V3(message)),
+					),
+				),
+				LOG_TARGET,
+			)
+			.await;
+	}
+	.boxed();
+
+	let action = NetworkAction::new(
+		authority_id.clone(),
+		future,
+		size,
+		random_latency(config.latency.as_ref()),
+	);
+	network.clone().submit_peer_action(authority_id, action);
+}
+
+/// Emulates the reaction of the remote peers to the messages and requests of our node.
+async fn emulate_peers(
+	mut outgoing_rx: UnboundedReceiver<OutgoingMessage>,
+	network: NetworkEmulator,
+	overseer_handle: OverseerHandle,
+	config: TestConfiguration,
+	test_authorities: TestAuthorities,
+	pairs: Arc<Vec<Pair>>,
+	own_group: HashSet<ValidatorIndex>,
+	responses: Arc<HashMap<CandidateHash, AttestedCandidateResponse>>,
+) {
+	let validator_index_by_peer_id = test_authorities
+		.peer_ids
+		.iter()
+		.enumerate()
+		.map(|(index, peer_id)| (*peer_id, ValidatorIndex(index as u32)))
+		.collect::<HashMap<PeerId, ValidatorIndex>>();
+
+	while let Some(message) = outgoing_rx.recv().await {
+		match message {
+			OutgoingMessage::Validation(
+				peers,
+				Versioned::V3(protocol_v3::ValidationProtocol::StatementDistribution(
+					protocol_v3::StatementDistributionMessage::Statement(relay_parent, statement),
+				)),
+			) => {
+				// Our group validates the candidates we second.
+				let CompactStatement::Seconded(candidate_hash) = statement.unchecked_payload()
+				else {
+					continue
+				};
+				let signing_context =
+					SigningContext { session_index: SESSION_INDEX, parent_hash: relay_parent };
+
+				for validator_index in
+					peers.iter().filter_map(|peer_id| validator_index_by_peer_id.get(peer_id))
+				{
+					if !own_group.contains(validator_index) {
+						continue
+					}
+					let statement = sign_statement(
+						&pairs,
+						CompactStatement::Valid(*candidate_hash),
+						*validator_index,
+						&signing_context,
+					);
+					send_peer_message(
+						&network,
+						overseer_handle.clone(),
+						&config,
+						&test_authorities,
+						*validator_index,
+						protocol_v3::StatementDistributionMessage::Statement(
+							relay_parent,
+							statement,
+						),
+					);
+				}
+			},
+			OutgoingMessage::Validation(_, message) => {
+				gum::trace!(target: LOG_TARGET, ?message, "Ignoring outgoing message");
+			},
+			OutgoingMessage::Request(Requests::AttestedCandidateV2(request)) => {
+				let Some(response) = responses.get(&request.payload.candidate_hash) else {
+					gum::warn!(target: LOG_TARGET, candidate_hash = ?request.payload.candidate_hash, "Request for an unknown candidate");
+					continue
+				};
+				let mask = &request.payload.mask;
+				let statements = response
+					.statements
+					.iter()
+					.enumerate()
+					.filter(|(index_in_group, statement)| match statement.unchecked_payload() {
+						CompactStatement::Seconded(_) =>
+							!mask.seconded_in_group.get(*index_in_group).map_or(false, |b| *b),
+						CompactStatement::Valid(_) =>
+							!mask.validated_in_group.get(*index_in_group).map_or(false, |b| *b),
+					})
+					.map(|(_, statement)| statement.clone())
+					.collect();
+				let response = AttestedCandidateResponse {
+					candidate_receipt: response.candidate_receipt.clone(),
+					persisted_validation_data: response.persisted_validation_data.clone(),
+					statements,
+				}
+				.encode();
+
+				let authority_id = match request.peer {
+					Recipient::Peer(peer_id) => network.authority_id_by_peer_id(&peer_id),
+					Recipient::Authority(authority_id) => Some(authority_id),
+				}
+				.expect("all test peers are known; qed");
+				let size = response.len();
+				let protocol_name =
+					network.req_protocol_names().get_name(AttestedCandidateRequest::PROTOCOL);
+				let network_clone = network.clone();
+
+				let future = async move {
+					network_clone.inc_received(size);
+					let _ = request.pending_response.send(Ok((response, protocol_name)));
+				}
+				.boxed();
+				let action = NetworkAction::new(
+					authority_id.clone(),
+					future,
+					size,
+					random_latency(config.latency.as_ref()),
+				);
+				network.clone().submit_peer_action(authority_id, action);
+			},
+			OutgoingMessage::Request(request) => {
+				gum::warn!(target: LOG_TARGET, ?request, "Ignoring unexpected request");
+			},
+		}
+	}
+}
+
+pub async fn benchmark_statement_distribution(
+	env: &mut TestEnvironment,
+	state: StatementTestState,
+	outgoing_rx: UnboundedReceiver<OutgoingMessage>,
+) {
+	let config = env.config().clone();
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let own_group = state
+		.groups
+		.iter()
+		.find(|group| group.contains(&ValidatorIndex(0)))
+		.map(|group| group.iter().copied().filter(|v| *v != ValidatorIndex(0)).collect())
+		.unwrap_or_default();
+	let peers = tokio::spawn(emulate_peers(
+		outgoing_rx,
+		env.network().clone(),
+		env.overseer_handle(),
+		config.clone(),
+		state.test_authorities.clone(),
+		state.pairs.clone(),
+		own_group,
+		state.responses.clone(),
+	));
+
+	// Connect the emulated peers and set up the gossip topology.
+	for event in generate_peer_connected(&state.test_authorities) {
+		env.send_message(AllMessages::StatementDistribution(
+			StatementDistributionMessage::NetworkBridgeUpdate(event),
+		))
+		.await;
+	}
+	env.send_message(AllMessages::StatementDistribution(
+		StatementDistributionMessage::NetworkBridgeUpdate(NetworkBridgeEvent::NewGossipTopology(
+			generate_new_gossip_topology(&state.test_authorities, SESSION_INDEX),
+		)),
+	))
+	.await;
+
+	let test_start = Instant::now();
+	let mut candidates_backed = 0;
+	let mut candidates_total = 0;
+
+	for (block_index, block) in state.blocks.iter().enumerate() {
+		let block_start = Instant::now();
+		gum::info!(target: LOG_TARGET, "Current block {}/{}", block_index + 1, config.num_blocks);
+		env.metrics().set_current_block(block_index);
+
+		env.import_block(BlockInfo {
+			hash: block.hash,
+			parent_hash: block.header.parent_hash,
+			number: block.header.number,
+			unpin_handle: dummy_unpin_handle(block.hash),
+		})
+		.await;
+		env.send_message(AllMessages::StatementDistribution(
+			StatementDistributionMessage::NetworkBridgeUpdate(NetworkBridgeEvent::OurViewChange(
+				OurView::new([(block.hash, Arc::new(jaeger::Span::Disabled))], 0),
+			)),
+		))
+		.await;
+		for peer_id in state.test_authorities.peer_ids.iter().skip(1) {
+			env.send_message(AllMessages::StatementDistribution(
+				StatementDistributionMessage::NetworkBridgeUpdate(
+					NetworkBridgeEvent::PeerViewChange(*peer_id, View::new([block.hash], 0)),
+				),
+			))
+			.await;
+		}
+
+		if let Some(statement) = block.own_candidate.clone() {
+			env.send_message(AllMessages::StatementDistribution(
+				StatementDistributionMessage::Share(block.hash, statement),
+			))
+			.await;
+		}
+		for (sender, manifest) in block.manifests.iter() {
+			send_peer_message(
+				env.network(),
+				env.overseer_handle(),
+				&config,
+				&state.test_authorities,
+				*sender,
+				protocol_v3::StatementDistributionMessage::BackedCandidateManifest(
+					manifest.clone(),
+				),
+			);
+		}
+
+		// Candidates nobody told us about will never be backed.
+		let expected = block
+			.candidates
+			.iter()
+			.filter(|candidate_hash| state.required_statements.contains_key(candidate_hash))
+			.collect::<Vec<_>>();
+		while block_start.elapsed() < BLOCK_TIMEOUT &&
+			!expected.iter().all(|candidate_hash| state.is_backed(candidate_hash))
+		{
+			tokio::time::sleep(POLL_INTERVAL).await;
+		}
+
+		let backed =
+			expected.iter().filter(|candidate_hash| state.is_backed(candidate_hash)).count();
+		candidates_backed += backed;
+		candidates_total += expected.len();
+
+		let block_time = block_start.elapsed().as_millis() as u64;
+		env.metrics().set_block_time(block_time);
+		gum::info!(
+			"{} of {} candidates backed in {}",
+			format!("{}", backed).cyan(),
+			expected.len(),
+			format!("{} ms", block_time).cyan()
+		);
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!("All blocks processed in {}", format!("{:?}ms", duration).cyan());
+	gum::info!(
+		"Backed {} of {} candidates",
+		format!("{}", candidates_backed).cyan(),
+		candidates_total
+	);
+
+	gum::info!("{}", &env);
+	peers.abort();
+	env.stop().await;
+}
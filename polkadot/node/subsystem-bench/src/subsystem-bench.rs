@@ -24,11 +24,13 @@ use pyroscope_pprofrs::{pprof_backend, PprofConfig};
 use colored::Colorize;
 use std::{path::Path, time::Duration};
 
+pub(crate) mod approval;
 pub(crate) mod availability;
 pub(crate) mod cli;
 pub(crate) mod core;
+pub(crate) mod statement;
 
-use availability::{prepare_test, NetworkEmulation, TestState};
+use availability::NetworkEmulation;
 use cli::TestObjective;
 
 use core::{
@@ -94,6 +96,33 @@ struct BenchCli {
 	pub objective: cli::TestObjective,
 }
 
+// Runs the benchmark of the test objective.
+fn run_test(test_config: TestConfiguration) {
+	match test_config.objective.clone() {
+		TestObjective::DataAvailabilityRead(_) => {
+			let mut state = availability::TestState::new(&test_config);
+			let (mut env, _protocol_config) = availability::prepare_test(test_config, &mut state);
+			env.runtime()
+				.block_on(availability::benchmark_availability_read(&mut env, state));
+		},
+		TestObjective::ApprovalVoting(options) => {
+			let state = approval::ApprovalTestState::new(&test_config, options);
+			let mut env = approval::prepare_test(test_config, &state);
+			env.runtime().block_on(approval::benchmark_approvals(&mut env, state));
+		},
+		TestObjective::StatementDistribution => {
+			let state = statement::StatementTestState::new(&test_config);
+			let (mut env, outgoing_rx) = statement::prepare_test(test_config, &state);
+			env.runtime().block_on(statement::benchmark_statement_distribution(
+				&mut env,
+				state,
+				outgoing_rx,
+			));
+		},
+		TestObjective::TestSequence(_) => panic!("Test sequences can not be nested"),
+	}
+}
+
 impl BenchCli {
 	fn launch(self) -> eyre::Result<()> {
 		let agent_running = if self.profile {
@@ -122,14 +151,13 @@ impl BenchCli {
 					gum::info!("{}", format!("Step {}/{}", index + 1, num_steps).bright_purple(),);
 					display_configuration(&test_config);
 
-					let mut state = TestState::new(&test_config);
-					let (mut env, _protocol_config) = prepare_test(test_config, &mut state);
-					env.runtime()
-						.block_on(availability::benchmark_availability_read(&mut env, state));
+					run_test(test_config);
 				}
 				return Ok(())
 			},
-			TestObjective::DataAvailabilityRead(ref _options) => match self.network {
+			TestObjective::DataAvailabilityRead(_) |
+			TestObjective::ApprovalVoting(_) |
+			TestObjective::StatementDistribution => match self.network {
 				NetworkEmulation::Healthy => TestConfiguration::healthy_network(
 					self.objective,
 					configuration.num_blocks,
@@ -183,11 +211,8 @@ impl BenchCli {
 
 		display_configuration(&test_config);
 
-		let mut state = TestState::new(&test_config);
-		let (mut env, _protocol_config) = prepare_test(test_config, &mut state);
 		// test_config.write_to_disk();
-		env.runtime()
-			.block_on(availability::benchmark_availability_read(&mut env, state));
+		run_test(test_config);
 
 		if let Some(agent_running) = agent_running {
 			let agent_ready = agent_running.stop()?;
@@ -724,6 +724,12 @@ pub enum RuntimeApiRequest {
 	/// Approval voting params
 	/// `V10`
 	ApprovalVotingParams(SessionIndex, RuntimeApiSender<ApprovalVotingParams>),
+	/// Returns a list of validators that backed a candidate concluded invalid in a past session
+	/// dispute and need to be slashed.
+	/// `V11`
+	UnappliedBackerSlashes(
+		RuntimeApiSender<Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>>,
+	),
 }
 
 impl RuntimeApiRequest {
@@ -758,6 +764,9 @@ impl RuntimeApiRequest {
 
 	/// `approval_voting_params`
 	pub const APPROVAL_VOTING_PARAMS_REQUIREMENT: u32 = 10;
+
+	/// `UnappliedBackerSlashes`
+	pub const UNAPPLIED_BACKER_SLASHES_RUNTIME_REQUIREMENT: u32 = 11;
 }
 
 /// A message to the Runtime API subsystem.
@@ -1126,14 +1135,18 @@ pub enum ProspectiveParachainsMessage {
 	/// has been backed. This requires that the candidate was successfully introduced in
 	/// the past.
 	CandidateBacked(ParaId, CandidateHash),
-	/// Get a backable candidate hash along with its relay parent for the given parachain,
-	/// under the given relay-parent hash, which is a descendant of the given candidate hashes.
-	/// Returns `None` on the channel if no such candidate exists.
-	GetBackableCandidate(
+	/// Get up to the given number of backable candidate hashes, along with their relay parents,
+	/// for the given parachain, under the given relay-parent hash, which are descendants of the
+	/// given candidate hashes.
+	///
+	/// The candidates form a chain, in which every candidate is a child of the one before it.
+	/// Returns an empty vector on the channel if no such candidate exists.
+	GetBackableCandidates(
 		Hash,
 		ParaId,
+		u32,
 		Vec<CandidateHash>,
-		oneshot::Sender<Option<(CandidateHash, Hash)>>,
+		oneshot::Sender<Vec<(CandidateHash, Hash)>>,
 	),
 	/// Get the hypothetical frontier membership of candidates with the given properties
 	/// under the specified active leaves' fragment trees.
@@ -0,0 +1,173 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Mapping of validators to the availability chunks they are responsible for.
+//!
+//! Without the `AvailabilityChunkMapping` node feature, each validator holds the chunk with its
+//! own index. With it enabled, the chunk indices are rotated by an offset derived from the
+//! candidate hash, so that the systematic chunks of different candidates are held by different
+//! validators.
+
+use polkadot_primitives::{
+	vstaging::{node_features::FeatureIndex, NodeFeatures},
+	CandidateHash, ValidatorIndex,
+};
+
+/// Whether the availability chunk mapping is enabled by the given node features.
+pub fn availability_chunk_mapping_enabled(maybe_node_features: Option<&NodeFeatures>) -> bool {
+	maybe_node_features
+		.and_then(|features| features.get(FeatureIndex::AvailabilityChunkMapping as usize))
+		.map(|bit| *bit)
+		.unwrap_or(false)
+}
+
+/// The offset by which the chunk indices of `candidate_hash` are rotated.
+fn chunk_offset(
+	maybe_node_features: Option<&NodeFeatures>,
+	n_validators: usize,
+	candidate_hash: &CandidateHash,
+) -> usize {
+	if n_validators == 0 || !availability_chunk_mapping_enabled(maybe_node_features) {
+		return 0
+	}
+
+	let mut bytes = [0u8; 4];
+	bytes.copy_from_slice(&candidate_hash.0.as_ref()[..4]);
+	u32::from_le_bytes(bytes) as usize % n_validators
+}
+
+/// Index of the availability chunk of `candidate_hash` held by `validator_index`.
+pub fn availability_chunk_index(
+	maybe_node_features: Option<&NodeFeatures>,
+	n_validators: usize,
+	candidate_hash: &CandidateHash,
+	validator_index: ValidatorIndex,
+) -> ValidatorIndex {
+	let offset = chunk_offset(maybe_node_features, n_validators, candidate_hash);
+	if offset == 0 {
+		return validator_index
+	}
+
+	ValidatorIndex(((validator_index.0 as usize + offset) % n_validators) as u32)
+}
+
+/// Index of the validator holding the availability chunk `chunk_index` of `candidate_hash`.
+///
+/// This is the inverse of [`availability_chunk_index`].
+pub fn availability_chunk_holder(
+	maybe_node_features: Option<&NodeFeatures>,
+	n_validators: usize,
+	candidate_hash: &CandidateHash,
+	chunk_index: ValidatorIndex,
+) -> ValidatorIndex {
+	let offset = chunk_offset(maybe_node_features, n_validators, candidate_hash);
+	if offset == 0 {
+		return chunk_index
+	}
+
+	ValidatorIndex(((chunk_index.0 as usize + n_validators - offset) % n_validators) as u32)
+}
+
+/// Indices of the availability chunks of `candidate_hash` held by each validator, indexed by the
+/// validator index.
+pub fn availability_chunk_indices(
+	maybe_node_features: Option<&NodeFeatures>,
+	n_validators: usize,
+	candidate_hash: &CandidateHash,
+) -> Vec<ValidatorIndex> {
+	(0..n_validators)
+		.map(|i| {
+			availability_chunk_index(
+				maybe_node_features,
+				n_validators,
+				candidate_hash,
+				ValidatorIndex(i as u32),
+			)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_primitives::Hash;
+
+	fn node_features_with_mapping_enabled() -> NodeFeatures {
+		let mut node_features = NodeFeatures::new();
+		node_features.resize(FeatureIndex::AvailabilityChunkMapping as usize + 1, false);
+		node_features.set(FeatureIndex::AvailabilityChunkMapping as usize, true);
+		node_features
+	}
+
+	#[test]
+	fn identity_mapping_without_node_feature() {
+		let candidate_hash = CandidateHash(Hash::repeat_byte(7));
+		let n_validators = 10;
+
+		for node_features in [None, Some(&NodeFeatures::EMPTY)] {
+			assert_eq!(
+				availability_chunk_indices(node_features, n_validators, &candidate_hash),
+				(0..n_validators as u32).map(ValidatorIndex).collect::<Vec<_>>(),
+			);
+		}
+	}
+
+	#[test]
+	fn mapping_is_a_permutation_and_holder_is_its_inverse() {
+		let node_features = node_features_with_mapping_enabled();
+		let n_validators = 7;
+
+		for byte in 0..=u8::MAX {
+			let candidate_hash = CandidateHash(Hash::repeat_byte(byte));
+			let mut indices =
+				availability_chunk_indices(Some(&node_features), n_validators, &candidate_hash);
+
+			for (validator, chunk) in indices.iter().enumerate() {
+				assert_eq!(
+					availability_chunk_holder(
+						Some(&node_features),
+						n_validators,
+						&candidate_hash,
+						*chunk
+					),
+					ValidatorIndex(validator as u32),
+				);
+			}
+
+			indices.sort();
+			assert_eq!(indices, (0..n_validators as u32).map(ValidatorIndex).collect::<Vec<_>>());
+		}
+	}
+
+	#[test]
+	fn systematic_chunks_are_spread_across_validators() {
+		let node_features = node_features_with_mapping_enabled();
+		let n_validators = 10;
+
+		let holders_of_first_chunk = (0..=u8::MAX)
+			.map(|byte| {
+				availability_chunk_holder(
+					Some(&node_features),
+					n_validators,
+					&CandidateHash(Hash::repeat_byte(byte)),
+					ValidatorIndex(0),
+				)
+			})
+			.collect::<std::collections::HashSet<_>>();
+
+		assert!(holders_of_first_chunk.len() > 1);
+	}
+}
@@ -42,8 +42,9 @@ use crate::{
 	request_async_backing_params, request_availability_cores, request_candidate_events,
 	request_from_runtime, request_key_ownership_proof, request_on_chain_votes,
 	request_session_executor_params, request_session_index_for_child, request_session_info,
-	request_submit_report_dispute_lost, request_unapplied_slashes, request_validation_code_by_hash,
-	request_validator_groups, vstaging::get_disabled_validators_with_fallback,
+	request_submit_report_dispute_lost, request_unapplied_backer_slashes,
+	request_unapplied_slashes, request_validation_code_by_hash, request_validator_groups,
+	vstaging::get_disabled_validators_with_fallback,
 };
 
 /// Errors that can happen on runtime fetches.
@@ -427,6 +428,17 @@ where
 	recv_runtime(request_unapplied_slashes(relay_parent, sender).await).await
 }
 
+/// Fetch a list of `PendingSlashes` for backers of candidates concluded invalid from the runtime.
+pub async fn get_unapplied_backer_slashes<Sender>(
+	sender: &mut Sender,
+	relay_parent: Hash,
+) -> Result<Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>>
+where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
+	recv_runtime(request_unapplied_backer_slashes(relay_parent, sender).await).await
+}
+
 /// Generate validator key ownership proof.
 ///
 /// Note: The choice of `relay_parent` is important here, it needs to match
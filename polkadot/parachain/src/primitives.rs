@@ -20,7 +20,7 @@
 use sp_std::vec::Vec;
 
 use bounded_collections::{BoundedVec, ConstU32};
-use parity_scale_codec::{CompactAs, Decode, Encode, MaxEncodedLen};
+use parity_scale_codec::{CompactAs, Decode, DecodeAll, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_core::{bytes, RuntimeDebug, TypeId};
@@ -312,6 +312,73 @@ impl HrmpChannelId {
 /// A message from a parachain to its Relay Chain.
 pub type UpwardMessage = Vec<u8>;
 
+/// The first byte of an upward message carrying an [`UpwardMessageFragment`].
+///
+/// No version of `VersionedXcm` is encoded starting with this byte, so fragments can't be mistaken
+/// for regular upward messages.
+pub const UMP_FRAGMENT_PREFIX: u8 = 0xff;
+
+/// A fragment of an upward message which is too large to be sent in one piece.
+///
+/// The parachain splits the message with [`UpwardMessageFragment::split`] and sends the fragments
+/// in order as individual upward messages. The relay chain reassembles them and enqueues the
+/// message once the last fragment arrived.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UpwardMessageFragment {
+	/// Identifies the message among the fragmented messages of the parachain being reassembled.
+	pub message_id: u32,
+	/// The index of this fragment in the message.
+	pub index: u16,
+	/// The number of fragments the message was split into.
+	pub count: u16,
+	/// The part of the message carried by this fragment.
+	pub data: Vec<u8>,
+}
+
+impl UpwardMessageFragment {
+	/// The maximum number of bytes a fragment adds to the data it carries once encoded as an
+	/// upward message.
+	pub const MAX_OVERHEAD: usize = 1 + 4 + 2 + 2 + 5;
+
+	/// Splits `message` into fragments encoded as upward messages of at most `max_size` bytes.
+	///
+	/// Returns `None` if the message is empty, `max_size` leaves no room for data, or the message
+	/// would need more than `u16::MAX` fragments.
+	pub fn split(message_id: u32, message: &[u8], max_size: u32) -> Option<Vec<UpwardMessage>> {
+		let chunk_size = (max_size as usize).checked_sub(Self::MAX_OVERHEAD).filter(|s| *s > 0)?;
+		let count = u16::try_from(message.chunks(chunk_size).len()).ok().filter(|c| *c > 0)?;
+		let fragments = message
+			.chunks(chunk_size)
+			.enumerate()
+			.map(|(index, data)| {
+				Self { message_id, index: index as u16, count, data: data.to_vec() }
+					.into_upward_message()
+			})
+			.collect();
+		Some(fragments)
+	}
+
+	/// Encodes the fragment as an upward message.
+	pub fn into_upward_message(self) -> UpwardMessage {
+		let mut message = sp_std::vec![UMP_FRAGMENT_PREFIX];
+		self.encode_to(&mut message);
+		message
+	}
+
+	/// Returns whether the upward message carries a fragment.
+	pub fn is_fragment(message: &[u8]) -> bool {
+		message.first() == Some(&UMP_FRAGMENT_PREFIX)
+	}
+
+	/// Decodes the fragment carried by an upward message.
+	pub fn from_upward_message(message: &[u8]) -> Result<Self, parity_scale_codec::Error> {
+		match message.split_first() {
+			Some((&UMP_FRAGMENT_PREFIX, mut data)) => Self::decode_all(&mut data),
+			_ => Err("Upward message doesn't carry a fragment".into()),
+		}
+	}
+}
+
 /// Something that should be called when a downward message is received.
 pub trait DmpMessageHandler {
 	/// Handle some incoming DMP messages (note these are individual XCM messages).
@@ -75,6 +75,7 @@ fn main() -> Result<()> {
 
 						overseer_gen: polkadot_service::RealOverseerGen,
 						overseer_message_channel_capacity_override: None,
+						availability_store_max_size: None,
 						malus_finality_delay: None,
 						hwbench: None,
 					},
@@ -281,5 +281,17 @@ sp_api::decl_runtime_apis! {
 		/// Approval voting configuration parameters
 		#[api_version(10)]
 		fn approval_voting_params() -> ApprovalVotingParams;
+
+		/***** Added in v11 *****/
+		/// Returns a list of validators that backed a candidate which was later disputed and
+		/// concluded invalid in a past session, and that are still pending slashing.
+		#[api_version(11)]
+		fn unapplied_backer_slashes() -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>;
+
+		/***** Added in v12 *****/
+		/// Returns the candidates dropped by the runtime in the most recent block, along with the
+		/// reason they were dropped.
+		#[api_version(12)]
+		fn dropped_candidates() -> Vec<vstaging::DroppedCandidate>;
 	}
 }
@@ -108,6 +108,14 @@ pub enum ExecutorParam {
 	/// Enables WASM bulk memory proposal
 	#[codec(index = 7)]
 	WasmExtBulkMemory,
+	/// Deterministic PVF execution limit, in units of fuel consumed by executed instructions.
+	/// A valid value must be non-zero.
+	///
+	/// When present, a candidate exhausting the fuel is invalid on every validator, regardless of
+	/// its hardware. The execution timeouts still apply and should be chosen so that they're not
+	/// reached before the fuel is exhausted.
+	#[codec(index = 8)]
+	PvfExecFuelLimit(u64),
 }
 
 /// Possible inconsistencies of executor params.
@@ -250,6 +258,7 @@ impl ExecutorParams {
 					PvfExecKind::Approval => "PvfExecKind::Approval",
 				},
 				WasmExtBulkMemory => "WasmExtBulkMemory",
+				PvfExecFuelLimit(_) => "PvfExecFuelLimit",
 			};
 
 			match *param {
@@ -284,6 +293,10 @@ impl ExecutorParams {
 				WasmExtBulkMemory => {
 					check!(param_ident, 1);
 				},
+
+				PvfExecFuelLimit(val) => {
+					check!(param_ident, val, val == 0,);
+				},
 			}
 		}
 
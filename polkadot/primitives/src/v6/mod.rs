@@ -16,7 +16,7 @@
 
 //! `V6` Primitives.
 
-use bitvec::vec::BitVec;
+use bitvec::{field::BitField, slice::BitSlice, vec::BitVec};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_std::{
@@ -707,10 +707,55 @@ pub struct BackedCandidate<H = Hash> {
 	/// The validity votes themselves, expressed as signatures.
 	pub validity_votes: Vec<ValidityAttestation>,
 	/// The indices of the validators within the group, expressed as a bitfield.
+	///
+	/// If the `ElasticScalingMVP` node feature is enabled, the bitfield is extended by 8 bits
+	/// holding the index of the core the candidate is backed on. Use
+	/// [`BackedCandidate::validator_indices_and_core_index`] to read it.
 	pub validator_indices: BitVec<u8, bitvec::order::Lsb0>,
 }
 
 impl<H> BackedCandidate<H> {
+	/// Construct a `BackedCandidate`, committing to the core index if one is provided.
+	pub fn new(
+		candidate: CommittedCandidateReceipt<H>,
+		validity_votes: Vec<ValidityAttestation>,
+		validator_indices: BitVec<u8, bitvec::order::Lsb0>,
+		core_index: Option<CoreIndex>,
+	) -> Self {
+		let mut instance = Self { candidate, validity_votes, validator_indices };
+		if let Some(core_index) = core_index {
+			instance.inject_core_index(core_index);
+		}
+		instance
+	}
+
+	/// Get the validator indices within the group and the core index the candidate is backed on.
+	///
+	/// The core index is only present if `core_index_enabled` is true, i.e. if the
+	/// `ElasticScalingMVP` node feature is enabled, and the validator indices carry it.
+	pub fn validator_indices_and_core_index(
+		&self,
+		core_index_enabled: bool,
+	) -> (&BitSlice<u8, bitvec::order::Lsb0>, Option<CoreIndex>) {
+		if core_index_enabled {
+			let core_idx_offset = self.validator_indices.len().saturating_sub(8);
+			if core_idx_offset > 0 {
+				let (validator_indices, core_idx) =
+					self.validator_indices.split_at(core_idx_offset);
+				return (validator_indices, Some(CoreIndex(core_idx.load::<u8>() as u32)))
+			}
+		}
+
+		(&self.validator_indices, None)
+	}
+
+	/// Append the core index to the validator indices.
+	fn inject_core_index(&mut self, core_index: CoreIndex) {
+		let core_index: BitVec<u8, bitvec::order::Lsb0> =
+			BitVec::from_vec(vec![core_index.0 as u8]);
+		self.validator_indices.extend(core_index);
+	}
+
 	/// Get a reference to the descriptor of the para.
 	pub fn descriptor(&self) -> &CandidateDescriptor<H> {
 		&self.candidate.descriptor
@@ -735,6 +780,9 @@ impl<H> BackedCandidate<H> {
 
 /// Verify the backing of the given candidate.
 ///
+/// The validator indices must not include the core index, see
+/// [`BackedCandidate::validator_indices_and_core_index`].
+///
 /// Provide a lookup from the index of a validator within the group assigned to this para,
 /// as opposed to the index of the validator within the overall validator set, as well as
 /// the number of validators in the group.
@@ -744,32 +792,30 @@ impl<H> BackedCandidate<H> {
 /// Returns either an error, indicating that one of the signatures was invalid or that the index
 /// was out-of-bounds, or the number of signatures checked.
 pub fn check_candidate_backing<H: AsRef<[u8]> + Clone + Encode>(
-	backed: &BackedCandidate<H>,
+	candidate_hash: CandidateHash,
+	validity_votes: &[ValidityAttestation],
+	validator_indices: &BitSlice<u8, bitvec::order::Lsb0>,
 	signing_context: &SigningContext<H>,
 	group_len: usize,
 	validator_lookup: impl Fn(usize) -> Option<ValidatorId>,
 ) -> Result<usize, ()> {
-	if backed.validator_indices.len() != group_len {
+	if validator_indices.len() != group_len {
 		return Err(())
 	}
 
-	if backed.validity_votes.len() > group_len {
+	if validity_votes.len() > group_len {
 		return Err(())
 	}
 
-	// this is known, even in runtime, to be blake2-256.
-	let hash = backed.candidate.hash();
-
 	let mut signed = 0;
-	for ((val_in_group_idx, _), attestation) in backed
-		.validator_indices
+	for ((val_in_group_idx, _), attestation) in validator_indices
 		.iter()
 		.enumerate()
 		.filter(|(_, signed)| **signed)
-		.zip(backed.validity_votes.iter())
+		.zip(validity_votes.iter())
 	{
 		let validator_id = validator_lookup(val_in_group_idx).ok_or(())?;
-		let payload = attestation.signed_payload(hash, signing_context);
+		let payload = attestation.signed_payload(candidate_hash, signing_context);
 		let sig = attestation.signature();
 
 		if sig.verify(&payload[..], &validator_id) {
@@ -779,7 +825,7 @@ pub fn check_candidate_backing<H: AsRef<[u8]> + Clone + Encode>(
 		}
 	}
 
-	if signed != backed.validity_votes.len() {
+	if signed != validity_votes.len() {
 		return Err(())
 	}
 
@@ -1142,6 +1188,12 @@ pub struct AbridgedHostConfiguration {
 	pub validation_upgrade_delay: BlockNumber,
 	/// Asynchronous backing parameters.
 	pub async_backing_params: AsyncBackingParams,
+	/// The maximum size of an upward message that can be sent in fragments, which the relay chain
+	/// reassembles.
+	///
+	/// Messages larger than `max_upward_message_size` must be split into fragments and can't
+	/// exceed this size.
+	pub max_upward_reassembled_message_size: u32,
 }
 
 /// Abridged version of `HrmpChannel` (from the `Hrmp` parachains host runtime module) meant to be
@@ -1930,4 +1982,57 @@ mod tests {
 
 		assert!(zero_b.leading_zeros() >= zero_u.leading_zeros());
 	}
+
+	fn dummy_backed_candidate(core_index: Option<CoreIndex>) -> BackedCandidate {
+		let descriptor = CandidateDescriptor {
+			para_id: 1.into(),
+			relay_parent: Hash::repeat_byte(1),
+			collator: primitives::sr25519::Public::from_raw([0; 32]).into(),
+			persisted_validation_data_hash: Hash::repeat_byte(2),
+			pov_hash: Hash::repeat_byte(3),
+			erasure_root: Hash::repeat_byte(4),
+			signature: primitives::sr25519::Signature::from_raw([0; 64]).into(),
+			para_head: Hash::repeat_byte(5),
+			validation_code_hash: Hash::repeat_byte(6).into(),
+		};
+		let candidate = CommittedCandidateReceipt {
+			descriptor,
+			commitments: CandidateCommitments {
+				upward_messages: Default::default(),
+				horizontal_messages: Default::default(),
+				new_validation_code: None,
+				head_data: vec![1, 2, 3].into(),
+				processed_downward_messages: 0,
+				hrmp_watermark: 0,
+			},
+		};
+
+		BackedCandidate::new(
+			candidate,
+			Vec::new(),
+			bitvec::bitvec![u8, bitvec::order::Lsb0; 1, 0, 1, 1, 0],
+			core_index,
+		)
+	}
+
+	#[test]
+	fn backed_candidate_core_index_roundtrip() {
+		let backed = dummy_backed_candidate(None);
+		assert_eq!(backed.validator_indices.len(), 5);
+		let (validator_indices, core_index) = backed.validator_indices_and_core_index(false);
+		assert_eq!(validator_indices, bitvec::bits![u8, bitvec::order::Lsb0; 1, 0, 1, 1, 0]);
+		assert_eq!(core_index, None);
+
+		let backed = dummy_backed_candidate(Some(CoreIndex(10)));
+		assert_eq!(backed.validator_indices.len(), 13);
+
+		let (validator_indices, core_index) = backed.validator_indices_and_core_index(true);
+		assert_eq!(validator_indices, bitvec::bits![u8, bitvec::order::Lsb0; 1, 0, 1, 1, 0]);
+		assert_eq!(core_index, Some(CoreIndex(10)));
+
+		// Without the feature, the core index is treated as part of the validator indices.
+		let (validator_indices, core_index) = backed.validator_indices_and_core_index(false);
+		assert_eq!(validator_indices.len(), 13);
+		assert_eq!(core_index, None);
+	}
 }
@@ -30,6 +30,11 @@ pub enum SlashingOffenceKind {
 	/// A minor offence when a validator disputed a valid block.
 	#[codec(index = 1)]
 	AgainstValid,
+	/// A severe offence when a validator backed a block which was later
+	/// disputed and concluded invalid. Unlike `ForInvalid`, this does not
+	/// require the validator to have participated in the dispute.
+	#[codec(index = 2)]
+	BackedInvalid,
 }
 
 /// Timeslots should uniquely identify offences and are used for the offence
@@ -50,6 +50,49 @@ impl Default for ApprovalVotingParams {
 	}
 }
 
+/// The reason a candidate was dropped by the runtime.
+#[derive(RuntimeDebug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum CandidateDropReason {
+	/// A dispute about the candidate concluded invalid.
+	#[codec(index = 0)]
+	ConcludedInvalid,
+	/// None of the cores scheduled for the para could be assigned to the candidate: no core is
+	/// scheduled for the para, or the candidate doesn't commit to one of them.
+	#[codec(index = 1)]
+	UnscheduledCore,
+	/// The core of the candidate is already used by another candidate backed in the same block.
+	#[codec(index = 2)]
+	CoreAlreadyUsed,
+	/// The para has no head data, e.g. because it is being offboarded.
+	#[codec(index = 3)]
+	MissingParaHead,
+	/// The candidate failed verification, e.g. because its relay parent is no longer allowed or it
+	/// doesn't build on the latest head of the para.
+	#[codec(index = 4)]
+	VerificationFailed,
+	/// A candidate before it in the chain of the para was dropped.
+	#[codec(index = 5)]
+	PredecessorDropped,
+	/// The candidate was pending availability when the session changed.
+	#[codec(index = 6)]
+	SessionChange,
+	/// The candidate didn't become available in time.
+	#[codec(index = 7)]
+	TimedOut,
+}
+
+/// A candidate dropped by the runtime, either before being backed on chain or while pending
+/// availability.
+#[derive(RuntimeDebug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct DroppedCandidate {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate belongs to.
+	pub para_id: Id,
+	/// Why the candidate was dropped.
+	pub reason: CandidateDropReason,
+}
+
 use bitvec::vec::BitVec;
 
 /// Bit indices in the `HostConfiguration.node_features` that correspond to different node features.
@@ -64,9 +107,17 @@ pub mod node_features {
 		/// Tells if tranch0 assignments could be sent in a single certificate.
 		/// Reserved for: `<https://github.com/paritytech/polkadot-sdk/issues/628>`
 		EnableAssignmentsV2 = 0,
+		/// This feature enables the extension of `BackedCandidate::validator_indices` by 8 bits.
+		/// The value stored there represents the assumed core index where the candidates
+		/// are backed. This is needed for the elastic scaling MVP.
+		ElasticScalingMVP = 1,
+		/// Tells if the availability chunks are shuffled among the validators of a session,
+		/// instead of each validator holding the chunk with its own index. This spreads the load
+		/// of serving the systematic chunks across the whole validator set.
+		AvailabilityChunkMapping = 2,
 		/// First unassigned feature bit.
 		/// Every time a new feature flag is assigned it should take this value.
 		/// and this should be incremented.
-		FirstUnassigned = 1,
+		FirstUnassigned = 3,
 	}
 }
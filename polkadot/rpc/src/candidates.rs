@@ -0,0 +1,264 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC methods for following the lifecycle of the candidates of a para.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{error::CallError, ErrorObject},
+};
+use polkadot_primitives::{
+	runtime_api::ParachainHost,
+	vstaging::{CandidateDropReason, DroppedCandidate},
+	Block, BlockNumber, CandidateEvent, Hash, Id as ParaId,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Header as HeaderT;
+
+const CANDIDATES_ERROR: i32 = 9600;
+
+/// The number of blocks the timeline covers if no depth is given.
+const DEFAULT_TIMELINE_DEPTH: u32 = 32;
+
+/// The maximum number of blocks the timeline can cover.
+const MAX_TIMELINE_DEPTH: u32 = 256;
+
+/// The version of the `ParachainHost` runtime API which introduced `dropped_candidates`.
+const DROPPED_CANDIDATES_API_VERSION: u32 = 12;
+
+/// Provides RPC methods for following the lifecycle of candidates.
+#[rpc(client, server)]
+pub trait CandidatesApi<BlockHash> {
+	/// Returns what happened to the candidates of the given para in the last `depth` blocks up to
+	/// `at`, oldest first: when they were backed, included or timed out, and when and why they
+	/// were dropped.
+	///
+	/// Defaults to the last 32 blocks up to the best block. At most 256 blocks are covered.
+	#[method(name = "parachain_candidateTimeline")]
+	fn candidate_timeline(
+		&self,
+		para_id: u32,
+		depth: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CandidateTimelineEntry>>;
+}
+
+/// Provides RPC methods for following the lifecycle of candidates.
+pub struct Candidates<C> {
+	/// The client instance to use.
+	client: Arc<C>,
+}
+
+impl<C> Candidates<C> {
+	/// Creates a new instance of the candidates RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> CandidatesApiServer<Hash> for Candidates<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ParachainHost<Block>,
+{
+	fn candidate_timeline(
+		&self,
+		para_id: u32,
+		depth: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<CandidateTimelineEntry>> {
+		let para_id = ParaId::from(para_id);
+		let depth = depth.unwrap_or(DEFAULT_TIMELINE_DEPTH).min(MAX_TIMELINE_DEPTH);
+		let mut block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let mut blocks = Vec::new();
+		for _ in 0..depth {
+			let header = self
+				.client
+				.header(block_hash)
+				.map_err(|e| Error::Blockchain(e.to_string()))?
+				.ok_or(Error::UnknownBlock(block_hash))?;
+			let block_number = *header.number();
+			let api = self.client.runtime_api();
+
+			let mut events = api
+				.candidate_events(block_hash)
+				.map_err(|e| Error::RuntimeApi(e.to_string()))?
+				.into_iter()
+				.filter_map(|event| CandidateLifecycleEvent::from_candidate_event(event, para_id))
+				.collect::<Vec<_>>();
+
+			let api_version = api
+				.api_version::<dyn ParachainHost<Block>>(block_hash)
+				.map_err(|e| Error::RuntimeApi(e.to_string()))?
+				.unwrap_or_default();
+			if api_version >= DROPPED_CANDIDATES_API_VERSION {
+				events.extend(
+					api.dropped_candidates(block_hash)
+						.map_err(|e| Error::RuntimeApi(e.to_string()))?
+						.into_iter()
+						.filter(|dropped| dropped.para_id == para_id)
+						.map(CandidateLifecycleEvent::from_dropped_candidate),
+				);
+			}
+
+			blocks.push(events.into_iter().map(move |(candidate_hash, event)| {
+				CandidateTimelineEntry { block_hash, block_number, candidate_hash, event }
+			}));
+
+			if block_number == 0 {
+				break
+			}
+			block_hash = *header.parent_hash();
+		}
+
+		Ok(blocks.into_iter().rev().flatten().collect())
+	}
+}
+
+/// Something that happened to a candidate in a block.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateTimelineEntry {
+	/// The hash of the block.
+	pub block_hash: Hash,
+	/// The number of the block.
+	pub block_number: BlockNumber,
+	/// The hash of the candidate.
+	pub candidate_hash: Hash,
+	/// What happened to the candidate.
+	pub event: CandidateLifecycleEvent,
+}
+
+/// A step in the lifecycle of a candidate.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CandidateLifecycleEvent {
+	/// The candidate was backed on the given core.
+	Backed {
+		/// The core the candidate occupies.
+		core: u32,
+	},
+	/// The candidate became available and was included.
+	Included {
+		/// The core the candidate occupied.
+		core: u32,
+	},
+	/// The candidate wasn't made available in time.
+	TimedOut {
+		/// The core the candidate occupied.
+		core: u32,
+	},
+	/// The candidate was dropped by the runtime.
+	Dropped {
+		/// Why the candidate was dropped.
+		reason: DropReason,
+	},
+}
+
+impl CandidateLifecycleEvent {
+	/// Returns the hash of the candidate along with the event, if the candidate belongs to the
+	/// given para.
+	fn from_candidate_event(event: CandidateEvent, para_id: ParaId) -> Option<(Hash, Self)> {
+		let (receipt, event) = match event {
+			CandidateEvent::CandidateBacked(receipt, _, core, _) =>
+				(receipt, Self::Backed { core: core.0 }),
+			CandidateEvent::CandidateIncluded(receipt, _, core, _) =>
+				(receipt, Self::Included { core: core.0 }),
+			CandidateEvent::CandidateTimedOut(receipt, _, core) =>
+				(receipt, Self::TimedOut { core: core.0 }),
+		};
+
+		(receipt.descriptor.para_id == para_id).then(|| (receipt.hash().0, event))
+	}
+
+	/// Returns the hash of the dropped candidate along with the event.
+	fn from_dropped_candidate(dropped: DroppedCandidate) -> (Hash, Self) {
+		(dropped.candidate_hash.0, Self::Dropped { reason: dropped.reason.into() })
+	}
+}
+
+/// Why a candidate was dropped by the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DropReason {
+	/// A dispute about the candidate concluded invalid.
+	ConcludedInvalid,
+	/// None of the cores scheduled for the para could be assigned to the candidate.
+	UnscheduledCore,
+	/// The core of the candidate was already used by another candidate backed in the same block.
+	CoreAlreadyUsed,
+	/// The para has no head data.
+	MissingParaHead,
+	/// The candidate failed verification.
+	VerificationFailed,
+	/// A candidate before it in the chain of the para was dropped.
+	PredecessorDropped,
+	/// The candidate was pending availability when the session changed.
+	SessionChange,
+	/// The candidate didn't become available in time.
+	TimedOut,
+}
+
+impl From<CandidateDropReason> for DropReason {
+	fn from(reason: CandidateDropReason) -> Self {
+		match reason {
+			CandidateDropReason::ConcludedInvalid => Self::ConcludedInvalid,
+			CandidateDropReason::UnscheduledCore => Self::UnscheduledCore,
+			CandidateDropReason::CoreAlreadyUsed => Self::CoreAlreadyUsed,
+			CandidateDropReason::MissingParaHead => Self::MissingParaHead,
+			CandidateDropReason::VerificationFailed => Self::VerificationFailed,
+			CandidateDropReason::PredecessorDropped => Self::PredecessorDropped,
+			CandidateDropReason::SessionChange => Self::SessionChange,
+			CandidateDropReason::TimedOut => Self::TimedOut,
+		}
+	}
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The block isn't known to the node.
+	#[error("Unknown block {0:?}")]
+	UnknownBlock(Hash),
+	/// Reading a block header failed.
+	#[error("Blockchain error: {0}")]
+	Blockchain(String),
+	/// Calling the runtime API failed.
+	#[error("Runtime API error: {0}")]
+	RuntimeApi(String),
+}
+
+impl From<Error> for JsonRpseeError {
+	fn from(error: Error) -> Self {
+		let error_code = match error {
+			Error::UnknownBlock(_) => 1,
+			Error::Blockchain(_) => 2,
+			Error::RuntimeApi(_) => 3,
+		};
+
+		JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+			CANDIDATES_ERROR + error_code,
+			error.to_string(),
+			Some(format!("{:?}", error)),
+		)))
+	}
+}
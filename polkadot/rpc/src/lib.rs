@@ -21,7 +21,9 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use polkadot_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
+use polkadot_primitives::{
+	runtime_api::ParachainHost, AccountId, Balance, Block, BlockNumber, Hash, Nonce,
+};
 use sc_client_api::AuxStore;
 use sc_consensus_beefy::communication::notification::{
 	BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
@@ -36,6 +38,9 @@ use sp_consensus_babe::BabeApi;
 use sp_keystore::KeystorePtr;
 use txpool_api::TransactionPool;
 
+pub mod candidates;
+pub mod overseer;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = RpcModule<()>;
 
@@ -61,6 +66,12 @@ pub struct GrandpaDeps<B> {
 	pub finality_provider: Arc<FinalityProofProvider<B, Block>>,
 }
 
+/// Overseer specific dependencies.
+pub struct OverseerDeps {
+	/// The health of the subsystems, if the overseer is running on this node.
+	pub subsystems_health: Option<polkadot_overseer::SubsystemsHealth>,
+}
+
 /// Dependencies for BEEFY
 pub struct BeefyDeps {
 	/// Receives notifications about finality proof events from BEEFY.
@@ -89,13 +100,26 @@ pub struct FullDeps<C, P, SC, B> {
 	pub grandpa: GrandpaDeps<B>,
 	/// BEEFY specific dependencies.
 	pub beefy: BeefyDeps,
+	/// Overseer specific dependencies.
+	pub overseer: OverseerDeps,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
 }
 
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P, SC, B>(
-	FullDeps { client, pool, select_chain, chain_spec, deny_unsafe, babe, grandpa, beefy, backend } : FullDeps<C, P, SC, B>,
+	FullDeps {
+		client,
+		pool,
+		select_chain,
+		chain_spec,
+		deny_unsafe,
+		babe,
+		grandpa,
+		beefy,
+		overseer,
+		backend,
+	}: FullDeps<C, P, SC, B>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>
@@ -110,11 +134,16 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: ParachainHost<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use self::{
+		candidates::{Candidates, CandidatesApiServer},
+		overseer::{Overseer, OverseerApiServer},
+	};
 	use frame_rpc_system::{System, SystemApiServer};
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -143,6 +172,7 @@ where
 	io.merge(StateMigration::new(client.clone(), backend.clone(), deny_unsafe).into_rpc())?;
 	io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(Candidates::new(client.clone()).into_rpc())?;
 	io.merge(
 		Mmr::new(
 			client.clone(),
@@ -179,5 +209,7 @@ where
 		.into_rpc(),
 	)?;
 
+	io.merge(Overseer::new(overseer.subsystems_health, deny_unsafe).into_rpc())?;
+
 	Ok(io)
 }
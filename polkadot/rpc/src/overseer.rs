@@ -0,0 +1,157 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC methods for introspecting the overseer and its subsystems.
+
+use std::time::UNIX_EPOCH;
+
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{error::CallError, ErrorObject},
+};
+use polkadot_overseer::{OverseerIntrospection, SubsystemHealth, SubsystemsHealth};
+use polkadot_primitives::{BlockNumber, Hash};
+use sc_rpc::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+
+const OVERSEER_ERROR: i32 = 9500;
+
+/// Provides RPC methods for introspecting the overseer.
+#[rpc(client, server)]
+pub trait OverseerApi {
+	/// Returns the health of every subsystem: the fill level of its channels, when it last
+	/// received a message or signal and whether it stalled. Also returns the active leaves known
+	/// to the overseer.
+	#[method(name = "overseer_subsystemsHealth")]
+	fn subsystems_health(&self) -> RpcResult<OverseerHealth>;
+}
+
+/// Provides RPC methods for introspecting the overseer.
+pub struct Overseer {
+	/// The health of the subsystems, shared with the overseer, if it is running.
+	subsystems_health: Option<SubsystemsHealth>,
+	/// Whether to deny unsafe calls
+	deny_unsafe: DenyUnsafe,
+}
+
+impl Overseer {
+	/// Creates a new instance of the overseer RPC handler.
+	pub fn new(subsystems_health: Option<SubsystemsHealth>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { subsystems_health, deny_unsafe }
+	}
+}
+
+impl OverseerApiServer for Overseer {
+	fn subsystems_health(&self) -> RpcResult<OverseerHealth> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let subsystems_health = self.subsystems_health.as_ref().ok_or(Error::NotRunning)?;
+
+		Ok(subsystems_health.introspect().into())
+	}
+}
+
+/// The health of a single subsystem.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubsystemHealthInfo {
+	/// The name of the subsystem.
+	pub name: String,
+	/// The number of messages waiting in the bounded channel of the subsystem.
+	pub bounded_queue_len: u64,
+	/// The number of messages waiting in the unbounded channel of the subsystem.
+	pub unbounded_queue_len: u64,
+	/// The number of signals the subsystem didn't receive yet.
+	pub pending_signals: u64,
+	/// The total number of messages the subsystem received.
+	pub messages_received: u64,
+	/// When the subsystem last received a message or signal, in milliseconds since the unix epoch.
+	pub last_progress: Option<u64>,
+	/// Whether the subsystem has pending messages or signals, but didn't receive any of them for a
+	/// while.
+	pub stalled: bool,
+}
+
+impl From<SubsystemHealth> for SubsystemHealthInfo {
+	fn from(health: SubsystemHealth) -> Self {
+		Self {
+			name: health.name.to_string(),
+			bounded_queue_len: health.bounded_queue_len as u64,
+			unbounded_queue_len: health.unbounded_queue_len as u64,
+			pending_signals: health.pending_signals as u64,
+			messages_received: health.messages_received as u64,
+			last_progress: health.last_progress.and_then(|last_progress| {
+				last_progress.duration_since(UNIX_EPOCH).ok().map(|d| d.as_millis() as u64)
+			}),
+			stalled: health.stalled,
+		}
+	}
+}
+
+/// An active leaf known to the overseer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ActiveLeaf {
+	/// The hash of the leaf.
+	pub hash: Hash,
+	/// The number of the leaf.
+	pub number: BlockNumber,
+}
+
+/// The health of the overseer and its subsystems.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverseerHealth {
+	/// The leaves currently active.
+	pub active_leaves: Vec<ActiveLeaf>,
+	/// The health of all running subsystems.
+	pub subsystems: Vec<SubsystemHealthInfo>,
+}
+
+impl From<OverseerIntrospection> for OverseerHealth {
+	fn from(introspection: OverseerIntrospection) -> Self {
+		Self {
+			active_leaves: introspection
+				.active_leaves
+				.into_iter()
+				.map(|(hash, number)| ActiveLeaf { hash, number })
+				.collect(),
+			subsystems: introspection.subsystems.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The overseer isn't running on this node.
+	#[error("The overseer isn't running")]
+	NotRunning,
+}
+
+impl From<Error> for JsonRpseeError {
+	fn from(error: Error) -> Self {
+		let error_code = match error {
+			Error::NotRunning => 1,
+		};
+
+		JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+			OVERSEER_ERROR + error_code,
+			error.to_string(),
+			Some(format!("{:?}", error)),
+		)))
+	}
+}
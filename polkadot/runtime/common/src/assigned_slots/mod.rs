@@ -730,6 +730,7 @@ mod tests {
 
 	impl parachains_configuration::Config for Test {
 		type WeightInfo = parachains_configuration::TestWeightInfo;
+		type MaxUmpMessageLen = ConstU32<0>;
 	}
 
 	parameter_types! {
@@ -141,6 +141,7 @@ impl TryConvert<&VersionedMultiLocation, xcm::latest::MultiLocation>
 		let latest = match location.clone() {
 			VersionedMultiLocation::V2(l) => l.try_into().map_err(|_| location)?,
 			VersionedMultiLocation::V3(l) => l,
+			VersionedMultiLocation::V4(l) => l.try_into().map_err(|_| location)?,
 		};
 		Ok(latest)
 	}
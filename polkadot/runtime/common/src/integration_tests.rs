@@ -195,6 +195,7 @@ impl pallet_balances::Config for Test {
 
 impl configuration::Config for Test {
 	type WeightInfo = configuration::TestWeightInfo;
+	type MaxUmpMessageLen = ConstU32<0>;
 }
 
 impl shared::Config for Test {}
@@ -41,7 +41,7 @@ use runtime_parachains::paras::{OnNewHead, ParaKind};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{CheckedSub, Saturating},
-	RuntimeDebug,
+	DispatchError, RuntimeDebug,
 };
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
@@ -73,6 +73,8 @@ pub trait WeightInfo {
 	fn swap() -> Weight;
 	fn schedule_code_upgrade(b: u32) -> Weight;
 	fn set_current_head(b: u32) -> Weight;
+	fn set_manager() -> Weight;
+	fn poke_deposit() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -98,6 +100,12 @@ impl WeightInfo for TestWeightInfo {
 	fn set_current_head(_b: u32) -> Weight {
 		Weight::zero()
 	}
+	fn set_manager() -> Weight {
+		Weight::zero()
+	}
+	fn poke_deposit() -> Weight {
+		Weight::zero()
+	}
 }
 
 #[frame_support::pallet]
@@ -153,6 +161,8 @@ pub mod pallet {
 		Deregistered { para_id: ParaId },
 		Reserved { para_id: ParaId, who: T::AccountId },
 		Swapped { para_id: ParaId, other_id: ParaId },
+		ManagerChanged { para_id: ParaId, manager: T::AccountId },
+		DepositPoked { para_id: ParaId, manager: T::AccountId, deposit: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -187,6 +197,8 @@ pub mod pallet {
 		/// Cannot perform a parachain slot / lifecycle swap. Check that the state of both paras
 		/// are correct for the swap to work.
 		CannotSwap,
+		/// The para has no head data or validation code yet.
+		NotOnboarded,
 	}
 
 	/// Pending swap operations.
@@ -431,6 +443,75 @@ pub mod pallet {
 			runtime_parachains::set_current_head::<T>(para, new_head);
 			Ok(())
 		}
+
+		/// Hand the management of a para over to a new manager.
+		///
+		/// Can be called by Root, the parachain, or the parachain manager if the parachain is
+		/// unlocked. A para can manage itself by setting its sovereign account as the manager,
+		/// which it then controls via XCM.
+		///
+		/// ## Deposits/Fees
+		/// The new manager reserves the deposit held for the para, and the deposit of the previous
+		/// manager is returned.
+		///
+		/// ## Events
+		/// The `ManagerChanged` event is emitted in case of success.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_manager())]
+		pub fn set_manager(
+			origin: OriginFor<T>,
+			para: ParaId,
+			new_manager: T::AccountId,
+		) -> DispatchResult {
+			Self::ensure_root_para_or_owner(origin, para)?;
+			let mut info = Paras::<T>::get(para).ok_or(Error::<T>::NotRegistered)?;
+			if info.manager == new_manager {
+				return Ok(())
+			}
+
+			<T as Config>::Currency::reserve(&new_manager, info.deposit)?;
+			<T as Config>::Currency::unreserve(&info.manager, info.deposit);
+			info.manager = new_manager.clone();
+			Paras::<T>::insert(para, info);
+
+			Self::deposit_event(Event::<T>::ManagerChanged { para_id: para, manager: new_manager });
+			Ok(())
+		}
+
+		/// Recompute the deposit of a para from its current head data and validation code.
+		///
+		/// Can be called by Root, the parachain, or the parachain manager if the parachain is
+		/// unlocked. The para must have been onboarded.
+		///
+		/// ## Deposits/Fees
+		/// The manager reserves whatever the deposit falls short of, e.g. after an upgrade to a
+		/// larger validation code, and gets any excess returned.
+		///
+		/// ## Events
+		/// The `DepositPoked` event is emitted in case of success.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(origin: OriginFor<T>, para: ParaId) -> DispatchResult {
+			Self::ensure_root_para_or_owner(origin, para)?;
+			let mut info = Paras::<T>::get(para).ok_or(Error::<T>::NotRegistered)?;
+			let head = paras::Pallet::<T>::para_head(para).ok_or(Error::<T>::NotOnboarded)?;
+			let code = paras::Pallet::<T>::current_code_hash(para)
+				.and_then(paras::Pallet::<T>::code_by_hash)
+				.ok_or(Error::<T>::NotOnboarded)?;
+			let deposit = Self::deposit_for(&head, &code);
+
+			if let Some(additional) = deposit.checked_sub(&info.deposit) {
+				<T as Config>::Currency::reserve(&info.manager, additional)?;
+			} else if let Some(rebate) = info.deposit.checked_sub(&deposit) {
+				<T as Config>::Currency::unreserve(&info.manager, rebate);
+			}
+			info.deposit = deposit;
+			let manager = info.manager.clone();
+			Paras::<T>::insert(para, info);
+
+			Self::deposit_event(Event::<T>::DepositPoked { para_id: para, manager, deposit });
+			Ok(())
+		}
 	}
 }
 
@@ -559,8 +640,8 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		if let Ok(caller_id) = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin.clone()))
 		{
-			// Check if matching para id...
-			ensure!(caller_id == id, Error::<T>::NotOwner);
+			// A para may only act on its own registration.
+			ensure!(caller_id == id, DispatchError::BadOrigin);
 		} else {
 			// Check if root...
 			ensure_root(origin.clone())?;
@@ -658,14 +739,19 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::HeadDataTooLarge
 		);
 
-		let per_byte_fee = T::DataDepositPerByte::get();
-		let deposit = T::ParaDeposit::get()
-			.saturating_add(per_byte_fee.saturating_mul((genesis_head.0.len() as u32).into()))
-			.saturating_add(per_byte_fee.saturating_mul((validation_code.0.len() as u32).into()));
+		let deposit = Self::deposit_for(&genesis_head, &validation_code);
 
 		Ok((ParaGenesisArgs { genesis_head, validation_code, para_kind }, deposit))
 	}
 
+	/// The deposit needed for storing the given head data and validation code of a para.
+	fn deposit_for(head: &HeadData, validation_code: &ValidationCode) -> BalanceOf<T> {
+		let per_byte_fee = T::DataDepositPerByte::get();
+		T::ParaDeposit::get()
+			.saturating_add(per_byte_fee.saturating_mul((head.0.len() as u32).into()))
+			.saturating_add(per_byte_fee.saturating_mul((validation_code.0.len() as u32).into()))
+	}
+
 	/// Swap a lease holding parachain and parathread (on-demand parachain), which involves
 	/// scheduling an appropriate lifecycle update.
 	fn do_thread_and_chain_swap(to_downgrade: ParaId, to_upgrade: ParaId) {
@@ -819,6 +905,7 @@ mod tests {
 
 	impl configuration::Config for Test {
 		type WeightInfo = configuration::TestWeightInfo;
+		type MaxUmpMessageLen = ConstU32<0>;
 	}
 
 	parameter_types! {
@@ -1324,6 +1411,108 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn set_manager_works() {
+		new_test_ext().execute_with(|| {
+			const START_SESSION_INDEX: SessionIndex = 1;
+			run_to_session(START_SESSION_INDEX);
+
+			let para_id = LOWEST_PUBLIC_ID;
+			let validation_code = test_validation_code(32);
+			assert_ok!(Registrar::reserve(RuntimeOrigin::signed(1)));
+			assert_ok!(Registrar::register(
+				RuntimeOrigin::signed(1),
+				para_id,
+				test_genesis_head(32),
+				validation_code.clone(),
+			));
+			conclude_pvf_checking::<Test>(&validation_code, VALIDATORS, START_SESSION_INDEX);
+			let deposit = <Test as Config>::ParaDeposit::get() + 64;
+			assert_eq!(Balances::reserved_balance(&1), deposit);
+
+			// Only the manager can hand the para over.
+			assert_noop!(Registrar::set_manager(RuntimeOrigin::signed(2), para_id, 2), BadOrigin);
+			assert_ok!(Registrar::set_manager(RuntimeOrigin::signed(1), para_id, 2));
+			assert_eq!(Registrar::manager_of(para_id), Some(2));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::reserved_balance(&2), deposit);
+
+			// Once locked, the manager can't act anymore, but the para itself can.
+			Registrar::on_new_head(para_id, &Default::default());
+			assert_noop!(Registrar::set_manager(RuntimeOrigin::signed(2), para_id, 3), BadOrigin);
+			assert_noop!(Registrar::set_manager(para_origin(para_id + 1), para_id, 3), BadOrigin);
+			assert_ok!(Registrar::set_manager(para_origin(para_id), para_id, 3));
+			assert_eq!(Balances::reserved_balance(&2), 0);
+			assert_eq!(Balances::reserved_balance(&3), deposit);
+
+			// The new manager must be able to pay the deposit.
+			assert_noop!(
+				Registrar::set_manager(para_origin(para_id), para_id, 4),
+				BalancesError::<Test, _>::InsufficientBalance
+			);
+
+			// The deposit is returned to the latest manager when the para deregisters itself.
+			run_to_session(START_SESSION_INDEX + 2);
+			assert!(Parachains::is_parathread(para_id));
+			assert_ok!(Registrar::deregister(para_origin(para_id), para_id));
+			run_to_session(START_SESSION_INDEX + 4);
+			assert!(paras::Pallet::<Test>::lifecycle(para_id).is_none());
+			assert_eq!(Balances::reserved_balance(&3), 0);
+		});
+	}
+
+	#[test]
+	fn poke_deposit_works() {
+		new_test_ext().execute_with(|| {
+			const START_SESSION_INDEX: SessionIndex = 1;
+			run_to_session(START_SESSION_INDEX);
+
+			let para_id = LOWEST_PUBLIC_ID;
+			let validation_code = test_validation_code(32);
+			assert_ok!(Registrar::reserve(RuntimeOrigin::signed(1)));
+			assert_ok!(Registrar::register(
+				RuntimeOrigin::signed(1),
+				para_id,
+				test_genesis_head(32),
+				validation_code.clone(),
+			));
+			conclude_pvf_checking::<Test>(&validation_code, VALIDATORS, START_SESSION_INDEX);
+
+			// The para has no head data or code until onboarded.
+			assert_noop!(
+				Registrar::poke_deposit(para_origin(para_id), para_id),
+				Error::<Test>::NotOnboarded
+			);
+			run_to_session(START_SESSION_INDEX + 2);
+			assert!(Parachains::is_parathread(para_id));
+
+			// A larger code requires a larger deposit.
+			assert_ok!(Parachains::force_set_current_code(
+				RuntimeOrigin::root(),
+				para_id,
+				test_validation_code(64),
+			));
+			assert_noop!(Registrar::poke_deposit(RuntimeOrigin::signed(2), para_id), BadOrigin);
+			assert_ok!(Registrar::poke_deposit(para_origin(para_id), para_id));
+			assert_eq!(
+				Balances::reserved_balance(&1),
+				<Test as Config>::ParaDeposit::get() + 32 + 64
+			);
+
+			// A smaller code returns the excess.
+			assert_ok!(Parachains::force_set_current_code(
+				RuntimeOrigin::root(),
+				para_id,
+				test_validation_code(16),
+			));
+			assert_ok!(Registrar::poke_deposit(RuntimeOrigin::signed(1), para_id));
+			assert_eq!(
+				Balances::reserved_balance(&1),
+				<Test as Config>::ParaDeposit::get() + 32 + 16
+			);
+		});
+	}
+
 	#[test]
 	fn swap_handles_bad_states() {
 		new_test_ext().execute_with(|| {
@@ -1602,6 +1791,26 @@ mod benchmarking {
 			let para_id = ParaId::from(1000);
 		}: _(RawOrigin::Root, para_id, new_head)
 
+		set_manager {
+			let para = register_para::<T>(LOWEST_PUBLIC_ID.into());
+			let caller: T::AccountId = whitelisted_caller();
+			let manager: T::AccountId = account("manager", 0, 0);
+			T::Currency::make_free_balance_be(&manager, BalanceOf::<T>::max_value());
+		}: _(RawOrigin::Signed(caller), para, manager.clone())
+		verify {
+			assert_last_event::<T>(Event::<T>::ManagerChanged { para_id: para, manager }.into());
+		}
+
+		poke_deposit {
+			let para = register_para::<T>(LOWEST_PUBLIC_ID.into());
+			next_scheduled_session::<T>();
+			let caller: T::AccountId = whitelisted_caller();
+		}: _(RawOrigin::Signed(caller.clone()), para)
+		verify {
+			let deposit = Paras::<T>::get(para).unwrap().deposit;
+			assert_last_event::<T>(Event::<T>::DepositPoked { para_id: para, manager: caller, deposit }.into());
+		}
+
 		impl_benchmark_test_suite!(
 			Registrar,
 			crate::integration_tests::new_test_ext(),
@@ -97,6 +97,23 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id)
 	}
 
+	#[benchmark]
+	fn place_order_with_credits(s: Linear<1, MAX_FILL_BENCH>) {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		Pallet::<T>::credit_account(caller.clone(), BalanceOf::<T>::max_value());
+		let order = EnqueuedOrder::new(para_id);
+
+		for _ in 0..s {
+			Pallet::<T>::add_on_demand_order(order.clone(), QueuePushDirection::Back).unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), BalanceOf::<T>::max_value(), para_id)
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(
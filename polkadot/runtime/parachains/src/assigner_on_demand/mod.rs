@@ -25,6 +25,10 @@
 //! a specific `ParaId`, orders for blockspace for that `ParaId` will only be assigned to
 //! that `CoreIndex`. This affinity mechanism can be removed if it can be shown that parallel
 //! execution is valid.
+//!
+//! Orders are either paid from the free balance of the ordering account, or from credits
+//! previously granted to that account, e.g. purchased on the coretime chain. The revenue of all
+//! orders is accounted per block until it gets claimed by the coretime chain.
 
 mod benchmarking;
 mod mock_helpers;
@@ -45,7 +49,7 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 use primitives::{CoreIndex, Id as ParaId};
 use sp_runtime::{
-	traits::{One, SaturatedConversion},
+	traits::{CheckedSub, One, SaturatedConversion, Zero},
 	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Saturating,
 };
 
@@ -58,6 +62,7 @@ pub use pallet::*;
 pub trait WeightInfo {
 	fn place_order_allow_death(s: u32) -> Weight;
 	fn place_order_keep_alive(s: u32) -> Weight;
+	fn place_order_with_credits(s: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -71,6 +76,10 @@ impl WeightInfo for TestWeightInfo {
 	fn place_order_keep_alive(_: u32) -> Weight {
 		Weight::MAX
 	}
+
+	fn place_order_with_credits(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 /// Keeps track of how many assignments a scheduler currently has at a specific `CoreIndex` for a
@@ -88,8 +97,17 @@ pub enum QueuePushDirection {
 	Front,
 }
 
+/// How an on demand order gets paid for.
+#[derive(Clone, Copy)]
+pub(super) enum PaymentType {
+	/// Withdraw the spot price from the free balance of the ordering account.
+	Balance(ExistenceRequirement),
+	/// Consume the spot price from the credits of the ordering account.
+	Credits,
+}
+
 /// Shorthand for the Balance type the runtime is using.
-type BalanceOf<T> =
+pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 /// Errors that can happen during spot traffic calculation.
@@ -139,6 +157,11 @@ pub mod pallet {
 		/// The default value for the spot traffic multiplier.
 		#[pallet::constant]
 		type TrafficDefaultValue: Get<FixedU128>;
+
+		/// The maximum number of blocks for which unclaimed revenue is kept separately. Revenue
+		/// of older blocks is accumulated into the oldest kept entry.
+		#[pallet::constant]
+		type MaxHistoricalRevenue: Get<u32>;
 	}
 
 	/// Creates an empty spot traffic value if one isn't present in storage already.
@@ -172,6 +195,22 @@ pub mod pallet {
 	pub(super) type ParaIdAffinity<T: Config> =
 		StorageMap<_, Twox256, ParaId, CoreAffinityCount, OptionQuery>;
 
+	/// Revenue of on demand orders per block, which has not been claimed by the coretime chain
+	/// yet. Only the last `MaxHistoricalRevenue` blocks have an entry, the revenue of older
+	/// blocks is accumulated into the oldest kept block.
+	#[pallet::storage]
+	pub(crate) type Revenue<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, BalanceOf<T>, ValueQuery>;
+
+	/// The first block whose revenue has not been claimed yet.
+	#[pallet::storage]
+	pub(crate) type RevenueClaimedUntil<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Credits of accounts, which can be consumed to place on demand orders.
+	#[pallet::storage]
+	pub(super) type Credits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -179,6 +218,8 @@ pub mod pallet {
 		OnDemandOrderPlaced { para_id: ParaId, spot_price: BalanceOf<T> },
 		/// The value of the spot traffic multiplier changed.
 		SpotTrafficSet { traffic: FixedU128 },
+		/// An account was credited for placing on demand orders.
+		AccountCredited { who: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -191,11 +232,24 @@ pub mod pallet {
 		/// The current spot price is higher than the max amount specified in the `place_order`
 		/// call, making it invalid.
 		SpotPriceHigherThanMaxAmount,
+		/// The account does not have enough credits to pay for the order.
+		InsufficientCredits,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// Revenue which was not claimed in time is kept in the oldest entry.
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			if let Some(dropped) = now.checked_sub(&T::MaxHistoricalRevenue::get().into()) {
+				let overdue = Revenue::<T>::take(dropped);
+				if !overdue.is_zero() {
+					let oldest = dropped.saturating_add(One::one());
+					Revenue::<T>::mutate(oldest, |r| *r = r.saturating_add(overdue));
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+				}
+			}
+
 			let config = <configuration::Pallet<T>>::config();
 			// Calculate spot price multiplier and store it.
 			let old_traffic = SpotTraffic::<T>::get();
@@ -213,7 +267,7 @@ pub mod pallet {
 						Pallet::<T>::deposit_event(Event::<T>::SpotTrafficSet {
 							traffic: new_traffic,
 						});
-						return T::DbWeight::get().reads_writes(2, 1)
+						return weight.saturating_add(T::DbWeight::get().reads_writes(2, 1))
 					}
 				},
 				Err(SpotTrafficCalculationErr::QueueCapacityIsZero) => {
@@ -235,7 +289,7 @@ pub mod pallet {
 					);
 				},
 			};
-			T::DbWeight::get().reads_writes(2, 0)
+			weight.saturating_add(T::DbWeight::get().reads(2))
 		}
 	}
 
@@ -265,7 +319,12 @@ pub mod pallet {
 			para_id: ParaId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Pallet::<T>::do_place_order(sender, max_amount, para_id, AllowDeath)
+			Pallet::<T>::do_place_order(
+				sender,
+				max_amount,
+				para_id,
+				PaymentType::Balance(AllowDeath),
+			)
 		}
 
 		/// Same as the [`place_order_allow_death`](Self::place_order_allow_death) call , but with a
@@ -292,7 +351,39 @@ pub mod pallet {
 			para_id: ParaId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Pallet::<T>::do_place_order(sender, max_amount, para_id, KeepAlive)
+			Pallet::<T>::do_place_order(
+				sender,
+				max_amount,
+				para_id,
+				PaymentType::Balance(KeepAlive),
+			)
+		}
+
+		/// Same as the [`place_order_allow_death`](Self::place_order_allow_death) call, but the
+		/// spot price is paid from the credits of the origin instead of its free balance.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, credits will be consumed from this account.
+		/// - `max_amount`: The maximum amount of credits to consume to place an order.
+		/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
+		///
+		/// Errors:
+		/// - `InsufficientCredits`
+		/// - `InvalidParaId`
+		/// - `QueueFull`
+		/// - `SpotPriceHigherThanMaxAmount`
+		///
+		/// Events:
+		/// - `SpotOrderPlaced`
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order_with_credits(OnDemandQueue::<T>::get().len() as u32))]
+		pub fn place_order_with_credits(
+			origin: OriginFor<T>,
+			max_amount: BalanceOf<T>,
+			para_id: ParaId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Pallet::<T>::do_place_order(sender, max_amount, para_id, PaymentType::Credits)
 		}
 	}
 }
@@ -308,10 +399,12 @@ where
 	/// - `sender`: The sender of the call, funds will be withdrawn from this account.
 	/// - `max_amount`: The maximum balance to withdraw from the origin to place an order.
 	/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
-	/// - `existence_requirement`: Whether or not to ensure that the account will not be reaped.
+	/// - `payment_type`: Whether to pay from the credits or the free balance of the account, in the
+	///   latter case also whether or not to ensure that the account will not be reaped.
 	///
 	/// Errors:
 	/// - `InsufficientBalance`: from the Currency implementation
+	/// - `InsufficientCredits`
 	/// - `InvalidParaId`
 	/// - `QueueFull`
 	/// - `SpotPriceHigherThanMaxAmount`
@@ -322,7 +415,7 @@ where
 		sender: <T as frame_system::Config>::AccountId,
 		max_amount: BalanceOf<T>,
 		para_id: ParaId,
-		existence_requirement: ExistenceRequirement,
+		payment_type: PaymentType,
 	) -> DispatchResult {
 		let config = <configuration::Pallet<T>>::config();
 
@@ -337,24 +430,45 @@ where
 		ensure!(spot_price.le(&max_amount), Error::<T>::SpotPriceHigherThanMaxAmount);
 
 		// Charge the sending account the spot price
-		let _ = T::Currency::withdraw(
-			&sender,
-			spot_price,
-			WithdrawReasons::FEE,
-			existence_requirement,
-		)?;
+		match payment_type {
+			PaymentType::Balance(existence_requirement) => {
+				let _ = T::Currency::withdraw(
+					&sender,
+					spot_price,
+					WithdrawReasons::FEE,
+					existence_requirement,
+				)?;
+			},
+			PaymentType::Credits => {
+				let credits = Credits::<T>::get(&sender);
+				let remaining =
+					credits.checked_sub(&spot_price).ok_or(Error::<T>::InsufficientCredits)?;
+				if remaining.is_zero() {
+					Credits::<T>::remove(&sender);
+				} else {
+					Credits::<T>::insert(&sender, remaining);
+				}
+			},
+		}
 
 		let order = EnqueuedOrder::new(para_id);
 
 		let res = Pallet::<T>::add_on_demand_order(order, QueuePushDirection::Back);
 
 		if res.is_ok() {
+			Pallet::<T>::add_revenue(spot_price);
 			Pallet::<T>::deposit_event(Event::<T>::OnDemandOrderPlaced { para_id, spot_price });
 		}
 
 		res
 	}
 
+	/// Account `amount` as revenue of the current block.
+	fn add_revenue(amount: BalanceOf<T>) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Revenue::<T>::mutate(now, |revenue| *revenue = revenue.saturating_add(amount));
+	}
+
 	/// The spot price multiplier. This is based on the transaction fee calculations defined in:
 	/// https://research.web3.foundation/Polkadot/overview/token-economics#setting-transaction-fees
 	///
@@ -576,6 +690,42 @@ impl<T: Config> Pallet<T> {
 		popped.map(|p| Assignment::Pool { para_id: p.para_id, core_index: core_idx })
 	}
 
+	/// Add credits to an account, which it can consume to place on demand orders.
+	///
+	/// Parameters:
+	/// - `who`: The account to credit.
+	/// - `amount`: The amount of credits to add.
+	///
+	/// Events:
+	/// - `AccountCredited`
+	pub fn credit_account(who: T::AccountId, amount: BalanceOf<T>) {
+		Credits::<T>::mutate(&who, |credits| *credits = credits.saturating_add(amount));
+		Pallet::<T>::deposit_event(Event::<T>::AccountCredited { who, amount });
+	}
+
+	/// Claim the revenue of all blocks before `when`, removing it from the revenue accounting.
+	///
+	/// Parameters:
+	/// - `when`: The first block whose revenue is not claimed.
+	///
+	/// Returns:
+	/// - The accumulated revenue of all claimed blocks.
+	pub fn claim_revenue_until(when: BlockNumberFor<T>) -> BalanceOf<T> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let oldest_kept =
+			now.saturating_sub(T::MaxHistoricalRevenue::get().saturating_sub(1).into());
+		// The current block may have received revenue after it was claimed.
+		let mut block = RevenueClaimedUntil::<T>::get().min(now).max(oldest_kept);
+		let until = when.min(now.saturating_add(One::one()));
+		let mut amount: BalanceOf<T> = Zero::zero();
+		while block < until {
+			amount = amount.saturating_add(Revenue::<T>::take(block));
+			block = block.saturating_add(One::one());
+		}
+		RevenueClaimedUntil::<T>::mutate(|claimed| *claimed = (*claimed).max(until));
+		amount
+	}
+
 	/// Report that the `para_id` & `core_index` combination was processed.
 	pub fn report_processed(para_id: ParaId, core_index: CoreIndex) {
 		Pallet::<T>::decrease_affinity(para_id, core_index)
@@ -18,10 +18,11 @@ use super::*;
 
 use crate::{
 	assigner_on_demand::{mock_helpers::GenesisConfigBuilder, Error},
+	coretime::Error as CoretimeError,
 	initializer::SessionChangeNotification,
 	mock::{
-		new_test_ext, Balances, OnDemandAssigner, Paras, ParasShared, RuntimeOrigin, Scheduler,
-		System, Test,
+		new_test_ext, Balances, Coretime, MaxHistoricalRevenue, OnDemandAssigner, Paras,
+		ParasShared, RuntimeOrigin, Scheduler, System, Test,
 	},
 	paras::{ParaGenesisArgs, ParaKind},
 };
@@ -278,6 +279,147 @@ fn place_order_keep_alive_keeps_alive() {
 	});
 }
 
+#[test]
+fn place_order_with_credits_works() {
+	let alice = 1u64;
+	let max_amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		// Initialize the parathread and wait for it to be ready.
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		assert!(Paras::is_parathread(para_id));
+
+		// Does not work without credits, even with a sufficient balance.
+		Balances::make_free_balance_be(&alice, max_amt);
+		assert_noop!(
+			OnDemandAssigner::place_order_with_credits(
+				RuntimeOrigin::signed(alice),
+				max_amt,
+				para_id
+			),
+			Error::<Test>::InsufficientCredits
+		);
+
+		// The spot price is the base fee of 10_000.
+		OnDemandAssigner::credit_account(alice, 15_000);
+		assert_eq!(Credits::<Test>::get(alice), 15_000);
+
+		// Does not work with max_amount lower than fee
+		assert_noop!(
+			OnDemandAssigner::place_order_with_credits(RuntimeOrigin::signed(alice), 1, para_id),
+			Error::<Test>::SpotPriceHigherThanMaxAmount
+		);
+
+		assert_ok!(OnDemandAssigner::place_order_with_credits(
+			RuntimeOrigin::signed(alice),
+			max_amt,
+			para_id
+		));
+		assert_eq!(Credits::<Test>::get(alice), 5_000);
+		// The free balance is untouched.
+		assert_eq!(Balances::free_balance(alice), max_amt);
+
+		assert_noop!(
+			OnDemandAssigner::place_order_with_credits(
+				RuntimeOrigin::signed(alice),
+				max_amt,
+				para_id
+			),
+			Error::<Test>::InsufficientCredits
+		);
+
+		// Consuming all credits removes the entry.
+		OnDemandAssigner::credit_account(alice, 5_000);
+		assert_ok!(OnDemandAssigner::place_order_with_credits(
+			RuntimeOrigin::signed(alice),
+			max_amt,
+			para_id
+		));
+		assert!(!Credits::<Test>::contains_key(alice));
+		assert_eq!(OnDemandAssigner::queue_size(), 2);
+	});
+}
+
+#[test]
+fn revenue_is_accounted_and_claimed() {
+	let alice = 1u64;
+	let max_amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		// Initialize the parathread and wait for it to be ready.
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		assert!(Paras::is_parathread(para_id));
+		Balances::make_free_balance_be(&alice, max_amt);
+
+		let place_order = || {
+			assert_ok!(OnDemandAssigner::place_order_allow_death(
+				RuntimeOrigin::signed(alice),
+				max_amt,
+				para_id
+			));
+		};
+		let next_block = || {
+			let b = System::block_number() + 1;
+			System::set_block_number(b);
+			OnDemandAssigner::on_initialize(b);
+		};
+
+		OnDemandAssigner::on_initialize(100);
+		place_order();
+		next_block();
+		place_order();
+		place_order();
+		assert_eq!(Revenue::<Test>::get(100), 10_000);
+		assert_eq!(Revenue::<Test>::get(101), 20_000);
+
+		// Claiming revenue of blocks before 101 only returns the revenue of block 100.
+		assert_eq!(OnDemandAssigner::claim_revenue_until(101), 10_000);
+		assert!(!Revenue::<Test>::contains_key(100));
+		assert_eq!(Revenue::<Test>::get(101), 20_000);
+		assert_eq!(OnDemandAssigner::claim_revenue_until(101), 0);
+
+		// The revenue of the current block can be claimed as well.
+		assert_eq!(OnDemandAssigner::claim_revenue_until(102), 20_000);
+		assert_eq!(Revenue::<Test>::iter().count(), 0);
+
+		// Revenue of the current block received after claiming it is not lost.
+		place_order();
+		assert_eq!(OnDemandAssigner::claim_revenue_until(102), 10_000);
+
+		// Revenue is kept for `MaxHistoricalRevenue` blocks, older revenue is accumulated.
+		next_block();
+		place_order();
+		for _ in 0..MaxHistoricalRevenue::get() {
+			next_block();
+			place_order();
+		}
+		assert_eq!(System::block_number(), 112);
+		assert_eq!(Revenue::<Test>::iter().count(), MaxHistoricalRevenue::get() as usize);
+		// Block 103 is the oldest kept block, holding the revenue of block 102 as well.
+		assert_eq!(OnDemandAssigner::claim_revenue_until(104), 20_000);
+		assert_eq!(OnDemandAssigner::claim_revenue_until(113), 9 * 10_000);
+	});
+}
+
+#[test]
+fn future_revenue_cannot_be_requested() {
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		System::set_block_number(100);
+		Revenue::<Test>::insert(100, 10_000);
+
+		assert_noop!(
+			Coretime::request_revenue_info_at(RuntimeOrigin::root(), 102),
+			CoretimeError::<Test>::RequestedFutureRevenue
+		);
+		assert_ok!(Coretime::request_revenue_info_at(RuntimeOrigin::root(), 101));
+		assert!(!Revenue::<Test>::contains_key(100));
+	});
+}
+
 #[test]
 fn add_on_demand_order_works() {
 	let para_a = ParaId::from(111);
@@ -132,6 +132,13 @@ pub struct HostConfiguration<BlockNumber> {
 	pub validation_upgrade_delay: BlockNumber,
 	/// Asynchronous backing parameters.
 	pub async_backing_params: AsyncBackingParams,
+	/// The maximum size of an upward message that can be sent in fragments, which the relay chain
+	/// reassembles.
+	///
+	/// Messages larger than [`max_upward_message_size`](Self::max_upward_message_size) must be
+	/// split into fragments and can't exceed this size. It can't be set above the maximal length
+	/// of a message in the upward message queue, see [`Config::MaxUmpMessageLen`].
+	pub max_upward_reassembled_message_size: u32,
 
 	/**
 	 * The parameters that are not essential, but still may be of interest for parachains.
@@ -299,6 +306,7 @@ impl<BlockNumber: Default + From<u32>> Default for HostConfiguration<BlockNumber
 			max_upward_queue_size: Default::default(),
 			max_downward_message_size: Default::default(),
 			max_upward_message_size: Default::default(),
+			max_upward_reassembled_message_size: Default::default(),
 			max_upward_message_num_per_candidate: Default::default(),
 			hrmp_sender_deposit: Default::default(),
 			hrmp_recipient_deposit: Default::default(),
@@ -520,7 +528,8 @@ pub mod pallet {
 	/// v8-v9:  <https://github.com/paritytech/polkadot/pull/7577>
 	/// v9-v10: <https://github.com/paritytech/polkadot-sdk/pull/2177>
 	/// v10-11: <https://github.com/paritytech/polkadot-sdk/pull/1191>
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(11);
+	/// v11-12: adds `max_upward_reassembled_message_size`
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -531,6 +540,13 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + shared::Config {
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The maximal length of a message in the upward message queue.
+		///
+		/// Bounds `max_upward_reassembled_message_size`, so that the relay chain can enqueue all
+		/// the messages it reassembles.
+		#[pallet::constant]
+		type MaxUmpMessageLen: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -572,6 +588,10 @@ pub mod pallet {
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			self.config.panic_if_not_consistent();
+			assert!(
+				self.config.max_upward_reassembled_message_size <= T::MaxUmpMessageLen::get(),
+				"`max_upward_reassembled_message_size` exceeds the maximal length of a UMP message",
+			);
 			ActiveConfig::<T>::put(&self.config);
 		}
 	}
@@ -1244,6 +1264,24 @@ pub mod pallet {
 				config.approval_voting_params = new;
 			})
 		}
+
+		/// Sets the maximum size of an upward message that can be sent in fragments.
+		#[pallet::call_index(55)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_u32(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_max_upward_reassembled_message_size(
+			origin: OriginFor<T>,
+			new: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(new <= T::MaxUmpMessageLen::get(), Error::<T>::InvalidNewValue);
+
+			Self::schedule_config_update(|config| {
+				config.max_upward_reassembled_message_size = new;
+			})
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
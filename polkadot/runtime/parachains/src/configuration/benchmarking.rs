@@ -45,6 +45,7 @@ benchmarks! {
 		ExecutorParam::PvfPrepTimeout(PvfPrepKind::Prepare, 360_000),
 		ExecutorParam::PvfExecTimeout(PvfExecKind::Backing, 2_000),
 		ExecutorParam::PvfExecTimeout(PvfExecKind::Approval, 12_000),
+		ExecutorParam::PvfExecFuelLimit(1_000_000_000),
 	][..]))
 
 	set_config_with_perbill {}: set_on_demand_fee_variability(RawOrigin::Root, Perbill::from_percent(100))
@@ -301,6 +301,7 @@ fn setting_pending_config_members() {
 			max_upward_queue_size: 228,
 			max_downward_message_size: 2048,
 			max_upward_message_size: 448,
+			max_upward_reassembled_message_size: 16 * 1024,
 			max_upward_message_num_per_candidate: 5,
 			hrmp_sender_deposit: 22,
 			hrmp_recipient_deposit: 4905,
@@ -424,6 +425,18 @@ fn setting_pending_config_members() {
 			new_config.max_upward_message_size,
 		)
 		.unwrap();
+		Configuration::set_max_upward_reassembled_message_size(
+			RuntimeOrigin::root(),
+			new_config.max_upward_reassembled_message_size,
+		)
+		.unwrap();
+		assert_noop!(
+			Configuration::set_max_upward_reassembled_message_size(
+				RuntimeOrigin::root(),
+				<Test as Config>::MaxUmpMessageLen::get() + 1,
+			),
+			Error::<Test>::InvalidNewValue
+		);
 		Configuration::set_max_upward_message_num_per_candidate(
 			RuntimeOrigin::root(),
 			new_config.max_upward_message_num_per_candidate,
@@ -505,6 +518,7 @@ fn verify_externally_accessible() {
 		let mut ground_truth = HostConfiguration::default();
 		ground_truth.async_backing_params =
 			AsyncBackingParams { allowed_ancestry_len: 111, max_candidate_depth: 222 };
+		ground_truth.max_upward_reassembled_message_size = 333;
 
 		// Make sure that the configuration is stored in the storage.
 		ActiveConfig::<Test>::put(ground_truth.clone());
@@ -529,6 +543,8 @@ fn verify_externally_accessible() {
 				validation_upgrade_cooldown: ground_truth.validation_upgrade_cooldown,
 				validation_upgrade_delay: ground_truth.validation_upgrade_delay,
 				async_backing_params: ground_truth.async_backing_params,
+				max_upward_reassembled_message_size: ground_truth
+					.max_upward_reassembled_message_size,
 			},
 		);
 	});
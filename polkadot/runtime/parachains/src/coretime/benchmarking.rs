@@ -41,6 +41,42 @@ mod benchmarks {
 		)
 	}
 
+	#[benchmark]
+	fn request_revenue_info_at() {
+		// Setup
+		let root_origin = <T as frame_system::Config>::RuntimeOrigin::root();
+		// Worst case: revenue of all kept blocks is claimed.
+		let mhr = <T as assigner_on_demand::Config>::MaxHistoricalRevenue::get();
+		let now = mhr + 5;
+		frame_system::Pallet::<T>::set_block_number(now.into());
+		for block in (now + 1 - mhr)..=now {
+			assigner_on_demand::Revenue::<T>::insert(
+				BlockNumberFor::<T>::from(block),
+				BalanceOf::<T>::from(1_000u32),
+			);
+		}
+
+		#[extrinsic_call]
+		_(
+			root_origin as <T as frame_system::Config>::RuntimeOrigin,
+			BlockNumberFor::<T>::from(now + 1),
+		)
+	}
+
+	#[benchmark]
+	fn credit_account() {
+		// Setup
+		let root_origin = <T as frame_system::Config>::RuntimeOrigin::root();
+		let who: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(
+			root_origin as <T as frame_system::Config>::RuntimeOrigin,
+			who,
+			BalanceOf::<T>::from(1_000u32),
+		)
+	}
+
 	#[benchmark]
 	fn assign_core(s: Linear<1, 100>) {
 		// Setup
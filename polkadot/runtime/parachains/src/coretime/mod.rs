@@ -25,7 +25,7 @@ use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use pallet_broker::{CoreAssignment, CoreIndex as BrokerCoreIndex};
 use primitives::{CoreIndex, Id as ParaId};
-use sp_arithmetic::traits::SaturatedConversion;
+use sp_arithmetic::traits::{One, SaturatedConversion};
 use xcm::v3::{
 	send_xcm, Instruction, Junction, Junctions, MultiLocation, OriginKind, SendXcm, WeightLimit,
 	Xcm,
@@ -33,6 +33,7 @@ use xcm::v3::{
 
 use crate::{
 	assigner_coretime::{self, PartsOf57600},
+	assigner_on_demand::{self, BalanceOf},
 	initializer::{OnNewSession, SessionChangeNotification},
 	origin::{ensure_parachain, Origin},
 };
@@ -42,8 +43,8 @@ pub mod migration;
 
 pub trait WeightInfo {
 	fn request_core_count() -> Weight;
-	//fn request_revenue_info_at() -> Weight;
-	//fn credit_account() -> Weight;
+	fn request_revenue_info_at() -> Weight;
+	fn credit_account() -> Weight;
 	fn assign_core(s: u32) -> Weight;
 }
 
@@ -54,14 +55,12 @@ impl WeightInfo for TestWeightInfo {
 	fn request_core_count() -> Weight {
 		Weight::MAX
 	}
-	// TODO: Add real benchmarking functionality for each of these to
-	// benchmarking.rs, then uncomment here and in trait definition.
-	/*fn request_revenue_info_at() -> Weight {
+	fn request_revenue_info_at() -> Weight {
 		Weight::MAX
 	}
 	fn credit_account() -> Weight {
 		Weight::MAX
-	}*/
+	}
 	fn assign_core(_s: u32) -> Weight {
 		Weight::MAX
 	}
@@ -86,6 +85,9 @@ enum CoretimeCalls {
 	SetLease(pallet_broker::TaskId, pallet_broker::Timeslice),
 	#[codec(index = 19)]
 	NotifyCoreCount(u16),
+	/// The on demand revenue of all relay chain blocks before the given one.
+	#[codec(index = 20)]
+	NotifyRevenue(pallet_broker::OnDemandRevenueRecord<u32, u128>),
 }
 
 #[frame_support::pallet]
@@ -126,6 +128,8 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// The paraid making the call is not the coretime brokerage system parachain.
 		NotBroker,
+		/// The requested revenue includes blocks which have not been built yet.
+		RequestedFutureRevenue,
 	}
 
 	#[pallet::hooks]
@@ -142,30 +146,50 @@ pub mod pallet {
 			configuration::Pallet::<T>::set_coretime_cores_unchecked(u32::from(count))
 		}
 
-		//// TODO Impl me!
-		////#[pallet::weight(<T as Config>::WeightInfo::request_revenue_info_at())]
-		//#[pallet::call_index(2)]
-		//pub fn request_revenue_info_at(
-		//	origin: OriginFor<T>,
-		//	_when: BlockNumberFor<T>,
-		//) -> DispatchResult {
-		//	// Ignore requests not coming from the broker parachain or root.
-		//	Self::ensure_root_or_para(origin, <T as Config>::BrokerId::get().into())?;
-		//	Ok(())
-		//}
-
-		//// TODO Impl me!
-		////#[pallet::weight(<T as Config>::WeightInfo::credit_account())]
-		//#[pallet::call_index(3)]
-		//pub fn credit_account(
-		//	origin: OriginFor<T>,
-		//	_who: T::AccountId,
-		//	_amount: BalanceOf<T>,
-		//) -> DispatchResult {
-		//	// Ignore requests not coming from the broker parachain or root.
-		//	Self::ensure_root_or_para(origin, <T as Config>::BrokerId::get().into())?;
-		//	Ok(())
-		//}
+		/// Request the on demand revenue of all blocks before `when`, the revenue gets claimed
+		/// and reported back to the broker chain.
+		///
+		/// Parameters:
+		/// -`origin`: The `ExternalBrokerOrigin`, assumed to be the Broker system parachain.
+		/// -`when`: The first block whose revenue is not yet reported, at most the block after
+		/// the current one.
+		#[pallet::weight(<T as Config>::WeightInfo::request_revenue_info_at())]
+		#[pallet::call_index(2)]
+		pub fn request_revenue_info_at(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+		) -> DispatchResult {
+			// Ignore requests not coming from the broker parachain or root.
+			Self::ensure_root_or_para(origin, <T as Config>::BrokerId::get().into())?;
+			// The revenue of later blocks can't be claimed yet, so it could not be reported.
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(when <= now.saturating_add(One::one()), Error::<T>::RequestedFutureRevenue);
+
+			Self::notify_revenue(when);
+			Self::deposit_event(Event::<T>::RevenueInfoRequested { when });
+			Ok(())
+		}
+
+		/// Credit an account with funds purchased on the broker chain, which it can use to place
+		/// on demand orders.
+		///
+		/// Parameters:
+		/// -`origin`: The `ExternalBrokerOrigin`, assumed to be the Broker system parachain.
+		/// -`who`: The account to credit.
+		/// -`amount`: The amount of credits to add.
+		#[pallet::weight(<T as Config>::WeightInfo::credit_account())]
+		#[pallet::call_index(3)]
+		pub fn credit_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			// Ignore requests not coming from the broker parachain or root.
+			Self::ensure_root_or_para(origin, <T as Config>::BrokerId::get().into())?;
+
+			<assigner_on_demand::Pallet<T>>::credit_account(who, amount);
+			Ok(())
+		}
 
 		/// Receive instructions from the `ExternalBrokerOrigin`, detailing how a specific core is
 		/// to be used.
@@ -216,6 +240,33 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Claim the on demand revenue of all blocks before `when` and report it to the broker
+	/// chain.
+	fn notify_revenue(when: BlockNumberFor<T>) {
+		let revenue = <assigner_on_demand::Pallet<T>>::claim_revenue_until(when);
+		let message = Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			mk_coretime_call(crate::coretime::CoretimeCalls::NotifyRevenue(
+				pallet_broker::OnDemandRevenueRecord {
+					until: when.saturated_into(),
+					amount: revenue.saturated_into(),
+				},
+			)),
+		]);
+		if let Err(err) = send_xcm::<T::SendXcm>(
+			MultiLocation {
+				parents: 0,
+				interior: Junctions::X1(Junction::Parachain(T::BrokerId::get())),
+			},
+			message,
+		) {
+			log::error!("Sending `NotifyRevenue` to coretime chain failed: {:?}", err);
+		}
+	}
+
 	pub fn initializer_on_new_session(notification: &SessionChangeNotification<BlockNumberFor<T>>) {
 		let old_core_count = notification.prev_config.coretime_cores;
 		let new_core_count = notification.new_config.coretime_cores;
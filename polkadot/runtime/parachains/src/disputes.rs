@@ -53,6 +53,10 @@ pub mod migration;
 
 const LOG_TARGET: &str = "runtime::disputes";
 
+/// The maximum number of backers records removed per block, when pruning the backers recorded at
+/// backing time for old sessions.
+const MAX_BACKERS_ON_INCLUSION_PRUNED_PER_BLOCK: u32 = 512;
+
 /// Whether the dispute is local or remote.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum DisputeLocation {
@@ -100,6 +104,15 @@ pub trait SlashingHandler<BlockNumber> {
 		backers: impl IntoIterator<Item = ValidatorIndex>,
 	);
 
+	/// Punish the validators who backed a parablock which was later disputed
+	/// and concluded invalid, regardless of whether they participated in the
+	/// dispute. This is expected to be a major punishment.
+	fn punish_backers_of_invalid(
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		backers: impl IntoIterator<Item = ValidatorIndex>,
+	);
+
 	/// Called by the initializer to initialize the slashing pallet.
 	fn initializer_initialize(now: BlockNumber) -> Weight;
 
@@ -127,6 +140,13 @@ impl<BlockNumber> SlashingHandler<BlockNumber> for () {
 	) {
 	}
 
+	fn punish_backers_of_invalid(
+		_: SessionIndex,
+		_: CandidateHash,
+		_: impl IntoIterator<Item = ValidatorIndex>,
+	) {
+	}
+
 	fn initializer_initialize(_now: BlockNumber) -> Weight {
 		Weight::zero()
 	}
@@ -229,6 +249,14 @@ pub trait DisputesHandler<BlockNumber: Ord> {
 		included_in: BlockNumber,
 	);
 
+	/// Note the validators who backed the given candidate, as seen by the
+	/// inclusion pallet at backing time.
+	fn note_backed(
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		backers: BTreeSet<ValidatorIndex>,
+	);
+
 	/// Retrieve the included state of a given candidate in a particular session. If it
 	/// returns `Some`, then we have a local dispute for the given `candidate_hash`.
 	fn included_state(session: SessionIndex, candidate_hash: CandidateHash) -> Option<BlockNumber>;
@@ -278,6 +306,13 @@ impl<BlockNumber: Ord> DisputesHandler<BlockNumber> for () {
 	) {
 	}
 
+	fn note_backed(
+		_session: SessionIndex,
+		_candidate_hash: CandidateHash,
+		_backers: BTreeSet<ValidatorIndex>,
+	) {
+	}
+
 	fn included_state(
 		_session: SessionIndex,
 		_candidate_hash: CandidateHash,
@@ -328,6 +363,14 @@ where
 		pallet::Pallet::<T>::note_included(session, candidate_hash, included_in)
 	}
 
+	fn note_backed(
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		backers: BTreeSet<ValidatorIndex>,
+	) {
+		pallet::Pallet::<T>::note_backed(session, candidate_hash, backers)
+	}
+
 	fn included_state(
 		session: SessionIndex,
 		candidate_hash: CandidateHash,
@@ -415,6 +458,25 @@ pub mod pallet {
 		BTreeSet<ValidatorIndex>,
 	>;
 
+	/// The validators who backed a candidate, as recorded at backing time.
+	/// This storage is used for slashing backers of candidates concluded invalid.
+	#[pallet::storage]
+	pub(super) type BackersOnInclusion<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Blake2_128Concat,
+		CandidateHash,
+		BTreeSet<ValidatorIndex>,
+	>;
+
+	/// Sessions for which the backers recorded at backing time still need to be removed.
+	///
+	/// There is an entry in `BackersOnInclusion` for every backed candidate, so these are removed
+	/// over several blocks.
+	#[pallet::storage]
+	pub(super) type BackersOnInclusionToPrune<T> = StorageValue<_, Vec<SessionIndex>, ValueQuery>;
+
 	/// All included blocks on the chain, as well as the block number in this chain that
 	/// should be reverted back to if the candidate is disputed and determined to be invalid.
 	#[pallet::storage]
@@ -855,7 +917,7 @@ impl StatementSetFilter {
 impl<T: Config> Pallet<T> {
 	/// Called by the initializer to initialize the disputes module.
 	pub(crate) fn initializer_initialize(_now: BlockNumberFor<T>) -> Weight {
-		Weight::zero()
+		Self::prune_backers_on_inclusion()
 	}
 
 	/// Called by the initializer to finalize the disputes pallet.
@@ -886,6 +948,8 @@ impl<T: Config> Pallet<T> {
 				<Disputes<T>>::remove_prefix(to_prune, None);
 				#[allow(deprecated)]
 				<BackersOnDisputes<T>>::remove_prefix(to_prune, None);
+				// There is an entry for every backed candidate, so this is done lazily.
+				BackersOnInclusionToPrune::<T>::append(to_prune);
 
 				// This is larger, and will be extracted to the `shared` pallet for more proper
 				// pruning. TODO: https://github.com/paritytech/polkadot/issues/3469
@@ -1176,13 +1240,28 @@ impl<T: Config> Pallet<T> {
 				backers.clone(),
 			);
 
+			// Backers recorded at backing time are punished separately, once the dispute
+			// concludes against the candidate.
+			let backers_on_inclusion =
+				<BackersOnInclusion<T>>::get(&session, &candidate_hash).unwrap_or_default();
+
 			// an invalid candidate, according to 2/3. Punish those on the 'for' side.
 			T::SlashingHandler::punish_for_invalid(
 				session,
 				candidate_hash,
 				summary.slash_for,
-				backers,
+				backers.difference(&backers_on_inclusion).cloned().collect::<Vec<_>>(),
 			);
+
+			if summary.new_flags.contains(DisputeStateFlags::AGAINST_SUPERMAJORITY) &&
+				!backers_on_inclusion.is_empty()
+			{
+				T::SlashingHandler::punish_backers_of_invalid(
+					session,
+					candidate_hash,
+					backers_on_inclusion,
+				);
+			}
 		}
 
 		<Disputes<T>>::insert(&session, &candidate_hash, &summary.state);
@@ -1224,6 +1303,40 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	pub(crate) fn note_backed(
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		backers: BTreeSet<ValidatorIndex>,
+	) {
+		if backers.is_empty() {
+			return
+		}
+
+		<BackersOnInclusion<T>>::insert(&session, &candidate_hash, backers);
+	}
+
+	/// Remove a bounded number of the backers recorded at backing time for sessions which are
+	/// no longer disputable.
+	fn prune_backers_on_inclusion() -> Weight {
+		let mut to_prune = BackersOnInclusionToPrune::<T>::get();
+		let session = match to_prune.first() {
+			Some(session) => *session,
+			None => return T::DbWeight::get().reads(1),
+		};
+
+		let removed = <BackersOnInclusion<T>>::clear_prefix(
+			session,
+			MAX_BACKERS_ON_INCLUSION_PRUNED_PER_BLOCK,
+			None,
+		);
+		if removed.maybe_cursor.is_none() {
+			to_prune.remove(0);
+			BackersOnInclusionToPrune::<T>::put(to_prune);
+		}
+
+		T::DbWeight::get().reads_writes(1 + removed.loops as u64, 1 + removed.unique as u64)
+	}
+
 	pub(crate) fn included_state(
 		session: SessionIndex,
 		candidate_hash: CandidateHash,
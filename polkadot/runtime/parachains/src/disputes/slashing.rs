@@ -22,6 +22,14 @@
 //! that, we might want to kick out the validators from the active set.
 //! Currently, we limit slashing to the backing group for invalid disputes.
 //!
+//! The backers of a candidate are recorded by the `inclusion` pallet at
+//! backing time. Once a dispute concludes against such a candidate, all of its
+//! backers are reported for a separate `BackedInvalid` offence, regardless of
+//! whether they participated in the dispute. These offenders are also disabled.
+//! The `BackedInvalid` offence is reported as a [`BackingOffence`], whose kind
+//! differs from the one of the other dispute offences, so that the offenders of
+//! both aren't mixed up.
+//!
 //! The `offences` pallet from Substrate provides us with a way to do both.
 //! Currently, the interface expects us to provide staking information including
 //! nominator exposure in order to submit an offence.
@@ -76,6 +84,7 @@ const LOG_TARGET: &str = "runtime::parachains::slashing";
 // via `HostConfiguration` in the future.
 const SLASH_FOR_INVALID: Perbill = Perbill::from_percent(100);
 const SLASH_AGAINST_VALID: Perbill = Perbill::zero();
+const SLASH_BACKED_INVALID: Perbill = Perbill::from_percent(100);
 const DEFENSIVE_PROOF: &'static str = "disputes module should bail on old session";
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -140,6 +149,7 @@ where
 			// in the future we might change it based on number of disputes initiated:
 			// <https://github.com/paritytech/polkadot/issues/5946>
 			SlashingOffenceKind::AgainstValid => DisableStrategy::Never,
+			SlashingOffenceKind::BackedInvalid => DisableStrategy::Always,
 		}
 	}
 
@@ -148,6 +158,49 @@ where
 	}
 }
 
+/// A [`SlashingOffence`] of the `BackedInvalid` kind, filed against all the backers of a
+/// candidate concluded invalid.
+///
+/// The offences reported for the same kind and time slot are considered concurrent. Reporting
+/// the backers under their own kind keeps the validators who lost the dispute about the same
+/// candidate from being reported once again along with them.
+#[derive(TypeInfo)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq, Eq))]
+pub struct BackingOffence<KeyOwnerIdentification>(pub SlashingOffence<KeyOwnerIdentification>);
+
+impl<Offender> Offence<Offender> for BackingOffence<Offender>
+where
+	Offender: Clone,
+{
+	const ID: Kind = *b"disputes:backing";
+
+	type TimeSlot = DisputesTimeSlot;
+
+	fn offenders(&self) -> Vec<Offender> {
+		self.0.offenders()
+	}
+
+	fn session_index(&self) -> SessionIndex {
+		self.0.session_index()
+	}
+
+	fn validator_set_count(&self) -> ValidatorSetCount {
+		self.0.validator_set_count()
+	}
+
+	fn time_slot(&self) -> Self::TimeSlot {
+		self.0.time_slot()
+	}
+
+	fn disable_strategy(&self) -> DisableStrategy {
+		self.0.disable_strategy()
+	}
+
+	fn slash_fraction(&self, offenders: u32) -> Perbill {
+		self.0.slash_fraction(offenders)
+	}
+}
+
 impl<KeyOwnerIdentification> SlashingOffence<KeyOwnerIdentification> {
 	fn new(
 		session_index: SessionIndex,
@@ -160,6 +213,7 @@ impl<KeyOwnerIdentification> SlashingOffence<KeyOwnerIdentification> {
 		let slash_fraction = match kind {
 			SlashingOffenceKind::ForInvalid => SLASH_FOR_INVALID,
 			SlashingOffenceKind::AgainstValid => SLASH_AGAINST_VALID,
+			SlashingOffenceKind::BackedInvalid => SLASH_BACKED_INVALID,
 		};
 		Self { time_slot, validator_set_count, offenders, slash_fraction, kind }
 	}
@@ -218,7 +272,7 @@ where
 		backers: impl IntoIterator<Item = ValidatorIndex>,
 	) {
 		// sanity check for the current implementation
-		if kind == SlashingOffenceKind::AgainstValid {
+		if kind != SlashingOffenceKind::ForInvalid {
 			debug_assert!(false, "should only slash ForInvalid disputes");
 			return
 		}
@@ -228,6 +282,18 @@ where
 		}
 		let backers: BTreeSet<_> = backers.into_iter().collect();
 		let to_punish: Vec<ValidatorIndex> = losers.intersection(&backers).cloned().collect();
+
+		Self::punish_offenders(session_index, candidate_hash, kind, to_punish)
+	}
+
+	/// Report an offence for the given validators if they can be identified,
+	/// otherwise record an unapplied slash to be reported by the node.
+	fn punish_offenders(
+		session_index: SessionIndex,
+		candidate_hash: CandidateHash,
+		kind: SlashingOffenceKind,
+		to_punish: Vec<ValidatorIndex>,
+	) {
 		if to_punish.is_empty() {
 			return
 		}
@@ -267,7 +333,12 @@ where
 
 			old.keys.extend(unapplied.keys)
 		};
-		<UnappliedSlashes<T>>::mutate(session_index, candidate_hash, append);
+		match kind {
+			SlashingOffenceKind::BackedInvalid =>
+				<UnappliedBackerSlashes<T>>::mutate(session_index, candidate_hash, append),
+			SlashingOffenceKind::ForInvalid | SlashingOffenceKind::AgainstValid =>
+				<UnappliedSlashes<T>>::mutate(session_index, candidate_hash, append),
+		}
 	}
 }
 
@@ -295,6 +366,16 @@ where
 		// NOTE: changing that requires modifying `do_punish` implementation
 	}
 
+	fn punish_backers_of_invalid(
+		session_index: SessionIndex,
+		candidate_hash: CandidateHash,
+		backers: impl IntoIterator<Item = ValidatorIndex>,
+	) {
+		let kind = SlashingOffenceKind::BackedInvalid;
+		let to_punish = backers.into_iter().collect::<BTreeSet<_>>().into_iter().collect();
+		Self::punish_offenders(session_index, candidate_hash, kind, to_punish);
+	}
+
 	fn initializer_initialize(now: BlockNumberFor<T>) -> Weight {
 		Pallet::<T>::initializer_initialize(now)
 	}
@@ -317,16 +398,18 @@ pub trait HandleReports<T: Config> {
 	/// (in blocks, not eras).
 	type ReportLongevity: Get<u64>;
 
-	/// Report an offence.
+	/// Report an offence. `BackedInvalid` offences are reported as a
+	/// [`BackingOffence`].
 	fn report_offence(
 		offence: SlashingOffence<T::KeyOwnerIdentification>,
 	) -> Result<(), OffenceError>;
 
 	/// Returns true if the offenders at the given time slot has already been
-	/// reported.
+	/// reported for an offence of the given kind.
 	fn is_known_offence(
 		offenders: &[T::KeyOwnerIdentification],
 		time_slot: &DisputesTimeSlot,
+		kind: SlashingOffenceKind,
 	) -> bool;
 
 	/// Create and dispatch a slashing report extrinsic.
@@ -349,6 +432,7 @@ impl<T: Config> HandleReports<T> for () {
 	fn is_known_offence(
 		_offenders: &[T::KeyOwnerIdentification],
 		_time_slot: &DisputesTimeSlot,
+		_kind: SlashingOffenceKind,
 	) -> bool {
 		true
 	}
@@ -427,6 +511,20 @@ pub mod pallet {
 		PendingSlashes,
 	>;
 
+	/// Backers of candidates concluded invalid pending slashes.
+	///
+	/// Kept separately from `UnappliedSlashes`, as a candidate might have both
+	/// dispute participants and backers pending slashes.
+	#[pallet::storage]
+	pub(super) type UnappliedBackerSlashes<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Blake2_128Concat,
+		CandidateHash,
+		PendingSlashes,
+	>;
+
 	/// `ValidatorSetCount` per session.
 	#[pallet::storage]
 	pub(super) type ValidatorSetCounts<T> =
@@ -498,7 +596,20 @@ pub mod pallet {
 				Ok(())
 			};
 
-			<UnappliedSlashes<T>>::try_mutate_exists(&session_index, &candidate_hash, try_remove)?;
+			match dispute_proof.kind {
+				SlashingOffenceKind::BackedInvalid =>
+					<UnappliedBackerSlashes<T>>::try_mutate_exists(
+						&session_index,
+						&candidate_hash,
+						try_remove,
+					)?,
+				SlashingOffenceKind::ForInvalid | SlashingOffenceKind::AgainstValid =>
+					<UnappliedSlashes<T>>::try_mutate_exists(
+						&session_index,
+						&candidate_hash,
+						try_remove,
+					)?,
+			}
 
 			let offence = SlashingOffence::new(
 				session_index,
@@ -551,12 +662,17 @@ impl<T: Config> Pallet<T> {
 
 		let old_session = session_index - config.dispute_period - 1;
 		let _ = <UnappliedSlashes<T>>::clear_prefix(old_session, REMOVE_LIMIT, None);
+		let _ = <UnappliedBackerSlashes<T>>::clear_prefix(old_session, REMOVE_LIMIT, None);
 	}
 
 	pub(crate) fn unapplied_slashes() -> Vec<(SessionIndex, CandidateHash, PendingSlashes)> {
 		<UnappliedSlashes<T>>::iter().collect()
 	}
 
+	pub(crate) fn unapplied_backer_slashes() -> Vec<(SessionIndex, CandidateHash, PendingSlashes)> {
+		<UnappliedBackerSlashes<T>>::iter().collect()
+	}
+
 	pub(crate) fn submit_unsigned_slashing_report(
 		dispute_proof: DisputeProof,
 		key_ownership_proof: <T as Config>::KeyOwnerProof,
@@ -594,6 +710,7 @@ impl<T: Config> Pallet<T> {
 			let tag_prefix = match dispute_proof.kind {
 				SlashingOffenceKind::ForInvalid => "DisputeForInvalid",
 				SlashingOffenceKind::AgainstValid => "DisputeAgainstValid",
+				SlashingOffenceKind::BackedInvalid => "DisputeBackedInvalid",
 			};
 
 			ValidTransaction::with_tag_prefix(tag_prefix)
@@ -634,6 +751,7 @@ fn is_known_offence<T: Config>(
 	let is_known_offence = <T::HandleReports as HandleReports<T>>::is_known_offence(
 		&[offender],
 		&dispute_proof.time_slot,
+		dispute_proof.kind,
 	);
 
 	if is_known_offence {
@@ -661,10 +779,14 @@ impl<T, R, L> HandleReports<T> for SlashingReportHandler<T::KeyOwnerIdentificati
 where
 	T: Config + frame_system::offchain::SendTransactionTypes<Call<T>>,
 	R: ReportOffence<
-		T::AccountId,
-		T::KeyOwnerIdentification,
-		SlashingOffence<T::KeyOwnerIdentification>,
-	>,
+			T::AccountId,
+			T::KeyOwnerIdentification,
+			SlashingOffence<T::KeyOwnerIdentification>,
+		> + ReportOffence<
+			T::AccountId,
+			T::KeyOwnerIdentification,
+			BackingOffence<T::KeyOwnerIdentification>,
+		>,
 	L: Get<u64>,
 {
 	type ReportLongevity = L;
@@ -673,18 +795,40 @@ where
 		offence: SlashingOffence<T::KeyOwnerIdentification>,
 	) -> Result<(), OffenceError> {
 		let reporters = Vec::new();
-		R::report_offence(reporters, offence)
+		match offence.kind {
+			SlashingOffenceKind::BackedInvalid =>
+				<R as ReportOffence<
+					T::AccountId,
+					T::KeyOwnerIdentification,
+					BackingOffence<T::KeyOwnerIdentification>,
+				>>::report_offence(reporters, BackingOffence(offence)),
+			SlashingOffenceKind::ForInvalid | SlashingOffenceKind::AgainstValid =>
+				<R as ReportOffence<
+					T::AccountId,
+					T::KeyOwnerIdentification,
+					SlashingOffence<T::KeyOwnerIdentification>,
+				>>::report_offence(reporters, offence),
+		}
 	}
 
 	fn is_known_offence(
 		offenders: &[T::KeyOwnerIdentification],
 		time_slot: &DisputesTimeSlot,
+		kind: SlashingOffenceKind,
 	) -> bool {
-		<R as ReportOffence<
-			T::AccountId,
-			T::KeyOwnerIdentification,
-			SlashingOffence<T::KeyOwnerIdentification>,
-		>>::is_known_offence(offenders, time_slot)
+		match kind {
+			SlashingOffenceKind::BackedInvalid => <R as ReportOffence<
+				T::AccountId,
+				T::KeyOwnerIdentification,
+				BackingOffence<T::KeyOwnerIdentification>,
+			>>::is_known_offence(offenders, time_slot),
+			SlashingOffenceKind::ForInvalid | SlashingOffenceKind::AgainstValid =>
+				<R as ReportOffence<
+					T::AccountId,
+					T::KeyOwnerIdentification,
+					SlashingOffence<T::KeyOwnerIdentification>,
+				>>::is_known_offence(offenders, time_slot),
+		}
 	}
 
 	fn submit_unsigned_slashing_report(
@@ -20,8 +20,8 @@ use crate::{
 	disputes::DisputesHandler,
 	mock::{
 		new_test_ext, AccountId, AllPalletsWithSystem, Initializer, MockGenesisConfig, System,
-		Test, PUNISH_BACKERS_FOR, PUNISH_VALIDATORS_AGAINST, PUNISH_VALIDATORS_FOR,
-		REWARD_VALIDATORS,
+		Test, PUNISH_BACKERS_FOR, PUNISH_BACKERS_OF_INVALID, PUNISH_VALIDATORS_AGAINST,
+		PUNISH_VALIDATORS_FOR, REWARD_VALIDATORS,
 	},
 };
 use frame_support::{
@@ -411,6 +411,51 @@ fn test_initializer_on_new_session() {
 	});
 }
 
+// Test the backers recorded at backing time are pruned over several blocks
+#[test]
+fn test_backers_on_inclusion_are_pruned_lazily() {
+	let dispute_period = 3;
+
+	let mock_genesis_config = MockGenesisConfig {
+		configuration: crate::configuration::GenesisConfig {
+			config: HostConfiguration { dispute_period, ..Default::default() },
+		},
+		..Default::default()
+	};
+
+	new_test_ext(mock_genesis_config).execute_with(|| {
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let backers: BTreeSet<_> = [ValidatorIndex(0)].into_iter().collect();
+
+		for i in 0..=MAX_BACKERS_ON_INCLUSION_PRUNED_PER_BLOCK {
+			let candidate_hash = CandidateHash(sp_core::H256::from_low_u64_be(i as u64));
+			Pallet::<Test>::note_backed(0, candidate_hash, backers.clone());
+		}
+		let candidate_hash = CandidateHash(sp_core::H256::repeat_byte(1));
+		Pallet::<Test>::note_backed(7, candidate_hash, backers.clone());
+
+		// A limited number of entries is removed at once.
+		BackersOnInclusionToPrune::<Test>::put(vec![0]);
+		Pallet::<Test>::prune_backers_on_inclusion();
+		assert_eq!(BackersOnInclusion::<Test>::iter_prefix(0).count(), 1);
+		assert_eq!(BackersOnInclusionToPrune::<Test>::get(), vec![0]);
+
+		Pallet::<Test>::prune_backers_on_inclusion();
+		assert_eq!(BackersOnInclusion::<Test>::iter_prefix(0).count(), 0);
+		assert!(BackersOnInclusionToPrune::<Test>::get().is_empty());
+
+		// Old sessions are scheduled for pruning on session changes.
+		Pallet::<Test>::note_backed(1, candidate_hash, backers.clone());
+		run_to_block(10, |b| {
+			// a new session at each block
+			Some((true, b, vec![(&0, v0.public())], Some(vec![(&0, v0.public())])))
+		});
+
+		assert_eq!(BackersOnInclusion::<Test>::get(1, candidate_hash), None);
+		assert_eq!(BackersOnInclusion::<Test>::get(7, candidate_hash), Some(backers));
+	});
+}
+
 #[test]
 fn test_provide_data_duplicate_error() {
 	new_test_ext(Default::default()).execute_with(|| {
@@ -1394,6 +1439,163 @@ fn test_punish_post_conclusion() {
 	})
 }
 
+/// The backers of a candidate recorded at backing time are punished once the
+/// dispute concludes AGAINST, and are not punished again as dispute participants.
+#[test]
+fn test_punish_backers_of_invalid() {
+	new_test_ext(Default::default()).execute_with(|| {
+		// supermajority threshold is 5
+		let v0 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v1 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v2 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v3 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v4 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v5 = <ValidatorId as CryptoType>::Pair::generate().0;
+		let v6 = <ValidatorId as CryptoType>::Pair::generate().0;
+		// Mapping between key pair and `ValidatorIndex`
+		// v0 -> 0
+		// v1 -> 3
+		// v2 -> 6
+		// v3 -> 5
+		// v4 -> 1
+		// v5 -> 4
+		// v6 -> 2
+
+		run_to_block(6, |b| {
+			// a new session at each block
+			Some((
+				true,
+				b,
+				vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				],
+				Some(vec![
+					(&0, v0.public()),
+					(&1, v1.public()),
+					(&2, v2.public()),
+					(&3, v3.public()),
+					(&4, v4.public()),
+					(&5, v5.public()),
+					(&6, v6.public()),
+				]),
+			))
+		});
+
+		let candidate_hash = CandidateHash(sp_core::H256::repeat_byte(1));
+		let inclusion_parent = sp_core::H256::repeat_byte(0xff);
+		let session = 3;
+
+		// `ValidatorIndex(3)` backed the candidate, but its backing vote is not part of the
+		// dispute.
+		Pallet::<Test>::note_backed(
+			session,
+			candidate_hash,
+			[ValidatorIndex(0), ValidatorIndex(3)].into_iter().collect(),
+		);
+
+		let stmts = vec![DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(
+						inclusion_parent,
+					)),
+					ValidatorIndex(0),
+					v0.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+						&SigningContext { session_index: session, parent_hash: inclusion_parent },
+					)),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(1),
+					v4.sign(
+						&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+							.signing_payload(),
+					),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(2),
+					v6.sign(
+						&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+							.signing_payload(),
+					),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(6),
+					v2.sign(
+						&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+							.signing_payload(),
+					),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(4),
+					v5.sign(
+						&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+							.signing_payload(),
+					),
+				),
+				(
+					DisputeStatement::Invalid(InvalidDisputeStatementKind::Explicit),
+					ValidatorIndex(5),
+					v3.sign(
+						&ExplicitDisputeStatement { valid: false, candidate_hash, session }
+							.signing_payload(),
+					),
+				),
+				(
+					DisputeStatement::Valid(ValidDisputeStatementKind::ApprovalChecking),
+					ValidatorIndex(3),
+					v1.sign(&ApprovalVote(candidate_hash).signing_payload(session)),
+				),
+			],
+		}];
+
+		let stmts = filter_dispute_set(stmts);
+		assert_ok!(
+			Pallet::<Test>::process_checked_multi_dispute_data(&stmts),
+			vec![(session, candidate_hash)],
+		);
+
+		assert_eq!(
+			PUNISH_BACKERS_OF_INVALID.with(|r| r.borrow().clone()),
+			vec![(session, candidate_hash, vec![ValidatorIndex(0), ValidatorIndex(3)])],
+		);
+		assert_eq!(PUNISH_BACKERS_FOR.with(|r| r.borrow().clone()), vec![(session, vec![])]);
+
+		// someone reveals 3 backing vote, which doesn't punish the backers again.
+		let stmts = vec![DisputeStatementSet {
+			candidate_hash,
+			session,
+			statements: vec![(
+				DisputeStatement::Valid(ValidDisputeStatementKind::BackingValid(inclusion_parent)),
+				ValidatorIndex(3),
+				v1.sign(&CompactStatement::Valid(candidate_hash).signing_payload(
+					&SigningContext { session_index: session, parent_hash: inclusion_parent },
+				)),
+			)],
+		}];
+
+		let stmts = filter_dispute_set(stmts);
+		assert_ok!(Pallet::<Test>::process_checked_multi_dispute_data(&stmts), vec![]);
+
+		assert_eq!(
+			PUNISH_BACKERS_FOR.with(|r| r.borrow().clone()),
+			vec![(session, vec![]), (session, vec![])],
+		);
+		assert_eq!(PUNISH_BACKERS_OF_INVALID.with(|r| r.borrow().len()), 1);
+	})
+}
+
 #[test]
 fn test_revert_and_freeze() {
 	new_test_ext(Default::default()).execute_with(|| {
@@ -65,6 +65,10 @@ pub trait WeightInfo {
 	fn force_open_hrmp_channel(c: u32) -> Weight;
 	fn establish_system_channel() -> Weight;
 	fn poke_channel_deposits() -> Weight;
+	fn hrmp_init_update_channel() -> Weight;
+	fn hrmp_accept_update_channel() -> Weight;
+	fn hrmp_cancel_update_request(c: u32) -> Weight;
+	fn force_process_hrmp_update(c: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -104,6 +108,18 @@ impl WeightInfo for TestWeightInfo {
 	fn poke_channel_deposits() -> Weight {
 		Weight::MAX
 	}
+	fn hrmp_init_update_channel() -> Weight {
+		Weight::MAX
+	}
+	fn hrmp_accept_update_channel() -> Weight {
+		Weight::MAX
+	}
+	fn hrmp_cancel_update_request(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn force_process_hrmp_update(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 /// A description of a request to open an HRMP channel.
@@ -124,6 +140,25 @@ pub struct HrmpOpenChannelRequest {
 	pub max_total_size: u32,
 }
 
+/// A description of a request to update the parameters of an existing HRMP channel.
+#[derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct HrmpUpdateChannelRequest {
+	/// Indicates if this request was confirmed by the recipient.
+	pub confirmed: bool,
+	/// The deposit the sender will hold for the channel once the update is enacted.
+	pub sender_deposit: Balance,
+	/// The deposit the recipient will hold for the channel once the update is enacted. Only
+	/// meaningful if the request is confirmed.
+	pub recipient_deposit: Balance,
+	/// The proposed maximum message size that could be put into the channel.
+	pub max_message_size: u32,
+	/// The proposed maximum number of messages that can be pending in the channel at once.
+	pub max_capacity: u32,
+	/// The maximum total size of the messages that can be pending in the channel at once.
+	pub max_total_size: u32,
+}
+
 /// A metadata of an HRMP channel.
 #[derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(test, derive(Debug))]
@@ -306,6 +341,19 @@ pub mod pallet {
 		},
 		/// An HRMP channel's deposits were updated.
 		OpenChannelDepositsUpdated { sender: ParaId, recipient: ParaId },
+		/// Update of the parameters of an HRMP channel requested.
+		UpdateChannelRequested {
+			sender: ParaId,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		},
+		/// Update of the parameters of an HRMP channel accepted.
+		UpdateChannelAccepted { sender: ParaId, recipient: ParaId },
+		/// A request to update the parameters of an HRMP channel was canceled by either party.
+		UpdateChannelCanceled { by_parachain: ParaId, channel_id: HrmpChannelId },
+		/// The parameters of an HRMP channel were updated.
+		ChannelUpdated { channel_id: HrmpChannelId, max_capacity: u32, max_message_size: u32 },
 	}
 
 	#[pallet::error]
@@ -350,6 +398,20 @@ pub mod pallet {
 		WrongWitness,
 		/// The channel between these two chains cannot be authorized.
 		ChannelCreationNotAuthorized,
+		/// The channel to be updated doesn't exist.
+		UpdateHrmpChannelDoesntExist,
+		/// There is already a request to update the same channel.
+		UpdateHrmpChannelAlreadyRequested,
+		/// The channel to be updated is going to be closed.
+		UpdateHrmpChannelClosing,
+		/// The update request doesn't exist.
+		UpdateHrmpChannelRequestDoesntExist,
+		/// The update request is already confirmed.
+		UpdateHrmpChannelAlreadyConfirmed,
+		/// Canceling is requested by neither the sender nor recipient of the update request.
+		CancelHrmpUpdateChannelUnauthorized,
+		/// The channel has a pending update request, its deposits can't be poked.
+		UpdateHrmpChannelPending,
 	}
 
 	/// The set of pending HRMP open channel requests.
@@ -397,6 +459,22 @@ pub mod pallet {
 	pub type HrmpCloseChannelRequestsList<T: Config> =
 		StorageValue<_, Vec<HrmpChannelId>, ValueQuery>;
 
+	/// The set of pending requests to update the parameters of existing HRMP channels. Confirmed
+	/// requests are enacted during the session change.
+	///
+	/// The set is accompanied by a list for iteration.
+	///
+	/// Invariants:
+	/// - There are no channels that exists in list but not in the set and vice versa.
+	/// - Each request refers to a channel in `HrmpChannels`.
+	#[pallet::storage]
+	pub type HrmpUpdateChannelRequests<T: Config> =
+		StorageMap<_, Twox64Concat, HrmpChannelId, HrmpUpdateChannelRequest>;
+
+	#[pallet::storage]
+	pub type HrmpUpdateChannelRequestsList<T: Config> =
+		StorageValue<_, Vec<HrmpChannelId>, ValueQuery>;
+
 	/// The HRMP watermark associated with each para.
 	/// Invariant:
 	/// - each para `P` used here as a key should satisfy `Paras::is_valid_para(P)` within a
@@ -744,6 +822,9 @@ pub mod pallet {
 		/// Update the deposits held for an HRMP channel to the latest `Configuration`. Channels
 		/// with system chains do not require a deposit.
 		///
+		/// Fails for a channel with a pending update request, whose deposits are adjusted when the
+		/// request is enacted or cancelled.
+		///
 		/// Arguments:
 		///
 		/// - `sender`: A chain, `ParaId`.
@@ -759,6 +840,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			let _caller = ensure_signed(origin)?;
 			let channel_id = HrmpChannelId { sender, recipient };
+			ensure!(
+				!HrmpUpdateChannelRequests::<T>::contains_key(&channel_id),
+				Error::<T>::UpdateHrmpChannelPending,
+			);
 			let is_system = sender.is_system() || recipient.is_system();
 
 			let config = <configuration::Pallet<T>>::config();
@@ -836,6 +921,102 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Initiate updating the parameters of an existing channel from a parachain to the given
+		/// recipient, without closing the channel.
+		///
+		/// - `proposed_max_capacity` - specifies how many messages can be in the channel at once.
+		/// - `proposed_max_message_size` - specifies the maximum size of the messages.
+		///
+		/// These numbers are a subject to the relay-chain configuration limits. The deposits of
+		/// the channel are adjusted to the current configuration as part of the update.
+		///
+		/// The channel is updated only after the recipient confirms it and only on a session
+		/// change.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::hrmp_init_update_channel())]
+		pub fn hrmp_init_update_channel(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::init_update_channel(
+				origin,
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			)?;
+			Self::deposit_event(Event::UpdateChannelRequested {
+				sender: origin,
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			});
+			Ok(())
+		}
+
+		/// Accept a pending update request for the channel from the given sender.
+		///
+		/// The channel will be updated only on the next session boundary.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::hrmp_accept_update_channel())]
+		pub fn hrmp_accept_update_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::accept_update_channel(origin, sender)?;
+			Self::deposit_event(Event::UpdateChannelAccepted { sender, recipient: origin });
+			Ok(())
+		}
+
+		/// This cancels a pending channel update request. It can be canceled by either of the
+		/// sender or the recipient of the channel. The origin must be either of those.
+		///
+		/// The cancellation happens immediately. It is not possible to cancel the request if it is
+		/// already accepted.
+		///
+		/// Total number of update requests (i.e. `HrmpUpdateChannelRequestsList`) must be provided
+		/// as witness data.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::hrmp_cancel_update_request(*update_requests))]
+		pub fn hrmp_cancel_update_request(
+			origin: OriginFor<T>,
+			channel_id: HrmpChannelId,
+			update_requests: u32,
+		) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			ensure!(
+				HrmpUpdateChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32 <=
+					update_requests,
+				Error::<T>::WrongWitness
+			);
+			Self::cancel_update_request(origin, channel_id.clone())?;
+			Self::deposit_event(Event::UpdateChannelCanceled { by_parachain: origin, channel_id });
+			Ok(())
+		}
+
+		/// Force process HRMP update channel requests.
+		///
+		/// If there are pending HRMP update channel requests, you can use this function to process
+		/// all of those requests immediately.
+		///
+		/// Total number of channels to be updated must be provided as witness data.
+		///
+		/// Origin must be the `ChannelManager`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_process_hrmp_update(*channels))]
+		pub fn force_process_hrmp_update(origin: OriginFor<T>, channels: u32) -> DispatchResult {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			ensure!(
+				HrmpUpdateChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32 <=
+					channels,
+				Error::<T>::WrongWitness
+			);
+
+			Self::process_hrmp_update_channel_requests();
+			Ok(())
+		}
 	}
 }
 
@@ -879,10 +1060,14 @@ impl<T: Config> Pallet<T> {
 	) -> Weight {
 		let w1 = Self::perform_outgoing_para_cleanup(&notification.prev_config, outgoing_paras);
 		Self::process_hrmp_open_channel_requests(&notification.prev_config);
+		// Updates are enacted before closing, so that closing a channel returns the updated
+		// deposits.
+		let update_count = Self::process_hrmp_update_channel_requests();
 		Self::process_hrmp_close_channel_requests();
 		w1.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_open(
 			outgoing_paras.len() as u32
 		))
+		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_update(update_count))
 		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_close(
 			outgoing_paras.len() as u32
 		))
@@ -1058,6 +1243,95 @@ impl<T: Config> Pallet<T> {
 		HrmpOpenChannelRequestsList::<T>::put(open_req_channels);
 	}
 
+	/// Iterate over all update channel requests and enact the confirmed ones, adjusting the
+	/// deposits of the channel.
+	///
+	/// Returns the number of iterated requests.
+	fn process_hrmp_update_channel_requests() -> u32 {
+		let mut update_req_channels = HrmpUpdateChannelRequestsList::<T>::get();
+		let count = update_req_channels.len() as u32;
+
+		update_req_channels.retain(|channel_id| {
+			let request = match HrmpUpdateChannelRequests::<T>::get(channel_id) {
+				Some(request) => request,
+				// Can't normally happen but no need to panic.
+				None => return false,
+			};
+			if !request.confirmed {
+				return true
+			}
+
+			HrmpUpdateChannelRequests::<T>::remove(channel_id);
+			HrmpChannels::<T>::mutate(channel_id, |channel| {
+				if let Some(channel) = channel {
+					// Additional deposits were reserved when the request was made and accepted,
+					// only excess deposits need to be returned now.
+					T::Currency::unreserve(
+						&channel_id.sender.into_account_truncating(),
+						channel
+							.sender_deposit
+							.saturating_sub(request.sender_deposit)
+							.unique_saturated_into(),
+					);
+					T::Currency::unreserve(
+						&channel_id.recipient.into_account_truncating(),
+						channel
+							.recipient_deposit
+							.saturating_sub(request.recipient_deposit)
+							.unique_saturated_into(),
+					);
+
+					// NOTE: The new limits might be exceeded by the messages pending in the
+					// channel. In that case no new messages are accepted until it is drained
+					// below the new limits.
+					channel.sender_deposit = request.sender_deposit;
+					channel.recipient_deposit = request.recipient_deposit;
+					channel.max_capacity = request.max_capacity;
+					channel.max_message_size = request.max_message_size;
+					channel.max_total_size = request.max_total_size;
+
+					Self::deposit_event(Event::ChannelUpdated {
+						channel_id: channel_id.clone(),
+						max_capacity: request.max_capacity,
+						max_message_size: request.max_message_size,
+					});
+				}
+			});
+			false
+		});
+
+		HrmpUpdateChannelRequestsList::<T>::put(update_req_channels);
+		count
+	}
+
+	/// Remove a pending update request for the given channel, if any, returning the additional
+	/// deposits reserved for it.
+	fn remove_update_channel_request(channel_id: &HrmpChannelId, channel: &HrmpChannel) {
+		let Some(request) = HrmpUpdateChannelRequests::<T>::take(channel_id) else { return };
+		HrmpUpdateChannelRequestsList::<T>::mutate(|update_req_channels| {
+			if let Some(pos) = update_req_channels.iter().position(|x| x == channel_id) {
+				update_req_channels.swap_remove(pos);
+			}
+		});
+
+		T::Currency::unreserve(
+			&channel_id.sender.into_account_truncating(),
+			request
+				.sender_deposit
+				.saturating_sub(channel.sender_deposit)
+				.unique_saturated_into(),
+		);
+		if request.confirmed {
+			T::Currency::unreserve(
+				&channel_id.recipient.into_account_truncating(),
+				request
+					.recipient_deposit
+					.saturating_sub(channel.recipient_deposit)
+					.unique_saturated_into(),
+			);
+		}
+	}
+
 	/// Iterate over all close channel requests unconditionally closing the channels.
 	fn process_hrmp_close_channel_requests() {
 		let close_reqs = HrmpCloseChannelRequestsList::<T>::take();
@@ -1074,9 +1348,10 @@ impl<T: Config> Pallet<T> {
 	/// This function is idempotent, meaning that after the first application it should have no
 	/// effect (i.e. it won't return the deposits twice).
 	fn close_hrmp_channel(channel_id: &HrmpChannelId) {
-		if let Some(HrmpChannel { sender_deposit, recipient_deposit, .. }) =
-			HrmpChannels::<T>::take(channel_id)
-		{
+		if let Some(channel) = HrmpChannels::<T>::take(channel_id) {
+			Self::remove_update_channel_request(channel_id, &channel);
+
+			let HrmpChannel { sender_deposit, recipient_deposit, .. } = channel;
 			T::Currency::unreserve(
 				&channel_id.sender.into_account_truncating(),
 				sender_deposit.unique_saturated_into(),
@@ -1531,6 +1806,119 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Initiate updating the parameters of an existing channel from a parachain to a given
+	/// recipient. If neither chain is part of the system, then the deposits of the channel are
+	/// adjusted to the `Configuration`, where any additional deposit is required from `origin`
+	/// (the sender) upon requesting the update and from the `recipient` upon accepting it.
+	///
+	/// Basically the same as [`hrmp_init_update_channel`](Pallet::hrmp_init_update_channel) but
+	/// intended for calling directly from other pallets rather than dispatched.
+	pub fn init_update_channel(
+		origin: ParaId,
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	) -> DispatchResult {
+		let channel_id = HrmpChannelId { sender: origin, recipient };
+		let channel =
+			HrmpChannels::<T>::get(&channel_id).ok_or(Error::<T>::UpdateHrmpChannelDoesntExist)?;
+		ensure!(
+			!HrmpUpdateChannelRequests::<T>::contains_key(&channel_id),
+			Error::<T>::UpdateHrmpChannelAlreadyRequested,
+		);
+		ensure!(
+			!HrmpCloseChannelRequests::<T>::contains_key(&channel_id),
+			Error::<T>::UpdateHrmpChannelClosing,
+		);
+
+		let config = <configuration::Pallet<T>>::config();
+		ensure!(proposed_max_capacity > 0, Error::<T>::OpenHrmpChannelZeroCapacity);
+		ensure!(
+			proposed_max_capacity <= config.hrmp_channel_max_capacity,
+			Error::<T>::OpenHrmpChannelCapacityExceedsLimit,
+		);
+		ensure!(proposed_max_message_size > 0, Error::<T>::OpenHrmpChannelZeroMessageSize);
+		ensure!(
+			proposed_max_message_size <= config.hrmp_channel_max_message_size,
+			Error::<T>::OpenHrmpChannelMessageSizeExceedsLimit,
+		);
+
+		// Do not require deposits for channels with or amongst the system.
+		let is_system = origin.is_system() || recipient.is_system();
+		let deposit = if is_system { 0 } else { config.hrmp_sender_deposit };
+		let additional_deposit = deposit.saturating_sub(channel.sender_deposit);
+		if !additional_deposit.is_zero() {
+			T::Currency::reserve(
+				&origin.into_account_truncating(),
+				additional_deposit.unique_saturated_into(),
+			)?;
+		}
+
+		// mutating storage directly now -- shall not bail henceforth.
+
+		HrmpUpdateChannelRequests::<T>::insert(
+			&channel_id,
+			HrmpUpdateChannelRequest {
+				confirmed: false,
+				sender_deposit: deposit,
+				recipient_deposit: channel.recipient_deposit,
+				max_capacity: proposed_max_capacity,
+				max_message_size: proposed_max_message_size,
+				max_total_size: config.hrmp_channel_max_total_size,
+			},
+		);
+		HrmpUpdateChannelRequestsList::<T>::append(channel_id);
+
+		Ok(())
+	}
+
+	/// Accept a pending request to update the channel from the given sender.
+	///
+	/// Basically the same as [`hrmp_accept_update_channel`](Pallet::hrmp_accept_update_channel)
+	/// but intended for calling directly from other pallets rather than dispatched.
+	pub fn accept_update_channel(origin: ParaId, sender: ParaId) -> DispatchResult {
+		let channel_id = HrmpChannelId { sender, recipient: origin };
+		let mut update_req = HrmpUpdateChannelRequests::<T>::get(&channel_id)
+			.ok_or(Error::<T>::UpdateHrmpChannelRequestDoesntExist)?;
+		ensure!(!update_req.confirmed, Error::<T>::UpdateHrmpChannelAlreadyConfirmed);
+		let channel =
+			HrmpChannels::<T>::get(&channel_id).ok_or(Error::<T>::UpdateHrmpChannelDoesntExist)?;
+
+		// Do not require deposits for channels with or amongst the system.
+		let config = <configuration::Pallet<T>>::config();
+		let is_system = origin.is_system() || sender.is_system();
+		let deposit = if is_system { 0 } else { config.hrmp_recipient_deposit };
+		let additional_deposit = deposit.saturating_sub(channel.recipient_deposit);
+		if !additional_deposit.is_zero() {
+			T::Currency::reserve(
+				&origin.into_account_truncating(),
+				additional_deposit.unique_saturated_into(),
+			)?;
+		}
+
+		update_req.confirmed = true;
+		update_req.recipient_deposit = deposit;
+		HrmpUpdateChannelRequests::<T>::insert(&channel_id, update_req);
+
+		Ok(())
+	}
+
+	fn cancel_update_request(origin: ParaId, channel_id: HrmpChannelId) -> DispatchResult {
+		// check if the origin is allowed to cancel the request.
+		ensure!(channel_id.is_participant(origin), Error::<T>::CancelHrmpUpdateChannelUnauthorized);
+
+		let update_req = HrmpUpdateChannelRequests::<T>::get(&channel_id)
+			.ok_or(Error::<T>::UpdateHrmpChannelRequestDoesntExist)?;
+		ensure!(!update_req.confirmed, Error::<T>::UpdateHrmpChannelAlreadyConfirmed);
+
+		// The request can only exist for an open channel, see the invariant.
+		if let Some(channel) = HrmpChannels::<T>::get(&channel_id) {
+			Self::remove_update_channel_request(&channel_id, &channel);
+		}
+
+		Ok(())
+	}
+
 	fn cancel_open_request(origin: ParaId, channel_id: HrmpChannelId) -> DispatchResult {
 		// check if the origin is allowed to close the channel.
 		ensure!(channel_id.is_participant(origin), Error::<T>::CancelHrmpOpenChannelUnauthorized);
@@ -1734,6 +2122,15 @@ impl<T: Config> Pallet<T> {
 			HrmpCloseChannelRequestsList::<T>::get().into_iter().collect::<BTreeSet<_>>(),
 		);
 
+		assert_eq!(
+			HrmpUpdateChannelRequests::<T>::iter().map(|(k, _)| k).collect::<BTreeSet<_>>(),
+			HrmpUpdateChannelRequestsList::<T>::get().into_iter().collect::<BTreeSet<_>>(),
+		);
+		// Update requests can only exist for open channels.
+		for (channel_id, _) in HrmpUpdateChannelRequests::<T>::iter() {
+			assert!(HrmpChannels::<T>::contains_key(&channel_id));
+		}
+
 		// A HRMP watermark can be None for an onboarded parachain. However, an offboarded parachain
 		// cannot have an HRMP watermark: it should've been cleanup.
 		assert_contains_only_onboarded(
@@ -124,6 +124,33 @@ where
 	output
 }
 
+/// Establish a channel between two parachains and request an update of its parameters, which
+/// is accepted by the recipient if `accept` is set.
+fn establish_para_connection_with_update<T: Config>(
+	from: u32,
+	to: u32,
+	accept: bool,
+) -> [(ParaId, crate::Origin); 2]
+where
+	<T as frame_system::Config>::RuntimeOrigin: From<crate::Origin>,
+{
+	let output = establish_para_connection::<T>(from, to, ParachainSetupStep::Established);
+	let [(sender, sender_origin), (recipient, recipient_origin)] = output.clone();
+	let config = Configuration::<T>::config();
+
+	assert_ok!(Hrmp::<T>::hrmp_init_update_channel(
+		sender_origin.into(),
+		recipient,
+		config.hrmp_channel_max_capacity,
+		config.hrmp_channel_max_message_size,
+	));
+	if accept {
+		assert_ok!(Hrmp::<T>::hrmp_accept_update_channel(recipient_origin.into(), sender));
+	}
+
+	output
+}
+
 /// Prefix value for account generation. These numbers are used as seeds to create distinct (para)
 /// accounts.
 ///
@@ -513,6 +540,80 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn hrmp_init_update_channel() {
+		let [(sender, sender_origin), (recipient, _)] =
+			establish_para_connection::<T>(1, 2, ParachainSetupStep::Established);
+		let config = Configuration::<T>::config();
+		let capacity = config.hrmp_channel_max_capacity;
+		let message_size = config.hrmp_channel_max_message_size;
+
+		#[extrinsic_call]
+		_(sender_origin, recipient, capacity, message_size);
+
+		assert_last_event::<T>(
+			Event::<T>::UpdateChannelRequested {
+				sender,
+				recipient,
+				proposed_max_capacity: capacity,
+				proposed_max_message_size: message_size,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn hrmp_accept_update_channel() {
+		let [(sender, _), (recipient, recipient_origin)] =
+			establish_para_connection_with_update::<T>(1, 2, false);
+
+		#[extrinsic_call]
+		_(recipient_origin, sender);
+
+		assert_last_event::<T>(Event::<T>::UpdateChannelAccepted { sender, recipient }.into());
+	}
+
+	#[benchmark]
+	fn hrmp_cancel_update_request(
+		// number of items already existing in the `HrmpUpdateChannelRequestsList`, other than the
+		// one that we remove.
+		c: Linear<0, MAX_UNIQUE_CHANNELS>,
+	) {
+		for id in 0..c {
+			let _ = establish_para_connection_with_update::<T>(PREFIX_0 + id, PREFIX_1 + id, false);
+		}
+
+		let [(sender, sender_origin), (recipient, _)] =
+			establish_para_connection_with_update::<T>(1, 2, false);
+		assert_eq!(
+			HrmpUpdateChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32,
+			c + 1
+		);
+		let channel_id = HrmpChannelId { sender, recipient };
+
+		#[extrinsic_call]
+		_(sender_origin, channel_id, c + 1);
+
+		assert_eq!(HrmpUpdateChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32, c);
+	}
+
+	#[benchmark]
+	fn force_process_hrmp_update(
+		// number of channels that need to be processed. Worse case is an N-M relation: unique
+		// sender and recipients for all channels.
+		c: Linear<0, MAX_UNIQUE_CHANNELS>,
+	) {
+		for id in 0..c {
+			let _ = establish_para_connection_with_update::<T>(PREFIX_0 + id, PREFIX_1 + id, true);
+		}
+		assert_eq!(HrmpUpdateChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32, c);
+
+		#[extrinsic_call]
+		_(frame_system::Origin::<T>::Root, c);
+
+		assert_eq!(HrmpUpdateChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32, 0);
+	}
+
 	impl_benchmark_test_suite!(
 		Hrmp,
 		crate::mock::new_test_ext(crate::hrmp::tests::GenesisConfigBuilder::default().build()),
@@ -888,6 +888,236 @@ fn cancel_pending_open_channel_request() {
 	});
 }
 
+fn set_hrmp_deposits(sender_deposit: Balance, recipient_deposit: Balance) {
+	crate::configuration::ActiveConfig::<Test>::mutate(|config| {
+		config.hrmp_sender_deposit = sender_deposit;
+		config.hrmp_recipient_deposit = recipient_deposit;
+	});
+}
+
+#[test]
+fn update_channel_works() {
+	let para_a = 2032.into();
+	let para_a_origin: crate::Origin = 2032.into();
+	let para_b = 2064.into();
+	let para_b_origin: crate::Origin = 2064.into();
+	let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		// Register two parachains and open a channel between them.
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(8, Some(vec![8]));
+		assert!(channel_exists(para_a, para_b));
+
+		// Only existing channels can be updated.
+		assert_noop!(
+			Hrmp::init_update_channel(para_b, para_a, 1, 4),
+			Error::<Test>::UpdateHrmpChannelDoesntExist
+		);
+
+		// The sender deposit increased while the recipient deposit decreased since opening.
+		set_hrmp_deposits(30, 10);
+
+		Hrmp::hrmp_init_update_channel(para_a_origin.into(), para_b, 1, 4).unwrap();
+		Hrmp::assert_storage_consistency_exhaustive();
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::UpdateChannelRequested {
+				sender: para_a,
+				recipient: para_b,
+				proposed_max_capacity: 1,
+				proposed_max_message_size: 4
+			})));
+		// The additional deposit is reserved right away.
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 70);
+		assert_noop!(
+			Hrmp::init_update_channel(para_a, para_b, 2, 8),
+			Error::<Test>::UpdateHrmpChannelAlreadyRequested
+		);
+
+		Hrmp::hrmp_accept_update_channel(para_b_origin.into(), para_a).unwrap();
+		Hrmp::assert_storage_consistency_exhaustive();
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::UpdateChannelAccepted {
+				sender: para_a,
+				recipient: para_b
+			})));
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_b.into_account_truncating()), 95);
+
+		// Without a session change the channel is not updated.
+		run_to_block(9, None);
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.max_capacity, channel.max_message_size), (2, 8));
+
+		run_to_block(10, Some(vec![10]));
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.max_capacity, channel.max_message_size), (1, 4));
+		assert_eq!((channel.sender_deposit, channel.recipient_deposit), (30, 10));
+		assert!(HrmpUpdateChannelRequests::<Test>::get(&channel_id).is_none());
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// The excess deposit of the recipient is returned.
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 70);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_b.into_account_truncating()),
+			100
+		);
+	});
+}
+
+#[test]
+fn poke_deposits_with_pending_update() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+	let free_balance =
+		|para: ParaId| <Test as Config>::Currency::free_balance(&para.into_account_truncating());
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		// Register two parachains and open a channel between them.
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(8, Some(vec![8]));
+
+		// Poke, then cancel: the deposits can't be poked while the update is pending, and
+		// canceling it returns exactly the additional deposit.
+		set_hrmp_deposits(30, 15);
+		Hrmp::init_update_channel(para_a, para_b, 1, 4).unwrap();
+		assert_eq!(free_balance(para_a), 70);
+		assert_noop!(
+			Hrmp::poke_channel_deposits(RuntimeOrigin::signed(1), para_a, para_b),
+			Error::<Test>::UpdateHrmpChannelPending
+		);
+		Hrmp::cancel_update_request(para_a, channel_id.clone()).unwrap();
+		assert_eq!((free_balance(para_a), free_balance(para_b)), (80, 95));
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Poke, then enact: the update is enacted with the deposits of the request.
+		Hrmp::init_update_channel(para_a, para_b, 1, 4).unwrap();
+		set_hrmp_deposits(40, 20);
+		assert_noop!(
+			Hrmp::poke_channel_deposits(RuntimeOrigin::signed(1), para_a, para_b),
+			Error::<Test>::UpdateHrmpChannelPending
+		);
+		Hrmp::accept_update_channel(para_b, para_a).unwrap();
+		assert_noop!(
+			Hrmp::poke_channel_deposits(RuntimeOrigin::signed(1), para_a, para_b),
+			Error::<Test>::UpdateHrmpChannelPending
+		);
+		run_to_block(10, Some(vec![10]));
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.sender_deposit, channel.recipient_deposit), (30, 20));
+		assert_eq!((free_balance(para_a), free_balance(para_b)), (70, 90));
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Once enacted, poking adjusts the deposits to the configuration again.
+		assert_ok!(Hrmp::poke_channel_deposits(RuntimeOrigin::signed(1), para_a, para_b));
+		assert_eq!((free_balance(para_a), free_balance(para_b)), (60, 90));
+		let channel = HrmpChannels::<Test>::get(&channel_id).unwrap();
+		assert_eq!((channel.sender_deposit, channel.recipient_deposit), (40, 20));
+	});
+}
+
+#[test]
+fn cancel_pending_update_channel_request() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		// Register two parachains and open a channel between them.
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(8, Some(vec![8]));
+
+		set_hrmp_deposits(30, 15);
+		Hrmp::init_update_channel(para_a, para_b, 1, 4).unwrap();
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 70);
+
+		// Cancel the update, which returns the additional deposit.
+		Hrmp::cancel_update_request(para_b, channel_id.clone()).unwrap();
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 80);
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Accepted requests can't be canceled.
+		Hrmp::init_update_channel(para_a, para_b, 1, 4).unwrap();
+		Hrmp::accept_update_channel(para_b, para_a).unwrap();
+		assert_noop!(
+			Hrmp::cancel_update_request(para_a, channel_id.clone()),
+			Error::<Test>::UpdateHrmpChannelAlreadyConfirmed
+		);
+
+		// Channels which are going to be closed can't be updated.
+		run_to_block(10, Some(vec![10]));
+		Hrmp::close_channel(para_b, channel_id.clone()).unwrap();
+		assert_noop!(
+			Hrmp::init_update_channel(para_a, para_b, 2, 8),
+			Error::<Test>::UpdateHrmpChannelClosing
+		);
+	});
+}
+
+#[test]
+fn refund_update_deposit_on_closure() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		// Register two parachains and open a channel between them.
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(8, Some(vec![8]));
+
+		// Request an update with increased deposits.
+		set_hrmp_deposits(30, 30);
+		Hrmp::init_update_channel(para_a, para_b, 1, 4).unwrap();
+		Hrmp::accept_update_channel(para_b, para_a).unwrap();
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 70);
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_b.into_account_truncating()), 80);
+
+		// Close the channel in the same session.
+		Hrmp::close_channel(para_a, channel_id.clone()).unwrap();
+
+		// All deposits are returned once the channel is closed.
+		run_to_block(10, Some(vec![10]));
+		assert!(!channel_exists(para_a, para_b));
+		Hrmp::assert_storage_consistency_exhaustive();
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()),
+			100
+		);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_b.into_account_truncating()),
+			110
+		);
+	});
+}
+
 #[test]
 fn watermark_maxed_out_at_relay_parent() {
 	let para_a = 2032.into();
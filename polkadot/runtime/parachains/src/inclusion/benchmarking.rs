@@ -33,6 +33,48 @@ benchmarks! {
 		Pallet::<T>::receive_upward_messages(para, vec![vec![0; max_len]; 1].as_slice());
 	}: { Pallet::<T>::receive_upward_messages(para, upward_messages.as_slice()) }
 
+	receive_upward_message_fragment {
+		let s in 1 .. MAX_UPWARD_MESSAGE_SIZE_BOUND;
+
+		let max_len = MaxUmpMessageLenOf::<T>::get();
+		let para = 42u32.into();
+		// The para reassembles as many other messages as it may.
+		for message_id in 1..T::MaxFragmentedUpwardMessages::get() {
+			let fragment =
+				UpwardMessageFragment { message_id, index: 0, count: 2, data: vec![0; 1] };
+			Pallet::<T>::receive_upward_message_fragment(para, &fragment.into_upward_message());
+		}
+		let data = vec![0; s.min(max_len) as usize];
+		let fragment = UpwardMessageFragment { message_id: 0, index: 0, count: 2, data }
+			.into_upward_message();
+	}: { Pallet::<T>::receive_upward_message_fragment(para, &fragment) }
+	verify {
+		assert!(PartialUpwardMessages::<T>::contains_key(para, 0));
+	}
+
+	reassemble_upward_message {
+		let c in 1 .. 1000;
+		let s in 1 .. MAX_UPWARD_MESSAGE_SIZE_BOUND;
+
+		let max_len = MaxUmpMessageLenOf::<T>::get();
+		let para = 42u32.into();
+		let len = s.min(max_len) as usize;
+		let chunk_size = len / c as usize + 1;
+		let count = c as u16 + 1;
+		let message = vec![0; len];
+		let mut chunks = message.chunks(chunk_size);
+		for index in 0..c as u16 {
+			let data = chunks.next().unwrap_or_default().to_vec();
+			let fragment = UpwardMessageFragment { message_id: 0, index, count, data };
+			Pallet::<T>::receive_upward_message_fragment(para, &fragment.into_upward_message());
+		}
+		let fragment = UpwardMessageFragment { message_id: 0, index: c as u16, count, data: vec![] }
+			.into_upward_message();
+	}: { Pallet::<T>::receive_upward_message_fragment(para, &fragment) }
+	verify {
+		assert!(!PartialUpwardMessages::<T>::contains_key(para, 0));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(Default::default()),
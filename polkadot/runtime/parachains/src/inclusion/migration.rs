@@ -0,0 +1,229 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A module that is responsible for migration of storage.
+
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration, storage_alias, traits::OnRuntimeUpgrade, weights::Weight,
+};
+
+mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, PartialEq, TypeInfo, Clone, RuntimeDebug)]
+	pub(crate) struct CandidatePendingAvailability<H, N> {
+		pub core: CoreIndex,
+		pub hash: CandidateHash,
+		pub descriptor: CandidateDescriptor<H>,
+		pub availability_votes: BitVec<u8, BitOrderLsb0>,
+		pub backers: BitVec<u8, BitOrderLsb0>,
+		pub relay_parent_number: N,
+		pub backed_in_number: N,
+		pub backing_group: GroupIndex,
+	}
+
+	#[storage_alias]
+	pub(crate) type PendingAvailability<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		ParaId,
+		CandidatePendingAvailability<<T as frame_system::Config>::Hash, BlockNumberFor<T>>,
+	>;
+
+	#[storage_alias]
+	pub(crate) type PendingAvailabilityCommitments<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ParaId, CandidateCommitments>;
+}
+
+mod v1 {
+	use super::*;
+
+	/// Merges the commitments into the candidates pending availability, which are now stored as
+	/// a chain per para.
+	pub struct UncheckedMigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight: Weight = Weight::zero();
+
+			let candidates = v0::PendingAvailability::<T>::drain().collect::<Vec<_>>();
+			weight.saturating_accrue(
+				T::DbWeight::get().reads_writes(candidates.len() as u64, candidates.len() as u64),
+			);
+
+			for (para_id, candidate) in candidates {
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+				// The commitments are always written along with the candidate.
+				let Some(commitments) = v0::PendingAvailabilityCommitments::<T>::take(&para_id)
+				else {
+					log::warn!(
+						target: LOG_TARGET,
+						"Dropping candidate {:?} of para {:?} pending availability without commitments",
+						candidate.hash,
+						para_id,
+					);
+					continue
+				};
+
+				let candidate = CandidatePendingAvailability {
+					core: candidate.core,
+					hash: candidate.hash,
+					descriptor: candidate.descriptor,
+					commitments,
+					availability_votes: candidate.availability_votes,
+					backers: candidate.backers,
+					relay_parent_number: candidate.relay_parent_number,
+					backed_in_number: candidate.backed_in_number,
+					backing_group: candidate.backing_group,
+				};
+				PendingAvailability::<T>::insert(&para_id, VecDeque::from([candidate]));
+			}
+
+			// Commitments without a candidate are stale.
+			let removed = v0::PendingAvailabilityCommitments::<T>::clear(u32::MAX, None).unique;
+			weight.saturating_accrue(T::DbWeight::get().writes(removed as u64));
+
+			log::info!(target: LOG_TARGET, "Migrated candidates pending availability to v1");
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = v0::PendingAvailability::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let old_count = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of candidates pending availability")?;
+
+			ensure!(
+				PendingAvailability::<T>::iter_values().all(|candidates| candidates.len() == 1),
+				"Every para has exactly one candidate pending availability after migration"
+			);
+			ensure!(
+				PendingAvailability::<T>::iter_keys().count() as u32 <= old_count,
+				"No candidates pending availability were added by the migration"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Migrate `V0` to `V1` of the storage format.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+	0,
+	1,
+	v1::UncheckedMigrateToV1<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use super::{v0, v1::UncheckedMigrateToV1, *};
+	use crate::mock::{new_test_ext, MockGenesisConfig, Test};
+	use frame_support::traits::OnRuntimeUpgrade;
+	use primitives::Id as ParaId;
+	use test_helpers::{dummy_candidate_commitments, dummy_candidate_descriptor, dummy_hash};
+
+	#[test]
+	fn migrate_to_v1() {
+		new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+			// No data to migrate.
+			let _ = UncheckedMigrateToV1::<Test>::on_runtime_upgrade();
+			assert!(PendingAvailability::<Test>::iter().next().is_none());
+
+			let mut expected = vec![];
+
+			for i in 1..5 {
+				let descriptor = dummy_candidate_descriptor(dummy_hash());
+				v0::PendingAvailability::<Test>::insert(
+					ParaId::from(i),
+					v0::CandidatePendingAvailability {
+						core: CoreIndex(i),
+						descriptor: descriptor.clone(),
+						relay_parent_number: i,
+						hash: CandidateHash(dummy_hash()),
+						availability_votes: Default::default(),
+						backed_in_number: i,
+						backers: Default::default(),
+						backing_group: GroupIndex(i),
+					},
+				);
+				v0::PendingAvailabilityCommitments::<Test>::insert(
+					ParaId::from(i),
+					dummy_candidate_commitments(HeadData(vec![i as _])),
+				);
+
+				expected.push((
+					ParaId::from(i),
+					[CandidatePendingAvailability {
+						core: CoreIndex(i),
+						descriptor,
+						relay_parent_number: i,
+						hash: CandidateHash(dummy_hash()),
+						availability_votes: Default::default(),
+						backed_in_number: i,
+						backers: Default::default(),
+						backing_group: GroupIndex(i),
+						commitments: dummy_candidate_commitments(HeadData(vec![i as _])),
+					}]
+					.into_iter()
+					.collect::<VecDeque<_>>(),
+				));
+			}
+			// add some wrong data also, candidates without commitments or commitments without
+			// candidates.
+			v0::PendingAvailability::<Test>::insert(
+				ParaId::from(6),
+				v0::CandidatePendingAvailability {
+					core: CoreIndex(6),
+					descriptor: dummy_candidate_descriptor(dummy_hash()),
+					relay_parent_number: 6,
+					hash: CandidateHash(dummy_hash()),
+					availability_votes: Default::default(),
+					backed_in_number: 6,
+					backers: Default::default(),
+					backing_group: GroupIndex(6),
+				},
+			);
+			v0::PendingAvailabilityCommitments::<Test>::insert(
+				ParaId::from(7),
+				dummy_candidate_commitments(HeadData(vec![7 as _])),
+			);
+
+			// For tests, db weight is zero.
+			assert_eq!(
+				<Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1),
+				Weight::zero()
+			);
+
+			let _ = UncheckedMigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(v0::PendingAvailabilityCommitments::<Test>::iter().next(), None);
+
+			let mut actual = PendingAvailability::<Test>::iter().collect::<Vec<_>>();
+			actual.sort_by(|(id1, _), (id2, _)| id1.cmp(id2));
+			assert_eq!(actual, expected);
+		});
+	}
+}
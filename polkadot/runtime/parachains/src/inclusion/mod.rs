@@ -30,25 +30,28 @@ use bitvec::{order::Lsb0 as BitOrderLsb0, vec::BitVec};
 use frame_support::{
 	defensive,
 	pallet_prelude::*,
-	traits::{Defensive, EnqueueMessage, Footprint, QueueFootprint},
+	traits::{EnqueueMessage, Footprint, QueueFootprint},
 	BoundedSlice,
 };
 use frame_system::pallet_prelude::*;
 use pallet_message_queue::OnQueueChanged;
 use parity_scale_codec::{Decode, Encode};
+use polkadot_parachain_primitives::primitives::UpwardMessageFragment;
 use primitives::{
-	effective_minimum_backing_votes, supermajority_threshold, well_known_keys,
-	AvailabilityBitfield, BackedCandidate, CandidateCommitments, CandidateDescriptor,
-	CandidateHash, CandidateReceipt, CommittedCandidateReceipt, CoreIndex, GroupIndex, Hash,
-	HeadData, Id as ParaId, SignedAvailabilityBitfields, SigningContext, UpwardMessage,
-	ValidatorId, ValidatorIndex, ValidityAttestation,
+	effective_minimum_backing_votes, supermajority_threshold,
+	vstaging::{CandidateDropReason, DroppedCandidate},
+	well_known_keys, AvailabilityBitfield, BackedCandidate, CandidateCommitments,
+	CandidateDescriptor, CandidateHash, CandidateReceipt, CommittedCandidateReceipt, CoreIndex,
+	GroupIndex, Hash, HeadData, Id as ParaId, SignedAvailabilityBitfields, SigningContext,
+	UpwardMessage, ValidatorId, ValidatorIndex, ValidityAttestation,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{traits::One, DispatchError, SaturatedConversion, Saturating};
 #[cfg(feature = "std")]
 use sp_std::fmt;
 use sp_std::{
-	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	borrow::Cow,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet, vec_deque::VecDeque},
 	prelude::*,
 };
 
@@ -60,8 +63,12 @@ pub(crate) mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migration;
+
 pub trait WeightInfo {
 	fn receive_upward_messages(i: u32) -> Weight;
+	fn receive_upward_message_fragment(s: u32) -> Weight;
+	fn reassemble_upward_message(c: u32, s: u32) -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -69,12 +76,24 @@ impl WeightInfo for TestWeightInfo {
 	fn receive_upward_messages(_: u32) -> Weight {
 		Weight::MAX
 	}
+	fn receive_upward_message_fragment(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn reassemble_upward_message(_: u32, _: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 impl WeightInfo for () {
 	fn receive_upward_messages(_: u32) -> Weight {
 		Weight::zero()
 	}
+	fn receive_upward_message_fragment(_: u32) -> Weight {
+		Weight::zero()
+	}
+	fn reassemble_upward_message(_: u32, _: u32) -> Weight {
+		Weight::zero()
+	}
 }
 
 /// Maximum value that `config.max_upward_message_size` can be set to.
@@ -105,6 +124,8 @@ pub struct CandidatePendingAvailability<H, N> {
 	hash: CandidateHash,
 	/// The candidate descriptor.
 	descriptor: CandidateDescriptor<H>,
+	/// The candidate commitments.
+	commitments: CandidateCommitments,
 	/// The received availability votes. One bit per validator.
 	availability_votes: BitVec<u8, BitOrderLsb0>,
 	/// The backers of the candidate pending availability.
@@ -143,6 +164,11 @@ impl<H, N> CandidatePendingAvailability<H, N> {
 		&self.descriptor
 	}
 
+	/// Get the candidate commitments.
+	pub(crate) fn candidate_commitments(&self) -> &CandidateCommitments {
+		&self.commitments
+	}
+
 	/// Get the candidate's relay parent's number.
 	pub(crate) fn relay_parent_number(&self) -> N
 	where
@@ -156,6 +182,7 @@ impl<H, N> CandidatePendingAvailability<H, N> {
 		core: CoreIndex,
 		hash: CandidateHash,
 		descriptor: CandidateDescriptor<H>,
+		commitments: CandidateCommitments,
 		availability_votes: BitVec<u8, BitOrderLsb0>,
 		backers: BitVec<u8, BitOrderLsb0>,
 		relay_parent_number: N,
@@ -166,6 +193,7 @@ impl<H, N> CandidatePendingAvailability<H, N> {
 			core,
 			hash,
 			descriptor,
+			commitments,
 			availability_votes,
 			backers,
 			relay_parent_number,
@@ -252,11 +280,28 @@ impl From<u32> for AggregateMessageOrigin {
 pub type MaxUmpMessageLenOf<T> =
 	<<T as Config>::MessageQueue as EnqueueMessage<AggregateMessageOrigin>>::MaxMessageLen;
 
+/// An upward message being reassembled from its fragments.
+#[derive(Encode, Decode, PartialEq, TypeInfo, RuntimeDebug)]
+pub(crate) struct PartialUpwardMessage<N> {
+	/// The number of fragments the message was split into.
+	pub(crate) count: u16,
+	/// The index of the next fragment. Fragments must arrive in order.
+	pub(crate) next_index: u16,
+	/// The length of the data of the fragments received so far.
+	pub(crate) len: u32,
+	/// The block number at which the message is discarded if it isn't complete by then.
+	pub(crate) expires_at: N,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -281,6 +326,16 @@ pub mod pallet {
 		/// adding new variants to `AggregateMessageOrigin`.
 		type MessageQueue: EnqueueMessage<AggregateMessageOrigin>;
 
+		/// The maximum number of fragmented upward messages a para can have under reassembly at
+		/// the same time.
+		#[pallet::constant]
+		type MaxFragmentedUpwardMessages: Get<u32>;
+
+		/// The number of blocks after which a fragmented upward message that isn't complete yet is
+		/// discarded.
+		#[pallet::constant]
+		type UpwardMessageFragmentTimeout: Get<BlockNumberFor<Self>>;
+
 		/// Weight info for the calls of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -296,6 +351,9 @@ pub mod pallet {
 		CandidateTimedOut(CandidateReceipt<T::Hash>, HeadData, CoreIndex),
 		/// Some upward messages have been received and will be processed.
 		UpwardMessagesReceived { from: ParaId, count: u32 },
+		/// A fragmented upward message was discarded before being complete: it timed out, a
+		/// fragment was out of order or a reassembly limit was hit.
+		FragmentedUpwardMessageDiscarded { from: ParaId, message_id: u32 },
 	}
 
 	#[pallet::error]
@@ -363,6 +421,8 @@ pub mod pallet {
 		/// either intentionally or as part of a concluded
 		/// invalid dispute.
 		BitfieldReferencesFreedCore,
+		/// More than one candidate was backed on the same core.
+		DuplicateCoreIndex,
 	}
 
 	/// The latest bitfield for each validator, referred to by their index in the validator set.
@@ -371,18 +431,52 @@ pub mod pallet {
 		StorageMap<_, Twox64Concat, ValidatorIndex, AvailabilityBitfieldRecord<BlockNumberFor<T>>>;
 
 	/// Candidates pending availability by `ParaId`.
+	///
+	/// The candidates of a para form a chain: the first one builds on the para head, every other
+	/// one on the head produced by its predecessor. They can only be enacted in that order.
 	#[pallet::storage]
 	pub(crate) type PendingAvailability<T: Config> = StorageMap<
 		_,
 		Twox64Concat,
 		ParaId,
-		CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>,
+		VecDeque<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>>,
+	>;
+
+	/// The candidates dropped in this block, along with the reason they were dropped.
+	///
+	/// Cleared at the beginning of every block. Candidates dropped on a session change are noted
+	/// in the last block of the old session.
+	#[pallet::storage]
+	pub(crate) type DroppedCandidates<T: Config> =
+		StorageValue<_, Vec<DroppedCandidate>, ValueQuery>;
+
+	/// Fragmented upward messages being reassembled, by para and message id.
+	#[pallet::storage]
+	pub(crate) type PartialUpwardMessages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ParaId,
+		Twox64Concat,
+		u32,
+		PartialUpwardMessage<BlockNumberFor<T>>,
+	>;
+
+	/// The data of the fragments received so far, by para, message id and fragment index.
+	///
+	/// The fragments are kept apart until the last one arrives, so that every fragment is written
+	/// once, and concatenated when the message is complete.
+	#[pallet::storage]
+	pub(crate) type UpwardMessageFragments<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Twox64Concat, ParaId>, NMapKey<Twox64Concat, u32>, NMapKey<Twox64Concat, u16>),
+		Vec<u8>,
 	>;
 
-	/// The commitments of candidates pending availability, by `ParaId`.
+	/// The fragmented upward messages to discard at the given block number, unless they were
+	/// completed or restarted in the meantime.
 	#[pallet::storage]
-	pub(crate) type PendingAvailabilityCommitments<T: Config> =
-		StorageMap<_, Twox64Concat, ParaId, CandidateCommitments>;
+	pub(crate) type PartialUpwardMessageTimeouts<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<(ParaId, u32)>, ValueQuery>;
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
@@ -458,8 +552,19 @@ impl fmt::Debug for UmpAcceptanceCheckErr {
 
 impl<T: Config> Pallet<T> {
 	/// Block initialization logic, called by initializer.
-	pub(crate) fn initializer_initialize(_now: BlockNumberFor<T>) -> Weight {
-		Weight::zero()
+	pub(crate) fn initializer_initialize(now: BlockNumberFor<T>) -> Weight {
+		DroppedCandidates::<T>::kill();
+		let mut weight = T::DbWeight::get().reads_writes(1, 2);
+
+		for (para, message_id) in PartialUpwardMessageTimeouts::<T>::take(now) {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+			let timed_out = PartialUpwardMessages::<T>::get(para, message_id)
+				.map_or(false, |partial| partial.expires_at == now);
+			if timed_out {
+				weight.saturating_accrue(Self::discard_partial_upward_message(para, message_id));
+			}
+		}
+		weight
 	}
 
 	/// Block finalization logic, called by initializer.
@@ -472,8 +577,15 @@ impl<T: Config> Pallet<T> {
 	) {
 		// unlike most drain methods, drained elements are not cleared on `Drop` of the iterator
 		// and require consumption.
-		for _ in <PendingAvailabilityCommitments<T>>::drain() {}
-		for _ in <PendingAvailability<T>>::drain() {}
+		for (para_id, pending) in <PendingAvailability<T>>::drain() {
+			for candidate in pending {
+				Self::note_dropped_candidate(
+					candidate.hash,
+					para_id,
+					CandidateDropReason::SessionChange,
+				);
+			}
+		}
 		for _ in <AvailabilityBitfields<T>>::drain() {}
 
 		Self::cleanup_outgoing_ump_dispatch_queues(outgoing_paras);
@@ -487,6 +599,8 @@ impl<T: Config> Pallet<T> {
 
 	pub(crate) fn cleanup_outgoing_ump_dispatch_queue(para: ParaId) {
 		T::MessageQueue::sweep_queue(AggregateMessageOrigin::Ump(UmpQueueId::Para(para)));
+		let _ = PartialUpwardMessages::<T>::clear_prefix(para, u32::MAX, None);
+		let _ = UpwardMessageFragments::<T>::clear_prefix((para,), u32::MAX, None);
 	}
 
 	/// Extract the freed cores based on cores that became available.
@@ -495,23 +609,18 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Updates storage items `PendingAvailability` and `AvailabilityBitfields`.
 	///
+	/// A candidate is only enacted once it and all of its predecessors in the chain of the para
+	/// became available.
+	///
 	/// Returns a `Vec` of `CandidateHash`es and their respective `AvailabilityCore`s that became
 	/// available, and cores free.
-	pub(crate) fn update_pending_availability_and_get_freed_cores<F>(
-		expected_bits: usize,
+	pub(crate) fn update_pending_availability_and_get_freed_cores(
 		validators: &[ValidatorId],
 		signed_bitfields: SignedAvailabilityBitfields,
-		core_lookup: F,
-	) -> Vec<(CoreIndex, CandidateHash)>
-	where
-		F: Fn(CoreIndex) -> Option<ParaId>,
-	{
-		let mut assigned_paras_record = (0..expected_bits)
-			.map(|bit_index| core_lookup(CoreIndex::from(bit_index as u32)))
-			.map(|opt_para_id| {
-				opt_para_id.map(|para_id| (para_id, PendingAvailability::<T>::get(&para_id)))
-			})
-			.collect::<Vec<_>>();
+	) -> Vec<(CoreIndex, CandidateHash)> {
+		let threshold = availability_threshold(validators.len());
+
+		let mut votes_per_core: BTreeMap<CoreIndex, BTreeSet<ValidatorIndex>> = BTreeMap::new();
 
 		let now = <frame_system::Pallet<T>>::block_number();
 		for (checked_bitfield, validator_index) in
@@ -521,27 +630,10 @@ impl<T: Config> Pallet<T> {
 				(checked_bitfield, validator_idx)
 			}) {
 			for (bit_idx, _) in checked_bitfield.0.iter().enumerate().filter(|(_, is_av)| **is_av) {
-				let pending_availability = if let Some((_, pending_availability)) =
-					assigned_paras_record[bit_idx].as_mut()
-				{
-					pending_availability
-				} else {
-					// For honest validators, this happens in case of unoccupied cores,
-					// which in turn happens in case of a disputed candidate.
-					// A malicious one might include arbitrary indices, but they are represented
-					// by `None` values and will be sorted out in the next if case.
-					continue
-				};
-
-				// defensive check - this is constructed by loading the availability bitfield
-				// record, which is always `Some` if the core is occupied - that's why we're here.
-				let validator_index = validator_index.0 as usize;
-				if let Some(mut bit) =
-					pending_availability.as_mut().and_then(|candidate_pending_availability| {
-						candidate_pending_availability.availability_votes.get_mut(validator_index)
-					}) {
-					*bit = true;
-				}
+				votes_per_core
+					.entry(CoreIndex::from(bit_idx as u32))
+					.or_default()
+					.insert(validator_index);
 			}
 
 			let record =
@@ -550,221 +642,235 @@ impl<T: Config> Pallet<T> {
 			<AvailabilityBitfields<T>>::insert(&validator_index, record);
 		}
 
-		let threshold = availability_threshold(validators.len());
+		let mut freed_cores = Vec::new();
+
+		let pending_paras = <PendingAvailability<T>>::iter_keys().collect::<Vec<_>>();
+		for para_id in pending_paras {
+			let mut enacted = Vec::new();
+
+			<PendingAvailability<T>>::mutate_exists(&para_id, |maybe_candidates| {
+				let Some(candidates) = maybe_candidates else { return };
+
+				// Votes are noted on all candidates, even if a predecessor is not available yet.
+				// A candidate which is available but can't be enacted yet is checked again on the
+				// next block.
+				let mut all_predecessors_available = true;
+				let mut available = 0;
+				for candidate in candidates.iter_mut() {
+					if let Some(validator_indices) = votes_per_core.remove(&candidate.core) {
+						for validator_index in validator_indices {
+							// defensive check - the bitfields are sanitized, so the validator
+							// index is in bounds.
+							if let Some(mut bit) =
+								candidate.availability_votes.get_mut(validator_index.0 as usize)
+							{
+								*bit = true;
+							}
+						}
+					}
 
-		let mut freed_cores = Vec::with_capacity(expected_bits);
-		for (para_id, pending_availability) in assigned_paras_record
-			.into_iter()
-			.flatten()
-			.filter_map(|(id, p)| p.map(|p| (id, p)))
-		{
-			if pending_availability.availability_votes.count_ones() >= threshold {
-				<PendingAvailability<T>>::remove(&para_id);
-				let commitments = match PendingAvailabilityCommitments::<T>::take(&para_id) {
-					Some(commitments) => commitments,
-					None => {
-						log::warn!(
-							target: LOG_TARGET,
-							"Inclusion::process_bitfields: PendingAvailability and PendingAvailabilityCommitments
-							are out of sync, did someone mess with the storage?",
-						);
-						continue
-					},
-				};
+					if all_predecessors_available &&
+						candidate.availability_votes.count_ones() >= threshold
+					{
+						available += 1;
+					} else {
+						all_predecessors_available = false;
+					}
+				}
+
+				enacted.extend(candidates.drain(..available));
+				if candidates.is_empty() {
+					*maybe_candidates = None;
+				}
+			});
 
+			for candidate in enacted {
 				let receipt = CommittedCandidateReceipt {
-					descriptor: pending_availability.descriptor,
-					commitments,
+					descriptor: candidate.descriptor,
+					commitments: candidate.commitments,
 				};
 				let _weight = Self::enact_candidate(
-					pending_availability.relay_parent_number,
+					candidate.relay_parent_number,
 					receipt,
-					pending_availability.backers,
-					pending_availability.availability_votes,
-					pending_availability.core,
-					pending_availability.backing_group,
+					candidate.backers,
+					candidate.availability_votes,
+					candidate.core,
+					candidate.backing_group,
 				);
 
-				freed_cores.push((pending_availability.core, pending_availability.hash));
-			} else {
-				<PendingAvailability<T>>::insert(&para_id, &pending_availability);
+				freed_cores.push((candidate.core, candidate.hash));
 			}
 		}
 
 		freed_cores
 	}
 
-	/// Process candidates that have been backed. Provide the relay storage root, a set of
-	/// candidates and scheduled cores.
+	/// Process candidates that have been backed. Provide the backed candidates of every para
+	/// along with the cores they are backed on, and the scheduled cores.
+	///
+	/// The candidates of a para should be given in the order of their chain, starting from the
+	/// latest head of the para (see [`Self::para_latest_head_and_context`]). Every candidate
+	/// should be backed on a distinct core scheduled for its para. If these conditions are not
+	/// met, the execution of the function fails.
 	///
-	/// Both should be sorted ascending by core index, and the candidates should be a subset of
-	/// scheduled cores. If these conditions are not met, the execution of the function fails.
+	/// Unless `core_index_enabled`, i.e. the `ElasticScalingMVP` node feature is enabled, a para
+	/// must not have any candidate pending availability.
 	pub(crate) fn process_candidates<GV>(
 		allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
-		candidates: Vec<BackedCandidate<T::Hash>>,
-		scheduled: &BTreeMap<ParaId, CoreIndex>,
+		candidates: &BTreeMap<ParaId, Vec<(BackedCandidate<T::Hash>, CoreIndex)>>,
+		scheduled: &BTreeMap<ParaId, BTreeSet<CoreIndex>>,
 		group_validators: GV,
+		core_index_enabled: bool,
 	) -> Result<ProcessedCandidates<T::Hash>, DispatchError>
 	where
 		GV: Fn(GroupIndex) -> Option<Vec<ValidatorIndex>>,
 	{
 		let now = <frame_system::Pallet<T>>::block_number();
 
-		ensure!(candidates.len() <= scheduled.len(), Error::<T>::UnscheduledCandidate);
-
-		if scheduled.is_empty() {
+		if candidates.is_empty() {
 			return Ok(ProcessedCandidates::default())
 		}
 
 		let minimum_backing_votes = configuration::Pallet::<T>::config().minimum_backing_votes;
 		let validators = shared::Pallet::<T>::active_validator_keys();
+		let session_index = shared::Pallet::<T>::session_index();
 
 		// Collect candidate receipts with backers.
-		let mut candidate_receipt_with_backing_validator_indices =
-			Vec::with_capacity(candidates.len());
+		let mut candidate_receipt_with_backing_validator_indices = Vec::new();
 
 		// Do all checks before writing storage.
 		let core_indices_and_backers = {
-			let mut core_indices_and_backers = Vec::with_capacity(candidates.len());
-			let mut last_core = None;
-
-			let mut check_assignment_in_order = |core_idx| -> DispatchResult {
-				ensure!(
-					last_core.map_or(true, |core| core_idx > core),
-					Error::<T>::ScheduledOutOfOrder,
-				);
-
-				last_core = Some(core_idx);
-				Ok(())
-			};
+			let mut core_indices_and_backers = Vec::new();
+			let mut used_cores = BTreeSet::new();
+			let mut candidate_idx = 0;
+
+			for (para_id, para_candidates) in candidates {
+				let scheduled_cores =
+					scheduled.get(para_id).ok_or(Error::<T>::UnscheduledCandidate)?;
+				if !core_index_enabled {
+					ensure!(
+						<PendingAvailability<T>>::get(para_id).is_none(),
+						Error::<T>::CandidateScheduledBeforeParaFree,
+					);
+				}
 
-			// We combine an outer loop over candidates with an inner loop over the scheduled,
-			// where each iteration of the outer loop picks up at the position
-			// in scheduled just after the past iteration left off.
-			//
-			// If the candidates appear in the same order as they appear in `scheduled`,
-			// then they should always be found. If the end of `scheduled` is reached,
-			// then the candidate was either not scheduled or out-of-order.
-			//
-			// In the meantime, we do certain sanity checks on the candidates and on the scheduled
-			// list.
-			for (candidate_idx, backed_candidate) in candidates.iter().enumerate() {
-				let relay_parent_hash = backed_candidate.descriptor().relay_parent;
-				let para_id = backed_candidate.descriptor().para_id;
-
-				let prev_context = <paras::Pallet<T>>::para_most_recent_context(para_id);
-
-				let check_ctx = CandidateCheckContext::<T>::new(prev_context);
-				let signing_context = SigningContext {
-					parent_hash: relay_parent_hash,
-					session_index: shared::Pallet::<T>::session_index(),
+				let (latest_head_data, prev_context) = Self::para_latest_head_and_context(*para_id);
+				let Some(mut latest_head_data) = latest_head_data else {
+					log::debug!(
+						target: LOG_TARGET,
+						"Failed to create PVD for candidate {}",
+						candidate_idx,
+					);
+					// We don't want to error out here because it will
+					// brick the relay-chain. So we return early without
+					// doing anything.
+					return Ok(ProcessedCandidates::default())
 				};
-
-				let relay_parent_number = match check_ctx.verify_backed_candidate(
-					&allowed_relay_parents,
-					candidate_idx,
-					backed_candidate,
-				)? {
-					Err(FailedToCreatePVD) => {
-						log::debug!(
+				let mut prev_context = prev_context;
+
+				for (backed_candidate, core_idx) in para_candidates {
+					let relay_parent_hash = backed_candidate.descriptor().relay_parent;
+
+					let check_ctx = CandidateCheckContext::<T>::new(prev_context);
+					let signing_context =
+						SigningContext { parent_hash: relay_parent_hash, session_index };
+
+					let relay_parent_number = check_ctx.verify_backed_candidate(
+						&allowed_relay_parents,
+						candidate_idx,
+						backed_candidate,
+						latest_head_data.clone(),
+					)?;
+
+					let mut backers = bitvec::bitvec![u8, BitOrderLsb0; 0; validators.len()];
+
+					ensure!(scheduled_cores.contains(core_idx), Error::<T>::UnscheduledCandidate);
+					ensure!(used_cores.insert(*core_idx), Error::<T>::DuplicateCoreIndex);
+
+					// The candidate based upon relay parent `N` should be backed by a group
+					// assigned to core at block `N + 1`. Thus, `relay_parent_number + 1`
+					// will always land in the current session.
+					let group_idx = <scheduler::Pallet<T>>::group_assigned_to_core(
+						*core_idx,
+						relay_parent_number + One::one(),
+					)
+					.ok_or_else(|| {
+						log::warn!(
 							target: LOG_TARGET,
-							"Failed to create PVD for candidate {}",
-							candidate_idx,
+							"Failed to compute group index for candidate {}",
+							candidate_idx
 						);
-						// We don't want to error out here because it will
-						// brick the relay-chain. So we return early without
-						// doing anything.
-						return Ok(ProcessedCandidates::default())
-					},
-					Ok(rpn) => rpn,
-				};
-
-				let para_id = backed_candidate.descriptor().para_id;
-				let mut backers = bitvec::bitvec![u8, BitOrderLsb0; 0; validators.len()];
+						Error::<T>::InvalidAssignment
+					})?;
+					let group_vals =
+						group_validators(group_idx).ok_or_else(|| Error::<T>::InvalidGroupIndex)?;
 
-				let core_idx = *scheduled.get(&para_id).ok_or(Error::<T>::UnscheduledCandidate)?;
-				check_assignment_in_order(core_idx)?;
-				ensure!(
-					<PendingAvailability<T>>::get(&para_id).is_none() &&
-						<PendingAvailabilityCommitments<T>>::get(&para_id).is_none(),
-					Error::<T>::CandidateScheduledBeforeParaFree,
-				);
-
-				// The candidate based upon relay parent `N` should be backed by a group
-				// assigned to core at block `N + 1`. Thus, `relay_parent_number + 1`
-				// will always land in the current session.
-				let group_idx = <scheduler::Pallet<T>>::group_assigned_to_core(
-					core_idx,
-					relay_parent_number + One::one(),
-				)
-				.ok_or_else(|| {
-					log::warn!(
-						target: LOG_TARGET,
-						"Failed to compute group index for candidate {}",
-						candidate_idx
-					);
-					Error::<T>::InvalidAssignment
-				})?;
-				let group_vals =
-					group_validators(group_idx).ok_or_else(|| Error::<T>::InvalidGroupIndex)?;
-
-				// check the signatures in the backing and that it is a majority.
-				{
-					let maybe_amount_validated = primitives::check_candidate_backing(
-						&backed_candidate,
-						&signing_context,
-						group_vals.len(),
-						|intra_group_vi| {
-							group_vals
-								.get(intra_group_vi)
-								.and_then(|vi| validators.get(vi.0 as usize))
-								.map(|v| v.clone())
-						},
-					);
-
-					match maybe_amount_validated {
-						Ok(amount_validated) => ensure!(
-							amount_validated >=
-								effective_minimum_backing_votes(
-									group_vals.len(),
-									minimum_backing_votes
-								),
-							Error::<T>::InsufficientBacking,
-						),
-						Err(()) => {
-							Err(Error::<T>::InvalidBacking)?;
-						},
-					}
-
-					let mut backer_idx_and_attestation =
-						Vec::<(ValidatorIndex, ValidityAttestation)>::with_capacity(
-							backed_candidate.validator_indices.count_ones(),
-						);
-					let candidate_receipt = backed_candidate.receipt();
-
-					for ((bit_idx, _), attestation) in backed_candidate
-						.validator_indices
-						.iter()
-						.enumerate()
-						.filter(|(_, signed)| **signed)
-						.zip(backed_candidate.validity_votes.iter().cloned())
+					// check the signatures in the backing and that it is a majority.
 					{
-						let val_idx =
-							group_vals.get(bit_idx).expect("this query succeeded above; qed");
-						backer_idx_and_attestation.push((*val_idx, attestation));
+						let (validator_indices, _) =
+							backed_candidate.validator_indices_and_core_index(core_index_enabled);
+						let maybe_amount_validated = primitives::check_candidate_backing(
+							backed_candidate.hash(),
+							&backed_candidate.validity_votes,
+							validator_indices,
+							&signing_context,
+							group_vals.len(),
+							|intra_group_vi| {
+								group_vals
+									.get(intra_group_vi)
+									.and_then(|vi| validators.get(vi.0 as usize))
+									.map(|v| v.clone())
+							},
+						);
 
-						backers.set(val_idx.0 as _, true);
+						match maybe_amount_validated {
+							Ok(amount_validated) => ensure!(
+								amount_validated >=
+									effective_minimum_backing_votes(
+										group_vals.len(),
+										minimum_backing_votes
+									),
+								Error::<T>::InsufficientBacking,
+							),
+							Err(()) => {
+								Err(Error::<T>::InvalidBacking)?;
+							},
+						}
+
+						let mut backer_idx_and_attestation =
+							Vec::<(ValidatorIndex, ValidityAttestation)>::with_capacity(
+								validator_indices.count_ones(),
+							);
+						let candidate_receipt = backed_candidate.receipt();
+
+						for ((bit_idx, _), attestation) in validator_indices
+							.iter()
+							.enumerate()
+							.filter(|(_, signed)| **signed)
+							.zip(backed_candidate.validity_votes.iter().cloned())
+						{
+							let val_idx =
+								group_vals.get(bit_idx).expect("this query succeeded above; qed");
+							backer_idx_and_attestation.push((*val_idx, attestation));
+
+							backers.set(val_idx.0 as _, true);
+						}
+						candidate_receipt_with_backing_validator_indices
+							.push((candidate_receipt, backer_idx_and_attestation));
 					}
-					candidate_receipt_with_backing_validator_indices
-						.push((candidate_receipt, backer_idx_and_attestation));
-				}
 
-				core_indices_and_backers.push((
-					(core_idx, para_id),
-					backers,
-					group_idx,
-					relay_parent_number,
-				));
+					core_indices_and_backers.push((
+						(*core_idx, *para_id),
+						backers,
+						group_idx,
+						relay_parent_number,
+					));
+
+					// The next candidate of the para has to build upon this one.
+					latest_head_data = backed_candidate.candidate.commitments.head_data.clone();
+					prev_context = Some(relay_parent_number);
+					candidate_idx += 1;
+				}
 			}
 
 			core_indices_and_backers
@@ -772,41 +878,52 @@ impl<T: Config> Pallet<T> {
 
 		// one more sweep for actually writing to storage.
 		let core_indices = core_indices_and_backers.iter().map(|(c, ..)| *c).collect();
-		for (candidate, (core, backers, group, relay_parent_number)) in
-			candidates.into_iter().zip(core_indices_and_backers)
+		for ((backed_candidate, _), (core, backers, group, relay_parent_number)) in
+			candidates.values().flatten().zip(core_indices_and_backers)
 		{
-			let para_id = candidate.descriptor().para_id;
+			let para_id = backed_candidate.descriptor().para_id;
 
 			// initialize all availability votes to 0.
 			let availability_votes: BitVec<u8, BitOrderLsb0> =
 				bitvec::bitvec![u8, BitOrderLsb0; 0; validators.len()];
 
 			Self::deposit_event(Event::<T>::CandidateBacked(
-				candidate.candidate.to_plain(),
-				candidate.candidate.commitments.head_data.clone(),
+				backed_candidate.candidate.to_plain(),
+				backed_candidate.candidate.commitments.head_data.clone(),
 				core.0,
 				group,
 			));
 
-			let candidate_hash = candidate.candidate.hash();
+			let candidate_hash = backed_candidate.candidate.hash();
 
-			let (descriptor, commitments) =
-				(candidate.candidate.descriptor, candidate.candidate.commitments);
+			// Record the backers, so that they can be held accountable if the candidate is
+			// later disputed and concluded invalid.
+			<T::DisputesHandler as disputes::DisputesHandler<_>>::note_backed(
+				session_index,
+				candidate_hash,
+				backers.iter_ones().map(|i| ValidatorIndex(i as _)).collect(),
+			);
 
-			<PendingAvailability<T>>::insert(
-				&para_id,
-				CandidatePendingAvailability {
-					core: core.0,
-					hash: candidate_hash,
-					descriptor,
-					availability_votes,
-					relay_parent_number,
-					backers: backers.to_bitvec(),
-					backed_in_number: now,
-					backing_group: group,
-				},
+			let (descriptor, commitments) = (
+				backed_candidate.candidate.descriptor.clone(),
+				backed_candidate.candidate.commitments.clone(),
 			);
-			<PendingAvailabilityCommitments<T>>::insert(&para_id, commitments);
+
+			<PendingAvailability<T>>::mutate(&para_id, |pending| {
+				pending
+					.get_or_insert_with(VecDeque::new)
+					.push_back(CandidatePendingAvailability {
+						core: core.0,
+						hash: candidate_hash,
+						descriptor,
+						commitments,
+						availability_votes,
+						relay_parent_number,
+						backers: backers.to_bitvec(),
+						backed_in_number: now,
+						backing_group: group,
+					})
+			});
 		}
 
 		Ok(ProcessedCandidates::<T::Hash> {
@@ -985,8 +1102,23 @@ impl<T: Config> Pallet<T> {
 	/// This function is infallible since the candidate was already accepted and we therefore need
 	/// to deal with the messages as given. Messages that are too long will be ignored since such
 	/// candidates should have already been rejected in [`Self::check_upward_messages`].
+	///
+	/// Fragments of larger messages are not enqueued themselves. The message they belong to is
+	/// enqueued in place of its last fragment once complete.
 	pub(crate) fn receive_upward_messages(para: ParaId, upward_messages: &[Vec<u8>]) -> Weight {
-		let bounded = upward_messages
+		let mut weight = Weight::zero();
+		let messages: Vec<Cow<[u8]>> = upward_messages
+			.iter()
+			.filter_map(|d| {
+				if !UpwardMessageFragment::is_fragment(d) {
+					return Some(Cow::Borrowed(&d[..]))
+				}
+				let (message, fragment_weight) = Self::receive_upward_message_fragment(para, d);
+				weight.saturating_accrue(fragment_weight);
+				message.map(Cow::Owned)
+			})
+			.collect();
+		let bounded = messages
 			.iter()
 			.filter_map(|d| {
 				BoundedSlice::try_from(&d[..])
@@ -997,7 +1129,108 @@ impl<T: Config> Pallet<T> {
 					.ok()
 			})
 			.collect();
-		Self::receive_bounded_upward_messages(para, bounded)
+		weight.saturating_add(Self::receive_bounded_upward_messages(para, bounded))
+	}
+
+	/// Adds a fragment to the upward message it belongs to and returns the message once complete,
+	/// along with the weight consumed.
+	///
+	/// The message is discarded if the fragment breaks the rules of reassembly: fragments must
+	/// arrive in order, a para can't reassemble more than `MaxFragmentedUpwardMessages` messages at
+	/// the same time, and the message can't grow past `config.max_upward_reassembled_message_size`.
+	/// A first fragment restarts the reassembly of a message with the same id.
+	fn receive_upward_message_fragment(para: ParaId, message: &[u8]) -> (Option<Vec<u8>>, Weight) {
+		// Covers counting the other messages of the para, which are bounded by
+		// `MaxFragmentedUpwardMessages`, and storing the fragment.
+		let mut weight =
+			<T as Config>::WeightInfo::receive_upward_message_fragment(message.len() as u32);
+		let fragment = match UpwardMessageFragment::from_upward_message(message) {
+			Ok(fragment) => fragment,
+			Err(e) => {
+				log::debug!(
+					target: LOG_TARGET,
+					"Ignoring malformed upward message fragment from {:?}: {:?}",
+					para,
+					e,
+				);
+				return (None, weight)
+			},
+		};
+		let message_id = fragment.message_id;
+		let discard = |weight: Weight| {
+			(None, weight.saturating_add(Self::discard_partial_upward_message(para, message_id)))
+		};
+
+		let is_first = fragment.index == 0;
+		let mut partial = if is_first {
+			weight.saturating_accrue(Self::remove_partial_upward_message(para, message_id));
+			let others = PartialUpwardMessages::<T>::iter_key_prefix(para).count();
+			if others >= T::MaxFragmentedUpwardMessages::get() as usize {
+				return discard(weight)
+			}
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::UpwardMessageFragmentTimeout::get());
+			PartialUpwardMessage { count: fragment.count, next_index: 0, len: 0, expires_at }
+		} else {
+			match PartialUpwardMessages::<T>::get(para, message_id) {
+				Some(partial) => partial,
+				None => return discard(weight),
+			}
+		};
+
+		let len = partial.len.saturating_add(fragment.data.len() as u32);
+		if fragment.index != partial.next_index ||
+			fragment.count != partial.count ||
+			fragment.index >= fragment.count ||
+			len > configuration::Pallet::<T>::config().max_upward_reassembled_message_size
+		{
+			return discard(weight)
+		}
+
+		partial.len = len;
+		partial.next_index.saturating_inc();
+		if partial.next_index == partial.count {
+			PartialUpwardMessages::<T>::remove(para, message_id);
+			weight.saturating_accrue(<T as Config>::WeightInfo::reassemble_upward_message(
+				fragment.index.into(),
+				len,
+			));
+			let mut data = Vec::with_capacity(len as usize);
+			for index in 0..fragment.index {
+				match UpwardMessageFragments::<T>::take((para, message_id, index)) {
+					Some(previous) => data.extend(previous),
+					None => {
+						defensive!("Fragments of a partial upward message are stored");
+						return (None, weight)
+					},
+				}
+			}
+			data.extend(fragment.data);
+			return (Some(data), weight)
+		}
+
+		if is_first {
+			PartialUpwardMessageTimeouts::<T>::append(partial.expires_at, (para, message_id));
+		}
+		UpwardMessageFragments::<T>::insert((para, message_id, fragment.index), fragment.data);
+		PartialUpwardMessages::<T>::insert(para, message_id, partial);
+		(None, weight)
+	}
+
+	/// Removes a fragmented upward message along with the fragments received so far and returns
+	/// the weight consumed.
+	fn remove_partial_upward_message(para: ParaId, message_id: u32) -> Weight {
+		let received = PartialUpwardMessages::<T>::take(para, message_id)
+			.map_or(0, |partial| partial.next_index);
+		let _ =
+			UpwardMessageFragments::<T>::clear_prefix((para, message_id), received.into(), None);
+		T::DbWeight::get().reads_writes(1, u64::from(received).saturating_add(1))
+	}
+
+	/// Like [`Self::remove_partial_upward_message`], but notes that the message was discarded.
+	fn discard_partial_upward_message(para: ParaId, message_id: u32) -> Weight {
+		Self::deposit_event(Event::FragmentedUpwardMessageDiscarded { from: para, message_id });
+		Self::remove_partial_upward_message(para, message_id)
 	}
 
 	/// Enqueues storage-bounded `upward_messages` from a `para`'s accepted candidate block.
@@ -1019,82 +1252,121 @@ impl<T: Config> Pallet<T> {
 		weight
 	}
 
-	/// Cleans up all paras pending availability that the predicate returns true for.
+	/// Cleans up all candidates pending availability that the predicate returns true for.
+	///
+	/// The predicate accepts the block number the core has been occupied since (i.e. the block
+	/// number the candidate was backed at in this fork of the relay chain).
 	///
-	/// The predicate accepts the index of the core and the block number the core has been occupied
-	/// since (i.e. the block number the candidate was backed at in this fork of the relay chain).
+	/// Since the candidates of a para form a chain, all successors of a timed out candidate are
+	/// cleaned up as well.
 	///
 	/// Returns a vector of cleaned-up core IDs.
 	pub(crate) fn collect_pending(
 		pred: impl Fn(BlockNumberFor<T>) -> AvailabilityTimeoutStatus<BlockNumberFor<T>>,
 	) -> Vec<CoreIndex> {
-		let mut cleaned_up_ids = Vec::new();
-		let mut cleaned_up_cores = Vec::new();
+		let timed_out =
+			Self::drop_pending_from(|candidate| pred(candidate.backed_in_number).timed_out);
 
-		for (para_id, pending_record) in <PendingAvailability<T>>::iter() {
-			if pred(pending_record.backed_in_number).timed_out {
-				cleaned_up_ids.push(para_id);
-				cleaned_up_cores.push(pending_record.core);
-			}
-		}
-
-		for para_id in cleaned_up_ids {
-			let pending = <PendingAvailability<T>>::take(&para_id);
-			let commitments = <PendingAvailabilityCommitments<T>>::take(&para_id);
+		let mut cleaned_up_cores = Vec::with_capacity(timed_out.len());
+		for candidate in timed_out {
+			let reason = if pred(candidate.backed_in_number).timed_out {
+				CandidateDropReason::TimedOut
+			} else {
+				CandidateDropReason::PredecessorDropped
+			};
+			Self::note_dropped_candidate(candidate.hash, candidate.descriptor.para_id, reason);
 
-			if let (Some(pending), Some(commitments)) = (pending, commitments) {
-				// defensive: this should always be true.
-				let candidate = CandidateReceipt {
-					descriptor: pending.descriptor,
-					commitments_hash: commitments.hash(),
-				};
+			let receipt = CandidateReceipt {
+				descriptor: candidate.descriptor,
+				commitments_hash: candidate.commitments.hash(),
+			};
 
-				Self::deposit_event(Event::<T>::CandidateTimedOut(
-					candidate,
-					commitments.head_data,
-					pending.core,
-				));
-			}
+			Self::deposit_event(Event::<T>::CandidateTimedOut(
+				receipt,
+				candidate.commitments.head_data,
+				candidate.core,
+			));
+			cleaned_up_cores.push(candidate.core);
 		}
 
 		cleaned_up_cores
 	}
 
-	/// Cleans up all paras pending availability that are in the given list of disputed candidates.
+	/// Cleans up all candidates pending availability that are in the given list of disputed
+	/// candidates, along with their successors.
 	///
 	/// Returns a vector of cleaned-up core IDs.
 	pub(crate) fn collect_disputed(disputed: &BTreeSet<CandidateHash>) -> Vec<CoreIndex> {
-		let mut cleaned_up_ids = Vec::new();
-		let mut cleaned_up_cores = Vec::new();
+		Self::drop_pending_from(|candidate| disputed.contains(&candidate.hash))
+			.into_iter()
+			.map(|candidate| {
+				let reason = if disputed.contains(&candidate.hash) {
+					CandidateDropReason::ConcludedInvalid
+				} else {
+					CandidateDropReason::PredecessorDropped
+				};
+				Self::note_dropped_candidate(candidate.hash, candidate.descriptor.para_id, reason);
+				candidate.core
+			})
+			.collect()
+	}
 
-		for (para_id, pending_record) in <PendingAvailability<T>>::iter() {
-			if disputed.contains(&pending_record.hash) {
-				cleaned_up_ids.push(para_id);
-				cleaned_up_cores.push(pending_record.core);
+	/// Drops the first candidate pending availability of every para the predicate returns true
+	/// for, along with all of its successors.
+	///
+	/// Returns the dropped candidates.
+	fn drop_pending_from(
+		pred: impl Fn(&CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>) -> bool,
+	) -> Vec<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>> {
+		let mut cleaned_up = Vec::new();
+
+		for (para_id, pending) in <PendingAvailability<T>>::iter() {
+			if let Some(position) = pending.iter().position(&pred) {
+				cleaned_up.push((para_id, position));
 			}
 		}
 
-		for para_id in cleaned_up_ids {
-			let _ = <PendingAvailability<T>>::take(&para_id);
-			let _ = <PendingAvailabilityCommitments<T>>::take(&para_id);
+		let mut dropped = Vec::new();
+		for (para_id, position) in cleaned_up {
+			<PendingAvailability<T>>::mutate_exists(&para_id, |maybe_pending| {
+				if let Some(pending) = maybe_pending {
+					dropped.extend(pending.drain(position..));
+					if pending.is_empty() {
+						*maybe_pending = None;
+					}
+				}
+			});
 		}
 
-		cleaned_up_cores
+		dropped
+	}
+
+	/// Notes that the given candidate was dropped in this block for the given reason.
+	pub(crate) fn note_dropped_candidate(
+		candidate_hash: CandidateHash,
+		para_id: ParaId,
+		reason: CandidateDropReason,
+	) {
+		DroppedCandidates::<T>::append(DroppedCandidate { candidate_hash, para_id, reason });
 	}
 
-	/// Forcibly enact the candidate with the given ID as though it had been deemed available
-	/// by bitfields.
+	/// Returns the candidates dropped in this block.
+	pub(crate) fn dropped_candidates() -> Vec<DroppedCandidate> {
+		DroppedCandidates::<T>::get()
+	}
+
+	/// Forcibly enact all candidates of the para with the given ID as though they had been deemed
+	/// available by bitfields.
 	///
 	/// Is a no-op if there is no candidate pending availability for this para-id.
 	/// This should generally not be used but it is useful during execution of Runtime APIs,
 	/// where the changes to the state are expected to be discarded directly after.
 	pub(crate) fn force_enact(para: ParaId) {
-		let pending = <PendingAvailability<T>>::take(&para);
-		let commitments = <PendingAvailabilityCommitments<T>>::take(&para);
-
-		if let (Some(pending), Some(commitments)) = (pending, commitments) {
-			let candidate =
-				CommittedCandidateReceipt { descriptor: pending.descriptor, commitments };
+		for pending in <PendingAvailability<T>>::take(&para).into_iter().flatten() {
+			let candidate = CommittedCandidateReceipt {
+				descriptor: pending.descriptor,
+				commitments: pending.commitments,
+			};
 
 			Self::enact_candidate(
 				pending.relay_parent_number,
@@ -1107,22 +1379,60 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Returns the `CommittedCandidateReceipt` pending availability for the para provided, if any.
+	/// Returns the first `CommittedCandidateReceipt` pending availability for the para provided,
+	/// if any.
 	pub(crate) fn candidate_pending_availability(
 		para: ParaId,
 	) -> Option<CommittedCandidateReceipt<T::Hash>> {
-		<PendingAvailability<T>>::get(&para)
-			.map(|p| p.descriptor)
-			.and_then(|d| <PendingAvailabilityCommitments<T>>::get(&para).map(move |c| (d, c)))
-			.map(|(d, c)| CommittedCandidateReceipt { descriptor: d, commitments: c })
+		<PendingAvailability<T>>::get(&para).and_then(|p| {
+			p.front().map(|p| CommittedCandidateReceipt {
+				descriptor: p.descriptor.clone(),
+				commitments: p.commitments.clone(),
+			})
+		})
 	}
 
-	/// Returns the metadata around the candidate pending availability for the
+	/// Returns the metadata around the first candidate pending availability for the
 	/// para provided, if any.
 	pub(crate) fn pending_availability(
 		para: ParaId,
+	) -> Option<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>> {
+		<PendingAvailability<T>>::get(&para).and_then(|mut p| p.pop_front())
+	}
+
+	/// Returns the metadata around the candidate of the para pending availability on the given
+	/// core, if any.
+	pub(crate) fn pending_availability_with_core(
+		para: ParaId,
+		core: CoreIndex,
 	) -> Option<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>> {
 		<PendingAvailability<T>>::get(&para)
+			.and_then(|p| p.into_iter().find(|candidate| candidate.core == core))
+	}
+
+	/// Returns the metadata around all candidates of the para pending availability, in the order
+	/// of their chain.
+	pub(crate) fn pending_availability_chain(
+		para: ParaId,
+	) -> Vec<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>> {
+		<PendingAvailability<T>>::get(&para).map(Vec::from).unwrap_or_default()
+	}
+
+	/// Returns the head data the next backed candidate of the para has to build upon, along with
+	/// the relay-parent number it must not be older than.
+	///
+	/// These are taken from the last candidate pending availability, if any. Otherwise they are
+	/// the current head of the para and the context it was produced in.
+	pub(crate) fn para_latest_head_and_context(
+		para: ParaId,
+	) -> (Option<HeadData>, Option<BlockNumberFor<T>>) {
+		match <PendingAvailability<T>>::get(&para).and_then(|mut p| p.pop_back()) {
+			Some(last) => (Some(last.commitments.head_data), Some(last.relay_parent_number)),
+			None => (
+				<paras::Pallet<T>>::para_head(&para),
+				<paras::Pallet<T>>::para_most_recent_context(para),
+			),
+		}
 	}
 }
 
@@ -1173,10 +1483,6 @@ pub(crate) struct CandidateCheckContext<T: Config> {
 	prev_context: Option<BlockNumberFor<T>>,
 }
 
-/// An error indicating that creating Persisted Validation Data failed
-/// while checking a candidate's validity.
-pub(crate) struct FailedToCreatePVD;
-
 impl<T: Config> CandidateCheckContext<T> {
 	pub(crate) fn new(prev_context: Option<BlockNumberFor<T>>) -> Self {
 		Self { config: <configuration::Pallet<T>>::config(), prev_context }
@@ -1186,6 +1492,7 @@ impl<T: Config> CandidateCheckContext<T> {
 	///
 	/// Assures:
 	///  * relay-parent in-bounds
+	///  * the candidate builds upon `parent_head_data`
 	///  * collator signature check passes
 	///  * code hash of commitments matches current code hash
 	///  * para head in the descriptor and commitments match
@@ -1196,7 +1503,8 @@ impl<T: Config> CandidateCheckContext<T> {
 		allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
 		candidate_idx: usize,
 		backed_candidate: &BackedCandidate<<T as frame_system::Config>::Hash>,
-	) -> Result<Result<BlockNumberFor<T>, FailedToCreatePVD>, Error<T>> {
+		parent_head_data: HeadData,
+	) -> Result<BlockNumberFor<T>, Error<T>> {
 		let para_id = backed_candidate.descriptor().para_id;
 		let relay_parent = backed_candidate.descriptor().relay_parent;
 
@@ -1209,16 +1517,12 @@ impl<T: Config> CandidateCheckContext<T> {
 		};
 
 		{
-			let persisted_validation_data = match crate::util::make_persisted_validation_data::<T>(
-				para_id,
-				relay_parent_number,
-				relay_parent_storage_root,
-			)
-			.defensive_proof("the para is registered")
-			{
-				Some(l) => l,
-				None => return Ok(Err(FailedToCreatePVD)),
-			};
+			let persisted_validation_data =
+				crate::util::make_persisted_validation_data_with_parent::<T>(
+					relay_parent_number,
+					relay_parent_storage_root,
+					parent_head_data,
+				);
 
 			let expected = persisted_validation_data.hash();
 
@@ -1265,7 +1569,7 @@ impl<T: Config> CandidateCheckContext<T> {
 			);
 			Err(err.strip_into_dispatch_err::<T>())?;
 		};
-		Ok(Ok(relay_parent_number))
+		Ok(relay_parent_number)
 	}
 
 	/// Check the given outputs after candidate validation on whether it passes the acceptance
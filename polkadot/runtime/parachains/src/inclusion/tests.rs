@@ -157,13 +157,17 @@ pub(crate) fn back_candidate(
 
 	let backed = BackedCandidate { candidate, validity_votes, validator_indices };
 
-	let successfully_backed =
-		primitives::check_candidate_backing(&backed, signing_context, group.len(), |i| {
-			Some(validators[group[i].0 as usize].public().into())
-		})
-		.ok()
-		.unwrap_or(0) >=
-			threshold;
+	let successfully_backed = primitives::check_candidate_backing(
+		backed.hash(),
+		&backed.validity_votes,
+		&backed.validator_indices,
+		signing_context,
+		group.len(),
+		|i| Some(validators[group[i].0 as usize].public().into()),
+	)
+	.ok()
+	.unwrap_or(0) >=
+		threshold;
 
 	match kind {
 		BackingKind::Unanimous | BackingKind::Threshold => assert!(successfully_backed),
@@ -354,20 +358,48 @@ fn simple_sanitize_bitfields(
 }
 /// Process a set of already sanitized bitfields.
 pub(crate) fn process_bitfields(
-	expected_bits: usize,
 	signed_bitfields: SignedAvailabilityBitfields,
-	core_lookup: impl Fn(CoreIndex) -> Option<ParaId>,
 ) -> Vec<(CoreIndex, CandidateHash)> {
 	let validators = shared::Pallet::<Test>::active_validator_keys();
 
-	ParaInclusion::update_pending_availability_and_get_freed_cores::<_>(
-		expected_bits,
+	ParaInclusion::update_pending_availability_and_get_freed_cores(
 		&validators[..],
 		signed_bitfields,
-		core_lookup,
 	)
 }
 
+/// The cores scheduled for the given assignments, a single core per para.
+fn scheduled_cores(
+	assignments: impl IntoIterator<Item = (ParaId, CoreIndex)>,
+) -> BTreeMap<ParaId, BTreeSet<CoreIndex>> {
+	assignments
+		.into_iter()
+		.map(|(para_id, core)| (para_id, [core].into()))
+		.collect()
+}
+
+/// Group backed candidates by para, keeping their order.
+fn candidates_with_cores(
+	candidates: impl IntoIterator<Item = (BackedCandidate, CoreIndex)>,
+) -> BTreeMap<ParaId, Vec<(BackedCandidate, CoreIndex)>> {
+	let mut candidates_with_cores = BTreeMap::<_, Vec<_>>::new();
+	for (candidate, core) in candidates {
+		candidates_with_cores
+			.entry(candidate.descriptor().para_id)
+			.or_default()
+			.push((candidate, core));
+	}
+	candidates_with_cores
+}
+
+/// Insert candidates pending availability of a para, in the order of their chain.
+fn insert_pending_availability(
+	para_id: ParaId,
+	candidates: impl IntoIterator<Item = CandidatePendingAvailability<Hash, BlockNumber>>,
+) {
+	<PendingAvailability<Test>>::insert(para_id, candidates.into_iter().collect::<VecDeque<_>>());
+}
+
 #[test]
 fn collect_pending_cleans_up_pending() {
 	let chain_a = ParaId::from(1_u32);
@@ -383,52 +415,110 @@ fn collect_pending_cleans_up_pending() {
 	config.configuration.config.group_rotation_frequency = 3;
 	new_test_ext(config).execute_with(|| {
 		let default_candidate = TestCandidateBuilder::default().build();
-		<PendingAvailability<Test>>::insert(
+		let timed_out = DroppedCandidate {
+			candidate_hash: default_candidate.hash(),
+			para_id: default_candidate.descriptor.para_id,
+			reason: CandidateDropReason::TimedOut,
+		};
+		insert_pending_availability(
 			chain_a,
-			CandidatePendingAvailability {
+			[CandidatePendingAvailability {
 				core: CoreIndex::from(0),
 				hash: default_candidate.hash(),
 				descriptor: default_candidate.descriptor.clone(),
+				commitments: default_candidate.commitments.clone(),
 				availability_votes: default_availability_votes(),
 				relay_parent_number: 0,
 				backed_in_number: 0,
 				backers: default_backing_bitfield(),
 				backing_group: GroupIndex::from(0),
-			},
-		);
-		PendingAvailabilityCommitments::<Test>::insert(
-			chain_a,
-			default_candidate.commitments.clone(),
+			}],
 		);
 
-		<PendingAvailability<Test>>::insert(
-			&chain_b,
-			CandidatePendingAvailability {
+		insert_pending_availability(
+			chain_b,
+			[CandidatePendingAvailability {
 				core: CoreIndex::from(1),
 				hash: default_candidate.hash(),
 				descriptor: default_candidate.descriptor,
+				commitments: default_candidate.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: 0,
 				backed_in_number: 5,
 				backers: default_backing_bitfield(),
 				backing_group: GroupIndex::from(1),
-			},
+			}],
 		);
-		PendingAvailabilityCommitments::<Test>::insert(chain_b, default_candidate.commitments);
 
 		run_to_block(5, |_| None);
 
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_some());
 		assert!(<PendingAvailability<Test>>::get(&chain_b).is_some());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_some());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_some());
 
 		ParaInclusion::collect_pending(Scheduler::availability_timeout_predicate());
 
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_none());
 		assert!(<PendingAvailability<Test>>::get(&chain_b).is_some());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_none());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_some());
+		assert_eq!(ParaInclusion::dropped_candidates(), vec![timed_out]);
+	});
+}
+
+#[test]
+fn dropped_pending_candidates_are_noted() {
+	let chain_a = ParaId::from(1_u32);
+	let chain_b = ParaId::from(2_u32);
+
+	let paras = vec![(chain_a, ParaKind::Parachain), (chain_b, ParaKind::Parachain)];
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		let pending = |para_id: ParaId, pov_hash: u8, core: u32| {
+			let candidate = TestCandidateBuilder {
+				para_id,
+				pov_hash: Hash::repeat_byte(pov_hash),
+				..Default::default()
+			}
+			.build();
+			CandidatePendingAvailability {
+				core: CoreIndex::from(core),
+				hash: candidate.hash(),
+				descriptor: candidate.descriptor,
+				commitments: candidate.commitments,
+				availability_votes: default_availability_votes(),
+				relay_parent_number: 0,
+				backed_in_number: 0,
+				backers: default_backing_bitfield(),
+				backing_group: GroupIndex::from(core),
+			}
+		};
+		let dropped = |candidate: &CandidatePendingAvailability<Hash, BlockNumber>,
+		               reason: CandidateDropReason| DroppedCandidate {
+			candidate_hash: candidate.hash,
+			para_id: candidate.descriptor.para_id,
+			reason,
+		};
+
+		let a_1 = pending(chain_a, 1, 0);
+		let a_2 = pending(chain_a, 2, 2);
+		let b_1 = pending(chain_b, 3, 1);
+		let expected = vec![
+			dropped(&a_1, CandidateDropReason::ConcludedInvalid),
+			dropped(&a_2, CandidateDropReason::PredecessorDropped),
+		];
+		let expected_on_session_change = vec![dropped(&b_1, CandidateDropReason::SessionChange)];
+		insert_pending_availability(chain_a, [a_1.clone(), a_2]);
+		insert_pending_availability(chain_b, [b_1]);
+
+		// The successors of a disputed candidate are dropped along with it.
+		let cores = ParaInclusion::collect_disputed(&[a_1.hash].into_iter().collect());
+		assert_eq!(cores, vec![CoreIndex::from(0), CoreIndex::from(2)]);
+		assert_eq!(ParaInclusion::dropped_candidates(), expected);
+
+		// Dropped candidates are only kept for the block they were dropped in.
+		run_to_block(2, |_| None);
+		assert!(ParaInclusion::dropped_candidates().is_empty());
+
+		ParaInclusion::initializer_on_new_session(&Default::default(), &[]);
+		assert_eq!(ParaInclusion::dropped_candidates(), expected_on_session_change);
+		assert!(<PendingAvailability<Test>>::get(&chain_b).is_none());
 	});
 }
 
@@ -468,14 +558,6 @@ fn bitfield_checks() {
 		let signing_context =
 			SigningContext { parent_hash: System::parent_hash(), session_index: 5 };
 
-		let core_lookup = |core| match core {
-			core if core == CoreIndex::from(0) => Some(chain_a),
-			core if core == CoreIndex::from(1) => Some(chain_b),
-			core if core == CoreIndex::from(2) => Some(thread_a),
-			core if core == CoreIndex::from(3) => None, // for the expected_cores() + 1 test below.
-			_ => panic!("out of bounds for testing"),
-		};
-
 		// too many bits in bitfield
 		{
 			let mut bare_bitfield = default_bitfield();
@@ -544,7 +626,7 @@ fn bitfield_checks() {
 			);
 			assert_eq!(checked_bitfields.len(), 1, "No bitfields should have been filtered!");
 
-			let x = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
+			let x = process_bitfields(checked_bitfields);
 			assert!(x.is_empty(), "No core should be freed.");
 		}
 
@@ -565,7 +647,7 @@ fn bitfield_checks() {
 			);
 			assert_eq!(checked_bitfields.len(), 1, "No bitfields should have been filtered!");
 
-			let x = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
+			let x = process_bitfields(checked_bitfields);
 			assert!(x.is_empty(), "No core should be freed.");
 		}
 
@@ -573,23 +655,21 @@ fn bitfield_checks() {
 		{
 			let mut bare_bitfield = default_bitfield();
 
-			assert_eq!(core_lookup(CoreIndex::from(0)), Some(chain_a));
-
 			let default_candidate = TestCandidateBuilder::default().build();
-			<PendingAvailability<Test>>::insert(
+			insert_pending_availability(
 				chain_a,
-				CandidatePendingAvailability {
+				[CandidatePendingAvailability {
 					core: CoreIndex::from(0),
 					hash: default_candidate.hash(),
 					descriptor: default_candidate.descriptor,
+					commitments: default_candidate.commitments,
 					availability_votes: default_availability_votes(),
 					relay_parent_number: 0,
 					backed_in_number: 0,
 					backers: default_backing_bitfield(),
 					backing_group: GroupIndex::from(0),
-				},
+				}],
 			);
-			PendingAvailabilityCommitments::<Test>::insert(chain_a, default_candidate.commitments);
 
 			*bare_bitfield.0.get_mut(0).unwrap() = true;
 			let signed = sign_bitfield(
@@ -607,53 +687,10 @@ fn bitfield_checks() {
 			);
 			assert_eq!(checked_bitfields.len(), 1, "No bitfields should have been filtered!");
 
-			let x = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
+			let x = process_bitfields(checked_bitfields);
 			assert!(x.is_empty(), "No core should be freed.");
 
 			<PendingAvailability<Test>>::remove(chain_a);
-			PendingAvailabilityCommitments::<Test>::remove(chain_a);
-		}
-
-		// bitfield signed with pending bit signed, but no commitments.
-		{
-			let mut bare_bitfield = default_bitfield();
-
-			assert_eq!(core_lookup(CoreIndex::from(0)), Some(chain_a));
-
-			let default_candidate = TestCandidateBuilder::default().build();
-			<PendingAvailability<Test>>::insert(
-				chain_a,
-				CandidatePendingAvailability {
-					core: CoreIndex::from(0),
-					hash: default_candidate.hash(),
-					descriptor: default_candidate.descriptor,
-					availability_votes: default_availability_votes(),
-					relay_parent_number: 0,
-					backed_in_number: 0,
-					backers: default_backing_bitfield(),
-					backing_group: GroupIndex::from(0),
-				},
-			);
-
-			*bare_bitfield.0.get_mut(0).unwrap() = true;
-			let signed = sign_bitfield(
-				&keystore,
-				&validators[0],
-				ValidatorIndex(0),
-				bare_bitfield,
-				&signing_context,
-			);
-
-			let checked_bitfields = simple_sanitize_bitfields(
-				vec![signed.into()],
-				DisputedBitfield::zeros(expected_bits()),
-				expected_bits(),
-			);
-			assert_eq!(checked_bitfields.len(), 1, "No bitfields should have been filtered!");
-
-			let x = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
-			// no core is freed
-			assert!(x.is_empty(), "No core should be freed.");
 		}
 	});
 }
@@ -701,13 +738,6 @@ fn supermajority_bitfields_trigger_availability() {
 		let signing_context =
 			SigningContext { parent_hash: System::parent_hash(), session_index: 5 };
 
-		let core_lookup = |core| match core {
-			core if core == CoreIndex::from(0) => Some(chain_a),
-			core if core == CoreIndex::from(1) => Some(chain_b),
-			core if core == CoreIndex::from(2) => Some(thread_a),
-			_ => panic!("Core out of bounds for 2 parachains and 1 parathread core."),
-		};
-
 		let candidate_a = TestCandidateBuilder {
 			para_id: chain_a,
 			head_data: vec![1, 2, 3, 4].into(),
@@ -715,20 +745,20 @@ fn supermajority_bitfields_trigger_availability() {
 		}
 		.build();
 
-		<PendingAvailability<Test>>::insert(
+		insert_pending_availability(
 			chain_a,
-			CandidatePendingAvailability {
+			[CandidatePendingAvailability {
 				core: CoreIndex::from(0),
 				hash: candidate_a.hash(),
 				descriptor: candidate_a.clone().descriptor,
+				commitments: candidate_a.clone().commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: 0,
 				backed_in_number: 0,
 				backers: backing_bitfield(&[3, 4]),
 				backing_group: GroupIndex::from(0),
-			},
+			}],
 		);
-		PendingAvailabilityCommitments::<Test>::insert(chain_a, candidate_a.clone().commitments);
 
 		let candidate_b = TestCandidateBuilder {
 			para_id: chain_b,
@@ -737,20 +767,20 @@ fn supermajority_bitfields_trigger_availability() {
 		}
 		.build();
 
-		<PendingAvailability<Test>>::insert(
+		insert_pending_availability(
 			chain_b,
-			CandidatePendingAvailability {
+			[CandidatePendingAvailability {
 				core: CoreIndex::from(1),
 				hash: candidate_b.hash(),
 				descriptor: candidate_b.descriptor,
+				commitments: candidate_b.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: 0,
 				backed_in_number: 0,
 				backers: backing_bitfield(&[0, 2]),
 				backing_group: GroupIndex::from(1),
-			},
+			}],
 		);
-		PendingAvailabilityCommitments::<Test>::insert(chain_b, candidate_b.commitments);
 
 		// this bitfield signals that a and b are available.
 		let a_and_b_available = {
@@ -809,15 +839,13 @@ fn supermajority_bitfields_trigger_availability() {
 		assert_eq!(checked_bitfields.len(), old_len, "No bitfields should have been filtered!");
 
 		// only chain A's core is freed.
-		let v = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
+		let v = process_bitfields(checked_bitfields);
 		assert_eq!(vec![(CoreIndex(0), candidate_a.hash())], v);
 
 		// chain A had 4 signing off, which is >= threshold.
 		// chain B has 3 signing off, which is < threshold.
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_none());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_none());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_some());
-		assert_eq!(<PendingAvailability<Test>>::get(&chain_b).unwrap().availability_votes, {
+		assert_eq!(<PendingAvailability<Test>>::get(&chain_b).unwrap()[0].availability_votes, {
 			// check that votes from first 3 were tracked.
 
 			let mut votes = default_availability_votes();
@@ -944,69 +972,15 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_b_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_b_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::UnscheduledCandidate
 			);
 		}
 
-		// candidates out of order.
-		{
-			let mut candidate_a = TestCandidateBuilder {
-				para_id: chain_a,
-				relay_parent: System::parent_hash(),
-				pov_hash: Hash::repeat_byte(1),
-				persisted_validation_data_hash: make_vdata_hash(chain_a).unwrap(),
-				hrmp_watermark: RELAY_PARENT_NUM,
-				..Default::default()
-			}
-			.build();
-			let mut candidate_b = TestCandidateBuilder {
-				para_id: chain_b,
-				relay_parent: System::parent_hash(),
-				pov_hash: Hash::repeat_byte(2),
-				persisted_validation_data_hash: make_vdata_hash(chain_b).unwrap(),
-				hrmp_watermark: RELAY_PARENT_NUM,
-				..Default::default()
-			}
-			.build();
-
-			collator_sign_candidate(Sr25519Keyring::One, &mut candidate_a);
-
-			collator_sign_candidate(Sr25519Keyring::Two, &mut candidate_b);
-
-			let backed_a = back_candidate(
-				candidate_a,
-				&validators,
-				group_validators(GroupIndex::from(0)).unwrap().as_ref(),
-				&keystore,
-				&signing_context,
-				BackingKind::Threshold,
-			);
-
-			let backed_b = back_candidate(
-				candidate_b,
-				&validators,
-				group_validators(GroupIndex::from(1)).unwrap().as_ref(),
-				&keystore,
-				&signing_context,
-				BackingKind::Threshold,
-			);
-
-			// out-of-order manifests as unscheduled.
-			assert_noop!(
-				ParaInclusion::process_candidates(
-					&allowed_relay_parents,
-					vec![backed_b, backed_a],
-					&[chain_a_assignment, chain_b_assignment].into_iter().collect(),
-					&group_validators,
-				),
-				Error::<Test>::ScheduledOutOfOrder
-			);
-		}
-
 		// candidate not backed.
 		{
 			let mut candidate = TestCandidateBuilder {
@@ -1032,9 +1006,10 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::InsufficientBacking
 			);
@@ -1089,9 +1064,13 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed_b, backed_a],
-					&[chain_a_assignment, chain_b_assignment].into_iter().collect(),
+					&candidates_with_cores([
+						(backed_b, chain_b_assignment.1),
+						(backed_a, chain_a_assignment.1),
+					]),
+					&scheduled_cores([chain_a_assignment, chain_b_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::DisallowedRelayParent
 			);
@@ -1127,9 +1106,10 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[thread_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, thread_a_assignment.1)]),
+					&scheduled_cores([thread_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::NotCollatorSigned
 			);
@@ -1159,72 +1139,33 @@ fn candidate_checks() {
 			);
 
 			let candidate = TestCandidateBuilder::default().build();
-			<PendingAvailability<Test>>::insert(
-				&chain_a,
-				CandidatePendingAvailability {
+			insert_pending_availability(
+				chain_a,
+				[CandidatePendingAvailability {
 					core: CoreIndex::from(0),
 					hash: candidate.hash(),
 					descriptor: candidate.descriptor,
+					commitments: candidate.commitments,
 					availability_votes: default_availability_votes(),
 					relay_parent_number: 3,
 					backed_in_number: 4,
 					backers: default_backing_bitfield(),
 					backing_group: GroupIndex::from(0),
-				},
+				}],
 			);
-			<PendingAvailabilityCommitments<Test>>::insert(&chain_a, candidate.commitments);
 
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::CandidateScheduledBeforeParaFree
 			);
 
 			<PendingAvailability<Test>>::remove(&chain_a);
-			<PendingAvailabilityCommitments<Test>>::remove(&chain_a);
-		}
-
-		// messed up commitments storage - do not panic - reject.
-		{
-			let mut candidate = TestCandidateBuilder {
-				para_id: chain_a,
-				relay_parent: System::parent_hash(),
-				pov_hash: Hash::repeat_byte(1),
-				persisted_validation_data_hash: make_vdata_hash(chain_a).unwrap(),
-				hrmp_watermark: RELAY_PARENT_NUM,
-				..Default::default()
-			}
-			.build();
-
-			collator_sign_candidate(Sr25519Keyring::One, &mut candidate);
-
-			// this is not supposed to happen
-			<PendingAvailabilityCommitments<Test>>::insert(&chain_a, candidate.commitments.clone());
-
-			let backed = back_candidate(
-				candidate,
-				&validators,
-				group_validators(GroupIndex::from(0)).unwrap().as_ref(),
-				&keystore,
-				&signing_context,
-				BackingKind::Threshold,
-			);
-
-			assert_noop!(
-				ParaInclusion::process_candidates(
-					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
-					&group_validators,
-				),
-				Error::<Test>::CandidateScheduledBeforeParaFree
-			);
-
-			<PendingAvailabilityCommitments<Test>>::remove(&chain_a);
 		}
 
 		// interfering code upgrade - reject
@@ -1267,9 +1208,10 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::PrematureCodeUpgrade
 			);
@@ -1301,9 +1243,10 @@ fn candidate_checks() {
 			assert_eq!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Err(Error::<Test>::ValidationDataHashMismatch.into()),
 			);
@@ -1336,9 +1279,10 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::InvalidValidationCodeHash
 			);
@@ -1371,9 +1315,10 @@ fn candidate_checks() {
 			assert_noop!(
 				ParaInclusion::process_candidates(
 					&allowed_relay_parents,
-					vec![backed],
-					&[chain_a_assignment].into_iter().collect(),
+					&candidates_with_cores([(backed, chain_a_assignment.1)]),
+					&scheduled_cores([chain_a_assignment]),
 					&group_validators,
+					false,
 				),
 				Error::<Test>::ParaHeadMismatch
 			);
@@ -1531,11 +1476,14 @@ fn backing_works() {
 			candidate_receipt_with_backing_validator_indices,
 		} = ParaInclusion::process_candidates(
 			&allowed_relay_parents,
-			backed_candidates.clone(),
-			&[chain_a_assignment, chain_b_assignment, thread_a_assignment]
-				.into_iter()
-				.collect(),
+			&candidates_with_cores(backed_candidates.clone().into_iter().zip([
+				chain_a_assignment.1,
+				chain_b_assignment.1,
+				thread_a_assignment.1,
+			])),
+			&scheduled_cores([chain_a_assignment, chain_b_assignment, thread_a_assignment]),
 			&group_validators,
+			false,
 		)
 		.expect("candidates scheduled, in order, and backed");
 
@@ -1605,20 +1553,17 @@ fn backing_works() {
 		};
 		assert_eq!(
 			<PendingAvailability<Test>>::get(&chain_a),
-			Some(CandidatePendingAvailability {
+			Some(VecDeque::from([CandidatePendingAvailability {
 				core: CoreIndex::from(0),
 				hash: candidate_a.hash(),
 				descriptor: candidate_a.descriptor,
+				commitments: candidate_a.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: System::block_number() - 1,
 				backed_in_number: System::block_number(),
 				backers,
 				backing_group: GroupIndex::from(0),
-			})
-		);
-		assert_eq!(
-			<PendingAvailabilityCommitments<Test>>::get(&chain_a),
-			Some(candidate_a.commitments),
+			}]))
 		);
 
 		let backers = {
@@ -1630,38 +1575,32 @@ fn backing_works() {
 		};
 		assert_eq!(
 			<PendingAvailability<Test>>::get(&chain_b),
-			Some(CandidatePendingAvailability {
+			Some(VecDeque::from([CandidatePendingAvailability {
 				core: CoreIndex::from(1),
 				hash: candidate_b.hash(),
 				descriptor: candidate_b.descriptor,
+				commitments: candidate_b.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: System::block_number() - 1,
 				backed_in_number: System::block_number(),
 				backers,
 				backing_group: GroupIndex::from(1),
-			})
-		);
-		assert_eq!(
-			<PendingAvailabilityCommitments<Test>>::get(&chain_b),
-			Some(candidate_b.commitments),
+			}]))
 		);
 
 		assert_eq!(
 			<PendingAvailability<Test>>::get(&thread_a),
-			Some(CandidatePendingAvailability {
+			Some(VecDeque::from([CandidatePendingAvailability {
 				core: CoreIndex::from(2),
 				hash: candidate_c.hash(),
 				descriptor: candidate_c.descriptor,
+				commitments: candidate_c.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: System::block_number() - 1,
 				backed_in_number: System::block_number(),
 				backers: backing_bitfield(&[4]),
 				backing_group: GroupIndex::from(2),
-			})
-		);
-		assert_eq!(
-			<PendingAvailabilityCommitments<Test>>::get(&thread_a),
-			Some(candidate_c.commitments),
+			}]))
 		);
 	});
 }
@@ -1745,9 +1684,10 @@ fn can_include_candidate_with_ok_code_upgrade() {
 		let ProcessedCandidates { core_indices: occupied_cores, .. } =
 			ParaInclusion::process_candidates(
 				&allowed_relay_parents,
-				vec![backed_a],
-				&[chain_a_assignment].into_iter().collect(),
+				&candidates_with_cores([(backed_a, chain_a_assignment.1)]),
+				&scheduled_cores([chain_a_assignment]),
 				&group_validators,
+				false,
 			)
 			.expect("candidates scheduled, in order, and backed");
 
@@ -1762,21 +1702,243 @@ fn can_include_candidate_with_ok_code_upgrade() {
 		};
 		assert_eq!(
 			<PendingAvailability<Test>>::get(&chain_a),
-			Some(CandidatePendingAvailability {
+			Some(VecDeque::from([CandidatePendingAvailability {
 				core: CoreIndex::from(0),
 				hash: candidate_a.hash(),
 				descriptor: candidate_a.descriptor,
+				commitments: candidate_a.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: System::block_number() - 1,
 				backed_in_number: System::block_number(),
 				backers,
 				backing_group: GroupIndex::from(0),
-			})
+			}]))
+		);
+	});
+}
+
+#[test]
+fn chained_candidates_of_a_para_are_backed_and_included_in_order() {
+	let chain_a = ParaId::from(1_u32);
+
+	// The block number of the relay-parent for testing.
+	const RELAY_PARENT_NUM: BlockNumber = 4;
+
+	let paras = vec![(chain_a, ParaKind::Parachain)];
+	let validators = vec![
+		Sr25519Keyring::Alice,
+		Sr25519Keyring::Bob,
+		Sr25519Keyring::Charlie,
+		Sr25519Keyring::Dave,
+		Sr25519Keyring::Ferdie,
+	];
+	let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+	for validator in validators.iter() {
+		Keystore::sr25519_generate_new(
+			&*keystore,
+			PARACHAIN_KEY_TYPE_ID,
+			Some(&validator.to_seed()),
+		)
+		.unwrap();
+	}
+	let validator_public = validator_pubkeys(&validators);
+
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		shared::Pallet::<Test>::set_active_validators_ascending(validator_public.clone());
+		shared::Pallet::<Test>::set_session_index(5);
+
+		run_to_block(5, |_| None);
+
+		let signing_context =
+			SigningContext { parent_hash: System::parent_hash(), session_index: 5 };
+
+		let group_validators = |group_index: GroupIndex| {
+			match group_index {
+				group_index if group_index == GroupIndex::from(0) => Some(vec![0, 1, 2]),
+				group_index if group_index == GroupIndex::from(1) => Some(vec![3, 4]),
+				_ => panic!("Group index out of bounds for 2 cores"),
+			}
+			.map(|vs| vs.into_iter().map(ValidatorIndex).collect::<Vec<_>>())
+		};
+
+		// When processing candidates, we compute the group index from scheduler.
+		let validator_groups = vec![
+			vec![ValidatorIndex(0), ValidatorIndex(1), ValidatorIndex(2)],
+			vec![ValidatorIndex(3), ValidatorIndex(4)],
+		];
+		Scheduler::set_validator_groups(validator_groups);
+
+		let allowed_relay_parents = default_allowed_relay_parent_tracker();
+
+		// Both cores are assigned to chain A.
+		let scheduled: BTreeMap<_, _> =
+			[(chain_a, [CoreIndex::from(0), CoreIndex::from(1)].into())]
+				.into_iter()
+				.collect();
+
+		let mut candidate_a = TestCandidateBuilder {
+			para_id: chain_a,
+			relay_parent: System::parent_hash(),
+			pov_hash: Hash::repeat_byte(1),
+			persisted_validation_data_hash: make_vdata_hash(chain_a).unwrap(),
+			head_data: vec![1].into(),
+			hrmp_watermark: RELAY_PARENT_NUM,
+			..Default::default()
+		}
+		.build();
+		collator_sign_candidate(Sr25519Keyring::One, &mut candidate_a);
+
+		// Candidate B builds upon the head data of candidate A.
+		let mut candidate_b = TestCandidateBuilder {
+			para_id: chain_a,
+			relay_parent: System::parent_hash(),
+			pov_hash: Hash::repeat_byte(2),
+			persisted_validation_data_hash:
+				crate::util::make_persisted_validation_data_with_parent::<Test>(
+					RELAY_PARENT_NUM,
+					Default::default(),
+					vec![1].into(),
+				)
+				.hash(),
+			head_data: vec![2].into(),
+			hrmp_watermark: RELAY_PARENT_NUM,
+			..Default::default()
+		}
+		.build();
+		collator_sign_candidate(Sr25519Keyring::One, &mut candidate_b);
+
+		let with_core_index = |backed: BackedCandidate, core: CoreIndex| {
+			BackedCandidate::new(
+				backed.candidate,
+				backed.validity_votes,
+				backed.validator_indices,
+				Some(core),
+			)
+		};
+		let backed_a = with_core_index(
+			back_candidate(
+				candidate_a.clone(),
+				&validators,
+				group_validators(GroupIndex::from(0)).unwrap().as_ref(),
+				&keystore,
+				&signing_context,
+				BackingKind::Threshold,
+			),
+			CoreIndex::from(0),
+		);
+		let backed_b = with_core_index(
+			back_candidate(
+				candidate_b.clone(),
+				&validators,
+				group_validators(GroupIndex::from(1)).unwrap().as_ref(),
+				&keystore,
+				&signing_context,
+				BackingKind::Threshold,
+			),
+			CoreIndex::from(1),
+		);
+
+		// Candidate B doesn't build upon the para head.
+		assert_noop!(
+			ParaInclusion::process_candidates(
+				&allowed_relay_parents,
+				&candidates_with_cores([
+					(backed_b.clone(), CoreIndex::from(1)),
+					(backed_a.clone(), CoreIndex::from(0)),
+				]),
+				&scheduled,
+				&group_validators,
+				true,
+			),
+			Error::<Test>::ValidationDataHashMismatch
+		);
+
+		// Both candidates can't occupy the same core.
+		assert_noop!(
+			ParaInclusion::process_candidates(
+				&allowed_relay_parents,
+				&candidates_with_cores([
+					(backed_a.clone(), CoreIndex::from(0)),
+					(backed_b.clone(), CoreIndex::from(0)),
+				]),
+				&scheduled,
+				&group_validators,
+				true,
+			),
+			Error::<Test>::DuplicateCoreIndex
+		);
+
+		let ProcessedCandidates { core_indices: occupied_cores, .. } =
+			ParaInclusion::process_candidates(
+				&allowed_relay_parents,
+				&candidates_with_cores([
+					(backed_a, CoreIndex::from(0)),
+					(backed_b, CoreIndex::from(1)),
+				]),
+				&scheduled,
+				&group_validators,
+				true,
+			)
+			.expect("candidates scheduled, in order, and backed");
+
+		assert_eq!(
+			occupied_cores,
+			vec![(CoreIndex::from(0), chain_a), (CoreIndex::from(1), chain_a)]
 		);
 		assert_eq!(
-			<PendingAvailabilityCommitments<Test>>::get(&chain_a),
-			Some(candidate_a.commitments),
+			ParaInclusion::pending_availability_chain(chain_a)
+				.into_iter()
+				.map(|candidate| (candidate.core_occupied(), candidate.candidate_hash()))
+				.collect::<Vec<_>>(),
+			vec![
+				(CoreIndex::from(0), candidate_a.hash()),
+				(CoreIndex::from(1), candidate_b.hash())
+			],
+		);
+
+		let sign_bitfields = |cores: &[usize]| {
+			let mut bare_bitfield = default_bitfield();
+			for core in cores {
+				*bare_bitfield.0.get_mut(*core).unwrap() = true;
+			}
+
+			let signed_bitfields = validators
+				.iter()
+				.enumerate()
+				.map(|(i, key)| {
+					sign_bitfield(
+						&keystore,
+						key,
+						ValidatorIndex(i as _),
+						bare_bitfield.clone(),
+						&signing_context,
+					)
+					.into()
+				})
+				.collect::<Vec<_>>();
+
+			simple_sanitize_bitfields(
+				signed_bitfields,
+				DisputedBitfield::zeros(expected_bits()),
+				expected_bits(),
+			)
+		};
+
+		// Candidate B is available, but can't be enacted before candidate A.
+		assert!(process_bitfields(sign_bitfields(&[1])).is_empty());
+		assert_eq!(ParaInclusion::pending_availability_chain(chain_a).len(), 2);
+		assert_eq!(Paras::para_head(&chain_a), Some(Default::default()));
+
+		// Once candidate A is available, both are enacted in order.
+		assert_eq!(
+			process_bitfields(sign_bitfields(&[0])),
+			vec![
+				(CoreIndex::from(0), candidate_a.hash()),
+				(CoreIndex::from(1), candidate_b.hash()),
+			],
 		);
+		assert!(<PendingAvailability<Test>>::get(&chain_a).is_none());
+		assert_eq!(Paras::para_head(&chain_a), Some(vec![2].into()));
 	});
 }
 
@@ -1956,11 +2118,14 @@ fn check_allowed_relay_parents() {
 
 		ParaInclusion::process_candidates(
 			&allowed_relay_parents,
-			backed_candidates.clone(),
-			&[chain_a_assignment, chain_b_assignment, thread_a_assignment]
-				.into_iter()
-				.collect(),
+			&candidates_with_cores(backed_candidates.clone().into_iter().zip([
+				chain_a_assignment.1,
+				chain_b_assignment.1,
+				thread_a_assignment.1,
+			])),
+			&scheduled_cores([chain_a_assignment, chain_b_assignment, thread_a_assignment]),
 			&group_validators,
+			false,
 		)
 		.expect("candidates scheduled, in order, and backed");
 	});
@@ -2022,35 +2187,35 @@ fn session_change_wipes() {
 		);
 
 		let candidate = TestCandidateBuilder::default().build();
-		<PendingAvailability<Test>>::insert(
-			&chain_a,
-			CandidatePendingAvailability {
+		insert_pending_availability(
+			chain_a,
+			[CandidatePendingAvailability {
 				core: CoreIndex::from(0),
 				hash: candidate.hash(),
 				descriptor: candidate.descriptor.clone(),
+				commitments: candidate.commitments.clone(),
 				availability_votes: default_availability_votes(),
 				relay_parent_number: 5,
 				backed_in_number: 6,
 				backers: default_backing_bitfield(),
 				backing_group: GroupIndex::from(0),
-			},
+			}],
 		);
-		<PendingAvailabilityCommitments<Test>>::insert(&chain_a, candidate.commitments.clone());
 
-		<PendingAvailability<Test>>::insert(
-			&chain_b,
-			CandidatePendingAvailability {
+		insert_pending_availability(
+			chain_b,
+			[CandidatePendingAvailability {
 				core: CoreIndex::from(1),
 				hash: candidate.hash(),
 				descriptor: candidate.descriptor,
+				commitments: candidate.commitments,
 				availability_votes: default_availability_votes(),
 				relay_parent_number: 6,
 				backed_in_number: 7,
 				backers: default_backing_bitfield(),
 				backing_group: GroupIndex::from(1),
-			},
+			}],
 		);
-		<PendingAvailabilityCommitments<Test>>::insert(&chain_b, candidate.commitments);
 
 		run_to_block(11, |_| None);
 
@@ -2062,8 +2227,6 @@ fn session_change_wipes() {
 
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_some());
 		assert!(<PendingAvailability<Test>>::get(&chain_b).is_some());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_some());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_some());
 
 		run_to_block(12, |n| match n {
 			12 => Some(SessionChangeNotification {
@@ -2085,12 +2248,9 @@ fn session_change_wipes() {
 
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_none());
 		assert!(<PendingAvailability<Test>>::get(&chain_b).is_none());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_none());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_none());
 
 		assert!(<AvailabilityBitfields<Test>>::iter().collect::<Vec<_>>().is_empty());
 		assert!(<PendingAvailability<Test>>::iter().collect::<Vec<_>>().is_empty());
-		assert!(<PendingAvailabilityCommitments<Test>>::iter().collect::<Vec<_>>().is_empty());
 	});
 }
 
@@ -2162,11 +2322,6 @@ fn para_upgrade_delay_scheduled_from_inclusion() {
 		]];
 		Scheduler::set_validator_groups(validator_groups);
 
-		let core_lookup = |core| match core {
-			core if core == CoreIndex::from(0) => Some(chain_a),
-			_ => None,
-		};
-
 		let allowed_relay_parents = default_allowed_relay_parent_tracker();
 
 		let chain_a_assignment = (chain_a, CoreIndex::from(0));
@@ -2194,9 +2349,10 @@ fn para_upgrade_delay_scheduled_from_inclusion() {
 		let ProcessedCandidates { core_indices: occupied_cores, .. } =
 			ParaInclusion::process_candidates(
 				&allowed_relay_parents,
-				vec![backed_a],
-				&[chain_a_assignment].into_iter().collect(),
+				&candidates_with_cores([(backed_a, chain_a_assignment.1)]),
+				&scheduled_cores([chain_a_assignment]),
 				&group_validators,
+				false,
 			)
 			.expect("candidates scheduled, in order, and backed");
 
@@ -2229,11 +2385,10 @@ fn para_upgrade_delay_scheduled_from_inclusion() {
 			expected_bits(),
 		);
 
-		let v = process_bitfields(expected_bits(), checked_bitfields, core_lookup);
+		let v = process_bitfields(checked_bitfields);
 		assert_eq!(vec![(CoreIndex(0), candidate_a.hash())], v);
 
 		assert!(<PendingAvailability<Test>>::get(&chain_a).is_none());
-		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_none());
 
 		let active_vote_state = paras::Pallet::<Test>::active_vote_state(&new_validation_code_hash)
 			.expect("prechecking must be initiated");
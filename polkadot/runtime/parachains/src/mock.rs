@@ -192,6 +192,7 @@ impl crate::initializer::Config for Test {
 
 impl crate::configuration::Config for Test {
 	type WeightInfo = crate::configuration::TestWeightInfo;
+	type MaxUmpMessageLen = crate::inclusion::MaxUmpMessageLenOf<Test>;
 }
 
 impl crate::shared::Config for Test {}
@@ -256,6 +257,7 @@ thread_local! {
 	pub static PUNISH_VALIDATORS_FOR: RefCell<Vec<(SessionIndex, Vec<ValidatorIndex>)>> = RefCell::new(Vec::new());
 	pub static PUNISH_VALIDATORS_AGAINST: RefCell<Vec<(SessionIndex, Vec<ValidatorIndex>)>> = RefCell::new(Vec::new());
 	pub static PUNISH_BACKERS_FOR: RefCell<Vec<(SessionIndex, Vec<ValidatorIndex>)>> = RefCell::new(Vec::new());
+	pub static PUNISH_BACKERS_OF_INVALID: RefCell<Vec<(SessionIndex, CandidateHash, Vec<ValidatorIndex>)>> = RefCell::new(Vec::new());
 }
 
 impl crate::disputes::RewardValidators for Test {
@@ -289,6 +291,15 @@ impl crate::disputes::SlashingHandler<BlockNumber> for Test {
 			.with(|r| r.borrow_mut().push((session, losers.into_iter().collect())))
 	}
 
+	fn punish_backers_of_invalid(
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		backers: impl IntoIterator<Item = ValidatorIndex>,
+	) {
+		PUNISH_BACKERS_OF_INVALID
+			.with(|r| r.borrow_mut().push((session, candidate_hash, backers.into_iter().collect())))
+	}
+
 	fn initializer_initialize(_now: BlockNumber) -> Weight {
 		Weight::zero()
 	}
@@ -355,6 +366,7 @@ impl pallet_message_queue::Config for Test {
 
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
+	pub const MaxHistoricalRevenue: u32 = 2 * 5;
 }
 
 impl assigner_parachains::Config for Test {}
@@ -363,6 +375,7 @@ impl assigner_on_demand::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
+	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type WeightInfo = crate::assigner_on_demand::TestWeightInfo;
 }
 
@@ -403,6 +416,8 @@ impl crate::inclusion::Config for Test {
 	type DisputesHandler = Disputes;
 	type RewardValidators = TestRewardValidators;
 	type MessageQueue = MessageQueue;
+	type MaxFragmentedUpwardMessages = ConstU32<2>;
+	type UpwardMessageFragmentTimeout = ConstU32<10>;
 }
 
 impl crate::paras_inherent::Config for Test {
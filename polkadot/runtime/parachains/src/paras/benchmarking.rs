@@ -16,7 +16,7 @@
 
 use super::*;
 use crate::configuration::HostConfiguration;
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use primitives::{HeadData, Id as ParaId, ValidationCode, MAX_CODE_SIZE, MAX_HEAD_DATA_SIZE};
 use sp_runtime::traits::{One, Saturating};
@@ -197,6 +197,38 @@ benchmarks! {
 		let _ = Pallet::<T>::include_pvf_check_statement(RawOrigin::None.into(), stmt, signature);
 	}
 
+	authorize_force_set_current_code_hash {
+		let para_id = ParaId::from(1000);
+		let code_hash = ValidationCode(vec![0]).hash();
+		let valid_period = BlockNumberFor::<T>::from(1000u32);
+	}: _(RawOrigin::Root, para_id, code_hash, valid_period)
+	verify {
+		assert_last_event::<T>(Event::CodeAuthorized(para_id, code_hash).into());
+	}
+
+	apply_authorized_force_set_current_code {
+		let c in 1 .. MAX_CODE_SIZE;
+		let new_code = ValidationCode(vec![0; c as usize]);
+		let para_id = ParaId::from(1000);
+		ParaLifecycles::<T>::insert(&para_id, ParaLifecycle::Parachain);
+		CurrentCodeHash::<T>::insert(&para_id, ValidationCode(vec![1]).hash());
+		// the code is already known, so it is accepted and set right away, which is the most
+		// expensive path.
+		CodeByHash::<T>::insert(new_code.hash(), &new_code);
+		AuthorizedCodeHash::<T>::insert(
+			&para_id,
+			AuthorizedCodeHashAndExpiry {
+				code_hash: new_code.hash(),
+				expire_at: BlockNumberFor::<T>::from(1000u32),
+			},
+		);
+		generate_disordered_pruning::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), para_id, new_code)
+	verify {
+		assert_last_event::<T>(Event::CurrentCodeUpdated(para_id).into());
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(Default::default()),
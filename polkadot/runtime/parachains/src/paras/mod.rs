@@ -51,6 +51,11 @@
 //! 2. The validation code was vetted by the validators and declared as non-malicious in a processes
 //!    known as PVF pre-checking.
 //!
+//! A para that can't upgrade itself anymore, e.g. because its current code is broken, can be
+//! recovered without root submitting the whole code: root only authorizes the hash of the new code
+//! for a limited time, and anybody can then submit the matching code. Once the code passed PVF
+//! pre-checking, it becomes the current code of the para right away.
+//!
 //! # Validation Code Management
 //!
 //! Potentially, one validation code can be used by several different paras. For example, during
@@ -157,6 +162,17 @@ pub struct ReplacementTimes<N> {
 	activated_at: N,
 }
 
+/// A code hash authorized to become the current code of a para, along with the block number its
+/// authorization expires at.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AuthorizedCodeHashAndExpiry<N> {
+	/// The authorized code hash.
+	pub code_hash: ValidationCodeHash,
+	/// The block number at which the authorization expires. The code can't be applied anymore
+	/// starting at this block.
+	pub expire_at: N,
+}
+
 /// Metadata used to track previous parachain validation code that we keep in
 /// the state.
 #[derive(Default, Encode, Decode, TypeInfo)]
@@ -389,6 +405,9 @@ pub(crate) enum PvfCheckCause<BlockNumber> {
 		/// Whether or not the given para should be sent the `GoAhead` signal.
 		set_go_ahead: SetGoAhead,
 	},
+	/// PVF vote was initiated by applying a code authorized for the given para. The code becomes
+	/// the current code of the para as soon as it is accepted.
+	ForceSetCurrentCode(ParaId),
 }
 
 /// Should the `GoAhead` signal be set after a successful check of the new wasm binary?
@@ -404,6 +423,7 @@ impl<BlockNumber> PvfCheckCause<BlockNumber> {
 		match *self {
 			PvfCheckCause::Onboarding(id) => id,
 			PvfCheckCause::Upgrade { id, .. } => id,
+			PvfCheckCause::ForceSetCurrentCode(id) => id,
 		}
 	}
 }
@@ -536,6 +556,8 @@ pub trait WeightInfo {
 	fn include_pvf_check_statement_finalize_onboarding_accept() -> Weight;
 	fn include_pvf_check_statement_finalize_onboarding_reject() -> Weight;
 	fn include_pvf_check_statement() -> Weight;
+	fn authorize_force_set_current_code_hash() -> Weight;
+	fn apply_authorized_force_set_current_code(c: u32) -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -581,6 +603,12 @@ impl WeightInfo for TestWeightInfo {
 		// This special value is to distinguish from the finalizing variants above in tests.
 		Weight::MAX - Weight::from_parts(1, 1)
 	}
+	fn authorize_force_set_current_code_hash() -> Weight {
+		Weight::MAX
+	}
+	fn apply_authorized_force_set_current_code(_c: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 #[frame_support::pallet]
@@ -651,6 +679,9 @@ pub mod pallet {
 		/// The given validation code was rejected by the PVF pre-checking vote.
 		/// `code_hash` `para_id`
 		PvfCheckRejected(ValidationCodeHash, ParaId),
+		/// The given validation code hash was authorized to become the current code of a Para.
+		/// `para_id` `code_hash`
+		CodeAuthorized(ParaId, ValidationCodeHash),
 	}
 
 	#[pallet::error]
@@ -679,6 +710,12 @@ pub mod pallet {
 		PvfCheckSubjectInvalid,
 		/// Parachain cannot currently schedule a code upgrade.
 		CannotUpgradeCode,
+		/// No code hash is authorized for the para.
+		NothingAuthorized,
+		/// The submitted code doesn't match the code hash authorized for the para.
+		Unauthorized,
+		/// The authorization of the code hash expired.
+		AuthorizationExpired,
 	}
 
 	/// All currently active PVF pre-checking votes.
@@ -843,6 +880,12 @@ pub mod pallet {
 	pub(super) type CodeByHash<T: Config> =
 		StorageMap<_, Identity, ValidationCodeHash, ValidationCode>;
 
+	/// The code hash authorized to become the current code of a para, along with the expiry of
+	/// the authorization.
+	#[pallet::storage]
+	pub(super) type AuthorizedCodeHash<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, AuthorizedCodeHashAndExpiry<BlockNumberFor<T>>>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -878,22 +921,9 @@ pub mod pallet {
 			new_code: ValidationCode,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			let maybe_prior_code_hash = CurrentCodeHash::<T>::get(&para);
 			let new_code_hash = new_code.hash();
 			Self::increase_code_ref(&new_code_hash, &new_code);
-			CurrentCodeHash::<T>::insert(&para, new_code_hash);
-
-			let now = frame_system::Pallet::<T>::block_number();
-			if let Some(prior_code_hash) = maybe_prior_code_hash {
-				Self::note_past_code(para, now, now, prior_code_hash);
-			} else {
-				log::error!(
-					target: LOG_TARGET,
-					"Pallet paras storage is inconsistent, prior code not found {:?}",
-					&para
-				);
-			}
-			Self::deposit_event(Event::CurrentCodeUpdated(para));
+			Self::set_current_code(para, new_code_hash);
 			Ok(())
 		}
 
@@ -1144,6 +1174,63 @@ pub mod pallet {
 			MostRecentContext::<T>::insert(&para, context);
 			Ok(())
 		}
+
+		/// Authorize the given code hash to become the current code of the para.
+		///
+		/// Anybody can then apply the authorized code with
+		/// `apply_authorized_force_set_current_code` until `valid_period` blocks passed. A new
+		/// authorization replaces the previous one.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::authorize_force_set_current_code_hash())]
+		pub fn authorize_force_set_current_code_hash(
+			origin: OriginFor<T>,
+			para: ParaId,
+			new_code_hash: ValidationCodeHash,
+			valid_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			AuthorizedCodeHash::<T>::insert(
+				&para,
+				AuthorizedCodeHashAndExpiry {
+					code_hash: new_code_hash,
+					expire_at: now.saturating_add(valid_period),
+				},
+			);
+			Self::deposit_event(Event::CodeAuthorized(para, new_code_hash));
+			Ok(())
+		}
+
+		/// Apply the code authorized for the para with `authorize_force_set_current_code_hash`.
+		///
+		/// The code goes through PVF pre-checking and becomes the current code of the para once
+		/// accepted. The authorization is consumed. The fee is refunded on success.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::apply_authorized_force_set_current_code(new_code.0.len() as u32))]
+		pub fn apply_authorized_force_set_current_code(
+			origin: OriginFor<T>,
+			para: ParaId,
+			new_code: ValidationCode,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(ParaLifecycles::<T>::contains_key(&para), Error::<T>::NotRegistered);
+			let authorized =
+				AuthorizedCodeHash::<T>::get(&para).ok_or(Error::<T>::NothingAuthorized)?;
+			let code_hash = new_code.hash();
+			ensure!(authorized.code_hash == code_hash, Error::<T>::Unauthorized);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < authorized.expire_at, Error::<T>::AuthorizationExpired);
+
+			AuthorizedCodeHash::<T>::remove(&para);
+			let config = configuration::Pallet::<T>::config();
+			Self::kick_off_pvf_check(
+				PvfCheckCause::ForceSetCurrentCode(para),
+				code_hash,
+				new_code,
+				&config,
+			);
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -1325,6 +1412,7 @@ impl<T: Config> Pallet<T> {
 					FutureCodeUpgrades::<T>::remove(&para);
 					UpgradeGoAheadSignal::<T>::remove(&para);
 					UpgradeRestrictionSignal::<T>::remove(&para);
+					AuthorizedCodeHash::<T>::remove(&para);
 					ParaLifecycles::<T>::remove(&para);
 					let removed_future_code_hash = FutureCodeHash::<T>::take(&para);
 					if let Some(removed_future_code_hash) = removed_future_code_hash {
@@ -1584,11 +1672,43 @@ impl<T: Config> Pallet<T> {
 						*set_go_ahead,
 					);
 				},
+				PvfCheckCause::ForceSetCurrentCode(id) => {
+					weight += T::DbWeight::get().reads(1);
+					if ParaLifecycles::<T>::contains_key(id) {
+						weight += Self::set_current_code(*id, *code_hash);
+					} else {
+						// The para was offboarded while the code was pre-checked.
+						weight += Self::decrease_code_ref(code_hash);
+					}
+				},
 			}
 		}
 		weight
 	}
 
+	/// Make the given code the current code of the para immediately, noting the prior code as past
+	/// code.
+	///
+	/// The caller must have already increased the reference count of the code.
+	fn set_current_code(id: ParaId, code_hash: ValidationCodeHash) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		let maybe_prior_code_hash = CurrentCodeHash::<T>::get(&id);
+		CurrentCodeHash::<T>::insert(&id, code_hash);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(prior_code_hash) = maybe_prior_code_hash {
+			weight += Self::note_past_code(id, now, now, prior_code_hash);
+		} else {
+			log::error!(
+				target: LOG_TARGET,
+				"Pallet paras storage is inconsistent, prior code not found {:?}",
+				&id
+			);
+		}
+		Self::deposit_event(Event::CurrentCodeUpdated(id));
+		weight
+	}
+
 	fn proceed_with_onboarding(id: ParaId, sessions_observed: SessionIndex) -> Weight {
 		let weight = T::DbWeight::get().reads_writes(2, 1);
 
@@ -1687,6 +1807,8 @@ impl<T: Config> Pallet<T> {
 					UpgradeGoAheadSignal::<T>::insert(&id, UpgradeGoAhead::Abort);
 					FutureCodeHash::<T>::remove(&id);
 				},
+				// Nothing was changed for the para before the code got accepted.
+				PvfCheckCause::ForceSetCurrentCode(_) => {},
 			}
 		}
 
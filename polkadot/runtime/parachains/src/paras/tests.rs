@@ -1819,6 +1819,152 @@ fn add_trusted_validation_code_enacts_existing_pvf_vote() {
 	});
 }
 
+#[test]
+fn authorized_code_becomes_current_code_after_pvf_check() {
+	let para_id = ParaId::from(111);
+	let old_code: ValidationCode = vec![1, 2, 3].into();
+	let new_code: ValidationCode = vec![3, 2, 1].into();
+
+	let paras = vec![(
+		para_id,
+		ParaGenesisArgs {
+			para_kind: ParaKind::Parachain,
+			genesis_head: Default::default(),
+			validation_code: old_code.clone(),
+		},
+	)];
+
+	let genesis_config = MockGenesisConfig {
+		paras: GenesisConfig { paras, ..Default::default() },
+		..Default::default()
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(2, Some(vec![1]));
+		const EXPECTED_SESSION: SessionIndex = 1;
+
+		// Only root can authorize a code hash.
+		assert!(Paras::authorize_force_set_current_code_hash(
+			RuntimeOrigin::signed(1),
+			para_id,
+			new_code.hash(),
+			10,
+		)
+		.is_err());
+		assert_ok!(Paras::authorize_force_set_current_code_hash(
+			RuntimeOrigin::root(),
+			para_id,
+			new_code.hash(),
+			10,
+		));
+		assert_eq!(
+			AuthorizedCodeHash::<Test>::get(&para_id),
+			Some(AuthorizedCodeHashAndExpiry { code_hash: new_code.hash(), expire_at: 12 })
+		);
+
+		// Anybody can apply the authorized code, which kicks off PVF pre-checking.
+		assert_ok!(Paras::apply_authorized_force_set_current_code(
+			RuntimeOrigin::signed(1),
+			para_id,
+			new_code.clone(),
+		));
+		assert!(AuthorizedCodeHash::<Test>::get(&para_id).is_none());
+		check_code_is_stored(&new_code);
+		assert!(PvfActiveVoteMap::<Test>::contains_key(&new_code.hash()));
+		assert_eq!(CurrentCodeHash::<Test>::get(&para_id), Some(old_code.hash()));
+
+		// Once the code is accepted, it becomes the current code right away.
+		submit_super_majority_pvf_votes(&new_code, EXPECTED_SESSION, true);
+		assert_eq!(CurrentCodeHash::<Test>::get(&para_id), Some(new_code.hash()));
+		assert_eq!(Paras::past_code_meta(&para_id).most_recent_change(), Some(2));
+		check_code_is_stored(&old_code);
+
+		// The authorization was consumed.
+		assert_err!(
+			Paras::apply_authorized_force_set_current_code(
+				RuntimeOrigin::signed(1),
+				para_id,
+				new_code.clone(),
+			),
+			Error::<Test>::NothingAuthorized,
+		);
+	});
+}
+
+#[test]
+fn apply_authorized_code_checks_authorization() {
+	let para_id = ParaId::from(111);
+	let old_code: ValidationCode = vec![1, 2, 3].into();
+	let new_code: ValidationCode = vec![3, 2, 1].into();
+
+	let paras = vec![(
+		para_id,
+		ParaGenesisArgs {
+			para_kind: ParaKind::Parachain,
+			genesis_head: Default::default(),
+			validation_code: old_code,
+		},
+	)];
+
+	let genesis_config = MockGenesisConfig {
+		paras: GenesisConfig { paras, ..Default::default() },
+		..Default::default()
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(2, Some(vec![1]));
+
+		// Unknown paras are rejected.
+		assert_err!(
+			Paras::apply_authorized_force_set_current_code(
+				RuntimeOrigin::signed(1),
+				ParaId::from(222),
+				new_code.clone(),
+			),
+			Error::<Test>::NotRegistered,
+		);
+
+		// Nothing authorized yet.
+		assert_err!(
+			Paras::apply_authorized_force_set_current_code(
+				RuntimeOrigin::signed(1),
+				para_id,
+				new_code.clone(),
+			),
+			Error::<Test>::NothingAuthorized,
+		);
+
+		assert_ok!(Paras::authorize_force_set_current_code_hash(
+			RuntimeOrigin::root(),
+			para_id,
+			new_code.hash(),
+			5,
+		));
+
+		// The code must match the authorized hash.
+		assert_err!(
+			Paras::apply_authorized_force_set_current_code(
+				RuntimeOrigin::signed(1),
+				para_id,
+				vec![4, 5, 6].into(),
+			),
+			Error::<Test>::Unauthorized,
+		);
+
+		// The authorization can't be used once expired.
+		run_to_block(7, None);
+		assert_err!(
+			Paras::apply_authorized_force_set_current_code(
+				RuntimeOrigin::signed(1),
+				para_id,
+				new_code.clone(),
+			),
+			Error::<Test>::AuthorizationExpired,
+		);
+		check_code_is_not_stored(&new_code);
+	});
+}
+
 #[test]
 fn verify_upgrade_go_ahead_signal_is_externally_accessible() {
 	use primitives::well_known_keys;
@@ -28,9 +28,9 @@ use crate::{
 	inclusion::CandidateCheckContext,
 	initializer,
 	metrics::METRICS,
-	paras,
 	scheduler::{self, FreedReason},
-	shared, ParaId,
+	shared::{self, AllowedRelayParentsTracker},
+	ParaId,
 };
 use bitvec::prelude::BitVec;
 use frame_support::{
@@ -42,6 +42,7 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 use pallet_babe::{self, ParentBlockRandomness};
 use primitives::{
+	vstaging::{node_features::FeatureIndex, CandidateDropReason},
 	BackedCandidate, CandidateHash, CandidateReceipt, CheckedDisputeStatementSet,
 	CheckedMultiDisputeStatementSet, CoreIndex, DisputeStatementSet,
 	InherentData as ParachainsInherentData, MultiDisputeStatementSet, ScrapedOnChainVotes,
@@ -564,11 +565,9 @@ impl<T: Config> Pallet<T> {
 		// Process new availability bitfields, yielding any availability cores whose
 		// work has now concluded.
 		let freed_concluded =
-			<inclusion::Pallet<T>>::update_pending_availability_and_get_freed_cores::<_>(
-				expected_bits,
+			<inclusion::Pallet<T>>::update_pending_availability_and_get_freed_cores(
 				&validator_public[..],
 				bitfields.clone(),
-				<scheduler::Pallet<T>>::core_para,
 			);
 
 		// Inform the disputes module of all included candidates.
@@ -581,37 +580,42 @@ impl<T: Config> Pallet<T> {
 		let freed = collect_all_freed_cores::<T, _>(freed_concluded.iter().cloned());
 
 		<scheduler::Pallet<T>>::free_cores_and_fill_claimqueue(freed, now);
-		let scheduled = <scheduler::Pallet<T>>::scheduled_paras()
-			.map(|(core_idx, para_id)| (para_id, core_idx))
-			.collect();
+		let mut scheduled: BTreeMap<ParaId, BTreeSet<CoreIndex>> = BTreeMap::new();
+		for (core_idx, para_id) in <scheduler::Pallet<T>>::scheduled_paras() {
+			scheduled.entry(para_id).or_default().insert(core_idx);
+		}
+
+		let core_index_enabled = config
+			.node_features
+			.get(FeatureIndex::ElasticScalingMVP as usize)
+			.map(|b| *b)
+			.unwrap_or(false);
 
 		METRICS.on_candidates_processed_total(backed_candidates.len() as u64);
 
-		let backed_candidates = sanitize_backed_candidates::<T, _>(
+		let mut backed_candidates_with_core = sanitize_backed_candidates::<T, _>(
 			backed_candidates,
-			|candidate_idx: usize,
+			|_candidate_idx: usize,
 			 backed_candidate: &BackedCandidate<<T as frame_system::Config>::Hash>|
 			 -> bool {
-				let para_id = backed_candidate.descriptor().para_id;
-				let prev_context = <paras::Pallet<T>>::para_most_recent_context(para_id);
-				let check_ctx = CandidateCheckContext::<T>::new(prev_context);
-
 				// never include a concluded-invalid candidate
-				current_concluded_invalid_disputes.contains(&backed_candidate.hash()) ||
-					// Instead of checking the candidates with code upgrades twice
-					// move the checking up here and skip it in the training wheels fallback.
-					// That way we avoid possible duplicate checks while assuring all
-					// backed candidates fine to pass on.
-					//
-					// NOTE: this is the only place where we check the relay-parent.
-					check_ctx
-						.verify_backed_candidate(&allowed_relay_parents, candidate_idx, backed_candidate)
-						.is_err()
+				current_concluded_invalid_disputes.contains(&backed_candidate.hash())
 			},
 			&scheduled,
+			core_index_enabled,
 		);
 
-		METRICS.on_candidates_sanitized(backed_candidates.len() as u64);
+		// Instead of checking the candidates with code upgrades twice
+		// move the checking up here and skip it in the training wheels fallback.
+		// That way we avoid possible duplicate checks while assuring all
+		// backed candidates fine to pass on.
+		//
+		// NOTE: this is the only place where we check the relay-parent.
+		filter_unchained_candidates::<T>(&mut backed_candidates_with_core, &allowed_relay_parents);
+
+		METRICS.on_candidates_sanitized(
+			backed_candidates_with_core.values().map(|c| c.len() as u64).sum(),
+		);
 
 		// Process backed candidates according to scheduled cores.
 		let inclusion::ProcessedCandidates::<<HeaderFor<T> as HeaderT>::Hash> {
@@ -619,9 +623,10 @@ impl<T: Config> Pallet<T> {
 			candidate_receipt_with_backing_validator_indices,
 		} = <inclusion::Pallet<T>>::process_candidates(
 			&allowed_relay_parents,
-			backed_candidates.clone(),
+			&backed_candidates_with_core,
 			&scheduled,
 			<scheduler::Pallet<T>>::group_validators,
+			core_index_enabled,
 		)?;
 		// Note which of the scheduled cores were actually occupied by a backed candidate.
 		<scheduler::Pallet<T>>::occupied(occupied.into_iter().map(|e| (e.0, e.1)).collect());
@@ -638,6 +643,12 @@ impl<T: Config> Pallet<T> {
 
 		let bitfields = bitfields.into_iter().map(|v| v.into_unchecked()).collect();
 
+		let backed_candidates = backed_candidates_with_core
+			.into_values()
+			.flatten()
+			.map(|(backed_candidate, _)| backed_candidate)
+			.collect();
+
 		let processed =
 			ParachainsInherentData { bitfields, backed_candidates, disputes, parent_header };
 		Ok((processed, Some(all_weight_after).into()))
@@ -900,7 +911,8 @@ pub(crate) fn sanitize_bitfields<T: crate::inclusion::Config>(
 	bitfields
 }
 
-/// Filter out any candidates that have a concluded invalid dispute.
+/// Filter out any candidates that have a concluded invalid dispute, and map the remaining ones to
+/// the cores they are backed on.
 ///
 /// `scheduled` follows the same naming scheme as provided in the
 /// guide: Currently `free` but might become `occupied`.
@@ -910,43 +922,181 @@ pub(crate) fn sanitize_bitfields<T: crate::inclusion::Config>(
 /// `candidate_has_concluded_invalid_dispute` must return `true` if the candidate
 /// is disputed, false otherwise. The passed `usize` is the candidate index.
 ///
-/// The returned `Vec` is sorted according to the occupied core index.
+/// If `core_index_enabled`, i.e. the `ElasticScalingMVP` node feature is enabled, a candidate is
+/// backed on the core it commits to, which must be scheduled for its para. Otherwise it is backed
+/// on the core scheduled for its para, which must be the only one. Candidates are dropped if their
+/// core can't be determined or is already used by a candidate before them.
+///
+/// Every dropped candidate is noted along with the reason it was dropped.
+///
+/// The returned candidates are grouped by para. The candidates of a para keep their relative
+/// order, which must be the order of their chain.
 fn sanitize_backed_candidates<
 	T: crate::inclusion::Config,
 	F: FnMut(usize, &BackedCandidate<T::Hash>) -> bool,
 >(
 	mut backed_candidates: Vec<BackedCandidate<T::Hash>>,
-	mut candidate_has_concluded_invalid_dispute_or_is_invalid: F,
-	scheduled: &BTreeMap<ParaId, CoreIndex>,
-) -> Vec<BackedCandidate<T::Hash>> {
+	mut candidate_has_concluded_invalid_dispute: F,
+	scheduled: &BTreeMap<ParaId, BTreeSet<CoreIndex>>,
+	core_index_enabled: bool,
+) -> BTreeMap<ParaId, Vec<(BackedCandidate<T::Hash>, CoreIndex)>> {
 	// Remove any candidates that were concluded invalid.
 	// This does not assume sorting.
 	backed_candidates.indexed_retain(move |candidate_idx, backed_candidate| {
-		!candidate_has_concluded_invalid_dispute_or_is_invalid(candidate_idx, backed_candidate)
+		if candidate_has_concluded_invalid_dispute(candidate_idx, backed_candidate) {
+			<inclusion::Pallet<T>>::note_dropped_candidate(
+				backed_candidate.hash(),
+				backed_candidate.descriptor().para_id,
+				CandidateDropReason::ConcludedInvalid,
+			);
+			return false
+		}
+		true
 	});
 
-	// Assure the backed candidate's `ParaId`'s core is free.
-	// This holds under the assumption that `Scheduler::schedule` is called _before_.
-	// We don't check the relay-parent because this is done in the closure when
-	// constructing the inherent and during actual processing otherwise.
+	let mut backed_candidates_with_core: BTreeMap<ParaId, Vec<_>> = BTreeMap::new();
+	let mut used_cores = BTreeSet::new();
+
+	for backed_candidate in backed_candidates {
+		let para_id = backed_candidate.descriptor().para_id;
+
+		// Assure the backed candidate's `ParaId`'s core is free.
+		// This holds under the assumption that `Scheduler::schedule` is called _before_.
+		// We don't check the relay-parent because this is done in `filter_unchained_candidates`
+		// when constructing the inherent and during actual processing otherwise.
+		let Some(scheduled_cores) = scheduled.get(&para_id) else {
+			<inclusion::Pallet<T>>::note_dropped_candidate(
+				backed_candidate.hash(),
+				para_id,
+				CandidateDropReason::UnscheduledCore,
+			);
+			continue
+		};
 
-	backed_candidates.retain(|backed_candidate| {
-		let desc = backed_candidate.descriptor();
+		let core_idx = if core_index_enabled {
+			backed_candidate
+				.validator_indices_and_core_index(core_index_enabled)
+				.1
+				.filter(|core_idx| scheduled_cores.contains(core_idx))
+		} else if scheduled_cores.len() == 1 {
+			scheduled_cores.iter().next().copied()
+		} else {
+			// The core can't be told without the candidate committing to it.
+			None
+		};
 
-		scheduled.get(&desc.para_id).is_some()
-	});
+		let Some(core_idx) = core_idx else {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} of para {:?}: can't determine its core",
+				backed_candidate.hash(),
+				para_id,
+			);
+			<inclusion::Pallet<T>>::note_dropped_candidate(
+				backed_candidate.hash(),
+				para_id,
+				CandidateDropReason::UnscheduledCore,
+			);
+			continue
+		};
 
-	// Sort the `Vec` last, once there is a guarantee that these
-	// `BackedCandidates` references the expected relay chain parent,
-	// but more importantly are scheduled for a free core.
-	// This both avoids extra work for obviously invalid candidates,
-	// but also allows this to be done in place.
-	backed_candidates.sort_by(|x, y| {
-		// Never panics, since we filtered all panic arguments out in the previous `fn retain`.
-		scheduled[&x.descriptor().para_id].cmp(&scheduled[&y.descriptor().para_id])
-	});
+		if !used_cores.insert(core_idx) {
+			log::debug!(
+				target: LOG_TARGET,
+				"Dropping candidate {:?} of para {:?}: core {:?} is already used",
+				backed_candidate.hash(),
+				para_id,
+				core_idx,
+			);
+			<inclusion::Pallet<T>>::note_dropped_candidate(
+				backed_candidate.hash(),
+				para_id,
+				CandidateDropReason::CoreAlreadyUsed,
+			);
+			continue
+		}
+
+		backed_candidates_with_core
+			.entry(para_id)
+			.or_default()
+			.push((backed_candidate, core_idx));
+	}
 
-	backed_candidates
+	backed_candidates_with_core
+}
+
+/// Filter out the candidates which don't pass [`CandidateCheckContext::verify_backed_candidate`],
+/// along with all the candidates of the same para after them.
+///
+/// The first candidate of a para must build upon its latest head, which is the head produced by
+/// the last candidate pending availability, or the current para head. Every other candidate must
+/// build upon the candidate before it.
+///
+/// Every dropped candidate is noted along with the reason it was dropped.
+fn filter_unchained_candidates<T: inclusion::Config>(
+	backed_candidates_with_core: &mut BTreeMap<ParaId, Vec<(BackedCandidate<T::Hash>, CoreIndex)>>,
+	allowed_relay_parents: &AllowedRelayParentsTracker<T::Hash, BlockNumberFor<T>>,
+) {
+	let mut candidate_idx = 0;
+
+	backed_candidates_with_core.retain(|para_id, candidates| {
+		let (latest_head_data, mut prev_context) =
+			<inclusion::Pallet<T>>::para_latest_head_and_context(*para_id);
+		let Some(mut latest_head_data) = latest_head_data else {
+			log::debug!(target: LOG_TARGET, "Dropping candidates of para {:?} without head", para_id);
+			for (backed_candidate, _) in candidates.iter() {
+				<inclusion::Pallet<T>>::note_dropped_candidate(
+					backed_candidate.hash(),
+					*para_id,
+					CandidateDropReason::MissingParaHead,
+				);
+			}
+			return false
+		};
+
+		let mut chain_len = 0;
+		for (backed_candidate, _) in candidates.iter() {
+			let check_ctx = CandidateCheckContext::<T>::new(prev_context);
+			match check_ctx.verify_backed_candidate(
+				allowed_relay_parents,
+				candidate_idx,
+				backed_candidate,
+				latest_head_data.clone(),
+			) {
+				Ok(relay_parent_number) => {
+					latest_head_data = backed_candidate.candidate.commitments.head_data.clone();
+					prev_context = Some(relay_parent_number);
+					chain_len += 1;
+					candidate_idx += 1;
+				},
+				Err(err) => {
+					log::debug!(
+						target: LOG_TARGET,
+						"Dropping candidate {:?} of para {:?} and its successors: {:?}",
+						backed_candidate.hash(),
+						para_id,
+						err,
+					);
+					break
+				},
+			}
+		}
+
+		for (idx, (backed_candidate, _)) in candidates.iter().enumerate().skip(chain_len) {
+			let reason = if idx == chain_len {
+				CandidateDropReason::VerificationFailed
+			} else {
+				CandidateDropReason::PredecessorDropped
+			};
+			<inclusion::Pallet<T>>::note_dropped_candidate(
+				backed_candidate.hash(),
+				*para_id,
+				reason,
+			);
+		}
+		candidates.truncate(chain_len);
+		!candidates.is_empty()
+	});
 }
 
 /// Derive entropy from babe provided per block randomness.
@@ -977,6 +977,7 @@ mod sanitizers {
 	};
 	use bitvec::order::Lsb0;
 	use primitives::{
+		vstaging::{CandidateDropReason, DroppedCandidate},
 		AvailabilityBitfield, GroupIndex, Hash, Id as ParaId, SignedAvailabilityBitfield,
 		ValidatorIndex,
 	};
@@ -1232,7 +1233,23 @@ mod sanitizers {
 		// Backed candidates and scheduled parachains used for `sanitize_backed_candidates` testing
 		struct TestData {
 			backed_candidates: Vec<BackedCandidate>,
-			scheduled_paras: BTreeMap<primitives::Id, CoreIndex>,
+			scheduled_paras: BTreeMap<primitives::Id, BTreeSet<CoreIndex>>,
+		}
+
+		// The backed candidates of `TestData` grouped by para, each on the single core scheduled
+		// for its para.
+		fn with_scheduled_cores(
+			backed_candidates: Vec<BackedCandidate>,
+			scheduled: &BTreeMap<primitives::Id, BTreeSet<CoreIndex>>,
+		) -> BTreeMap<primitives::Id, Vec<(BackedCandidate, CoreIndex)>> {
+			backed_candidates
+				.into_iter()
+				.map(|candidate| {
+					let para_id = candidate.descriptor().para_id;
+					let core = *scheduled[&para_id].iter().next().unwrap();
+					(para_id, vec![(candidate, core)])
+				})
+				.collect()
 		}
 
 		// Generate test data for the candidates test
@@ -1264,7 +1281,7 @@ mod sanitizers {
 
 			let scheduled = (0_usize..2)
 				.into_iter()
-				.map(|idx| (ParaId::from(1_u32 + idx as u32), CoreIndex::from(idx as u32)))
+				.map(|idx| (ParaId::from(1_u32 + idx as u32), [CoreIndex::from(idx as u32)].into()))
 				.collect::<BTreeMap<_, _>>();
 
 			let group_validators = |group_index: GroupIndex| {
@@ -1319,12 +1336,11 @@ mod sanitizers {
 					sanitize_backed_candidates::<Test, _>(
 						backed_candidates.clone(),
 						has_concluded_invalid,
-						&scheduled
+						&scheduled,
+						false,
 					),
-					backed_candidates
+					with_scheduled_cores(backed_candidates, &scheduled)
 				);
-
-				{}
 			});
 		}
 
@@ -1340,7 +1356,8 @@ mod sanitizers {
 				assert!(sanitize_backed_candidates::<Test, _>(
 					backed_candidates.clone(),
 					has_concluded_invalid,
-					&scheduled
+					&scheduled,
+					false,
 				)
 				.is_empty());
 			});
@@ -1368,12 +1385,109 @@ mod sanitizers {
 					sanitize_backed_candidates::<Test, _>(
 						backed_candidates.clone(),
 						has_concluded_invalid,
-						&scheduled
+						&scheduled,
+						false,
 					)
 					.len(),
 					backed_candidates.len() / 2
 				);
 			});
 		}
+
+		// without the core index committed, the core of a para with multiple scheduled cores
+		// can't be determined.
+		#[test]
+		fn multiple_cores_require_core_index() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras: mut scheduled } =
+					get_test_data();
+				scheduled.get_mut(&ParaId::from(1)).unwrap().insert(CoreIndex::from(2));
+
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				let sanitized = sanitize_backed_candidates::<Test, _>(
+					backed_candidates.clone(),
+					has_concluded_invalid,
+					&scheduled,
+					false,
+				);
+				assert_eq!(sanitized.keys().copied().collect::<Vec<_>>(), vec![ParaId::from(2)]);
+			});
+		}
+
+		// with the core index committed, candidates are assigned to the cores they were backed on,
+		// as long as these are scheduled for their para.
+		#[test]
+		fn committed_core_index_is_used() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras: mut scheduled } =
+					get_test_data();
+				scheduled.get_mut(&ParaId::from(1)).unwrap().insert(CoreIndex::from(2));
+
+				let with_core_index = |backed: BackedCandidate, core: u32| {
+					BackedCandidate::new(
+						backed.candidate,
+						backed.validity_votes,
+						backed.validator_indices,
+						Some(CoreIndex::from(core)),
+					)
+				};
+				let mut backed_candidates = backed_candidates.into_iter();
+				let backed_a = with_core_index(backed_candidates.next().unwrap(), 2);
+				// Core 0 is scheduled, but not for para 2.
+				let backed_b = with_core_index(backed_candidates.next().unwrap(), 0);
+
+				let has_concluded_invalid =
+					|_idx: usize, _backed_candidate: &BackedCandidate| -> bool { false };
+
+				assert_eq!(
+					sanitize_backed_candidates::<Test, _>(
+						vec![backed_a.clone(), backed_b],
+						has_concluded_invalid,
+						&scheduled,
+						true,
+					),
+					[(ParaId::from(1), vec![(backed_a, CoreIndex::from(2))])].into_iter().collect()
+				);
+			});
+		}
+
+		// candidates dropped by the sanitizer are noted along with the reason
+		#[test]
+		fn dropped_candidates_are_noted() {
+			new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+				let TestData { backed_candidates, scheduled_paras: mut scheduled } =
+					get_test_data();
+				scheduled.remove(&ParaId::from(2));
+
+				let invalid = backed_candidates[0].hash();
+				let has_concluded_invalid =
+					|_idx: usize, candidate: &BackedCandidate| candidate.hash() == invalid;
+
+				assert!(sanitize_backed_candidates::<Test, _>(
+					backed_candidates.clone(),
+					has_concluded_invalid,
+					&scheduled,
+					false,
+				)
+				.is_empty());
+				assert_eq!(
+					inclusion::Pallet::<Test>::dropped_candidates(),
+					vec![
+						DroppedCandidate {
+							candidate_hash: invalid,
+							para_id: ParaId::from(1),
+							reason: CandidateDropReason::ConcludedInvalid,
+						},
+						DroppedCandidate {
+							candidate_hash: backed_candidates[1].hash(),
+							para_id: ParaId::from(2),
+							reason: CandidateDropReason::UnscheduledCore,
+						},
+					]
+				);
+			});
+		}
 	}
 }
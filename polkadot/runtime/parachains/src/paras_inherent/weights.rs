@@ -148,16 +148,16 @@ pub fn signed_bitfield_weight<T: Config>(bitfield: &UncheckedSignedAvailabilityB
 pub fn backed_candidate_weight<T: frame_system::Config + Config>(
 	candidate: &BackedCandidate<T::Hash>,
 ) -> Weight {
-	set_proof_size_to_tx_size(
-		if candidate.candidate.commitments.new_validation_code.is_some() {
-			<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidate_code_upgrade()
-		} else {
-			<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidates_variable(
-				candidate.validity_votes.len() as u32,
-			)
-		},
-		candidate,
-	)
+	let weight = if candidate.candidate.commitments.new_validation_code.is_some() {
+		<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidate_code_upgrade()
+	} else {
+		<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidates_variable(
+			candidate.validity_votes.len() as u32,
+		)
+	};
+
+	// The backers of every backed candidate are recorded by the disputes pallet.
+	set_proof_size_to_tx_size(weight.saturating_add(T::DbWeight::get().writes(1)), candidate)
 }
 
 pub fn backed_candidates_weight<T: frame_system::Config + Config>(
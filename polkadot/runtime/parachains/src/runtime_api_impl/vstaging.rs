@@ -16,10 +16,11 @@
 
 //! Put implementations of functions from staging APIs here.
 
-use crate::{configuration, initializer, shared};
+use crate::{configuration, disputes, inclusion, initializer, shared};
 use primitives::{
-	vstaging::{ApprovalVotingParams, NodeFeatures},
-	ValidatorIndex,
+	slashing,
+	vstaging::{ApprovalVotingParams, DroppedCandidate, NodeFeatures},
+	CandidateHash, SessionIndex, ValidatorIndex,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::Vec};
 
@@ -56,3 +57,14 @@ pub fn approval_voting_params<T: initializer::Config>() -> ApprovalVotingParams
 	let config = <configuration::Pallet<T>>::config();
 	config.approval_voting_params
 }
+
+/// Implementation of `unapplied_backer_slashes` runtime API
+pub fn unapplied_backer_slashes<T: disputes::slashing::Config>(
+) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+	<disputes::slashing::Pallet<T>>::unapplied_backer_slashes()
+}
+
+/// Implementation of `dropped_candidates` runtime API
+pub fn dropped_candidates<T: inclusion::Config>() -> Vec<DroppedCandidate> {
+	<inclusion::Pallet<T>>::dropped_candidates()
+}
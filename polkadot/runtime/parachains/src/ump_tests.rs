@@ -17,7 +17,8 @@
 use crate::{
 	inclusion::{
 		tests::run_to_block_default_notifications as run_to_block, AggregateMessageOrigin,
-		AggregateMessageOrigin::Ump, UmpAcceptanceCheckErr, UmpQueueId,
+		AggregateMessageOrigin::Ump, PartialUpwardMessageTimeouts, PartialUpwardMessages,
+		UmpAcceptanceCheckErr, UmpQueueId, UpwardMessageFragments,
 	},
 	mock::{
 		assert_last_event, assert_last_events, new_test_ext, Configuration, MessageQueue,
@@ -30,6 +31,7 @@ use frame_support::{
 	traits::{EnqueueMessage, ExecuteOverweightError, ServiceQueues},
 	weights::Weight,
 };
+use polkadot_parachain_primitives::primitives::UpwardMessageFragment;
 use primitives::{well_known_keys, Id as ParaId, UpwardMessage};
 use sp_core::twox_64;
 use sp_io::hashing::blake2_256;
@@ -38,6 +40,7 @@ use sp_std::prelude::*;
 
 pub(super) struct GenesisConfigBuilder {
 	max_upward_message_size: u32,
+	max_upward_reassembled_message_size: u32,
 	max_upward_message_num_per_candidate: u32,
 	max_upward_queue_count: u32,
 	max_upward_queue_size: u32,
@@ -47,6 +50,7 @@ impl Default for GenesisConfigBuilder {
 	fn default() -> Self {
 		Self {
 			max_upward_message_size: 16,
+			max_upward_reassembled_message_size: 64,
 			max_upward_message_num_per_candidate: 2,
 			max_upward_queue_count: 4,
 			max_upward_queue_size: 64,
@@ -64,6 +68,7 @@ impl GenesisConfigBuilder {
 		let config = &mut genesis.configuration.config;
 
 		config.max_upward_message_size = self.max_upward_message_size;
+		config.max_upward_reassembled_message_size = self.max_upward_reassembled_message_size;
 		config.max_upward_message_num_per_candidate = self.max_upward_message_num_per_candidate;
 		config.max_upward_queue_count = self.max_upward_queue_count;
 		config.max_upward_queue_size = self.max_upward_queue_size;
@@ -659,3 +664,185 @@ fn cannot_enqueue_ump_while_offboarding() {
 		assert!(!Paras::is_valid_para(para));
 	});
 }
+
+/// Fragments are reassembled into the original message, which is enqueued in place of its last
+/// fragment.
+#[test]
+fn fragmented_message_is_reassembled() {
+	let a = ParaId::from(228);
+	let msg = vec![42u8; 50];
+	let other_msg = 1000u32.encode();
+
+	let config = GenesisConfigBuilder {
+		max_upward_message_size: 32,
+		max_upward_message_num_per_candidate: 4,
+		..Default::default()
+	};
+	new_test_ext(config.build()).execute_with(|| {
+		let fragments = UpwardMessageFragment::split(0, &msg, 32).unwrap();
+		assert_eq!(fragments.len(), 3);
+		assert!(fragments.iter().all(|fragment| fragment.len() <= 32));
+
+		queue_upward_msg(a, fragments[0].clone());
+		queue_upward_msg(a, other_msg.clone());
+		queue_upward_msg(a, fragments[1].clone());
+		assert!(PartialUpwardMessages::<Test>::contains_key(a, 0));
+		// The fragments are stored apart until the message is complete.
+		assert_eq!(UpwardMessageFragments::<Test>::iter_prefix_values((a, 0)).count(), 2);
+		// The last fragment completes the message.
+		assert_ok!(ParaInclusion::check_upward_messages(
+			&Configuration::config(),
+			a,
+			&fragments[2..]
+		));
+		ParaInclusion::receive_upward_messages(a, &fragments[2..]);
+		assert!(!PartialUpwardMessages::<Test>::contains_key(a, 0));
+		assert!(UpwardMessageFragments::<Test>::iter_prefix_values((a,)).next().is_none());
+
+		MessageQueue::service_queues(Weight::max_value());
+		assert_eq!(Processed::take(), vec![(a, other_msg), (a, msg)]);
+	});
+}
+
+/// Messages are reassembled up to `max_upward_reassembled_message_size`, here with sizes close to
+/// the ones of Rococo, whose message queue enqueues messages of less than 32 KiB.
+#[test]
+fn fragmented_message_is_limited_by_config() {
+	let a = ParaId::from(228);
+	let max_size = 32 * 1024 - 256;
+
+	let config = GenesisConfigBuilder {
+		max_upward_message_size: 8 * 1024,
+		max_upward_reassembled_message_size: max_size,
+		max_upward_message_num_per_candidate: 8,
+		max_upward_queue_size: 64 * 1024,
+		..Default::default()
+	};
+	new_test_ext(config.build()).execute_with(|| {
+		assert!(max_size <= crate::inclusion::MaxUmpMessageLenOf::<Test>::get());
+
+		let msg = vec![42u8; max_size as usize];
+		let fragments = UpwardMessageFragment::split(0, &msg, 8 * 1024).unwrap();
+		assert_eq!(fragments.len(), 4);
+		assert_ok!(ParaInclusion::check_upward_messages(&Configuration::config(), a, &fragments));
+		ParaInclusion::receive_upward_messages(a, &fragments);
+		assert!(!PartialUpwardMessages::<Test>::contains_key(a, 0));
+
+		// One byte more is discarded once the fragments exceed the limit.
+		let too_big = vec![42u8; max_size as usize + 1];
+		let fragments = UpwardMessageFragment::split(1, &too_big, 8 * 1024).unwrap();
+		ParaInclusion::receive_upward_messages(a, &fragments);
+		assert_last_event(
+			crate::inclusion::Event::<Test>::FragmentedUpwardMessageDiscarded {
+				from: a,
+				message_id: 1,
+			}
+			.into(),
+		);
+		assert!(UpwardMessageFragments::<Test>::iter_prefix_values((a,)).next().is_none());
+
+		MessageQueue::service_queues(Weight::max_value());
+		assert_eq!(Processed::take(), vec![(a, msg)]);
+	});
+}
+
+/// A fragment out of order discards the message.
+#[test]
+fn fragmented_message_out_of_order_is_discarded() {
+	let a = ParaId::from(228);
+	let msg = vec![42u8; 50];
+
+	let config = GenesisConfigBuilder { max_upward_message_size: 32, ..Default::default() };
+	new_test_ext(config.build()).execute_with(|| {
+		let fragments = UpwardMessageFragment::split(7, &msg, 32).unwrap();
+
+		queue_upward_msg(a, fragments[0].clone());
+		queue_upward_msg(a, fragments[2].clone());
+		assert_last_event(
+			crate::inclusion::Event::<Test>::FragmentedUpwardMessageDiscarded {
+				from: a,
+				message_id: 7,
+			}
+			.into(),
+		);
+		assert!(!PartialUpwardMessages::<Test>::contains_key(a, 7));
+		assert!(UpwardMessageFragments::<Test>::iter_prefix_values((a,)).next().is_none());
+
+		// The remaining fragment doesn't belong to any message anymore.
+		queue_upward_msg(a, fragments[1].clone());
+		assert!(!PartialUpwardMessages::<Test>::contains_key(a, 7));
+
+		MessageQueue::service_queues(Weight::max_value());
+		assert!(Processed::take().is_empty());
+	});
+}
+
+/// A para can only reassemble a limited number of messages at the same time.
+#[test]
+fn fragmented_messages_per_para_are_limited() {
+	let a = ParaId::from(228);
+	let b = ParaId::from(229);
+	let msg = vec![42u8; 50];
+	let limit = <Test as crate::inclusion::Config>::MaxFragmentedUpwardMessages::get();
+
+	let config = GenesisConfigBuilder {
+		max_upward_message_size: 32,
+		max_upward_queue_count: 128,
+		..Default::default()
+	};
+	new_test_ext(config.build()).execute_with(|| {
+		for message_id in 0..limit {
+			let fragments = UpwardMessageFragment::split(message_id, &msg, 32).unwrap();
+			queue_upward_msg(a, fragments[0].clone());
+		}
+
+		// One message too many.
+		let fragments = UpwardMessageFragment::split(limit, &msg, 32).unwrap();
+		queue_upward_msg(a, fragments[0].clone());
+		assert_last_event(
+			crate::inclusion::Event::<Test>::FragmentedUpwardMessageDiscarded {
+				from: a,
+				message_id: limit,
+			}
+			.into(),
+		);
+		assert!(!PartialUpwardMessages::<Test>::contains_key(a, limit));
+
+		// Restarting a message in progress is fine, and so is another para.
+		let fragments = UpwardMessageFragment::split(0, &msg, 32).unwrap();
+		queue_upward_msg(a, fragments[0].clone());
+		queue_upward_msg(b, fragments[0].clone());
+		assert!(PartialUpwardMessages::<Test>::contains_key(a, 0));
+		assert!(PartialUpwardMessages::<Test>::contains_key(b, 0));
+	});
+}
+
+/// A message which isn't complete before the timeout is discarded.
+#[test]
+fn fragmented_message_times_out() {
+	let a = ParaId::from(228);
+	let msg = vec![42u8; 50];
+	let timeout = <Test as crate::inclusion::Config>::UpwardMessageFragmentTimeout::get();
+
+	let config = GenesisConfigBuilder { max_upward_message_size: 32, ..Default::default() };
+	new_test_ext(config.build()).execute_with(|| {
+		run_to_block(1, vec![]);
+		let fragments = UpwardMessageFragment::split(0, &msg, 32).unwrap();
+		queue_upward_msg(a, fragments[0].clone());
+
+		run_to_block(timeout, vec![]);
+		assert!(PartialUpwardMessages::<Test>::contains_key(a, 0));
+
+		run_to_block(1 + timeout, vec![]);
+		assert!(!PartialUpwardMessages::<Test>::contains_key(a, 0));
+		assert!(UpwardMessageFragments::<Test>::iter_prefix_values((a,)).next().is_none());
+		assert!(PartialUpwardMessageTimeouts::<Test>::iter().next().is_none());
+		assert_last_event(
+			crate::inclusion::Event::<Test>::FragmentedUpwardMessageDiscarded {
+				from: a,
+				message_id: 0,
+			}
+			.into(),
+		);
+	});
+}
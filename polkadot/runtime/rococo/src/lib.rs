@@ -24,7 +24,7 @@ use pallet_nis::WithMaximumOf;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::{
 	slashing,
-	vstaging::{ApprovalVotingParams, NodeFeatures},
+	vstaging::{ApprovalVotingParams, DroppedCandidate, NodeFeatures},
 	AccountId, AccountIndex, Balance, BlockNumber, CandidateEvent, CandidateHash,
 	CommittedCandidateReceipt, CoreState, DisputeState, ExecutorParams, GroupRotationInfo, Hash,
 	Id as ParaId, InboundDownwardMessage, InboundHrmpMessage, Moment, Nonce,
@@ -910,6 +910,7 @@ impl parachains_origin::Config for Runtime {}
 
 impl parachains_configuration::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_configuration::WeightInfo<Runtime>;
+	type MaxUmpMessageLen = parachains_inclusion::MaxUmpMessageLenOf<Runtime>;
 }
 
 impl parachains_shared::Config for Runtime {}
@@ -925,11 +926,18 @@ impl runtime_parachains::inclusion::RewardValidators for RewardValidators {
 	fn reward_bitfields(_: impl IntoIterator<Item = ValidatorIndex>) {}
 }
 
+parameter_types! {
+	pub const MaxFragmentedUpwardMessages: u32 = 4;
+	pub const UpwardMessageFragmentTimeout: BlockNumber = 1 * HOURS;
+}
+
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
 	type RewardValidators = RewardValidators;
 	type MessageQueue = MessageQueue;
+	type MaxFragmentedUpwardMessages = MaxFragmentedUpwardMessages;
+	type UpwardMessageFragmentTimeout = UpwardMessageFragmentTimeout;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
 }
 
@@ -1031,12 +1039,15 @@ impl coretime::Config for Runtime {
 
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
+	// Keep unclaimed revenue of the last hour per block, older revenue is accumulated.
+	pub const MaxHistoricalRevenue: BlockNumber = 1 * HOURS;
 }
 
 impl parachains_assigner_on_demand::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TrafficDefaultValue = OnDemandTrafficDefaultValue;
+	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type WeightInfo = weights::runtime_parachains_assigner_on_demand::WeightInfo<Runtime>;
 }
 
@@ -1668,8 +1679,10 @@ pub mod migrations {
 		// Migrate Identity pallet for Usernames
 		pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
 		parachains_configuration::migration::v11::MigrateToV11<Runtime>,
+		parachains_configuration::migration::v12::MigrateToV12<Runtime>,
 		// This needs to come after the `parachains_configuration` above as we are reading the configuration.
 		coretime::migration::MigrateToCoretime<Runtime, crate::xcm_config::XcmRouter, GetLegacyLeaseImpl>,
+		parachains_inclusion::migration::MigrateV0ToV1<Runtime>,
 	);
 }
 
@@ -1832,7 +1845,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(10)]
+	#[api_version(12)]
 	impl primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -1980,6 +1993,15 @@ sp_api::impl_runtime_apis! {
 			parachains_staging_runtime_api_impl::approval_voting_params::<Runtime>()
 		}
 
+		fn unapplied_backer_slashes(
+		) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+			parachains_staging_runtime_api_impl::unapplied_backer_slashes::<Runtime>()
+		}
+
+		fn dropped_candidates() -> Vec<DroppedCandidate> {
+			parachains_staging_runtime_api_impl::dropped_candidates::<Runtime>()
+		}
+
 		fn disabled_validators() -> Vec<ValidatorIndex> {
 			parachains_staging_runtime_api_impl::disabled_validators::<Runtime>()
 		}
@@ -218,4 +218,38 @@ impl<T: frame_system::Config> runtime_common::paras_registrar::WeightInfo for We
 			.saturating_add(Weight::from_parts(855, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Registrar Paras (r:1 w:1)
+	/// Proof Skipped: Registrar Paras (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_manager() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `689`
+		//  Estimated: `6196`
+		// Minimum execution time: 40_107_000 picoseconds.
+		Weight::from_parts(40_912_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Registrar Paras (r:1 w:1)
+	/// Proof Skipped: Registrar Paras (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras Heads (r:1 w:0)
+	/// Proof Skipped: Paras Heads (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras CurrentCodeHash (r:1 w:0)
+	/// Proof Skipped: Paras CurrentCodeHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras CodeByHash (r:1 w:0)
+	/// Proof Skipped: Paras CodeByHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn poke_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3179341`
+		//  Estimated: `3182806`
+		// Minimum execution time: 3_912_047_000 picoseconds.
+		Weight::from_parts(4_021_385_000, 0)
+			.saturating_add(Weight::from_parts(0, 3182806))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
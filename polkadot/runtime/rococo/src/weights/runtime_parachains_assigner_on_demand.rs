@@ -54,6 +54,8 @@ impl<T: frame_system::Config> runtime_parachains::assigner_on_demand::WeightInfo
 	/// Proof: `Paras::ParaLifecycles` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `OnDemandAssignmentProvider::OnDemandQueue` (r:1 w:1)
 	/// Proof: `OnDemandAssignmentProvider::OnDemandQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::Revenue` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::Revenue` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `s` is `[1, 9999]`.
 	fn place_order_keep_alive(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -64,8 +66,8 @@ impl<T: frame_system::Config> runtime_parachains::assigner_on_demand::WeightInfo
 			.saturating_add(Weight::from_parts(0, 3762))
 			// Standard Error: 129
 			.saturating_add(Weight::from_parts(14_041, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(s.into()))
 	}
 	/// Storage: `OnDemandAssignmentProvider::SpotTraffic` (r:1 w:0)
@@ -74,6 +76,8 @@ impl<T: frame_system::Config> runtime_parachains::assigner_on_demand::WeightInfo
 	/// Proof: `Paras::ParaLifecycles` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `OnDemandAssignmentProvider::OnDemandQueue` (r:1 w:1)
 	/// Proof: `OnDemandAssignmentProvider::OnDemandQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::Revenue` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::Revenue` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `s` is `[1, 9999]`.
 	fn place_order_allow_death(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -84,8 +88,32 @@ impl<T: frame_system::Config> runtime_parachains::assigner_on_demand::WeightInfo
 			.saturating_add(Weight::from_parts(0, 3762))
 			// Standard Error: 143
 			.saturating_add(Weight::from_parts(14_215, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 4).saturating_mul(s.into()))
+	}
+	/// Storage: `OnDemandAssignmentProvider::SpotTraffic` (r:1 w:0)
+	/// Proof: `OnDemandAssignmentProvider::SpotTraffic` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::Credits` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::Credits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Paras::ParaLifecycles` (r:1 w:0)
+	/// Proof: `Paras::ParaLifecycles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::OnDemandQueue` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::OnDemandQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::Revenue` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::Revenue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 9999]`.
+	fn place_order_with_credits(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349 + s * (4 ±0)`
+		//  Estimated: `3814 + s * (4 ±0)`
+		// Minimum execution time: 36_903_000 picoseconds.
+		Weight::from_parts(38_517_212, 0)
+			.saturating_add(Weight::from_parts(0, 3814))
+			// Standard Error: 141
+			.saturating_add(Weight::from_parts(14_187, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(s.into()))
 	}
 }
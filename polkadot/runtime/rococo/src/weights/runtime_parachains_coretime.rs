@@ -53,6 +53,40 @@ impl<T: frame_system::Config + configuration::Config> runtime_parachains::coreti
 	fn request_core_count() -> Weight {
 		<T as configuration::Config>::WeightInfo::set_config_with_u32()
 	}
+	/// Storage: `OnDemandAssignmentProvider::RevenueClaimedUntil` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::RevenueClaimedUntil` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `OnDemandAssignmentProvider::Revenue` (r:600 w:600)
+	/// Proof: `OnDemandAssignmentProvider::Revenue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `Dmp::DeliveryFeeFactor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SupportedVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueueHeads` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_revenue_info_at() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `22147`
+		//  Estimated: `1507537`
+		// Minimum execution time: 1_262_418_000 picoseconds.
+		Weight::from_parts(1_279_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 1507537))
+			.saturating_add(T::DbWeight::get().reads(605))
+			.saturating_add(T::DbWeight::get().writes(603))
+	}
+	/// Storage: `OnDemandAssignmentProvider::Credits` (r:1 w:1)
+	/// Proof: `OnDemandAssignmentProvider::Credits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn credit_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1`
+		//  Estimated: `3466`
+		// Minimum execution time: 7_972_000 picoseconds.
+		Weight::from_parts(8_228_000, 0)
+			.saturating_add(Weight::from_parts(0, 3466))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `CoreTimeAssignmentProvider::CoreDescriptors` (r:1 w:1)
 	/// Proof: `CoreTimeAssignmentProvider::CoreDescriptors` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CoreTimeAssignmentProvider::CoreSchedules` (r:0 w:1)
@@ -319,16 +319,96 @@ impl<T: frame_system::Config> runtime_parachains::hrmp::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Hrmp::HrmpChannels` (r:1 w:1)
 	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn poke_channel_deposits() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263`
 		//  Estimated: `3728`
-		// Minimum execution time: 173_371_000 picoseconds.
-		Weight::from_parts(175_860_000, 0)
+		// Minimum execution time: 175_771_000 picoseconds.
+		Weight::from_parts(178_380_000, 0)
 			.saturating_add(Weight::from_parts(0, 3728))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Hrmp::HrmpChannels` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpCloseChannelRequests` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpCloseChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn hrmp_init_update_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `487`
+		//  Estimated: `3952`
+		// Minimum execution time: 22_886_000 picoseconds.
+		Weight::from_parts(23_511_000, 0)
+			.saturating_add(Weight::from_parts(0, 3952))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn hrmp_accept_update_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4003`
+		// Minimum execution time: 19_614_000 picoseconds.
+		Weight::from_parts(20_088_000, 0)
+			.saturating_add(Weight::from_parts(0, 4003))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 128]`.
+	fn hrmp_cancel_update_request(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1081 + c * (13 ±0)`
+		//  Estimated: `4350 + c * (15 ±0)`
+		// Minimum execution time: 21_773_000 picoseconds.
+		Weight::from_parts(25_512_318, 0)
+			.saturating_add(Weight::from_parts(0, 4350))
+			// Standard Error: 812
+			.saturating_add(Weight::from_parts(48_915, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 15).saturating_mul(c.into()))
+	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 128]`.
+	fn force_process_hrmp_update(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + c * (210 ±0)`
+		//  Estimated: `1787 + c * (2685 ±0)`
+		// Minimum execution time: 5_904_000 picoseconds.
+		Weight::from_parts(1_103_417, 0)
+			.saturating_add(Weight::from_parts(0, 1787))
+			// Standard Error: 13_972
+			.saturating_add(Weight::from_parts(9_785_314, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 }
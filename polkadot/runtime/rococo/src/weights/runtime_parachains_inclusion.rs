@@ -71,4 +71,47 @@ impl<T: frame_system::Config> runtime_parachains::inclusion::WeightInfo for Weig
 			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 	}
+	/// Storage: ParaInclusion PartialUpwardMessages (r:4 w:1)
+	/// Proof Skipped: ParaInclusion PartialUpwardMessages (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParaInclusion PartialUpwardMessageTimeouts (r:1 w:1)
+	/// Proof Skipped: ParaInclusion PartialUpwardMessageTimeouts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParaInclusion UpwardMessageFragments (r:0 w:1)
+	/// Proof Skipped: ParaInclusion UpwardMessageFragments (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 131072]`.
+	fn receive_upward_message_fragment(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `10312`
+		// Minimum execution time: 31_207_000 picoseconds.
+		Weight::from_parts(29_844_517, 0)
+			.saturating_add(Weight::from_parts(0, 10312))
+			// Standard Error: 7
+			.saturating_add(Weight::from_parts(1_194, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: ParaInclusion PartialUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParaInclusion PartialUpwardMessages (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParaInclusion UpwardMessageFragments (r:999 w:999)
+	/// Proof Skipped: ParaInclusion UpwardMessageFragments (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 1000]`.
+	/// The range of component `s` is `[1, 131072]`.
+	fn reassemble_upward_message(c: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `227 + c * (44 ±0) + s * (1 ±0)`
+		//  Estimated: `3692 + c * (2519 ±0) + s * (1 ±0)`
+		// Minimum execution time: 19_311_000 picoseconds.
+		Weight::from_parts(14_902_874, 0)
+			.saturating_add(Weight::from_parts(0, 3692))
+			// Standard Error: 2_874
+			.saturating_add(Weight::from_parts(5_318_449, 0).saturating_mul(c.into()))
+			// Standard Error: 21
+			.saturating_add(Weight::from_parts(1_421, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
 }
@@ -291,4 +291,48 @@ impl<T: frame_system::Config> runtime_parachains::paras::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Paras AuthorizedCodeHash (r:0 w:1)
+	/// Proof Skipped: Paras AuthorizedCodeHash (max_values: None, max_size: None, mode: Measured)
+	fn authorize_force_set_current_code_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_012_000 picoseconds.
+		Weight::from_parts(9_417_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Paras ParaLifecycles (r:2 w:0)
+	/// Proof Skipped: Paras ParaLifecycles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras AuthorizedCodeHash (r:1 w:1)
+	/// Proof Skipped: Paras AuthorizedCodeHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Paras PvfActiveVoteMap (r:1 w:0)
+	/// Proof Skipped: Paras PvfActiveVoteMap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras CodeByHash (r:1 w:0)
+	/// Proof Skipped: Paras CodeByHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras CurrentCodeHash (r:1 w:1)
+	/// Proof Skipped: Paras CurrentCodeHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras PastCodeMeta (r:1 w:1)
+	/// Proof Skipped: Paras PastCodeMeta (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras PastCodePruning (r:1 w:1)
+	/// Proof Skipped: Paras PastCodePruning (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Paras CodeByHashRefs (r:1 w:1)
+	/// Proof Skipped: Paras CodeByHashRefs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras PastCodeHash (r:0 w:1)
+	/// Proof Skipped: Paras PastCodeHash (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 3145728]`.
+	fn apply_authorized_force_set_current_code(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8449`
+		//  Estimated: `11914`
+		// Minimum execution time: 31_902_000 picoseconds.
+		Weight::from_parts(32_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 11914))
+			// Standard Error: 5
+			.saturating_add(Weight::from_parts(2_011, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 }
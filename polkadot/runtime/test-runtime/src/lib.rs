@@ -476,6 +476,7 @@ impl pallet_sudo::Config for Runtime {
 
 impl parachains_configuration::Config for Runtime {
 	type WeightInfo = parachains_configuration::TestWeightInfo;
+	type MaxUmpMessageLen = parachains_inclusion::MaxUmpMessageLenOf<Runtime>;
 }
 
 impl parachains_shared::Config for Runtime {}
@@ -485,6 +486,8 @@ impl parachains_inclusion::Config for Runtime {
 	type DisputesHandler = ParasDisputes;
 	type RewardValidators = RewardValidatorsWithEraPoints<Runtime>;
 	type MessageQueue = ();
+	type MaxFragmentedUpwardMessages = ConstU32<4>;
+	type UpwardMessageFragmentTimeout = ConstU32<10>;
 	type WeightInfo = ();
 }
 
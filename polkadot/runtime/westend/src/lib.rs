@@ -46,7 +46,7 @@ use pallet_transaction_payment::{CurrencyAdapter, FeeDetails, RuntimeDispatchInf
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::{
 	slashing,
-	vstaging::{ApprovalVotingParams, NodeFeatures},
+	vstaging::{ApprovalVotingParams, DroppedCandidate, NodeFeatures},
 	AccountId, AccountIndex, Balance, BlockNumber, CandidateEvent, CandidateHash,
 	CommittedCandidateReceipt, CoreState, DisputeState, ExecutorParams, GroupRotationInfo, Hash,
 	Id as ParaId, InboundDownwardMessage, InboundHrmpMessage, Moment, Nonce,
@@ -98,10 +98,13 @@ use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use xcm::{
-	latest::{InteriorMultiLocation, Junction, Junction::PalletInstance},
-	VersionedMultiLocation,
+	latest::{ExecuteXcm, InteriorMultiLocation, Junction, Junction::PalletInstance},
+	VersionedMultiLocation, VersionedXcm,
 };
 use xcm_builder::PayOverXcm;
+use xcm_dry_run_runtime_api::{
+	Error as XcmDryRunApiError, ExtrinsicDryRunEffects, XcmDryRunEffects,
+};
 
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -1115,6 +1118,7 @@ impl parachains_origin::Config for Runtime {}
 
 impl parachains_configuration::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_configuration::WeightInfo<Runtime>;
+	type MaxUmpMessageLen = parachains_inclusion::MaxUmpMessageLenOf<Runtime>;
 }
 
 impl parachains_shared::Config for Runtime {}
@@ -1123,11 +1127,18 @@ impl parachains_session_info::Config for Runtime {
 	type ValidatorSet = Historical;
 }
 
+parameter_types! {
+	pub const MaxFragmentedUpwardMessages: u32 = 4;
+	pub const UpwardMessageFragmentTimeout: BlockNumber = 1 * HOURS;
+}
+
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
 	type RewardValidators = parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>;
 	type MessageQueue = MessageQueue;
+	type MaxFragmentedUpwardMessages = MaxFragmentedUpwardMessages;
+	type UpwardMessageFragmentTimeout = UpwardMessageFragmentTimeout;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
 }
 
@@ -1656,6 +1667,8 @@ pub mod migrations {
 		// Migrate Identity pallet for Usernames
 		pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
 		parachains_configuration::migration::v11::MigrateToV11<Runtime>,
+		parachains_configuration::migration::v12::MigrateToV12<Runtime>,
+		parachains_inclusion::migration::MigrateV0ToV1<Runtime>,
 	);
 }
 
@@ -1796,7 +1809,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(10)]
+	#[api_version(12)]
 	impl primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -1944,6 +1957,15 @@ sp_api::impl_runtime_apis! {
 			parachains_staging_runtime_api_impl::approval_voting_params::<Runtime>()
 		}
 
+		fn unapplied_backer_slashes(
+		) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+			parachains_staging_runtime_api_impl::unapplied_backer_slashes::<Runtime>()
+		}
+
+		fn dropped_candidates() -> Vec<DroppedCandidate> {
+			parachains_staging_runtime_api_impl::dropped_candidates::<Runtime>()
+		}
+
 		fn disabled_validators() -> Vec<ValidatorIndex> {
 			parachains_staging_runtime_api_impl::disabled_validators::<Runtime>()
 		}
@@ -2190,6 +2212,70 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_runtime_api::XcmDryRunApi<Block, RuntimeCall, RuntimeEvent> for Runtime {
+		fn dry_run_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+		) -> Result<ExtrinsicDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			System::reset_events();
+			let (result, forwarded_xcms) =
+				xcm_builder::record_sent_messages(|| Executive::apply_extrinsic(extrinsic));
+			let execution_result = result.map_err(|error| {
+				log::error!(
+					target: "xcm::XcmDryRunApi::dry_run_extrinsic",
+					"Applying extrinsic failed with error {:?}",
+					error,
+				);
+				XcmDryRunApiError::InvalidExtrinsic
+			})?;
+			let emitted_events =
+				System::read_events_no_consensus().map(|record| record.event).collect();
+			Ok(ExtrinsicDryRunEffects {
+				execution_result,
+				emitted_events,
+				forwarded_xcms: xcm_dry_run_runtime_api::versioned_forwarded_xcms(forwarded_xcms),
+			})
+		}
+
+		fn dry_run_xcm(
+			origin_location: VersionedMultiLocation,
+			xcm: VersionedXcm<RuntimeCall>,
+		) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			let origin_location: xcm::latest::MultiLocation =
+				origin_location.try_into().map_err(|()| {
+					log::error!(
+						target: "xcm::XcmDryRunApi::dry_run_xcm",
+						"Location version conversion failed",
+					);
+					XcmDryRunApiError::VersionedConversionFailed
+				})?;
+			let xcm: xcm::latest::Xcm<RuntimeCall> = xcm.try_into().map_err(|()| {
+				log::error!(
+					target: "xcm::XcmDryRunApi::dry_run_xcm",
+					"Xcm version conversion failed",
+				);
+				XcmDryRunApiError::VersionedConversionFailed
+			})?;
+			let mut hash = xcm.using_encoded(sp_core::hashing::blake2_256);
+			System::reset_events();
+			let (execution_result, forwarded_xcms) = xcm_builder::record_sent_messages(|| {
+				xcm_executor::XcmExecutor::<xcm_config::XcmConfig>::prepare_and_execute(
+					origin_location,
+					xcm,
+					&mut hash,
+					Weight::MAX,
+					Weight::zero(),
+				)
+			});
+			let emitted_events =
+				System::read_events_no_consensus().map(|record| record.event).collect();
+			Ok(XcmDryRunEffects {
+				execution_result,
+				emitted_events,
+				forwarded_xcms: xcm_dry_run_runtime_api::versioned_forwarded_xcms(forwarded_xcms),
+			})
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<
 		Block,
 		AccountId,
@@ -312,16 +312,96 @@ impl<T: frame_system::Config> runtime_parachains::hrmp::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Hrmp::HrmpChannels` (r:1 w:1)
 	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn poke_channel_deposits() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263`
 		//  Estimated: `3728`
-		// Minimum execution time: 173_371_000 picoseconds.
-		Weight::from_parts(175_860_000, 0)
+		// Minimum execution time: 175_771_000 picoseconds.
+		Weight::from_parts(178_380_000, 0)
 			.saturating_add(Weight::from_parts(0, 3728))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Hrmp::HrmpChannels` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpCloseChannelRequests` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpCloseChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn hrmp_init_update_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `454`
+		//  Estimated: `3919`
+		// Minimum execution time: 17_518_000 picoseconds.
+		Weight::from_parts(17_974_000, 0)
+			.saturating_add(Weight::from_parts(0, 3919))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn hrmp_accept_update_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `505`
+		//  Estimated: `3970`
+		// Minimum execution time: 14_636_000 picoseconds.
+		Weight::from_parts(14_956_000, 0)
+			.saturating_add(Weight::from_parts(0, 3970))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 128]`.
+	fn hrmp_cancel_update_request(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1048 + c * (13 ±0)`
+		//  Estimated: `4317 + c * (15 ±0)`
+		// Minimum execution time: 17_902_000 picoseconds.
+		Weight::from_parts(20_736_094, 0)
+			.saturating_add(Weight::from_parts(0, 4317))
+			// Standard Error: 694
+			.saturating_add(Weight::from_parts(40_213, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 15).saturating_mul(c.into()))
+	}
+	/// Storage: `Hrmp::HrmpUpdateChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpUpdateChannelRequests` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpUpdateChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 128]`.
+	fn force_process_hrmp_update(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `289 + c * (210 ±0)`
+		//  Estimated: `1754 + c * (2685 ±0)`
+		// Minimum execution time: 4_718_000 picoseconds.
+		Weight::from_parts(2_361_552, 0)
+			.saturating_add(Weight::from_parts(0, 1754))
+			// Standard Error: 11_408
+			.saturating_add(Weight::from_parts(8_047_906, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 }
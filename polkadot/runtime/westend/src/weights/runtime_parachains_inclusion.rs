@@ -72,4 +72,47 @@ impl<T: frame_system::Config> runtime_parachains::inclusion::WeightInfo for Weig
 			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 	}
+	/// Storage: ParaInclusion PartialUpwardMessages (r:4 w:1)
+	/// Proof Skipped: ParaInclusion PartialUpwardMessages (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParaInclusion PartialUpwardMessageTimeouts (r:1 w:1)
+	/// Proof Skipped: ParaInclusion PartialUpwardMessageTimeouts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParaInclusion UpwardMessageFragments (r:0 w:1)
+	/// Proof Skipped: ParaInclusion UpwardMessageFragments (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 131072]`.
+	fn receive_upward_message_fragment(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `125`
+		//  Estimated: `10025`
+		// Minimum execution time: 34_591_000 picoseconds.
+		Weight::from_parts(35_117_302, 0)
+			.saturating_add(Weight::from_parts(0, 10025))
+			// Standard Error: 11
+			.saturating_add(Weight::from_parts(1_386, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: ParaInclusion PartialUpwardMessages (r:1 w:1)
+	/// Proof Skipped: ParaInclusion PartialUpwardMessages (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParaInclusion UpwardMessageFragments (r:999 w:999)
+	/// Proof Skipped: ParaInclusion UpwardMessageFragments (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[1, 1000]`.
+	/// The range of component `s` is `[1, 131072]`.
+	fn reassemble_upward_message(c: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93 + c * (44 ±0) + s * (1 ±0)`
+		//  Estimated: `3558 + c * (2519 ±0) + s * (1 ±0)`
+		// Minimum execution time: 21_846_000 picoseconds.
+		Weight::from_parts(17_265_190, 0)
+			.saturating_add(Weight::from_parts(0, 3558))
+			// Standard Error: 3_617
+			.saturating_add(Weight::from_parts(6_042_781, 0).saturating_mul(c.into()))
+			// Standard Error: 29
+			.saturating_add(Weight::from_parts(1_642, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
 }
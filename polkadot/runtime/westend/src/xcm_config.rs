@@ -47,8 +47,8 @@ use xcm_builder::{
 	ChildParachainConvertsVia, DescribeBodyTerminal, DescribeFamily, HashedDescription, IsConcrete,
 	MintLocation, OriginToPluralityVoice, SignedAccountId32AsNative, SignedToAccountId32,
 	SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
-	XcmFeeToAccount,
+	WeightInfoBounds, WithComputedOrigin, WithMessageRecording, WithUniqueTopic,
+	XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 use xcm_executor::XcmExecutor;
 
@@ -107,10 +107,12 @@ pub type PriceForChildParachainDelivery =
 	ExponentialPrice<FeeAssetId, BaseDeliveryFee, TransactionByteFee, Dmp>;
 
 /// The XCM router. When we want to send an XCM message, we use this type. It amalgamates all of our
-/// individual routers.
+/// individual routers. Sent messages can be recorded so that they show up in dry-runs.
 pub type XcmRouter = WithUniqueTopic<
-	// Only one router so far - use DMP to communicate with child parachains.
-	ChildParachainRouter<Runtime, XcmPallet, PriceForChildParachainDelivery>,
+	WithMessageRecording<
+		// Only one router so far - use DMP to communicate with child parachains.
+		ChildParachainRouter<Runtime, XcmPallet, PriceForChildParachainDelivery>,
+	>,
 >;
 
 parameter_types! {
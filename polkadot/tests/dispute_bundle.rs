@@ -0,0 +1,62 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use assert_cmd::cargo::cargo_bin;
+use std::process::Command;
+use tempfile::tempdir;
+
+#[test]
+fn export_dispute_fails_without_votes() {
+	let tmpdir = tempdir().expect("could not create temp dir");
+	let bundle = tmpdir.path().join("bundle");
+
+	let output = Command::new(cargo_bin("polkadot"))
+		.args(["export-dispute", "--dev", "--session", "0", "--candidate-hash"])
+		.arg(format!("0x{}", "01".repeat(32)))
+		.arg("--output")
+		.arg(&bundle)
+		.arg("-d")
+		.arg(tmpdir.path())
+		.output()
+		.unwrap();
+
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stderr).contains("No votes known"));
+	assert!(!bundle.exists());
+}
+
+#[test]
+fn replay_dispute_rejects_unknown_bundle_version() {
+	let tmpdir = tempdir().expect("could not create temp dir");
+	let bundle = tmpdir.path().join("bundle");
+	// A SCALE encoded version 2 followed by garbage.
+	std::fs::write(&bundle, [2, 0, 0, 0, 0xff, 0xff]).unwrap();
+
+	let output = Command::new(cargo_bin("polkadot"))
+		.args(["replay-dispute", "--dev"])
+		.arg(&bundle)
+		.arg("-d")
+		.arg(tmpdir.path())
+		.output()
+		.unwrap();
+
+	assert!(!output.status.success());
+	assert!(
+		String::from_utf8_lossy(&output.stderr).contains("Unsupported dispute bundle version 2")
+	);
+}
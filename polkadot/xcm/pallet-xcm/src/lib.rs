@@ -48,7 +48,7 @@ use sp_runtime::{
 	RuntimeDebug,
 };
 use sp_std::{boxed::Box, marker::PhantomData, prelude::*, result::Result, vec};
-use xcm::{latest::QueryResponseInfo, prelude::*};
+use xcm::{latest::QueryResponseInfo, prelude::*, MAX_XCM_VERSION};
 use xcm_builder::{
 	ExecuteController, ExecuteControllerWeightInfo, QueryController, QueryControllerWeightInfo,
 	SendController, SendControllerWeightInfo,
@@ -2604,7 +2604,7 @@ impl<T: Config> WrapVersion for Pallet<T> {
 				);
 				()
 			})
-			.and_then(|v| xcm.into().into_version(v.min(XCM_VERSION)))
+			.and_then(|v| xcm.into().into_version(v.min(MAX_XCM_VERSION)))
 	}
 }
 
@@ -30,7 +30,7 @@ use frame_support::{
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
-use xcm::{latest::QueryResponseInfo, prelude::*};
+use xcm::{latest::QueryResponseInfo, prelude::*, MAX_XCM_VERSION};
 use xcm_builder::AllowKnownQueryResponses;
 use xcm_executor::{
 	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
@@ -1064,3 +1064,29 @@ fn get_and_wrap_version_works() {
 		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), vec![(remote_b.into(), 2)]);
 	})
 }
+
+#[test]
+fn wrap_version_supports_versions_ahead_of_latest() {
+	new_test_ext_with_balances_and_xcm_version(vec![], Some(XCM_VERSION)).execute_with(|| {
+		let remote_v4: MultiLocation = Parachain(1000).into();
+		let remote_future: MultiLocation = Parachain(1001).into();
+		assert_ok!(XcmPallet::force_xcm_version(
+			RuntimeOrigin::root(),
+			Box::new(remote_v4),
+			xcm::v4::VERSION
+		));
+		assert_ok!(XcmPallet::force_xcm_version(
+			RuntimeOrigin::root(),
+			Box::new(remote_future),
+			MAX_XCM_VERSION + 1
+		));
+
+		let message = Xcm::<()>(vec![ClearOrigin]);
+		let expected = VersionedXcm::V4(xcm::v4::Xcm(vec![xcm::v4::Instruction::ClearOrigin]));
+
+		// a destination speaking v4 gets the message wrapped as v4 even though `latest` is v3
+		assert_eq!(XcmPallet::wrap_version(&remote_v4, message.clone()), Ok(expected.clone()));
+		// a destination ahead of us gets the newest version we are able to produce
+		assert_eq!(XcmPallet::wrap_version(&remote_future, message), Ok(expected));
+	})
+}
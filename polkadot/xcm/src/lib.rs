@@ -30,6 +30,7 @@ use scale_info::TypeInfo;
 
 pub mod v2;
 pub mod v3;
+pub mod v4;
 
 pub mod lts {
 	pub use super::v3::*;
@@ -51,6 +52,12 @@ pub const MAX_XCM_DECODE_DEPTH: u32 = 8;
 /// A version of XCM.
 pub type Version = u32;
 
+/// The most recent XCM version that can be encoded, decoded and converted into.
+///
+/// This may be ahead of `latest::VERSION`, which is the version the executor and the rest of the
+/// XCM machinery are expressed in.
+pub const MAX_XCM_VERSION: Version = v4::VERSION;
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum Unsupported {}
 impl Encode for Unsupported {}
@@ -225,13 +232,232 @@ macro_rules! versioned_type {
 			}
 		}
 	};
+
+	($(#[$attr:meta])* pub enum $n:ident {
+		$(#[$index3:meta])+
+		V3($v3:ty),
+		$(#[$index4:meta])+
+		V4($v4:ty),
+	}) => {
+		#[derive(Derivative, Encode, Decode, TypeInfo)]
+		#[derivative(
+			Clone(bound = ""),
+			Eq(bound = ""),
+			PartialEq(bound = ""),
+			Debug(bound = "")
+		)]
+		#[codec(encode_bound())]
+		#[codec(decode_bound())]
+		#[scale_info(replace_segment("staging_xcm", "xcm"))]
+		$(#[$attr])*
+		pub enum $n {
+			$(#[$index3])*
+			V3($v3),
+			$(#[$index4])*
+			V4($v4),
+		}
+		impl $n {
+			pub fn try_as<T>(&self) -> Result<&T, ()> where Self: TryAs<T> {
+				<Self as TryAs<T>>::try_as(&self)
+			}
+		}
+		impl TryAs<$v3> for $n {
+			fn try_as(&self) -> Result<&$v3, ()> {
+				match &self {
+					Self::V3(ref x) => Ok(x),
+					_ => Err(()),
+				}
+			}
+		}
+		impl TryAs<$v4> for $n {
+			fn try_as(&self) -> Result<&$v4, ()> {
+				match &self {
+					Self::V4(ref x) => Ok(x),
+					_ => Err(()),
+				}
+			}
+		}
+		impl IntoVersion for $n {
+			fn into_version(self, n: Version) -> Result<Self, ()> {
+				Ok(match n {
+					3 => Self::V3(self.try_into()?),
+					4 => Self::V4(self.try_into()?),
+					_ => return Err(()),
+				})
+			}
+		}
+		impl From<$v4> for $n {
+			fn from(x: $v4) -> Self {
+				$n::V4(x)
+			}
+		}
+		impl<T: Into<$v3>> From<T> for $n {
+			fn from(x: T) -> Self {
+				$n::V3(x.into())
+			}
+		}
+		impl TryFrom<$n> for $v3 {
+			type Error = ();
+			fn try_from(x: $n) -> Result<Self, ()> {
+				use $n::*;
+				match x {
+					V3(x) => Ok(x),
+					V4(x) => x.try_into(),
+				}
+			}
+		}
+		impl TryFrom<$n> for $v4 {
+			type Error = ();
+			fn try_from(x: $n) -> Result<Self, ()> {
+				use $n::*;
+				match x {
+					V3(x) => x.try_into(),
+					V4(x) => Ok(x),
+				}
+			}
+		}
+		impl MaxEncodedLen for $n {
+			fn max_encoded_len() -> usize {
+				<$v3>::max_encoded_len().max(<$v4>::max_encoded_len())
+			}
+		}
+	};
+
+	($(#[$attr:meta])* pub enum $n:ident {
+		$(#[$index2:meta])+
+		V2($v2:ty),
+		$(#[$index3:meta])+
+		V3($v3:ty),
+		$(#[$index4:meta])+
+		V4($v4:ty),
+	}) => {
+		#[derive(Derivative, Encode, Decode, TypeInfo)]
+		#[derivative(
+			Clone(bound = ""),
+			Eq(bound = ""),
+			PartialEq(bound = ""),
+			Debug(bound = "")
+		)]
+		#[codec(encode_bound())]
+		#[codec(decode_bound())]
+		#[scale_info(replace_segment("staging_xcm", "xcm"))]
+		$(#[$attr])*
+		pub enum $n {
+			$(#[$index2])*
+			V2($v2),
+			$(#[$index3])*
+			V3($v3),
+			$(#[$index4])*
+			V4($v4),
+		}
+		impl $n {
+			pub fn try_as<T>(&self) -> Result<&T, ()> where Self: TryAs<T> {
+				<Self as TryAs<T>>::try_as(&self)
+			}
+		}
+		impl TryAs<$v2> for $n {
+			fn try_as(&self) -> Result<&$v2, ()> {
+				match &self {
+					Self::V2(ref x) => Ok(x),
+					_ => Err(()),
+				}
+			}
+		}
+		impl TryAs<$v3> for $n {
+			fn try_as(&self) -> Result<&$v3, ()> {
+				match &self {
+					Self::V3(ref x) => Ok(x),
+					_ => Err(()),
+				}
+			}
+		}
+		impl TryAs<$v4> for $n {
+			fn try_as(&self) -> Result<&$v4, ()> {
+				match &self {
+					Self::V4(ref x) => Ok(x),
+					_ => Err(()),
+				}
+			}
+		}
+		impl IntoVersion for $n {
+			fn into_version(self, n: Version) -> Result<Self, ()> {
+				Ok(match n {
+					1 | 2 => Self::V2(self.try_into()?),
+					3 => Self::V3(self.try_into()?),
+					4 => Self::V4(self.try_into()?),
+					_ => return Err(()),
+				})
+			}
+		}
+		impl From<$v2> for $n {
+			fn from(x: $v2) -> Self {
+				$n::V2(x)
+			}
+		}
+		impl From<$v4> for $n {
+			fn from(x: $v4) -> Self {
+				$n::V4(x)
+			}
+		}
+		impl<T: Into<$v3>> From<T> for $n {
+			fn from(x: T) -> Self {
+				$n::V3(x.into())
+			}
+		}
+		impl TryFrom<$n> for $v2 {
+			type Error = ();
+			fn try_from(x: $n) -> Result<Self, ()> {
+				use $n::*;
+				match x {
+					V2(x) => Ok(x),
+					V3(x) => x.try_into(),
+					V4(x) => {
+						let v3: $v3 = x.try_into()?;
+						v3.try_into()
+					},
+				}
+			}
+		}
+		impl TryFrom<$n> for $v3 {
+			type Error = ();
+			fn try_from(x: $n) -> Result<Self, ()> {
+				use $n::*;
+				match x {
+					V2(x) => x.try_into(),
+					V3(x) => Ok(x),
+					V4(x) => x.try_into(),
+				}
+			}
+		}
+		impl TryFrom<$n> for $v4 {
+			type Error = ();
+			fn try_from(x: $n) -> Result<Self, ()> {
+				use $n::*;
+				match x {
+					V2(x) => {
+						let v3: $v3 = x.try_into()?;
+						v3.try_into()
+					},
+					V3(x) => x.try_into(),
+					V4(x) => Ok(x),
+				}
+			}
+		}
+		impl MaxEncodedLen for $n {
+			fn max_encoded_len() -> usize {
+				<$v3>::max_encoded_len().max(<$v4>::max_encoded_len())
+			}
+		}
+	};
 }
 
 versioned_type! {
-	/// A single version's `Response` value, together with its version code.
+	/// A single version's `AssetId` value, together with its version code.
 	pub enum VersionedAssetId {
 		#[codec(index = 3)]
 		V3(v3::AssetId),
+		#[codec(index = 4)]
+		V4(v4::AssetId),
 	}
 }
 
@@ -242,6 +468,8 @@ versioned_type! {
 		V2(v2::Response),
 		#[codec(index = 3)]
 		V3(v3::Response),
+		#[codec(index = 4)]
+		V4(v4::Response),
 	}
 }
 
@@ -262,6 +490,8 @@ versioned_type! {
 		V2(v2::Junction),
 		#[codec(index = 3)]
 		V3(v3::Junction),
+		#[codec(index = 4)]
+		V4(v4::Junction),
 	}
 }
 
@@ -273,6 +503,8 @@ versioned_type! {
 		V2(v2::MultiLocation),
 		#[codec(index = 3)]
 		V3(v3::MultiLocation),
+		#[codec(index = 4)]
+		V4(v4::Location),
 	}
 }
 
@@ -283,6 +515,8 @@ versioned_type! {
 		V2(v2::InteriorMultiLocation),
 		#[codec(index = 3)]
 		V3(v3::InteriorMultiLocation),
+		#[codec(index = 4)]
+		V4(v4::InteriorLocation),
 	}
 }
 
@@ -293,6 +527,8 @@ versioned_type! {
 		V2(v2::MultiAsset),
 		#[codec(index = 3)]
 		V3(v3::MultiAsset),
+		#[codec(index = 4)]
+		V4(v4::Asset),
 	}
 }
 
@@ -303,6 +539,8 @@ versioned_type! {
 		V2(v2::MultiAssets),
 		#[codec(index = 3)]
 		V3(v3::MultiAssets),
+		#[codec(index = 4)]
+		V4(v4::Assets),
 	}
 }
 
@@ -318,6 +556,8 @@ pub enum VersionedXcm<RuntimeCall> {
 	V2(v2::Xcm<RuntimeCall>),
 	#[codec(index = 3)]
 	V3(v3::Xcm<RuntimeCall>),
+	#[codec(index = 4)]
+	V4(v4::Xcm<RuntimeCall>),
 }
 
 impl<C> IntoVersion for VersionedXcm<C> {
@@ -325,6 +565,7 @@ impl<C> IntoVersion for VersionedXcm<C> {
 		Ok(match n {
 			2 => Self::V2(self.try_into()?),
 			3 => Self::V3(self.try_into()?),
+			4 => Self::V4(self.try_into()?),
 			_ => return Err(()),
 		})
 	}
@@ -342,6 +583,12 @@ impl<RuntimeCall> From<v3::Xcm<RuntimeCall>> for VersionedXcm<RuntimeCall> {
 	}
 }
 
+impl<RuntimeCall> From<v4::Xcm<RuntimeCall>> for VersionedXcm<RuntimeCall> {
+	fn from(x: v4::Xcm<RuntimeCall>) -> Self {
+		VersionedXcm::V4(x)
+	}
+}
+
 impl<RuntimeCall> TryFrom<VersionedXcm<RuntimeCall>> for v2::Xcm<RuntimeCall> {
 	type Error = ();
 	fn try_from(x: VersionedXcm<RuntimeCall>) -> Result<Self, ()> {
@@ -349,6 +596,10 @@ impl<RuntimeCall> TryFrom<VersionedXcm<RuntimeCall>> for v2::Xcm<RuntimeCall> {
 		match x {
 			V2(x) => Ok(x),
 			V3(x) => x.try_into(),
+			V4(x) => {
+				let v3: v3::Xcm<RuntimeCall> = x.try_into()?;
+				v3.try_into()
+			},
 		}
 	}
 }
@@ -360,6 +611,22 @@ impl<Call> TryFrom<VersionedXcm<Call>> for v3::Xcm<Call> {
 		match x {
 			V2(x) => x.try_into(),
 			V3(x) => Ok(x),
+			V4(x) => x.try_into(),
+		}
+	}
+}
+
+impl<Call> TryFrom<VersionedXcm<Call>> for v4::Xcm<Call> {
+	type Error = ();
+	fn try_from(x: VersionedXcm<Call>) -> Result<Self, ()> {
+		use VersionedXcm::*;
+		match x {
+			V2(x) => {
+				let v3: v3::Xcm<Call> = x.try_into()?;
+				v3.try_into()
+			},
+			V3(x) => x.try_into(),
+			V4(x) => Ok(x),
 		}
 	}
 }
@@ -424,6 +691,23 @@ impl GetVersion for AlwaysV3 {
 	}
 }
 
+/// `WrapVersion` implementation which attempts to always convert the XCM to version 4 before
+/// wrapping it.
+pub struct AlwaysV4;
+impl WrapVersion for AlwaysV4 {
+	fn wrap_version<Call>(
+		_: &latest::MultiLocation,
+		xcm: impl Into<VersionedXcm<Call>>,
+	) -> Result<VersionedXcm<Call>, ()> {
+		Ok(VersionedXcm::<Call>::V4(xcm.into().try_into()?))
+	}
+}
+impl GetVersion for AlwaysV4 {
+	fn get_version_for(_dest: &latest::MultiLocation) -> Option<Version> {
+		Some(v4::VERSION)
+	}
+}
+
 /// `WrapVersion` implementation which attempts to always convert the XCM to the latest version
 /// before wrapping it.
 pub type AlwaysLatest = AlwaysV3;
@@ -434,10 +718,10 @@ pub type AlwaysLts = AlwaysV3;
 
 pub mod prelude {
 	pub use super::{
-		latest::prelude::*, AlwaysLatest, AlwaysLts, AlwaysV2, AlwaysV3, GetVersion, IntoVersion,
-		Unsupported, Version as XcmVersion, VersionedAssetId, VersionedInteriorMultiLocation,
-		VersionedMultiAsset, VersionedMultiAssets, VersionedMultiLocation, VersionedResponse,
-		VersionedXcm, WrapVersion,
+		latest::prelude::*, AlwaysLatest, AlwaysLts, AlwaysV2, AlwaysV3, AlwaysV4, GetVersion,
+		IntoVersion, Unsupported, Version as XcmVersion, VersionedAssetId,
+		VersionedInteriorMultiLocation, VersionedMultiAsset, VersionedMultiAssets,
+		VersionedMultiLocation, VersionedResponse, VersionedXcm, WrapVersion,
 	};
 }
 
@@ -454,6 +738,12 @@ pub mod opaque {
 		// Then override with the opaque types in v3
 		pub use crate::v3::opaque::{Instruction, Xcm};
 	}
+	pub mod v4 {
+		// Everything from v4
+		pub use crate::v4::*;
+		// Then override with the opaque types in v4
+		pub use crate::v4::opaque::{Instruction, Xcm};
+	}
 
 	pub mod latest {
 		pub use super::v3::*;
@@ -16,9 +16,15 @@
 
 //! Version 3 of the Cross-Consensus Message format data structures.
 
-use super::v2::{
-	Instruction as OldInstruction, Response as OldResponse, WeightLimit as OldWeightLimit,
-	Xcm as OldXcm,
+use super::{
+	v2::{
+		Instruction as OldInstruction, Response as OldResponse, WeightLimit as OldWeightLimit,
+		Xcm as OldXcm,
+	},
+	v4::{
+		Instruction as NewInstruction, QueryResponseInfo as NewQueryResponseInfo,
+		Response as NewResponse, Xcm as NewXcm,
+	},
 };
 use crate::DoubleEncoded;
 use alloc::{vec, vec::Vec};
@@ -1368,6 +1374,151 @@ impl<Call> TryFrom<OldInstruction<Call>> for Instruction<Call> {
 	}
 }
 
+// Convert from a v4 response to a v3 response.
+impl TryFrom<NewResponse> for Response {
+	type Error = ();
+	fn try_from(new: NewResponse) -> result::Result<Self, ()> {
+		use NewResponse::*;
+		Ok(match new {
+			Null => Self::Null,
+			Assets(assets) => Self::Assets(assets.try_into()?),
+			ExecutionResult(result) => Self::ExecutionResult(result),
+			Version(version) => Self::Version(version),
+			PalletsInfo(pallet_info) => Self::PalletsInfo(pallet_info),
+			DispatchResult(maybe_error) => Self::DispatchResult(maybe_error),
+		})
+	}
+}
+
+// Convert from a v4 query response info to a v3 one.
+impl TryFrom<NewQueryResponseInfo> for QueryResponseInfo {
+	type Error = ();
+	fn try_from(new: NewQueryResponseInfo) -> result::Result<Self, ()> {
+		Ok(Self {
+			destination: new.destination.try_into()?,
+			query_id: new.query_id,
+			max_weight: new.max_weight,
+		})
+	}
+}
+
+// Convert from a v4 XCM to a v3 XCM.
+impl<Call> TryFrom<NewXcm<Call>> for Xcm<Call> {
+	type Error = ();
+	fn try_from(new_xcm: NewXcm<Call>) -> result::Result<Self, ()> {
+		Ok(Xcm(new_xcm.0.into_iter().map(TryInto::try_into).collect::<result::Result<_, _>>()?))
+	}
+}
+
+// Convert from a v4 instruction to a v3 instruction.
+impl<Call> TryFrom<NewInstruction<Call>> for Instruction<Call> {
+	type Error = ();
+	fn try_from(new_instruction: NewInstruction<Call>) -> result::Result<Self, ()> {
+		use NewInstruction::*;
+		Ok(match new_instruction {
+			WithdrawAsset(assets) => Self::WithdrawAsset(assets.try_into()?),
+			ReserveAssetDeposited(assets) => Self::ReserveAssetDeposited(assets.try_into()?),
+			ReceiveTeleportedAsset(assets) => Self::ReceiveTeleportedAsset(assets.try_into()?),
+			QueryResponse { query_id, response, max_weight, querier } => Self::QueryResponse {
+				query_id,
+				response: response.try_into()?,
+				max_weight,
+				querier: querier.map(|x| x.try_into()).transpose()?,
+			},
+			TransferAsset { assets, beneficiary } => Self::TransferAsset {
+				assets: assets.try_into()?,
+				beneficiary: beneficiary.try_into()?,
+			},
+			TransferReserveAsset { assets, dest, xcm } => Self::TransferReserveAsset {
+				assets: assets.try_into()?,
+				dest: dest.try_into()?,
+				xcm: xcm.try_into()?,
+			},
+			Transact { origin_kind, require_weight_at_most, call } =>
+				Self::Transact { origin_kind, require_weight_at_most, call: call.into() },
+			HrmpNewChannelOpenRequest { sender, max_message_size, max_capacity } =>
+				Self::HrmpNewChannelOpenRequest { sender, max_message_size, max_capacity },
+			HrmpChannelAccepted { recipient } => Self::HrmpChannelAccepted { recipient },
+			HrmpChannelClosing { initiator, sender, recipient } =>
+				Self::HrmpChannelClosing { initiator, sender, recipient },
+			ClearOrigin => Self::ClearOrigin,
+			DescendOrigin(who) => Self::DescendOrigin(who.try_into()?),
+			ReportError(response_info) => Self::ReportError(response_info.try_into()?),
+			DepositAsset { assets, beneficiary } => Self::DepositAsset {
+				assets: assets.try_into()?,
+				beneficiary: beneficiary.try_into()?,
+			},
+			DepositReserveAsset { assets, dest, xcm } => Self::DepositReserveAsset {
+				assets: assets.try_into()?,
+				dest: dest.try_into()?,
+				xcm: xcm.try_into()?,
+			},
+			ExchangeAsset { give, want, maximal } =>
+				Self::ExchangeAsset { give: give.try_into()?, want: want.try_into()?, maximal },
+			InitiateReserveWithdraw { assets, reserve, xcm } => Self::InitiateReserveWithdraw {
+				assets: assets.try_into()?,
+				reserve: reserve.try_into()?,
+				xcm: xcm.try_into()?,
+			},
+			InitiateTeleport { assets, dest, xcm } => Self::InitiateTeleport {
+				assets: assets.try_into()?,
+				dest: dest.try_into()?,
+				xcm: xcm.try_into()?,
+			},
+			ReportHolding { response_info, assets } => Self::ReportHolding {
+				response_info: response_info.try_into()?,
+				assets: assets.try_into()?,
+			},
+			BuyExecution { fees, weight_limit } =>
+				Self::BuyExecution { fees: fees.try_into()?, weight_limit },
+			RefundSurplus => Self::RefundSurplus,
+			SetErrorHandler(xcm) => Self::SetErrorHandler(xcm.try_into()?),
+			SetAppendix(xcm) => Self::SetAppendix(xcm.try_into()?),
+			ClearError => Self::ClearError,
+			ClaimAsset { assets, ticket } =>
+				Self::ClaimAsset { assets: assets.try_into()?, ticket: ticket.try_into()? },
+			Trap(code) => Self::Trap(code),
+			SubscribeVersion { query_id, max_response_weight } =>
+				Self::SubscribeVersion { query_id, max_response_weight },
+			UnsubscribeVersion => Self::UnsubscribeVersion,
+			BurnAsset(assets) => Self::BurnAsset(assets.try_into()?),
+			ExpectAsset(assets) => Self::ExpectAsset(assets.try_into()?),
+			ExpectOrigin(origin) => Self::ExpectOrigin(origin.map(|x| x.try_into()).transpose()?),
+			ExpectError(error) => Self::ExpectError(error),
+			ExpectTransactStatus(transact_status) => Self::ExpectTransactStatus(transact_status),
+			QueryPallet { module_name, response_info } =>
+				Self::QueryPallet { module_name, response_info: response_info.try_into()? },
+			ExpectPallet { index, name, module_name, crate_major, min_crate_minor } =>
+				Self::ExpectPallet { index, name, module_name, crate_major, min_crate_minor },
+			ReportTransactStatus(response_info) =>
+				Self::ReportTransactStatus(response_info.try_into()?),
+			ClearTransactStatus => Self::ClearTransactStatus,
+			UniversalOrigin(junction) => Self::UniversalOrigin(junction.try_into()?),
+			ExportMessage { network, destination, xcm } => Self::ExportMessage {
+				network,
+				destination: destination.try_into()?,
+				xcm: xcm.try_into()?,
+			},
+			LockAsset { asset, unlocker } =>
+				Self::LockAsset { asset: asset.try_into()?, unlocker: unlocker.try_into()? },
+			UnlockAsset { asset, target } =>
+				Self::UnlockAsset { asset: asset.try_into()?, target: target.try_into()? },
+			NoteUnlockable { asset, owner } =>
+				Self::NoteUnlockable { asset: asset.try_into()?, owner: owner.try_into()? },
+			RequestUnlock { asset, locker } =>
+				Self::RequestUnlock { asset: asset.try_into()?, locker: locker.try_into()? },
+			SetFeesMode { jit_withdraw } => Self::SetFeesMode { jit_withdraw },
+			SetTopic(topic) => Self::SetTopic(topic),
+			ClearTopic => Self::ClearTopic,
+			AliasOrigin(location) => Self::AliasOrigin(location.try_into()?),
+			UnpaidExecution { weight_limit, check_origin } => Self::UnpaidExecution {
+				weight_limit,
+				check_origin: check_origin.map(|x| x.try_into()).transpose()?,
+			},
+		})
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{prelude::*, *};
@@ -0,0 +1,646 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM `Junctions`/`InteriorLocation` datatype.
+
+use super::{Junction, Location, NetworkId};
+use alloc::sync::Arc;
+use core::{convert::TryFrom, mem, result};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Maximum number of `Junction`s that a `Junctions` can contain.
+pub(crate) const MAX_JUNCTIONS: usize = 8;
+
+/// Non-parent junctions that can be constructed, up to the length of 8.
+///
+/// Each variant keeps its junctions in an `Arc`-shared array, so cloning a `Junctions` is cheap.
+/// Rather than matching on the variants directly, prefer `as_slice` (or `Location::unpack`) and
+/// slice patterns, which work the same way regardless of the number of junctions.
+///
+/// Parent junctions cannot be constructed with this type. Refer to `Location` for
+/// instructions on constructing parent junctions.
+#[derive(
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	Debug,
+	TypeInfo,
+	serde::Serialize,
+	serde::Deserialize,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[scale_info(replace_segment("staging_xcm", "xcm"))]
+pub enum Junctions {
+	/// The interpreting consensus system.
+	Here,
+	/// A relative path comprising 1 junction.
+	X1(Arc<[Junction; 1]>),
+	/// A relative path comprising 2 junctions.
+	X2(Arc<[Junction; 2]>),
+	/// A relative path comprising 3 junctions.
+	X3(Arc<[Junction; 3]>),
+	/// A relative path comprising 4 junctions.
+	X4(Arc<[Junction; 4]>),
+	/// A relative path comprising 5 junctions.
+	X5(Arc<[Junction; 5]>),
+	/// A relative path comprising 6 junctions.
+	X6(Arc<[Junction; 6]>),
+	/// A relative path comprising 7 junctions.
+	X7(Arc<[Junction; 7]>),
+	/// A relative path comprising 8 junctions.
+	X8(Arc<[Junction; 8]>),
+}
+
+impl MaxEncodedLen for Junctions {
+	fn max_encoded_len() -> usize {
+		// The variant index, followed by at most `MAX_JUNCTIONS` junctions.
+		Junction::max_encoded_len().saturating_mul(MAX_JUNCTIONS).saturating_add(1)
+	}
+}
+
+pub struct JunctionsIterator(Junctions);
+impl Iterator for JunctionsIterator {
+	type Item = Junction;
+	fn next(&mut self) -> Option<Junction> {
+		self.0.take_first()
+	}
+}
+
+impl DoubleEndedIterator for JunctionsIterator {
+	fn next_back(&mut self) -> Option<Junction> {
+		self.0.take_last()
+	}
+}
+
+impl<'a> IntoIterator for &'a Junctions {
+	type Item = &'a Junction;
+	type IntoIter = core::slice::Iter<'a, Junction>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl IntoIterator for Junctions {
+	type Item = Junction;
+	type IntoIter = JunctionsIterator;
+	fn into_iter(self) -> Self::IntoIter {
+		JunctionsIterator(self)
+	}
+}
+
+impl Junctions {
+	/// Convert `self` into a `Location` containing 0 parents.
+	pub fn into_location(self) -> Location {
+		Location { parents: 0, interior: self }
+	}
+
+	/// Convert `self` into a `Location` containing `n` parents.
+	///
+	/// Similar to `Self::into_location`, with the added ability to specify the number of parent
+	/// junctions.
+	pub fn into_exterior(self, n: u8) -> Location {
+		Location { parents: n, interior: self }
+	}
+
+	/// Return the junctions as a slice.
+	///
+	/// This is the most convenient way to pattern match on a `Junctions` value, e.g.
+	/// `if let [Parachain(id), ..] = junctions.as_slice() { .. }`.
+	pub fn as_slice(&self) -> &[Junction] {
+		match self {
+			Junctions::Here => &[],
+			Junctions::X1(ref a) => &a[..],
+			Junctions::X2(ref a) => &a[..],
+			Junctions::X3(ref a) => &a[..],
+			Junctions::X4(ref a) => &a[..],
+			Junctions::X5(ref a) => &a[..],
+			Junctions::X6(ref a) => &a[..],
+			Junctions::X7(ref a) => &a[..],
+			Junctions::X8(ref a) => &a[..],
+		}
+	}
+
+	/// Return the junctions as a mutable slice.
+	///
+	/// The underlying array is cloned first if it is shared with any other `Junctions` value.
+	pub fn as_slice_mut(&mut self) -> &mut [Junction] {
+		match self {
+			Junctions::Here => &mut [],
+			Junctions::X1(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X2(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X3(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X4(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X5(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X6(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X7(ref mut a) => &mut Arc::make_mut(a)[..],
+			Junctions::X8(ref mut a) => &mut Arc::make_mut(a)[..],
+		}
+	}
+
+	/// Build a `Junctions` value out of a slice of junctions, or `None` if the slice holds more
+	/// than `MAX_JUNCTIONS` items.
+	fn from_slice(junctions: &[Junction]) -> Option<Self> {
+		Some(match *junctions {
+			[] => Junctions::Here,
+			[a] => [a].into(),
+			[a, b] => [a, b].into(),
+			[a, b, c] => [a, b, c].into(),
+			[a, b, c, d] => [a, b, c, d].into(),
+			[a, b, c, d, e] => [a, b, c, d, e].into(),
+			[a, b, c, d, e, f] => [a, b, c, d, e, f].into(),
+			[a, b, c, d, e, f, g] => [a, b, c, d, e, f, g].into(),
+			[a, b, c, d, e, f, g, h] => [a, b, c, d, e, f, g, h].into(),
+			_ => return None,
+		})
+	}
+
+	/// Remove the `NetworkId` value in any `Junction`s.
+	pub fn remove_network_id(&mut self) {
+		self.as_slice_mut().iter_mut().for_each(Junction::remove_network_id);
+	}
+
+	/// Treating `self` as the universal context, return the location of the local consensus system
+	/// from the point of view of the given `target`.
+	pub fn invert_target(&self, target: &Location) -> Result<Location, ()> {
+		let mut itself = self.clone();
+		let mut junctions = Self::Here;
+		for _ in 0..target.parent_count() {
+			junctions = junctions
+				.pushed_front_with(itself.take_last().unwrap_or(Junction::OnlyChild))
+				.map_err(|_| ())?;
+		}
+		let parents = target.interior().len() as u8;
+		Ok(Location::new(parents, junctions))
+	}
+
+	/// Extract the network ID treating this value as a universal location.
+	///
+	/// This will return an `Err` if the first item is not a `GlobalConsensus`, which would indicate
+	/// that this value is not a universal location.
+	pub fn global_consensus(&self) -> Result<NetworkId, ()> {
+		if let Some(Junction::GlobalConsensus(network)) = self.first() {
+			Ok(*network)
+		} else {
+			Err(())
+		}
+	}
+
+	/// Extract the network ID and the interior consensus location, treating this value as a
+	/// universal location.
+	///
+	/// This will return an `Err` if the first item is not a `GlobalConsensus`, which would indicate
+	/// that this value is not a universal location.
+	pub fn split_global(self) -> Result<(NetworkId, Junctions), ()> {
+		match self.split_first() {
+			(location, Some(Junction::GlobalConsensus(network))) => Ok((network, location)),
+			_ => return Err(()),
+		}
+	}
+
+	/// Treat `self` as a universal location and the context of `relative`, returning the universal
+	/// location of relative.
+	///
+	/// This will return an error if `relative` has as many (or more) parents than there are
+	/// junctions in `self`, implying that relative refers into a different global consensus.
+	pub fn within_global(mut self, relative: Location) -> Result<Self, ()> {
+		if self.len() <= relative.parents as usize {
+			return Err(())
+		}
+		for _ in 0..relative.parents {
+			self.take_last();
+		}
+		for j in relative.interior {
+			self.push(j).map_err(|_| ())?;
+		}
+		Ok(self)
+	}
+
+	/// Consumes `self` and returns how `viewer` would address it locally.
+	pub fn relative_to(mut self, viewer: &Junctions) -> Location {
+		let mut i = 0;
+		while match (self.first(), viewer.at(i)) {
+			(Some(x), Some(y)) => x == y,
+			_ => false,
+		} {
+			self = self.split_first().0;
+			// NOTE: Cannot overflow as loop can only iterate at most `MAX_JUNCTIONS` times.
+			i += 1;
+		}
+		// AUDIT NOTES:
+		// - above loop ensures that `i <= viewer.len()`.
+		// - `viewer.len()` is at most `MAX_JUNCTIONS`, so won't overflow a `u8`.
+		Location { parents: (viewer.len() - i) as u8, interior: self }
+	}
+
+	/// Returns first junction, or `None` if the location is empty.
+	pub fn first(&self) -> Option<&Junction> {
+		self.as_slice().first()
+	}
+
+	/// Returns last junction, or `None` if the location is empty.
+	pub fn last(&self) -> Option<&Junction> {
+		self.as_slice().last()
+	}
+
+	/// Splits off the first junction, returning the remaining suffix (first item in tuple) and the
+	/// first element (second item in tuple) or `None` if it was empty.
+	pub fn split_first(self) -> (Junctions, Option<Junction>) {
+		match self.as_slice().split_first() {
+			Some((first, rest)) =>
+				(Self::from_slice(rest).expect("`rest` is shorter than `self`; qed"), Some(*first)),
+			None => (Junctions::Here, None),
+		}
+	}
+
+	/// Splits off the last junction, returning the remaining prefix (first item in tuple) and the
+	/// last element (second item in tuple) or `None` if it was empty.
+	pub fn split_last(self) -> (Junctions, Option<Junction>) {
+		match self.as_slice().split_last() {
+			Some((last, rest)) =>
+				(Self::from_slice(rest).expect("`rest` is shorter than `self`; qed"), Some(*last)),
+			None => (Junctions::Here, None),
+		}
+	}
+
+	/// Removes the first element from `self`, returning it (or `None` if it was empty).
+	pub fn take_first(&mut self) -> Option<Junction> {
+		let mut d = Junctions::Here;
+		mem::swap(&mut *self, &mut d);
+		let (tail, head) = d.split_first();
+		*self = tail;
+		head
+	}
+
+	/// Removes the last element from `self`, returning it (or `None` if it was empty).
+	pub fn take_last(&mut self) -> Option<Junction> {
+		let mut d = Junctions::Here;
+		mem::swap(&mut *self, &mut d);
+		let (head, tail) = d.split_last();
+		*self = head;
+		tail
+	}
+
+	/// Mutates `self` to be appended with `new` or returns an `Err` with `new` if would overflow.
+	pub fn push(&mut self, new: impl Into<Junction>) -> result::Result<(), Junction> {
+		let new = new.into();
+		let mut dummy = Junctions::Here;
+		mem::swap(self, &mut dummy);
+		match dummy.pushed_with(new) {
+			Ok(s) => {
+				*self = s;
+				Ok(())
+			},
+			Err((s, j)) => {
+				*self = s;
+				Err(j)
+			},
+		}
+	}
+
+	/// Mutates `self` to be prepended with `new` or returns an `Err` with `new` if would overflow.
+	pub fn push_front(&mut self, new: impl Into<Junction>) -> result::Result<(), Junction> {
+		let new = new.into();
+		let mut dummy = Junctions::Here;
+		mem::swap(self, &mut dummy);
+		match dummy.pushed_front_with(new) {
+			Ok(s) => {
+				*self = s;
+				Ok(())
+			},
+			Err((s, j)) => {
+				*self = s;
+				Err(j)
+			},
+		}
+	}
+
+	/// Consumes `self` and returns a `Junctions` suffixed with `new`, or an `Err` with the
+	/// original value of `self` and `new` in case of overflow.
+	pub fn pushed_with(self, new: impl Into<Junction>) -> result::Result<Self, (Self, Junction)> {
+		let new = new.into();
+		let len = self.len();
+		if len >= MAX_JUNCTIONS {
+			return Err((self, new))
+		}
+		let mut junctions = [new; MAX_JUNCTIONS];
+		junctions[..len].copy_from_slice(self.as_slice());
+		Ok(Self::from_slice(&junctions[..=len]).expect("`len < MAX_JUNCTIONS`; qed"))
+	}
+
+	/// Consumes `self` and returns a `Junctions` prefixed with `new`, or an `Err` with the
+	/// original value of `self` and `new` in case of overflow.
+	pub fn pushed_front_with(
+		self,
+		new: impl Into<Junction>,
+	) -> result::Result<Self, (Self, Junction)> {
+		let new = new.into();
+		let len = self.len();
+		if len >= MAX_JUNCTIONS {
+			return Err((self, new))
+		}
+		let mut junctions = [new; MAX_JUNCTIONS];
+		junctions[1..=len].copy_from_slice(self.as_slice());
+		Ok(Self::from_slice(&junctions[..=len]).expect("`len < MAX_JUNCTIONS`; qed"))
+	}
+
+	/// Mutate `self` so that it is suffixed with `suffix`.
+	///
+	/// Does not modify `self` and returns `Err` with `suffix` in case of overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// # use staging_xcm::v4::{Junctions, Junction::*, Location};
+	/// let mut m = Junctions::from([Parachain(21)]);
+	/// assert_eq!(m.append_with([PalletInstance(3)]), Ok(()));
+	/// assert_eq!(m, [Parachain(21), PalletInstance(3)]);
+	/// ```
+	pub fn append_with(&mut self, suffix: impl Into<Junctions>) -> Result<(), Junctions> {
+		let suffix = suffix.into();
+		if self.len().saturating_add(suffix.len()) > MAX_JUNCTIONS {
+			return Err(suffix)
+		}
+		for j in suffix.into_iter() {
+			self.push(j).expect("Already checked the sum of the len()s; qed")
+		}
+		Ok(())
+	}
+
+	/// Returns the number of junctions in `self`.
+	pub const fn len(&self) -> usize {
+		match &self {
+			Junctions::Here => 0,
+			Junctions::X1(..) => 1,
+			Junctions::X2(..) => 2,
+			Junctions::X3(..) => 3,
+			Junctions::X4(..) => 4,
+			Junctions::X5(..) => 5,
+			Junctions::X6(..) => 6,
+			Junctions::X7(..) => 7,
+			Junctions::X8(..) => 8,
+		}
+	}
+
+	/// Returns the junction at index `i`, or `None` if the location doesn't contain that many
+	/// elements.
+	pub fn at(&self, i: usize) -> Option<&Junction> {
+		self.as_slice().get(i)
+	}
+
+	/// Returns a mutable reference to the junction at index `i`, or `None` if the location doesn't
+	/// contain that many elements.
+	pub fn at_mut(&mut self, i: usize) -> Option<&mut Junction> {
+		self.as_slice_mut().get_mut(i)
+	}
+
+	/// Returns a reference iterator over the junctions.
+	pub fn iter(&self) -> core::slice::Iter<Junction> {
+		self.as_slice().iter()
+	}
+
+	/// Ensures that self begins with `prefix` and that it has a single `Junction` item following.
+	/// If so, returns a reference to this `Junction` item.
+	///
+	/// # Example
+	/// ```rust
+	/// # use staging_xcm::v4::{Junctions, Junction::*};
+	/// let mut m = Junctions::from([Parachain(2), PalletInstance(3), OnlyChild]);
+	/// assert_eq!(m.match_and_split(&[Parachain(2), PalletInstance(3)].into()), Some(&OnlyChild));
+	/// assert_eq!(m.match_and_split(&[Parachain(2)].into()), None);
+	/// ```
+	pub fn match_and_split(&self, prefix: &Junctions) -> Option<&Junction> {
+		if prefix.len() + 1 != self.len() || !self.starts_with(prefix) {
+			return None
+		}
+		self.at(prefix.len())
+	}
+
+	pub fn starts_with(&self, prefix: &Junctions) -> bool {
+		self.as_slice().starts_with(prefix.as_slice())
+	}
+}
+
+impl<const N: usize> PartialEq<[Junction; N]> for Junctions {
+	fn eq(&self, other: &[Junction; N]) -> bool {
+		self.as_slice() == other
+	}
+}
+
+impl TryFrom<Location> for Junctions {
+	type Error = Location;
+	fn try_from(x: Location) -> result::Result<Self, Location> {
+		if x.parents > 0 {
+			Err(x)
+		} else {
+			Ok(x.interior)
+		}
+	}
+}
+
+impl<T: Into<Junction>> From<T> for Junctions {
+	fn from(x: T) -> Self {
+		[x.into()].into()
+	}
+}
+
+impl From<[Junction; 0]> for Junctions {
+	fn from(_: [Junction; 0]) -> Self {
+		Self::Here
+	}
+}
+
+impl From<()> for Junctions {
+	fn from(_: ()) -> Self {
+		Self::Here
+	}
+}
+
+xcm_procedural::impl_conversion_functions_for_junctions_v4!();
+
+#[cfg(test)]
+mod tests {
+	use super::{super::prelude::*, *};
+
+	#[test]
+	fn inverting_works() {
+		let context: InteriorLocation = (Parachain(1000), PalletInstance(42)).into();
+		let target = (Parent, PalletInstance(69)).into();
+		let expected = (Parent, PalletInstance(42)).into();
+		let inverted = context.invert_target(&target).unwrap();
+		assert_eq!(inverted, expected);
+
+		let context: InteriorLocation =
+			(Parachain(1000), PalletInstance(42), GeneralIndex(1)).into();
+		let target = (Parent, Parent, PalletInstance(69), GeneralIndex(2)).into();
+		let expected = (Parent, Parent, PalletInstance(42), GeneralIndex(1)).into();
+		let inverted = context.invert_target(&target).unwrap();
+		assert_eq!(inverted, expected);
+	}
+
+	#[test]
+	fn relative_to_works() {
+		use NetworkId::*;
+		assert_eq!(
+			Junctions::from([Polkadot.into()]).relative_to(&Junctions::from([Kusama.into()])),
+			(Parent, Polkadot).into()
+		);
+		let base = Junctions::from([Kusama.into(), Parachain(1), PalletInstance(1)]);
+
+		// Ancestors.
+		assert_eq!(Here.relative_to(&base), (Parent, Parent, Parent).into());
+		assert_eq!(Junctions::from([Kusama.into()]).relative_to(&base), (Parent, Parent).into());
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(1)]).relative_to(&base),
+			(Parent,).into()
+		);
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(1), PalletInstance(1)]).relative_to(&base),
+			Here.into()
+		);
+
+		// Ancestors with one child.
+		assert_eq!(
+			Junctions::from([Polkadot.into()]).relative_to(&base),
+			(Parent, Parent, Parent, Polkadot).into()
+		);
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(2)]).relative_to(&base),
+			(Parent, Parent, Parachain(2)).into()
+		);
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(1), PalletInstance(2)]).relative_to(&base),
+			(Parent, PalletInstance(2)).into()
+		);
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(1), PalletInstance(1), [1u8; 32].into()])
+				.relative_to(&base),
+			([1u8; 32],).into()
+		);
+
+		// Ancestors with grandchildren.
+		assert_eq!(
+			Junctions::from([Polkadot.into(), Parachain(1)]).relative_to(&base),
+			(Parent, Parent, Parent, Polkadot, Parachain(1)).into()
+		);
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(2), PalletInstance(1)]).relative_to(&base),
+			(Parent, Parent, Parachain(2), PalletInstance(1)).into()
+		);
+		assert_eq!(
+			Junctions::from([Kusama.into(), Parachain(1), PalletInstance(2), [1u8; 32].into()])
+				.relative_to(&base),
+			(Parent, PalletInstance(2), [1u8; 32]).into()
+		);
+		assert_eq!(
+			Junctions::from([
+				Kusama.into(),
+				Parachain(1),
+				PalletInstance(1),
+				[1u8; 32].into(),
+				1u128.into()
+			])
+			.relative_to(&base),
+			([1u8; 32], 1u128).into()
+		);
+	}
+
+	#[test]
+	fn global_consensus_works() {
+		use NetworkId::*;
+		assert_eq!(Junctions::from([Polkadot.into()]).global_consensus(), Ok(Polkadot));
+		assert_eq!(Junctions::from([Kusama.into(), 1u64.into()]).global_consensus(), Ok(Kusama));
+		assert_eq!(Here.global_consensus(), Err(()));
+		assert_eq!(Junctions::from([1u64.into()]).global_consensus(), Err(()));
+		assert_eq!(Junctions::from([1u64.into(), Kusama.into()]).global_consensus(), Err(()));
+	}
+
+	#[test]
+	fn test_conversion() {
+		use super::{Junction::*, NetworkId::*};
+		let x: Junctions = GlobalConsensus(Polkadot).into();
+		assert_eq!(x, Junctions::from([GlobalConsensus(Polkadot)]));
+		let x: Junctions = Polkadot.into();
+		assert_eq!(x, Junctions::from([GlobalConsensus(Polkadot)]));
+		let x: Junctions = (Polkadot, Kusama).into();
+		assert_eq!(x, Junctions::from([GlobalConsensus(Polkadot), GlobalConsensus(Kusama)]));
+	}
+
+	#[test]
+	fn push_and_split_work() {
+		let mut junctions = Junctions::Here;
+		for i in 0..MAX_JUNCTIONS {
+			assert_eq!(junctions.push(GeneralIndex(i as u128)), Ok(()));
+		}
+		assert_eq!(junctions.len(), MAX_JUNCTIONS);
+		assert_eq!(junctions.push(OnlyChild), Err(OnlyChild));
+		assert_eq!(
+			junctions.clone().pushed_front_with(OnlyChild),
+			Err((junctions.clone(), OnlyChild))
+		);
+
+		let (rest, first) = junctions.clone().split_first();
+		assert_eq!(first, Some(GeneralIndex(0)));
+		assert_eq!(rest.as_slice(), &junctions.as_slice()[1..]);
+		let (rest, last) = junctions.clone().split_last();
+		assert_eq!(last, Some(GeneralIndex(7)));
+		assert_eq!(rest.as_slice(), &junctions.as_slice()[..7]);
+
+		let mut junctions = Junctions::from([Parachain(1000)]);
+		assert_eq!(junctions.push_front(GlobalConsensus(NetworkId::Kusama)), Ok(()));
+		assert_eq!(junctions, [GlobalConsensus(NetworkId::Kusama), Parachain(1000)]);
+		assert_eq!(junctions.take_first(), Some(GlobalConsensus(NetworkId::Kusama)));
+		assert_eq!(junctions.take_last(), Some(Parachain(1000)));
+		assert_eq!(junctions, Here);
+	}
+
+	#[test]
+	fn mutating_shared_junctions_does_not_affect_clones() {
+		let original =
+			Junctions::from([AccountId32 { network: Some(NetworkId::Kusama), id: [0; 32] }]);
+		let mut copy = original.clone();
+		copy.remove_network_id();
+		assert_eq!(copy, [AccountId32 { network: None, id: [0; 32] }]);
+		assert_eq!(original, [AccountId32 { network: Some(NetworkId::Kusama), id: [0; 32] }]);
+	}
+
+	#[test]
+	fn slice_patterns_work() {
+		let junctions = Junctions::from([Parachain(1000), PalletInstance(50), GeneralIndex(1)]);
+		let matched = match junctions.as_slice() {
+			[Parachain(para), PalletInstance(50), GeneralIndex(index)] => Some((*para, *index)),
+			_ => None,
+		};
+		assert_eq!(matched, Some((1000, 1)));
+	}
+
+	#[test]
+	fn encoding_matches_v3() {
+		use crate::v3::{Junction as OldJunction, Junctions as OldJunctions};
+		let old = OldJunctions::X2(
+			OldJunction::Parachain(42),
+			OldJunction::AccountIndex64 { network: None, index: 23 },
+		);
+		let new = Junctions::try_from(old).unwrap();
+		assert_eq!(new, [Parachain(42), AccountIndex64 { network: None, index: 23 }]);
+		assert_eq!(new.encode(), old.encode());
+		assert_eq!(Junctions::decode(&mut &old.encode()[..]), Ok(new.clone()));
+		assert_eq!(OldJunctions::try_from(new), Ok(old));
+	}
+}
@@ -105,7 +105,7 @@ mod filter_asset_location;
 pub use filter_asset_location::{AllAssets, Case, LocationWithAssetFilters, NativeAsset};
 
 mod routing;
-pub use routing::{WithTopicSource, WithUniqueTopic};
+pub use routing::{record_sent_messages, WithMessageRecording, WithTopicSource, WithUniqueTopic};
 
 mod universal_exports;
 pub use universal_exports::{
@@ -18,9 +18,11 @@
 
 use frame_system::unique;
 use parity_scale_codec::Encode;
-use sp_std::{marker::PhantomData, result::Result};
+use sp_std::{marker::PhantomData, result::Result, vec::Vec};
 use xcm::prelude::*;
 
+environmental::environmental!(recorded_messages: Vec<(MultiLocation, Xcm<()>)>);
+
 /// Wrapper router which, if the message does not already end with a `SetTopic` instruction,
 /// appends one to the message filled with a universally unique ID. This ID is returned from a
 /// successful `deliver`.
@@ -104,3 +106,51 @@ impl<Inner: SendXcm, TopicSource: SourceTopic> SendXcm for WithTopicSource<Inner
 		Ok(unique_id)
 	}
 }
+
+/// Wrapper router which, while running inside [`record_sent_messages`], remembers every message
+/// that `Inner` successfully delivers together with its destination. Outside of such a scope it
+/// is fully transparent.
+///
+/// This is meant for dry-running: wrap the runtime's router with it and the messages an extrinsic
+/// or XCM program would forward can be inspected without looking into the individual transport
+/// queues. Place it below any wrapper which alters the message (such as [`WithUniqueTopic`]) so
+/// that the recorded message is the one actually handed to the transport.
+pub struct WithMessageRecording<Inner>(PhantomData<Inner>);
+impl<Inner: SendXcm> SendXcm for WithMessageRecording<Inner> {
+	type Ticket = (Inner::Ticket, Option<(MultiLocation, Xcm<()>)>);
+
+	fn validate(
+		destination: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let record = recorded_messages::with(|_| ())
+			.and_then(|()| destination.as_ref().copied().zip(message.clone()));
+		let (ticket, assets) = Inner::validate(destination, message)?;
+		Ok(((ticket, record), assets))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let (ticket, record) = ticket;
+		let hash = Inner::deliver(ticket)?;
+		if let Some(record) = record {
+			recorded_messages::with(|messages| messages.push(record));
+		}
+		Ok(hash)
+	}
+}
+
+/// Executes `f` and returns its result together with the messages delivered meanwhile through any
+/// [`WithMessageRecording`] router, grouped by destination in the order each destination was
+/// first sent to.
+pub fn record_sent_messages<R>(f: impl FnOnce() -> R) -> (R, Vec<(MultiLocation, Vec<Xcm<()>>)>) {
+	let mut sent = Vec::new();
+	let result = recorded_messages::using(&mut sent, f);
+	let mut grouped: Vec<(MultiLocation, Vec<Xcm<()>>)> = Vec::new();
+	for (destination, message) in sent {
+		match grouped.iter_mut().find(|(d, _)| *d == destination) {
+			Some((_, messages)) => messages.push(message),
+			None => grouped.push((destination, sp_std::vec![message])),
+		}
+	}
+	(result, grouped)
+}
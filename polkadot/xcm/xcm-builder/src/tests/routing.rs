@@ -0,0 +1,66 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::assert_ok;
+
+type RecordingRouter = WithMessageRecording<TestMessageSender>;
+
+#[test]
+fn message_recording_groups_messages_by_destination() {
+	let para_a: MultiLocation = Parachain(1).into();
+	let para_b: MultiLocation = Parachain(2).into();
+
+	let ((), forwarded) = record_sent_messages(|| {
+		assert_ok!(send_xcm::<RecordingRouter>(para_a, Xcm(vec![ClearOrigin])));
+		assert_ok!(send_xcm::<RecordingRouter>(para_b, Xcm(vec![ClearTopic])));
+		assert_ok!(send_xcm::<RecordingRouter>(para_a, Xcm(vec![ClearError])));
+	});
+
+	assert_eq!(
+		forwarded,
+		vec![
+			(para_a, vec![Xcm(vec![ClearOrigin]), Xcm(vec![ClearError])]),
+			(para_b, vec![Xcm(vec![ClearTopic])]),
+		]
+	);
+	// recording does not get in the way of the actual delivery
+	assert_eq!(sent_xcm().len(), 3);
+}
+
+#[test]
+fn message_recording_is_transparent_outside_of_scope() {
+	let para_a: MultiLocation = Parachain(1).into();
+
+	assert_ok!(send_xcm::<RecordingRouter>(para_a, Xcm(vec![ClearOrigin])));
+	let ((), forwarded) = record_sent_messages(|| ());
+
+	assert!(forwarded.is_empty());
+	assert_eq!(sent_xcm().len(), 1);
+}
+
+#[test]
+fn message_recording_skips_undelivered_messages() {
+	let para_a: MultiLocation = Parachain(1).into();
+
+	let (result, forwarded) = record_sent_messages(|| {
+		validate_send::<RecordingRouter>(para_a, Xcm(vec![ClearOrigin])).map(|_| ())
+	});
+
+	assert_ok!(result);
+	assert!(forwarded.is_empty());
+	assert!(sent_xcm().is_empty());
+}
@@ -132,6 +132,7 @@ impl shared::Config for Runtime {}
 
 impl configuration::Config for Runtime {
 	type WeightInfo = configuration::TestWeightInfo;
+	type MaxUmpMessageLen = ConstU32<0>;
 }
 
 // aims to closely emulate the Kusama XcmConfig
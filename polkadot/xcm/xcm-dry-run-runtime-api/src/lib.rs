@@ -0,0 +1,106 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for dry-running extrinsics and XCM programs.
+//!
+//! A dry-run executes against the current state without persisting anything, and reports what
+//! happened locally together with every XCM message that would have been forwarded to other
+//! locations. Feeding those forwarded messages into a dry-run on their respective destinations
+//! allows following a cross-chain transfer hop by hop.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::DispatchResult;
+use sp_std::vec::Vec;
+use xcm::{
+	latest::{MultiLocation, Outcome, Xcm},
+	VersionedMultiLocation, VersionedXcm,
+};
+
+/// Effects of dry-running an extrinsic.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo)]
+pub struct ExtrinsicDryRunEffects<Event> {
+	/// The result of dispatching the extrinsic.
+	pub execution_result: DispatchResult,
+	/// The events emitted while applying the extrinsic.
+	pub emitted_events: Vec<Event>,
+	/// The XCM messages sent to each destination, in the order they were sent.
+	pub forwarded_xcms: Vec<(VersionedMultiLocation, Vec<VersionedXcm<()>>)>,
+}
+
+/// Effects of dry-running an XCM program.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo)]
+pub struct XcmDryRunEffects<Event> {
+	/// The outcome of executing the program locally.
+	pub execution_result: Outcome,
+	/// The events emitted while executing the program.
+	pub emitted_events: Vec<Event>,
+	/// The XCM messages sent to each destination, in the order they were sent.
+	pub forwarded_xcms: Vec<(VersionedMultiLocation, Vec<VersionedXcm<()>>)>,
+}
+
+/// Reasons a dry-run could not be performed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum Error {
+	/// The runtime does not support dry-running.
+	#[codec(index = 0)]
+	Unimplemented,
+	/// A versioned location or message could not be converted into the version the runtime uses.
+	#[codec(index = 1)]
+	VersionedConversionFailed,
+	/// The extrinsic could not be applied, e.g. because it is invalid or cannot pay its fees.
+	#[codec(index = 2)]
+	InvalidExtrinsic,
+}
+
+/// Converts the messages captured while dry-running, grouped by destination, into the versioned
+/// form returned by [`XcmDryRunApi`].
+pub fn versioned_forwarded_xcms(
+	forwarded: Vec<(MultiLocation, Vec<Xcm<()>>)>,
+) -> Vec<(VersionedMultiLocation, Vec<VersionedXcm<()>>)> {
+	forwarded
+		.into_iter()
+		.map(|(destination, messages)| {
+			(destination.into(), messages.into_iter().map(VersionedXcm::from).collect())
+		})
+		.collect()
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for dry-running extrinsics and XCM programs.
+	///
+	/// Nothing is persisted: all state changes made by a dry-run are discarded once the call
+	/// returns.
+	pub trait XcmDryRunApi<Call, Event>
+	where
+		Call: Codec,
+		Event: Codec,
+	{
+		/// Dry-run an extrinsic, as if it was included in the next block.
+		fn dry_run_extrinsic(
+			extrinsic: Block::Extrinsic,
+		) -> Result<ExtrinsicDryRunEffects<Event>, Error>;
+
+		/// Dry-run an XCM program as if it arrived from `origin_location`, without any weight
+		/// limit.
+		fn dry_run_xcm(
+			origin_location: VersionedMultiLocation,
+			xcm: VersionedXcm<Call>,
+		) -> Result<XcmDryRunEffects<Event>, Error>;
+	}
+}
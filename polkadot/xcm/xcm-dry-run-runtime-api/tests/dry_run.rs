@@ -0,0 +1,131 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+mod mock;
+
+use codec::Encode;
+use mock::*;
+use polkadot_parachain_primitives::primitives::Id as ParaId;
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{generic::SignedPayload, traits::AccountIdConversion, MultiSignature};
+use xcm::{latest::prelude::*, VersionedMultiAssets, VersionedMultiLocation, VersionedXcm};
+use xcm_dry_run_runtime_api::XcmDryRunApi;
+
+const INITIAL_BALANCE: Balance = 1_000;
+const SEND_AMOUNT: Balance = 100;
+
+fn signed_extrinsic(pair: &sr25519::Pair, call: RuntimeCall) -> UncheckedExtrinsic {
+	let extra: SignedExtra = (frame_system::CheckWeight::new(),);
+	let payload = SignedPayload::new(call.clone(), extra.clone()).unwrap();
+	let signature = payload.using_encoded(|payload| pair.sign(payload));
+	UncheckedExtrinsic::new_signed(
+		call,
+		pair.public().into(),
+		MultiSignature::Sr25519(signature),
+		extra,
+	)
+}
+
+fn sent_to(
+	forwarded_xcms: &[(VersionedMultiLocation, Vec<VersionedXcm<()>>)],
+	destination: MultiLocation,
+) -> Vec<Xcm<()>> {
+	let destination = VersionedMultiLocation::from(destination);
+	forwarded_xcms
+		.iter()
+		.filter(|(location, _)| *location == destination)
+		.flat_map(|(_, messages)| messages.iter().cloned().map(|m| m.try_into().unwrap()))
+		.collect()
+}
+
+/// A reserve transfer extrinsic reports the pallet's events and the message it sends to the
+/// destination.
+#[test]
+fn dry_run_extrinsic_reports_events_and_forwarded_xcms() {
+	let pair = sr25519::Pair::from_seed(&[1; 32]);
+	let who: AccountId = pair.public().into();
+	let dest: MultiLocation = Parachain(2000).into();
+	let beneficiary: MultiLocation = AccountId32 { network: None, id: [2; 32] }.into();
+	let call = RuntimeCall::XcmPallet(pallet_xcm::Call::limited_reserve_transfer_assets {
+		dest: Box::new(dest.into()),
+		beneficiary: Box::new(beneficiary.into()),
+		assets: Box::new(VersionedMultiAssets::from(MultiAssets::from((Here, SEND_AMOUNT)))),
+		fee_asset_item: 0,
+		weight_limit: Unlimited,
+	});
+	let extrinsic = signed_extrinsic(&pair, call);
+
+	new_test_ext_with_balances(vec![(who, INITIAL_BALANCE)]).execute_with(|| {
+		let effects = TestApi.dry_run_extrinsic(H256::zero(), extrinsic).unwrap().unwrap();
+
+		assert_eq!(effects.execution_result, Ok(()));
+		assert!(effects.emitted_events.iter().any(|event| matches!(
+			event,
+			RuntimeEvent::XcmPallet(pallet_xcm::Event::Attempted {
+				outcome: Outcome::Complete { .. }
+			})
+		)));
+		assert!(effects
+			.emitted_events
+			.iter()
+			.any(|event| matches!(event, RuntimeEvent::Balances(_))));
+
+		assert_eq!(effects.forwarded_xcms.len(), 1);
+		let messages = sent_to(&effects.forwarded_xcms, dest);
+		assert_eq!(messages.len(), 1);
+		assert!(matches!(messages[0].0.first(), Some(ReserveAssetDeposited(_))));
+		// Recorded messages are still handed to the wrapped router.
+		assert_eq!(sent_xcm().len(), 1);
+		assert_eq!(sent_xcm()[0].0, dest);
+	});
+}
+
+/// An XCM program run on behalf of a sibling reports the balance events and the message it
+/// forwards to the reserve destination.
+#[test]
+fn dry_run_xcm_reports_events_and_forwarded_xcms() {
+	let origin: MultiLocation = Parachain(2000).into();
+	let dest: MultiLocation = Parachain(2001).into();
+	let sovereign: AccountId = ParaId::from(2000).into_account_truncating();
+	let beneficiary: MultiLocation = AccountId32 { network: None, id: [2; 32] }.into();
+	let xcm: Xcm<RuntimeCall> = Xcm(vec![
+		WithdrawAsset((Here, SEND_AMOUNT).into()),
+		BuyExecution { fees: (Here, SEND_AMOUNT).into(), weight_limit: Unlimited },
+		DepositReserveAsset {
+			assets: AllCounted(1).into(),
+			dest,
+			xcm: Xcm(vec![DepositAsset { assets: AllCounted(1).into(), beneficiary }]),
+		},
+	]);
+
+	new_test_ext_with_balances(vec![(sovereign, INITIAL_BALANCE)]).execute_with(|| {
+		let effects = TestApi
+			.dry_run_xcm(H256::zero(), origin.into(), VersionedXcm::from(xcm))
+			.unwrap()
+			.unwrap();
+
+		assert!(matches!(effects.execution_result, Outcome::Complete { .. }));
+		assert!(effects
+			.emitted_events
+			.iter()
+			.any(|event| matches!(event, RuntimeEvent::Balances(_))));
+
+		assert_eq!(effects.forwarded_xcms.len(), 1);
+		let messages = sent_to(&effects.forwarded_xcms, dest);
+		assert_eq!(messages.len(), 1);
+		assert!(matches!(messages[0].0.first(), Some(ReserveAssetDeposited(_))));
+	});
+}
@@ -0,0 +1,291 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime implementing [`XcmDryRunApi`] the same way a real runtime would.
+
+use codec::Encode;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, Everything, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use polkadot_parachain_primitives::primitives::Id as ParaId;
+use sp_core::H256;
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Block as BlockT, IdentityLookup},
+	AccountId32, BuildStorage, MultiSignature,
+};
+use std::cell::RefCell;
+use xcm::{latest::prelude::*, VersionedMultiLocation, VersionedXcm};
+#[allow(deprecated)]
+use xcm_builder::CurrencyAdapter as XcmCurrencyAdapter;
+use xcm_builder::{
+	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, ChildParachainConvertsVia, EnsureXcmOrigin,
+	FixedRateOfFungible, FixedWeightBounds, IsConcrete, SignedAccountId32AsNative,
+	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, WithMessageRecording,
+};
+use xcm_dry_run_runtime_api::{
+	Error as XcmDryRunApiError, ExtrinsicDryRunEffects, XcmDryRunApi, XcmDryRunEffects,
+};
+use xcm_executor::{traits::ExecuteXcm, XcmExecutor};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type SignedExtra = (frame_system::CheckWeight<TestRuntime>,);
+pub type UncheckedExtrinsic =
+	generic::UncheckedExtrinsic<AccountId, RuntimeCall, MultiSignature, SignedExtra>;
+pub type Block = generic::Block<generic::Header<u64, BlakeTwo256>, UncheckedExtrinsic>;
+pub type Executive = frame_executive::Executive<
+	TestRuntime,
+	Block,
+	frame_system::ChainContext<TestRuntime>,
+	TestRuntime,
+	AllPalletsWithSystem,
+>;
+
+construct_runtime!(
+	pub enum TestRuntime
+	{
+		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		XcmPallet: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>},
+	}
+);
+
+thread_local! {
+	pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|q| (*q.borrow()).clone())
+}
+
+/// Sender that accepts every message and keeps it in [`SENT_XCM`].
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (MultiLocation, Xcm<()>);
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<(MultiLocation, Xcm<()>)> {
+		let pair = (dest.take().ok_or(SendError::MissingArgument)?, msg.take().unwrap());
+		Ok((pair, MultiAssets::new()))
+	}
+	fn deliver(pair: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
+		let hash = pair.1.using_encoded(sp_io::hashing::blake2_256);
+		SENT_XCM.with(|q| q.borrow_mut().push(pair));
+		Ok(hash)
+	}
+}
+
+pub type XcmRouter = WithMessageRecording<TestSendXcm>;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for TestRuntime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type BaseCallFilter = Everything;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for TestRuntime {
+	type MaxLocks = ConstU32<50>;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+parameter_types! {
+	pub const RelayLocation: MultiLocation = Here.into_location();
+	pub const AnyNetwork: Option<NetworkId> = None;
+	pub UniversalLocation: InteriorMultiLocation = Here;
+	pub const BaseXcmWeight: Weight = Weight::from_parts(1_000, 1_000);
+	pub CurrencyPerSecondPerByte: (AssetId, u128, u128) = (Concrete(RelayLocation::get()), 1, 1);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+	pub static AdvertisedXcmVersion: pallet_xcm::XcmVersion = 3;
+}
+
+pub type LocationToAccountId =
+	(ChildParachainConvertsVia<ParaId, AccountId>, AccountId32Aliases<AnyNetwork, AccountId>);
+
+#[allow(deprecated)]
+pub type AssetTransactor =
+	XcmCurrencyAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+type LocalOriginConverter = (
+	SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+	SignedAccountId32AsNative<AnyNetwork, RuntimeOrigin>,
+);
+
+pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = XcmRouter;
+	type AssetTransactor = AssetTransactor;
+	type OriginConverter = LocalOriginConverter;
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = Barrier;
+	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Trader = FixedRateOfFungible<CurrencyPerSecondPerByte, ()>;
+	type ResponseHandler = XcmPallet;
+	type AssetTrap = XcmPallet;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = XcmPallet;
+	type SubscriptionService = XcmPallet;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
+
+impl pallet_xcm::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = XcmRouter;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = AdvertisedXcmVersion;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type TrustedLockers = ();
+	type SovereignAccountOf = LocationToAccountId;
+	type Currency = Balances;
+	type CurrencyMatcher = IsConcrete<RelayLocation>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+}
+
+pub fn new_test_ext_with_balances(balances: Vec<(AccountId, Balance)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<TestRuntime> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	pallet_xcm::GenesisConfig::<TestRuntime> {
+		safe_xcm_version: Some(XCM_VERSION),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Client side of the runtime API, calling straight into the mock runtime.
+pub struct TestApi;
+
+sp_api::mock_impl_runtime_apis! {
+	impl XcmDryRunApi<Block, RuntimeCall, RuntimeEvent> for TestApi {
+		fn dry_run_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+		) -> Result<ExtrinsicDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			System::reset_events();
+			let (result, forwarded_xcms) =
+				xcm_builder::record_sent_messages(|| Executive::apply_extrinsic(extrinsic));
+			let execution_result =
+				result.map_err(|_| XcmDryRunApiError::InvalidExtrinsic)?;
+			let emitted_events =
+				System::read_events_no_consensus().map(|record| record.event).collect();
+			Ok(ExtrinsicDryRunEffects {
+				execution_result,
+				emitted_events,
+				forwarded_xcms: xcm_dry_run_runtime_api::versioned_forwarded_xcms(forwarded_xcms),
+			})
+		}
+
+		fn dry_run_xcm(
+			origin_location: VersionedMultiLocation,
+			xcm: VersionedXcm<RuntimeCall>,
+		) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			let origin_location: MultiLocation = origin_location
+				.try_into()
+				.map_err(|()| XcmDryRunApiError::VersionedConversionFailed)?;
+			let xcm: Xcm<RuntimeCall> =
+				xcm.try_into().map_err(|()| XcmDryRunApiError::VersionedConversionFailed)?;
+			let mut hash = xcm.using_encoded(sp_io::hashing::blake2_256);
+			System::reset_events();
+			let (execution_result, forwarded_xcms) = xcm_builder::record_sent_messages(|| {
+				XcmExecutor::<XcmConfig>::prepare_and_execute(
+					origin_location,
+					xcm,
+					&mut hash,
+					Weight::MAX,
+					Weight::zero(),
+				)
+			});
+			let emitted_events =
+				System::read_events_no_consensus().map(|record| record.event).collect();
+			Ok(XcmDryRunEffects {
+				execution_result,
+				emitted_events,
+				forwarded_xcms: xcm_dry_run_runtime_api::versioned_forwarded_xcms(forwarded_xcms),
+			})
+		}
+	}
+}
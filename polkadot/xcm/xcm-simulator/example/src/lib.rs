@@ -126,6 +126,16 @@ pub fn relay_ext() -> sp_io::TestExternalities {
 	.assimilate_storage(&mut t)
 	.unwrap();
 
+	polkadot_runtime_parachains::configuration::GenesisConfig::<Runtime> {
+		config: polkadot_runtime_parachains::configuration::HostConfiguration {
+			max_code_size: 1024,
+			max_head_data_size: 1024,
+			..Default::default()
+		},
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| {
 		System::set_block_number(1);
@@ -649,4 +659,185 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn para_manages_own_registration() {
+		use polkadot_parachain_primitives::primitives::{HeadData, Id as ParaId, ValidationCode};
+		use polkadot_runtime_common::{paras_registrar, traits::Registrar as _};
+
+		MockNet::reset();
+
+		let para_a = ParaId::from(1u32);
+		let para_b = ParaId::from(2u32);
+		let deposit = 1_000;
+		let weight = Weight::from_parts(INITIAL_BALANCE as u64, 1024 * 1024);
+		let transact = |call: relay_chain::RuntimeCall| Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: weight,
+			call: call.encode().into(),
+		};
+		Relay::execute_with(|| {
+			use relay_chain::{Balances, Paras, ParasShared, Registrar, RuntimeOrigin, System};
+
+			let validation_code = ValidationCode(vec![1, 2, 3]);
+			assert_ok!(Paras::add_trusted_validation_code(
+				RuntimeOrigin::root(),
+				validation_code.clone()
+			));
+			assert_ok!(Registrar::force_register(
+				RuntimeOrigin::root(),
+				ALICE,
+				deposit,
+				para_a,
+				HeadData(vec![1]),
+				validation_code.clone(),
+			));
+			assert_ok!(Registrar::force_register(
+				RuntimeOrigin::root(),
+				child_account_id(2),
+				deposit,
+				para_b,
+				HeadData(vec![2]),
+				validation_code,
+			));
+			ParasShared::set_session_index(ParasShared::scheduled_session());
+			Paras::test_on_new_session();
+			assert_ok!(Registrar::make_parachain(para_b));
+			ParasShared::set_session_index(ParasShared::scheduled_session());
+			Paras::test_on_new_session();
+			assert!(Paras::is_parathread(para_a));
+			assert!(Paras::is_parachain(para_b));
+
+			// The manager hands the para over to the para itself.
+			assert_ok!(Registrar::set_manager(
+				RuntimeOrigin::signed(ALICE),
+				para_a,
+				child_account_id(1)
+			));
+			assert_eq!(Balances::reserved_balance(&ALICE), 0);
+			assert_eq!(Balances::reserved_balance(&child_account_id(1)), deposit);
+			System::reset_events();
+		});
+
+		// Another para can't deregister it.
+		ParaB::execute_with(|| {
+			assert_ok!(ParachainPalletXcm::send_xcm(
+				Here,
+				Parent,
+				Xcm(vec![
+					transact(relay_chain::RuntimeCall::Registrar(
+						paras_registrar::Call::deregister { id: para_a }
+					)),
+					ExpectTransactStatus(MaybeErrorCode::from(
+						sp_runtime::DispatchError::BadOrigin.encode()
+					)),
+				]),
+			));
+		});
+
+		Relay::execute_with(|| {
+			use relay_chain::{Balances, RuntimeEvent, System};
+
+			// The expectation held, so the message was processed successfully.
+			assert!(System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+					success: true,
+					..
+				})
+			)));
+			assert!(paras_registrar::Paras::<relay_chain::Runtime>::contains_key(para_a));
+			assert_eq!(Balances::reserved_balance(&child_account_id(1)), deposit);
+		});
+
+		// The para recomputes its own deposit.
+		ParaA::execute_with(|| {
+			assert_ok!(ParachainPalletXcm::send_xcm(
+				Here,
+				Parent,
+				Xcm(vec![transact(relay_chain::RuntimeCall::Registrar(
+					paras_registrar::Call::poke_deposit { para: para_a }
+				))]),
+			));
+		});
+
+		// One byte of head data and three bytes of code on top of the base deposit.
+		let poked_deposit = deposit + 4;
+		Relay::execute_with(|| {
+			use relay_chain::{Balances, RuntimeEvent, System};
+
+			System::assert_has_event(RuntimeEvent::Registrar(
+				paras_registrar::Event::DepositPoked {
+					para_id: para_a,
+					manager: child_account_id(1),
+					deposit: poked_deposit,
+				},
+			));
+			assert_eq!(Balances::reserved_balance(&child_account_id(1)), poked_deposit);
+		});
+
+		// Both paras agree to swap.
+		ParaA::execute_with(|| {
+			assert_ok!(ParachainPalletXcm::send_xcm(
+				Here,
+				Parent,
+				Xcm(vec![transact(relay_chain::RuntimeCall::Registrar(
+					paras_registrar::Call::swap { id: para_a, other: para_b }
+				))]),
+			));
+		});
+		ParaB::execute_with(|| {
+			assert_ok!(ParachainPalletXcm::send_xcm(
+				Here,
+				Parent,
+				Xcm(vec![transact(relay_chain::RuntimeCall::Registrar(
+					paras_registrar::Call::swap { id: para_b, other: para_a }
+				))]),
+			));
+		});
+
+		Relay::execute_with(|| {
+			use relay_chain::{Paras, ParasShared, RuntimeEvent, System};
+
+			assert!(System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::Registrar(paras_registrar::Event::Swapped { para_id, other_id })
+					if para_id == para_b && other_id == para_a
+			)));
+			ParasShared::set_session_index(ParasShared::scheduled_session());
+			Paras::test_on_new_session();
+			assert!(Paras::is_parachain(para_a));
+			assert!(Paras::is_parathread(para_b));
+		});
+
+		// The para, now on-demand, locks its registration and deregisters itself.
+		ParaB::execute_with(|| {
+			assert_ok!(ParachainPalletXcm::send_xcm(
+				Here,
+				Parent,
+				Xcm(vec![
+					transact(relay_chain::RuntimeCall::Registrar(
+						paras_registrar::Call::add_lock { para: para_b }
+					)),
+					transact(relay_chain::RuntimeCall::Registrar(
+						paras_registrar::Call::deregister { id: para_b }
+					)),
+				]),
+			));
+		});
+
+		Relay::execute_with(|| {
+			use relay_chain::{Balances, RuntimeEvent, System};
+
+			assert!(System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::Registrar(paras_registrar::Event::Deregistered { para_id: id })
+					if id == para_b
+			)));
+			assert!(!paras_registrar::Paras::<relay_chain::Runtime>::contains_key(para_b));
+			// The deposit is returned to the sovereign account of the para.
+			assert_eq!(Balances::reserved_balance(&child_account_id(2)), 0);
+			assert_eq!(Balances::free_balance(&child_account_id(2)), INITIAL_BALANCE);
+		});
+	}
 }
@@ -24,13 +24,14 @@ use frame_support::{
 
 use frame_system::EnsureRoot;
 use sp_core::{ConstU32, H256};
-use sp_runtime::{traits::IdentityLookup, AccountId32};
+use sp_runtime::{traits::IdentityLookup, transaction_validity::TransactionPriority, AccountId32};
 
 use polkadot_parachain_primitives::primitives::Id as ParaId;
+use polkadot_runtime_common::paras_registrar;
 use polkadot_runtime_parachains::{
 	configuration,
 	inclusion::{AggregateMessageOrigin, UmpQueueId},
-	origin, shared,
+	origin, paras, shared,
 };
 use xcm::latest::prelude::*;
 use xcm_builder::{
@@ -124,6 +125,44 @@ impl shared::Config for Runtime {}
 
 impl configuration::Config for Runtime {
 	type WeightInfo = configuration::TestWeightInfo;
+	type MaxUmpMessageLen = ConstU32<0>;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+parameter_types! {
+	pub const ParasUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+}
+
+impl paras::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = paras::TestWeightInfo;
+	type UnsignedPriority = ParasUnsignedPriority;
+	type QueueFootprinter = ();
+	type NextSessionRotation = ();
+	type OnNewHead = Registrar;
+	type AssignCoretime = ();
+}
+
+parameter_types! {
+	pub const ParaDeposit: Balance = 1_000;
+	pub const DataDepositPerByte: Balance = 1;
+}
+
+impl paras_registrar::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type OnSwap = ();
+	type ParaDeposit = ParaDeposit;
+	type DataDepositPerByte = DataDepositPerByte;
+	type WeightInfo = paras_registrar::TestWeightInfo;
 }
 
 parameter_types! {
@@ -233,6 +272,7 @@ parameter_types! {
 
 impl origin::Config for Runtime {}
 
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 parameter_types! {
@@ -285,5 +325,9 @@ construct_runtime!(
 		XcmPallet: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin},
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 		MessageQueue: pallet_message_queue::{Pallet, Event<T>},
+		Configuration: configuration::{Pallet, Call, Storage, Config<T>},
+		ParasShared: shared::{Pallet, Call, Storage},
+		Paras: paras::{Pallet, Call, Storage, Config<T>, Event},
+		Registrar: paras_registrar::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -104,6 +104,7 @@ impl shared::Config for Runtime {}
 
 impl configuration::Config for Runtime {
 	type WeightInfo = configuration::TestWeightInfo;
+	type MaxUmpMessageLen = ConstU32<0>;
 }
 
 parameter_types! {
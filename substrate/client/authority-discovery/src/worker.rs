@@ -23,10 +23,10 @@ use crate::{
 };
 
 use std::{
-	collections::{HashMap, HashSet},
+	collections::{hash_map::Entry, HashMap, HashSet},
 	marker::PhantomData,
 	sync::Arc,
-	time::Duration,
+	time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use futures::{channel::mpsc, future, stream::Fuse, FutureExt, Stream, StreamExt};
@@ -38,7 +38,9 @@ use libp2p::{core::multiaddr, identity::PublicKey, multihash::Multihash, Multiad
 use multihash_codetable::{Code, MultihashDigest};
 
 use log::{debug, error, log_enabled};
-use prometheus_endpoint::{register, Counter, CounterVec, Gauge, Opts, U64};
+use prometheus_endpoint::{
+	register, Counter, CounterVec, Gauge, Histogram, HistogramOpts, Opts, U64,
+};
 use prost::Message;
 use rand::{seq::SliceRandom, thread_rng};
 
@@ -60,7 +62,7 @@ mod schema {
 	#[cfg(test)]
 	mod tests;
 
-	include!(concat!(env!("OUT_DIR"), "/authority_discovery_v2.rs"));
+	include!(concat!(env!("OUT_DIR"), "/authority_discovery_v3.rs"));
 }
 #[cfg(test)]
 pub mod tests;
@@ -73,6 +75,12 @@ const MAX_ADDRESSES_PER_AUTHORITY: usize = 10;
 /// Maximum number of in-flight DHT lookups at any given point in time.
 const MAX_IN_FLIGHT_LOOKUPS: usize = 8;
 
+/// Records of an authority created this long before its most recent record are discarded.
+///
+/// Nodes republish their records at least every hour with the default [`WorkerConfig`], so records
+/// that are much older than the most recent one belong to nodes that are gone.
+const MAX_RECORD_AGE_DIFFERENCE: Duration = Duration::from_secs(4 * 60 * 60);
+
 /// Role an authority discovery [`Worker`] can run as.
 pub enum Role {
 	/// Publish own addresses and discover addresses of others.
@@ -92,7 +100,8 @@ pub enum Role {
 ///
 ///    3. Sign the addresses with the keys.
 ///
-///    4. Put addresses and signature as a record with the authority id as a key on a Kademlia DHT.
+///    4. Put addresses, creation time and signature as a record with the authority id as a key on
+///    a Kademlia DHT.
 ///
 /// When constructed with either [`Role::PublishAndDiscover`] or [`Role::Discover`] a [`Worker`]
 /// will
@@ -103,10 +112,14 @@ pub enum Role {
 ///
 ///    3. Validate the signatures of the retrieved key value pairs.
 ///
-///    4. Add the retrieved external addresses as priority nodes to the
+///    4. Keep the most recent record of every node of the authority. Multiple nodes (e.g. sentry
+///    nodes) can publish records for the same authority, as long as each of them uses its own
+///    [`PeerId`].
+///
+///    5. Add the retrieved external addresses as priority nodes to the
 ///    network peerset.
 ///
-///    5. Allow querying of the collected addresses via the [`crate::Service`].
+///    6. Allow querying of the collected addresses via the [`crate::Service`].
 pub struct Worker<Client, Network, Block, DhtEventStream> {
 	/// Channel receiver for messages send by a [`crate::Service`].
 	from_service: Fuse<mpsc::Receiver<ServicetoWorkerMsg>>,
@@ -141,6 +154,10 @@ pub struct Worker<Client, Network, Block, DhtEventStream> {
 
 	addr_cache: addr_cache::AddrCache,
 
+	/// Creation time of the most recent record seen for every node of every authority. Used to
+	/// discard outdated records still stored in the DHT.
+	last_known_records: HashMap<AuthorityId, HashMap<PeerId, u128>>,
+
 	metrics: Option<Metrics>,
 
 	role: Role,
@@ -238,6 +255,7 @@ where
 			pending_lookups: Vec::new(),
 			in_flight_lookups: HashMap::new(),
 			addr_cache,
+			last_known_records: HashMap::new(),
 			role,
 			metrics,
 			phantom: PhantomData,
@@ -359,7 +377,7 @@ where
 				.set(addresses.len().try_into().unwrap_or(std::u64::MAX));
 		}
 
-		let serialized_record = serialize_authority_record(addresses)?;
+		let serialized_record = serialize_authority_record(addresses, Some(now_as_nanos()))?;
 		let peer_signature = sign_record_with_peer_id(&serialized_record, self.network.as_ref())?;
 
 		let keys_vec = keys.iter().cloned().collect::<Vec<_>>();
@@ -401,6 +419,7 @@ where
 			.collect::<Vec<_>>();
 
 		self.addr_cache.retain_ids(&authorities);
+		self.last_known_records.retain(|id, _| authorities.contains(id));
 
 		authorities.shuffle(&mut thread_rng());
 		self.pending_lookups = authorities;
@@ -506,7 +525,7 @@ where
 
 		let local_peer_id = self.network.local_peer_id();
 
-		let remote_addresses: Vec<Multiaddr> = values
+		let records = values
 			.into_iter()
 			.map(|(_k, v)| {
 				let schema::SignedAuthorityRecord { record, auth_signature, peer_signature } =
@@ -520,9 +539,16 @@ where
 					return Err(Error::VerifyingDhtPayload)
 				}
 
-				let addresses: Vec<Multiaddr> = schema::AuthorityRecord::decode(record.as_slice())
-					.map(|a| a.addresses)
-					.map_err(Error::DecodingProto)?
+				let schema::AuthorityRecord { addresses, creation_time } =
+					schema::AuthorityRecord::decode(record.as_slice())
+						.map_err(Error::DecodingProto)?;
+
+				let creation_time = creation_time
+					.map(|t| u128::decode(&mut &t.timestamp[..]))
+					.transpose()
+					.map_err(Error::EncodingDecodingScale)?;
+
+				let addresses: Vec<Multiaddr> = addresses
 					.into_iter()
 					.map(|a| a.try_into())
 					.collect::<std::result::Result<_, _>>()
@@ -564,13 +590,28 @@ where
 						"Received unsigned authority discovery record from {}", authority_id
 					);
 				}
-				Ok(addresses)
+				Ok((remote_peer_id, creation_time, addresses))
 			})
-			.collect::<Result<Vec<Vec<Multiaddr>>>>()?
+			.collect::<Result<Vec<_>>>()?;
+
+		// Take the addresses of every node in turn, so that a node publishing many addresses
+		// doesn't crowd out the other nodes of the authority.
+		let mut records: Vec<_> = self
+			.select_most_recent_records(&authority_id, records)
 			.into_iter()
-			.flatten()
-			.take(MAX_ADDRESSES_PER_AUTHORITY)
+			.map(IntoIterator::into_iter)
 			.collect();
+		let mut remote_addresses: Vec<Multiaddr> = Vec::new();
+		while remote_addresses.len() < MAX_ADDRESSES_PER_AUTHORITY && !records.is_empty() {
+			records.retain_mut(|addresses| match addresses.next() {
+				Some(address) => {
+					remote_addresses.push(address);
+					true
+				},
+				None => false,
+			});
+		}
+		remote_addresses.truncate(MAX_ADDRESSES_PER_AUTHORITY);
 
 		if !remote_addresses.is_empty() {
 			self.addr_cache.insert(authority_id, remote_addresses);
@@ -583,6 +624,95 @@ where
 		Ok(())
 	}
 
+	/// Keep the most recent of the given records of `authority_id` for every node, discarding the
+	/// outdated ones, and return their addresses.
+	///
+	/// The most recently published records come first, the order of the records is deterministic.
+	///
+	/// Records without a creation time, published by old versions, can't be compared to each
+	/// other and are only discarded in favor of a record of the same node with a creation time.
+	fn select_most_recent_records(
+		&mut self,
+		authority_id: &AuthorityId,
+		records: Vec<(PeerId, Option<u128>, Vec<Multiaddr>)>,
+	) -> Vec<Vec<Multiaddr>> {
+		let last_known_records = self.last_known_records.entry(authority_id.clone()).or_default();
+		let mut most_recent: HashMap<PeerId, (Option<u128>, Vec<Multiaddr>)> = HashMap::new();
+		let mut outdated = 0u64;
+
+		for (peer_id, creation_time, addresses) in records {
+			// A more recent record of this node has been seen in a previous lookup.
+			if last_known_records
+				.get(&peer_id)
+				.map_or(false, |known| Some(*known) > creation_time)
+			{
+				outdated += 1;
+				continue
+			}
+
+			match most_recent.entry(peer_id) {
+				Entry::Vacant(entry) => {
+					entry.insert((creation_time, addresses));
+				},
+				Entry::Occupied(mut entry) =>
+					if creation_time > entry.get().0 {
+						entry.insert((creation_time, addresses));
+						outdated += 1;
+					} else if creation_time < entry.get().0 {
+						outdated += 1;
+					},
+			}
+		}
+
+		let newest = most_recent.values().filter_map(|(creation_time, _)| *creation_time).max();
+		let now = now_as_nanos();
+		let mut addresses = Vec::with_capacity(most_recent.len());
+
+		for (peer_id, (creation_time, record_addresses)) in most_recent {
+			let Some(creation_time) = creation_time else {
+				if let Some(metrics) = &self.metrics {
+					metrics.records_without_creation_time.inc();
+				}
+				addresses.push((None, peer_id, record_addresses));
+				continue
+			};
+
+			last_known_records.insert(peer_id, creation_time);
+
+			// Records of nodes that stopped publishing linger in the DHT until they expire.
+			if newest.map_or(false, |newest| {
+				newest.saturating_sub(creation_time) > MAX_RECORD_AGE_DIFFERENCE.as_nanos()
+			}) {
+				outdated += 1;
+				continue
+			}
+
+			if let Some(metrics) = &self.metrics {
+				let age = Duration::from_nanos(
+					now.saturating_sub(creation_time).try_into().unwrap_or(u64::MAX),
+				);
+				metrics.record_age.observe(age.as_secs_f64());
+			}
+			addresses.push((Some(creation_time), peer_id, record_addresses));
+		}
+
+		if outdated > 0 {
+			debug!(
+				target: LOG_TARGET,
+				"Discarded {outdated} outdated authority discovery records of {authority_id}",
+			);
+			if let Some(metrics) = &self.metrics {
+				metrics.outdated_records.inc_by(outdated);
+			}
+		}
+
+		// Records without a creation time come last.
+		addresses.sort_by(|(time_a, peer_a, _), (time_b, peer_b, _)| {
+			time_b.cmp(time_a).then_with(|| peer_a.cmp(peer_b))
+		});
+		addresses.into_iter().map(|(_, _, addresses)| addresses).collect()
+	}
+
 	/// Retrieve our public keys within the current and next authority set.
 	// A node might have multiple authority discovery keys within its keystore, e.g. an old one and
 	// one for the upcoming session. In addition it could be participating in the current and (/ or)
@@ -643,9 +773,20 @@ fn serialize_addresses(addresses: impl Iterator<Item = Multiaddr>) -> Vec<Vec<u8
 	addresses.map(|a| a.to_vec()).collect()
 }
 
-fn serialize_authority_record(addresses: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+/// Time elapsed since the UNIX epoch, in nanoseconds.
+fn now_as_nanos() -> u128 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()
+}
+
+fn serialize_authority_record(
+	addresses: Vec<Vec<u8>>,
+	creation_time: Option<u128>,
+) -> Result<Vec<u8>> {
+	let creation_time =
+		creation_time.map(|time| schema::TimestampInfo { timestamp: time.encode() });
+
 	let mut serialized_record = vec![];
-	schema::AuthorityRecord { addresses }
+	schema::AuthorityRecord { addresses, creation_time }
 		.encode(&mut serialized_record)
 		.map_err(Error::EncodingProto)?;
 	Ok(serialized_record)
@@ -705,6 +846,9 @@ pub(crate) struct Metrics {
 	dht_event_received: CounterVec<U64>,
 	handle_value_found_event_failure: Counter<U64>,
 	known_authorities_count: Gauge<U64>,
+	record_age: Histogram,
+	outdated_records: Counter<U64>,
+	records_without_creation_time: Counter<U64>,
 }
 
 impl Metrics {
@@ -764,6 +908,31 @@ impl Metrics {
 				)?,
 				registry,
 			)?,
+			record_age: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_authority_discovery_record_age_seconds",
+						"Age of the authority discovery records accepted from the DHT.",
+					)
+					.buckets(vec![60.0, 300.0, 900.0, 1800.0, 3600.0, 7200.0, 14400.0, 86400.0]),
+				)?,
+				registry,
+			)?,
+			outdated_records: register(
+				Counter::new(
+					"substrate_authority_discovery_outdated_records_total",
+					"Number of authority discovery records discarded because a more recent record \
+					 of the same node is known.",
+				)?,
+				registry,
+			)?,
+			records_without_creation_time: register(
+				Counter::new(
+					"substrate_authority_discovery_records_without_creation_time_total",
+					"Number of authority discovery records accepted without a creation time.",
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
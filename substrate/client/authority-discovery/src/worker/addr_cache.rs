@@ -31,8 +31,8 @@ pub(super) struct AddrCache {
 	/// are similar to a bi-directional map.
 	///
 	/// Since we may store the mapping across several sessions, a single
-	/// `PeerId` might correspond to multiple `AuthorityId`s. However,
-	/// it's not expected that a single `AuthorityId` can have multiple `PeerId`s.
+	/// `PeerId` might correspond to multiple `AuthorityId`s. A single `AuthorityId` can also have
+	/// multiple `PeerId`s when several nodes publish records on behalf of the same authority.
 	authority_id_to_addresses: HashMap<AuthorityId, HashSet<Multiaddr>>,
 	peer_id_to_authority_ids: HashMap<PeerId, HashSet<AuthorityId>>,
 }
@@ -61,7 +61,7 @@ impl AddrCache {
 
 			return
 		} else if peer_ids.len() > 1 {
-			log::warn!(
+			log::debug!(
 				target: super::LOG_TARGET,
 				"Authority({:?}) can be reached through multiple peer ids: {:?}",
 				authority_id,
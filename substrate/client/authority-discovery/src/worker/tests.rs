@@ -213,9 +213,26 @@ fn build_dht_event<Signer: NetworkSigner>(
 	public_key: AuthorityId,
 	key_store: &MemoryKeystore,
 	network: Option<&Signer>,
+) -> Vec<(KademliaKey, Vec<u8>)> {
+	build_dht_event_with_creation_time(
+		addresses,
+		public_key,
+		key_store,
+		network,
+		Some(now_as_nanos()),
+	)
+}
+
+fn build_dht_event_with_creation_time<Signer: NetworkSigner>(
+	addresses: Vec<Multiaddr>,
+	public_key: AuthorityId,
+	key_store: &MemoryKeystore,
+	network: Option<&Signer>,
+	creation_time: Option<u128>,
 ) -> Vec<(KademliaKey, Vec<u8>)> {
 	let serialized_record =
-		serialize_authority_record(serialize_addresses(addresses.into_iter())).unwrap();
+		serialize_authority_record(serialize_addresses(addresses.into_iter()), creation_time)
+			.unwrap();
 
 	let peer_signature = network.map(|n| sign_record_with_peer_id(&serialized_record, n).unwrap());
 	let kv_pairs = sign_record_with_authority_ids(
@@ -681,6 +698,126 @@ fn do_not_cache_addresses_without_peer_id() {
 	);
 }
 
+#[test]
+fn keep_most_recent_record_of_node() {
+	let mut tester = DhtValueFoundTester::new();
+	let (old_addr, new_addr) = (tester.multiaddr_with_peer_id(1), tester.multiaddr_with_peer_id(2));
+	let now = now_as_nanos();
+
+	let mut kv_pairs = build_dht_event_with_creation_time(
+		vec![new_addr.clone()],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &tester.remote_node_key }),
+		Some(now),
+	);
+	kv_pairs.extend(build_dht_event_with_creation_time(
+		vec![old_addr.clone()],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &tester.remote_node_key }),
+		Some(now - 1),
+	));
+
+	let cached_remote_addresses = tester.process_value_found(false, kv_pairs);
+	assert_eq!(Some(&HashSet::from([new_addr.clone()])), cached_remote_addresses);
+
+	// The outdated record is still ignored when received on its own in a later lookup.
+	let kv_pairs = build_dht_event_with_creation_time(
+		vec![old_addr],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &tester.remote_node_key }),
+		Some(now - 1),
+	);
+	let worker = tester.local_worker.as_mut().unwrap();
+	block_on(worker.refill_pending_lookups_queue()).unwrap();
+	worker.start_new_lookups();
+	worker.handle_dht_value_found_event(kv_pairs).unwrap();
+
+	assert_eq!(
+		Some(&HashSet::from([new_addr])),
+		worker
+			.addr_cache
+			.get_addresses_by_authority_id(&tester.remote_authority_public.into()),
+	);
+}
+
+#[test]
+fn keep_records_of_multiple_nodes() {
+	let mut tester = DhtValueFoundTester::new();
+	let now = now_as_nanos();
+	let other_node_key = Keypair::generate_ed25519();
+	let other_addr: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:ff/tcp/30333".parse().unwrap();
+	let other_addr =
+		other_addr.with(multiaddr::Protocol::P2p(other_node_key.public().to_peer_id().into()));
+	let stale_node_key = Keypair::generate_ed25519();
+	let stale_addr: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:fe/tcp/30333".parse().unwrap();
+	let stale_addr =
+		stale_addr.with(multiaddr::Protocol::P2p(stale_node_key.public().to_peer_id().into()));
+	let addr = tester.multiaddr_with_peer_id(1);
+
+	let mut kv_pairs = build_dht_event_with_creation_time(
+		vec![addr.clone()],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &tester.remote_node_key }),
+		Some(now),
+	);
+	kv_pairs.extend(build_dht_event_with_creation_time(
+		vec![other_addr.clone()],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &other_node_key }),
+		Some(now - Duration::from_secs(60).as_nanos()),
+	));
+	// Published by a node that is gone for a long time.
+	kv_pairs.extend(build_dht_event_with_creation_time(
+		vec![stale_addr],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &stale_node_key }),
+		Some(now - 2 * MAX_RECORD_AGE_DIFFERENCE.as_nanos()),
+	));
+
+	let cached_remote_addresses = tester.process_value_found(false, kv_pairs);
+	assert_eq!(Some(&HashSet::from([addr, other_addr])), cached_remote_addresses);
+}
+
+#[test]
+fn limit_number_of_addresses_per_node() {
+	let mut tester = DhtValueFoundTester::new();
+	let now = now_as_nanos();
+	let other_node_key = Keypair::generate_ed25519();
+	let other_addr: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:ff/tcp/30333".parse().unwrap();
+	let other_addr =
+		other_addr.with(multiaddr::Protocol::P2p(other_node_key.public().to_peer_id().into()));
+	let addresses: Vec<_> = (1..100).map(|i| tester.multiaddr_with_peer_id(i)).collect();
+
+	let mut kv_pairs = build_dht_event_with_creation_time(
+		addresses.clone(),
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &tester.remote_node_key }),
+		Some(now),
+	);
+	kv_pairs.extend(build_dht_event_with_creation_time(
+		vec![other_addr.clone()],
+		tester.remote_authority_public.into(),
+		&tester.remote_key_store,
+		Some(&TestSigner { keypair: &other_node_key }),
+		Some(now - 1),
+	));
+
+	// The node publishing many addresses doesn't crowd out the other one, and the same
+	// addresses of the first node are always kept.
+	let mut expected: HashSet<_> =
+		addresses.into_iter().take(MAX_ADDRESSES_PER_AUTHORITY - 1).collect();
+	expected.insert(other_addr);
+	let cached_remote_addresses = tester.process_value_found(false, kv_pairs);
+	assert_eq!(Some(&expected), cached_remote_addresses);
+}
+
 #[test]
 fn addresses_to_publish_adds_p2p() {
 	let (_dht_event_tx, dht_event_rx) = channel(1000);
@@ -20,7 +20,8 @@ use crate::{arg_enums::SyncMode, params::node_key_params::NodeKeyParams};
 use clap::Args;
 use sc_network::{
 	config::{
-		NetworkConfiguration, NodeKeyConfig, NonReservedPeerMode, SetConfig, TransportConfig,
+		NetworkConfiguration, NodeKeyConfig, NonReservedPeerMode, ProtocolName, ProtocolRateLimits,
+		RateLimit, SetConfig, TransportConfig,
 	},
 	multiaddr::Protocol,
 };
@@ -28,7 +29,74 @@ use sc_service::{
 	config::{Multiaddr, MultiaddrWithPeerId},
 	ChainSpec, ChainType,
 };
-use std::{borrow::Cow, num::NonZeroUsize, path::PathBuf};
+use std::{
+	borrow::Cow,
+	collections::HashMap,
+	num::{NonZeroU64, NonZeroUsize},
+	path::PathBuf,
+};
+
+/// Traffic that a [`ProtocolRateLimitParam`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitedTraffic {
+	/// Inbound traffic, all peers included.
+	Inbound,
+	/// Outbound traffic, all peers included.
+	Outbound,
+	/// Inbound traffic of every single peer.
+	InboundPerPeer,
+	/// Outbound traffic of every single peer.
+	OutboundPerPeer,
+}
+
+/// Rate limit of a single protocol, as passed to `--protocol-rate-limit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolRateLimitParam {
+	/// Name of the rate limited protocol.
+	pub protocol: ProtocolName,
+	/// Traffic the limit applies to.
+	pub traffic: RateLimitedTraffic,
+	/// The limit itself.
+	pub limit: RateLimit,
+}
+
+/// Parses `<PROTOCOL>=<TRAFFIC>:<BYTES_PER_SECOND>[:<BURST>]`.
+fn parse_protocol_rate_limit(s: &str) -> Result<ProtocolRateLimitParam, String> {
+	let (protocol, limit) = s.rsplit_once('=').ok_or_else(|| {
+		"expected `<PROTOCOL>=<TRAFFIC>:<BYTES_PER_SECOND>[:<BURST>]`".to_string()
+	})?;
+	if protocol.is_empty() {
+		return Err("protocol name is empty".into())
+	}
+
+	let mut parts = limit.split(':');
+	let traffic = match parts.next() {
+		Some("inbound") => RateLimitedTraffic::Inbound,
+		Some("outbound") => RateLimitedTraffic::Outbound,
+		Some("inbound-per-peer") => RateLimitedTraffic::InboundPerPeer,
+		Some("outbound-per-peer") => RateLimitedTraffic::OutboundPerPeer,
+		other => return Err(format!("unknown traffic {:?}", other.unwrap_or_default())),
+	};
+	let bytes_per_second = parts
+		.next()
+		.ok_or_else(|| "missing bytes per second".to_string())?
+		.parse::<NonZeroU64>()
+		.map_err(|e| format!("invalid bytes per second: {e}"))?;
+	let burst = parts
+		.next()
+		.map(|burst| burst.parse::<u64>().map_err(|e| format!("invalid burst: {e}")))
+		.transpose()?
+		.unwrap_or(0);
+	if parts.next().is_some() {
+		return Err("too many `:` separated values".into())
+	}
+
+	Ok(ProtocolRateLimitParam {
+		protocol: protocol.to_string().into(),
+		traffic,
+		limit: RateLimit { bytes_per_second, burst },
+	})
+}
 
 /// Parameters used to create the network configuration.
 #[derive(Debug, Clone, Args)]
@@ -166,6 +234,19 @@ pub struct NetworkParams {
 	/// and observe block requests timing out.
 	#[arg(long, value_name = "COUNT", default_value_t = 64)]
 	pub max_blocks_per_request: u32,
+
+	/// Rate limit the traffic of a notifications or request-response protocol.
+	///
+	/// Format: `<PROTOCOL>=<TRAFFIC>:<BYTES_PER_SECOND>[:<BURST>]`, where `<TRAFFIC>` is one of
+	/// `inbound`, `outbound`, `inbound-per-peer` or `outbound-per-peer`. `<BURST>` defaults to 0.
+	///
+	/// Can be passed multiple times. Protocols which are never passed aren't rate limited.
+	#[arg(
+		long,
+		value_name = "PROTOCOL=TRAFFIC:BYTES_PER_SECOND[:BURST]",
+		value_parser = parse_protocol_rate_limit
+	)]
+	pub protocol_rate_limit: Vec<ProtocolRateLimitParam>,
 }
 
 impl NetworkParams {
@@ -261,8 +342,25 @@ impl NetworkParams {
 			yamux_window_size: None,
 			ipfs_server: self.ipfs_server,
 			sync_mode: self.sync.into(),
+			protocol_rate_limits: self.protocol_rate_limits(),
 		}
 	}
+
+	/// Collect the `--protocol-rate-limit` parameters, by protocol.
+	fn protocol_rate_limits(&self) -> HashMap<ProtocolName, ProtocolRateLimits> {
+		let mut limits = HashMap::<ProtocolName, ProtocolRateLimits>::new();
+		for param in &self.protocol_rate_limit {
+			let limits = limits.entry(param.protocol.clone()).or_default();
+			let limit = match param.traffic {
+				RateLimitedTraffic::Inbound => &mut limits.inbound,
+				RateLimitedTraffic::Outbound => &mut limits.outbound,
+				RateLimitedTraffic::InboundPerPeer => &mut limits.inbound_per_peer,
+				RateLimitedTraffic::OutboundPerPeer => &mut limits.outbound_per_peer,
+			};
+			*limit = Some(param.limit);
+		}
+		limits
+	}
 }
 
 #[cfg(test)]
@@ -315,4 +413,53 @@ mod tests {
 
 		assert_eq!(SyncMode::Warp, params.network_params.sync);
 	}
+
+	#[test]
+	fn protocol_rate_limits_are_grouped_by_protocol() {
+		let params = Cli::try_parse_from([
+			"",
+			"--protocol-rate-limit",
+			"/block-announces/1=inbound:1000",
+			"--protocol-rate-limit",
+			"/block-announces/1=outbound-per-peer:200:50",
+			"--protocol-rate-limit",
+			"/sync/2=inbound-per-peer:300",
+		])
+		.expect("Parses network params");
+
+		let limit = |bytes_per_second, burst| {
+			Some(RateLimit { bytes_per_second: NonZeroU64::new(bytes_per_second).unwrap(), burst })
+		};
+		let limits = params.network_params.protocol_rate_limits();
+		assert_eq!(limits.len(), 2);
+		assert_eq!(
+			limits[&ProtocolName::from("/block-announces/1")],
+			ProtocolRateLimits {
+				inbound: limit(1000, 0),
+				outbound_per_peer: limit(200, 50),
+				..Default::default()
+			},
+		);
+		assert_eq!(
+			limits[&ProtocolName::from("/sync/2")],
+			ProtocolRateLimits { inbound_per_peer: limit(300, 0), ..Default::default() },
+		);
+	}
+
+	#[test]
+	fn invalid_protocol_rate_limits_are_rejected() {
+		for invalid in [
+			"/sync/2",
+			"=inbound:1000",
+			"/sync/2=sideways:1000",
+			"/sync/2=inbound",
+			"/sync/2=inbound:0",
+			"/sync/2=inbound:1000:10:1",
+		] {
+			assert!(
+				Cli::try_parse_from(["", "--protocol-rate-limit", invalid]).is_err(),
+				"{invalid} should be rejected",
+			);
+		}
+	}
 }
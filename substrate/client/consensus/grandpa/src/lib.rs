@@ -136,8 +136,8 @@ pub use justification::GrandpaJustification;
 pub use notification::{GrandpaJustificationSender, GrandpaJustificationStream};
 pub use observer::run_grandpa_observer;
 pub use voting_rule::{
-	BeforeBestBlockBy, ThreeQuartersOfTheUnfinalizedChain, VotingRule, VotingRuleResult,
-	VotingRulesBuilder,
+	BeforeBestBlockBy, RuntimeVotingRule, ThreeQuartersOfTheUnfinalizedChain, VotingRule,
+	VotingRuleResult, VotingRulesBuilder,
 };
 
 use aux_schema::PersistentData;
@@ -27,6 +27,8 @@ use std::{future::Future, pin::Pin, sync::Arc};
 use dyn_clone::DynClone;
 
 use sc_client_api::blockchain::HeaderBackend;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_consensus_grandpa::{GrandpaVotingRuleApi, CLIENT_LOG_TARGET as LOG_TARGET};
 use sp_runtime::traits::{Block as BlockT, Header, NumberFor, One, Zero};
 
 /// A future returned by a `VotingRule` to restrict a given vote, if any restriction is necessary.
@@ -166,6 +168,63 @@ where
 	}
 }
 
+/// A custom voting rule that limits votes according to the runtime, as returned by
+/// [`GrandpaVotingRuleApi::voting_target_limit`] at the current target block.
+///
+/// The vote is never restricted below the given `base`. If the runtime doesn't expose the API,
+/// or if it fails to return the limit, the vote isn't restricted.
+#[derive(Clone)]
+pub struct RuntimeVotingRule;
+
+impl<Block, B> VotingRule<Block, B> for RuntimeVotingRule
+where
+	Block: BlockT,
+	B: HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+	B::Api: GrandpaVotingRuleApi<Block>,
+{
+	fn restrict_vote(
+		&self,
+		backend: Arc<B>,
+		base: &Block::Header,
+		_best_target: &Block::Header,
+		current_target: &Block::Header,
+	) -> VotingRuleResult<Block> {
+		let api = backend.runtime_api();
+		let at = current_target.hash();
+
+		let limit = match api.has_api::<dyn GrandpaVotingRuleApi<Block>>(at) {
+			Ok(true) => api.voting_target_limit(at),
+			Ok(false) => return Box::pin(async { None }),
+			Err(err) => Err(err),
+		};
+
+		let limit = match limit {
+			Ok(Some(limit)) => limit,
+			Ok(None) => return Box::pin(async { None }),
+			Err(err) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to fetch the voting target limit from the runtime at {:?}: {}",
+					at,
+					err,
+				);
+				return Box::pin(async { None })
+			},
+		};
+
+		// our current target is already lower than the runtime would restrict
+		if limit >= *current_target.number() {
+			return Box::pin(async { None })
+		}
+
+		// constrain to the base number, if that's the minimal vote that can be placed.
+		let target_number = limit.max(*base.number());
+
+		// find the block at the given target height
+		Box::pin(std::future::ready(find_target(&*backend, target_number, current_target)))
+	}
+}
+
 // walk backwards until we find the target block
 fn find_target<Block, B>(
 	backend: &B,
@@ -335,7 +394,7 @@ mod tests {
 	use sp_runtime::traits::Header as _;
 
 	use substrate_test_runtime_client::{
-		runtime::{Block, Header},
+		runtime::{Block, Hash, Header},
 		Backend, Client, ClientBlockImportExt, DefaultTestClientBuilderExt, TestClientBuilder,
 		TestClientBuilderExt,
 	};
@@ -451,4 +510,98 @@ mod tests {
 			assert_eq!(number, expected, "best = {}, lag = 2, base = {}", best_number, i);
 		}
 	}
+
+	/// A backend wrapping the test client, exposing a mocked `GrandpaVotingRuleApi`.
+	#[derive(Clone)]
+	struct RuntimeLimitedBackend {
+		client: Arc<Client<Backend>>,
+		limit: Option<u64>,
+	}
+
+	impl HeaderBackend<Block> for RuntimeLimitedBackend {
+		fn header(&self, hash: Hash) -> sp_blockchain::Result<Option<Header>> {
+			self.client.header(hash)
+		}
+
+		fn info(&self) -> sp_blockchain::Info<Block> {
+			self.client.info()
+		}
+
+		fn status(&self, hash: Hash) -> sp_blockchain::Result<sp_blockchain::BlockStatus> {
+			self.client.status(hash)
+		}
+
+		fn number(&self, hash: Hash) -> sp_blockchain::Result<Option<u64>> {
+			self.client.number(hash)
+		}
+
+		fn hash(&self, number: u64) -> sp_blockchain::Result<Option<Hash>> {
+			self.client.hash(number)
+		}
+	}
+
+	struct RuntimeApi {
+		limit: Option<u64>,
+	}
+
+	impl ProvideRuntimeApi<Block> for RuntimeLimitedBackend {
+		type Api = RuntimeApi;
+
+		fn runtime_api(&self) -> sp_api::ApiRef<'_, Self::Api> {
+			RuntimeApi { limit: self.limit }.into()
+		}
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl GrandpaVotingRuleApi<Block> for RuntimeApi {
+			fn voting_target_limit(&self) -> Option<u64> {
+				self.limit
+			}
+		}
+	}
+
+	#[test]
+	fn runtime_voting_rule_restricts_vote() {
+		let mut client = Arc::new(TestClientBuilder::new().build());
+
+		let mut hashes = Vec::with_capacity(10);
+		for _ in 0..10 {
+			let block = BlockBuilderBuilder::new(&*client)
+				.on_parent_block(client.chain_info().best_hash)
+				.with_parent_block_number(client.chain_info().best_number)
+				.build()
+				.unwrap()
+				.build()
+				.unwrap()
+				.block;
+			hashes.push(block.hash());
+
+			futures::executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+		}
+
+		let genesis = client.header(client.info().genesis_hash).unwrap().unwrap();
+		let best = client.header(client.info().best_hash).unwrap().unwrap();
+		let block4 = client.header(hashes[3]).unwrap().unwrap();
+		let block8 = client.header(hashes[7]).unwrap().unwrap();
+
+		let restrict = |limit, base: &Header| {
+			let backend = Arc::new(RuntimeLimitedBackend { client: client.clone(), limit });
+			futures::executor::block_on(
+				RuntimeVotingRule.restrict_vote(backend, base, &best, &best),
+			)
+		};
+
+		// no restriction requested by the runtime.
+		assert_eq!(restrict(None, &genesis), None);
+
+		// the limit is above the current target.
+		assert_eq!(restrict(Some(12), &genesis), None);
+
+		// the vote is restricted to the limit.
+		assert_eq!(restrict(Some(6), &genesis), Some((hashes[5], 6)));
+
+		// the vote is never restricted below the base.
+		assert_eq!(restrict(Some(6), &block8), Some((block8.hash(), 8)));
+		assert_eq!(restrict(Some(6), &block4), Some((hashes[5], 6)));
+	}
 }
@@ -35,6 +35,10 @@ use sp_timestamp::TimestampInherentData;
 use std::{marker::PhantomData, sync::Arc};
 
 /// Consensus data provider for Aura.
+///
+/// The slot is derived from the timestamp inherent, so the digests are reproducible as long as
+/// the timestamps are, e.g. when they are provided by
+/// [`SlotTimestampProvider::new_aura_at`](super::timestamp::SlotTimestampProvider::new_aura_at).
 pub struct AuraConsensusDataProvider<B, C, P> {
 	// slot duration
 	slot_duration: SlotDuration,
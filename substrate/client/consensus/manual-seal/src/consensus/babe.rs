@@ -69,6 +69,9 @@ pub struct BabeConsensusDataProvider<B: BlockT, C, P> {
 
 	/// Authorities to be used for this babe chain.
 	authorities: Vec<(AuthorityId, BabeAuthorityWeight)>,
+
+	/// Claim secondary plain slots only, see [`Self::with_deterministic_digests`].
+	deterministic: bool,
 	_phantom: PhantomData<P>,
 }
 
@@ -159,10 +162,30 @@ where
 			keystore,
 			epoch_changes,
 			authorities,
+			deterministic: false,
 			_phantom: Default::default(),
 		})
 	}
 
+	/// Only claim secondary plain slots, on behalf of the first of the given authorities.
+	///
+	/// Primary and secondary VRF claims carry a randomized VRF signature, so sealing the same
+	/// block twice yields two different headers. Secondary plain claims only depend on the slot,
+	/// which makes the digests reproducible when building forks.
+	pub fn with_deterministic_digests(mut self) -> Self {
+		self.deterministic = true;
+		self
+	}
+
+	fn secondary_plain_claim(&self, slot: Slot, epoch: &Epoch) -> Option<PreDigest> {
+		let (authority, _) = self.authorities.first()?;
+		let authority_index = epoch.authorities.iter().position(|(id, _)| id == authority)?;
+		Some(PreDigest::SecondaryPlain(SecondaryPlainPreDigest {
+			slot,
+			authority_index: authority_index as u32,
+		}))
+	}
+
 	fn epoch(&self, parent: &B::Header, slot: Slot) -> Result<Epoch, Error> {
 		let epoch_changes = self.epoch_changes.shared_data();
 		let epoch_descriptor = epoch_changes
@@ -205,10 +228,14 @@ where
 			.ok_or_else(|| Error::StringError("No babe inherent data".into()))?;
 		let epoch = self.epoch(parent, slot)?;
 
+		let claim = if self.deterministic {
+			self.secondary_plain_claim(slot, &epoch)
+		} else {
+			authorship::claim_slot(slot, &epoch, &self.keystore).map(|(predigest, _)| predigest)
+		};
+
 		// this is a dev node environment, we should always be able to claim a slot.
-		let logs = if let Some((predigest, _)) =
-			authorship::claim_slot(slot, &epoch, &self.keystore)
-		{
+		let logs = if let Some(predigest) = claim {
 			vec![<DigestItem as CompatibleDigestItem>::babe_pre_digest(predigest)]
 		} else {
 			// well we couldn't claim a slot because this is an existing chain and we're not in the
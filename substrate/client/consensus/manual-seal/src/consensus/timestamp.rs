@@ -30,7 +30,7 @@ use sp_consensus_aura::{
 use sp_consensus_babe::BabeApi;
 use sp_consensus_slots::{Slot, SlotDuration};
 use sp_inherents::{InherentData, InherentDataProvider, InherentIdentifier};
-use sp_runtime::traits::{Block as BlockT, Zero};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Zero};
 use sp_timestamp::{InherentType, INHERENT_IDENTIFIER};
 use std::{
 	sync::{atomic, Arc},
@@ -90,6 +90,88 @@ impl SlotTimestampProvider {
 		Ok(Self { unix_millis: atomic::AtomicU64::new(time), slot_duration })
 	}
 
+	/// Create a new deterministic time stamp provider, for babe.
+	///
+	/// Unlike [`Self::new_babe`], the timestamp only depends on the given parent block: it is
+	/// `genesis_timestamp` when building on top of genesis and the time of the slot following
+	/// the parent block slot otherwise. Sealing the same blocks on top of the same parent always
+	/// yields the same timestamps, which makes this provider suitable to build forks.
+	pub fn new_babe_at<B, C>(
+		client: Arc<C>,
+		parent_hash: B::Hash,
+		genesis_timestamp: u64,
+	) -> Result<Self, Error>
+	where
+		B: BlockT,
+		C: AuxStore + HeaderBackend<B> + ProvideRuntimeApi<B> + UsageProvider<B>,
+		C::Api: BabeApi<B>,
+	{
+		let slot_duration = sc_consensus_babe::configuration(&*client)?.slot_duration();
+
+		let time =
+			Self::with_parent(&client, parent_hash, slot_duration, genesis_timestamp, |header| {
+				let slot_number = *sc_consensus_babe::find_pre_digest::<B>(&header)
+					.map_err(|err| format!("{}", err))?
+					.slot();
+				Ok(slot_number)
+			})?;
+
+		Ok(Self { unix_millis: atomic::AtomicU64::new(time), slot_duration })
+	}
+
+	/// Create a new deterministic time stamp provider, for aura.
+	///
+	/// See [`Self::new_babe_at`] for how the timestamp is derived from the parent block.
+	pub fn new_aura_at<B, C>(
+		client: Arc<C>,
+		parent_hash: B::Hash,
+		genesis_timestamp: u64,
+	) -> Result<Self, Error>
+	where
+		B: BlockT,
+		C: AuxStore + HeaderBackend<B> + ProvideRuntimeApi<B> + UsageProvider<B>,
+		C::Api: AuraApi<B, AuthorityId>,
+	{
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+
+		let time =
+			Self::with_parent(&client, parent_hash, slot_duration, genesis_timestamp, |header| {
+				let slot_number =
+					*sc_consensus_aura::find_pre_digest::<B, AuthoritySignature>(&header)
+						.map_err(|err| format!("{}", err))?;
+				Ok(slot_number)
+			})?;
+
+		Ok(Self { unix_millis: atomic::AtomicU64::new(time), slot_duration })
+	}
+
+	fn with_parent<F, C, B>(
+		client: &Arc<C>,
+		parent_hash: B::Hash,
+		slot_duration: SlotDuration,
+		genesis_timestamp: u64,
+		func: F,
+	) -> Result<u64, Error>
+	where
+		B: BlockT,
+		C: HeaderBackend<B>,
+		F: Fn(B::Header) -> Result<u64, Error>,
+	{
+		let header = client
+			.header(parent_hash)?
+			.ok_or_else(|| Error::BlockNotFound(format!("{}", parent_hash)))?;
+
+		let time = if header.number().is_zero() {
+			genesis_timestamp
+		} else {
+			let slot = func(header)?;
+			// the block is built in the slot following the parent one.
+			(slot + 1) * slot_duration.as_millis() as u64
+		};
+
+		Ok(time)
+	}
+
 	fn with_header<F, C, B>(
 		client: &Arc<C>,
 		slot_duration: SlotDuration,
@@ -38,6 +38,9 @@ mod codes {
 	pub const CONSENSUS_ERROR: i32 = 14_000;
 	pub const INHERENTS_ERROR: i32 = 15_000;
 	pub const BLOCKCHAIN_ERROR: i32 = 16_000;
+	pub const FORK_NOT_FOUND: i32 = 17_000;
+	pub const FORK_ALREADY_EXISTS: i32 = 18_000;
+	pub const FORKS_DISABLED: i32 = 19_000;
 	pub const UNKNOWN_ERROR: i32 = 20_000;
 }
 
@@ -64,6 +67,15 @@ pub enum Error {
 	/// Supplied parent_hash doesn't exist in chain
 	#[error("Supplied parent_hash: {0} doesn't exist in chain")]
 	BlockNotFound(String),
+	/// No fork with the given name was created
+	#[error("Fork {0:?} doesn't exist")]
+	ForkNotFound(String),
+	/// A fork with the given name was already created
+	#[error("Fork {0:?} already exists")]
+	ForkAlreadyExists(String),
+	/// The engine was started without named forks support
+	#[error("Named forks are not enabled, run the engine with `run_manual_seal_with_forks`")]
+	ForksDisabled,
 	/// Some string error
 	#[error("{0}")]
 	StringError(String),
@@ -100,6 +112,9 @@ impl Error {
 			ConsensusError(_) => codes::CONSENSUS_ERROR,
 			InherentError(_) => codes::INHERENTS_ERROR,
 			BlockchainError(_) => codes::BLOCKCHAIN_ERROR,
+			ForkNotFound(_) => codes::FORK_NOT_FOUND,
+			ForkAlreadyExists(_) => codes::FORK_ALREADY_EXISTS,
+			ForksDisabled => codes::FORKS_DISABLED,
 			SendError(_) | Canceled(_) => codes::SERVER_SHUTTING_DOWN,
 			_ => codes::UNKNOWN_ERROR,
 		}
@@ -0,0 +1,185 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Named forks utilities, used to simulate reorgs.
+
+use crate::{ConsensusDataProvider, Error};
+use sc_client_api::backend::{
+	Backend as ClientBackend, BlockImportOperation, ImportNotificationAction, ImportSummary,
+	LockImportRun,
+};
+use sc_consensus::BlockImportParams;
+use sp_blockchain::{HeaderBackend, HeaderMetadata};
+use sp_consensus::BlockOrigin;
+use sp_inherents::InherentData;
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT},
+	Digest, DigestItem,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Prefix of the digest item identifying the blocks sealed on a named fork.
+pub const FORK_DIGEST_PREFIX: &[u8] = b"manual-seal-fork:";
+
+/// Tracks the tip of every named fork.
+pub(crate) struct Forks<Hash> {
+	tips: HashMap<String, Hash>,
+	// marks the given block as the best one.
+	set_best_block: Box<dyn Fn(Hash) -> Result<(), Error> + Send>,
+}
+
+impl<Hash: Copy> Forks<Hash> {
+	pub(crate) fn new<B, C, CB>(client: Arc<C>) -> Self
+	where
+		B: BlockT<Hash = Hash>,
+		C: HeaderBackend<B>
+			+ HeaderMetadata<B, Error = sp_blockchain::Error>
+			+ LockImportRun<B, CB>
+			+ Send
+			+ Sync
+			+ 'static,
+		CB: ClientBackend<B>,
+	{
+		Self {
+			tips: HashMap::new(),
+			set_best_block: Box::new(move |hash| set_best_block::<B, C, CB>(&*client, hash)),
+		}
+	}
+
+	/// Register a new fork starting at `from`.
+	pub(crate) fn create(&mut self, name: String, from: Hash) -> Result<(), Error> {
+		if self.tips.contains_key(&name) {
+			return Err(Error::ForkAlreadyExists(name))
+		}
+		self.tips.insert(name, from);
+		Ok(())
+	}
+
+	/// Get the tip of a fork.
+	pub(crate) fn tip(&self, name: &str) -> Result<Hash, Error> {
+		self.tips.get(name).copied().ok_or_else(|| Error::ForkNotFound(name.into()))
+	}
+
+	/// Move the tip of an existing fork.
+	pub(crate) fn set_tip(&mut self, name: &str, tip: Hash) {
+		if let Some(current) = self.tips.get_mut(name) {
+			*current = tip;
+		}
+	}
+
+	/// Stop tracking a fork, returning its tip.
+	///
+	/// The blocks sealed on the fork are left untouched.
+	pub(crate) fn remove(&mut self, name: &str) -> Result<Hash, Error> {
+		self.tips.remove(name).ok_or_else(|| Error::ForkNotFound(name.into()))
+	}
+
+	/// Make the tip of a fork the best block.
+	pub(crate) fn switch_to(&self, name: &str) -> Result<Hash, Error> {
+		let tip = self.tip(name)?;
+		(self.set_best_block)(tip)?;
+		Ok(tip)
+	}
+}
+
+/// Get the forks tracker, if the engine was started with named forks support.
+pub(crate) fn enabled<Hash>(forks: &mut Option<Forks<Hash>>) -> Result<&mut Forks<Hash>, Error> {
+	forks.as_mut().ok_or(Error::ForksDisabled)
+}
+
+/// Marks the given block as the best one, regardless of the fork choice rule.
+///
+/// Import notification subscribers are notified of the new best block, with the route from the
+/// previous best block when it isn't the parent, the same way as when a block is imported.
+fn set_best_block<B, C, CB>(client: &C, hash: B::Hash) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + HeaderMetadata<B, Error = sp_blockchain::Error> + LockImportRun<B, CB>,
+	CB: ClientBackend<B>,
+{
+	client.lock_import_and_run(|operation| {
+		let best_hash = client.info().best_hash;
+		if best_hash == hash {
+			return Ok(())
+		}
+		let header = client.expect_header(hash)?;
+		let parent_hash = *header.parent_hash();
+		let tree_route = if best_hash != parent_hash {
+			Some(sp_blockchain::tree_route(client, best_hash, parent_hash)?)
+		} else {
+			None
+		};
+
+		operation.op.mark_head(hash)?;
+		operation.notify_imported = Some(ImportSummary {
+			hash,
+			origin: BlockOrigin::Own,
+			header,
+			is_new_best: true,
+			storage_changes: None,
+			tree_route,
+			import_notification_action: ImportNotificationAction::RecentBlock,
+		});
+		Ok::<_, sp_blockchain::Error>(())
+	})?;
+	Ok(())
+}
+
+/// Wraps the configured consensus data provider, tagging the sealed blocks with the fork name.
+///
+/// Without the tag, empty blocks sealed on two forks starting at the same block would be
+/// identical.
+pub(crate) struct ForkDigestProvider<'a, B: BlockT, P> {
+	inner: Option<&'a dyn ConsensusDataProvider<B, Proof = P>>,
+	fork: &'a str,
+}
+
+impl<'a, B: BlockT, P> ForkDigestProvider<'a, B, P> {
+	pub(crate) fn new(
+		inner: Option<&'a dyn ConsensusDataProvider<B, Proof = P>>,
+		fork: &'a str,
+	) -> Self {
+		Self { inner, fork }
+	}
+}
+
+impl<'a, B: BlockT, P> ConsensusDataProvider<B> for ForkDigestProvider<'a, B, P> {
+	type Proof = P;
+
+	fn create_digest(&self, parent: &B::Header, inherents: &InherentData) -> Result<Digest, Error> {
+		let mut digest = match self.inner {
+			Some(inner) => inner.create_digest(parent, inherents)?,
+			None => Default::default(),
+		};
+		digest.push(DigestItem::Other([FORK_DIGEST_PREFIX, self.fork.as_bytes()].concat()));
+		Ok(digest)
+	}
+
+	fn append_block_import(
+		&self,
+		parent: &B::Header,
+		params: &mut BlockImportParams<B>,
+		inherents: &InherentData,
+		proof: Self::Proof,
+	) -> Result<(), Error> {
+		match self.inner {
+			Some(inner) => inner.append_block_import(parent, params, inherents, proof),
+			None => Ok(()),
+		}
+	}
+}
@@ -19,18 +19,18 @@
 //! A manual sealing engine: the engine listens for rpc calls to seal blocks and create forks.
 //! This is suitable for a testing environment.
 
-use futures::prelude::*;
+use futures::{channel::oneshot, prelude::*};
 use futures_timer::Delay;
 use prometheus_endpoint::Registry;
 use sc_client_api::{
-	backend::{Backend as ClientBackend, Finalizer},
+	backend::{Backend as ClientBackend, Finalizer, LockImportRun},
 	client::BlockchainEvents,
 };
 use sc_consensus::{
 	block_import::{BlockImport, BlockImportParams, ForkChoiceStrategy},
 	import_queue::{BasicQueue, BoxBlockImport, Verifier},
 };
-use sp_blockchain::HeaderBackend;
+use sp_blockchain::{HeaderBackend, HeaderMetadata};
 use sp_consensus::{Environment, Proposer, SelectChain};
 use sp_core::traits::SpawnNamed;
 use sp_inherents::CreateInherentDataProviders;
@@ -39,6 +39,7 @@ use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 mod error;
 mod finalize_block;
+mod forks;
 mod seal_block;
 
 pub mod consensus;
@@ -48,6 +49,7 @@ pub use self::{
 	consensus::ConsensusDataProvider,
 	error::Error,
 	finalize_block::{finalize_block, FinalizeBlockParams},
+	forks::FORK_DIGEST_PREFIX,
 	rpc::{CreatedBlock, EngineCommand},
 	seal_block::{seal_block, SealBlockParams, MAX_PROPOSAL_DURATION},
 };
@@ -151,7 +153,65 @@ pub struct DelayedFinalizeParams<C, S> {
 }
 
 /// Creates the background authorship task for the manually seal engine.
+///
+/// Named forks commands are rejected with [`Error::ForksDisabled`], use
+/// [`run_manual_seal_with_forks`] to enable them.
 pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
+	params: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+) where
+	B: BlockT + 'static,
+	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
+	C: HeaderBackend<B> + Finalizer<B, CB> + ProvideRuntimeApi<B> + 'static,
+	CB: ClientBackend<B> + 'static,
+	E: Environment<B> + 'static,
+	E::Proposer: Proposer<B, Proof = P>,
+	CS: Stream<Item = EngineCommand<<B as BlockT>::Hash>> + Unpin + 'static,
+	SC: SelectChain<B> + 'static,
+	TP: TransactionPool<Block = B>,
+	CIDP: CreateInherentDataProviders<B, ()>,
+	P: codec::Encode + Send + Sync + 'static,
+{
+	run_engine(params, None).await
+}
+
+/// Creates the background authorship task for the manually seal engine, with named forks
+/// support.
+///
+/// On top of the commands handled by [`run_manual_seal`], the engine handles
+/// [`EngineCommand::CreateFork`], [`EngineCommand::SealOnFork`], [`EngineCommand::SwitchToFork`]
+/// and [`EngineCommand::RemoveFork`]. Switching to a fork overrides the fork choice rule, which
+/// requires the client to lock the block import.
+///
+/// Sealing the same blocks on two forks only yields the same timestamps if the inherent data
+/// providers only depend on the parent block, see the `new_babe_at` and `new_aura_at`
+/// constructors of [`SlotTimestampProvider`](consensus::timestamp::SlotTimestampProvider).
+pub async fn run_manual_seal_with_forks<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
+	params: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+) where
+	B: BlockT + 'static,
+	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
+	C: HeaderBackend<B>
+		+ HeaderMetadata<B, Error = sp_blockchain::Error>
+		+ Finalizer<B, CB>
+		+ LockImportRun<B, CB>
+		+ ProvideRuntimeApi<B>
+		+ Send
+		+ Sync
+		+ 'static,
+	CB: ClientBackend<B> + 'static,
+	E: Environment<B> + 'static,
+	E::Proposer: Proposer<B, Proof = P>,
+	CS: Stream<Item = EngineCommand<<B as BlockT>::Hash>> + Unpin + 'static,
+	SC: SelectChain<B> + 'static,
+	TP: TransactionPool<Block = B>,
+	CIDP: CreateInherentDataProviders<B, ()>,
+	P: codec::Encode + Send + Sync + 'static,
+{
+	let forks = forks::Forks::new::<B, C, CB>(params.client.clone());
+	run_engine(params, Some(forks)).await
+}
+
+async fn run_engine<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 	ManualSealParams {
 		mut block_import,
 		mut env,
@@ -162,6 +222,7 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 		consensus_data_provider,
 		create_inherent_data_providers,
 	}: ManualSealParams<B, BI, E, C, TP, SC, CS, CIDP, P>,
+	mut forks: Option<forks::Forks<B::Hash>>,
 ) where
 	B: BlockT + 'static,
 	BI: BlockImport<B, Error = sp_consensus::Error> + Send + Sync + 'static,
@@ -204,6 +265,65 @@ pub async fn run_manual_seal<B, BI, CB, E, C, TP, SC, CS, CIDP, P>(
 				})
 				.await
 			},
+			EngineCommand::CreateFork { name, from, mut sender } => {
+				let result =
+					forks::enabled(&mut forks).and_then(|forks| match client.header(from) {
+						Ok(Some(_)) => forks.create(name, from).map(|_| from),
+						Ok(None) => Err(Error::BlockNotFound(format!("{}", from))),
+						Err(e) => Err(e.into()),
+					});
+				rpc::send_result(&mut sender, result)
+			},
+			EngineCommand::SealOnFork { name, count, create_empty, finalize, mut sender } => {
+				let result = async {
+					let forks = forks::enabled(&mut forks)?;
+					let mut tip = forks.tip(&name)?;
+					let digest_provider =
+						forks::ForkDigestProvider::new(consensus_data_provider.as_deref(), &name);
+					let mut created = Vec::with_capacity(count as usize);
+					for _ in 0..count {
+						let (block_sender, block_receiver) = oneshot::channel();
+						seal_block(SealBlockParams {
+							sender: Some(block_sender),
+							parent_hash: Some(tip),
+							finalize,
+							create_empty,
+							env: &mut env,
+							select_chain: &select_chain,
+							block_import: &mut block_import,
+							consensus_data_provider: Some(&digest_provider),
+							pool: pool.clone(),
+							client: client.clone(),
+							create_inherent_data_providers: &create_inherent_data_providers,
+						})
+						.await;
+						let block = block_receiver.await??;
+						// keep track of the progress even if sealing one of the next blocks fails.
+						tip = block.hash;
+						forks.set_tip(&name, tip);
+						created.push(block);
+					}
+					Ok::<_, Error>(created)
+				}
+				.await;
+				rpc::send_result(&mut sender, result)
+			},
+			EngineCommand::SwitchToFork { name, mut sender } => {
+				let result = forks::enabled(&mut forks).and_then(|forks| forks.switch_to(&name));
+				if let Ok(tip) = &result {
+					log::info!(
+						target: LOG_TARGET,
+						"🔀 Switched best block to fork {:?}: {}",
+						name,
+						tip
+					);
+				}
+				rpc::send_result(&mut sender, result)
+			},
+			EngineCommand::RemoveFork { name, mut sender } => {
+				let result = forks::enabled(&mut forks).and_then(|forks| forks.remove(&name));
+				rpc::send_result(&mut sender, result)
+			},
 		}
 	}
 }
@@ -346,6 +466,7 @@ pub async fn run_delayed_finalize<B, CB, C, S>(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::consensus::{babe::BabeConsensusDataProvider, timestamp::SlotTimestampProvider};
 	use sc_basic_authorship::ProposerFactory;
 	use sc_consensus::ImportedAux;
 	use sc_transaction_pool::{BasicPool, FullChainApi, Options, RevalidationType};
@@ -353,7 +474,8 @@ mod tests {
 	use sp_inherents::InherentData;
 	use sp_runtime::generic::{Digest, DigestItem};
 	use substrate_test_runtime_client::{
-		AccountKeyring::*, DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
+		runtime::Block, AccountKeyring::*, BlockOrigin, ClientBlockImportExt,
+		DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
 	};
 	use substrate_test_runtime_transaction_pool::{uxt, TestApi};
 
@@ -760,6 +882,356 @@ mod tests {
 		assert!(client.header(imported.hash).unwrap().is_some())
 	}
 
+	#[tokio::test]
+	async fn manual_seal_named_forks() {
+		let builder = TestClientBuilder::new();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool_api = Arc::new(FullChainApi::new(client.clone(), None, &spawner.clone()));
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			pool_api,
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+		let future = run_manual_seal_with_forks(ManualSealParams {
+			block_import: client.clone(),
+			env,
+			client: client.clone(),
+			pool: pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: None,
+			create_inherent_data_providers: |_, _| async { Ok(()) },
+		});
+		std::thread::spawn(|| {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			// spawn the background authorship task
+			rt.block_on(future);
+		});
+
+		let create_fork = |name: &str| {
+			let (tx, rx) = futures::channel::oneshot::channel();
+			(
+				EngineCommand::CreateFork {
+					name: name.into(),
+					from: genesis_hash,
+					sender: Some(tx),
+				},
+				rx,
+			)
+		};
+		let seal_fork = |name: &str, count| {
+			let (tx, rx) = futures::channel::oneshot::channel();
+			let command = EngineCommand::SealOnFork {
+				name: name.into(),
+				count,
+				create_empty: true,
+				finalize: false,
+				sender: Some(tx),
+			};
+			(command, rx)
+		};
+		let switch_fork = |name: &str| {
+			let (tx, rx) = futures::channel::oneshot::channel();
+			(EngineCommand::SwitchToFork { name: name.into(), sender: Some(tx) }, rx)
+		};
+
+		// create two forks from genesis.
+		for name in ["a", "b"] {
+			let (command, rx) = create_fork(name);
+			sink.send(command).await.unwrap();
+			assert_eq!(rx.await.unwrap().unwrap(), genesis_hash);
+		}
+		let (command, rx) = create_fork("a");
+		sink.send(command).await.unwrap();
+		assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::ForkAlreadyExists(_)));
+
+		// the longest fork is the best one.
+		let (command, rx) = seal_fork("a", 3);
+		sink.send(command).await.unwrap();
+		let fork_a = rx.await.unwrap().unwrap();
+		assert_eq!(fork_a.len(), 3);
+		assert_eq!(client.info().best_hash, fork_a[2].hash);
+
+		let (command, rx) = seal_fork("b", 2);
+		sink.send(command).await.unwrap();
+		let fork_b = rx.await.unwrap().unwrap();
+		assert_eq!(fork_b.len(), 2);
+		// empty blocks sealed on different forks differ.
+		assert_ne!(fork_a[0].hash, fork_b[0].hash);
+		assert_eq!(client.header(fork_b[1].hash).unwrap().unwrap().number, 2);
+		assert_eq!(client.info().best_hash, fork_a[2].hash);
+
+		// switch the best block to the shorter fork.
+		let mut import_notifications = client.import_notification_stream();
+		let (command, rx) = switch_fork("b");
+		sink.send(command).await.unwrap();
+		assert_eq!(rx.await.unwrap().unwrap(), fork_b[1].hash);
+		assert_eq!(client.info().best_hash, fork_b[1].hash);
+
+		// subscribers are notified of the new best block and of the retracted blocks.
+		let notification = import_notifications.next().await.unwrap();
+		assert_eq!(notification.hash, fork_b[1].hash);
+		assert!(notification.is_new_best);
+		let tree_route = notification.tree_route.as_ref().unwrap();
+		let hashes = |blocks: &[sp_blockchain::HashAndNumber<Block>]| {
+			blocks.iter().map(|block| block.hash).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			hashes(tree_route.retracted()),
+			vec![fork_a[2].hash, fork_a[1].hash, fork_a[0].hash],
+		);
+		assert_eq!(hashes(tree_route.enacted()), vec![fork_b[0].hash]);
+
+		// switching to the current best block is a no-op.
+		let (command, rx) = switch_fork("b");
+		sink.send(command).await.unwrap();
+		assert_eq!(rx.await.unwrap().unwrap(), fork_b[1].hash);
+
+		// new blocks are built on top of the new best block.
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::SealNewBlock {
+			parent_hash: None,
+			sender: Some(tx),
+			create_empty: true,
+			finalize: false,
+		})
+		.await
+		.unwrap();
+		let created_block = rx.await.unwrap().unwrap();
+		let header = client.header(created_block.hash).unwrap().unwrap();
+		assert_eq!(header.parent_hash, fork_b[1].hash);
+
+		let (command, rx) = switch_fork("c");
+		sink.send(command).await.unwrap();
+		assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::ForkNotFound(_)));
+
+		// removing a fork returns its tip and forgets about it.
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::RemoveFork { name: "a".into(), sender: Some(tx) })
+			.await
+			.unwrap();
+		assert_eq!(rx.await.unwrap().unwrap(), fork_a[2].hash);
+		let (command, rx) = seal_fork("a", 1);
+		sink.send(command).await.unwrap();
+		assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::ForkNotFound(_)));
+		// the blocks sealed on the removed fork are kept.
+		assert!(client.header(fork_a[2].hash).unwrap().is_some());
+
+		// the name can be reused once the fork is removed.
+		let (command, rx) = create_fork("a");
+		sink.send(command).await.unwrap();
+		assert_eq!(rx.await.unwrap().unwrap(), genesis_hash);
+	}
+
+	#[tokio::test]
+	async fn manual_seal_forks_disabled() {
+		let builder = TestClientBuilder::new();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			api(),
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+		let future = run_manual_seal(ManualSealParams {
+			block_import: client.clone(),
+			env,
+			client: client.clone(),
+			pool: pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: None,
+			create_inherent_data_providers: |_, _| async { Ok(()) },
+		});
+		std::thread::spawn(|| {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			rt.block_on(future);
+		});
+
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::CreateFork {
+			name: "a".into(),
+			from: genesis_hash,
+			sender: Some(tx),
+		})
+		.await
+		.unwrap();
+		assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::ForksDisabled));
+
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::SwitchToFork { name: "a".into(), sender: Some(tx) })
+			.await
+			.unwrap();
+		assert_matches::assert_matches!(rx.await.unwrap(), Err(Error::ForksDisabled));
+
+		// the regular commands keep working.
+		let (tx, rx) = futures::channel::oneshot::channel();
+		sink.send(EngineCommand::SealNewBlock {
+			parent_hash: None,
+			sender: Some(tx),
+			create_empty: true,
+			finalize: false,
+		})
+		.await
+		.unwrap();
+		let created_block = rx.await.unwrap().unwrap();
+		assert_eq!(client.header(created_block.hash).unwrap().unwrap().number, 1);
+	}
+
+	#[tokio::test]
+	async fn manual_seal_babe_forks_are_deterministic() {
+		let builder = TestClientBuilder::new();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let genesis_hash = client.info().genesis_hash;
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(),
+			true.into(),
+			api(),
+			None,
+			RevalidationType::Full,
+			spawner.clone(),
+			0,
+			genesis_hash,
+			genesis_hash,
+		));
+		let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None, None);
+
+		let config = sc_consensus_babe::configuration(&*client).unwrap();
+		let (block_import, babe_link) =
+			sc_consensus_babe::block_import(config, client.clone(), client.clone()).unwrap();
+		let consensus_data_provider = BabeConsensusDataProvider::new(
+			client.clone(),
+			Arc::new(sp_keystore::testing::MemoryKeystore::new()),
+			babe_link.epoch_changes().clone(),
+			vec![(Alice.public().into(), 1)],
+		)
+		.unwrap()
+		.with_deterministic_digests();
+
+		let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+		let cidp_client = client.clone();
+		let future = run_manual_seal_with_forks(ManualSealParams {
+			block_import,
+			env,
+			client: client.clone(),
+			pool: pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: Some(Box::new(consensus_data_provider)),
+			create_inherent_data_providers: move |parent, _| {
+				let client = cidp_client.clone();
+				async move {
+					let timestamp = SlotTimestampProvider::new_babe_at(client, parent, 1_000_000)?;
+					let slot =
+						sp_consensus_babe::inherents::InherentDataProvider::new(timestamp.slot());
+					Ok::<_, Box<dyn std::error::Error + Send + Sync>>((slot, timestamp))
+				}
+			},
+		});
+		std::thread::spawn(|| {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			rt.block_on(future);
+		});
+
+		let mut forks = Vec::new();
+		for name in ["a", "b"] {
+			let (tx, rx) = futures::channel::oneshot::channel();
+			sink.send(EngineCommand::CreateFork {
+				name: name.into(),
+				from: genesis_hash,
+				sender: Some(tx),
+			})
+			.await
+			.unwrap();
+			rx.await.unwrap().unwrap();
+
+			let (tx, rx) = futures::channel::oneshot::channel();
+			sink.send(EngineCommand::SealOnFork {
+				name: name.into(),
+				count: 2,
+				create_empty: true,
+				finalize: false,
+				sender: Some(tx),
+			})
+			.await
+			.unwrap();
+			forks.push(rx.await.unwrap().unwrap());
+		}
+
+		let pre_digests = |blocks: &[CreatedBlock<_>]| {
+			blocks
+				.iter()
+				.map(|block| {
+					let header = client.header(block.hash).unwrap().unwrap();
+					sc_consensus_babe::find_pre_digest::<Block>(&header).unwrap()
+				})
+				.collect::<Vec<_>>()
+		};
+		let (fork_a, fork_b) = (pre_digests(&forks[0]), pre_digests(&forks[1]));
+		// both forks claim the same slots, following the genesis timestamp.
+		assert_eq!(fork_a.iter().map(|p| *p.slot()).collect::<Vec<_>>(), vec![1000, 1001]);
+		assert_eq!(fork_a, fork_b);
+		// the blocks still differ through the fork digest.
+		assert_ne!(forks[0][1].hash, forks[1][1].hash);
+	}
+
+	#[test]
+	fn slot_timestamp_provider_at_parent() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let genesis_hash = client.info().genesis_hash;
+
+		// on top of genesis, the given timestamp is used.
+		let timestamp =
+			SlotTimestampProvider::new_aura_at(client.clone(), genesis_hash, 42_000).unwrap();
+		assert_eq!(*timestamp.timestamp(), 42_000);
+		assert_eq!(*timestamp.slot(), 42);
+
+		// otherwise the time of the slot following the parent one is used.
+		let digest = Digest {
+			logs: vec![<DigestItem as sc_consensus_aura::CompatibleDigestItem<
+				sp_consensus_aura::sr25519::AuthoritySignature,
+			>>::aura_pre_digest(5.into())],
+		};
+		let block = sc_block_builder::BlockBuilderBuilder::new(&*client)
+			.on_parent_block(genesis_hash)
+			.with_parent_block_number(0)
+			.with_inherent_digests(digest)
+			.build()
+			.unwrap()
+			.build()
+			.unwrap()
+			.block;
+		let hash = block.hash();
+		futures::executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+
+		let timestamp = SlotTimestampProvider::new_aura_at(client.clone(), hash, 42_000).unwrap();
+		assert_eq!(*timestamp.timestamp(), 6_000);
+		assert_eq!(*timestamp.slot(), 6);
+	}
+
 	#[tokio::test]
 	async fn manual_seal_post_hash() {
 		let builder = TestClientBuilder::new();
@@ -62,6 +62,47 @@ pub enum EngineCommand<Hash> {
 		/// finalization justification
 		justification: Option<EncodedJustification>,
 	},
+	/// Tells the engine to register a new named fork starting at the given block
+	CreateFork {
+		/// name of the fork
+		name: String,
+		/// hash of the block the fork starts from
+		from: Hash,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<Hash>,
+	},
+	/// Tells the engine to seal a number of blocks on top of a named fork
+	///
+	/// The tip of the fork is moved to the last sealed block.
+	SealOnFork {
+		/// name of the fork
+		name: String,
+		/// number of blocks to seal
+		count: u32,
+		/// if true, empty blocks(without extrinsics) will be created.
+		/// otherwise, will return Error::EmptyTransactionPool.
+		create_empty: bool,
+		/// instantly finalize the sealed blocks?
+		finalize: bool,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<Vec<CreatedBlock<Hash>>>,
+	},
+	/// Tells the engine to make the tip of a named fork the best block
+	SwitchToFork {
+		/// name of the fork
+		name: String,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<Hash>,
+	},
+	/// Tells the engine to stop tracking a named fork
+	///
+	/// The blocks sealed on the fork are kept, the fork name can be reused.
+	RemoveFork {
+		/// name of the fork
+		name: String,
+		/// sender to report errors/success to the rpc.
+		sender: Sender<Hash>,
+	},
 }
 
 /// RPC trait that provides methods for interacting with the manual-seal authorship task over rpc.
@@ -83,6 +124,28 @@ pub trait ManualSealApi<Hash> {
 		hash: Hash,
 		justification: Option<EncodedJustification>,
 	) -> RpcResult<bool>;
+
+	/// Instructs the manual-seal authorship task to register a named fork starting at `from`
+	#[method(name = "engine_createFork")]
+	async fn create_fork(&self, name: String, from: Hash) -> RpcResult<Hash>;
+
+	/// Instructs the manual-seal authorship task to seal `count` blocks on top of a named fork
+	#[method(name = "engine_sealFork")]
+	async fn seal_fork(
+		&self,
+		name: String,
+		count: u32,
+		create_empty: bool,
+		finalize: bool,
+	) -> RpcResult<Vec<CreatedBlock<Hash>>>;
+
+	/// Instructs the manual-seal authorship task to make the tip of a named fork the best block
+	#[method(name = "engine_switchFork")]
+	async fn switch_fork(&self, name: String) -> RpcResult<Hash>;
+
+	/// Instructs the manual-seal authorship task to forget a named fork, returning its tip
+	#[method(name = "engine_removeFork")]
+	async fn remove_fork(&self, name: String) -> RpcResult<Hash>;
 }
 
 /// A struct that implements the [`ManualSealApiServer`].
@@ -146,6 +209,55 @@ impl<Hash: Send + 'static> ManualSealApiServer<Hash> for ManualSeal<Hash> {
 		sink.send(command).await?;
 		receiver.await.map(|_| true).map_err(|e| JsonRpseeError::to_call_error(e))
 	}
+
+	async fn create_fork(&self, name: String, from: Hash) -> RpcResult<Hash> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		let command = EngineCommand::CreateFork { name, from, sender: Some(sender) };
+		sink.send(command).await?;
+		flatten_result(receiver.await)
+	}
+
+	async fn seal_fork(
+		&self,
+		name: String,
+		count: u32,
+		create_empty: bool,
+		finalize: bool,
+	) -> RpcResult<Vec<CreatedBlock<Hash>>> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		let command =
+			EngineCommand::SealOnFork { name, count, create_empty, finalize, sender: Some(sender) };
+		sink.send(command).await?;
+		flatten_result(receiver.await)
+	}
+
+	async fn switch_fork(&self, name: String) -> RpcResult<Hash> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		let command = EngineCommand::SwitchToFork { name, sender: Some(sender) };
+		sink.send(command).await?;
+		flatten_result(receiver.await)
+	}
+
+	async fn remove_fork(&self, name: String) -> RpcResult<Hash> {
+		let mut sink = self.import_block_channel.clone();
+		let (sender, receiver) = oneshot::channel();
+		let command = EngineCommand::RemoveFork { name, sender: Some(sender) };
+		sink.send(command).await?;
+		flatten_result(receiver.await)
+	}
+}
+
+fn flatten_result<T>(
+	result: std::result::Result<std::result::Result<T, Error>, oneshot::Canceled>,
+) -> RpcResult<T> {
+	match result {
+		Ok(Ok(value)) => Ok(value),
+		Ok(Err(e)) => Err(e.into()),
+		Err(e) => Err(JsonRpseeError::to_call_error(e)),
+	}
 }
 
 /// report any errors or successes encountered by the authorship task back
@@ -0,0 +1,677 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Types and functions related to block authoring and tickets generation.
+
+use crate::{
+	aux_schema, find_next_epoch_digest, find_slot, Epoch, SassafrasConfiguration,
+	SassafrasIntermediate, SassafrasLink, TicketSecret, INTERMEDIATE_KEY, LOG_TARGET,
+};
+
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use codec::Encode;
+use futures::prelude::*;
+use log::{debug, error, info, warn};
+
+use sc_client_api::{backend::AuxStore, BlockchainEvents};
+use sc_consensus::{
+	block_import::{BlockImport, BlockImportParams, StateAction},
+	JustificationSyncLink,
+};
+use sc_consensus_epochs::{
+	descendent_query, Epoch as EpochT, EpochIdentifier, EpochIdentifierPosition,
+	SharedEpochChanges, ViableEpochDescriptor,
+};
+use sc_consensus_slots::{
+	BackoffAuthoringBlocksStrategy, InherentDataProviderExt, SimpleSlotWorkerToSlotWorker,
+	SlotInfo, SlotProportion, StorageChanges,
+};
+use sc_telemetry::TelemetryHandle;
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_application_crypto::{AppCrypto, ByteArray};
+use sp_blockchain::{Error as ClientError, HeaderBackend, HeaderMetadata};
+use sp_consensus::{
+	BlockOrigin, Environment, Error as ConsensusError, Proposer, SelectChain, SyncOracle,
+};
+use sp_consensus_sassafras::{
+	digests::SlotClaim, ticket_id_threshold, vrf, AuthorityId, AuthorityIndex, AuthoritySignature,
+	SassafrasApi, Slot, TicketBody, TicketClaim, TicketEnvelope, TicketId,
+};
+use sp_core::{crypto::Wraps, ed25519, hashing::blake2_256, Pair};
+use sp_inherents::CreateInherentDataProviders;
+use sp_keystore::KeystorePtr;
+use sp_runtime::{
+	traits::{Block as BlockT, Header, NumberFor, One},
+	DigestItem,
+};
+
+/// Get secondary authority index for the given epoch and slot.
+///
+/// The authority is picked deterministically from the epoch randomness and the slot value.
+/// Returns `None` if the epoch has no authorities.
+pub(crate) fn secondary_authority_index(slot: Slot, epoch: &Epoch) -> Option<AuthorityIndex> {
+	if epoch.authorities.is_empty() {
+		return None
+	}
+	let hash = (epoch.randomness, slot).using_encoded(blake2_256);
+	let rand = u64::from_le_bytes(hash[..8].try_into().expect("hash is 32 bytes long; qed"));
+	Some((rand % epoch.authorities.len() as u64) as AuthorityIndex)
+}
+
+/// Try to claim an epoch slot.
+///
+/// If the slot has an associated ticket (`maybe_ticket`) then the slot can be claimed
+/// only if the ticket was generated by the local node, otherwise the slot falls back to
+/// the deterministic secondary assignment.
+///
+/// On success returns the slot claim and the public key of the claiming authority.
+pub(crate) fn claim_slot(
+	slot: Slot,
+	epoch: &Epoch,
+	maybe_ticket: Option<(TicketId, TicketBody)>,
+	keystore: &KeystorePtr,
+) -> Option<(SlotClaim, AuthorityId)> {
+	if epoch.authorities.is_empty() {
+		return None
+	}
+
+	let mut epoch_index = epoch.index;
+	if epoch.end_slot() <= slot {
+		// Slot doesn't strictly belong to the epoch, create a clone with fixed values.
+		epoch_index = epoch.clone_for_slot(slot).index;
+	}
+
+	let (authority_idx, ticket_secret) = match maybe_ticket {
+		Some((ticket_id, ticket_body)) => {
+			debug!(target: LOG_TARGET, "[TRY PRIMARY (slot {slot}, tkt = {ticket_id:032x})]");
+			let (authority_idx, ticket_secret) = epoch.tickets_aux.get(&ticket_id)?.clone();
+			debug!(
+				target: LOG_TARGET,
+				"Ticket = [ticket: {ticket_id:032x}, auth: {authority_idx}, attempt: {}]",
+				ticket_body.attempt_idx
+			);
+			(authority_idx, Some(ticket_secret))
+		},
+		None => {
+			debug!(target: LOG_TARGET, "[TRY SECONDARY (slot {slot})]");
+			(secondary_authority_index(slot, epoch)?, None)
+		},
+	};
+
+	let authority_id = epoch.authorities.get(authority_idx as usize)?;
+
+	let data = vrf::slot_claim_sign_data(&epoch.randomness, slot, epoch_index);
+	let vrf_signature = keystore
+		.bandersnatch_vrf_sign(AuthorityId::ID, authority_id.as_ref(), &data)
+		.ok()
+		.flatten()?;
+
+	let ticket_claim = ticket_secret.map(|secret| {
+		let erased_pair = ed25519::Pair::from_seed(&secret.erased_secret);
+		let erased_signature = erased_pair.sign(&data.challenge::<32>());
+		TicketClaim { erased_signature }
+	});
+
+	let claim = SlotClaim { authority_idx, slot, ticket_claim, vrf_signature };
+	Some((claim, authority_id.clone()))
+}
+
+/// Generate the tickets for the given epoch.
+///
+/// Tickets additional information is stored within the `Epoch` structure.
+/// The additional information will be used later during the epoch to claim slots.
+pub(crate) fn generate_epoch_tickets(
+	epoch: &mut Epoch,
+	keystore: &KeystorePtr,
+	ring_ctx: &vrf::RingContext,
+) -> Vec<TicketEnvelope> {
+	let mut tickets = Vec::new();
+	let mut tickets_aux = Vec::new();
+
+	let threshold = ticket_id_threshold(
+		epoch.config.redundancy_factor,
+		epoch.length,
+		epoch.config.attempts_number,
+		epoch.authorities.len() as u32,
+	);
+	debug!(target: LOG_TARGET, "Tickets threshold: {:032x}", threshold);
+
+	let authorities = epoch.authorities.iter().map(|a| *a.as_inner_ref()).collect::<Vec<_>>();
+
+	for (authority_idx, authority_id) in epoch.authorities.iter().enumerate() {
+		if !keystore.has_keys(&[(authority_id.to_raw_vec(), AuthorityId::ID)]) {
+			continue
+		}
+
+		debug!(target: LOG_TARGET, ">>> Generating new ring prover key...");
+		let Some(prover) = ring_ctx.prover(&authorities, authority_idx) else {
+			warn!(target: LOG_TARGET, "Unable to construct ring prover for {:?}", authority_id);
+			continue
+		};
+		debug!(target: LOG_TARGET, ">>> ...done");
+
+		let make_ticket = |attempt_idx| {
+			let ticket_id_input = vrf::ticket_id_input(&epoch.randomness, attempt_idx, epoch.index);
+			let ticket_id_pre_output = keystore
+				.bandersnatch_vrf_pre_output(
+					AuthorityId::ID,
+					authority_id.as_ref(),
+					&ticket_id_input,
+				)
+				.ok()??;
+
+			let ticket_id = vrf::make_ticket_id(&ticket_id_input, &ticket_id_pre_output);
+			if ticket_id >= threshold {
+				return None
+			}
+
+			// Erased key is randomly generated and never disclosed until the ticket is claimed.
+			let (erased_pair, erased_secret) = ed25519::Pair::generate();
+			let erased_public = erased_pair.public();
+
+			// Revealed key is derived from the VRF output and can be recomputed on demand.
+			let revealed_input =
+				vrf::revealed_key_input(&epoch.randomness, attempt_idx, epoch.index);
+			let revealed_pre_output = keystore
+				.bandersnatch_vrf_pre_output(
+					AuthorityId::ID,
+					authority_id.as_ref(),
+					&revealed_input,
+				)
+				.ok()??;
+			let revealed_seed = vrf::make_revealed_key_seed(&revealed_input, &revealed_pre_output);
+			let revealed_public = ed25519::Pair::from_seed(&revealed_seed).public();
+
+			let body = TicketBody { attempt_idx, erased_public, revealed_public };
+
+			debug!(target: LOG_TARGET, ">>> Creating ring proof for attempt {}", attempt_idx);
+			let sign_data = vrf::ticket_body_sign_data(&body, ticket_id_input);
+			let signature = keystore
+				.bandersnatch_ring_vrf_sign(
+					AuthorityId::ID,
+					authority_id.as_ref(),
+					&sign_data,
+					&prover,
+				)
+				.ok()??;
+			debug!(target: LOG_TARGET, ">>> ...done");
+
+			let ticket_envelope = TicketEnvelope { body, signature };
+			let ticket_secret = TicketSecret { attempt_idx, erased_secret };
+			Some((ticket_id, ticket_envelope, ticket_secret))
+		};
+
+		for attempt in 0..epoch.config.attempts_number {
+			if let Some((ticket_id, ticket_envelope, ticket_secret)) = make_ticket(attempt) {
+				debug!(target: LOG_TARGET, "    → {ticket_id:032x}");
+				tickets.push(ticket_envelope);
+				tickets_aux.push((ticket_id, (authority_idx as AuthorityIndex, ticket_secret)));
+			}
+		}
+	}
+
+	epoch.tickets_aux.extend(tickets_aux);
+	tickets
+}
+
+struct SlotWorker<B: BlockT, C, E, I, SO, L, BS> {
+	client: Arc<C>,
+	block_import: I,
+	env: E,
+	sync_oracle: SO,
+	justification_sync_link: L,
+	force_authoring: bool,
+	backoff_authoring_blocks: Option<BS>,
+	keystore: KeystorePtr,
+	epoch_changes: SharedEpochChanges<B, Epoch>,
+	config: SassafrasConfiguration,
+	block_proposal_slot_portion: SlotProportion,
+	max_block_proposal_slot_portion: Option<SlotProportion>,
+	telemetry: Option<TelemetryHandle>,
+}
+
+#[async_trait::async_trait]
+impl<B, C, E, I, ER, SO, L, BS> sc_consensus_slots::SimpleSlotWorker<B>
+	for SlotWorker<B, C, E, I, SO, L, BS>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + HeaderMetadata<B, Error = ClientError>,
+	C::Api: SassafrasApi<B>,
+	E: Environment<B, Error = ER> + Send + Sync,
+	E::Proposer: Proposer<B, Error = ER>,
+	I: BlockImport<B> + Send + Sync + 'static,
+	SO: SyncOracle + Send + Clone + Sync,
+	L: JustificationSyncLink<B>,
+	BS: BackoffAuthoringBlocksStrategy<NumberFor<B>> + Send + Sync,
+	ER: std::error::Error + Send + From<ConsensusError> + From<I::Error> + 'static,
+{
+	type Claim = (SlotClaim, AuthorityId);
+	type SyncOracle = SO;
+	type JustificationSyncLink = L;
+	type CreateProposer =
+		Pin<Box<dyn Future<Output = Result<E::Proposer, ConsensusError>> + Send + 'static>>;
+	type Proposer = E::Proposer;
+	type BlockImport = I;
+	type AuxData = ViableEpochDescriptor<B::Hash, NumberFor<B>, Epoch>;
+
+	fn logging_target(&self) -> &'static str {
+		LOG_TARGET
+	}
+
+	fn block_import(&mut self) -> &mut Self::BlockImport {
+		&mut self.block_import
+	}
+
+	fn aux_data(&self, parent: &B::Header, slot: Slot) -> Result<Self::AuxData, ConsensusError> {
+		self.epoch_changes
+			.shared_data()
+			.epoch_descriptor_for_child_of(
+				descendent_query(&*self.client),
+				&parent.hash(),
+				*parent.number(),
+				slot,
+			)
+			.map_err(|e| ConsensusError::ChainLookup(e.to_string()))?
+			.ok_or(ConsensusError::InvalidAuthoritiesSet)
+	}
+
+	fn authorities_len(&self, epoch_descriptor: &Self::AuxData) -> Option<usize> {
+		self.epoch_changes
+			.shared_data()
+			.viable_epoch(epoch_descriptor, |slot| Epoch::genesis(&self.config, slot))
+			.map(|epoch| epoch.as_ref().authorities.len())
+	}
+
+	async fn claim_slot(
+		&mut self,
+		parent_header: &B::Header,
+		slot: Slot,
+		epoch_descriptor: &ViableEpochDescriptor<B::Hash, NumberFor<B>, Epoch>,
+	) -> Option<Self::Claim> {
+		// Get the next slot ticket from the runtime.
+		let maybe_ticket =
+			self.client.runtime_api().slot_ticket(parent_header.hash(), slot).ok()?;
+
+		let claim = claim_slot(
+			slot,
+			self.epoch_changes
+				.shared_data()
+				.viable_epoch(epoch_descriptor, |slot| Epoch::genesis(&self.config, slot))?
+				.as_ref(),
+			maybe_ticket,
+			&self.keystore,
+		);
+
+		if claim.is_some() {
+			debug!(target: LOG_TARGET, "Claimed slot {}", slot);
+		}
+
+		claim
+	}
+
+	fn pre_digest_data(&self, _slot: Slot, (claim, _): &Self::Claim) -> Vec<DigestItem> {
+		vec![DigestItem::from(claim)]
+	}
+
+	async fn block_import_params(
+		&self,
+		header: B::Header,
+		header_hash: &B::Hash,
+		body: Vec<B::Extrinsic>,
+		storage_changes: StorageChanges<B>,
+		(_, public): Self::Claim,
+		epoch_descriptor: Self::AuxData,
+	) -> Result<BlockImportParams<B>, ConsensusError> {
+		let signature = self
+			.keystore
+			.bandersnatch_sign(AuthorityId::ID, public.as_ref(), header_hash.as_ref())
+			.map_err(|e| ConsensusError::CannotSign(format!("{}. Key {:?}", e, public)))?
+			.map(AuthoritySignature::from)
+			.ok_or_else(|| {
+				ConsensusError::CannotSign(format!(
+					"Could not find key in keystore. Key {:?}",
+					public
+				))
+			})?;
+
+		let mut block = BlockImportParams::new(BlockOrigin::Own, header);
+		block.post_digests.push(DigestItem::from(&signature));
+		block.body = Some(body);
+		block.state_action =
+			StateAction::ApplyChanges(sc_consensus::StorageChanges::Changes(storage_changes));
+		block
+			.insert_intermediate(INTERMEDIATE_KEY, SassafrasIntermediate::<B> { epoch_descriptor });
+
+		Ok(block)
+	}
+
+	fn force_authoring(&self) -> bool {
+		self.force_authoring
+	}
+
+	fn should_backoff(&self, slot: Slot, chain_head: &B::Header) -> bool {
+		if let Some(ref strategy) = self.backoff_authoring_blocks {
+			if let Ok(chain_head_slot) = find_slot::<B>(chain_head) {
+				return strategy.should_backoff(
+					*chain_head.number(),
+					chain_head_slot,
+					self.client.info().finalized_number,
+					slot,
+					self.logging_target(),
+				)
+			}
+		}
+		false
+	}
+
+	fn sync_oracle(&mut self) -> &mut Self::SyncOracle {
+		&mut self.sync_oracle
+	}
+
+	fn justification_sync_link(&mut self) -> &mut Self::JustificationSyncLink {
+		&mut self.justification_sync_link
+	}
+
+	fn proposer(&mut self, block: &B::Header) -> Self::CreateProposer {
+		Box::pin(self.env.init(block).map_err(|e| ConsensusError::ClientImport(e.to_string())))
+	}
+
+	fn telemetry(&self) -> Option<TelemetryHandle> {
+		self.telemetry.clone()
+	}
+
+	fn proposing_remaining_duration(&self, slot_info: &SlotInfo<B>) -> Duration {
+		let parent_slot = find_slot::<B>(&slot_info.chain_head).ok();
+
+		sc_consensus_slots::proposing_remaining_duration(
+			parent_slot,
+			slot_info,
+			&self.block_proposal_slot_portion,
+			self.max_block_proposal_slot_portion.as_ref(),
+			sc_consensus_slots::SlotLenienceType::Exponential,
+			self.logging_target(),
+		)
+	}
+}
+
+/// Authoring tickets generation worker.
+///
+/// Listens on the client's import notification stream for blocks which contain new epoch
+/// information. When a new epoch is announced, tickets for that epoch are generated, the
+/// secrets are saved in the epoch changes tree and the tickets are submitted on-chain via
+/// an unsigned extrinsic.
+async fn start_tickets_worker<B, C, SC>(
+	client: Arc<C>,
+	keystore: KeystorePtr,
+	epoch_changes: SharedEpochChanges<B, Epoch>,
+	select_chain: SC,
+	offchain_tx_pool_factory: OffchainTransactionPoolFactory<B>,
+) where
+	B: BlockT,
+	C: BlockchainEvents<B> + ProvideRuntimeApi<B> + AuxStore,
+	C::Api: SassafrasApi<B>,
+	SC: SelectChain<B> + 'static,
+{
+	let mut notifications = client.import_notification_stream();
+
+	while let Some(notification) = notifications.next().await {
+		let epoch_desc = match find_next_epoch_digest::<B>(&notification.header) {
+			Ok(Some(epoch_desc)) => epoch_desc,
+			Err(err) => {
+				warn!(target: LOG_TARGET, "Error fetching next epoch digest: {}", err);
+				continue
+			},
+			_ => continue,
+		};
+
+		debug!(target: LOG_TARGET, "Loading epoch announced at block {}", notification.hash);
+
+		if !epoch_desc
+			.authorities
+			.iter()
+			.any(|id| keystore.has_keys(&[(id.to_raw_vec(), AuthorityId::ID)]))
+		{
+			debug!(target: LOG_TARGET, "Not an authority for the announced epoch");
+			continue
+		}
+
+		let number = *notification.header.number();
+		let position = if number == One::one() {
+			EpochIdentifierPosition::Genesis1
+		} else {
+			EpochIdentifierPosition::Regular
+		};
+		let epoch_identifier = EpochIdentifier { position, hash: notification.hash, number };
+
+		let mut epoch = match epoch_changes.shared_data().epoch(&epoch_identifier).cloned() {
+			Some(epoch) => epoch,
+			None => {
+				warn!(
+					target: LOG_TARGET,
+					"Unexpected missing epoch data for {:?}", epoch_identifier
+				);
+				continue
+			},
+		};
+
+		let ring_ctx = match client.runtime_api().ring_context(notification.hash) {
+			Ok(Some(ctx)) => ctx,
+			Ok(None) => {
+				warn!(target: LOG_TARGET, "Ring context not initialized yet");
+				continue
+			},
+			Err(err) => {
+				error!(target: LOG_TARGET, "Unable to read ring context: {}", err);
+				continue
+			},
+		};
+
+		let tickets = generate_epoch_tickets(&mut epoch, &keystore, &ring_ctx);
+		if tickets.is_empty() {
+			continue
+		}
+
+		// Get the best block on which we will publish the tickets.
+		let best_hash = match select_chain.best_chain().await {
+			Ok(header) => header.hash(),
+			Err(err) => {
+				error!(target: LOG_TARGET, "Error fetching best chain block id: {}", err);
+				continue
+			},
+		};
+
+		let mut runtime_api = client.runtime_api();
+
+		// Register the offchain tx pool to be able to use it from the runtime.
+		runtime_api
+			.register_extension(offchain_tx_pool_factory.offchain_transaction_pool(best_hash));
+
+		let tickets_count = tickets.len();
+		let err = match runtime_api.submit_tickets_unsigned_extrinsic(best_hash, tickets) {
+			Err(err) => Some(err.to_string()),
+			Ok(false) => Some("Unknown reason".to_string()),
+			_ => None,
+		};
+		if let Some(err) = err {
+			error!(target: LOG_TARGET, "Unable to submit tickets: {}", err);
+			continue
+		}
+
+		info!(
+			target: LOG_TARGET,
+			"🎫 Submitted {} tickets for epoch {}", tickets_count, epoch.index
+		);
+
+		// Save tickets secrets in the epoch changes tree and persist it.
+		let mut epoch_changes_data = epoch_changes.shared_data();
+		if let Some(target_epoch) = epoch_changes_data.epoch_mut(&epoch_identifier) {
+			target_epoch.tickets_aux = epoch.tickets_aux;
+		}
+		if let Err(err) =
+			aux_schema::write_epoch_changes::<B, _, _>(&epoch_changes_data, |values| {
+				client.insert_aux(values, &[])
+			}) {
+			error!(target: LOG_TARGET, "Unable to persist tickets secrets: {}", err);
+		}
+	}
+}
+
+/// Worker for Sassafras which implements `Future<Output=()>`. This must be polled.
+#[must_use]
+pub struct SassafrasWorker {
+	inner: Pin<Box<dyn Future<Output = ()> + Send + 'static>>,
+}
+
+impl Future for SassafrasWorker {
+	type Output = ();
+
+	fn poll(
+		mut self: Pin<&mut Self>,
+		cx: &mut futures::task::Context,
+	) -> futures::task::Poll<Self::Output> {
+		self.inner.as_mut().poll(cx)
+	}
+}
+
+/// Parameters for Sassafras.
+pub struct SassafrasParams<B: BlockT, C, SC, E, I, SO, L, CIDP, BS> {
+	/// The client to use
+	pub client: Arc<C>,
+	/// The keystore that manages the keys of the node.
+	pub keystore: KeystorePtr,
+	/// The chain selection strategy
+	pub select_chain: SC,
+	/// The environment we are producing blocks for.
+	pub env: E,
+	/// The underlying block-import object to supply our produced blocks to.
+	/// This must be a `SassafrasBlockImport` or a wrapper of it, otherwise
+	/// critical consensus logic will be omitted.
+	pub block_import: I,
+	/// A sync oracle
+	pub sync_oracle: SO,
+	/// Hook into the sync module to control the justification sync process.
+	pub justification_sync_link: L,
+	/// Something that can create the inherent data providers.
+	pub create_inherent_data_providers: CIDP,
+	/// Force authoring of blocks even if we are offline
+	pub force_authoring: bool,
+	/// Strategy and parameters for backing off block production.
+	pub backoff_authoring_blocks: Option<BS>,
+	/// The source of timestamps for relative slots
+	pub sassafras_link: SassafrasLink<B>,
+	/// The proportion of the slot dedicated to proposing.
+	///
+	/// The block proposing will be limited to this proportion of the slot from the starting of the
+	/// slot. However, the proposing can still take longer when there is some lenience factor
+	/// applied, because there were no blocks produced for some slots.
+	pub block_proposal_slot_portion: SlotProportion,
+	/// The maximum proportion of the slot dedicated to proposing with any lenience factor applied
+	/// due to no blocks being produced.
+	pub max_block_proposal_slot_portion: Option<SlotProportion>,
+	/// Handle use to report telemetries.
+	pub telemetry: Option<TelemetryHandle>,
+	/// The offchain transaction pool factory.
+	///
+	/// Will be used when submitting tickets.
+	pub offchain_tx_pool_factory: OffchainTransactionPoolFactory<B>,
+}
+
+/// Start the Sassafras worker.
+///
+/// The returned future drives both the slot worker and the tickets generation worker.
+pub fn start_sassafras<B, C, SC, E, I, SO, CIDP, BS, L, ER>(
+	SassafrasParams {
+		client,
+		keystore,
+		select_chain,
+		env,
+		block_import,
+		sync_oracle,
+		justification_sync_link,
+		create_inherent_data_providers,
+		force_authoring,
+		backoff_authoring_blocks,
+		sassafras_link,
+		block_proposal_slot_portion,
+		max_block_proposal_slot_portion,
+		telemetry,
+		offchain_tx_pool_factory,
+	}: SassafrasParams<B, C, SC, E, I, SO, L, CIDP, BS>,
+) -> Result<SassafrasWorker, ConsensusError>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>
+		+ HeaderBackend<B>
+		+ HeaderMetadata<B, Error = ClientError>
+		+ BlockchainEvents<B>
+		+ AuxStore
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: SassafrasApi<B>,
+	SC: SelectChain<B> + 'static,
+	E: Environment<B, Error = ER> + Send + Sync + 'static,
+	E::Proposer: Proposer<B, Error = ER>,
+	I: BlockImport<B, Error = ConsensusError> + Send + Sync + 'static,
+	SO: SyncOracle + Send + Sync + Clone + 'static,
+	L: JustificationSyncLink<B> + 'static,
+	CIDP: CreateInherentDataProviders<B, ()> + Send + Sync + 'static,
+	CIDP::InherentDataProviders: InherentDataProviderExt + Send,
+	BS: BackoffAuthoringBlocksStrategy<NumberFor<B>> + Send + Sync + 'static,
+	ER: std::error::Error + Send + From<ConsensusError> + From<I::Error> + 'static,
+{
+	info!(target: LOG_TARGET, "🍁 Starting Sassafras Authorship worker");
+
+	let slot_worker = SlotWorker {
+		client: client.clone(),
+		block_import,
+		env,
+		sync_oracle: sync_oracle.clone(),
+		justification_sync_link,
+		force_authoring,
+		backoff_authoring_blocks,
+		keystore: keystore.clone(),
+		epoch_changes: sassafras_link.epoch_changes.clone(),
+		config: sassafras_link.config.clone(),
+		block_proposal_slot_portion,
+		max_block_proposal_slot_portion,
+		telemetry,
+	};
+
+	let slot_worker = sc_consensus_slots::start_slot_worker(
+		sassafras_link.config.slot_duration,
+		select_chain.clone(),
+		SimpleSlotWorkerToSlotWorker(slot_worker),
+		sync_oracle,
+		create_inherent_data_providers,
+	);
+
+	let tickets_worker = start_tickets_worker(
+		client,
+		keystore,
+		sassafras_link.epoch_changes,
+		select_chain,
+		offchain_tx_pool_factory,
+	);
+
+	let inner = future::select(Box::pin(slot_worker), Box::pin(tickets_worker));
+
+	Ok(SassafrasWorker { inner: Box::pin(inner.map(|_| ())) })
+}
@@ -0,0 +1,512 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Types and functions related to block import.
+
+use crate::{
+	aux_schema, claim_weight, find_next_epoch_digest, find_slot, find_slot_claim, import_err,
+	Epoch, Error, SassafrasConfiguration, SassafrasIntermediate, SassafrasLink, INTERMEDIATE_KEY,
+	LOG_TARGET,
+};
+
+use std::{collections::HashSet, sync::Arc};
+
+use log::{debug, log, warn};
+
+use sc_client_api::{backend::AuxStore, AuxDataOperations, FinalityNotification, PreCommitActions};
+use sc_consensus::block_import::{
+	BlockCheckParams, BlockImport, BlockImportParams, ForkChoiceStrategy, ImportResult,
+};
+use sc_consensus_epochs::{descendent_query, Epoch as EpochT, EpochChangesFor, SharedEpochChanges};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::{BlockStatus, HeaderBackend, HeaderMetadata, Result as ClientResult};
+use sp_consensus::{BlockOrigin, Error as ConsensusError};
+use sp_consensus_sassafras::SassafrasApi;
+use sp_runtime::traits::{Block as BlockT, Header, Zero};
+
+/// A block-import handler for Sassafras.
+///
+/// This scans each imported block for epoch change announcements. The announcements are
+/// tracked in a tree (of all forks), and the import logic validates all epoch change
+/// transitions, i.e. whether a given epoch change is expected or whether it is missing.
+///
+/// The epoch change tree should be pruned as blocks are finalized.
+pub struct SassafrasBlockImport<B: BlockT, C, I> {
+	inner: I,
+	client: Arc<C>,
+	epoch_changes: SharedEpochChanges<B, Epoch>,
+	config: SassafrasConfiguration,
+}
+
+impl<B: BlockT, I: Clone, C> Clone for SassafrasBlockImport<B, C, I> {
+	fn clone(&self) -> Self {
+		SassafrasBlockImport {
+			inner: self.inner.clone(),
+			client: self.client.clone(),
+			epoch_changes: self.epoch_changes.clone(),
+			config: self.config.clone(),
+		}
+	}
+}
+
+impl<B: BlockT, C, I> SassafrasBlockImport<B, C, I> {
+	/// Constructor.
+	pub fn new(
+		inner: I,
+		client: Arc<C>,
+		epoch_changes: SharedEpochChanges<B, Epoch>,
+		config: SassafrasConfiguration,
+	) -> Self {
+		SassafrasBlockImport { inner, client, epoch_changes, config }
+	}
+}
+
+impl<B, C, I> SassafrasBlockImport<B, C, I>
+where
+	B: BlockT,
+	I: BlockImport<B> + Send + Sync,
+	I::Error: Into<ConsensusError>,
+	C: HeaderBackend<B>
+		+ HeaderMetadata<B, Error = sp_blockchain::Error>
+		+ AuxStore
+		+ ProvideRuntimeApi<B>
+		+ Send
+		+ Sync,
+	C::Api: SassafrasApi<B> + ApiExt<B>,
+{
+	/// Import whole state after a warp sync.
+	///
+	/// This function makes multiple transactions to the DB. If one of them fails we may
+	/// end up in an inconsistent state and have to resync.
+	async fn import_state(
+		&mut self,
+		mut block: BlockImportParams<B>,
+	) -> Result<ImportResult, ConsensusError> {
+		let hash = block.post_hash();
+		let parent_hash = *block.header.parent_hash();
+		let number = *block.header.number();
+
+		block.fork_choice = Some(ForkChoiceStrategy::Custom(true));
+
+		// Reset block weight.
+		aux_schema::write_block_weight(hash, 0, |values| {
+			block
+				.auxiliary
+				.extend(values.iter().map(|(k, v)| (k.to_vec(), Some(v.to_vec()))))
+		});
+
+		// First make the client import the state.
+		let aux = match self.inner.import_block(block).await {
+			Ok(ImportResult::Imported(aux)) => aux,
+			Ok(r) =>
+				return Err(ConsensusError::ClientImport(format!(
+					"Unexpected import result: {:?}",
+					r
+				))),
+			Err(e) => return Err(e.into()),
+		};
+
+		// Read epoch info from the imported state.
+		let curr_epoch = self
+			.client
+			.runtime_api()
+			.current_epoch(hash)
+			.map_err(|e| import_err::<B>(Error::RuntimeApi(e)))?;
+		let next_epoch = self
+			.client
+			.runtime_api()
+			.next_epoch(hash)
+			.map_err(|e| import_err::<B>(Error::RuntimeApi(e)))?;
+
+		let mut epoch_changes = self.epoch_changes.shared_data_locked();
+		epoch_changes.reset(parent_hash, hash, number, curr_epoch.into(), next_epoch.into());
+
+		aux_schema::write_epoch_changes::<B, _, _>(&*epoch_changes, |insert| {
+			self.client.insert_aux(insert, [])
+		})
+		.map_err(|e| ConsensusError::ClientImport(e.to_string()))?;
+
+		Ok(ImportResult::Imported(aux))
+	}
+}
+
+#[async_trait::async_trait]
+impl<B, C, I> BlockImport<B> for SassafrasBlockImport<B, C, I>
+where
+	B: BlockT,
+	I: BlockImport<B> + Send + Sync,
+	I::Error: Into<ConsensusError>,
+	C: HeaderBackend<B>
+		+ HeaderMetadata<B, Error = sp_blockchain::Error>
+		+ AuxStore
+		+ ProvideRuntimeApi<B>
+		+ Send
+		+ Sync,
+	C::Api: SassafrasApi<B> + ApiExt<B>,
+{
+	type Error = ConsensusError;
+
+	async fn import_block(
+		&mut self,
+		mut block: BlockImportParams<B>,
+	) -> Result<ImportResult, Self::Error> {
+		let hash = block.post_hash();
+		let number = *block.header.number();
+		let info = self.client.info();
+
+		let block_status = self
+			.client
+			.status(hash)
+			.map_err(|e| ConsensusError::ClientImport(e.to_string()))?;
+
+		// Skip protocol-specific logic if block already in chain or importing blocks during
+		// initial sync, otherwise the check for epoch changes will error because trying to
+		// re-import an epoch change entry or because of missing epoch data in the tree,
+		// respectively.
+		if info.block_gap.map_or(false, |(s, e)| s <= number && number <= e) ||
+			block_status == BlockStatus::InChain
+		{
+			// When re-importing existing block strip away intermediates.
+			// In case of initial sync intermediates should not be present...
+			let _ = block.remove_intermediate::<SassafrasIntermediate<B>>(INTERMEDIATE_KEY);
+			block.fork_choice = Some(ForkChoiceStrategy::Custom(false));
+			return self.inner.import_block(block).await.map_err(Into::into)
+		}
+
+		if block.with_state() {
+			return self.import_state(block).await
+		}
+
+		let claim = find_slot_claim::<B>(&block.header).map_err(import_err)?;
+		let slot = claim.slot;
+
+		let parent_hash = *block.header.parent_hash();
+		let parent_header = self
+			.client
+			.header(parent_hash)
+			.map_err(|e| ConsensusError::ChainLookup(e.to_string()))?
+			.ok_or_else(|| import_err(Error::<B>::ParentUnavailable(parent_hash, hash)))?;
+
+		let parent_slot = find_slot::<B>(&parent_header).map_err(import_err)?;
+
+		// Make sure that slot number is strictly increasing
+		if slot <= parent_slot {
+			return Err(import_err(Error::<B>::SlotMustIncrease(parent_slot, slot)))
+		}
+
+		// If there's a pending epoch we'll save the previous epoch changes here
+		// this way we can revert it if there's any error.
+		let mut old_epoch_changes = None;
+
+		// Use an extra scope to make the compiler happy, because otherwise it complains about the
+		// mutex, even if we dropped it...
+		let mut epoch_changes = {
+			let mut epoch_changes = self.epoch_changes.shared_data_locked();
+
+			// Check if there's any epoch change expected to happen at this slot.
+			// `epoch` is the epoch to verify the block under, and `first_in_epoch` is true
+			// if this is the first block in its chain for that epoch.
+			let parent_weight = if parent_header.number().is_zero() {
+				0
+			} else {
+				aux_schema::load_block_weight(&*self.client, parent_hash)
+					.map_err(|e| ConsensusError::ClientImport(e.to_string()))?
+					.ok_or_else(|| import_err(Error::<B>::ParentBlockNoAssociatedWeight(hash)))?
+			};
+
+			let intermediate =
+				block.remove_intermediate::<SassafrasIntermediate<B>>(INTERMEDIATE_KEY)?;
+			let epoch_descriptor = intermediate.epoch_descriptor;
+			let first_in_epoch = parent_slot < epoch_descriptor.start_slot();
+
+			let total_weight = parent_weight + claim_weight(&claim);
+
+			// Search for this all the time so we can reject unexpected announcements.
+			let next_epoch_digest = find_next_epoch_digest::<B>(&block.header)
+				.map_err(|e| ConsensusError::ClientImport(e.to_string()))?;
+
+			match (first_in_epoch, next_epoch_digest.is_some()) {
+				(true, false) =>
+					return Err(import_err(Error::<B>::ExpectedEpochChange(hash, slot))),
+				(false, true) => return Err(import_err(Error::<B>::UnexpectedEpochChange)),
+				_ => (),
+			}
+
+			if let Some(next_epoch_descriptor) = next_epoch_digest {
+				old_epoch_changes = Some((*epoch_changes).clone());
+
+				let mut viable_epoch = epoch_changes
+					.viable_epoch(&epoch_descriptor, |slot| Epoch::genesis(&self.config, slot))
+					.ok_or_else(|| import_err(Error::<B>::FetchEpoch(parent_hash)))?
+					.into_cloned();
+
+				// Restrict info logging during initial sync to avoid spam
+				let log_level = if block.origin == BlockOrigin::NetworkInitialSync {
+					log::Level::Debug
+				} else {
+					log::Level::Info
+				};
+
+				if viable_epoch.as_ref().end_slot() <= slot {
+					// Some epochs must have been skipped as our current slot fits outside the
+					// current epoch. We will figure out which is the first skipped epoch and we
+					// will partially re-use its data for this "recovery" epoch.
+					// As in BABE, we only update a local copy of the `Epoch` and the original
+					// epoch data (which may be used by other forks) is left untouched.
+					let epoch = viable_epoch.as_mut();
+					let prev_index = epoch.index;
+					*epoch = epoch.clone_for_slot(slot);
+
+					warn!(
+						target: LOG_TARGET,
+						"🍁 Epoch(s) skipped from {} to {}", prev_index, epoch.index,
+					);
+				}
+
+				log!(
+					target: LOG_TARGET,
+					log_level,
+					"🍁 New epoch {} launching at block {} (block slot {} >= start slot {}).",
+					viable_epoch.as_ref().index,
+					hash,
+					slot,
+					viable_epoch.as_ref().start,
+				);
+
+				let next_epoch = viable_epoch.increment(next_epoch_descriptor);
+
+				log!(
+					target: LOG_TARGET,
+					log_level,
+					"🍁 Next epoch starts at slot {}",
+					next_epoch.as_ref().start,
+				);
+
+				// Prune the tree of epochs not part of the finalized chain or
+				// that are not live anymore, and then track the given epoch change
+				// in the tree.
+				// NOTE: it is important that these operations are done in this
+				// order, otherwise if pruning after import the `is_descendent_of`
+				// used by pruning may not know about the block that is being
+				// imported.
+				let prune_and_import = || {
+					prune_finalized(self.client.clone(), &mut epoch_changes)?;
+
+					epoch_changes
+						.import(
+							descendent_query(&*self.client),
+							hash,
+							number,
+							*block.header.parent_hash(),
+							next_epoch,
+						)
+						.map_err(|e| {
+							ConsensusError::ClientImport(format!(
+								"Error importing epoch changes: {}",
+								e
+							))
+						})?;
+
+					Ok(())
+				};
+
+				if let Err(e) = prune_and_import() {
+					debug!(target: LOG_TARGET, "Failed to launch next epoch: {}", e);
+					*epoch_changes =
+						old_epoch_changes.expect("set `Some` above and not taken; qed");
+					return Err(e)
+				}
+
+				aux_schema::write_epoch_changes::<B, _, _>(&*epoch_changes, |insert| {
+					block
+						.auxiliary
+						.extend(insert.iter().map(|(k, v)| (k.to_vec(), Some(v.to_vec()))))
+				});
+			}
+
+			aux_schema::write_block_weight(hash, total_weight, |values| {
+				block
+					.auxiliary
+					.extend(values.iter().map(|(k, v)| (k.to_vec(), Some(v.to_vec()))))
+			});
+
+			// The fork choice rule is that we pick the heaviest chain (i.e. more primary
+			// blocks), if there's a tie we go with the longest chain.
+			block.fork_choice = {
+				let (last_best, last_best_number) = (info.best_hash, info.best_number);
+
+				let last_best_weight = if &last_best == block.header.parent_hash() {
+					// The parent=genesis case is already covered for loading parent weight,
+					// so we don't need to cover again here.
+					parent_weight
+				} else {
+					aux_schema::load_block_weight(&*self.client, last_best)
+						.map_err(|e| ConsensusError::ChainLookup(e.to_string()))?
+						.ok_or_else(|| {
+							ConsensusError::ChainLookup(
+								"No block weight for parent header.".to_string(),
+							)
+						})?
+				};
+
+				let is_new_best = total_weight > last_best_weight ||
+					(total_weight == last_best_weight && number > last_best_number);
+				Some(ForkChoiceStrategy::Custom(is_new_best))
+			};
+
+			// Release the mutex, but it stays locked
+			epoch_changes.release_mutex()
+		};
+
+		let import_result = self.inner.import_block(block).await;
+
+		// Revert to the original epoch changes in case there's an error
+		// importing the block
+		if import_result.is_err() {
+			if let Some(old_epoch_changes) = old_epoch_changes {
+				*epoch_changes.upgrade() = old_epoch_changes;
+			}
+		}
+
+		import_result.map_err(Into::into)
+	}
+
+	async fn check_block(
+		&mut self,
+		block: BlockCheckParams<B>,
+	) -> Result<ImportResult, Self::Error> {
+		self.inner.check_block(block).await.map_err(Into::into)
+	}
+}
+
+/// Gets the best finalized block and its slot, and prunes the given epoch tree.
+fn prune_finalized<B, C>(
+	client: Arc<C>,
+	epoch_changes: &mut EpochChangesFor<B, Epoch>,
+) -> Result<(), ConsensusError>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + HeaderMetadata<B, Error = sp_blockchain::Error>,
+{
+	let info = client.info();
+
+	let finalized_slot = {
+		let finalized_header = client
+			.header(info.finalized_hash)
+			.map_err(|e| ConsensusError::ClientImport(e.to_string()))?
+			.expect(
+				"best finalized hash was given by client; finalized headers must exist in db; qed",
+			);
+
+		find_slot::<B>(&finalized_header)
+			.expect("finalized header must be valid; valid blocks have a slot claim; qed")
+	};
+
+	epoch_changes
+		.prune_finalized(
+			descendent_query(&*client),
+			&info.finalized_hash,
+			info.finalized_number,
+			finalized_slot,
+		)
+		.map_err(|e| ConsensusError::ClientImport(e.to_string()))?;
+
+	Ok(())
+}
+
+// Remove obsolete block's weight data by leveraging finality notifications.
+// This includes data for all finalized blocks (excluding the most recent one)
+// and all stale branches.
+fn aux_storage_cleanup<C: HeaderMetadata<B> + HeaderBackend<B>, B: BlockT>(
+	client: &C,
+	notification: &FinalityNotification<B>,
+) -> AuxDataOperations {
+	let mut hashes = HashSet::new();
+
+	let first = notification.tree_route.first().unwrap_or(&notification.hash);
+	match client.header_metadata(*first) {
+		Ok(meta) => {
+			hashes.insert(meta.parent);
+		},
+		Err(err) => {
+			warn!(target: LOG_TARGET, "Failed to lookup metadata for block `{:?}`: {}", first, err)
+		},
+	}
+
+	// Cleans data for finalized block's ancestors
+	hashes.extend(
+		notification
+			.tree_route
+			.iter()
+			// Ensure we don't prune latest finalized block.
+			// This should not happen, but better be safe than sorry!
+			.filter(|h| **h != notification.hash),
+	);
+
+	// Cleans data for stale forks.
+	let stale_forks = match client.expand_forks(&notification.stale_heads) {
+		Ok(stale_forks) => stale_forks,
+		Err((stale_forks, e)) => {
+			warn!(target: LOG_TARGET, "{:?}", e);
+			stale_forks
+		},
+	};
+	hashes.extend(stale_forks.iter());
+
+	hashes
+		.into_iter()
+		.map(|val| (aux_schema::block_weight_key(val), None))
+		.collect()
+}
+
+/// Produce a Sassafras block-import object to be used later on in the construction of
+/// an import-queue.
+///
+/// Also returns a link object used to correctly instantiate the import queue
+/// and authoring worker.
+pub fn block_import<C, B: BlockT, I>(
+	config: SassafrasConfiguration,
+	inner_block_import: I,
+	client: Arc<C>,
+) -> ClientResult<(SassafrasBlockImport<B, C, I>, SassafrasLink<B>)>
+where
+	C: AuxStore
+		+ HeaderBackend<B>
+		+ HeaderMetadata<B, Error = sp_blockchain::Error>
+		+ PreCommitActions<B>
+		+ 'static,
+{
+	let epoch_changes = aux_schema::load_epoch_changes::<B, _>(&*client)?;
+
+	prune_finalized(client.clone(), &mut epoch_changes.shared_data())?;
+
+	let client_weak = Arc::downgrade(&client);
+	let on_finality = move |notification: &FinalityNotification<B>| {
+		if let Some(client) = client_weak.upgrade() {
+			aux_storage_cleanup(client.as_ref(), notification)
+		} else {
+			Default::default()
+		}
+	};
+	client.register_finality_action(Box::new(on_finality));
+
+	let link = SassafrasLink { epoch_changes: epoch_changes.clone(), config: config.clone() };
+	let block_import = SassafrasBlockImport::new(inner_block_import, client, epoch_changes, config);
+
+	Ok((block_import, link))
+}
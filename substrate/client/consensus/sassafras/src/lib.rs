@@ -0,0 +1,425 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Sassafras (Semi Anonymous Sortition of Staked Assignees For Fixed-time Rhythmic Assignment
+//! of Slots)
+//!
+//! Sassafras is a slot-based block production mechanism which assigns every slot of an
+//! epoch to exactly one authority, without revealing who the author is until the block
+//! is produced.
+//!
+//! During epoch N each authority generates a number of anonymous *tickets* for epoch N+1.
+//! A ticket identifier is the output of a VRF evaluated over the next epoch randomness and
+//! the ticket attempt index, while the ticket itself is signed using a ring-VRF signature.
+//! Ring signatures prove that the ticket was produced by one of the next epoch authorities
+//! without disclosing which one. Tickets whose identifier is below a threshold derived from
+//! the epoch configuration are submitted on-chain via an unsigned extrinsic, and the runtime
+//! assigns the best tickets to the next epoch slots.
+//!
+//! When a slot comes, the authority owning the ticket assigned to that slot claims it by
+//! revealing the ephemeral secret associated to the ticket (the *erased* key), which is
+//! kept locally in the client epoch data. Slots without an associated ticket fall back to a
+//! deterministic secondary assignment picked as:
+//!
+//! `blake2_256(epoch_randomness ++ slot) % authorities_len`.
+//!
+//! Every claim carries a plain VRF signature used by the runtime to accumulate the
+//! on-chain randomness.
+//!
+//! The fork choice rule is weight-based, where weight equals the number of primary
+//! (i.e. ticket-based) blocks in the chain. We pick the heaviest chain and go with the
+//! longest one in case of a tie.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+use std::{
+	collections::BTreeMap,
+	ops::{Deref, DerefMut},
+};
+
+use codec::{Decode, Encode};
+use log::{debug, trace};
+
+use sc_client_api::{backend::AuxStore, UsageProvider};
+use sc_consensus_epochs::{Epoch as EpochT, SharedEpochChanges, ViableEpochDescriptor};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::Result as ClientResult;
+use sp_consensus::Error as ConsensusError;
+use sp_consensus_slots::{Slot, SlotDuration};
+use sp_runtime::{
+	generic::OpaqueDigestItemId,
+	traits::{Block as BlockT, Header, NumberFor, Zero},
+};
+
+pub use sc_consensus_slots::SlotProportion;
+pub use sp_consensus::SyncOracle;
+pub use sp_consensus_sassafras::{
+	digests::{ConsensusLog, NextEpochDescriptor, SlotClaim},
+	vrf, AuthorityId, AuthorityIndex, AuthorityPair, AuthoritySignature, EpochConfiguration,
+	Randomness, SassafrasApi, SassafrasBlockWeight, TicketBody, TicketClaim, TicketEnvelope,
+	TicketId, RANDOMNESS_LENGTH, SASSAFRAS_ENGINE_ID,
+};
+
+pub use authorship::{start_sassafras, SassafrasParams, SassafrasWorker};
+pub use aux_schema::load_block_weight as block_weight;
+pub use block_import::{block_import, SassafrasBlockImport};
+pub use verification::{import_queue, ImportQueueParams, SassafrasVerifier};
+
+mod authorship;
+mod block_import;
+mod verification;
+
+pub mod aux_schema;
+#[cfg(test)]
+mod tests;
+
+const LOG_TARGET: &str = "sassafras 🌳";
+
+/// Secret data associated to one of the tickets generated by the local node.
+///
+/// This information is never disclosed and is required to claim the slot which
+/// gets assigned to the ticket.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct TicketSecret {
+	/// Attempt index of the ticket.
+	pub attempt_idx: u32,
+	/// Seed of the ephemeral key pair erased when the ticket is claimed.
+	pub erased_secret: [u8; 32],
+}
+
+/// Sassafras epoch information augmented with client-only data.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Epoch {
+	/// Epoch information as returned by the runtime.
+	pub inner: sp_consensus_sassafras::Epoch,
+	/// Secret information of the tickets generated by this node for this epoch.
+	///
+	/// Maps the ticket identifier to the index of the authority which created it.
+	pub tickets_aux: BTreeMap<TicketId, (AuthorityIndex, TicketSecret)>,
+}
+
+impl Deref for Epoch {
+	type Target = sp_consensus_sassafras::Epoch;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+impl DerefMut for Epoch {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.inner
+	}
+}
+
+impl From<sp_consensus_sassafras::Epoch> for Epoch {
+	fn from(epoch: sp_consensus_sassafras::Epoch) -> Self {
+		Epoch { inner: epoch, tickets_aux: Default::default() }
+	}
+}
+
+impl EpochT for Epoch {
+	type NextEpochDescriptor = NextEpochDescriptor;
+	type Slot = Slot;
+
+	fn increment(&self, descriptor: NextEpochDescriptor) -> Epoch {
+		sp_consensus_sassafras::Epoch {
+			index: self.index + 1,
+			start: self.start + self.length as u64,
+			length: self.length,
+			randomness: descriptor.randomness,
+			authorities: descriptor.authorities,
+			config: descriptor.config.unwrap_or(self.config),
+		}
+		.into()
+	}
+
+	fn start_slot(&self) -> Slot {
+		self.start
+	}
+
+	fn end_slot(&self) -> Slot {
+		self.start + self.length as u64
+	}
+}
+
+impl Epoch {
+	/// Create the genesis epoch (epoch #0).
+	///
+	/// This is defined to start at the slot of the first block, so that has to be provided.
+	pub fn genesis(config: &SassafrasConfiguration, slot: Slot) -> Epoch {
+		let genesis = &config.genesis_epoch;
+		sp_consensus_sassafras::Epoch {
+			index: 0,
+			start: slot,
+			length: genesis.length,
+			randomness: genesis.randomness,
+			authorities: genesis.authorities.clone(),
+			config: genesis.config,
+		}
+		.into()
+	}
+
+	/// Clone and tweak epoch information to refer to the specified slot.
+	///
+	/// All the information which depends on the slot value is recomputed and assigned
+	/// to the returned epoch instance. Tickets secrets are dropped, since the tickets
+	/// were generated for the original epoch only.
+	///
+	/// The `slot` must be greater than or equal the original epoch start slot,
+	/// if is less this operation is equivalent to a simple clone.
+	pub fn clone_for_slot(&self, slot: Slot) -> Epoch {
+		let skipped_epochs = *slot.saturating_sub(self.start) / self.length as u64;
+		if skipped_epochs == 0 {
+			return self.clone()
+		}
+
+		let index = self.index.checked_add(skipped_epochs).expect(
+			"epoch number is u64; it should be strictly smaller than number of slots; \
+				slots relate in some way to wall clock time; \
+				if u64 is not enough we should crash for safety; qed.",
+		);
+
+		let start = skipped_epochs
+			.checked_mul(self.length as u64)
+			.and_then(|skipped_slots| self.start.checked_add(skipped_slots))
+			.expect(
+				"slot number is u64; it should relate in some way to wall clock time; \
+				 if u64 is not enough we should crash for safety; qed.",
+			);
+
+		sp_consensus_sassafras::Epoch { index, start: Slot::from(start), ..self.inner.clone() }
+			.into()
+	}
+}
+
+/// Errors encountered by the Sassafras routines.
+#[derive(Debug, thiserror::Error)]
+pub enum Error<B: BlockT> {
+	/// Multiple Sassafras pre-runtime digests
+	#[error("Multiple Sassafras pre-runtime digests")]
+	MultipleSlotClaims,
+	/// No Sassafras pre-runtime digest found
+	#[error("No Sassafras pre-runtime digest found")]
+	MissingSlotClaim,
+	/// Multiple Sassafras epoch change digests
+	#[error("Multiple Sassafras epoch change digests")]
+	MultipleEpochChangeDigests,
+	/// Could not fetch epoch
+	#[error("Could not fetch epoch at {0:?}")]
+	FetchEpoch(B::Hash),
+	/// Header rejected: too far in the future
+	#[error("Header {0:?} rejected: too far in the future")]
+	TooFarInFuture(B::Hash),
+	/// Parent unavailable. Cannot import
+	#[error("Parent ({0}) of {1} unavailable. Cannot import")]
+	ParentUnavailable(B::Hash, B::Hash),
+	/// Slot number must increase
+	#[error("Slot number must increase: parent slot: {0}, this slot: {1}")]
+	SlotMustIncrease(Slot, Slot),
+	/// Header has a bad seal
+	#[error("Header {0:?} has a bad seal")]
+	HeaderBadSeal(B::Hash),
+	/// Header is unsealed
+	#[error("Header {0:?} is unsealed")]
+	HeaderUnsealed(B::Hash),
+	/// Slot author not found
+	#[error("Slot author not found")]
+	SlotAuthorNotFound,
+	/// Bad signature
+	#[error("Bad signature on {0:?}")]
+	BadSignature(B::Hash),
+	/// VRF verification failed
+	#[error("VRF verification failed")]
+	VrfVerificationFailed,
+	/// Ticket claim is missing while the slot has an associated ticket.
+	#[error("Missing ticket claim for slot with ticket")]
+	MissingTicketClaim,
+	/// Ticket claim is present while the slot has no associated ticket.
+	#[error("Unexpected ticket claim for slot without ticket")]
+	UnexpectedTicketClaim,
+	/// Ticket claim signature is not valid for the ticket erased key.
+	#[error("Bad ticket claim signature")]
+	BadTicketClaimSignature,
+	/// Invalid author: Expected secondary author
+	#[error("Invalid author: expected secondary author index: {0}, got: {1}")]
+	InvalidSecondaryAuthor(AuthorityIndex, AuthorityIndex),
+	/// Could not fetch parent header
+	#[error("Could not fetch parent header: {0}")]
+	FetchParentHeader(sp_blockchain::Error),
+	/// Expected epoch change to happen.
+	#[error("Expected epoch change to happen at {0:?}, s{1}")]
+	ExpectedEpochChange(B::Hash, Slot),
+	/// Unexpected epoch change
+	#[error("Unexpected epoch change")]
+	UnexpectedEpochChange,
+	/// Parent block has no associated weight
+	#[error("Parent block of {0} has no associated weight")]
+	ParentBlockNoAssociatedWeight(B::Hash),
+	/// Check inherents error
+	#[error("Checking inherents failed: {0}")]
+	CheckInherents(sp_inherents::Error),
+	/// Unhandled check inherents error
+	#[error("Checking inherents unhandled error: {}", String::from_utf8_lossy(.0))]
+	CheckInherentsUnhandled(sp_inherents::InherentIdentifier),
+	/// Create inherents error.
+	#[error("Creating inherents failed: {0}")]
+	CreateInherents(sp_inherents::Error),
+	/// Client error
+	#[error(transparent)]
+	Client(sp_blockchain::Error),
+	/// Runtime Api error.
+	#[error(transparent)]
+	RuntimeApi(sp_api::ApiError),
+	/// Fork tree error
+	#[error(transparent)]
+	ForkTree(Box<fork_tree::Error<sp_blockchain::Error>>),
+}
+
+impl<B: BlockT> From<Error<B>> for String {
+	fn from(error: Error<B>) -> String {
+		error.to_string()
+	}
+}
+
+// Convenience function for error logging
+fn sassafras_err<B: BlockT>(error: Error<B>) -> Error<B> {
+	debug!(target: LOG_TARGET, "{}", error);
+	error
+}
+
+/// Intermediate value passed to block importer from authoring or validation logic.
+pub struct SassafrasIntermediate<B: BlockT> {
+	/// The epoch descriptor.
+	pub epoch_descriptor: ViableEpochDescriptor<B::Hash, NumberFor<B>, Epoch>,
+}
+
+/// Intermediate key for Sassafras engine.
+pub static INTERMEDIATE_KEY: &[u8] = b"sass1";
+
+/// Sassafras protocol configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SassafrasConfiguration {
+	/// The slot duration, this is not provided by the runtime and must be
+	/// known in advance by the node.
+	pub slot_duration: SlotDuration,
+	/// Epoch information used as a template for the genesis epoch.
+	pub genesis_epoch: sp_consensus_sassafras::Epoch,
+}
+
+/// Read configuration from the runtime state.
+///
+/// The genesis epoch template is read at the latest finalized block, or at the
+/// genesis block if no finalized state is available.
+pub fn configuration<B: BlockT, C>(
+	client: &C,
+	slot_duration: SlotDuration,
+) -> ClientResult<SassafrasConfiguration>
+where
+	C: AuxStore + ProvideRuntimeApi<B> + UsageProvider<B>,
+	C::Api: SassafrasApi<B>,
+{
+	let info = client.usage_info().chain;
+	let hash = info.finalized_state.map(|(hash, _)| hash).unwrap_or_else(|| {
+		debug!(target: LOG_TARGET, "No finalized state is available. Reading config from genesis");
+		info.genesis_hash
+	});
+
+	let genesis_epoch = client.runtime_api().current_epoch(hash)?;
+	Ok(SassafrasConfiguration { slot_duration, genesis_epoch })
+}
+
+/// Block weight increment for the given slot claim.
+///
+/// Primary (ticket based) claims add one unit of weight, secondary claims add nothing.
+pub(crate) fn claim_weight(claim: &SlotClaim) -> SassafrasBlockWeight {
+	claim.ticket_claim.is_some() as SassafrasBlockWeight
+}
+
+/// Extract the Sassafras slot claim from the given header.
+///
+/// Slot claim is mandatory for all non-genesis blocks, the function will return `Err`
+/// if none is found.
+pub fn find_slot_claim<B: BlockT>(header: &B::Header) -> Result<SlotClaim, Error<B>> {
+	let mut claim: Option<_> = None;
+	for log in header.digest().logs() {
+		trace!(target: LOG_TARGET, "Checking log {:?}, looking for slot claim", log);
+		match (SlotClaim::try_from(log), claim.is_some()) {
+			(Ok(_), true) => return Err(sassafras_err(Error::MultipleSlotClaims)),
+			(Err(_), _) => trace!(target: LOG_TARGET, "Ignoring digest not meant for us"),
+			(Ok(c), false) => claim = Some(c),
+		}
+	}
+	claim.ok_or_else(|| sassafras_err(Error::MissingSlotClaim))
+}
+
+/// Extract the slot of the given header.
+///
+/// Genesis block doesn't contain a slot claim, thus its slot is conventionally zero.
+pub fn find_slot<B: BlockT>(header: &B::Header) -> Result<Slot, Error<B>> {
+	if header.number().is_zero() {
+		return Ok(0.into())
+	}
+	find_slot_claim::<B>(header).map(|claim| claim.slot)
+}
+
+/// Extract the Sassafras epoch change digest from the given header, if it exists.
+fn find_next_epoch_digest<B: BlockT>(
+	header: &B::Header,
+) -> Result<Option<NextEpochDescriptor>, Error<B>> {
+	let mut epoch_digest: Option<_> = None;
+	for log in header.digest().logs() {
+		trace!(target: LOG_TARGET, "Checking log {:?}, looking for epoch change digest.", log);
+		let log = log.try_to::<ConsensusLog>(OpaqueDigestItemId::Consensus(&SASSAFRAS_ENGINE_ID));
+		match (log, epoch_digest.is_some()) {
+			(Some(ConsensusLog::NextEpochData(_)), true) =>
+				return Err(sassafras_err(Error::MultipleEpochChangeDigests)),
+			(Some(ConsensusLog::NextEpochData(epoch)), false) => epoch_digest = Some(epoch),
+			_ => trace!(target: LOG_TARGET, "Ignoring digest not meant for us"),
+		}
+	}
+
+	Ok(epoch_digest)
+}
+
+/// State that must be shared between the import queue and the authoring logic.
+#[derive(Clone)]
+pub struct SassafrasLink<B: BlockT> {
+	/// Epoch changes tree.
+	epoch_changes: SharedEpochChanges<B, Epoch>,
+	/// Protocol configuration.
+	config: SassafrasConfiguration,
+}
+
+impl<B: BlockT> SassafrasLink<B> {
+	/// Get the epoch changes of this link.
+	pub fn epoch_changes(&self) -> &SharedEpochChanges<B, Epoch> {
+		&self.epoch_changes
+	}
+
+	/// Get the config of this link.
+	pub fn config(&self) -> &SassafrasConfiguration {
+		&self.config
+	}
+}
+
+/// Convert a client error into a consensus import error.
+pub(crate) fn import_err<B: BlockT>(error: Error<B>) -> ConsensusError {
+	ConsensusError::ClientImport(sassafras_err(error).into())
+}
@@ -0,0 +1,895 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Sassafras client tests.
+
+use super::*;
+use crate::{
+	authorship::{claim_slot, generate_epoch_tickets, secondary_authority_index},
+	verification::{check_header, VerificationParams},
+};
+use futures::{future, StreamExt};
+use parking_lot::Mutex;
+use sc_block_builder::BlockBuilderBuilder;
+use sc_client_api::{
+	BlockchainEvents, ClientInfo, FinalityNotifications, ImportNotifications, OnFinalityAction,
+	OnImportAction, PreCommitActions, StorageEventStream, StorageKey,
+};
+use sc_consensus::{
+	BlockCheckParams, BlockImport, BlockImportParams, BoxJustificationImport, ImportResult,
+	Verifier,
+};
+use sc_consensus_epochs::descendent_query;
+use sc_consensus_slots::{BackoffAuthoringOnFinalizedHeadLagging, CheckedHeader};
+use sc_network_test::{BlockImportAdapter, Peer, PeersClient, PeersFullClient, TestNetFactory};
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, RejectAllTxPool};
+use sp_api::{ApiError, ApiRef, ProvideRuntimeApi};
+use sp_application_crypto::AppCrypto;
+use sp_block_builder::BlockBuilder as BlockBuilderApi;
+use sp_blockchain::{BlockStatus, CachedHeaderMetadata, HeaderBackend, HeaderMetadata, Info};
+use sp_consensus::{
+	BlockOrigin, DisableProofRecording, Environment, NoNetwork as DummyOracle, Proposal, Proposer,
+};
+use sp_consensus_sassafras::{EphemeralPublic, EquivocationProof, OpaqueKeyOwnershipProof};
+use sp_core::crypto::{Pair, Wraps};
+use sp_inherents::{
+	CheckInherentsResult, CreateInherentDataProviders, InherentData, InherentIdentifier,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystorePtr};
+use sp_runtime::{ApplyExtrinsicResult, Digest, DigestItem};
+use std::{sync::Arc, task::Poll, time::Duration};
+use substrate_test_runtime_client::{
+	runtime::{Block as TestBlock, Extrinsic, Hash, Header as TestHeader},
+	DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
+};
+
+const EPOCH_LENGTH: u32 = 10;
+
+const SLOT_DURATION_MS: u64 = 250;
+
+fn create_keystore(seeds: &[&str]) -> (KeystorePtr, Vec<AuthorityId>) {
+	let keystore = MemoryKeystore::new();
+	let authorities = seeds
+		.iter()
+		.map(|seed| {
+			keystore
+				.bandersnatch_generate_new(AuthorityId::ID, Some(seed))
+				.expect("Creates authority key")
+				.into()
+		})
+		.collect();
+	(Arc::new(keystore), authorities)
+}
+
+fn create_epoch(authorities: Vec<AuthorityId>, config: EpochConfiguration) -> Epoch {
+	sp_consensus_sassafras::Epoch {
+		index: 3,
+		start: 100.into(),
+		length: EPOCH_LENGTH,
+		randomness: [7; RANDOMNESS_LENGTH],
+		authorities,
+		config,
+	}
+	.into()
+}
+
+// Build a sealed header carrying the given claim.
+fn make_header(claim: &SlotClaim, keystore: &KeystorePtr, author: &AuthorityId) -> TestHeader {
+	let mut header = TestHeader::new(
+		1,
+		Default::default(),
+		Default::default(),
+		Hash::random(),
+		Digest { logs: vec![DigestItem::from(claim)] },
+	);
+	let signature: AuthoritySignature = keystore
+		.bandersnatch_sign(AuthorityId::ID, author.as_ref(), header.hash().as_ref())
+		.unwrap()
+		.unwrap()
+		.into();
+	header.digest_mut().push(DigestItem::from(&signature));
+	header
+}
+
+fn verify(
+	header: TestHeader,
+	claim: SlotClaim,
+	epoch: &Epoch,
+	maybe_ticket: Option<(TicketId, TicketBody)>,
+) -> Result<CheckedHeader<TestHeader, AuthorityId>, Error<TestBlock>> {
+	let params = VerificationParams::<TestBlock> {
+		header,
+		claim,
+		slot_now: 1000.into(),
+		epoch,
+		maybe_ticket,
+	};
+	check_header::<TestBlock>(params).map(|checked| match checked {
+		CheckedHeader::Checked(header, info) => CheckedHeader::Checked(header, info.authority_id),
+		CheckedHeader::Deferred(header, slot) => CheckedHeader::Deferred(header, slot),
+	})
+}
+
+#[test]
+fn epoch_increment_and_clone_for_slot() {
+	let (_, authorities) = create_keystore(&["//Alice"]);
+	let config = EpochConfiguration { redundancy_factor: 1, attempts_number: 4 };
+	let epoch = create_epoch(authorities.clone(), config);
+
+	assert_eq!(epoch.start_slot(), 100.into());
+	assert_eq!(epoch.end_slot(), 110.into());
+
+	let next_config = EpochConfiguration { redundancy_factor: 2, attempts_number: 8 };
+	let next = epoch.increment(NextEpochDescriptor {
+		randomness: [9; RANDOMNESS_LENGTH],
+		authorities: authorities.clone(),
+		config: Some(next_config),
+	});
+	assert_eq!(next.index, 4);
+	assert_eq!(next.start, 110.into());
+	assert_eq!(next.config, next_config);
+	assert!(next.tickets_aux.is_empty());
+
+	// Config is inherited when not announced.
+	let next = epoch.increment(NextEpochDescriptor {
+		randomness: [9; RANDOMNESS_LENGTH],
+		authorities,
+		config: None,
+	});
+	assert_eq!(next.config, config);
+
+	// Slot within the epoch.
+	assert_eq!(epoch.clone_for_slot(105.into()), epoch);
+
+	// Two skipped epochs.
+	let cloned = epoch.clone_for_slot(125.into());
+	assert_eq!(cloned.index, 5);
+	assert_eq!(cloned.start, 120.into());
+	assert_eq!(cloned.randomness, epoch.randomness);
+}
+
+#[test]
+fn secondary_authority_index_is_deterministic() {
+	let (_, authorities) = create_keystore(&["//Alice", "//Bob", "//Charlie"]);
+	let config = EpochConfiguration { redundancy_factor: 1, attempts_number: 4 };
+	let epoch = create_epoch(authorities, config);
+
+	for slot in 100..110 {
+		let idx = secondary_authority_index(slot.into(), &epoch).unwrap();
+		assert!(idx < 3);
+		assert_eq!(secondary_authority_index(slot.into(), &epoch), Some(idx));
+	}
+
+	let empty = create_epoch(vec![], config);
+	assert_eq!(secondary_authority_index(100.into(), &empty), None);
+}
+
+#[test]
+fn claim_and_verify_secondary_slot() {
+	let (keystore, authorities) = create_keystore(&["//Alice", "//Bob", "//Charlie"]);
+	let config = EpochConfiguration { redundancy_factor: 1, attempts_number: 4 };
+	let epoch = create_epoch(authorities, config);
+
+	let slot = Slot::from(103);
+	let (claim, author) = claim_slot(slot, &epoch, None, &keystore).unwrap();
+	assert_eq!(claim.authority_idx, secondary_authority_index(slot, &epoch).unwrap());
+	assert!(claim.ticket_claim.is_none());
+
+	let header = make_header(&claim, &keystore, &author);
+	let res = verify(header.clone(), claim.clone(), &epoch, None).unwrap();
+	assert!(matches!(res, CheckedHeader::Checked(_, id) if id == author));
+
+	// A ticket for the slot is expected, but the claim is for a secondary slot.
+	let ticket_body = TicketBody {
+		attempt_idx: 0,
+		erased_public: EphemeralPublic::from_raw([0; 32]),
+		revealed_public: EphemeralPublic::from_raw([0; 32]),
+	};
+	let res = verify(header, claim, &epoch, Some((0, ticket_body)));
+	assert!(matches!(res, Err(Error::MissingTicketClaim)));
+}
+
+#[test]
+fn secondary_claim_from_wrong_authority_is_rejected() {
+	let (keystore, authorities) = create_keystore(&["//Alice", "//Bob", "//Charlie"]);
+	let config = EpochConfiguration { redundancy_factor: 1, attempts_number: 4 };
+	let epoch = create_epoch(authorities.clone(), config);
+
+	let slot = Slot::from(104);
+	let (mut claim, _) = claim_slot(slot, &epoch, None, &keystore).unwrap();
+
+	// Pretend the slot has been claimed by another authority.
+	let wrong_idx = (claim.authority_idx + 1) % authorities.len() as u32;
+	let wrong_author = authorities[wrong_idx as usize].clone();
+	claim.authority_idx = wrong_idx;
+	claim.vrf_signature = keystore
+		.bandersnatch_vrf_sign(
+			AuthorityId::ID,
+			wrong_author.as_ref(),
+			&vrf::slot_claim_sign_data(&epoch.randomness, slot, epoch.index),
+		)
+		.unwrap()
+		.unwrap();
+
+	let header = make_header(&claim, &keystore, &wrong_author);
+	let res = verify(header, claim, &epoch, None);
+	assert!(matches!(res, Err(Error::InvalidSecondaryAuthor(..))));
+}
+
+#[test]
+fn header_from_the_future_is_deferred() {
+	let (keystore, authorities) = create_keystore(&["//Alice"]);
+	let config = EpochConfiguration { redundancy_factor: 1, attempts_number: 4 };
+	let epoch = create_epoch(authorities, config);
+
+	let slot = Slot::from(2000);
+	let (claim, author) = claim_slot(slot, &epoch, None, &keystore).unwrap();
+	let header = make_header(&claim, &keystore, &author);
+
+	let res = verify(header, claim, &epoch, None).unwrap();
+	assert!(matches!(res, CheckedHeader::Deferred(_, s) if s == slot));
+}
+
+#[test]
+fn generate_tickets_then_claim_and_verify_primary_slot() {
+	sp_tracing::try_init_simple();
+
+	let (keystore, authorities) = create_keystore(&["//Alice", "//Bob"]);
+	// Only Alice keys are available to the local node.
+	let keystore_alice: KeystorePtr = {
+		let keystore = MemoryKeystore::new();
+		keystore.bandersnatch_generate_new(AuthorityId::ID, Some("//Alice")).unwrap();
+		Arc::new(keystore)
+	};
+	// Redundancy factor is high enough to accept every ticket.
+	let config = EpochConfiguration { redundancy_factor: 1000, attempts_number: 2 };
+	let mut epoch = create_epoch(authorities.clone(), config);
+
+	let ring_ctx = vrf::RingContext::new_testing();
+	let tickets = generate_epoch_tickets(&mut epoch, &keystore_alice, &ring_ctx);
+	assert_eq!(tickets.len(), 2);
+	assert_eq!(epoch.tickets_aux.len(), 2);
+
+	// Tickets ring signatures are verifiable using the ring of all the epoch authorities.
+	let ring = authorities.iter().map(|a| *a.as_inner_ref()).collect::<Vec<_>>();
+	let verifier = ring_ctx.verifier(&ring).unwrap();
+	for ticket in &tickets {
+		let input = vrf::ticket_id_input(&epoch.randomness, ticket.body.attempt_idx, epoch.index);
+		let ticket_id = vrf::make_ticket_id(&input, &ticket.signature.pre_outputs[0]);
+		assert!(epoch.tickets_aux.contains_key(&ticket_id));
+		let sign_data = vrf::ticket_body_sign_data(&ticket.body, input);
+		assert!(ticket.signature.ring_vrf_verify(&sign_data, &verifier));
+	}
+
+	// Claim a slot using one of our tickets.
+	let (ticket_id, (authority_idx, _)) =
+		epoch.tickets_aux.iter().next().map(|(id, aux)| (*id, aux.clone())).unwrap();
+	assert_eq!(authority_idx, 0);
+	let ticket_body = tickets
+		.iter()
+		.find(|t| {
+			let input = vrf::ticket_id_input(&epoch.randomness, t.body.attempt_idx, epoch.index);
+			vrf::make_ticket_id(&input, &t.signature.pre_outputs[0]) == ticket_id
+		})
+		.map(|t| t.body.clone())
+		.unwrap();
+
+	let slot = Slot::from(105);
+	let (claim, author) =
+		claim_slot(slot, &epoch, Some((ticket_id, ticket_body.clone())), &keystore).unwrap();
+	assert_eq!(author, authorities[0]);
+	assert!(claim.ticket_claim.is_some());
+	assert_eq!(claim_weight(&claim), 1);
+
+	let header = make_header(&claim, &keystore, &author);
+	let res =
+		verify(header.clone(), claim.clone(), &epoch, Some((ticket_id, ticket_body))).unwrap();
+	assert!(matches!(res, CheckedHeader::Checked(_, id) if id == author));
+
+	// Claim is rejected if the ticket erased key doesn't match.
+	let other_body = TicketBody {
+		attempt_idx: 0,
+		erased_public: EphemeralPublic::from_raw([0; 32]),
+		revealed_public: EphemeralPublic::from_raw([0; 32]),
+	};
+	let res = verify(header.clone(), claim.clone(), &epoch, Some((ticket_id, other_body)));
+	assert!(matches!(res, Err(Error::BadTicketClaimSignature)));
+
+	// Claim is rejected if no ticket is associated with the slot.
+	let res = verify(header, claim, &epoch, None);
+	assert!(matches!(res, Err(Error::UnexpectedTicketClaim)));
+
+	// Tickets not owned by the local node can't be claimed.
+	assert!(claim_slot(slot, &epoch, Some((ticket_id + 1, tickets[0].body.clone())), &keystore)
+		.is_none());
+}
+
+#[test]
+fn find_slot_claim_works() {
+	let (keystore, authorities) = create_keystore(&["//Alice"]);
+	let config = EpochConfiguration { redundancy_factor: 1, attempts_number: 4 };
+	let epoch = create_epoch(authorities, config);
+
+	let (claim, author) = claim_slot(101.into(), &epoch, None, &keystore).unwrap();
+	let mut header = make_header(&claim, &keystore, &author);
+	assert_eq!(find_slot_claim::<TestBlock>(&header).unwrap().slot, claim.slot);
+	assert_eq!(find_slot::<TestBlock>(&header).unwrap(), claim.slot);
+
+	header.digest_mut().push(DigestItem::from(&claim));
+	assert!(matches!(find_slot_claim::<TestBlock>(&header), Err(Error::MultipleSlotClaims)));
+
+	header.digest_mut().logs.clear();
+	assert!(matches!(find_slot_claim::<TestBlock>(&header), Err(Error::MissingSlotClaim)));
+}
+
+// Mocked runtime API, exposing a fixed genesis epoch template and no tickets.
+#[derive(Clone)]
+struct TestApi {
+	genesis_epoch: sp_consensus_sassafras::Epoch,
+}
+
+struct RuntimeApi {
+	inner: TestApi,
+}
+
+sp_api::mock_impl_runtime_apis! {
+	impl SassafrasApi<TestBlock> for RuntimeApi {
+		fn ring_context() -> Option<vrf::RingContext> {
+			None
+		}
+
+		fn submit_tickets_unsigned_extrinsic(_tickets: Vec<TicketEnvelope>) -> bool {
+			false
+		}
+
+		fn slot_ticket_id(_slot: Slot) -> Option<TicketId> {
+			None
+		}
+
+		fn slot_ticket(_slot: Slot) -> Option<(TicketId, TicketBody)> {
+			None
+		}
+
+		fn current_epoch(&self) -> sp_consensus_sassafras::Epoch {
+			self.inner.genesis_epoch.clone()
+		}
+
+		fn next_epoch(&self) -> sp_consensus_sassafras::Epoch {
+			let mut epoch = self.inner.genesis_epoch.clone();
+			epoch.index += 1;
+			epoch.start = epoch.start + epoch.length as u64;
+			epoch
+		}
+
+		fn generate_key_ownership_proof(
+			_authority_id: AuthorityId,
+		) -> Option<OpaqueKeyOwnershipProof> {
+			None
+		}
+
+		fn submit_report_equivocation_unsigned_extrinsic(
+			_equivocation_proof: EquivocationProof<TestHeader>,
+			_key_owner_proof: OpaqueKeyOwnershipProof,
+		) -> bool {
+			false
+		}
+	}
+
+	impl BlockBuilderApi<TestBlock> for RuntimeApi {
+		#[advanced]
+		fn apply_extrinsic(
+			_: Hash,
+			_extrinsic: Extrinsic,
+		) -> Result<ApplyExtrinsicResult, ApiError> {
+			Err(ApiError::Application("blocks are built using the peer client".into()))
+		}
+
+		#[advanced]
+		fn finalize_block(_: Hash) -> Result<TestHeader, ApiError> {
+			Err(ApiError::Application("blocks are built using the peer client".into()))
+		}
+
+		fn inherent_extrinsics(_data: InherentData) -> Vec<Extrinsic> {
+			Vec::new()
+		}
+
+		fn check_inherents(_block: TestBlock, _data: InherentData) -> CheckInherentsResult {
+			CheckInherentsResult::new()
+		}
+	}
+}
+
+// Peer client whose runtime API is replaced by the Sassafras mock.
+//
+// The test runtime doesn't implement `SassafrasApi`, thus everything else is delegated
+// to the peer client, which is used to build, execute and store the blocks.
+#[derive(Clone)]
+struct TestClient {
+	inner: Arc<PeersFullClient>,
+	api: TestApi,
+}
+
+impl ProvideRuntimeApi<TestBlock> for TestClient {
+	type Api = RuntimeApi;
+
+	fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+		RuntimeApi { inner: self.api.clone() }.into()
+	}
+}
+
+impl HeaderBackend<TestBlock> for TestClient {
+	fn header(&self, hash: Hash) -> sp_blockchain::Result<Option<TestHeader>> {
+		HeaderBackend::header(&*self.inner, hash)
+	}
+
+	fn info(&self) -> Info<TestBlock> {
+		HeaderBackend::info(&*self.inner)
+	}
+
+	fn status(&self, hash: Hash) -> sp_blockchain::Result<BlockStatus> {
+		HeaderBackend::status(&*self.inner, hash)
+	}
+
+	fn number(&self, hash: Hash) -> sp_blockchain::Result<Option<NumberFor<TestBlock>>> {
+		HeaderBackend::number(&*self.inner, hash)
+	}
+
+	fn hash(&self, number: NumberFor<TestBlock>) -> sp_blockchain::Result<Option<Hash>> {
+		HeaderBackend::hash(&*self.inner, number)
+	}
+}
+
+impl HeaderMetadata<TestBlock> for TestClient {
+	type Error = sp_blockchain::Error;
+
+	fn header_metadata(
+		&self,
+		hash: Hash,
+	) -> sp_blockchain::Result<CachedHeaderMetadata<TestBlock>> {
+		self.inner.header_metadata(hash)
+	}
+
+	fn insert_header_metadata(&self, hash: Hash, metadata: CachedHeaderMetadata<TestBlock>) {
+		self.inner.insert_header_metadata(hash, metadata)
+	}
+
+	fn remove_header_metadata(&self, hash: Hash) {
+		self.inner.remove_header_metadata(hash)
+	}
+}
+
+impl AuxStore for TestClient {
+	fn insert_aux<
+		'a,
+		'b: 'a,
+		'c: 'a,
+		I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+		D: IntoIterator<Item = &'a &'b [u8]>,
+	>(
+		&self,
+		insert: I,
+		delete: D,
+	) -> sp_blockchain::Result<()> {
+		AuxStore::insert_aux(&*self.inner, insert, delete)
+	}
+
+	fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+		AuxStore::get_aux(&*self.inner, key)
+	}
+}
+
+impl BlockchainEvents<TestBlock> for TestClient {
+	fn import_notification_stream(&self) -> ImportNotifications<TestBlock> {
+		self.inner.import_notification_stream()
+	}
+
+	fn every_import_notification_stream(&self) -> ImportNotifications<TestBlock> {
+		self.inner.every_import_notification_stream()
+	}
+
+	fn finality_notification_stream(&self) -> FinalityNotifications<TestBlock> {
+		self.inner.finality_notification_stream()
+	}
+
+	fn storage_changes_notification_stream(
+		&self,
+		filter_keys: Option<&[StorageKey]>,
+		child_filter_keys: Option<&[(StorageKey, Option<Vec<StorageKey>>)]>,
+	) -> sp_blockchain::Result<StorageEventStream<Hash>> {
+		self.inner.storage_changes_notification_stream(filter_keys, child_filter_keys)
+	}
+}
+
+impl PreCommitActions<TestBlock> for TestClient {
+	fn register_import_action(&self, action: OnImportAction<TestBlock>) {
+		self.inner.register_import_action(action)
+	}
+
+	fn register_finality_action(&self, action: OnFinalityAction<TestBlock>) {
+		self.inner.register_finality_action(action)
+	}
+}
+
+impl UsageProvider<TestBlock> for TestClient {
+	fn usage_info(&self) -> ClientInfo<TestBlock> {
+		self.inner.usage_info()
+	}
+}
+
+// Slot inherent data provider.
+//
+// The test runtime has no slot based pallet, thus no inherent data is provided.
+struct SlotProvider(Slot);
+
+impl std::ops::Deref for SlotProvider {
+	type Target = Slot;
+
+	fn deref(&self) -> &Slot {
+		&self.0
+	}
+}
+
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for SlotProvider {
+	async fn provide_inherent_data(&self, _: &mut InherentData) -> Result<(), sp_inherents::Error> {
+		Ok(())
+	}
+
+	async fn try_handle_error(
+		&self,
+		_: &InherentIdentifier,
+		_: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
+
+// Every block is authored in the slot following the one of its parent.
+fn next_slot_provider(
+	client: Arc<PeersFullClient>,
+) -> impl CreateInherentDataProviders<TestBlock, (), InherentDataProviders = (SlotProvider,)> {
+	move |parent, _| {
+		let parent_header = HeaderBackend::header(&*client, parent).ok().flatten().unwrap();
+		let slot = find_slot::<TestBlock>(&parent_header).unwrap() + 1;
+		async move { Ok((SlotProvider(slot),)) }
+	}
+}
+
+#[derive(Clone)]
+struct DummyFactory {
+	client: Arc<PeersFullClient>,
+	epoch_changes: SharedEpochChanges<TestBlock, Epoch>,
+	genesis_epoch: sp_consensus_sassafras::Epoch,
+	announce_epochs: bool,
+}
+
+struct DummyProposer {
+	factory: DummyFactory,
+	parent_header: TestHeader,
+}
+
+impl Environment<TestBlock> for DummyFactory {
+	type CreateProposer = future::Ready<Result<DummyProposer, sp_blockchain::Error>>;
+	type Proposer = DummyProposer;
+	type Error = sp_blockchain::Error;
+
+	fn init(&mut self, parent_header: &TestHeader) -> Self::CreateProposer {
+		future::ready(Ok(DummyProposer {
+			factory: self.clone(),
+			parent_header: parent_header.clone(),
+		}))
+	}
+}
+
+impl DummyProposer {
+	// The runtime doesn't announce the next epoch, so we do it on its behalf
+	// in the first block of each epoch.
+	fn next_epoch_digest(&self, pre_digests: &Digest) -> Option<DigestItem> {
+		let slot = pre_digests.logs().iter().find_map(|log| SlotClaim::try_from(log).ok())?.slot;
+		let parent_hash = self.parent_header.hash();
+		let parent_slot = find_slot::<TestBlock>(&self.parent_header).ok()?;
+		let epoch_start = self
+			.factory
+			.epoch_changes
+			.shared_data()
+			.epoch_descriptor_for_child_of(
+				descendent_query(&*self.factory.client),
+				&parent_hash,
+				*self.parent_header.number(),
+				slot,
+			)
+			.ok()??
+			.start_slot();
+		if parent_slot >= epoch_start {
+			return None
+		}
+		let genesis = &self.factory.genesis_epoch;
+		let descriptor = NextEpochDescriptor {
+			randomness: genesis.randomness,
+			authorities: genesis.authorities.clone(),
+			config: None,
+		};
+		Some(DigestItem::Consensus(
+			SASSAFRAS_ENGINE_ID,
+			ConsensusLog::NextEpochData(descriptor).encode(),
+		))
+	}
+}
+
+impl Proposer<TestBlock> for DummyProposer {
+	type Error = sp_blockchain::Error;
+	type Proposal = future::Ready<Result<Proposal<TestBlock, ()>, sp_blockchain::Error>>;
+	type ProofRecording = DisableProofRecording;
+	type Proof = ();
+
+	fn propose(
+		self,
+		_: InherentData,
+		mut pre_digests: Digest,
+		_: Duration,
+		_: Option<usize>,
+	) -> Self::Proposal {
+		if self.factory.announce_epochs {
+			if let Some(item) = self.next_epoch_digest(&pre_digests) {
+				pre_digests.push(item);
+			}
+		}
+
+		let block = BlockBuilderBuilder::new(&*self.factory.client)
+			.on_parent_block(self.parent_header.hash())
+			.with_parent_block_number(*self.parent_header.number())
+			.with_inherent_digests(pre_digests)
+			.build()
+			.and_then(|builder| builder.build())
+			.map(|built| built.block);
+
+		future::ready(block.map(|block| Proposal {
+			block,
+			proof: (),
+			storage_changes: Default::default(),
+		}))
+	}
+}
+
+#[derive(Clone)]
+struct PanickingBlockImport<B>(B);
+
+#[async_trait::async_trait]
+impl<B: BlockImport<TestBlock> + Send> BlockImport<TestBlock> for PanickingBlockImport<B> {
+	type Error = B::Error;
+
+	async fn import_block(
+		&mut self,
+		block: BlockImportParams<TestBlock>,
+	) -> Result<ImportResult, Self::Error> {
+		Ok(self.0.import_block(block).await.expect("importing block failed"))
+	}
+
+	async fn check_block(
+		&mut self,
+		block: BlockCheckParams<TestBlock>,
+	) -> Result<ImportResult, Self::Error> {
+		Ok(self.0.check_block(block).await.expect("checking block failed"))
+	}
+}
+
+type TestBlockImport =
+	PanickingBlockImport<SassafrasBlockImport<TestBlock, TestClient, Arc<PeersFullClient>>>;
+
+type TestSelectChain =
+	substrate_test_runtime_client::LongestChain<substrate_test_runtime_client::Backend, TestBlock>;
+
+type TestVerifier = SassafrasVerifier<
+	TestBlock,
+	TestClient,
+	TestSelectChain,
+	Box<dyn CreateInherentDataProviders<TestBlock, (), InherentDataProviders = (SlotProvider,)>>,
+>;
+
+struct PeerData {
+	link: SassafrasLink<TestBlock>,
+	block_import: Mutex<Option<TestBlockImport>>,
+}
+
+type SassafrasPeer = Peer<Option<PeerData>, TestBlockImport>;
+
+#[derive(Default)]
+struct SassafrasTestNet {
+	peers: Vec<SassafrasPeer>,
+}
+
+fn genesis_epoch() -> sp_consensus_sassafras::Epoch {
+	let authorities = ["//Alice", "//Bob", "//Charlie"]
+		.iter()
+		.map(|seed| AuthorityPair::from_string(seed, None).unwrap().public())
+		.collect();
+	sp_consensus_sassafras::Epoch {
+		index: 0,
+		start: 0.into(),
+		length: EPOCH_LENGTH,
+		randomness: [0; RANDOMNESS_LENGTH],
+		authorities,
+		config: EpochConfiguration { redundancy_factor: 1, attempts_number: 4 },
+	}
+}
+
+fn test_client(client: &PeersClient) -> Arc<TestClient> {
+	Arc::new(TestClient {
+		inner: client.as_client(),
+		api: TestApi { genesis_epoch: genesis_epoch() },
+	})
+}
+
+impl TestNetFactory for SassafrasTestNet {
+	type Verifier = TestVerifier;
+	type PeerData = Option<PeerData>;
+	type BlockImport = TestBlockImport;
+
+	fn make_block_import(
+		&self,
+		client: PeersClient,
+	) -> (
+		BlockImportAdapter<Self::BlockImport>,
+		Option<BoxJustificationImport<TestBlock>>,
+		Option<PeerData>,
+	) {
+		let inner = client.as_client();
+		let client = test_client(&client);
+
+		let config =
+			crate::configuration(&*client, SlotDuration::from_millis(SLOT_DURATION_MS)).unwrap();
+		let (block_import, link) = crate::block_import(config, inner, client).unwrap();
+		let block_import = PanickingBlockImport(block_import);
+
+		(
+			BlockImportAdapter::new(block_import.clone()),
+			None,
+			Some(PeerData { link, block_import: Mutex::new(Some(block_import)) }),
+		)
+	}
+
+	fn make_verifier(&self, client: PeersClient, data: &Option<PeerData>) -> Self::Verifier {
+		let data = data.as_ref().expect("sassafras link always provided to verifier");
+		let inner = client.as_client();
+		let (_, select_chain) = TestClientBuilder::new().build_with_longest_chain();
+
+		SassafrasVerifier::new(
+			test_client(&client),
+			select_chain,
+			Box::new(next_slot_provider(inner)),
+			data.link.config().clone(),
+			data.link.epoch_changes().clone(),
+			None,
+			OffchainTransactionPoolFactory::new(RejectAllTxPool::default()),
+		)
+	}
+
+	fn peer(&mut self, i: usize) -> &mut SassafrasPeer {
+		&mut self.peers[i]
+	}
+
+	fn peers(&self) -> &Vec<SassafrasPeer> {
+		&self.peers
+	}
+
+	fn peers_mut(&mut self) -> &mut Vec<SassafrasPeer> {
+		&mut self.peers
+	}
+
+	fn mut_peers<F: FnOnce(&mut Vec<SassafrasPeer>)>(&mut self, closure: F) {
+		closure(&mut self.peers);
+	}
+}
+
+// Run three authorities until each one of them imported a block authored by
+// itself and one authored by another peer, both past the second epoch start.
+async fn run_authoring_test(announce_epochs: bool) {
+	sp_tracing::try_init_simple();
+
+	let seeds = ["//Alice", "//Bob", "//Charlie"];
+	let net = Arc::new(Mutex::new(SassafrasTestNet::new(seeds.len())));
+	let mut import_notifications = Vec::new();
+	let mut sassafras_futures = Vec::new();
+
+	for (peer_id, seed) in seeds.iter().enumerate() {
+		let mut net = net.lock();
+		let peer = net.peer(peer_id);
+		let peer_client = peer.client().clone();
+		let inner = peer_client.as_client();
+		let client = test_client(&peer_client);
+		let select_chain = peer.select_chain().expect("full client has select chain");
+		let (keystore, _) = create_keystore(&[*seed]);
+		let data = peer.data.as_ref().expect("sassafras link set up during initialization");
+
+		let mut got_own = false;
+		let mut got_other = false;
+		import_notifications.push(
+			inner
+				.import_notification_stream()
+				.take_while(move |n| {
+					future::ready(
+						*n.header.number() <= EPOCH_LENGTH as u64 + 1 || {
+							if n.origin == BlockOrigin::Own {
+								got_own = true;
+							} else {
+								got_other = true;
+							}
+							!(got_own && got_other)
+						},
+					)
+				})
+				.for_each(|_| future::ready(())),
+		);
+
+		let env = DummyFactory {
+			client: inner.clone(),
+			epoch_changes: data.link.epoch_changes().clone(),
+			genesis_epoch: genesis_epoch(),
+			announce_epochs,
+		};
+
+		sassafras_futures.push(
+			start_sassafras(SassafrasParams {
+				client,
+				keystore,
+				select_chain,
+				env,
+				block_import: data.block_import.lock().take().expect("import set up during init"),
+				sync_oracle: DummyOracle,
+				justification_sync_link: (),
+				create_inherent_data_providers: next_slot_provider(inner),
+				force_authoring: false,
+				backoff_authoring_blocks: Some(BackoffAuthoringOnFinalizedHeadLagging::default()),
+				sassafras_link: data.link.clone(),
+				block_proposal_slot_portion: SlotProportion::new(0.5),
+				max_block_proposal_slot_portion: None,
+				telemetry: None,
+				offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(
+					RejectAllTxPool::default(),
+				),
+			})
+			.expect("starts sassafras"),
+		);
+	}
+
+	future::select(
+		future::poll_fn(move |cx| {
+			let mut net = net.lock();
+			net.poll(cx);
+			for peer in net.peers() {
+				if let Some((hash, err)) = peer.failed_verifications().into_iter().next() {
+					panic!("Verification failed for {:?}: {}", hash, err);
+				}
+			}
+			Poll::<()>::Pending
+		}),
+		future::select(future::join_all(import_notifications), future::join_all(sassafras_futures)),
+	)
+	.await;
+}
+
+#[tokio::test]
+async fn authoring_and_importing_blocks() {
+	run_authoring_test(true).await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "Expected epoch change to happen")]
+async fn rejects_missing_epoch_change_digest() {
+	run_authoring_test(false).await;
+}
@@ -314,6 +314,7 @@ where
 						wasm_bulk_memory: false,
 						wasm_reference_types: false,
 						wasm_simd: false,
+						fuel_limit: None,
 					},
 				},
 			)
@@ -77,11 +77,17 @@ struct InstanceCreator {
 	engine: Engine,
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
 	instance_counter: Arc<InstanceCounter>,
+	fuel_limit: Option<u64>,
 }
 
 impl InstanceCreator {
 	fn instantiate(&mut self) -> Result<InstanceWrapper> {
-		InstanceWrapper::new(&self.engine, &self.instance_pre, self.instance_counter.clone())
+		InstanceWrapper::new(
+			&self.engine,
+			&self.instance_pre,
+			self.instance_counter.clone(),
+			self.fuel_limit,
+		)
 	}
 }
 
@@ -139,6 +145,7 @@ pub struct WasmtimeRuntime {
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
 	instantiation_strategy: InternalInstantiationStrategy,
 	instance_counter: Arc<InstanceCounter>,
+	fuel_limit: Option<u64>,
 }
 
 impl WasmModule for WasmtimeRuntime {
@@ -148,6 +155,7 @@ impl WasmModule for WasmtimeRuntime {
 				engine: self.engine.clone(),
 				instance_pre: self.instance_pre.clone(),
 				instance_counter: self.instance_counter.clone(),
+				fuel_limit: self.fuel_limit,
 			}),
 		};
 
@@ -287,6 +295,8 @@ fn common_config(semantics: &Semantics) -> std::result::Result<wasmtime::Config,
 	config.wasm_threads(false);
 	config.wasm_memory64(false);
 
+	config.consume_fuel(semantics.fuel_limit.is_some());
+
 	let (use_pooling, use_cow) = match semantics.instantiation_strategy {
 		InstantiationStrategy::PoolingCopyOnWrite => (true, true),
 		InstantiationStrategy::Pooling => (true, false),
@@ -465,6 +475,14 @@ pub struct Semantics {
 
 	/// Enables WASM Fixed-Width SIMD proposal
 	pub wasm_simd: bool,
+
+	/// Specifying `Some` will enable deterministic fuel metering. Every instance gets the given
+	/// amount of fuel, which is consumed by executing wasm instructions. The execution traps once
+	/// the fuel is exhausted, at exactly the same point on every machine.
+	///
+	/// Fuel metering is part of the compiled code, so an artifact compiled with a different
+	/// setting can't be instantiated.
+	pub fuel_limit: Option<u64>,
 }
 
 #[derive(Clone)]
@@ -645,6 +663,7 @@ where
 		instance_pre: Arc::new(instance_pre),
 		instantiation_strategy,
 		instance_counter: Default::default(),
+		fuel_limit: config.semantics.fuel_limit,
 	})
 }
 
@@ -70,6 +70,7 @@ struct RuntimeBuilder {
 	deterministic_stack: bool,
 	heap_pages: HeapAllocStrategy,
 	precompile_runtime: bool,
+	fuel_limit: Option<u64>,
 	tmpdir: Option<tempfile::TempDir>,
 }
 
@@ -82,6 +83,7 @@ impl RuntimeBuilder {
 			deterministic_stack: false,
 			heap_pages: DEFAULT_HEAP_ALLOC_STRATEGY,
 			precompile_runtime: false,
+			fuel_limit: None,
 			tmpdir: None,
 		}
 	}
@@ -111,6 +113,11 @@ impl RuntimeBuilder {
 		self
 	}
 
+	fn fuel_limit(mut self, fuel_limit: Option<u64>) -> Self {
+		self.fuel_limit = fuel_limit;
+		self
+	}
+
 	fn build(&mut self) -> impl WasmModule + '_ {
 		let blob = {
 			let wasm: Vec<u8>;
@@ -146,6 +153,7 @@ impl RuntimeBuilder {
 				wasm_bulk_memory: false,
 				wasm_reference_types: false,
 				wasm_simd: false,
+				fuel_limit: self.fuel_limit,
 			},
 		};
 
@@ -303,6 +311,52 @@ fn test_stack_depth_reaching(instantiation_strategy: InstantiationStrategy) {
 	}
 }
 
+fn infinite_loop_wat() -> String {
+	r#"
+		(module
+		  (memory $0 32)
+		  (export "memory" (memory $0))
+		  (global (export "__heap_base") i32 (i32.const 0))
+
+		  (func (export "empty")
+		    (param i32 i32) (result i64)
+		    (i64.const 0)
+		  )
+
+		  (func (export "infinite_loop")
+		    (param i32 i32) (result i64)
+		    (loop $label$1
+		      (br $label$1)
+		    )
+		    (i64.const 0)
+		  )
+		)
+	"#
+	.to_string()
+}
+
+test_wasm_execution!(test_fuel_exhaustion_does_trap);
+fn test_fuel_exhaustion_does_trap(instantiation_strategy: InstantiationStrategy) {
+	let mut builder = RuntimeBuilder::new(instantiation_strategy)
+		.use_wat(infinite_loop_wat())
+		.fuel_limit(Some(1_000_000));
+
+	let runtime = builder.build();
+	let mut instance = runtime.new_instance().expect("failed to instantiate a runtime");
+
+	// Every call gets a fresh instance with the full amount of fuel.
+	instance.call_export("empty", &[]).unwrap();
+	instance.call_export("empty", &[]).unwrap();
+
+	match instance.call_export("infinite_loop", &[]).unwrap_err() {
+		Error::AbortedDueToTrap(error) => {
+			let expected = "wasm trap: all fuel consumed by WebAssembly";
+			assert_eq!(error.message, expected);
+		},
+		error => panic!("unexpected error: {:?}", error),
+	}
+}
+
 test_wasm_execution!(test_max_memory_pages_imported_memory_without_precompilation);
 fn test_max_memory_pages_imported_memory_without_precompilation(
 	instantiation_strategy: InstantiationStrategy,
@@ -473,6 +527,7 @@ fn test_instances_without_reuse_are_not_leaked() {
 				wasm_bulk_memory: false,
 				wasm_reference_types: false,
 				wasm_simd: false,
+				fuel_limit: None,
 			},
 		},
 	)
@@ -0,0 +1,338 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-protocol bandwidth accounting and rate limiting.
+//!
+//! Every notifications and request-response protocol gets a [`ProtocolBandwidth`], shared between
+//! the networking behaviours and the Prometheus metrics. Behaviours report the bytes they
+//! transfer and, if rate limits are configured for the protocol (see
+//! [`ProtocolRateLimits`]), ask how long they must wait before transferring more data.
+
+use crate::{
+	config::{ProtocolRateLimits, RateLimit},
+	types::ProtocolName,
+};
+
+use libp2p::PeerId;
+use parking_lot::Mutex;
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+/// Number of peers above which the state of the idle ones gets pruned.
+const MAX_TRACKED_PEERS: usize = 512;
+
+/// Direction of the traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+	/// Data received from a remote.
+	Inbound,
+	/// Data sent to a remote.
+	Outbound,
+}
+
+impl Direction {
+	/// Label of the direction in the metrics.
+	pub(crate) fn label(&self) -> &'static str {
+		match self {
+			Direction::Inbound => "in",
+			Direction::Outbound => "out",
+		}
+	}
+}
+
+/// Token bucket, refilled at the configured rate.
+#[derive(Debug)]
+struct TokenBucket {
+	limit: RateLimit,
+	tokens: f64,
+	last_refill: Instant,
+}
+
+impl TokenBucket {
+	fn new(limit: RateLimit, now: Instant) -> Self {
+		Self { limit, tokens: Self::capacity(&limit), last_refill: now }
+	}
+
+	fn capacity(limit: &RateLimit) -> f64 {
+		// A bucket of zero capacity would never allow anything through.
+		limit.burst.max(1) as f64
+	}
+
+	fn refill(&mut self, now: Instant) {
+		let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * self.limit.bytes_per_second.get() as f64)
+			.min(Self::capacity(&self.limit));
+		self.last_refill = now;
+	}
+
+	/// Returns how long to wait before the bucket isn't empty anymore.
+	fn delay(&mut self, now: Instant) -> Option<Duration> {
+		self.refill(now);
+
+		if self.tokens > 0.0 {
+			return None
+		}
+
+		let missing = 1.0 - self.tokens;
+		Some(Duration::from_secs_f64(missing / self.limit.bytes_per_second.get() as f64))
+	}
+
+	fn consume(&mut self, bytes: usize, now: Instant) {
+		self.refill(now);
+		self.tokens -= bytes as f64;
+	}
+
+	fn is_full(&mut self, now: Instant) -> bool {
+		self.refill(now);
+		self.tokens >= Self::capacity(&self.limit)
+	}
+}
+
+/// Rate limiter of a protocol, in a single direction.
+#[derive(Debug)]
+struct Limiter {
+	/// Bucket shared by all the peers.
+	total: Option<TokenBucket>,
+	/// Limit applied to every peer.
+	per_peer_limit: Option<RateLimit>,
+	/// Buckets of the individual peers, lazily created.
+	peers: HashMap<PeerId, TokenBucket>,
+}
+
+impl Limiter {
+	fn new(total: Option<RateLimit>, per_peer_limit: Option<RateLimit>, now: Instant) -> Self {
+		Self {
+			total: total.map(|limit| TokenBucket::new(limit, now)),
+			per_peer_limit,
+			peers: HashMap::new(),
+		}
+	}
+
+	fn delay(&mut self, peer: &PeerId, now: Instant) -> Option<Duration> {
+		let total = self.total.as_mut().and_then(|bucket| bucket.delay(now));
+		let peer = self.peers.get_mut(peer).and_then(|bucket| bucket.delay(now));
+
+		total.max(peer)
+	}
+
+	fn consume(&mut self, peer: &PeerId, bytes: usize, now: Instant) {
+		if let Some(bucket) = self.total.as_mut() {
+			bucket.consume(bytes, now);
+		}
+
+		if let Some(limit) = self.per_peer_limit {
+			self.peers
+				.entry(*peer)
+				.or_insert_with(|| TokenBucket::new(limit, now))
+				.consume(bytes, now);
+
+			// A full bucket is equivalent to a bucket that doesn't exist yet.
+			if self.peers.len() > MAX_TRACKED_PEERS {
+				self.peers.retain(|_, bucket| !bucket.is_full(now));
+			}
+		}
+	}
+}
+
+/// Bandwidth accounting and rate limiting of a single protocol.
+#[derive(Debug)]
+pub(crate) struct ProtocolBandwidth {
+	/// Total number of bytes received.
+	inbound_bytes: AtomicU64,
+	/// Total number of bytes sent.
+	outbound_bytes: AtomicU64,
+	/// Number of times the inbound traffic has been throttled.
+	inbound_rate_limited: AtomicU64,
+	/// Number of times the outbound traffic has been throttled.
+	outbound_rate_limited: AtomicU64,
+	/// Rate limiter of the inbound traffic, if any limit is configured.
+	inbound_limiter: Option<Mutex<Limiter>>,
+	/// Rate limiter of the outbound traffic, if any limit is configured.
+	outbound_limiter: Option<Mutex<Limiter>>,
+}
+
+impl ProtocolBandwidth {
+	/// Creates a new [`ProtocolBandwidth`] enforcing the given limits.
+	pub(crate) fn new(limits: ProtocolRateLimits) -> Self {
+		let now = Instant::now();
+		let limiter = |total: Option<RateLimit>, per_peer: Option<RateLimit>| {
+			(total.is_some() || per_peer.is_some())
+				.then(|| Mutex::new(Limiter::new(total, per_peer, now)))
+		};
+
+		Self {
+			inbound_bytes: AtomicU64::new(0),
+			outbound_bytes: AtomicU64::new(0),
+			inbound_rate_limited: AtomicU64::new(0),
+			outbound_rate_limited: AtomicU64::new(0),
+			inbound_limiter: limiter(limits.inbound, limits.inbound_per_peer),
+			outbound_limiter: limiter(limits.outbound, limits.outbound_per_peer),
+		}
+	}
+
+	/// Reports that `bytes` have been transferred with `peer`.
+	pub(crate) fn report(&self, peer: &PeerId, direction: Direction, bytes: usize) {
+		let (counter, limiter) = match direction {
+			Direction::Inbound => (&self.inbound_bytes, &self.inbound_limiter),
+			Direction::Outbound => (&self.outbound_bytes, &self.outbound_limiter),
+		};
+
+		counter.fetch_add(bytes as u64, Ordering::Relaxed);
+		if let Some(limiter) = limiter {
+			limiter.lock().consume(peer, bytes, Instant::now());
+		}
+	}
+
+	/// Returns how long to wait before transferring more data with `peer`, or `None` if data can
+	/// be transferred right away.
+	///
+	/// Every throttling is accounted for in the metrics: callers are expected to wait for the
+	/// returned delay before asking again.
+	pub(crate) fn delay(&self, peer: &PeerId, direction: Direction) -> Option<Duration> {
+		let (counter, limiter) = match direction {
+			Direction::Inbound => (&self.inbound_rate_limited, &self.inbound_limiter),
+			Direction::Outbound => (&self.outbound_rate_limited, &self.outbound_limiter),
+		};
+
+		let delay = limiter.as_ref()?.lock().delay(peer, Instant::now());
+		if delay.is_some() {
+			counter.fetch_add(1, Ordering::Relaxed);
+		}
+		delay
+	}
+
+	/// Total number of bytes transferred in the given direction.
+	pub(crate) fn total_bytes(&self, direction: Direction) -> u64 {
+		match direction {
+			Direction::Inbound => self.inbound_bytes.load(Ordering::Relaxed),
+			Direction::Outbound => self.outbound_bytes.load(Ordering::Relaxed),
+		}
+	}
+
+	/// Number of times the traffic has been throttled in the given direction.
+	pub(crate) fn total_rate_limited(&self, direction: Direction) -> u64 {
+		match direction {
+			Direction::Inbound => self.inbound_rate_limited.load(Ordering::Relaxed),
+			Direction::Outbound => self.outbound_rate_limited.load(Ordering::Relaxed),
+		}
+	}
+}
+
+/// Bandwidth accounting and rate limiting of all the installed protocols.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProtocolsBandwidth {
+	protocols: Arc<HashMap<ProtocolName, Arc<ProtocolBandwidth>>>,
+}
+
+impl ProtocolsBandwidth {
+	/// Creates a new [`ProtocolsBandwidth`] for the given protocols, rate limited according to
+	/// `limits`.
+	pub(crate) fn new(
+		protocols: impl IntoIterator<Item = ProtocolName>,
+		limits: &HashMap<ProtocolName, ProtocolRateLimits>,
+	) -> Self {
+		let protocols = protocols
+			.into_iter()
+			.map(|name| {
+				let limits = limits.get(&name).copied().unwrap_or_default();
+				(name, Arc::new(ProtocolBandwidth::new(limits)))
+			})
+			.collect();
+
+		Self { protocols: Arc::new(protocols) }
+	}
+
+	/// Returns the [`ProtocolBandwidth`] of the given protocol.
+	pub(crate) fn get(&self, protocol: &str) -> Option<Arc<ProtocolBandwidth>> {
+		self.protocols.get(protocol).cloned()
+	}
+
+	/// Iterates over all the protocols.
+	pub(crate) fn iter(&self) -> impl Iterator<Item = (&ProtocolName, &Arc<ProtocolBandwidth>)> {
+		self.protocols.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::num::NonZeroU64;
+
+	fn limit(bytes_per_second: u64, burst: u64) -> Option<RateLimit> {
+		Some(RateLimit { bytes_per_second: NonZeroU64::new(bytes_per_second).unwrap(), burst })
+	}
+
+	#[test]
+	fn token_bucket_refills() {
+		let now = Instant::now();
+		let mut bucket = TokenBucket::new(limit(1000, 500).unwrap(), now);
+
+		assert_eq!(bucket.delay(now), None);
+		bucket.consume(1500, now);
+		// 1000 bytes are missing before the bucket isn't empty anymore.
+		let delay = bucket.delay(now).unwrap();
+		assert!(delay > Duration::from_millis(1000) && delay < Duration::from_millis(1010));
+
+		assert!(bucket.delay(now + Duration::from_millis(1002)).is_none());
+		assert!(!bucket.is_full(now + Duration::from_millis(1002)));
+		assert!(bucket.is_full(now + Duration::from_secs(2)));
+	}
+
+	#[test]
+	fn unlimited_protocol_is_only_accounted() {
+		let bandwidth = ProtocolBandwidth::new(Default::default());
+		let peer = PeerId::random();
+
+		bandwidth.report(&peer, Direction::Inbound, 1_000_000);
+		bandwidth.report(&peer, Direction::Outbound, 10);
+
+		assert_eq!(bandwidth.delay(&peer, Direction::Inbound), None);
+		assert_eq!(bandwidth.total_bytes(Direction::Inbound), 1_000_000);
+		assert_eq!(bandwidth.total_bytes(Direction::Outbound), 10);
+		assert_eq!(bandwidth.total_rate_limited(Direction::Inbound), 0);
+	}
+
+	#[test]
+	fn per_peer_and_total_limits() {
+		let bandwidth = ProtocolBandwidth::new(ProtocolRateLimits {
+			inbound: limit(1, 1000),
+			inbound_per_peer: limit(1, 100),
+			..Default::default()
+		});
+		let (peer1, peer2, peer3) = (PeerId::random(), PeerId::random(), PeerId::random());
+
+		// `peer1` exhausts its own bucket.
+		bandwidth.report(&peer1, Direction::Inbound, 200);
+		assert!(bandwidth.delay(&peer1, Direction::Inbound).is_some());
+		assert!(bandwidth.delay(&peer2, Direction::Inbound).is_none());
+
+		// `peer2` exhausts the bucket shared by all the peers.
+		bandwidth.report(&peer2, Direction::Inbound, 900);
+		assert!(bandwidth.delay(&peer3, Direction::Inbound).is_some());
+
+		// Outbound traffic isn't limited.
+		assert!(bandwidth.delay(&peer1, Direction::Outbound).is_none());
+		assert_eq!(bandwidth.total_rate_limited(Direction::Inbound), 2);
+	}
+}
@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+	bandwidth::ProtocolsBandwidth,
 	discovery::{DiscoveryBehaviour, DiscoveryConfig, DiscoveryOut},
 	event::DhtEvent,
 	peer_info,
@@ -175,6 +176,7 @@ impl<B: BlockT> Behaviour<B> {
 		request_response_protocols: Vec<ProtocolConfig>,
 		peer_store_handle: PeerStoreHandle,
 		external_addresses: Arc<Mutex<HashSet<Multiaddr>>>,
+		protocols_bandwidth: ProtocolsBandwidth,
 	) -> Result<Self, request_responses::RegisterError> {
 		Ok(Self {
 			substrate,
@@ -187,7 +189,8 @@ impl<B: BlockT> Behaviour<B> {
 			request_responses: request_responses::RequestResponsesBehaviour::new(
 				request_response_protocols.into_iter(),
 				Box::new(peer_store_handle),
-			)?,
+			)?
+			.with_bandwidth(protocols_bandwidth),
 		})
 	}
 
@@ -51,13 +51,14 @@ pub use sc_network_common::{
 use sp_runtime::traits::Block as BlockT;
 
 use std::{
+	collections::HashMap,
 	error::Error,
 	fmt, fs,
 	future::Future,
 	io::{self, Write},
 	iter,
 	net::Ipv4Addr,
-	num::NonZeroUsize,
+	num::{NonZeroU64, NonZeroUsize},
 	path::{Path, PathBuf},
 	pin::Pin,
 	str::{self, FromStr},
@@ -569,6 +570,38 @@ impl NonDefaultSetConfig {
 	}
 }
 
+/// Maximum throughput allowed for some traffic, enforced using a token bucket.
+///
+/// Data is transferred as long as the bucket isn't empty, which means that messages larger than
+/// `burst` are never blocked forever: they are delayed until the bucket has been refilled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+	/// Sustained throughput, in bytes per second.
+	pub bytes_per_second: NonZeroU64,
+	/// Maximum number of bytes that can be transferred at once, above the sustained throughput.
+	pub burst: u64,
+}
+
+/// Rate limits applied to the traffic of a single notifications or request-response protocol.
+///
+/// For request-response protocols, both requests and responses are accounted for, but only
+/// requests are limited: inbound requests above the limit are dropped, and outbound requests
+/// above the limit fail with
+/// [`RequestFailure::RateLimited`](crate::request_responses::RequestFailure::RateLimited).
+///
+/// `None` means that the traffic isn't limited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProtocolRateLimits {
+	/// Limit of the inbound traffic, all peers included.
+	pub inbound: Option<RateLimit>,
+	/// Limit of the outbound traffic, all peers included.
+	pub outbound: Option<RateLimit>,
+	/// Limit of the inbound traffic of every single peer.
+	pub inbound_per_peer: Option<RateLimit>,
+	/// Limit of the outbound traffic of every single peer.
+	pub outbound_per_peer: Option<RateLimit>,
+}
+
 /// Network service configuration.
 #[derive(Clone, Debug)]
 pub struct NetworkConfiguration {
@@ -655,6 +688,12 @@ pub struct NetworkConfiguration {
 	/// a modification of the way the implementation works. Different nodes with different
 	/// configured values remain compatible with each other.
 	pub yamux_window_size: Option<u32>,
+
+	/// Rate limits of the notifications and request-response protocols, by protocol name.
+	///
+	/// Protocols missing from this map aren't rate limited. The bandwidth of every protocol is
+	/// accounted for in Prometheus, whether it is rate limited or not.
+	pub protocol_rate_limits: HashMap<ProtocolName, ProtocolRateLimits>,
 }
 
 impl NetworkConfiguration {
@@ -687,6 +726,7 @@ impl NetworkConfiguration {
 				.expect("value is a constant; constant is non-zero; qed."),
 			yamux_window_size: None,
 			ipfs_server: false,
+			protocol_rate_limits: HashMap::new(),
 		}
 	}
 
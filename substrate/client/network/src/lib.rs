@@ -242,6 +242,7 @@
 //!
 //! More precise usage details are still being worked on and will likely change in the future.
 
+mod bandwidth;
 mod behaviour;
 mod protocol;
 
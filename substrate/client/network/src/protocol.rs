@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+	bandwidth::ProtocolsBandwidth,
 	config, error,
 	peer_store::{PeerStoreHandle, PeerStoreProvider},
 	protocol_controller::{self, SetId},
@@ -86,6 +87,7 @@ impl<B: BlockT> Protocol<B> {
 		peer_store_handle: PeerStoreHandle,
 		protocol_controller_handles: Vec<protocol_controller::ProtocolHandle>,
 		from_protocol_controllers: TracingUnboundedReceiver<protocol_controller::Message>,
+		protocols_bandwidth: &ProtocolsBandwidth,
 	) -> error::Result<(Self, Vec<ProtocolHandle>)> {
 		let (behaviour, notification_protocols, handles) = {
 			let installed_protocols = iter::once(block_announces_protocol.protocol_name().clone())
@@ -101,6 +103,7 @@ impl<B: BlockT> Protocol<B> {
 					fallback_names: block_announces_protocol.fallback_names().cloned().collect(),
 					handshake: block_announces_protocol.handshake().as_ref().unwrap().to_vec(),
 					max_notification_size: block_announces_protocol.max_notification_size(),
+					bandwidth: protocols_bandwidth.get(block_announces_protocol.protocol_name()),
 				};
 
 				let (handle, command_stream) =
@@ -114,6 +117,7 @@ impl<B: BlockT> Protocol<B> {
 					fallback_names: s.fallback_names().cloned().collect(),
 					handshake: s.handshake().as_ref().map_or(roles.encode(), |h| (*h).to_vec()),
 					max_notification_size: s.max_notification_size(),
+					bandwidth: protocols_bandwidth.get(s.protocol_name()),
 				};
 
 				let (handle, command_stream) = s.take_protocol_handle().split();
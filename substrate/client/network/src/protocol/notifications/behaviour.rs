@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+	bandwidth::ProtocolBandwidth,
 	protocol::notifications::{
 		handler::{self, NotificationsSink, NotifsHandler, NotifsHandlerIn, NotifsHandlerOut},
 		service::{metrics, NotificationCommand, ProtocolHandle, ValidationCallResult},
@@ -181,6 +182,8 @@ pub struct ProtocolConfig {
 	pub handshake: Vec<u8>,
 	/// Maximum allowed size for a notification.
 	pub max_notification_size: u64,
+	/// Bandwidth accounting and rate limiting of the protocol.
+	pub bandwidth: Option<Arc<ProtocolBandwidth>>,
 }
 
 /// Identifier for a delay firing.
@@ -421,6 +424,7 @@ impl Notifications {
 						fallback_names: cfg.fallback_names,
 						handshake: Arc::new(RwLock::new(cfg.handshake)),
 						max_notification_size: cfg.max_notification_size,
+						bandwidth: cfg.bandwidth,
 					},
 					(protocol_handle, command_stream),
 				)
@@ -2457,6 +2461,7 @@ mod tests {
 						fallback_names: Vec::new(),
 						handshake: vec![1, 2, 3, 4],
 						max_notification_size: u64::MAX,
+						bandwidth: None,
 					},
 					notif_handle,
 					command_stream,
@@ -58,6 +58,7 @@
 //! [`NotifsHandlerIn::Open`] has gotten an answer.
 
 use crate::{
+	bandwidth::{Direction, ProtocolBandwidth},
 	protocol::notifications::{
 		service::metrics,
 		upgrade::{
@@ -152,7 +153,13 @@ impl NotifsHandler {
 						config.max_notification_size,
 					);
 
-					Protocol { config, in_upgrade, state: State::Closed { pending_opening: false } }
+					Protocol {
+						config,
+						in_upgrade,
+						state: State::Closed { pending_opening: false },
+						inbound_throttle: None,
+						outbound_throttle: None,
+					}
 				})
 				.collect(),
 			peer_id,
@@ -175,6 +182,8 @@ pub struct ProtocolConfig {
 	pub handshake: Arc<RwLock<Vec<u8>>>,
 	/// Maximum allowed size for a notification.
 	pub max_notification_size: u64,
+	/// Bandwidth accounting and rate limiting of the protocol.
+	pub bandwidth: Option<Arc<ProtocolBandwidth>>,
 }
 
 /// Fields specific for each individual protocol.
@@ -187,6 +196,42 @@ struct Protocol {
 
 	/// Current state of the substreams for this protocol.
 	state: State,
+
+	/// If set, notifications aren't read from the inbound substream before the delay fires.
+	inbound_throttle: Option<futures_timer::Delay>,
+
+	/// If set, notifications aren't written to the outbound substream before the delay fires.
+	outbound_throttle: Option<futures_timer::Delay>,
+}
+
+/// Returns `true` if the traffic with `peer` must currently be paused because of the rate limits
+/// of the protocol.
+///
+/// The task is woken up once the traffic can resume.
+fn is_throttled(
+	throttle: &mut Option<futures_timer::Delay>,
+	bandwidth: Option<&ProtocolBandwidth>,
+	peer: &PeerId,
+	direction: Direction,
+	cx: &mut Context,
+) -> bool {
+	if let Some(delay) = throttle {
+		if delay.poll_unpin(cx).is_pending() {
+			return true
+		}
+		*throttle = None;
+	}
+
+	let Some(duration) = bandwidth.and_then(|bandwidth| bandwidth.delay(peer, direction)) else {
+		return false
+	};
+
+	let mut delay = futures_timer::Delay::new(duration);
+	if delay.poll_unpin(cx).is_ready() {
+		return false
+	}
+	*throttle = Some(delay);
+	true
 }
 
 /// See the module-level documentation to learn about the meaning of these variants.
@@ -749,9 +794,12 @@ impl ConnectionHandler for NotifsHandler {
 		// For each open substream, try send messages from `notifications_sink_rx` to the
 		// substream.
 		for protocol_index in 0..self.protocols.len() {
+			let Protocol { config, state, outbound_throttle, .. } =
+				&mut self.protocols[protocol_index];
+
 			if let State::Open {
 				notifications_sink_rx, out_substream: Some(out_substream), ..
-			} = &mut self.protocols[protocol_index].state
+			} = state
 			{
 				loop {
 					// Only proceed with `out_substream.poll_ready_unpin` if there is an element
@@ -766,6 +814,18 @@ impl ConnectionHandler for NotifsHandler {
 						Poll::Ready(None) | Poll::Pending => break,
 					}
 
+					// Leave the element in `notifications_sink_rx` while the protocol is rate
+					// limited, applying backpressure to the senders.
+					if is_throttled(
+						outbound_throttle,
+						config.bandwidth.as_deref(),
+						&self.peer_id,
+						Direction::Outbound,
+						cx,
+					) {
+						break
+					}
+
 					// Before we extract the element from `notifications_sink_rx`, check that the
 					// substream is ready to accept a message.
 					match out_substream.poll_ready_unpin(cx) {
@@ -786,6 +846,10 @@ impl ConnectionHandler for NotifsHandler {
 						},
 					};
 
+					if let Some(bandwidth) = &config.bandwidth {
+						bandwidth.report(&self.peer_id, Direction::Outbound, message.len());
+					}
+
 					let _ = out_substream.start_send_unpin(message);
 					// Note that flushing is performed later down this function.
 				}
@@ -821,17 +885,35 @@ impl ConnectionHandler for NotifsHandler {
 
 		// Poll inbound substreams.
 		for protocol_index in 0..self.protocols.len() {
+			let Protocol { config, state, inbound_throttle, .. } =
+				&mut self.protocols[protocol_index];
+
 			// Inbound substreams being closed is always tolerated, except for the
 			// `OpenDesiredByRemote` state which might need to be switched back to `Closed`.
-			match &mut self.protocols[protocol_index].state {
+			match state {
 				State::Closed { .. } |
 				State::Open { in_substream: None, .. } |
 				State::Opening { in_substream: None, .. } => {},
 
+				// Stop reading from the substream while the protocol is rate limited, applying
+				// backpressure to the remote.
+				State::Open { in_substream: Some(_), .. }
+					if is_throttled(
+						inbound_throttle,
+						config.bandwidth.as_deref(),
+						&self.peer_id,
+						Direction::Inbound,
+						cx,
+					) => {},
+
 				State::Open { in_substream: in_substream @ Some(_), .. } =>
 					match Stream::poll_next(Pin::new(in_substream.as_mut().unwrap()), cx) {
 						Poll::Pending => {},
 						Poll::Ready(Some(Ok(message))) => {
+							if let Some(bandwidth) = &config.bandwidth {
+								bandwidth.report(&self.peer_id, Direction::Inbound, message.len());
+							}
+
 							let event = NotifsHandlerOut::Notification { protocol_index, message };
 							return Poll::Ready(ConnectionHandlerEvent::Custom(event))
 						},
@@ -1075,9 +1157,12 @@ pub mod tests {
 				fallback_names: vec![],
 				handshake: Arc::new(RwLock::new(b"hello, world".to_vec())),
 				max_notification_size: u64::MAX,
+				bandwidth: None,
 			},
 			in_upgrade: NotificationsIn::new("/foo", Vec::new(), u64::MAX),
 			state: State::Closed { pending_opening: false },
+			inbound_throttle: None,
+			outbound_throttle: None,
 		};
 
 		NotifsHandler {
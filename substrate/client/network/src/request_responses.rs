@@ -35,6 +35,7 @@
 //! is used to handle incoming requests.
 
 use crate::{
+	bandwidth::{Direction, ProtocolBandwidth, ProtocolsBandwidth},
 	peer_store::{PeerStoreProvider, BANNED_THRESHOLD},
 	types::ProtocolName,
 	ReputationChange,
@@ -78,6 +79,8 @@ pub enum RequestFailure {
 	Obsolete,
 	#[error("Problem on the network: {0}")]
 	Network(OutboundFailure),
+	#[error("The outbound rate limit of the protocol has been reached.")]
+	RateLimited,
 }
 
 /// Configuration for a single request-response protocol.
@@ -289,6 +292,9 @@ pub struct RequestResponsesBehaviour {
 
 	/// Primarily used to get a reputation of a node.
 	peer_store: Box<dyn PeerStoreProvider>,
+
+	/// Bandwidth accounting and rate limiting of the protocols.
+	bandwidth: ProtocolsBandwidth,
 }
 
 /// Generated by the response builder and waiting to be processed.
@@ -343,9 +349,17 @@ impl RequestResponsesBehaviour {
 			pending_responses_arrival_time: Default::default(),
 			send_feedback: Default::default(),
 			peer_store,
+			bandwidth: Default::default(),
 		})
 	}
 
+	/// Account the traffic of the protocols in the given bandwidth tracker, enforcing its rate
+	/// limits.
+	pub(crate) fn with_bandwidth(mut self, bandwidth: ProtocolsBandwidth) -> Self {
+		self.bandwidth = bandwidth;
+		self
+	}
+
 	/// Initiates sending a request.
 	///
 	/// If there is no established connection to the target peer, the behavior is determined by the
@@ -364,9 +378,11 @@ impl RequestResponsesBehaviour {
 		log::trace!(target: "sub-libp2p", "send request to {target} ({protocol_name:?}), {} bytes", request.len());
 
 		if let Some((protocol, _)) = self.protocols.get_mut(protocol_name.deref()) {
+			let bandwidth = self.bandwidth.get(&protocol_name);
 			Self::send_request_inner(
 				protocol,
 				&mut self.pending_requests,
+				bandwidth.as_deref(),
 				target,
 				protocol_name,
 				request,
@@ -387,6 +403,7 @@ impl RequestResponsesBehaviour {
 	fn send_request_inner(
 		behaviour: &mut Behaviour<GenericCodec>,
 		pending_requests: &mut HashMap<ProtocolRequestId, PendingRequest>,
+		bandwidth: Option<&ProtocolBandwidth>,
 		target: &PeerId,
 		protocol_name: ProtocolName,
 		request: Vec<u8>,
@@ -394,7 +411,25 @@ impl RequestResponsesBehaviour {
 		pending_response: oneshot::Sender<Result<(Vec<u8>, ProtocolName), RequestFailure>>,
 		connect: IfDisconnected,
 	) {
+		if bandwidth
+			.map_or(false, |bandwidth| bandwidth.delay(target, Direction::Outbound).is_some())
+		{
+			if pending_response.send(Err(RequestFailure::RateLimited)).is_err() {
+				log::debug!(
+					target: "sub-libp2p",
+					"Outbound rate limit of {:?} reached. At the same time local \
+					 node is no longer interested in the result.",
+					protocol_name,
+				);
+			}
+			return
+		}
+
 		if behaviour.is_connected(target) || connect.should_connect() {
+			if let Some(bandwidth) = bandwidth {
+				bandwidth.report(target, Direction::Outbound, request.len());
+			}
+
 			let request_id = behaviour.send_request(target, request);
 			let prev_req_id = pending_requests.insert(
 				(protocol_name.to_string().into(), request_id).into(),
@@ -605,6 +640,12 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 					if let Some((protocol, _)) = self.protocols.get_mut(&*protocol_name) {
 						log::trace!(target: "sub-libp2p", "send response to {peer} ({protocol_name:?}), {} bytes", payload.len());
 
+						// Responses are only accounted for: the rate of inbound requests is
+						// limited instead.
+						if let Some(bandwidth) = self.bandwidth.get(&protocol_name) {
+							bandwidth.report(&peer, Direction::Outbound, payload.len());
+						}
+
 						if protocol.send_response(inner_channel, Ok(payload)).is_err() {
 							// Note: Failure is handled further below when receiving
 							// `InboundFailure` event from request-response [`Behaviour`].
@@ -682,6 +723,23 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 								continue 'poll_protocol
 							}
 
+							if let Some(bandwidth) = self.bandwidth.get(protocol) {
+								let delay = bandwidth.delay(&peer, Direction::Inbound);
+								bandwidth.report(&peer, Direction::Inbound, request.len());
+
+								// Dropping `channel` is reported by the request-response
+								// [`Behaviour`] through an `InboundFailure::Omission` event.
+								if delay.is_some() {
+									log::debug!(
+										target: "sub-libp2p",
+										"Inbound rate limit of {:?} reached, dropping request from {}",
+										protocol,
+										peer,
+									);
+									continue 'poll_protocol
+								}
+							}
+
 							let (tx, rx) = oneshot::channel();
 
 							// Submit the request to the "response builder" passed by the user at
@@ -735,12 +793,17 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 								.remove(&(protocol.clone(), request_id).into())
 							{
 								Some(PendingRequest { started_at, response_tx, .. }) => {
+									let size =
+										response.as_ref().map_or(0usize, |response| response.len());
 									log::trace!(
 										target: "sub-libp2p",
-										"received response from {peer} ({protocol:?}), {} bytes",
-										response.as_ref().map_or(0usize, |response| response.len()),
+										"received response from {peer} ({protocol:?}), {size} bytes",
 									);
 
+									if let Some(bandwidth) = self.bandwidth.get(protocol) {
+										bandwidth.report(&peer, Direction::Inbound, size);
+									}
+
 									let delivered = response_tx
 										.send(
 											response
@@ -894,9 +957,11 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 			// Send out fallback requests.
 			for (peer, protocol, request, pending_response) in fallback_requests.drain(..) {
 				if let Some((behaviour, _)) = self.protocols.get_mut(&protocol) {
+					let bandwidth = self.bandwidth.get(&protocol);
 					Self::send_request_inner(
 						behaviour,
 						&mut self.pending_requests,
+						bandwidth.as_deref(),
 						&peer,
 						protocol,
 						request,
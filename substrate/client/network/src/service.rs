@@ -28,6 +28,7 @@
 //! which is then processed by [`NetworkWorker::next_action`].
 
 use crate::{
+	bandwidth::ProtocolsBandwidth,
 	behaviour::{self, Behaviour, BehaviourOut},
 	config::{parse_addr, FullNetworkConfiguration, MultiaddrWithPeerId, Params, TransportConfig},
 	discovery::DiscoveryConfig,
@@ -377,6 +378,13 @@ where
 		let num_connected = Arc::new(AtomicUsize::new(0));
 		let external_addresses = Arc::new(Mutex::new(HashSet::new()));
 
+		let protocols_bandwidth = ProtocolsBandwidth::new(
+			iter::once(params.block_announce_config.protocol_name().clone())
+				.chain(notification_protocols.iter().map(|p| p.protocol_name().clone()))
+				.chain(request_response_protocols.iter().map(|p| p.name.clone())),
+			&network_config.protocol_rate_limits,
+		);
+
 		let (protocol, notif_protocol_handles) = Protocol::new(
 			From::from(&params.role),
 			&params.metrics_registry,
@@ -385,6 +393,7 @@ where
 			params.peer_store.clone(),
 			protocol_handles.clone(),
 			from_protocol_controllers,
+			&protocols_bandwidth,
 		)?;
 
 		// Build the swarm.
@@ -435,6 +444,7 @@ where
 					request_response_protocols,
 					params.peer_store.clone(),
 					external_addresses.clone(),
+					protocols_bandwidth.clone(),
 				);
 
 				match result {
@@ -483,6 +493,7 @@ where
 				registry,
 				MetricSources {
 					bandwidth: bandwidth.clone(),
+					protocols_bandwidth,
 					connected_peers: num_connected.clone(),
 				},
 			)?),
@@ -1376,6 +1387,7 @@ where
 								RequestFailure::UnknownProtocol => "unknown-protocol",
 								RequestFailure::Refused => "refused",
 								RequestFailure::Obsolete => "obsolete",
+								RequestFailure::RateLimited => "rate-limited",
 								RequestFailure::Network(OutboundFailure::DialFailure) =>
 									"dial-failure",
 								RequestFailure::Network(OutboundFailure::Timeout) => "timeout",
@@ -16,7 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::transport::BandwidthSinks;
+use crate::{
+	bandwidth::{Direction, ProtocolsBandwidth},
+	transport::BandwidthSinks,
+};
 use prometheus_endpoint::{
 	self as prometheus, Counter, CounterVec, Gauge, GaugeVec, HistogramOpts, MetricSource, Opts,
 	PrometheusError, Registry, SourcedCounter, SourcedGauge, U64,
@@ -34,6 +37,7 @@ pub use prometheus_endpoint::{Histogram, HistogramVec};
 /// Registers all networking metrics with the given registry.
 pub fn register(registry: &Registry, sources: MetricSources) -> Result<Metrics, PrometheusError> {
 	BandwidthCounters::register(registry, sources.bandwidth)?;
+	ProtocolBandwidthCounters::register(registry, sources.protocols_bandwidth)?;
 	NumConnectedGauge::register(registry, sources.connected_peers)?;
 	Metrics::register(registry)
 }
@@ -41,6 +45,7 @@ pub fn register(registry: &Registry, sources: MetricSources) -> Result<Metrics,
 /// Predefined metric sources that are fed directly into prometheus.
 pub struct MetricSources {
 	pub bandwidth: Arc<BandwidthSinks>,
+	pub protocols_bandwidth: ProtocolsBandwidth,
 	pub connected_peers: Arc<AtomicUsize>,
 }
 
@@ -236,6 +241,72 @@ impl MetricSource for BandwidthCounters {
 	}
 }
 
+/// The per-protocol bandwidth counter metrics.
+#[derive(Clone)]
+pub struct ProtocolBandwidthCounters(ProtocolsBandwidth);
+
+impl ProtocolBandwidthCounters {
+	/// Registers the per-protocol bandwidth and rate limiting metrics whose values are
+	/// obtained from the given protocols.
+	fn register(registry: &Registry, protocols: ProtocolsBandwidth) -> Result<(), PrometheusError> {
+		prometheus::register(
+			SourcedCounter::new(
+				&Opts::new(
+					"substrate_sub_libp2p_protocol_bytes_total",
+					"Total bandwidth usage per protocol",
+				)
+				.variable_label("direction")
+				.variable_label("protocol"),
+				ProtocolBandwidthCounters(protocols.clone()),
+			)?,
+			registry,
+		)?;
+
+		prometheus::register(
+			SourcedCounter::new(
+				&Opts::new(
+					"substrate_sub_libp2p_protocol_rate_limited_total",
+					"Number of times the traffic of a protocol has been throttled",
+				)
+				.variable_label("direction")
+				.variable_label("protocol"),
+				ProtocolRateLimitedCounters(protocols),
+			)?,
+			registry,
+		)?;
+
+		Ok(())
+	}
+}
+
+impl MetricSource for ProtocolBandwidthCounters {
+	type N = u64;
+
+	fn collect(&self, mut set: impl FnMut(&[&str], Self::N)) {
+		for (protocol, bandwidth) in self.0.iter() {
+			for direction in [Direction::Inbound, Direction::Outbound] {
+				set(&[direction.label(), &protocol[..]], bandwidth.total_bytes(direction));
+			}
+		}
+	}
+}
+
+/// The per-protocol rate limiting counter metric.
+#[derive(Clone)]
+pub struct ProtocolRateLimitedCounters(ProtocolsBandwidth);
+
+impl MetricSource for ProtocolRateLimitedCounters {
+	type N = u64;
+
+	fn collect(&self, mut set: impl FnMut(&[&str], Self::N)) {
+		for (protocol, bandwidth) in self.0.iter() {
+			for direction in [Direction::Inbound, Direction::Outbound] {
+				set(&[direction.label(), &protocol[..]], bandwidth.total_rate_limited(direction));
+			}
+		}
+	}
+}
+
 /// The connected peers metric.
 #[derive(Clone)]
 pub struct NumConnectedGauge(Arc<AtomicUsize>);
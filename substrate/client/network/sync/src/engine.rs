@@ -247,6 +247,10 @@ pub struct SyncingEngine<B: BlockT, Client> {
 	/// All connected peers. Contains both full and light node peers.
 	peers: HashMap<PeerId, Peer<B>>,
 
+	/// Peers removed from the syncing strategy until the next tick because the local outbound
+	/// rate limit of a request protocol was reached.
+	rate_limited_peers: HashSet<PeerId>,
+
 	/// List of nodes for which we perform additional logging because they are important for the
 	/// user.
 	important_peers: HashSet<PeerId>,
@@ -485,6 +489,7 @@ where
 				genesis_hash,
 				important_peers,
 				default_peers_set_no_slot_connected_peers: HashSet::new(),
+				rate_limited_peers: HashSet::new(),
 				warp_sync_target_block_header_rx_fused,
 				boot_node_ids,
 				default_peers_set_no_slot_peers,
@@ -776,6 +781,13 @@ where
 	fn perform_periodic_actions(&mut self) {
 		self.report_metrics();
 
+		// give the rate limited peers back to the syncing strategy.
+		for peer_id in std::mem::take(&mut self.rate_limited_peers) {
+			if let Some(peer) = self.peers.get(&peer_id) {
+				self.strategy.add_peer(peer_id, peer.info.best_hash, peer.info.best_number);
+			}
+		}
+
 		// if `SyncingEngine` has just started, don't evict seemingly inactive peers right away
 		// as they may not have produced blocks not because they've disconnected but because
 		// they're still waiting to receive enough relaychain blocks to start producing blocks.
@@ -1002,6 +1014,7 @@ where
 		}
 
 		self.strategy.remove_peer(&peer_id);
+		self.rate_limited_peers.remove(&peer_id);
 		self.pending_responses.remove(&peer_id);
 		self.event_streams
 			.retain(|stream| stream.unbounded_send(SyncEvent::PeerDisconnected(peer_id)).is_ok());
@@ -1345,6 +1358,13 @@ where
 						self.network_service
 							.disconnect_peer(peer_id, self.block_announce_protocol_name.clone());
 					},
+					RequestFailure::RateLimited => {
+						// The local outbound rate limit isn't the peer's fault. Skip the peer until
+						// the next tick, so that the requested blocks are rescheduled on other
+						// peers.
+						self.strategy.remove_peer(&peer_id);
+						self.rate_limited_peers.insert(peer_id);
+					},
 					RequestFailure::UnknownProtocol => {
 						debug_assert!(false, "Block request protocol should always be known.");
 					},
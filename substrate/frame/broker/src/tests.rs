@@ -24,7 +24,7 @@ use frame_support::{
 	BoundedVec,
 };
 use frame_system::RawOrigin::Root;
-use sp_runtime::traits::Get;
+use sp_runtime::{traits::Get, DispatchError};
 use CoreAssignment::*;
 use CoretimeTraceItem::*;
 use Finality::*;
@@ -358,6 +358,33 @@ fn instapool_payouts_work() {
 	});
 }
 
+#[test]
+fn notified_revenue_is_processed() {
+	TestExt::new().execute_with(|| {
+		let revenue = OnDemandRevenueRecord { until: 10, amount: 10 };
+		assert_noop!(
+			Broker::notify_revenue(RuntimeOrigin::signed(2), revenue.clone()),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(Broker::notify_revenue(RuntimeOrigin::root(), revenue));
+		assert!(RevenueInbox::<Test>::get().is_some());
+
+		InstaPoolHistory::<Test>::insert(
+			4,
+			InstaPoolHistoryRecord {
+				private_contributions: 1,
+				system_contributions: 9,
+				maybe_payout: None,
+			},
+		);
+		assert!(Broker::process_revenue());
+		assert!(RevenueInbox::<Test>::get().is_none());
+		assert_eq!(InstaPoolHistory::<Test>::get(4).unwrap().maybe_payout, Some(1));
+		// Nothing is pending any more.
+		assert!(!Broker::process_revenue());
+	});
+}
+
 #[test]
 fn instapool_partial_core_payouts_work() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
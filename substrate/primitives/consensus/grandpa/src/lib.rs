@@ -532,4 +532,16 @@ sp_api::decl_runtime_apis! {
 		/// Get current GRANDPA authority set id.
 		fn current_set_id() -> SetId;
 	}
+
+	/// API allowing the runtime to restrict the blocks GRANDPA voters may vote for.
+	///
+	/// This is consulted by the client-side voting rules when picking a vote target, e.g. to
+	/// prevent finalizing past a block enacting a pending code upgrade until it is approved.
+	pub trait GrandpaVotingRuleApi {
+		/// Get the number of the highest block voters are allowed to vote for.
+		///
+		/// When called at block B, the returned number refers to a block in the chain ending at
+		/// B (B included). `None` is returned when the vote shouldn't be restricted.
+		fn voting_target_limit() -> Option<NumberFor<Block>>;
+	}
 }